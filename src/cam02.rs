@@ -0,0 +1,102 @@
+use crate::Xyz;
+
+/// Viewing conditions of the CIECAM02 color appearance model.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct ViewingConditions {
+    /// Adapting white, with a luminance of 100.
+    pub white: Xyz,
+    /// Luminance of the adapting field, in cd/m².
+    pub la: f64,
+    /// Relative luminance of the background.
+    pub yb: f64,
+    /// Degree of adaptation to the white (0 to 1).
+    pub d: f64,
+}
+
+/// CIECAM02 correlates of a color.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Cam02 {
+    /// Lightness.
+    pub j: f64,
+    /// Hue angle, in degrees.
+    pub h: f64,
+    /// Colorfulness.
+    pub m: f64,
+}
+
+// average surround
+const C: f64 = 0.69;
+const NC: f64 = 1.0;
+
+const CAT02: [[f64; 3]; 3] = [
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+];
+
+const CAT02_INV: [[f64; 3]; 3] = [
+    [1.096124, -0.278869, 0.182745],
+    [0.454369, 0.473533, 0.072098],
+    [-0.009628, -0.005698, 1.015326],
+];
+
+const HPE: [[f64; 3]; 3] = [
+    [0.38971, 0.68898, -0.07868],
+    [-0.22981, 1.18340, 0.04641],
+    [0.0, 0.0, 1.0],
+];
+
+fn mul(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+impl Cam02 {
+    /// The appearance of `xyz` under `vc`, with `xyz` relative to a white of 100.
+    pub fn from_xyz(xyz: &Xyz, vc: &ViewingConditions) -> Self {
+        let w = vc.white;
+        let k = 1.0 / (5.0 * vc.la + 1.0);
+        let k4 = k * k * k * k;
+        let fl = 0.2 * k4 * 5.0 * vc.la + 0.1 * (1.0 - k4).powi(2) * (5.0 * vc.la).cbrt();
+        let n = vc.yb / w.y;
+        let nbb = 0.725 * (1.0 / n).powf(0.2);
+        let z = 1.48 + n.sqrt();
+
+        let rgb_w = mul(&CAT02, [w.x, w.y, w.z]);
+        let gain: Vec<f64> = rgb_w.iter().map(|c| vc.d * w.y / c + 1.0 - vc.d).collect();
+        let adapt = |xyz: [f64; 3]| {
+            let rgb = mul(&CAT02, xyz);
+            let rgb_c = [rgb[0] * gain[0], rgb[1] * gain[1], rgb[2] * gain[2]];
+            let rgb_p = mul(&HPE, mul(&CAT02_INV, rgb_c));
+            let compress = |c: f64| {
+                let t = (fl * c.abs() / 100.0).powf(0.42);
+                c.signum() * 400.0 * t / (27.13 + t) + 0.1
+            };
+            [compress(rgb_p[0]), compress(rgb_p[1]), compress(rgb_p[2])]
+        };
+        let achromatic = |a: &[f64; 3]| (2.0 * a[0] + a[1] + a[2] / 20.0 - 0.305) * nbb;
+
+        let aw = adapt([w.x, w.y, w.z]);
+        let r = adapt([xyz.x, xyz.y, xyz.z]);
+        let a = r[0] - 12.0 * r[1] / 11.0 + r[2] / 11.0;
+        let b = (r[0] + r[1] - 2.0 * r[2]) / 9.0;
+        let h = b.atan2(a).to_degrees().rem_euclid(360.0);
+        let et = 0.25 * ((h.to_radians() + 2.0).cos() + 3.8);
+        let j = 100.0 * (achromatic(&r) / achromatic(&aw)).max(0.0).powf(C * z);
+        let t = (50000.0 / 13.0 * NC * nbb * et * (a * a + b * b).sqrt())
+            / (r[0] + r[1] + 21.0 / 20.0 * r[2]);
+        let c = t.powf(0.9) * (j / 100.0).sqrt() * (1.64 - 0.29f64.powf(n)).powf(0.73);
+        Self { j, h, m: c * fl.powf(0.25) }
+    }
+
+    /// The CAM02-UCS coordinates (J', a', b').
+    pub fn ucs(&self) -> [f64; 3] {
+        let j = 1.7 * self.j / (1.0 + 0.007 * self.j);
+        let m = (1.0 + 0.0228 * self.m).ln() / 0.0228;
+        let h = self.h.to_radians();
+        [j, m * h.cos(), m * h.sin()]
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{ Xyz, Yxy };
+use crate::chromaticity::{ xy_to_uv, xyz_to_xy };
+use crate::planckian::planckian_uv;
+
+/// Method used to estimate the correlated color temperature of a color.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CctMethod {
+    /// McCamy (1992) cubic approximation, accurate to a few kelvin between 2000K and 12500K.
+    McCamy,
+    /// Hernández-Andrés et al. (1999) exponential approximation, valid from 3000K to 800000K.
+    HernandezAndres,
+    /// Ohno (2013) combined triangular and parabolic search of the Planckian locus.
+    Ohno,
+}
+
+pub trait Cct {
+    /// Estimate the correlated color temperature in kelvin.
+    fn cct(&self, method: CctMethod) -> f64;
+
+    /// Distance from the Planckian locus in the CIE 1960 UCS diagram, positive above the locus.
+    fn duv(&self) -> f64;
+}
+
+const OHNO_MIN: f64 = 1000.0;
+const OHNO_MAX: f64 = 100000.0;
+const OHNO_STEP: f64 = 1.01;
+const OHNO_REFINEMENTS: usize = 3;
+const OHNO_SAMPLES: usize = 16;
+
+fn mccamy(x: f64, y: f64) -> f64 {
+    let n = (x - 0.3320) / (y - 0.1858);
+    -449.0 * n.powf(3.0) + 3525.0 * n * n - 6823.3 * n + 5520.33
+}
+
+fn hernandez_andres(x: f64, y: f64) -> f64 {
+    let n = (x - 0.3366) / (y - 0.1735);
+    let cct = -949.86315
+        + 6253.80338 * (-n / 0.92159).exp()
+        + 28.70599 * (-n / 0.20039).exp()
+        + 0.00004 * (-n / 0.07125).exp();
+    if cct <= 50000.0 {
+        return cct;
+    }
+    let n = (x - 0.3356) / (y - 0.1691);
+    36284.48953
+        + 0.00228 * (-n / 0.07861).exp()
+        + 5.4535e-36 * (-n / 0.01543).exp()
+}
+
+/// A sample of the Planckian locus with its distance to the test chromaticity.
+#[derive(Copy, Clone)]
+struct Sample {
+    t: f64,
+    u: f64,
+    v: f64,
+    d: f64,
+}
+
+fn sample(t: f64, u: f64, v: f64) -> Sample {
+    let (pu, pv) = planckian_uv(t);
+    Sample { t, u: pu, v: pv, d: ((u - pu) * (u - pu) + (v - pv) * (v - pv)).sqrt() }
+}
+
+fn closest(samples: &[Sample]) -> usize {
+    let mut m = 0;
+    for (i, s) in samples.iter().enumerate() {
+        if s.d < samples[m].d {
+            m = i;
+        }
+    }
+    m.max(1).min(samples.len() - 2)
+}
+
+/// Ohno (2013) estimation of (CCT, Duv), refining the 1% locus table around
+/// the closest point so the final interpolation runs over a very short arc.
+fn ohno(u: f64, v: f64) -> (f64, f64) {
+    let mut samples = Vec::new();
+    let mut t = OHNO_MIN;
+    while t < OHNO_MAX {
+        samples.push(sample(t, u, v));
+        t *= OHNO_STEP;
+    }
+    let mut m = closest(&samples);
+    for _ in 0..OHNO_REFINEMENTS {
+        let (lo, hi) = (samples[m - 1].t, samples[m + 1].t);
+        samples = (0..OHNO_SAMPLES)
+            .map(|i| sample(lo + (hi - lo) * i as f64 / (OHNO_SAMPLES - 1) as f64, u, v))
+            .collect();
+        m = closest(&samples);
+    }
+    let (p, c, n) = (samples[m - 1], samples[m], samples[m + 1]);
+
+    // triangular solution
+    let l = ((n.u - p.u) * (n.u - p.u) + (n.v - p.v) * (n.v - p.v)).sqrt();
+    let x = (p.d * p.d - n.d * n.d + l * l) / (2.0 * l);
+    let t = p.t + (n.t - p.t) * x / l;
+    let vtx = p.v + (n.v - p.v) * x / l;
+    let sign = if v - vtx < 0.0 { -1.0 } else { 1.0 };
+    let duv = (p.d * p.d - x * x).max(0.0).sqrt() * sign;
+    if duv.abs() < 0.002 {
+        return (t, duv);
+    }
+
+    // parabolic solution, in temperatures relative to the closest sample
+    let (tp, tn) = (p.t - c.t, n.t - c.t);
+    let x = tn * (tp - tn) * -tp;
+    let a = (tp * (n.d - c.d) + tn * (c.d - p.d)) / x;
+    let b = -(tp * tp * (n.d - c.d) + tn * tn * (c.d - p.d)) / x;
+    let t = -b / (2.0 * a);
+    (c.t + t, (a * t * t + b * t + c.d) * sign)
+}
+
+fn cct_xy(x: f64, y: f64, method: CctMethod) -> f64 {
+    match method {
+        CctMethod::McCamy => mccamy(x, y),
+        CctMethod::HernandezAndres => hernandez_andres(x, y),
+        CctMethod::Ohno => {
+            let (u, v) = xy_to_uv(x, y);
+            ohno(u, v).0
+        }
+    }
+}
+
+fn duv_xy(x: f64, y: f64) -> f64 {
+    let (u, v) = xy_to_uv(x, y);
+    ohno(u, v).1
+}
+
+impl Cct for Xyz {
+    fn cct(&self, method: CctMethod) -> f64 {
+        let (x, y) = xyz_to_xy(self);
+        cct_xy(x, y, method)
+    }
+
+    fn duv(&self) -> f64 {
+        let (x, y) = xyz_to_xy(self);
+        duv_xy(x, y)
+    }
+}
+
+impl Cct for Yxy {
+    fn cct(&self, method: CctMethod) -> f64 {
+        cct_xy(self.x, self.y2, method)
+    }
+
+    fn duv(&self) -> f64 {
+        duv_xy(self.x, self.y2)
+    }
+}
+
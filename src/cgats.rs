@@ -0,0 +1,387 @@
+use crate::{ Cmyk, Lab, Rgb, Spectrum, Xyz };
+use std::fmt;
+
+/// An error reading a CGATS file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CgatsError {
+    /// The file has no identifier line.
+    Empty,
+    /// A quoted string is not closed on the line it starts on.
+    UnterminatedString(usize),
+    /// A section, such as `BEGIN_DATA_FORMAT`, is missing or not closed.
+    MissingSection(&'static str),
+    /// The values of the data do not fill a whole number of rows.
+    RowLength { expected: usize, found: usize },
+    /// `NUMBER_OF_FIELDS` or `NUMBER_OF_SETS` disagrees with the data.
+    CountMismatch { keyword: &'static str, declared: usize, found: usize },
+    /// A row added to a table has different fields from the rows before it.
+    FieldMismatch,
+}
+
+impl fmt::Display for CgatsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CgatsError::Empty => write!(f, "the file is empty"),
+            CgatsError::UnterminatedString(line) => write!(f, "unterminated string on line {}", line),
+            CgatsError::MissingSection(name) => write!(f, "missing or unclosed {}", name),
+            CgatsError::RowLength { expected, found } => {
+                write!(f, "{} values do not make rows of {} fields", found, expected)
+            }
+            CgatsError::CountMismatch { keyword, declared, found } => {
+                write!(f, "{} declares {} but the data has {}", keyword, declared, found)
+            }
+            CgatsError::FieldMismatch => write!(f, "the row has different fields from the table"),
+        }
+    }
+}
+
+impl std::error::Error for CgatsError {}
+
+/// Keywords defined by CGATS.17, which need no `KEYWORD` declaration.
+const STANDARD_KEYWORDS: [&str; 26] = [
+    "NUMBER_OF_FIELDS", "NUMBER_OF_SETS", "ORIGINATOR", "FILE_DESCRIPTOR", "CREATED",
+    "DESCRIPTOR", "DIFFUSE_GEOMETRY", "MANUFACTURER", "MANUFACTURE", "PROD_DATE", "SERIAL",
+    "MATERIAL", "INSTRUMENTATION", "MEASUREMENT_SOURCE", "PRINT_CONDITIONS", "SAMPLE_BACKING",
+    "CHISQ_DOF", "MEASUREMENT_GEOMETRY", "FILTER", "POLARIZATION", "WEIGHTING_FUNCTION",
+    "COMPUTATIONAL_PARAMETER", "TARGET_TYPE", "COLORANT", "TABLE_DESCRIPTOR", "TABLE_NAME",
+];
+
+/// A CGATS.17 (IT8) measurement file with a single table.
+///
+/// Values are kept as text, so files round trip. Typed accessors read rows with the
+/// standard field names: `LAB_L`, `XYZ_X`, `CMYK_C` (percent), `RGB_R` (0 to 255),
+/// and spectral fields such as `SPECTRAL_NM380` or `nm380`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cgats {
+    /// The file type on the first line, such as `CGATS.17` or `IT8.7/4`.
+    pub identifier: String,
+    /// Header keywords and their values, in order, without quotes.
+    pub keywords: Vec<(String, String)>,
+    /// The fields of `BEGIN_DATA_FORMAT`.
+    pub fields: Vec<String>,
+    /// The values of each row of `BEGIN_DATA`, without quotes.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A color written as columns of a CGATS table.
+pub trait CgatsFields {
+    /// The field names and values of the color.
+    fn cgats_fields(&self) -> Vec<(String, f64)>;
+}
+
+impl CgatsFields for Lab {
+    fn cgats_fields(&self) -> Vec<(String, f64)> {
+        vec![("LAB_L".into(), self.l), ("LAB_A".into(), self.a), ("LAB_B".into(), self.b)]
+    }
+}
+
+impl CgatsFields for Xyz {
+    fn cgats_fields(&self) -> Vec<(String, f64)> {
+        vec![("XYZ_X".into(), self.x), ("XYZ_Y".into(), self.y), ("XYZ_Z".into(), self.z)]
+    }
+}
+
+impl CgatsFields for Cmyk {
+    fn cgats_fields(&self) -> Vec<(String, f64)> {
+        vec![
+            ("CMYK_C".into(), self.c * 100.0),
+            ("CMYK_M".into(), self.m * 100.0),
+            ("CMYK_Y".into(), self.y * 100.0),
+            ("CMYK_K".into(), self.k * 100.0),
+        ]
+    }
+}
+
+impl CgatsFields for Rgb {
+    fn cgats_fields(&self) -> Vec<(String, f64)> {
+        vec![("RGB_R".into(), self.r), ("RGB_G".into(), self.g), ("RGB_B".into(), self.b)]
+    }
+}
+
+impl CgatsFields for Spectrum {
+    fn cgats_fields(&self) -> Vec<(String, f64)> {
+        self.wavelengths
+            .iter()
+            .zip(&self.values)
+            .map(|(nm, v)| (format!("SPECTRAL_NM{}", nm.round()), *v))
+            .collect()
+    }
+}
+
+/// Split a line into whitespace separated tokens, keeping quoted strings whole and
+/// dropping comments. Within a quoted string a doubled quote stands for one. Quoted
+/// tokens are marked so keywords can tell them apart.
+fn tokenize(line: &str, number: usize) -> Result<Vec<(String, bool)>, CgatsError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '#' {
+            break;
+        } else if c == '"' || c == '\'' {
+            chars.next();
+            let mut token = String::new();
+            loop {
+                match chars.next() {
+                    Some(q) if q == c && chars.peek() == Some(&c) => {
+                        token.push(c);
+                        chars.next();
+                    }
+                    Some(q) if q == c => break,
+                    Some(other) => token.push(other),
+                    None => return Err(CgatsError::UnterminatedString(number)),
+                }
+            }
+            tokens.push((token, true));
+        } else {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+            tokens.push((token, false));
+        }
+    }
+    Ok(tokens)
+}
+
+/// The wavelength of a spectral field name.
+fn wavelength(field: &str) -> Option<f64> {
+    let upper = field.to_ascii_uppercase();
+    ["SPECTRAL_NM", "SPECTRAL_", "NM_", "NM"]
+        .iter()
+        .find_map(|prefix| upper.strip_prefix(prefix))
+        .and_then(|rest| rest.trim_start_matches('_').parse().ok())
+}
+
+impl Cgats {
+    /// An empty file of the type `identifier`.
+    pub fn new(identifier: &str) -> Self {
+        Self { identifier: identifier.into(), ..Default::default() }
+    }
+
+    /// Read a CGATS file. Of a file with several tables, the first is read.
+    pub fn parse(text: &str) -> Result<Self, CgatsError> {
+        let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+        let identifier = loop {
+            match lines.next() {
+                Some((n, line)) => match tokenize(line, n)?.into_iter().next() {
+                    Some((token, _)) => break token,
+                    None => continue,
+                },
+                None => return Err(CgatsError::Empty),
+            }
+        };
+        let mut cgats = Cgats::new(&identifier);
+        let mut format: Option<Vec<String>> = None;
+        let mut data: Option<Vec<String>> = None;
+        let mut section: Option<&str> = None;
+        'lines: for (n, line) in lines {
+            let tokens = tokenize(line, n)?;
+            for (token, quoted) in &tokens {
+                match (section, token.as_str(), quoted) {
+                    (Some("format"), "END_DATA_FORMAT", false) => section = None,
+                    (Some("data"), "END_DATA", false) => {
+                        section = None;
+                        break 'lines;
+                    }
+                    (Some("format"), _, _) => format.get_or_insert_with(Vec::new).push(token.clone()),
+                    (Some(_), _, _) => data.get_or_insert_with(Vec::new).push(token.clone()),
+                    (None, "BEGIN_DATA_FORMAT", false) => {
+                        section = Some("format");
+                        format.get_or_insert_with(Vec::new);
+                    }
+                    (None, "BEGIN_DATA", false) => {
+                        section = Some("data");
+                        data.get_or_insert_with(Vec::new);
+                    }
+                    // a declaration of a custom keyword, which needs no keeping
+                    (None, "KEYWORD", false) => break,
+                    (None, _, _) => {
+                        // a keyword line: the name, then the rest of the line as its value
+                        let value: Vec<&str> = tokens[1..].iter().map(|(t, _)| t.as_str()).collect();
+                        cgats.keywords.push((token.clone(), value.join(" ")));
+                        break;
+                    }
+                }
+            }
+        }
+        if section == Some("format") {
+            return Err(CgatsError::MissingSection("BEGIN_DATA_FORMAT"));
+        }
+        if section == Some("data") {
+            return Err(CgatsError::MissingSection("BEGIN_DATA"));
+        }
+        cgats.fields = format.ok_or(CgatsError::MissingSection("BEGIN_DATA_FORMAT"))?;
+        let data = data.ok_or(CgatsError::MissingSection("BEGIN_DATA"))?;
+        let width = cgats.fields.len();
+        if width == 0 || data.len() % width != 0 {
+            return Err(CgatsError::RowLength { expected: width, found: data.len() });
+        }
+        cgats.rows = data.chunks(width).map(|row| row.to_vec()).collect();
+        for &(keyword, found) in &[("NUMBER_OF_FIELDS", width), ("NUMBER_OF_SETS", cgats.rows.len())] {
+            if let Some(declared) = cgats.keyword(keyword).and_then(|v| v.parse::<usize>().ok()) {
+                if declared != found {
+                    return Err(CgatsError::CountMismatch { keyword, declared, found });
+                }
+            }
+        }
+        Ok(cgats)
+    }
+
+    /// The value of a header keyword.
+    pub fn keyword(&self, name: &str) -> Option<&str> {
+        self.keywords.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    /// Set a header keyword, replacing its value if it is already present.
+    pub fn set_keyword(&mut self, name: &str, value: &str) {
+        match self.keywords.iter_mut().find(|(k, _)| k == name) {
+            Some(entry) => entry.1 = value.into(),
+            None => self.keywords.push((name.into(), value.into())),
+        }
+    }
+
+    /// The index of a field, ignoring case.
+    pub fn field(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|f| f.eq_ignore_ascii_case(name))
+    }
+
+    /// The number in the field `name` of `row`.
+    pub fn number(&self, row: usize, name: &str) -> Option<f64> {
+        self.rows.get(row)?.get(self.field(name)?)?.parse().ok()
+    }
+
+    fn numbers<const N: usize>(&self, row: usize, names: [&str; N]) -> Option<[f64; N]> {
+        let mut values = [0.0; N];
+        for (value, name) in values.iter_mut().zip(names.iter()) {
+            *value = self.number(row, name)?;
+        }
+        Some(values)
+    }
+
+    /// The `SAMPLE_ID` of `row`, or its `SAMPLE_NAME` when there is no ID.
+    pub fn sample_id(&self, row: usize) -> Option<&str> {
+        let field = self.field("SAMPLE_ID").or_else(|| self.field("SAMPLE_NAME"))?;
+        self.rows.get(row)?.get(field).map(String::as_str)
+    }
+
+    /// The `Lab` of `row`.
+    pub fn lab(&self, row: usize) -> Option<Lab> {
+        let [l, a, b] = self.numbers(row, ["LAB_L", "LAB_A", "LAB_B"])?;
+        Some(Lab::new(l, a, b))
+    }
+
+    /// The `Xyz` of `row`.
+    pub fn xyz(&self, row: usize) -> Option<Xyz> {
+        let [x, y, z] = self.numbers(row, ["XYZ_X", "XYZ_Y", "XYZ_Z"])?;
+        Some(Xyz::new(x, y, z))
+    }
+
+    /// The `Cmyk` of `row`, from percentages.
+    pub fn cmyk(&self, row: usize) -> Option<Cmyk> {
+        let [c, m, y, k] = self.numbers(row, ["CMYK_C", "CMYK_M", "CMYK_Y", "CMYK_K"])?;
+        Some(Cmyk::new(c / 100.0, m / 100.0, y / 100.0, k / 100.0))
+    }
+
+    /// The `Rgb` of `row`.
+    pub fn rgb(&self, row: usize) -> Option<Rgb> {
+        let [r, g, b] = self.numbers(row, ["RGB_R", "RGB_G", "RGB_B"])?;
+        Some(Rgb::new(r, g, b))
+    }
+
+    /// The spectrum of `row`, with the values as in the file: instruments write either
+    /// fractions or percentages.
+    pub fn spectrum(&self, row: usize) -> Option<Spectrum> {
+        let values = self.rows.get(row)?;
+        let mut samples: Vec<(f64, f64)> = self
+            .fields
+            .iter()
+            .zip(values)
+            .filter_map(|(field, value)| Some((wavelength(field)?, value.parse().ok()?)))
+            .collect();
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let (wavelengths, values) = samples.into_iter().unzip();
+        Some(Spectrum::new(wavelengths, values))
+    }
+
+    /// Add a row with the sample `id` and the columns of `colors`. The first row sets the
+    /// fields of the table, and later rows must have the same ones.
+    pub fn push(&mut self, id: &str, colors: &[&dyn CgatsFields]) -> Result<(), CgatsError> {
+        let columns: Vec<(String, f64)> = colors.iter().flat_map(|c| c.cgats_fields()).collect();
+        let fields: Vec<String> = Some("SAMPLE_ID".to_string())
+            .into_iter()
+            .chain(columns.iter().map(|(name, _)| name.clone()))
+            .collect();
+        if self.rows.is_empty() && self.fields.is_empty() {
+            self.fields = fields;
+        } else if self.fields != fields {
+            return Err(CgatsError::FieldMismatch);
+        }
+        let row = Some(id.to_string())
+            .into_iter()
+            .chain(columns.iter().map(|(_, v)| format_number(*v)))
+            .collect();
+        self.rows.push(row);
+        Ok(())
+    }
+}
+
+fn format_number(v: f64) -> String {
+    let text = format!("{:.4}", v);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" => "0".into(),
+        _ => text.into(),
+    }
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+/// Write the file, declaring non-standard keywords and updating the field and set counts.
+impl fmt::Display for Cgats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.identifier)?;
+        for (name, value) in &self.keywords {
+            if name == "NUMBER_OF_FIELDS" || name == "NUMBER_OF_SETS" {
+                continue;
+            }
+            if !STANDARD_KEYWORDS.contains(&name.as_str()) {
+                writeln!(f, "KEYWORD \"{}\"", name)?;
+            }
+            match value.parse::<f64>() {
+                Ok(_) => writeln!(f, "{}\t{}", name, value)?,
+                Err(_) => writeln!(f, "{}\t{}", name, quote(value))?,
+            }
+        }
+        writeln!(f, "NUMBER_OF_FIELDS {}", self.fields.len())?;
+        writeln!(f, "BEGIN_DATA_FORMAT")?;
+        writeln!(f, "{}", self.fields.join("\t"))?;
+        writeln!(f, "END_DATA_FORMAT")?;
+        writeln!(f, "NUMBER_OF_SETS {}", self.rows.len())?;
+        writeln!(f, "BEGIN_DATA")?;
+        for row in &self.rows {
+            let values: Vec<String> = row
+                .iter()
+                .map(|v| {
+                    // values the tokenizer would split, or read as a string or a comment
+                    match v.is_empty() || v.contains(char::is_whitespace) || v.starts_with(&['"', '\'', '#'][..]) {
+                        true => quote(v),
+                        false => v.clone(),
+                    }
+                })
+                .collect();
+            writeln!(f, "{}", values.join("\t"))?;
+        }
+        writeln!(f, "END_DATA")
+    }
+}
@@ -0,0 +1,46 @@
+//! Helpers for moving chromaticity points between the CIE 1931 (x, y),
+//! CIE 1960 (u, v) and CIE 1976 (u', v') diagrams.
+
+use crate::Xyz;
+
+/// The CIE 1931 (x, y) chromaticity of a tristimulus value.
+pub(crate) fn xyz_to_xy(xyz: &Xyz) -> (f64, f64) {
+    let sum = xyz.x + xyz.y + xyz.z;
+    (xyz.x / sum, xyz.y / sum)
+}
+
+/// Convert a CIE 1931 (x, y) chromaticity to CIE 1960 (u, v).
+pub fn xy_to_uv(x: f64, y: f64) -> (f64, f64) {
+    let d = -2.0 * x + 12.0 * y + 3.0;
+    (4.0 * x / d, 6.0 * y / d)
+}
+
+/// Convert a CIE 1960 (u, v) chromaticity to CIE 1931 (x, y).
+pub fn uv_to_xy(u: f64, v: f64) -> (f64, f64) {
+    let d = 2.0 * u - 8.0 * v + 4.0;
+    (3.0 * u / d, 2.0 * v / d)
+}
+
+/// Convert a CIE 1931 (x, y) chromaticity to CIE 1976 (u', v').
+pub fn xy_to_uv_prime(x: f64, y: f64) -> (f64, f64) {
+    let d = -2.0 * x + 12.0 * y + 3.0;
+    (4.0 * x / d, 9.0 * y / d)
+}
+
+/// Convert a CIE 1976 (u', v') chromaticity to CIE 1931 (x, y).
+pub fn uv_prime_to_xy(u: f64, v: f64) -> (f64, f64) {
+    let d = 6.0 * u - 16.0 * v + 12.0;
+    (9.0 * u / d, 4.0 * v / d)
+}
+
+/// Convert a CIE 1960 (u, v) chromaticity to CIE 1976 (u', v').
+#[inline]
+pub fn uv_to_uv_prime(u: f64, v: f64) -> (f64, f64) {
+    (u, v * 1.5)
+}
+
+/// Convert a CIE 1976 (u', v') chromaticity to CIE 1960 (u, v).
+#[inline]
+pub fn uv_prime_to_uv(u: f64, v: f64) -> (f64, f64) {
+    (u, v / 1.5)
+}
@@ -0,0 +1,373 @@
+use crate::{ Cgats, Cmyk, Lab, Profile, Separation, Xyz };
+use crate::cmyk_tables::FOGRA39;
+use crate::icc::{ D50, from_pcs, to_pcs };
+use crate::lab::{ lab_to_xyz, xyz_to_lab };
+use crate::rgb_space::solve3;
+
+/// How black is chosen when converting colors into a characterized CMYK space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BlackGeneration {
+    /// A fixed black (0 to 1).
+    Fixed(f64),
+    /// Black from the gray component of a first, black-free solution, generated as by
+    /// the separation, whose total ink and black limits are then enforced.
+    Separation(Separation),
+}
+
+/// A slice of the characterization at one black level: a rectilinear grid over cyan,
+/// magenta and yellow.
+#[derive(Clone, Debug)]
+struct Plane {
+    k: f64,
+    levels: [Vec<f64>; 3],
+    values: Vec<[f64; 3]>,
+}
+
+/// Cyan, magenta and yellow with the CIELAB measured for them.
+type Sample = ([f64; 3], [f64; 3]);
+
+/// Smallest number of samples at a black level for it to become a plane of the model.
+const PLANE_SAMPLES: usize = 8;
+
+/// Rounding of the ink values when grouping samples into grids.
+const LEVEL_PRECISION: f64 = 1e4;
+
+fn round(v: f64) -> f64 {
+    (v * LEVEL_PRECISION).round() / LEVEL_PRECISION
+}
+
+/// The segment of `levels` holding `v` and the position of `v` within it.
+fn locate(levels: &[f64], v: f64) -> (usize, f64) {
+    if levels.len() == 1 {
+        return (0, 0.0);
+    }
+    let v = v.clamp(levels[0], levels[levels.len() - 1]);
+    let i = levels.windows(2).position(|w| v <= w[1]).unwrap_or(levels.len() - 2);
+    (i, (v - levels[i]) / (levels[i + 1] - levels[i]))
+}
+
+impl Plane {
+    fn new(k: f64, samples: &[Sample]) -> Self {
+        let axis = |i: usize| {
+            let mut levels: Vec<f64> = samples.iter().map(|(d, _)| round(d[i])).collect();
+            levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            levels.dedup();
+            levels
+        };
+        let levels = [axis(0), axis(1), axis(2)];
+        let (nm, ny) = (levels[1].len(), levels[2].len());
+        let mut sums = vec![([0.0; 3], 0usize); levels[0].len() * nm * ny];
+        for (d, lab) in samples {
+            let index = |i: usize| levels[i].iter().position(|&l| l == round(d[i])).unwrap();
+            let node = &mut sums[(index(0) * nm + index(1)) * ny + index(2)];
+            for (sum, v) in node.0.iter_mut().zip(lab) {
+                *sum += v;
+            }
+            node.1 += 1;
+        }
+        let measured: Vec<Option<[f64; 3]>> = sums
+            .iter()
+            .map(|(sum, count)| match count {
+                0 => None,
+                _ => Some([sum[0] / *count as f64, sum[1] / *count as f64, sum[2] / *count as f64]),
+            })
+            .collect();
+        let values = (0..measured.len())
+            .map(|n| measured[n].unwrap_or_else(|| fill(&levels, &measured, samples, n)))
+            .collect();
+        Plane { k, levels, values }
+    }
+
+    fn eval(&self, cmy: [f64; 3]) -> [f64; 3] {
+        let (nm, ny) = (self.levels[1].len(), self.levels[2].len());
+        let cells = [locate(&self.levels[0], cmy[0]), locate(&self.levels[1], cmy[1]), locate(&self.levels[2], cmy[2])];
+        let mut out = [0.0; 3];
+        for corner in 0..8 {
+            let mut weight = 1.0;
+            let mut index = [0; 3];
+            for i in 0..3 {
+                let upper = corner >> (2 - i) & 1 == 1;
+                let (cell, t) = cells[i];
+                weight *= if upper { t } else { 1.0 - t };
+                index[i] = (cell + upper as usize).min(self.levels[i].len() - 1);
+            }
+            if weight != 0.0 {
+                let value = self.values[(index[0] * nm + index[1]) * ny + index[2]];
+                for j in 0..3 {
+                    out[j] += weight * value[j];
+                }
+            }
+        }
+        out
+    }
+}
+
+/// A value for the grid node `n` that the data lacks: the mean of the linear interpolations
+/// between its measured neighbors along each axis, or when it has none, the inverse
+/// squared distance weighting of the nearest samples.
+fn fill(levels: &[Vec<f64>; 3], measured: &[Option<[f64; 3]>], samples: &[Sample], n: usize) -> [f64; 3] {
+    let sizes = [levels[0].len(), levels[1].len(), levels[2].len()];
+    let index = [n / (sizes[1] * sizes[2]), n / sizes[2] % sizes[1], n % sizes[2]];
+    let node = |i: [usize; 3]| measured[(i[0] * sizes[1] + i[1]) * sizes[2] + i[2]];
+    let mut value = [0.0; 3];
+    let mut count = 0;
+    for axis in 0..3 {
+        let i = index[axis];
+        if i == 0 || i + 1 >= sizes[axis] {
+            continue;
+        }
+        let (mut below, mut above) = (index, index);
+        below[axis] -= 1;
+        above[axis] += 1;
+        if let (Some(a), Some(b)) = (node(below), node(above)) {
+            let l = &levels[axis];
+            let t = (l[i] - l[i - 1]) / (l[i + 1] - l[i - 1]);
+            for j in 0..3 {
+                value[j] += a[j] + (b[j] - a[j]) * t;
+            }
+            count += 1;
+        }
+    }
+    if count > 0 {
+        return [value[0] / count as f64, value[1] / count as f64, value[2] / count as f64];
+    }
+    let at = [levels[0][index[0]], levels[1][index[1]], levels[2][index[2]]];
+    let mut nearest: Vec<(f64, [f64; 3])> = samples
+        .iter()
+        .map(|(d, lab)| ((0..3).map(|i| (d[i] - at[i]).powi(2)).sum::<f64>(), *lab))
+        .collect();
+    nearest.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let mut total = 0.0;
+    for (distance, lab) in nearest.iter().take(NEAREST) {
+        let w = 1.0 / distance;
+        for j in 0..3 {
+            value[j] += w * lab[j];
+        }
+        total += w;
+    }
+    [value[0] / total, value[1] / total, value[2] / total]
+}
+
+/// Samples weighted when filling a grid node with no measured neighbors.
+const NEAREST: usize = 8;
+
+/// A characterized CMYK printing condition, such as FOGRA39, GRACoL or SWOP.
+///
+/// Only FOGRA39 is built in, as `fogra39`; other conditions are loaded from their
+/// characterization data with `from_cgats` or `new`.
+///
+/// The forward model interpolates measured CIELAB values multilinearly: trilinearly over
+/// cyan, magenta and yellow within each measured black level, then linearly between
+/// black levels. Characterization targets such as IT8.7/4 measure full grids at a few
+/// black levels; nodes missing from a grid are filled in from the samples at that
+/// black level, and black levels with fewer than 8 samples are left out.
+///
+/// CIELAB values are kept in the terms of the data, which for printing is D50. As a
+/// `Profile`, colors are separated with the black generation set by `with_black`, by
+/// default `Separation::default()`.
+#[derive(Clone, Debug)]
+pub struct CmykModel {
+    planes: Vec<Plane>,
+    black: BlackGeneration,
+}
+
+impl CmykModel {
+    /// Build the model from measured samples, or `None` when no black level has enough
+    /// samples.
+    pub fn new(samples: &[(Cmyk, Lab)]) -> Option<Self> {
+        let mut groups: Vec<(f64, Vec<Sample>)> = Vec::new();
+        for (cmyk, lab) in samples {
+            let k = round(cmyk.k);
+            let sample = ([cmyk.c, cmyk.m, cmyk.y], [lab.l, lab.a, lab.b]);
+            match groups.iter_mut().find(|(level, _)| *level == k) {
+                Some((_, group)) => group.push(sample),
+                None => groups.push((k, vec![sample])),
+            }
+        }
+        let mut planes: Vec<Plane> = groups
+            .iter()
+            .filter(|(_, group)| group.len() >= PLANE_SAMPLES)
+            .map(|(k, group)| Plane::new(*k, group))
+            .collect();
+        planes.sort_by(|a, b| a.k.partial_cmp(&b.k).unwrap());
+        match planes.is_empty() {
+            true => None,
+            false => Some(CmykModel { planes, black: BlackGeneration::Separation(Separation::default()) }),
+        }
+    }
+
+    /// FOGRA39, offset printing on coated paper, from CIELAB values taken from the
+    /// colorimetric table of the ECI profile ISO Coated v2, which is built on the FOGRA39
+    /// characterization.
+    pub fn fogra39() -> Self {
+        let samples: Vec<(Cmyk, Lab)> = FOGRA39
+            .iter()
+            .map(|(c, l)| (Cmyk::new(c[0], c[1], c[2], c[3]), Lab::new(l[0], l[1], l[2])))
+            .collect();
+        Self::new(&samples).unwrap()
+    }
+
+    /// The model with `black` as the black generation of its `Profile` conversions.
+    pub fn with_black(self, black: BlackGeneration) -> Self {
+        CmykModel { black, ..self }
+    }
+
+    /// Build the model from the rows of a measurement file that have both `CMYK_` and
+    /// `LAB_` fields.
+    pub fn from_cgats(cgats: &Cgats) -> Option<Self> {
+        let samples: Vec<(Cmyk, Lab)> = (0..cgats.rows.len())
+            .filter_map(|row| Some((cgats.cmyk(row)?, cgats.lab(row)?)))
+            .collect();
+        Self::new(&samples)
+    }
+
+    /// The CIELAB color printed for `cmyk`.
+    pub fn to_lab(&self, cmyk: &Cmyk) -> Lab {
+        let cmy = [cmyk.c, cmyk.m, cmyk.y];
+        let ks: Vec<f64> = self.planes.iter().map(|p| p.k).collect();
+        let (i, t) = locate(&ks, cmyk.k);
+        let a = self.planes[i].eval(cmy);
+        let v = match self.planes.get(i + 1) {
+            Some(next) if t > 0.0 => {
+                let b = next.eval(cmy);
+                [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t, a[2] + (b[2] - a[2]) * t]
+            }
+            _ => a,
+        };
+        Lab::new(v[0], v[1], v[2])
+    }
+
+    /// The CMYK printing closest to the CIELAB color `lab`, with black chosen by `black`.
+    pub fn from_lab(&self, lab: &Lab, black: BlackGeneration) -> Cmyk {
+        match black {
+            BlackGeneration::Fixed(k) => self.solve(lab, k.clamp(0.0, 1.0)),
+            BlackGeneration::Separation(separation) => {
+                let first = self.solve(lab, 0.0);
+                let gray = first.c.min(first.m).min(first.y);
+                let chroma = first.c.max(first.m).max(first.y) - gray;
+                let k = separation.black(gray, chroma);
+                let mut cmyk = self.solve(lab, k);
+                if cmyk.total_ink() > separation.total_ink {
+                    // more black until the limit is met, within the largest black
+                    let (mut lo, mut hi) = (k, separation.max_black.max(k));
+                    if self.solve(lab, hi).total_ink() <= separation.total_ink {
+                        for _ in 0..20 {
+                            let mid = (lo + hi) / 2.0;
+                            match self.solve(lab, mid).total_ink() <= separation.total_ink {
+                                true => hi = mid,
+                                false => lo = mid,
+                            }
+                        }
+                        cmyk = self.solve(lab, hi);
+                    }
+                }
+                cmyk.limit_ink(separation.total_ink, separation.max_black)
+            }
+        }
+    }
+
+    /// Cyan, magenta and yellow minimizing ΔE*ab to `lab` at the black `k`, by a search
+    /// of a coarse grid refined with damped Gauss-Newton within the unit cube.
+    fn solve(&self, lab: &Lab, k: f64) -> Cmyk {
+        let target = [lab.l, lab.a, lab.b];
+        let residual = |cmy: [f64; 3]| {
+            let v = self.to_lab(&Cmyk::new(cmy[0], cmy[1], cmy[2], k));
+            [v.l - target[0], v.a - target[1], v.b - target[2]]
+        };
+        let norm = |r: [f64; 3]| r[0] * r[0] + r[1] * r[1] + r[2] * r[2];
+        let steps = [0.0, 0.25, 0.5, 0.75, 1.0];
+        let mut x = [0.0; 3];
+        let mut best = f64::INFINITY;
+        for &c in &steps {
+            for &m in &steps {
+                for &y in &steps {
+                    let e = norm(residual([c, m, y]));
+                    if e < best {
+                        best = e;
+                        x = [c, m, y];
+                    }
+                }
+            }
+        }
+        let mut r = residual(x);
+        let mut damping = 1e-3;
+        for _ in 0..100 {
+            let mut jacobian = [[0.0; 3]; 3];
+            for i in 0..3 {
+                let h = if x[i] > 0.5 { -1e-5 } else { 1e-5 };
+                let mut xh = x;
+                xh[i] += h;
+                let rh = residual(xh);
+                for j in 0..3 {
+                    jacobian[j][i] = (rh[j] - r[j]) / h;
+                }
+            }
+            // (JᵀJ + λI) δ = −Jᵀr
+            let mut a = [[0.0; 3]; 3];
+            let mut g = [0.0; 3];
+            for i in 0..3 {
+                for j in 0..3 {
+                    a[i][j] = (0..3).map(|n| jacobian[n][i] * jacobian[n][j]).sum();
+                }
+                a[i][i] += damping;
+                g[i] = -(0..3).map(|n| jacobian[n][i] * r[n]).sum::<f64>();
+            }
+            let delta = match solve3(&a, &g) {
+                Some(delta) => delta,
+                None => break,
+            };
+            let candidate = [
+                (x[0] + delta[0]).clamp(0.0, 1.0),
+                (x[1] + delta[1]).clamp(0.0, 1.0),
+                (x[2] + delta[2]).clamp(0.0, 1.0),
+            ];
+            let rc = residual(candidate);
+            if norm(rc) < norm(r) {
+                x = candidate;
+                r = rc;
+                damping = (damping / 10.0).max(1e-9);
+            } else {
+                damping *= 10.0;
+            }
+            if norm(r) < 1e-12 || damping > 1e6 {
+                break;
+            }
+        }
+        Cmyk::new(x[0], x[1], x[2], k)
+    }
+
+    fn paper(&self) -> [f64; 3] {
+        let xyz = lab_to_xyz(&self.to_lab(&Cmyk::new(0.0, 0.0, 0.0, 0.0)), &WHITE);
+        [xyz.x, xyz.y, xyz.z]
+    }
+}
+
+/// The D50 white of the data, with a luminance of 1.
+const WHITE: Xyz = Xyz { x: D50[0], y: D50[1], z: D50[2] };
+
+/// The data are relative to a perfect diffuser, and the profile colors to the paper.
+impl Profile for CmykModel {
+    fn channels(&self) -> usize {
+        4
+    }
+
+    fn device_to_xyz(&self, device: &[f64]) -> Xyz {
+        let xyz = lab_to_xyz(&self.to_lab(&Cmyk::new(device[0], device[1], device[2], device[3])), &WHITE);
+        let paper = self.paper();
+        from_pcs([xyz.x * D50[0] / paper[0], xyz.y / paper[1], xyz.z * D50[2] / paper[2]], false)
+    }
+
+    fn xyz_to_device(&self, xyz: &Xyz) -> Vec<f64> {
+        let relative = to_pcs(xyz, false);
+        let paper = self.paper();
+        let v = Xyz::new(relative[0] * paper[0] / D50[0], relative[1] * paper[1], relative[2] * paper[2] / D50[2]);
+        let lab = xyz_to_lab(&v, &WHITE);
+        let cmyk = self.from_lab(&lab, self.black);
+        vec![cmyk.c, cmyk.m, cmyk.y, cmyk.k]
+    }
+
+    fn media_white(&self) -> Xyz {
+        let paper = self.paper();
+        Xyz::new(paper[0] * 100.0, paper[1] * 100.0, paper[2] * 100.0)
+    }
+}
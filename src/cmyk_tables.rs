@@ -0,0 +1,1364 @@
+//! Characterization data for CMYK printing conditions.
+
+/// FOGRA39 (ISO 12647-2:2004, offset on coated paper): the CIELAB values, D50 and 2°,
+/// of the colorimetric table of the ECI profile ISO Coated v2, which is built from the
+/// FOGRA39 characterization, taken at a fine grid of cyan, magenta and yellow without
+/// black and a coarse one at black levels of 20% to 100%. The measured data set itself
+/// is published by FOGRA as FOGRA39L.
+#[allow(clippy::approx_constant)]
+pub(crate) static FOGRA39: [([f64; 4], [f64; 3]); 1354] = [
+    ([0.0, 0.0, 0.0, 0.0], [95.03, 0.02, -2.08]),
+    ([0.0, 0.0, 0.1, 0.0], [94.34, -0.93, 5.44]),
+    ([0.0, 0.0, 0.2, 0.0], [93.61, -1.66, 13.30]),
+    ([0.0, 0.0, 0.3, 0.0], [92.96, -2.61, 22.03]),
+    ([0.0, 0.0, 0.4, 0.0], [92.20, -3.50, 31.15]),
+    ([0.0, 0.0, 0.55, 0.0], [91.24, -4.27, 46.15]),
+    ([0.0, 0.0, 0.7, 0.0], [90.35, -4.69, 62.54]),
+    ([0.0, 0.0, 0.85, 0.0], [89.53, -4.96, 78.77]),
+    ([0.0, 0.0, 1.0, 0.0], [89.02, -4.99, 93.00]),
+    ([0.0, 0.1, 0.0, 0.0], [90.65, 5.92, -3.84]),
+    ([0.0, 0.1, 0.1, 0.0], [89.99, 4.78, 3.73]),
+    ([0.0, 0.1, 0.2, 0.0], [89.36, 3.89, 11.52]),
+    ([0.0, 0.1, 0.3, 0.0], [88.67, 3.35, 19.70]),
+    ([0.0, 0.1, 0.4, 0.0], [87.96, 2.66, 28.55]),
+    ([0.0, 0.1, 0.55, 0.0], [87.07, 1.67, 42.92]),
+    ([0.0, 0.1, 0.7, 0.0], [86.13, 1.41, 58.74]),
+    ([0.0, 0.1, 0.85, 0.0], [85.35, 1.20, 74.18]),
+    ([0.0, 0.1, 1.0, 0.0], [84.87, 1.06, 88.00]),
+    ([0.0, 0.2, 0.0, 0.0], [86.14, 12.06, -5.23]),
+    ([0.0, 0.2, 0.1, 0.0], [85.51, 10.93, 2.05]),
+    ([0.0, 0.2, 0.2, 0.0], [84.99, 10.02, 9.80]),
+    ([0.0, 0.2, 0.3, 0.0], [84.28, 9.48, 17.55]),
+    ([0.0, 0.2, 0.4, 0.0], [83.59, 8.81, 25.99]),
+    ([0.0, 0.2, 0.55, 0.0], [82.82, 7.99, 39.86]),
+    ([0.0, 0.2, 0.7, 0.0], [81.81, 7.62, 54.81]),
+    ([0.0, 0.2, 0.85, 0.0], [81.08, 7.35, 69.70]),
+    ([0.0, 0.2, 1.0, 0.0], [80.56, 7.39, 83.21]),
+    ([0.0, 0.3, 0.0, 0.0], [81.40, 18.69, -6.18]),
+    ([0.0, 0.3, 0.1, 0.0], [80.82, 17.60, 0.68]),
+    ([0.0, 0.3, 0.2, 0.0], [80.22, 16.82, 8.00]),
+    ([0.0, 0.3, 0.3, 0.0], [79.62, 16.18, 15.49]),
+    ([0.0, 0.3, 0.4, 0.0], [79.06, 15.60, 23.70]),
+    ([0.0, 0.3, 0.55, 0.0], [78.24, 14.77, 36.86]),
+    ([0.0, 0.3, 0.7, 0.0], [77.37, 14.55, 51.22]),
+    ([0.0, 0.3, 0.85, 0.0], [76.62, 14.45, 65.45]),
+    ([0.0, 0.3, 1.0, 0.0], [76.06, 14.41, 78.26]),
+    ([0.0, 0.4, 0.0, 0.0], [76.42, 25.77, -6.91]),
+    ([0.0, 0.4, 0.1, 0.0], [75.79, 25.00, -0.49]),
+    ([0.0, 0.4, 0.2, 0.0], [75.28, 24.35, 6.41]),
+    ([0.0, 0.4, 0.3, 0.0], [74.71, 23.60, 13.57]),
+    ([0.0, 0.4, 0.4, 0.0], [74.17, 22.94, 21.41]),
+    ([0.0, 0.4, 0.55, 0.0], [73.36, 22.30, 34.00]),
+    ([0.0, 0.4, 0.7, 0.0], [72.63, 21.90, 47.72]),
+    ([0.0, 0.4, 0.85, 0.0], [71.96, 22.00, 61.24]),
+    ([0.0, 0.4, 1.0, 0.0], [71.28, 22.12, 73.11]),
+    ([0.0, 0.55, 0.0, 0.0], [68.62, 37.76, -7.34]),
+    ([0.0, 0.55, 0.1, 0.0], [68.13, 37.02, -1.42]),
+    ([0.0, 0.55, 0.2, 0.0], [67.74, 36.26, 4.99]),
+    ([0.0, 0.55, 0.3, 0.0], [67.32, 35.80, 11.61]),
+    ([0.0, 0.55, 0.4, 0.0], [66.91, 35.17, 18.84]),
+    ([0.0, 0.55, 0.55, 0.0], [66.19, 34.48, 30.01]),
+    ([0.0, 0.55, 0.7, 0.0], [65.47, 34.19, 42.50]),
+    ([0.0, 0.55, 0.85, 0.0], [64.77, 34.22, 54.61]),
+    ([0.0, 0.55, 1.0, 0.0], [64.27, 34.09, 65.78]),
+    ([0.0, 0.7, 0.0, 0.0], [60.84, 50.64, -6.71]),
+    ([0.0, 0.7, 0.1, 0.0], [60.50, 50.06, -1.51]),
+    ([0.0, 0.7, 0.2, 0.0], [60.26, 49.38, 4.28]),
+    ([0.0, 0.7, 0.3, 0.0], [59.88, 48.85, 10.38]),
+    ([0.0, 0.7, 0.4, 0.0], [59.55, 48.17, 16.70]),
+    ([0.0, 0.7, 0.55, 0.0], [59.05, 47.48, 26.95]),
+    ([0.0, 0.7, 0.7, 0.0], [58.51, 47.14, 37.91]),
+    ([0.0, 0.7, 0.85, 0.0], [57.94, 47.00, 48.79]),
+    ([0.0, 0.7, 1.0, 0.0], [57.59, 46.85, 58.79]),
+    ([0.0, 0.85, 0.0, 0.0], [53.54, 63.76, -5.37]),
+    ([0.0, 0.85, 0.1, 0.0], [53.40, 62.99, -0.59]),
+    ([0.0, 0.85, 0.2, 0.0], [53.18, 62.21, 4.51]),
+    ([0.0, 0.85, 0.3, 0.0], [52.97, 61.61, 10.17]),
+    ([0.0, 0.85, 0.4, 0.0], [52.85, 60.99, 16.04]),
+    ([0.0, 0.85, 0.55, 0.0], [52.53, 60.15, 24.87]),
+    ([0.0, 0.85, 0.7, 0.0], [52.24, 59.63, 34.63]),
+    ([0.0, 0.85, 0.85, 0.0], [51.87, 59.17, 44.24]),
+    ([0.0, 0.85, 1.0, 0.0], [51.49, 58.75, 52.37]),
+    ([0.0, 1.0, 0.0, 0.0], [47.98, 74.02, -2.94]),
+    ([0.0, 1.0, 0.1, 0.0], [47.91, 73.29, 1.39]),
+    ([0.0, 1.0, 0.2, 0.0], [47.79, 72.50, 5.94]),
+    ([0.0, 1.0, 0.3, 0.0], [47.79, 71.88, 10.97]),
+    ([0.0, 1.0, 0.4, 0.0], [47.73, 71.20, 16.25]),
+    ([0.0, 1.0, 0.55, 0.0], [47.50, 70.13, 24.33]),
+    ([0.0, 1.0, 0.7, 0.0], [47.32, 69.30, 32.93]),
+    ([0.0, 1.0, 0.85, 0.0], [47.18, 68.56, 41.16]),
+    ([0.0, 1.0, 1.0, 0.0], [46.99, 68.03, 48.03]),
+    ([0.1, 0.0, 0.0, 0.0], [91.47, -2.97, -6.95]),
+    ([0.1, 0.0, 0.1, 0.0], [90.76, -4.11, 0.84]),
+    ([0.1, 0.0, 0.2, 0.0], [89.99, -5.13, 8.77]),
+    ([0.1, 0.0, 0.3, 0.0], [89.32, -6.09, 17.27]),
+    ([0.1, 0.0, 0.4, 0.0], [88.61, -6.82, 26.35]),
+    ([0.1, 0.0, 0.55, 0.0], [87.68, -7.87, 41.21]),
+    ([0.1, 0.0, 0.7, 0.0], [86.72, -8.56, 57.30]),
+    ([0.1, 0.0, 0.85, 0.0], [85.87, -8.65, 73.11]),
+    ([0.1, 0.0, 1.0, 0.0], [85.29, -9.00, 86.88]),
+    ([0.1, 0.1, 0.0, 0.0], [87.17, 2.62, -8.19]),
+    ([0.1, 0.1, 0.1, 0.0], [86.53, 1.51, -0.72]),
+    ([0.1, 0.1, 0.2, 0.0], [85.82, 0.51, 6.99]),
+    ([0.1, 0.1, 0.3, 0.0], [85.20, -0.29, 15.11]),
+    ([0.1, 0.1, 0.4, 0.0], [84.55, -1.02, 23.96]),
+    ([0.1, 0.1, 0.55, 0.0], [83.58, -1.99, 38.18]),
+    ([0.1, 0.1, 0.7, 0.0], [82.62, -2.56, 53.56]),
+    ([0.1, 0.1, 0.85, 0.0], [81.82, -2.81, 68.74]),
+    ([0.1, 0.1, 1.0, 0.0], [81.28, -3.03, 82.27]),
+    ([0.1, 0.2, 0.0, 0.0], [82.83, 8.53, -9.42]),
+    ([0.1, 0.2, 0.1, 0.0], [82.22, 7.53, -2.18]),
+    ([0.1, 0.2, 0.2, 0.0], [81.61, 6.42, 5.37]),
+    ([0.1, 0.2, 0.3, 0.0], [81.00, 5.69, 13.12]),
+    ([0.1, 0.2, 0.4, 0.0], [80.32, 5.06, 21.59]),
+    ([0.1, 0.2, 0.55, 0.0], [79.38, 4.11, 35.14]),
+    ([0.1, 0.2, 0.7, 0.0], [78.47, 3.69, 49.93]),
+    ([0.1, 0.2, 0.85, 0.0], [77.67, 3.43, 64.60]),
+    ([0.1, 0.2, 1.0, 0.0], [77.05, 3.19, 77.54]),
+    ([0.1, 0.3, 0.0, 0.0], [78.26, 15.13, -10.26]),
+    ([0.1, 0.3, 0.1, 0.0], [77.71, 14.05, -3.37]),
+    ([0.1, 0.3, 0.2, 0.0], [77.11, 13.04, 3.72]),
+    ([0.1, 0.3, 0.3, 0.0], [76.52, 12.27, 11.27]),
+    ([0.1, 0.3, 0.4, 0.0], [75.93, 11.57, 19.37]),
+    ([0.1, 0.3, 0.55, 0.0], [75.01, 10.80, 32.34]),
+    ([0.1, 0.3, 0.7, 0.0], [74.15, 10.33, 46.42]),
+    ([0.1, 0.3, 0.85, 0.0], [73.35, 10.15, 60.34]),
+    ([0.1, 0.3, 1.0, 0.0], [72.77, 9.90, 72.76]),
+    ([0.1, 0.4, 0.0, 0.0], [73.36, 22.10, -11.00]),
+    ([0.1, 0.4, 0.1, 0.0], [72.78, 21.29, -4.49]),
+    ([0.1, 0.4, 0.2, 0.0], [72.24, 20.46, 2.20]),
+    ([0.1, 0.4, 0.3, 0.0], [71.73, 19.68, 9.47]),
+    ([0.1, 0.4, 0.4, 0.0], [71.20, 18.84, 17.24]),
+    ([0.1, 0.4, 0.55, 0.0], [70.38, 18.11, 29.49]),
+    ([0.1, 0.4, 0.7, 0.0], [69.51, 17.73, 42.85]),
+    ([0.1, 0.4, 0.85, 0.0], [68.81, 17.57, 56.10]),
+    ([0.1, 0.4, 1.0, 0.0], [68.21, 17.39, 67.81]),
+    ([0.1, 0.55, 0.0, 0.0], [65.79, 33.99, -11.23]),
+    ([0.1, 0.55, 0.1, 0.0], [65.38, 33.14, -5.27]),
+    ([0.1, 0.55, 0.2, 0.0], [64.98, 32.30, 0.89]),
+    ([0.1, 0.55, 0.3, 0.0], [64.51, 31.59, 7.47]),
+    ([0.1, 0.55, 0.4, 0.0], [63.98, 30.99, 14.48]),
+    ([0.1, 0.55, 0.55, 0.0], [63.34, 30.34, 25.68]),
+    ([0.1, 0.55, 0.7, 0.0], [62.59, 29.86, 37.90]),
+    ([0.1, 0.55, 0.85, 0.0], [61.97, 29.71, 49.98]),
+    ([0.1, 0.55, 1.0, 0.0], [61.52, 29.49, 60.58]),
+    ([0.1, 0.7, 0.0, 0.0], [58.20, 46.58, -10.80]),
+    ([0.1, 0.7, 0.1, 0.0], [57.91, 45.79, -5.49]),
+    ([0.1, 0.7, 0.2, 0.0], [57.60, 45.04, 0.22]),
+    ([0.1, 0.7, 0.3, 0.0], [57.26, 44.38, 6.18]),
+    ([0.1, 0.7, 0.4, 0.0], [56.86, 43.81, 12.47]),
+    ([0.1, 0.7, 0.55, 0.0], [56.42, 43.04, 22.65]),
+    ([0.1, 0.7, 0.7, 0.0], [55.87, 42.59, 33.61]),
+    ([0.1, 0.7, 0.85, 0.0], [55.36, 42.27, 44.35]),
+    ([0.1, 0.7, 1.0, 0.0], [54.85, 42.03, 53.72]),
+    ([0.1, 0.85, 0.0, 0.0], [51.10, 59.39, -9.51]),
+    ([0.1, 0.85, 0.1, 0.0], [50.93, 58.56, -4.75]),
+    ([0.1, 0.85, 0.2, 0.0], [50.72, 57.79, 0.45]),
+    ([0.1, 0.85, 0.3, 0.0], [50.52, 57.01, 5.86]),
+    ([0.1, 0.85, 0.4, 0.0], [50.33, 56.30, 11.61]),
+    ([0.1, 0.85, 0.55, 0.0], [50.04, 55.54, 20.72]),
+    ([0.1, 0.85, 0.7, 0.0], [49.68, 54.80, 30.26]),
+    ([0.1, 0.85, 0.85, 0.0], [49.29, 54.30, 39.48]),
+    ([0.1, 0.85, 1.0, 0.0], [48.94, 53.83, 47.76]),
+    ([0.1, 1.0, 0.0, 0.0], [45.61, 69.65, -7.40]),
+    ([0.1, 1.0, 0.1, 0.0], [45.57, 68.87, -3.00]),
+    ([0.1, 1.0, 0.2, 0.0], [45.48, 68.09, 1.65]),
+    ([0.1, 1.0, 0.3, 0.0], [45.41, 67.21, 6.66]),
+    ([0.1, 1.0, 0.4, 0.0], [45.27, 66.41, 11.92]),
+    ([0.1, 1.0, 0.55, 0.0], [45.18, 65.42, 20.03]),
+    ([0.1, 1.0, 0.7, 0.0], [44.94, 64.57, 28.48]),
+    ([0.1, 1.0, 0.85, 0.0], [44.76, 63.81, 36.50]),
+    ([0.1, 1.0, 1.0, 0.0], [44.58, 63.21, 43.49]),
+    ([0.2, 0.0, 0.0, 0.0], [87.69, -5.79, -11.79]),
+    ([0.2, 0.0, 0.1, 0.0], [87.06, -7.21, -4.04]),
+    ([0.2, 0.0, 0.2, 0.0], [86.31, -8.42, 4.15]),
+    ([0.2, 0.0, 0.3, 0.0], [85.68, -9.61, 12.62]),
+    ([0.2, 0.0, 0.4, 0.0], [84.92, -10.65, 21.56]),
+    ([0.2, 0.0, 0.55, 0.0], [83.93, -11.57, 36.00]),
+    ([0.2, 0.0, 0.7, 0.0], [83.03, -12.43, 51.92]),
+    ([0.2, 0.0, 0.85, 0.0], [82.15, -12.68, 67.39]),
+    ([0.2, 0.0, 1.0, 0.0], [81.53, -12.91, 80.98]),
+    ([0.2, 0.1, 0.0, 0.0], [83.60, -0.57, -12.72]),
+    ([0.2, 0.1, 0.1, 0.0], [82.95, -1.74, -5.33]),
+    ([0.2, 0.1, 0.2, 0.0], [82.24, -2.99, 2.53]),
+    ([0.2, 0.1, 0.3, 0.0], [81.71, -3.97, 10.61]),
+    ([0.2, 0.1, 0.4, 0.0], [80.97, -4.85, 19.33]),
+    ([0.2, 0.1, 0.55, 0.0], [80.01, -6.04, 33.17]),
+    ([0.2, 0.1, 0.7, 0.0], [79.10, -6.69, 48.43]),
+    ([0.2, 0.1, 0.85, 0.0], [78.30, -7.07, 63.24]),
+    ([0.2, 0.1, 1.0, 0.0], [77.66, -7.35, 76.27]),
+    ([0.2, 0.2, 0.0, 0.0], [79.42, 5.09, -13.65]),
+    ([0.2, 0.2, 0.1, 0.0], [78.77, 4.02, -6.54]),
+    ([0.2, 0.2, 0.2, 0.0], [78.15, 2.89, 0.94]),
+    ([0.2, 0.2, 0.3, 0.0], [77.66, 1.96, 8.68]),
+    ([0.2, 0.2, 0.4, 0.0], [76.93, 0.98, 17.03]),
+    ([0.2, 0.2, 0.55, 0.0], [75.98, -0.07, 30.46]),
+    ([0.2, 0.2, 0.7, 0.0], [75.01, -0.62, 44.88]),
+    ([0.2, 0.2, 0.85, 0.0], [74.21, -0.97, 59.12]),
+    ([0.2, 0.2, 1.0, 0.0], [73.70, -1.18, 71.89]),
+    ([0.2, 0.3, 0.0, 0.0], [75.05, 11.49, -14.38]),
+    ([0.2, 0.3, 0.1, 0.0], [74.43, 10.40, -7.61]),
+    ([0.2, 0.3, 0.2, 0.0], [73.89, 9.32, -0.48]),
+    ([0.2, 0.3, 0.3, 0.0], [73.30, 8.44, 6.97]),
+    ([0.2, 0.3, 0.4, 0.0], [72.71, 7.53, 15.05]),
+    ([0.2, 0.3, 0.55, 0.0], [71.78, 6.66, 27.77]),
+    ([0.2, 0.3, 0.7, 0.0], [70.91, 6.02, 41.64]),
+    ([0.2, 0.3, 0.85, 0.0], [70.10, 5.73, 55.28]),
+    ([0.2, 0.3, 1.0, 0.0], [69.50, 5.34, 67.22]),
+    ([0.2, 0.4, 0.0, 0.0], [70.28, 18.36, -14.99]),
+    ([0.2, 0.4, 0.1, 0.0], [69.74, 17.49, -8.62]),
+    ([0.2, 0.4, 0.2, 0.0], [69.15, 16.49, -1.99]),
+    ([0.2, 0.4, 0.3, 0.0], [68.61, 15.57, 5.26]),
+    ([0.2, 0.4, 0.4, 0.0], [68.11, 14.66, 12.85]),
+    ([0.2, 0.4, 0.55, 0.0], [67.28, 13.78, 25.05]),
+    ([0.2, 0.4, 0.7, 0.0], [66.39, 13.29, 38.10]),
+    ([0.2, 0.4, 0.85, 0.0], [65.75, 12.97, 51.19]),
+    ([0.2, 0.4, 1.0, 0.0], [65.08, 12.65, 62.44]),
+    ([0.2, 0.55, 0.0, 0.0], [62.93, 29.90, -15.36]),
+    ([0.2, 0.55, 0.1, 0.0], [62.53, 29.01, -9.31]),
+    ([0.2, 0.55, 0.2, 0.0], [62.01, 28.15, -3.22]),
+    ([0.2, 0.55, 0.3, 0.0], [61.63, 27.33, 3.30]),
+    ([0.2, 0.55, 0.4, 0.0], [61.05, 26.61, 10.28]),
+    ([0.2, 0.55, 0.55, 0.0], [60.38, 25.79, 21.24]),
+    ([0.2, 0.55, 0.7, 0.0], [59.65, 25.21, 33.29]),
+    ([0.2, 0.55, 0.85, 0.0], [59.05, 24.87, 44.99]),
+    ([0.2, 0.55, 1.0, 0.0], [58.62, 24.63, 55.45]),
+    ([0.2, 0.7, 0.0, 0.0], [55.50, 42.37, -14.94]),
+    ([0.2, 0.7, 0.1, 0.0], [55.22, 41.51, -9.63]),
+    ([0.2, 0.7, 0.2, 0.0], [54.86, 40.72, -3.92]),
+    ([0.2, 0.7, 0.3, 0.0], [54.58, 39.95, 1.98]),
+    ([0.2, 0.7, 0.4, 0.0], [54.13, 39.24, 8.36]),
+    ([0.2, 0.7, 0.55, 0.0], [53.68, 38.43, 18.39]),
+    ([0.2, 0.7, 0.7, 0.0], [53.10, 37.75, 28.99]),
+    ([0.2, 0.7, 0.85, 0.0], [52.60, 37.41, 39.57]),
+    ([0.2, 0.7, 1.0, 0.0], [52.17, 37.10, 48.67]),
+    ([0.2, 0.85, 0.0, 0.0], [48.47, 55.06, -13.89]),
+    ([0.2, 0.85, 0.1, 0.0], [48.36, 54.14, -8.93]),
+    ([0.2, 0.85, 0.2, 0.0], [48.25, 53.28, -3.72]),
+    ([0.2, 0.85, 0.3, 0.0], [47.98, 52.45, 1.53]),
+    ([0.2, 0.85, 0.4, 0.0], [47.75, 51.64, 7.40]),
+    ([0.2, 0.85, 0.55, 0.0], [47.47, 50.59, 16.31]),
+    ([0.2, 0.85, 0.7, 0.0], [47.09, 49.90, 25.75]),
+    ([0.2, 0.85, 0.85, 0.0], [46.73, 49.35, 34.82]),
+    ([0.2, 0.85, 1.0, 0.0], [46.37, 48.90, 42.98]),
+    ([0.2, 1.0, 0.0, 0.0], [43.06, 65.22, -11.92]),
+    ([0.2, 1.0, 0.1, 0.0], [43.09, 64.38, -7.45]),
+    ([0.2, 1.0, 0.2, 0.0], [43.05, 63.48, -2.80]),
+    ([0.2, 1.0, 0.3, 0.0], [42.93, 62.50, 2.13]),
+    ([0.2, 1.0, 0.4, 0.0], [42.91, 61.60, 7.57]),
+    ([0.2, 1.0, 0.55, 0.0], [42.70, 60.56, 15.54]),
+    ([0.2, 1.0, 0.7, 0.0], [42.43, 59.47, 23.80]),
+    ([0.2, 1.0, 0.85, 0.0], [42.30, 58.66, 31.86]),
+    ([0.2, 1.0, 1.0, 0.0], [42.07, 58.10, 38.66]),
+    ([0.3, 0.0, 0.0, 0.0], [83.87, -9.16, -16.56]),
+    ([0.3, 0.0, 0.1, 0.0], [83.19, -10.65, -8.98]),
+    ([0.3, 0.0, 0.2, 0.0], [82.51, -12.15, -0.82]),
+    ([0.3, 0.0, 0.3, 0.0], [81.82, -13.43, 7.60]),
+    ([0.3, 0.0, 0.4, 0.0], [81.03, -14.53, 16.50]),
+    ([0.3, 0.0, 0.55, 0.0], [80.04, -15.83, 30.67]),
+    ([0.3, 0.0, 0.7, 0.0], [79.02, -16.81, 46.01]),
+    ([0.3, 0.0, 0.85, 0.0], [78.29, -17.34, 61.25]),
+    ([0.3, 0.0, 1.0, 0.0], [77.71, -17.66, 74.51]),
+    ([0.3, 0.1, 0.0, 0.0], [79.88, -3.87, -17.49]),
+    ([0.3, 0.1, 0.1, 0.0], [79.30, -5.32, -10.11]),
+    ([0.3, 0.1, 0.2, 0.0], [78.62, -6.77, -2.34]),
+    ([0.3, 0.1, 0.3, 0.0], [77.93, -7.93, 5.75]),
+    ([0.3, 0.1, 0.4, 0.0], [77.24, -9.03, 14.29]),
+    ([0.3, 0.1, 0.55, 0.0], [76.23, -10.21, 28.00]),
+    ([0.3, 0.1, 0.7, 0.0], [75.31, -11.09, 42.82]),
+    ([0.3, 0.1, 0.85, 0.0], [74.51, -11.69, 57.37]),
+    ([0.3, 0.1, 1.0, 0.0], [73.93, -12.09, 70.02]),
+    ([0.3, 0.2, 0.0, 0.0], [75.82, 1.72, -18.23]),
+    ([0.3, 0.2, 0.1, 0.0], [75.26, 0.38, -11.14]),
+    ([0.3, 0.2, 0.2, 0.0], [74.63, -0.93, -3.71]),
+    ([0.3, 0.2, 0.3, 0.0], [74.00, -2.16, 4.01]),
+    ([0.3, 0.2, 0.4, 0.0], [73.26, -3.08, 12.21]),
+    ([0.3, 0.2, 0.55, 0.0], [72.39, -4.30, 25.39]),
+    ([0.3, 0.2, 0.7, 0.0], [71.48, -5.15, 39.68]),
+    ([0.3, 0.2, 0.85, 0.0], [70.71, -5.75, 53.59]),
+    ([0.3, 0.2, 1.0, 0.0], [70.09, -5.95, 65.72]),
+    ([0.3, 0.3, 0.0, 0.0], [71.58, 7.81, -18.75]),
+    ([0.3, 0.3, 0.1, 0.0], [71.06, 6.53, -12.02]),
+    ([0.3, 0.3, 0.2, 0.0], [70.49, 5.36, -5.04]),
+    ([0.3, 0.3, 0.3, 0.0], [69.88, 4.17, 2.42]),
+    ([0.3, 0.3, 0.4, 0.0], [69.21, 3.22, 10.34]),
+    ([0.3, 0.3, 0.55, 0.0], [68.37, 2.06, 22.90]),
+    ([0.3, 0.3, 0.7, 0.0], [67.49, 1.21, 36.44]),
+    ([0.3, 0.3, 0.85, 0.0], [66.71, 0.76, 49.72]),
+    ([0.3, 0.3, 1.0, 0.0], [66.08, 0.45, 61.26]),
+    ([0.3, 0.4, 0.0, 0.0], [67.13, 14.53, -19.23]),
+    ([0.3, 0.4, 0.1, 0.0], [66.54, 13.35, -12.85]),
+    ([0.3, 0.4, 0.2, 0.0], [66.02, 12.18, -6.33]),
+    ([0.3, 0.4, 0.3, 0.0], [65.47, 11.19, 0.88]),
+    ([0.3, 0.4, 0.4, 0.0], [64.83, 10.27, 8.41]),
+    ([0.3, 0.4, 0.55, 0.0], [64.03, 9.26, 20.28]),
+    ([0.3, 0.4, 0.7, 0.0], [63.20, 8.36, 33.19]),
+    ([0.3, 0.4, 0.85, 0.0], [62.49, 8.01, 45.72]),
+    ([0.3, 0.4, 1.0, 0.0], [61.82, 7.67, 56.77]),
+    ([0.3, 0.55, 0.0, 0.0], [59.96, 25.70, -19.39]),
+    ([0.3, 0.55, 0.1, 0.0], [59.54, 24.66, -13.50]),
+    ([0.3, 0.55, 0.2, 0.0], [59.03, 23.66, -7.55]),
+    ([0.3, 0.55, 0.3, 0.0], [58.60, 22.69, -1.08]),
+    ([0.3, 0.55, 0.4, 0.0], [58.08, 21.87, 5.79]),
+    ([0.3, 0.55, 0.55, 0.0], [57.42, 20.81, 16.71]),
+    ([0.3, 0.55, 0.7, 0.0], [56.73, 20.16, 28.47]),
+    ([0.3, 0.55, 0.85, 0.0], [56.11, 19.67, 40.03]),
+    ([0.3, 0.55, 1.0, 0.0], [55.60, 19.29, 49.97]),
+    ([0.3, 0.7, 0.0, 0.0], [52.73, 37.99, -19.02]),
+    ([0.3, 0.7, 0.1, 0.0], [52.42, 36.98, -13.75]),
+    ([0.3, 0.7, 0.2, 0.0], [52.10, 36.00, -8.15]),
+    ([0.3, 0.7, 0.3, 0.0], [51.75, 35.13, -2.33]),
+    ([0.3, 0.7, 0.4, 0.0], [51.38, 34.28, 3.94]),
+    ([0.3, 0.7, 0.55, 0.0], [50.89, 33.17, 13.78]),
+    ([0.3, 0.7, 0.7, 0.0], [50.32, 32.44, 24.27]),
+    ([0.3, 0.7, 0.85, 0.0], [49.79, 31.99, 34.52]),
+    ([0.3, 0.7, 1.0, 0.0], [49.34, 31.45, 43.45]),
+    ([0.3, 0.85, 0.0, 0.0], [45.97, 50.42, -17.96]),
+    ([0.3, 0.85, 0.1, 0.0], [45.78, 49.38, -13.24]),
+    ([0.3, 0.85, 0.2, 0.0], [45.59, 48.42, -8.09]),
+    ([0.3, 0.85, 0.3, 0.0], [45.38, 47.53, -2.76]),
+    ([0.3, 0.85, 0.4, 0.0], [45.15, 46.64, 2.99]),
+    ([0.3, 0.85, 0.55, 0.0], [44.83, 45.39, 11.59]),
+    ([0.3, 0.85, 0.7, 0.0], [44.39, 44.52, 20.79]),
+    ([0.3, 0.85, 0.85, 0.0], [44.04, 43.81, 29.76]),
+    ([0.3, 0.85, 1.0, 0.0], [43.74, 43.24, 37.67]),
+    ([0.3, 1.0, 0.0, 0.0], [40.60, 60.55, -16.27]),
+    ([0.3, 1.0, 0.1, 0.0], [40.58, 59.56, -11.91]),
+    ([0.3, 1.0, 0.2, 0.0], [40.52, 58.57, -7.32]),
+    ([0.3, 1.0, 0.3, 0.0], [40.42, 57.52, -2.36]),
+    ([0.3, 1.0, 0.4, 0.0], [40.36, 56.52, 3.01]),
+    ([0.3, 1.0, 0.55, 0.0], [40.14, 55.36, 10.83]),
+    ([0.3, 1.0, 0.7, 0.0], [39.94, 54.16, 19.06]),
+    ([0.3, 1.0, 0.85, 0.0], [39.69, 53.28, 26.95]),
+    ([0.3, 1.0, 1.0, 0.0], [39.43, 52.65, 33.55]),
+    ([0.4, 0.0, 0.0, 0.0], [79.72, -12.55, -21.72]),
+    ([0.4, 0.0, 0.1, 0.0], [79.12, -14.41, -14.09]),
+    ([0.4, 0.0, 0.2, 0.0], [78.38, -16.01, -6.06]),
+    ([0.4, 0.0, 0.3, 0.0], [77.73, -17.61, 2.18]),
+    ([0.4, 0.0, 0.4, 0.0], [76.96, -19.07, 11.03]),
+    ([0.4, 0.0, 0.55, 0.0], [75.92, -20.35, 24.91]),
+    ([0.4, 0.0, 0.7, 0.0], [74.94, -21.51, 40.08]),
+    ([0.4, 0.0, 0.85, 0.0], [74.17, -22.23, 54.68]),
+    ([0.4, 0.0, 1.0, 0.0], [73.66, -22.85, 67.57]),
+    ([0.4, 0.1, 0.0, 0.0], [76.03, -7.57, -22.35]),
+    ([0.4, 0.1, 0.1, 0.0], [75.42, -9.18, -15.03]),
+    ([0.4, 0.1, 0.2, 0.0], [74.76, -10.83, -7.33]),
+    ([0.4, 0.1, 0.3, 0.0], [74.04, -12.17, 0.63]),
+    ([0.4, 0.1, 0.4, 0.0], [73.33, -13.52, 9.05]),
+    ([0.4, 0.1, 0.55, 0.0], [72.31, -14.85, 22.52]),
+    ([0.4, 0.1, 0.7, 0.0], [71.35, -16.02, 36.92]),
+    ([0.4, 0.1, 0.85, 0.0], [70.59, -16.71, 51.08]),
+    ([0.4, 0.1, 1.0, 0.0], [70.02, -17.34, 63.54]),
+    ([0.4, 0.2, 0.0, 0.0], [72.13, -2.01, -22.75]),
+    ([0.4, 0.2, 0.1, 0.0], [71.58, -3.70, -15.84]),
+    ([0.4, 0.2, 0.2, 0.0], [70.87, -5.17, -8.73]),
+    ([0.4, 0.2, 0.3, 0.0], [70.26, -6.56, -1.01]),
+    ([0.4, 0.2, 0.4, 0.0], [69.57, -7.62, 7.31]),
+    ([0.4, 0.2, 0.55, 0.0], [68.60, -9.15, 20.01]),
+    ([0.4, 0.2, 0.7, 0.0], [67.72, -10.19, 33.93]),
+    ([0.4, 0.2, 0.85, 0.0], [66.92, -10.94, 47.44]),
+    ([0.4, 0.2, 1.0, 0.0], [66.35, -11.48, 59.41]),
+    ([0.4, 0.3, 0.0, 0.0], [67.99, 3.88, -23.28]),
+    ([0.4, 0.3, 0.1, 0.0], [67.48, 2.39, -16.73]),
+    ([0.4, 0.3, 0.2, 0.0], [66.90, 1.03, -9.84]),
+    ([0.4, 0.3, 0.3, 0.0], [66.29, -0.35, -2.43]),
+    ([0.4, 0.3, 0.4, 0.0], [65.71, -1.55, 5.43]),
+    ([0.4, 0.3, 0.55, 0.0], [64.80, -2.89, 17.75]),
+    ([0.4, 0.3, 0.7, 0.0], [63.87, -3.87, 31.00]),
+    ([0.4, 0.3, 0.85, 0.0], [63.12, -4.48, 43.81]),
+    ([0.4, 0.3, 1.0, 0.0], [62.51, -4.92, 55.09]),
+    ([0.4, 0.4, 0.0, 0.0], [63.68, 10.34, -23.76]),
+    ([0.4, 0.4, 0.1, 0.0], [63.22, 8.97, -17.29]),
+    ([0.4, 0.4, 0.2, 0.0], [62.70, 7.70, -10.83]),
+    ([0.4, 0.4, 0.3, 0.0], [62.14, 6.53, -3.79]),
+    ([0.4, 0.4, 0.4, 0.0], [61.55, 5.40, 3.76]),
+    ([0.4, 0.4, 0.55, 0.0], [60.59, 4.13, 15.19]),
+    ([0.4, 0.4, 0.7, 0.0], [59.86, 3.08, 27.99]),
+    ([0.4, 0.4, 0.85, 0.0], [59.11, 2.40, 40.02]),
+    ([0.4, 0.4, 1.0, 0.0], [58.55, 2.12, 50.91]),
+    ([0.4, 0.55, 0.0, 0.0], [56.79, 21.31, -23.70]),
+    ([0.4, 0.55, 0.1, 0.0], [56.35, 20.06, -17.91]),
+    ([0.4, 0.55, 0.2, 0.0], [55.94, 18.82, -11.92]),
+    ([0.4, 0.55, 0.3, 0.0], [55.45, 17.83, -5.66]),
+    ([0.4, 0.55, 0.4, 0.0], [54.99, 16.74, 1.23]),
+    ([0.4, 0.55, 0.55, 0.0], [54.23, 15.58, 11.78]),
+    ([0.4, 0.55, 0.7, 0.0], [53.59, 14.57, 23.33]),
+    ([0.4, 0.55, 0.85, 0.0], [52.93, 14.05, 34.62]),
+    ([0.4, 0.55, 1.0, 0.0], [52.39, 13.46, 44.31]),
+    ([0.4, 0.7, 0.0, 0.0], [49.78, 33.42, -23.34]),
+    ([0.4, 0.7, 0.1, 0.0], [49.52, 32.16, -18.08]),
+    ([0.4, 0.7, 0.2, 0.0], [49.22, 31.11, -12.51]),
+    ([0.4, 0.7, 0.3, 0.0], [48.85, 30.00, -6.86]),
+    ([0.4, 0.7, 0.4, 0.0], [48.44, 29.07, -0.79]),
+    ([0.4, 0.7, 0.55, 0.0], [47.95, 27.73, 8.99]),
+    ([0.4, 0.7, 0.7, 0.0], [47.38, 26.88, 19.30]),
+    ([0.4, 0.7, 0.85, 0.0], [46.83, 26.13, 29.13]),
+    ([0.4, 0.7, 1.0, 0.0], [46.33, 25.54, 37.94]),
+    ([0.4, 0.85, 0.0, 0.0], [43.30, 45.48, -22.37]),
+    ([0.4, 0.85, 0.1, 0.0], [43.06, 44.33, -17.64]),
+    ([0.4, 0.85, 0.2, 0.0], [42.93, 43.20, -12.58]),
+    ([0.4, 0.85, 0.3, 0.0], [42.66, 42.12, -7.45]),
+    ([0.4, 0.85, 0.4, 0.0], [42.37, 41.19, -1.75]),
+    ([0.4, 0.85, 0.55, 0.0], [42.09, 39.86, 6.93]),
+    ([0.4, 0.85, 0.7, 0.0], [41.60, 38.83, 15.82]),
+    ([0.4, 0.85, 0.85, 0.0], [41.29, 37.94, 24.61]),
+    ([0.4, 0.85, 1.0, 0.0], [40.90, 37.17, 32.20]),
+    ([0.4, 1.0, 0.0, 0.0], [38.01, 55.37, -20.97]),
+    ([0.4, 1.0, 0.1, 0.0], [37.97, 54.34, -16.60]),
+    ([0.4, 1.0, 0.2, 0.0], [37.87, 53.29, -12.05]),
+    ([0.4, 1.0, 0.3, 0.0], [37.73, 52.08, -7.10]),
+    ([0.4, 1.0, 0.4, 0.0], [37.61, 51.12, -1.85]),
+    ([0.4, 1.0, 0.55, 0.0], [37.41, 49.80, 5.89]),
+    ([0.4, 1.0, 0.7, 0.0], [37.25, 48.44, 14.02]),
+    ([0.4, 1.0, 0.85, 0.0], [37.01, 47.38, 21.75]),
+    ([0.4, 1.0, 1.0, 0.0], [36.84, 46.46, 28.25]),
+    ([0.55, 0.0, 0.0, 0.0], [73.47, -18.49, -29.27]),
+    ([0.55, 0.0, 0.1, 0.0], [72.76, -20.60, -21.88]),
+    ([0.55, 0.0, 0.2, 0.0], [72.03, -22.69, -13.99]),
+    ([0.55, 0.0, 0.3, 0.0], [71.37, -24.63, -5.88]),
+    ([0.55, 0.0, 0.4, 0.0], [70.60, -26.27, 2.56]),
+    ([0.55, 0.0, 0.55, 0.0], [69.65, -28.44, 16.23]),
+    ([0.55, 0.0, 0.7, 0.0], [68.64, -29.90, 30.65]),
+    ([0.55, 0.0, 0.85, 0.0], [67.87, -30.96, 44.85]),
+    ([0.55, 0.0, 1.0, 0.0], [67.35, -31.85, 56.92]),
+    ([0.55, 0.1, 0.0, 0.0], [69.98, -13.56, -29.80]),
+    ([0.55, 0.1, 0.1, 0.0], [69.31, -15.62, -22.61]),
+    ([0.55, 0.1, 0.2, 0.0], [68.65, -17.65, -15.15]),
+    ([0.55, 0.1, 0.3, 0.0], [67.95, -19.32, -7.33]),
+    ([0.55, 0.1, 0.4, 0.0], [67.24, -20.94, 0.95]),
+    ([0.55, 0.1, 0.55, 0.0], [66.27, -23.01, 13.96]),
+    ([0.55, 0.1, 0.7, 0.0], [65.38, -24.50, 27.94]),
+    ([0.55, 0.1, 0.85, 0.0], [64.59, -25.52, 41.62]),
+    ([0.55, 0.1, 1.0, 0.0], [64.11, -26.23, 53.39]),
+    ([0.55, 0.2, 0.0, 0.0], [66.32, -8.20, -30.18]),
+    ([0.55, 0.2, 0.1, 0.0], [65.79, -10.27, -23.28]),
+    ([0.55, 0.2, 0.2, 0.0], [65.06, -12.14, -16.12]),
+    ([0.55, 0.2, 0.3, 0.0], [64.47, -13.75, -8.58]),
+    ([0.55, 0.2, 0.4, 0.0], [63.81, -15.30, -0.49]),
+    ([0.55, 0.2, 0.55, 0.0], [62.82, -17.40, 11.87]),
+    ([0.55, 0.2, 0.7, 0.0], [62.01, -18.98, 25.21]),
+    ([0.55, 0.2, 0.85, 0.0], [61.28, -19.77, 38.38]),
+    ([0.55, 0.2, 1.0, 0.0], [60.73, -20.55, 49.48]),
+    ([0.55, 0.3, 0.0, 0.0], [62.50, -2.47, -30.29]),
+    ([0.55, 0.3, 0.1, 0.0], [62.03, -4.46, -23.82]),
+    ([0.55, 0.3, 0.2, 0.0], [61.39, -6.27, -17.05]),
+    ([0.55, 0.3, 0.3, 0.0], [60.81, -7.87, -9.79]),
+    ([0.55, 0.3, 0.4, 0.0], [60.19, -9.22, -2.16]),
+    ([0.55, 0.3, 0.55, 0.0], [59.24, -11.20, 9.62]),
+    ([0.55, 0.3, 0.7, 0.0], [58.39, -12.65, 22.43]),
+    ([0.55, 0.3, 0.85, 0.0], [57.74, -13.50, 34.89]),
+    ([0.55, 0.3, 1.0, 0.0], [57.23, -14.16, 45.60]),
+    ([0.55, 0.4, 0.0, 0.0], [58.33, 3.86, -30.48]),
+    ([0.55, 0.4, 0.1, 0.0], [57.89, 1.98, -24.26]),
+    ([0.55, 0.4, 0.2, 0.0], [57.31, 0.37, -17.84]),
+    ([0.55, 0.4, 0.3, 0.0], [56.81, -1.20, -10.95]),
+    ([0.55, 0.4, 0.4, 0.0], [56.24, -2.52, -3.69]),
+    ([0.55, 0.4, 0.55, 0.0], [55.41, -4.47, 7.43]),
+    ([0.55, 0.4, 0.7, 0.0], [54.57, -5.79, 19.68]),
+    ([0.55, 0.4, 0.85, 0.0], [53.94, -6.77, 31.28]),
+    ([0.55, 0.4, 1.0, 0.0], [53.36, -7.22, 41.45]),
+    ([0.55, 0.55, 0.0, 0.0], [51.99, 14.44, -30.32]),
+    ([0.55, 0.55, 0.1, 0.0], [51.56, 12.70, -24.69]),
+    ([0.55, 0.55, 0.2, 0.0], [51.06, 11.25, -18.80]),
+    ([0.55, 0.55, 0.3, 0.0], [50.62, 9.88, -12.52]),
+    ([0.55, 0.55, 0.4, 0.0], [50.18, 8.62, -5.76]),
+    ([0.55, 0.55, 0.55, 0.0], [49.43, 6.82, 4.45]),
+    ([0.55, 0.55, 0.7, 0.0], [48.76, 5.44, 15.44]),
+    ([0.55, 0.55, 0.85, 0.0], [48.16, 4.52, 26.08]),
+    ([0.55, 0.55, 1.0, 0.0], [47.65, 3.91, 35.30]),
+    ([0.55, 0.7, 0.0, 0.0], [45.45, 25.85, -29.91]),
+    ([0.55, 0.7, 0.1, 0.0], [45.12, 24.45, -24.77]),
+    ([0.55, 0.7, 0.2, 0.0], [44.74, 23.06, -19.43]),
+    ([0.55, 0.7, 0.3, 0.0], [44.47, 21.71, -13.76]),
+    ([0.55, 0.7, 0.4, 0.0], [44.07, 20.51, -7.62]),
+    ([0.55, 0.7, 0.55, 0.0], [43.46, 18.74, 1.73]),
+    ([0.55, 0.7, 0.7, 0.0], [42.93, 17.37, 11.64]),
+    ([0.55, 0.7, 0.85, 0.0], [42.50, 16.40, 21.15]),
+    ([0.55, 0.7, 1.0, 0.0], [42.08, 15.51, 29.42]),
+    ([0.55, 0.85, 0.0, 0.0], [39.19, 37.54, -29.13]),
+    ([0.55, 0.85, 0.1, 0.0], [38.99, 36.23, -24.52]),
+    ([0.55, 0.85, 0.2, 0.0], [38.74, 34.80, -19.58]),
+    ([0.55, 0.85, 0.3, 0.0], [38.54, 33.48, -14.48]),
+    ([0.55, 0.85, 0.4, 0.0], [38.33, 32.24, -8.91]),
+    ([0.55, 0.85, 0.55, 0.0], [37.85, 30.58, -0.46]),
+    ([0.55, 0.85, 0.7, 0.0], [37.48, 29.09, 8.30]),
+    ([0.55, 0.85, 0.85, 0.0], [37.16, 27.92, 16.88]),
+    ([0.55, 0.85, 1.0, 0.0], [36.84, 27.06, 24.07]),
+    ([0.55, 1.0, 0.0, 0.0], [34.11, 47.40, -28.06]),
+    ([0.55, 1.0, 0.1, 0.0], [34.09, 46.00, -23.71]),
+    ([0.55, 1.0, 0.2, 0.0], [34.00, 44.61, -19.20]),
+    ([0.55, 1.0, 0.3, 0.0], [33.88, 43.12, -14.26]),
+    ([0.55, 1.0, 0.4, 0.0], [33.63, 41.90, -9.31]),
+    ([0.55, 1.0, 0.55, 0.0], [33.51, 40.23, -1.54]),
+    ([0.55, 1.0, 0.7, 0.0], [33.37, 38.56, 6.42]),
+    ([0.55, 1.0, 0.85, 0.0], [33.10, 37.30, 13.96]),
+    ([0.55, 1.0, 1.0, 0.0], [32.86, 36.33, 20.25]),
+    ([0.7, 0.0, 0.0, 0.0], [66.86, -24.72, -37.08]),
+    ([0.7, 0.0, 0.1, 0.0], [66.18, -27.55, -29.83]),
+    ([0.7, 0.0, 0.2, 0.0], [65.47, -30.19, -22.14]),
+    ([0.7, 0.0, 0.3, 0.0], [64.75, -32.57, -14.23]),
+    ([0.7, 0.0, 0.4, 0.0], [64.07, -34.88, -6.02]),
+    ([0.7, 0.0, 0.55, 0.0], [63.09, -37.50, 7.14]),
+    ([0.7, 0.0, 0.7, 0.0], [62.15, -39.83, 21.02]),
+    ([0.7, 0.0, 0.85, 0.0], [61.50, -41.45, 34.45]),
+    ([0.7, 0.0, 1.0, 0.0], [61.01, -42.29, 46.05]),
+    ([0.7, 0.1, 0.0, 0.0], [63.71, -19.98, -37.26]),
+    ([0.7, 0.1, 0.1, 0.0], [63.07, -22.69, -30.32]),
+    ([0.7, 0.1, 0.2, 0.0], [62.35, -25.11, -22.97]),
+    ([0.7, 0.1, 0.3, 0.0], [61.68, -27.42, -15.36]),
+    ([0.7, 0.1, 0.4, 0.0], [61.01, -29.53, -7.23]),
+    ([0.7, 0.1, 0.55, 0.0], [60.13, -32.27, 5.33]),
+    ([0.7, 0.1, 0.7, 0.0], [59.22, -34.37, 18.70]),
+    ([0.7, 0.1, 0.85, 0.0], [58.51, -35.89, 31.59]),
+    ([0.7, 0.1, 1.0, 0.0], [58.00, -36.73, 42.81]),
+    ([0.7, 0.2, 0.0, 0.0], [60.39, -14.97, -37.34]),
+    ([0.7, 0.2, 0.1, 0.0], [59.83, -17.52, -30.74]),
+    ([0.7, 0.2, 0.2, 0.0], [59.11, -19.83, -23.81]),
+    ([0.7, 0.2, 0.3, 0.0], [58.55, -22.10, -16.32]),
+    ([0.7, 0.2, 0.4, 0.0], [57.86, -24.14, -8.60]),
+    ([0.7, 0.2, 0.55, 0.0], [56.93, -26.75, 3.48]),
+    ([0.7, 0.2, 0.7, 0.0], [56.13, -28.89, 16.28]),
+    ([0.7, 0.2, 0.85, 0.0], [55.53, -30.26, 28.75]),
+    ([0.7, 0.2, 1.0, 0.0], [54.99, -31.16, 39.35]),
+    ([0.7, 0.3, 0.0, 0.0], [56.79, -9.39, -37.53]),
+    ([0.7, 0.3, 0.1, 0.0], [56.31, -11.89, -31.08]),
+    ([0.7, 0.3, 0.2, 0.0], [55.73, -14.14, -24.39]),
+    ([0.7, 0.3, 0.3, 0.0], [55.16, -16.32, -17.36]),
+    ([0.7, 0.3, 0.4, 0.0], [54.56, -18.25, -9.89]),
+    ([0.7, 0.3, 0.55, 0.0], [53.67, -20.63, 1.54]),
+    ([0.7, 0.3, 0.7, 0.0], [52.88, -22.78, 13.80]),
+    ([0.7, 0.3, 0.85, 0.0], [52.26, -24.14, 25.72]),
+    ([0.7, 0.3, 1.0, 0.0], [51.78, -25.01, 35.85]),
+    ([0.7, 0.4, 0.0, 0.0], [52.95, -3.28, -37.47]),
+    ([0.7, 0.4, 0.1, 0.0], [52.47, -5.45, -31.46]),
+    ([0.7, 0.4, 0.2, 0.0], [51.90, -7.63, -25.15]),
+    ([0.7, 0.4, 0.3, 0.0], [51.40, -9.71, -18.37]),
+    ([0.7, 0.4, 0.4, 0.0], [50.96, -11.57, -11.28]),
+    ([0.7, 0.4, 0.55, 0.0], [50.08, -13.96, -0.46]),
+    ([0.7, 0.4, 0.7, 0.0], [49.31, -15.94, 11.22]),
+    ([0.7, 0.4, 0.85, 0.0], [48.75, -17.20, 22.47]),
+    ([0.7, 0.4, 1.0, 0.0], [48.30, -18.21, 32.23]),
+    ([0.7, 0.55, 0.0, 0.0], [47.06, 6.84, -37.27]),
+    ([0.7, 0.55, 0.1, 0.0], [46.61, 4.74, -31.63]),
+    ([0.7, 0.55, 0.2, 0.0], [46.17, 2.72, -25.79]),
+    ([0.7, 0.55, 0.3, 0.0], [45.68, 0.98, -19.75]),
+    ([0.7, 0.55, 0.4, 0.0], [45.22, -0.74, -13.17]),
+    ([0.7, 0.55, 0.55, 0.0], [44.68, -3.10, -3.12]),
+    ([0.7, 0.55, 0.7, 0.0], [43.99, -5.09, 7.57]),
+    ([0.7, 0.55, 0.85, 0.0], [43.43, -6.37, 17.69]),
+    ([0.7, 0.55, 1.0, 0.0], [43.04, -7.34, 26.50]),
+    ([0.7, 0.7, 0.0, 0.0], [40.90, 17.89, -36.61]),
+    ([0.7, 0.7, 0.1, 0.0], [40.63, 15.95, -31.59]),
+    ([0.7, 0.7, 0.2, 0.0], [40.30, 14.24, -26.37]),
+    ([0.7, 0.7, 0.3, 0.0], [39.90, 12.45, -20.78]),
+    ([0.7, 0.7, 0.4, 0.0], [39.53, 10.78, -14.88]),
+    ([0.7, 0.7, 0.55, 0.0], [39.05, 8.49, -5.65]),
+    ([0.7, 0.7, 0.7, 0.0], [38.53, 6.59, 3.91]),
+    ([0.7, 0.7, 0.85, 0.0], [38.17, 5.15, 13.10]),
+    ([0.7, 0.7, 1.0, 0.0], [37.83, 4.12, 21.00]),
+    ([0.7, 0.85, 0.0, 0.0], [35.01, 29.19, -35.83]),
+    ([0.7, 0.85, 0.1, 0.0], [34.88, 27.29, -31.29]),
+    ([0.7, 0.85, 0.2, 0.0], [34.62, 25.58, -26.50]),
+    ([0.7, 0.85, 0.3, 0.0], [34.42, 23.81, -21.43]),
+    ([0.7, 0.85, 0.4, 0.0], [34.12, 22.23, -16.02]),
+    ([0.7, 0.85, 0.55, 0.0], [33.74, 20.06, -7.84]),
+    ([0.7, 0.85, 0.7, 0.0], [33.41, 18.15, 0.77]),
+    ([0.7, 0.85, 0.85, 0.0], [33.17, 16.68, 9.04]),
+    ([0.7, 0.85, 1.0, 0.0], [32.90, 15.40, 16.02]),
+    ([0.7, 1.0, 0.0, 0.0], [30.36, 38.71, -34.92]),
+    ([0.7, 1.0, 0.1, 0.0], [30.27, 36.87, -30.71]),
+    ([0.7, 1.0, 0.2, 0.0], [30.06, 35.15, -26.33]),
+    ([0.7, 1.0, 0.3, 0.0], [29.98, 33.32, -21.48]),
+    ([0.7, 1.0, 0.4, 0.0], [29.75, 31.78, -16.72]),
+    ([0.7, 1.0, 0.55, 0.0], [29.57, 29.65, -9.00]),
+    ([0.7, 1.0, 0.7, 0.0], [29.40, 27.67, -1.23]),
+    ([0.7, 1.0, 0.85, 0.0], [29.17, 26.00, 5.93]),
+    ([0.7, 1.0, 1.0, 0.0], [29.02, 24.58, 12.27]),
+    ([0.85, 0.0, 0.0, 0.0], [60.59, -31.32, -44.27]),
+    ([0.85, 0.0, 0.1, 0.0], [59.90, -34.68, -37.11]),
+    ([0.85, 0.0, 0.2, 0.0], [59.26, -37.96, -29.72]),
+    ([0.85, 0.0, 0.3, 0.0], [58.51, -40.83, -21.99]),
+    ([0.85, 0.0, 0.4, 0.0], [57.84, -43.61, -13.80]),
+    ([0.85, 0.0, 0.55, 0.0], [56.91, -47.29, -1.01]),
+    ([0.85, 0.0, 0.7, 0.0], [56.07, -50.25, 12.34]),
+    ([0.85, 0.0, 0.85, 0.0], [55.47, -52.29, 24.97]),
+    ([0.85, 0.0, 1.0, 0.0], [55.08, -53.42, 35.92]),
+    ([0.85, 0.1, 0.0, 0.0], [57.69, -26.56, -44.18]),
+    ([0.85, 0.1, 0.1, 0.0], [57.07, -29.87, -37.30]),
+    ([0.85, 0.1, 0.2, 0.0], [56.37, -32.90, -30.30]),
+    ([0.85, 0.1, 0.3, 0.0], [55.74, -35.86, -22.78]),
+    ([0.85, 0.1, 0.4, 0.0], [55.08, -38.56, -14.85]),
+    ([0.85, 0.1, 0.55, 0.0], [54.24, -42.12, -2.67]),
+    ([0.85, 0.1, 0.7, 0.0], [53.41, -44.94, 10.19]),
+    ([0.85, 0.1, 0.85, 0.0], [52.77, -47.01, 22.38]),
+    ([0.85, 0.1, 1.0, 0.0], [52.39, -48.24, 32.97]),
+    ([0.85, 0.2, 0.0, 0.0], [54.72, -21.77, -44.10]),
+    ([0.85, 0.2, 0.1, 0.0], [54.07, -24.74, -37.57]),
+    ([0.85, 0.2, 0.2, 0.0], [53.43, -27.64, -30.82]),
+    ([0.85, 0.2, 0.3, 0.0], [52.87, -30.54, -23.51]),
+    ([0.85, 0.2, 0.4, 0.0], [52.30, -33.34, -15.78]),
+    ([0.85, 0.2, 0.55, 0.0], [51.40, -36.59, -4.26]),
+    ([0.85, 0.2, 0.7, 0.0], [50.64, -39.54, 8.07]),
+    ([0.85, 0.2, 0.85, 0.0], [50.01, -41.45, 19.80]),
+    ([0.85, 0.2, 1.0, 0.0], [49.56, -42.58, 29.88]),
+    ([0.85, 0.3, 0.0, 0.0], [51.38, -16.32, -44.02]),
+    ([0.85, 0.3, 0.1, 0.0], [50.85, -19.31, -37.71]),
+    ([0.85, 0.3, 0.2, 0.0], [50.24, -22.07, -31.28]),
+    ([0.85, 0.3, 0.3, 0.0], [49.73, -24.86, -24.34]),
+    ([0.85, 0.3, 0.4, 0.0], [49.18, -27.40, -16.97]),
+    ([0.85, 0.3, 0.55, 0.0], [48.39, -30.67, -5.90]),
+    ([0.85, 0.3, 0.7, 0.0], [47.64, -33.38, 5.90]),
+    ([0.85, 0.3, 0.85, 0.0], [47.04, -35.35, 17.11]),
+    ([0.85, 0.3, 1.0, 0.0], [46.71, -36.62, 26.80]),
+    ([0.85, 0.4, 0.0, 0.0], [47.95, -10.42, -43.76]),
+    ([0.85, 0.4, 0.1, 0.0], [47.37, -13.07, -37.88]),
+    ([0.85, 0.4, 0.2, 0.0], [46.81, -15.76, -31.68]),
+    ([0.85, 0.4, 0.3, 0.0], [46.30, -18.46, -25.12]),
+    ([0.85, 0.4, 0.4, 0.0], [45.83, -21.04, -18.12]),
+    ([0.85, 0.4, 0.55, 0.0], [45.10, -23.99, -7.59]),
+    ([0.85, 0.4, 0.7, 0.0], [44.39, -26.74, 3.57]),
+    ([0.85, 0.4, 0.85, 0.0], [43.86, -28.63, 14.27]),
+    ([0.85, 0.4, 1.0, 0.0], [43.56, -29.96, 23.48]),
+    ([0.85, 0.55, 0.0, 0.0], [42.34, -0.57, -43.35]),
+    ([0.85, 0.55, 0.1, 0.0], [41.97, -3.25, -37.91]),
+    ([0.85, 0.55, 0.2, 0.0], [41.55, -5.87, -32.11]),
+    ([0.85, 0.55, 0.3, 0.0], [41.10, -8.19, -26.19]),
+    ([0.85, 0.55, 0.4, 0.0], [40.62, -10.39, -19.87]),
+    ([0.85, 0.55, 0.55, 0.0], [40.04, -13.39, -10.10]),
+    ([0.85, 0.55, 0.7, 0.0], [39.46, -16.01, 0.24]),
+    ([0.85, 0.55, 0.85, 0.0], [39.05, -17.99, 10.14]),
+    ([0.85, 0.55, 1.0, 0.0], [38.67, -19.10, 18.38]),
+    ([0.85, 0.7, 0.0, 0.0], [36.73, 9.90, -42.54]),
+    ([0.85, 0.7, 0.1, 0.0], [36.42, 7.56, -37.78]),
+    ([0.85, 0.7, 0.2, 0.0], [36.12, 5.15, -32.59]),
+    ([0.85, 0.7, 0.3, 0.0], [35.72, 2.98, -27.11]),
+    ([0.85, 0.7, 0.4, 0.0], [35.36, 0.85, -21.29]),
+    ([0.85, 0.7, 0.55, 0.0], [34.97, -2.09, -12.29]),
+    ([0.85, 0.7, 0.7, 0.0], [34.42, -4.53, -3.04]),
+    ([0.85, 0.7, 0.85, 0.0], [34.11, -6.49, 5.82]),
+    ([0.85, 0.7, 1.0, 0.0], [33.87, -7.87, 13.42]),
+    ([0.85, 0.85, 0.0, 0.0], [31.21, 20.84, -41.83]),
+    ([0.85, 0.85, 0.1, 0.0], [31.07, 18.49, -37.46]),
+    ([0.85, 0.85, 0.2, 0.0], [30.84, 16.27, -32.74]),
+    ([0.85, 0.85, 0.3, 0.0], [30.62, 14.01, -27.61]),
+    ([0.85, 0.85, 0.4, 0.0], [30.35, 12.11, -22.33]),
+    ([0.85, 0.85, 0.55, 0.0], [29.97, 9.18, -14.26]),
+    ([0.85, 0.85, 0.7, 0.0], [29.67, 6.76, -5.96]),
+    ([0.85, 0.85, 0.85, 0.0], [29.47, 4.91, 2.01]),
+    ([0.85, 0.85, 1.0, 0.0], [29.18, 3.37, 8.81]),
+    ([0.85, 1.0, 0.0, 0.0], [26.83, 30.11, -41.10]),
+    ([0.85, 1.0, 0.1, 0.0], [26.69, 27.89, -36.94]),
+    ([0.85, 1.0, 0.2, 0.0], [26.61, 25.51, -32.42]),
+    ([0.85, 1.0, 0.3, 0.0], [26.44, 23.35, -27.90]),
+    ([0.85, 1.0, 0.4, 0.0], [26.30, 21.37, -23.00]),
+    ([0.85, 1.0, 0.55, 0.0], [25.98, 18.62, -15.59]),
+    ([0.85, 1.0, 0.7, 0.0], [25.88, 16.18, -8.01]),
+    ([0.85, 1.0, 0.85, 0.0], [25.84, 14.14, -0.84]),
+    ([0.85, 1.0, 1.0, 0.0], [25.65, 12.49, 5.39]),
+    ([1.0, 0.0, 0.0, 0.0], [54.99, -37.00, -49.99]),
+    ([1.0, 0.0, 0.1, 0.0], [54.28, -41.05, -42.94]),
+    ([1.0, 0.0, 0.2, 0.0], [53.55, -44.88, -35.71]),
+    ([1.0, 0.0, 0.3, 0.0], [52.95, -48.72, -28.14]),
+    ([1.0, 0.0, 0.4, 0.0], [52.33, -52.32, -20.18]),
+    ([1.0, 0.0, 0.55, 0.0], [51.51, -56.88, -7.96]),
+    ([1.0, 0.0, 0.7, 0.0], [50.69, -60.61, 4.71]),
+    ([1.0, 0.0, 0.85, 0.0], [50.19, -63.34, 16.87]),
+    ([1.0, 0.0, 1.0, 0.0], [50.01, -65.00, 27.06]),
+    ([1.0, 0.1, 0.0, 0.0], [52.33, -32.47, -49.74]),
+    ([1.0, 0.1, 0.1, 0.0], [51.68, -36.35, -43.06]),
+    ([1.0, 0.1, 0.2, 0.0], [51.03, -40.14, -35.97]),
+    ([1.0, 0.1, 0.3, 0.0], [50.36, -43.69, -28.69]),
+    ([1.0, 0.1, 0.4, 0.0], [49.75, -47.08, -21.01]),
+    ([1.0, 0.1, 0.55, 0.0], [48.97, -51.54, -9.29]),
+    ([1.0, 0.1, 0.7, 0.0], [48.34, -55.42, 3.07]),
+    ([1.0, 0.1, 0.85, 0.0], [47.83, -58.28, 14.72]),
+    ([1.0, 0.1, 1.0, 0.0], [47.51, -59.94, 24.62]),
+    ([1.0, 0.2, 0.0, 0.0], [49.48, -27.82, -49.41]),
+    ([1.0, 0.2, 0.1, 0.0], [48.92, -31.53, -43.00]),
+    ([1.0, 0.2, 0.2, 0.0], [48.34, -35.18, -36.29]),
+    ([1.0, 0.2, 0.3, 0.0], [47.80, -38.67, -29.22]),
+    ([1.0, 0.2, 0.4, 0.0], [47.20, -42.08, -21.86]),
+    ([1.0, 0.2, 0.55, 0.0], [46.43, -46.37, -10.56]),
+    ([1.0, 0.2, 0.7, 0.0], [45.77, -49.86, 1.29]),
+    ([1.0, 0.2, 0.85, 0.0], [45.36, -52.69, 12.58]),
+    ([1.0, 0.2, 1.0, 0.0], [44.99, -54.43, 22.08]),
+    ([1.0, 0.3, 0.0, 0.0], [46.45, -22.45, -49.16]),
+    ([1.0, 0.3, 0.1, 0.0], [45.98, -26.14, -43.04]),
+    ([1.0, 0.3, 0.2, 0.0], [45.40, -29.72, -36.59]),
+    ([1.0, 0.3, 0.3, 0.0], [44.92, -33.05, -29.89]),
+    ([1.0, 0.3, 0.4, 0.0], [44.35, -36.29, -22.77]),
+    ([1.0, 0.3, 0.55, 0.0], [43.65, -40.31, -11.92]),
+    ([1.0, 0.3, 0.7, 0.0], [43.03, -43.86, -0.58]),
+    ([1.0, 0.3, 0.85, 0.0], [42.63, -46.63, 10.17]),
+    ([1.0, 0.3, 1.0, 0.0], [42.35, -48.27, 19.36]),
+    ([1.0, 0.4, 0.0, 0.0], [43.30, -16.99, -48.64]),
+    ([1.0, 0.4, 0.1, 0.0], [42.80, -20.30, -42.88]),
+    ([1.0, 0.4, 0.2, 0.0], [42.19, -23.54, -36.81]),
+    ([1.0, 0.4, 0.3, 0.0], [41.80, -26.79, -30.36]),
+    ([1.0, 0.4, 0.4, 0.0], [41.25, -29.84, -23.68]),
+    ([1.0, 0.4, 0.55, 0.0], [40.76, -33.97, -13.33]),
+    ([1.0, 0.4, 0.7, 0.0], [40.14, -37.47, -2.58]),
+    ([1.0, 0.4, 0.85, 0.0], [39.70, -39.83, 7.66]),
+    ([1.0, 0.4, 1.0, 0.0], [39.45, -41.70, 16.36]),
+    ([1.0, 0.55, 0.0, 0.0], [38.26, -7.60, -47.89]),
+    ([1.0, 0.55, 0.1, 0.0], [37.84, -10.63, -42.58]),
+    ([1.0, 0.55, 0.2, 0.0], [37.44, -13.87, -37.00]),
+    ([1.0, 0.55, 0.3, 0.0], [37.07, -16.83, -31.12]),
+    ([1.0, 0.55, 0.4, 0.0], [36.62, -19.74, -24.97]),
+    ([1.0, 0.55, 0.55, 0.0], [36.10, -23.59, -15.55]),
+    ([1.0, 0.55, 0.7, 0.0], [35.65, -26.78, -5.52]),
+    ([1.0, 0.55, 0.85, 0.0], [35.25, -29.18, 3.88]),
+    ([1.0, 0.55, 1.0, 0.0], [35.04, -31.10, 11.86]),
+    ([1.0, 0.7, 0.0, 0.0], [33.06, 2.84, -47.26]),
+    ([1.0, 0.7, 0.1, 0.0], [32.76, -0.23, -42.48]),
+    ([1.0, 0.7, 0.2, 0.0], [32.43, -3.23, -37.31]),
+    ([1.0, 0.7, 0.3, 0.0], [32.10, -5.89, -31.96]),
+    ([1.0, 0.7, 0.4, 0.0], [31.80, -8.74, -26.17]),
+    ([1.0, 0.7, 0.55, 0.0], [31.29, -12.37, -17.62]),
+    ([1.0, 0.7, 0.7, 0.0], [31.03, -15.57, -8.50]),
+    ([1.0, 0.7, 0.85, 0.0], [30.71, -18.03, 0.02]),
+    ([1.0, 0.7, 1.0, 0.0], [30.57, -19.71, 7.41]),
+    ([1.0, 0.85, 0.0, 0.0], [28.01, 13.06, -46.47]),
+    ([1.0, 0.85, 0.1, 0.0], [27.78, 10.26, -42.15]),
+    ([1.0, 0.85, 0.2, 0.0], [27.59, 7.59, -37.57]),
+    ([1.0, 0.85, 0.3, 0.0], [27.28, 4.86, -32.51]),
+    ([1.0, 0.85, 0.4, 0.0], [27.02, 2.33, -27.26]),
+    ([1.0, 0.85, 0.55, 0.0], [26.74, -1.43, -19.45]),
+    ([1.0, 0.85, 0.7, 0.0], [26.52, -4.56, -11.24]),
+    ([1.0, 0.85, 0.85, 0.0], [26.29, -6.81, -3.46]),
+    ([1.0, 0.85, 1.0, 0.0], [26.07, -8.57, 3.14]),
+    ([1.0, 1.0, 0.0, 0.0], [23.87, 22.12, -46.06]),
+    ([1.0, 1.0, 0.1, 0.0], [23.68, 19.45, -41.91]),
+    ([1.0, 1.0, 0.2, 0.0], [23.55, 16.74, -37.54]),
+    ([1.0, 1.0, 0.3, 0.0], [23.36, 14.05, -32.77]),
+    ([1.0, 1.0, 0.4, 0.0], [23.19, 11.35, -27.97]),
+    ([1.0, 1.0, 0.55, 0.0], [23.04, 7.94, -20.70]),
+    ([1.0, 1.0, 0.7, 0.0], [22.92, 4.74, -13.23]),
+    ([1.0, 1.0, 0.85, 0.0], [22.88, 1.96, -6.08]),
+    ([1.0, 1.0, 1.0, 0.0], [22.86, 0.15, -0.06]),
+    ([0.0, 0.0, 0.0, 0.2], [82.77, -0.14, -2.00]),
+    ([0.0, 0.0, 0.2, 0.2], [81.42, -1.66, 10.80]),
+    ([0.0, 0.0, 0.4, 0.2], [80.03, -3.39, 26.00]),
+    ([0.0, 0.0, 0.7, 0.2], [78.21, -4.74, 53.15]),
+    ([0.0, 0.0, 1.0, 0.2], [76.88, -5.17, 80.25]),
+    ([0.0, 0.2, 0.0, 0.2], [75.27, 9.77, -4.70]),
+    ([0.0, 0.2, 0.2, 0.2], [74.18, 8.00, 7.93]),
+    ([0.0, 0.2, 0.4, 0.2], [72.85, 6.86, 21.85]),
+    ([0.0, 0.2, 0.7, 0.2], [71.13, 5.55, 46.86]),
+    ([0.0, 0.2, 1.0, 0.2], [69.87, 5.10, 72.10]),
+    ([0.0, 0.4, 0.0, 0.2], [66.72, 21.67, -6.24]),
+    ([0.0, 0.4, 0.2, 0.2], [65.67, 20.48, 5.04]),
+    ([0.0, 0.4, 0.4, 0.2], [64.65, 19.23, 18.05]),
+    ([0.0, 0.4, 0.7, 0.2], [63.17, 18.11, 40.89]),
+    ([0.0, 0.4, 1.0, 0.2], [61.83, 18.03, 63.35]),
+    ([0.0, 0.7, 0.0, 0.2], [52.83, 43.56, -6.28]),
+    ([0.0, 0.7, 0.2, 0.2], [52.35, 42.42, 3.16]),
+    ([0.0, 0.7, 0.4, 0.2], [51.75, 41.28, 14.07]),
+    ([0.0, 0.7, 0.7, 0.2], [50.78, 40.17, 32.43]),
+    ([0.0, 0.7, 1.0, 0.2], [49.81, 39.67, 50.60]),
+    ([0.0, 1.0, 0.0, 0.2], [41.31, 64.43, -3.26]),
+    ([0.0, 1.0, 0.2, 0.2], [41.14, 62.92, 4.38]),
+    ([0.0, 1.0, 0.4, 0.2], [41.08, 61.52, 13.45]),
+    ([0.0, 1.0, 0.7, 0.2], [40.70, 59.55, 27.79]),
+    ([0.0, 1.0, 1.0, 0.2], [40.40, 58.21, 40.60]),
+    ([0.2, 0.0, 0.0, 0.2], [76.54, -5.08, -10.08]),
+    ([0.2, 0.0, 0.2, 0.2], [75.27, -7.31, 3.23]),
+    ([0.2, 0.0, 0.4, 0.2], [73.95, -9.32, 18.12]),
+    ([0.2, 0.0, 0.7, 0.2], [72.15, -11.12, 44.40]),
+    ([0.2, 0.0, 1.0, 0.2], [70.70, -11.77, 70.18]),
+    ([0.2, 0.2, 0.0, 0.2], [69.57, 3.93, -11.70]),
+    ([0.2, 0.2, 0.2, 0.2], [68.41, 2.07, 0.58]),
+    ([0.2, 0.2, 0.4, 0.2], [67.28, 0.37, 14.45]),
+    ([0.2, 0.2, 0.7, 0.2], [65.48, -1.25, 38.64]),
+    ([0.2, 0.2, 1.0, 0.2], [64.21, -1.97, 62.61]),
+    ([0.2, 0.4, 0.0, 0.2], [61.57, 15.51, -12.91]),
+    ([0.2, 0.4, 0.2, 0.2], [60.55, 13.96, -1.90]),
+    ([0.2, 0.4, 0.4, 0.2], [59.60, 12.34, 10.98]),
+    ([0.2, 0.4, 0.7, 0.2], [58.00, 11.00, 32.90]),
+    ([0.2, 0.4, 1.0, 0.2], [56.76, 10.25, 54.45]),
+    ([0.2, 0.7, 0.0, 0.2], [48.49, 36.62, -13.05]),
+    ([0.2, 0.7, 0.2, 0.2], [47.94, 35.13, -3.60]),
+    ([0.2, 0.7, 0.4, 0.2], [47.32, 33.72, 7.18]),
+    ([0.2, 0.7, 0.7, 0.2], [46.37, 32.24, 25.07]),
+    ([0.2, 0.7, 1.0, 0.2], [45.47, 31.53, 42.27]),
+    ([0.2, 1.0, 0.0, 0.2], [37.19, 57.09, -10.74]),
+    ([0.2, 1.0, 0.2, 0.2], [37.19, 55.41, -2.88]),
+    ([0.2, 1.0, 0.4, 0.2], [37.08, 53.52, 6.29]),
+    ([0.2, 1.0, 0.7, 0.2], [36.67, 51.40, 20.23]),
+    ([0.2, 1.0, 1.0, 0.2], [36.35, 50.00, 32.90]),
+    ([0.4, 0.0, 0.0, 0.2], [69.54, -11.09, -18.60]),
+    ([0.4, 0.0, 0.2, 0.2], [68.37, -13.95, -5.48]),
+    ([0.4, 0.0, 0.4, 0.2], [67.08, -16.60, 9.18]),
+    ([0.4, 0.0, 0.7, 0.2], [65.26, -18.93, 34.31]),
+    ([0.4, 0.0, 1.0, 0.2], [64.01, -20.35, 58.54]),
+    ([0.4, 0.2, 0.0, 0.2], [63.20, -2.30, -19.50]),
+    ([0.4, 0.2, 0.2, 0.2], [62.08, -4.93, -7.68]),
+    ([0.4, 0.2, 0.4, 0.2], [60.92, -7.09, 6.17]),
+    ([0.4, 0.2, 0.7, 0.2], [59.25, -9.48, 29.26]),
+    ([0.4, 0.2, 1.0, 0.2], [57.97, -10.80, 51.79]),
+    ([0.4, 0.4, 0.0, 0.2], [55.89, 8.55, -20.38]),
+    ([0.4, 0.4, 0.2, 0.2], [55.01, 6.34, -9.43]),
+    ([0.4, 0.4, 0.4, 0.2], [53.97, 4.29, 3.22]),
+    ([0.4, 0.4, 0.7, 0.2], [52.45, 2.19, 24.24]),
+    ([0.4, 0.4, 1.0, 0.2], [51.27, 1.28, 44.51]),
+    ([0.4, 0.7, 0.0, 0.2], [43.68, 28.77, -20.20]),
+    ([0.4, 0.7, 0.2, 0.2], [43.18, 26.70, -10.94]),
+    ([0.4, 0.7, 0.4, 0.2], [42.49, 24.76, -0.66]),
+    ([0.4, 0.7, 0.7, 0.2], [41.56, 22.72, 16.77]),
+    ([0.4, 0.7, 1.0, 0.2], [40.64, 21.51, 33.12]),
+    ([0.4, 1.0, 0.0, 0.2], [32.83, 48.47, -18.59]),
+    ([0.4, 1.0, 0.2, 0.2], [32.73, 46.54, -10.87]),
+    ([0.4, 1.0, 0.4, 0.2], [32.52, 44.45, -1.82]),
+    ([0.4, 1.0, 0.7, 0.2], [32.24, 41.90, 11.81]),
+    ([0.4, 1.0, 1.0, 0.2], [31.91, 39.95, 23.98]),
+    ([0.7, 0.0, 0.0, 0.2], [58.18, -22.10, -31.96]),
+    ([0.7, 0.0, 0.2, 0.2], [57.06, -26.54, -19.33]),
+    ([0.7, 0.0, 0.4, 0.2], [55.93, -30.48, -5.36]),
+    ([0.7, 0.0, 0.7, 0.2], [54.31, -34.78, 18.06]),
+    ([0.7, 0.0, 1.0, 0.2], [53.27, -37.08, 39.98]),
+    ([0.7, 0.2, 0.0, 0.2], [52.86, -13.74, -32.18]),
+    ([0.7, 0.2, 0.2, 0.2], [51.80, -17.72, -20.69]),
+    ([0.7, 0.2, 0.4, 0.2], [50.75, -21.40, -7.46]),
+    ([0.7, 0.2, 0.7, 0.2], [49.29, -25.50, 14.14]),
+    ([0.7, 0.2, 1.0, 0.2], [48.30, -27.57, 34.43]),
+    ([0.7, 0.4, 0.0, 0.2], [46.45, -3.19, -32.31]),
+    ([0.7, 0.4, 0.2, 0.2], [45.55, -6.79, -21.84]),
+    ([0.7, 0.4, 0.4, 0.2], [44.75, -10.23, -9.76]),
+    ([0.7, 0.4, 0.7, 0.2], [43.35, -13.96, 9.75]),
+    ([0.7, 0.4, 1.0, 0.2], [42.51, -15.93, 28.25]),
+    ([0.7, 0.7, 0.0, 0.2], [36.00, 15.39, -31.65]),
+    ([0.7, 0.7, 0.2, 0.2], [35.51, 12.20, -22.87]),
+    ([0.7, 0.7, 0.4, 0.2], [34.87, 9.05, -12.75]),
+    ([0.7, 0.7, 0.7, 0.2], [34.07, 5.37, 3.57]),
+    ([0.7, 0.7, 1.0, 0.2], [33.51, 3.21, 18.63]),
+    ([0.7, 1.0, 0.0, 0.2], [26.45, 33.78, -30.50]),
+    ([0.7, 1.0, 0.2, 0.2], [26.25, 30.66, -23.01]),
+    ([0.7, 1.0, 0.4, 0.2], [26.04, 27.62, -14.42]),
+    ([0.7, 1.0, 0.7, 0.2], [25.81, 23.93, -1.04]),
+    ([0.7, 1.0, 1.0, 0.2], [25.55, 21.06, 10.80]),
+    ([1.0, 0.0, 0.0, 0.2], [47.74, -33.62, -43.36]),
+    ([1.0, 0.0, 0.2, 0.2], [46.63, -40.08, -31.09]),
+    ([1.0, 0.0, 0.4, 0.2], [45.73, -46.31, -17.41]),
+    ([1.0, 0.0, 0.7, 0.2], [44.40, -53.12, 4.14]),
+    ([1.0, 0.0, 1.0, 0.2], [43.80, -56.82, 23.58]),
+    ([1.0, 0.2, 0.0, 0.2], [43.04, -25.45, -43.05]),
+    ([1.0, 0.2, 0.2, 0.2], [42.17, -31.53, -31.74]),
+    ([1.0, 0.2, 0.4, 0.2], [41.31, -37.38, -18.99]),
+    ([1.0, 0.2, 0.7, 0.2], [40.17, -43.84, 1.04]),
+    ([1.0, 0.2, 1.0, 0.2], [39.51, -47.83, 19.19]),
+    ([1.0, 0.4, 0.0, 0.2], [37.52, -15.28, -42.63]),
+    ([1.0, 0.4, 0.2, 0.2], [36.65, -20.73, -32.44]),
+    ([1.0, 0.4, 0.4, 0.2], [35.95, -26.15, -20.85]),
+    ([1.0, 0.4, 0.7, 0.2], [35.09, -32.61, -2.67]),
+    ([1.0, 0.4, 1.0, 0.2], [34.56, -36.42, 13.86]),
+    ([1.0, 0.7, 0.0, 0.2], [28.82, 2.38, -41.35]),
+    ([1.0, 0.7, 0.2, 0.2], [28.41, -2.88, -32.69]),
+    ([1.0, 0.7, 0.4, 0.2], [28.02, -7.81, -22.75]),
+    ([1.0, 0.7, 0.7, 0.2], [27.47, -13.75, -7.44]),
+    ([1.0, 0.7, 1.0, 0.2], [27.12, -17.54, 6.60]),
+    ([1.0, 1.0, 0.0, 0.2], [21.36, 18.80, -39.70]),
+    ([1.0, 1.0, 0.2, 0.2], [21.17, 14.23, -32.23]),
+    ([1.0, 1.0, 0.4, 0.2], [20.95, 9.53, -23.63]),
+    ([1.0, 1.0, 0.7, 0.2], [20.76, 3.78, -10.80]),
+    ([1.0, 1.0, 1.0, 0.2], [20.72, -0.47, 1.01]),
+    ([0.0, 0.0, 0.0, 0.4], [69.26, -0.16, -1.97]),
+    ([0.0, 0.0, 0.2, 0.4], [68.05, -1.55, 8.56]),
+    ([0.0, 0.0, 0.4, 0.4], [66.78, -3.18, 21.13]),
+    ([0.0, 0.0, 0.7, 0.4], [65.11, -4.59, 43.66]),
+    ([0.0, 0.0, 1.0, 0.4], [63.88, -5.14, 66.40]),
+    ([0.0, 0.2, 0.0, 0.4], [63.01, 8.00, -4.16]),
+    ([0.0, 0.2, 0.2, 0.4], [62.03, 6.42, 6.24]),
+    ([0.0, 0.2, 0.4, 0.4], [60.86, 5.30, 17.78]),
+    ([0.0, 0.2, 0.7, 0.4], [59.31, 3.93, 38.55]),
+    ([0.0, 0.2, 1.0, 0.4], [58.15, 3.29, 59.69]),
+    ([0.0, 0.4, 0.0, 0.4], [55.82, 17.92, -5.43]),
+    ([0.0, 0.4, 0.2, 0.4], [54.92, 16.84, 3.90]),
+    ([0.0, 0.4, 0.4, 0.4], [54.04, 15.65, 14.72]),
+    ([0.0, 0.4, 0.7, 0.4], [52.73, 14.43, 33.67]),
+    ([0.0, 0.4, 1.0, 0.4], [51.52, 14.06, 52.41]),
+    ([0.0, 0.7, 0.0, 0.4], [44.10, 36.26, -5.43]),
+    ([0.0, 0.7, 0.2, 0.4], [43.71, 35.23, 2.40]),
+    ([0.0, 0.7, 0.4, 0.4], [43.23, 34.14, 11.52]),
+    ([0.0, 0.7, 0.7, 0.4], [42.38, 32.91, 26.69]),
+    ([0.0, 0.7, 1.0, 0.4], [41.50, 32.11, 41.64]),
+    ([0.0, 1.0, 0.0, 0.4], [34.35, 53.94, -2.91]),
+    ([0.0, 1.0, 0.2, 0.4], [34.22, 52.61, 3.42]),
+    ([0.0, 1.0, 0.4, 0.4], [34.17, 51.31, 11.00]),
+    ([0.0, 1.0, 0.7, 0.4], [33.84, 49.30, 22.73]),
+    ([0.0, 1.0, 1.0, 0.4], [33.54, 47.72, 33.01]),
+    ([0.2, 0.0, 0.0, 0.4], [64.08, -4.38, -8.53]),
+    ([0.2, 0.0, 0.2, 0.4], [62.98, -6.29, 2.43]),
+    ([0.2, 0.0, 0.4, 0.4], [61.83, -8.09, 14.77]),
+    ([0.2, 0.0, 0.7, 0.4], [60.22, -9.84, 36.60]),
+    ([0.2, 0.0, 1.0, 0.4], [58.89, -10.60, 58.24]),
+    ([0.2, 0.2, 0.0, 0.4], [58.28, 3.10, -9.86]),
+    ([0.2, 0.2, 0.2, 0.4], [57.29, 1.49, 0.28]),
+    ([0.2, 0.2, 0.4, 0.4], [56.32, -0.05, 11.79]),
+    ([0.2, 0.2, 0.7, 0.4], [54.76, -1.64, 31.92]),
+    ([0.2, 0.2, 1.0, 0.4], [53.61, -2.50, 52.02]),
+    ([0.2, 0.4, 0.0, 0.4], [51.59, 12.84, -10.88]),
+    ([0.2, 0.4, 0.2, 0.4], [50.74, 11.48, -1.76]),
+    ([0.2, 0.4, 0.4, 0.4], [49.95, 9.99, 8.96]),
+    ([0.2, 0.4, 0.7, 0.4], [48.59, 8.63, 27.22]),
+    ([0.2, 0.4, 1.0, 0.4], [47.50, 7.75, 45.28]),
+    ([0.2, 0.7, 0.0, 0.4], [40.59, 30.61, -10.97]),
+    ([0.2, 0.7, 0.2, 0.4], [40.16, 29.25, -3.11]),
+    ([0.2, 0.7, 0.4, 0.4], [39.66, 27.92, 5.93]),
+    ([0.2, 0.7, 0.7, 0.4], [38.87, 26.39, 20.78]),
+    ([0.2, 0.7, 1.0, 0.4], [38.11, 25.49, 35.05]),
+    ([0.2, 1.0, 0.0, 0.4], [31.00, 47.97, -9.05]),
+    ([0.2, 1.0, 0.2, 0.4], [31.01, 46.45, -2.48]),
+    ([0.2, 1.0, 0.4, 0.4], [30.94, 44.71, 5.24]),
+    ([0.2, 1.0, 0.7, 0.4], [30.59, 42.59, 16.70]),
+    ([0.2, 1.0, 1.0, 0.4], [30.31, 41.02, 26.94]),
+    ([0.4, 0.0, 0.0, 0.4], [58.23, -9.59, -15.47]),
+    ([0.4, 0.0, 0.2, 0.4], [57.26, -11.94, -4.65]),
+    ([0.4, 0.0, 0.4, 0.4], [56.20, -14.18, 7.50]),
+    ([0.4, 0.0, 0.7, 0.4], [54.63, -16.29, 28.41]),
+    ([0.4, 0.0, 1.0, 0.4], [53.49, -17.72, 48.74]),
+    ([0.4, 0.2, 0.0, 0.4], [52.98, -2.20, -16.25]),
+    ([0.4, 0.2, 0.2, 0.4], [52.07, -4.39, -6.48]),
+    ([0.4, 0.2, 0.4, 0.4], [51.13, -6.25, 5.05]),
+    ([0.4, 0.2, 0.7, 0.4], [49.72, -8.43, 24.30]),
+    ([0.4, 0.2, 1.0, 0.4], [48.60, -9.76, 43.24]),
+    ([0.4, 0.4, 0.0, 0.4], [46.91, 7.03, -17.02]),
+    ([0.4, 0.4, 0.2, 0.4], [46.20, 5.16, -7.94]),
+    ([0.4, 0.4, 0.4, 0.4], [45.37, 3.33, 2.61]),
+    ([0.4, 0.4, 0.7, 0.4], [44.13, 1.38, 20.18]),
+    ([0.4, 0.4, 1.0, 0.4], [43.15, 0.43, 37.26]),
+    ([0.4, 0.7, 0.0, 0.4], [36.69, 24.14, -16.88]),
+    ([0.4, 0.7, 0.2, 0.4], [36.30, 22.29, -9.14]),
+    ([0.4, 0.7, 0.4, 0.4], [35.77, 20.48, -0.49]),
+    ([0.4, 0.7, 0.7, 0.4], [35.03, 18.52, 14.08]),
+    ([0.4, 0.7, 1.0, 0.4], [34.30, 17.29, 27.77]),
+    ([0.4, 1.0, 0.0, 0.4], [27.44, 40.87, -15.53]),
+    ([0.4, 1.0, 0.2, 0.4], [27.37, 39.11, -9.03]),
+    ([0.4, 1.0, 0.4, 0.4], [27.22, 37.16, -1.34]),
+    ([0.4, 1.0, 0.7, 0.4], [27.01, 34.70, 9.94]),
+    ([0.4, 1.0, 1.0, 0.4], [26.75, 32.77, 19.86]),
+    ([0.7, 0.0, 0.0, 0.4], [48.66, -19.07, -26.53]),
+    ([0.7, 0.0, 0.2, 0.4], [47.80, -22.62, -16.10]),
+    ([0.7, 0.0, 0.4, 0.4], [46.93, -25.84, -4.48]),
+    ([0.7, 0.0, 0.7, 0.4], [45.63, -29.44, 15.02]),
+    ([0.7, 0.0, 1.0, 0.4], [44.72, -31.51, 33.40]),
+    ([0.7, 0.2, 0.0, 0.4], [44.31, -11.93, -26.78]),
+    ([0.7, 0.2, 0.2, 0.4], [43.48, -15.13, -17.26]),
+    ([0.7, 0.2, 0.4, 0.4], [42.68, -18.17, -6.22]),
+    ([0.7, 0.2, 0.7, 0.4], [41.52, -21.62, 11.82]),
+    ([0.7, 0.2, 1.0, 0.4], [40.71, -23.50, 28.85]),
+    ([0.7, 0.4, 0.0, 0.4], [39.04, -2.85, -26.99]),
+    ([0.7, 0.4, 0.2, 0.4], [38.33, -5.77, -18.26]),
+    ([0.7, 0.4, 0.4, 0.4], [37.71, -8.63, -8.13]),
+    ([0.7, 0.4, 0.7, 0.4], [36.62, -11.79, 8.22]),
+    ([0.7, 0.4, 1.0, 0.4], [36.00, -13.62, 23.76]),
+    ([0.7, 0.7, 0.0, 0.4], [30.37, 12.98, -26.40]),
+    ([0.7, 0.7, 0.2, 0.4], [29.99, 10.28, -19.02]),
+    ([0.7, 0.7, 0.4, 0.4], [29.49, 7.53, -10.45]),
+    ([0.7, 0.7, 0.7, 0.4], [28.91, 4.30, 3.26]),
+    ([0.7, 0.7, 1.0, 0.4], [28.55, 2.28, 15.90]),
+    ([0.7, 1.0, 0.0, 0.4], [22.28, 28.60, -25.29]),
+    ([0.7, 1.0, 0.2, 0.4], [22.15, 25.84, -18.95]),
+    ([0.7, 1.0, 0.4, 0.4], [22.04, 23.06, -11.62]),
+    ([0.7, 1.0, 0.7, 0.4], [21.92, 19.73, -0.44]),
+    ([0.7, 1.0, 1.0, 0.4], [21.77, 17.08, 9.45]),
+    ([1.0, 0.0, 0.0, 0.4], [39.74, -29.02, -36.24]),
+    ([1.0, 0.0, 0.2, 0.4], [38.93, -34.29, -26.09]),
+    ([1.0, 0.0, 0.4, 0.4], [38.29, -39.44, -14.67]),
+    ([1.0, 0.0, 0.7, 0.4], [37.30, -44.87, 3.27]),
+    ([1.0, 0.0, 1.0, 0.4], [36.86, -47.81, 19.52]),
+    ([1.0, 0.2, 0.0, 0.4], [35.94, -22.15, -36.08]),
+    ([1.0, 0.2, 0.2, 0.4], [35.27, -27.03, -26.66]),
+    ([1.0, 0.2, 0.4, 0.4], [34.61, -31.76, -15.95]),
+    ([1.0, 0.2, 0.7, 0.4], [33.78, -36.90, 0.73]),
+    ([1.0, 0.2, 1.0, 0.4], [33.35, -40.24, 15.84]),
+    ([1.0, 0.4, 0.0, 0.4], [31.43, -13.47, -35.84]),
+    ([1.0, 0.4, 0.2, 0.4], [30.70, -17.73, -27.29]),
+    ([1.0, 0.4, 0.4, 0.4], [30.11, -21.97, -17.48]),
+    ([1.0, 0.4, 0.7, 0.4], [29.52, -27.18, -2.35]),
+    ([1.0, 0.4, 1.0, 0.4], [29.26, -30.56, 11.35]),
+    ([1.0, 0.7, 0.0, 0.4], [24.31, 1.71, -34.61]),
+    ([1.0, 0.7, 0.2, 0.4], [23.97, -2.46, -27.30]),
+    ([1.0, 0.7, 0.4, 0.4], [23.66, -6.39, -18.85]),
+    ([1.0, 0.7, 0.7, 0.4], [23.35, -11.41, -6.04]),
+    ([1.0, 0.7, 1.0, 0.4], [23.23, -14.92, 5.72]),
+    ([1.0, 1.0, 0.0, 0.4], [18.26, 15.70, -32.72]),
+    ([1.0, 1.0, 0.2, 0.4], [18.19, 11.89, -26.48]),
+    ([1.0, 1.0, 0.4, 0.4], [18.14, 7.88, -19.26]),
+    ([1.0, 1.0, 0.7, 0.4], [18.07, 2.87, -8.34]),
+    ([1.0, 1.0, 1.0, 0.4], [18.11, -1.02, 1.83]),
+    ([0.0, 0.0, 0.0, 0.6], [54.13, -0.13, -1.12]),
+    ([0.0, 0.0, 0.2, 0.6], [53.17, -1.31, 6.86]),
+    ([0.0, 0.0, 0.4, 0.6], [52.15, -2.77, 16.44]),
+    ([0.0, 0.0, 0.7, 0.6], [50.83, -4.11, 33.64]),
+    ([0.0, 0.0, 1.0, 0.6], [49.85, -4.72, 51.17]),
+    ([0.0, 0.2, 0.0, 0.6], [49.36, 6.20, -2.91]),
+    ([0.0, 0.2, 0.2, 0.6], [48.58, 4.90, 5.06]),
+    ([0.0, 0.2, 0.4, 0.6], [47.66, 3.89, 13.94]),
+    ([0.0, 0.2, 0.7, 0.6], [46.43, 2.57, 29.84]),
+    ([0.0, 0.2, 1.0, 0.6], [45.52, 1.79, 46.04]),
+    ([0.0, 0.4, 0.0, 0.6], [43.81, 14.04, -4.01]),
+    ([0.0, 0.4, 0.2, 0.6], [43.10, 13.15, 3.22]),
+    ([0.0, 0.4, 0.4, 0.6], [42.42, 12.13, 11.66]),
+    ([0.0, 0.4, 0.7, 0.6], [41.39, 10.92, 26.18]),
+    ([0.0, 0.4, 1.0, 0.6], [40.45, 10.26, 40.41]),
+    ([0.0, 0.7, 0.0, 0.6], [34.68, 28.55, -4.04]),
+    ([0.0, 0.7, 0.2, 0.6], [34.38, 27.65, 2.10]),
+    ([0.0, 0.7, 0.4, 0.6], [34.02, 26.66, 9.29]),
+    ([0.0, 0.7, 0.7, 0.6], [33.39, 25.38, 20.91]),
+    ([0.0, 0.7, 1.0, 0.6], [32.73, 24.36, 32.04]),
+    ([0.0, 1.0, 0.0, 0.6], [26.98, 42.58, -1.83]),
+    ([0.0, 1.0, 0.2, 0.6], [26.88, 41.28, 3.00]),
+    ([0.0, 1.0, 0.4, 0.6], [26.85, 39.94, 8.83]),
+    ([0.0, 1.0, 0.7, 0.6], [26.67, 37.92, 17.69]),
+    ([0.0, 1.0, 1.0, 0.6], [26.56, 36.34, 25.27]),
+    ([0.2, 0.0, 0.0, 0.6], [50.16, -3.55, -6.37]),
+    ([0.2, 0.0, 0.2, 0.6], [49.30, -5.11, 2.04]),
+    ([0.2, 0.0, 0.4, 0.6], [48.41, -6.66, 11.54]),
+    ([0.2, 0.0, 0.7, 0.6], [47.15, -8.23, 28.30]),
+    ([0.2, 0.0, 1.0, 0.6], [46.10, -9.05, 44.94]),
+    ([0.2, 0.2, 0.0, 0.6], [45.72, 2.30, -7.47]),
+    ([0.2, 0.2, 0.2, 0.6], [44.96, 0.99, 0.37]),
+    ([0.2, 0.2, 0.4, 0.6], [44.21, -0.34, 9.32]),
+    ([0.2, 0.2, 0.7, 0.6], [43.00, -1.80, 24.78]),
+    ([0.2, 0.2, 1.0, 0.6], [42.10, -2.75, 40.19]),
+    ([0.2, 0.4, 0.0, 0.6], [40.56, 10.05, -8.33]),
+    ([0.2, 0.4, 0.2, 0.6], [39.90, 8.95, -1.21]),
+    ([0.2, 0.4, 0.4, 0.6], [39.29, 7.68, 7.20]),
+    ([0.2, 0.4, 0.7, 0.6], [38.25, 6.38, 21.24]),
+    ([0.2, 0.4, 1.0, 0.6], [37.43, 5.36, 34.97]),
+    ([0.2, 0.7, 0.0, 0.6], [32.02, 24.14, -8.32]),
+    ([0.2, 0.7, 0.2, 0.6], [31.70, 22.96, -2.15]),
+    ([0.2, 0.7, 0.4, 0.6], [31.33, 21.71, 4.98]),
+    ([0.2, 0.7, 0.7, 0.6], [30.76, 20.23, 16.38]),
+    ([0.2, 0.7, 1.0, 0.6], [30.21, 19.22, 27.09]),
+    ([0.2, 1.0, 0.0, 0.6], [24.48, 37.90, -6.55]),
+    ([0.2, 1.0, 0.2, 0.6], [24.51, 36.44, -1.52]),
+    ([0.2, 1.0, 0.4, 0.6], [24.48, 34.71, 4.44]),
+    ([0.2, 1.0, 0.7, 0.6], [24.29, 32.65, 13.15]),
+    ([0.2, 1.0, 1.0, 0.6], [24.17, 31.17, 20.80]),
+    ([0.4, 0.0, 0.0, 0.6], [45.62, -7.84, -12.02]),
+    ([0.4, 0.0, 0.2, 0.6], [44.89, -9.70, -3.61]),
+    ([0.4, 0.0, 0.4, 0.6], [44.11, -11.56, 5.89]),
+    ([0.4, 0.0, 0.7, 0.6], [42.91, -13.38, 22.02]),
+    ([0.4, 0.0, 1.0, 0.6], [42.01, -14.73, 37.61]),
+    ([0.4, 0.2, 0.0, 0.6], [41.60, -1.99, -12.63]),
+    ([0.4, 0.2, 0.2, 0.6], [40.92, -3.74, -5.00]),
+    ([0.4, 0.2, 0.4, 0.6], [40.23, -5.31, 4.05]),
+    ([0.4, 0.2, 0.7, 0.6], [39.16, -7.19, 18.92]),
+    ([0.4, 0.2, 1.0, 0.6], [38.30, -8.49, 33.40]),
+    ([0.4, 0.4, 0.0, 0.6], [36.93, 5.43, -13.23]),
+    ([0.4, 0.4, 0.2, 0.6], [36.40, 3.92, -6.10]),
+    ([0.4, 0.4, 0.4, 0.6], [35.77, 2.36, 2.23]),
+    ([0.4, 0.4, 0.7, 0.6], [34.85, 0.64, 15.79]),
+    ([0.4, 0.4, 1.0, 0.6], [34.13, -0.36, 28.77]),
+    ([0.4, 0.7, 0.0, 0.6], [29.04, 19.01, -12.93]),
+    ([0.4, 0.7, 0.2, 0.6], [28.76, 17.39, -6.85]),
+    ([0.4, 0.7, 0.4, 0.6], [28.38, 15.72, -0.02]),
+    ([0.4, 0.7, 0.7, 0.6], [27.86, 13.94, 11.19]),
+    ([0.4, 0.7, 1.0, 0.6], [27.36, 12.80, 21.55]),
+    ([0.4, 1.0, 0.0, 0.6], [21.81, 32.17, -11.61]),
+    ([0.4, 1.0, 0.2, 0.6], [21.80, 30.53, -6.59]),
+    ([0.4, 1.0, 0.4, 0.6], [21.74, 28.60, -0.61]),
+    ([0.4, 1.0, 0.7, 0.6], [21.66, 26.37, 8.02]),
+    ([0.4, 1.0, 1.0, 0.6], [21.52, 24.74, 15.53]),
+    ([0.7, 0.0, 0.0, 0.6], [38.09, -15.54, -20.92]),
+    ([0.7, 0.0, 0.2, 0.6], [37.49, -18.26, -12.72]),
+    ([0.7, 0.0, 0.4, 0.6], [36.90, -20.77, -3.53]),
+    ([0.7, 0.0, 0.7, 0.6], [35.98, -23.63, 11.61]),
+    ([0.7, 0.0, 1.0, 0.6], [35.34, -25.43, 25.72]),
+    ([0.7, 0.2, 0.0, 0.6], [34.80, -9.75, -21.04]),
+    ([0.7, 0.2, 0.2, 0.6], [34.22, -12.22, -13.54]),
+    ([0.7, 0.2, 0.4, 0.6], [33.66, -14.64, -4.81]),
+    ([0.7, 0.2, 0.7, 0.6], [32.85, -17.40, 9.21]),
+    ([0.7, 0.2, 1.0, 0.6], [32.30, -19.00, 22.30]),
+    ([0.7, 0.4, 0.0, 0.6], [30.79, -2.30, -21.12]),
+    ([0.7, 0.4, 0.2, 0.6], [30.28, -4.59, -14.25]),
+    ([0.7, 0.4, 0.4, 0.6], [29.84, -6.95, -6.22]),
+    ([0.7, 0.4, 0.7, 0.6], [29.09, -9.48, 6.48]),
+    ([0.7, 0.4, 1.0, 0.6], [28.70, -10.98, 18.43]),
+    ([0.7, 0.7, 0.0, 0.6], [24.18, 10.34, -20.38]),
+    ([0.7, 0.7, 0.2, 0.6], [23.93, 8.07, -14.56]),
+    ([0.7, 0.7, 0.4, 0.6], [23.61, 5.68, -7.78]),
+    ([0.7, 0.7, 0.7, 0.6], [23.25, 3.03, 2.87]),
+    ([0.7, 0.7, 1.0, 0.6], [23.05, 1.45, 12.60]),
+    ([0.7, 1.0, 0.0, 0.6], [17.96, 22.41, -19.15]),
+    ([0.7, 1.0, 0.2, 0.6], [17.94, 20.02, -14.19]),
+    ([0.7, 1.0, 0.4, 0.6], [17.95, 17.51, -8.43]),
+    ([0.7, 1.0, 0.7, 0.6], [17.95, 14.77, 0.23]),
+    ([0.7, 1.0, 1.0, 0.6], [17.91, 12.70, 7.88]),
+    ([1.0, 0.0, 0.0, 0.6], [30.98, -23.75, -28.41]),
+    ([1.0, 0.0, 0.2, 0.6], [30.41, -27.55, -20.57]),
+    ([1.0, 0.0, 0.4, 0.6], [29.97, -31.34, -11.68]),
+    ([1.0, 0.0, 0.7, 0.6], [29.41, -35.35, 2.27]),
+    ([1.0, 0.0, 1.0, 0.6], [29.32, -37.66, 14.92]),
+    ([1.0, 0.2, 0.0, 0.6], [28.13, -18.09, -28.31]),
+    ([1.0, 0.2, 0.2, 0.6], [27.67, -21.64, -21.00]),
+    ([1.0, 0.2, 0.4, 0.6], [27.23, -25.18, -12.62]),
+    ([1.0, 0.2, 0.7, 0.6], [26.77, -28.97, 0.40]),
+    ([1.0, 0.2, 1.0, 0.6], [26.64, -31.53, 12.21]),
+    ([1.0, 0.4, 0.0, 0.6], [24.73, -10.86, -28.15]),
+    ([1.0, 0.4, 0.2, 0.6], [24.24, -14.01, -21.47]),
+    ([1.0, 0.4, 0.4, 0.6], [23.87, -17.25, -13.73]),
+    ([1.0, 0.4, 0.7, 0.6], [23.56, -21.09, -1.88]),
+    ([1.0, 0.4, 1.0, 0.6], [23.49, -23.62, 8.88]),
+    ([1.0, 0.7, 0.0, 0.6], [19.40, 1.63, -26.92]),
+    ([1.0, 0.7, 0.2, 0.6], [19.23, -1.63, -21.14]),
+    ([1.0, 0.7, 0.4, 0.6], [19.10, -4.78, -14.42]),
+    ([1.0, 0.7, 0.7, 0.6], [19.00, -8.59, -4.36]),
+    ([1.0, 0.7, 1.0, 0.6], [19.01, -11.29, 4.90]),
+    ([1.0, 1.0, 0.0, 0.6], [14.99, 12.63, -24.76]),
+    ([1.0, 1.0, 0.2, 0.6], [15.03, 9.61, -19.85]),
+    ([1.0, 1.0, 0.4, 0.6], [15.09, 6.32, -14.13]),
+    ([1.0, 1.0, 0.7, 0.6], [15.21, 2.37, -5.58]),
+    ([1.0, 1.0, 1.0, 0.6], [15.41, -0.81, 2.42]),
+    ([0.0, 0.0, 0.0, 0.8], [36.61, -0.04, -0.56]),
+    ([0.0, 0.0, 0.2, 0.8], [35.90, -0.92, 4.93]),
+    ([0.0, 0.0, 0.4, 0.8], [35.23, -1.99, 11.30]),
+    ([0.0, 0.0, 0.7, 0.8], [34.44, -3.24, 22.34]),
+    ([0.0, 0.0, 1.0, 0.8], [34.02, -4.27, 32.93]),
+    ([0.0, 0.2, 0.0, 0.8], [33.39, 4.55, -1.81]),
+    ([0.0, 0.2, 0.2, 0.8], [32.85, 3.52, 3.69]),
+    ([0.0, 0.2, 0.4, 0.8], [32.25, 2.72, 9.57]),
+    ([0.0, 0.2, 0.7, 0.8], [31.54, 1.51, 19.80]),
+    ([0.0, 0.2, 1.0, 0.8], [31.11, 0.53, 29.64]),
+    ([0.0, 0.4, 0.0, 0.8], [29.77, 9.94, -2.48]),
+    ([0.0, 0.4, 0.2, 0.8], [29.33, 9.12, 2.52]),
+    ([0.0, 0.4, 0.4, 0.8], [28.90, 8.22, 8.06]),
+    ([0.0, 0.4, 0.7, 0.8], [28.32, 7.06, 17.45]),
+    ([0.0, 0.4, 1.0, 0.8], [27.83, 6.31, 26.13]),
+    ([0.0, 0.7, 0.0, 0.8], [23.89, 19.76, -2.16]),
+    ([0.0, 0.7, 0.2, 0.8], [23.72, 18.86, 2.03]),
+    ([0.0, 0.7, 0.4, 0.8], [23.51, 17.90, 6.65]),
+    ([0.0, 0.7, 0.7, 0.8], [23.22, 16.63, 14.12]),
+    ([0.0, 0.7, 1.0, 0.8], [22.94, 15.67, 20.94]),
+    ([0.0, 1.0, 0.0, 0.8], [18.88, 29.47, -0.21]),
+    ([0.0, 1.0, 0.2, 0.8], [18.82, 28.19, 3.00]),
+    ([0.0, 1.0, 0.4, 0.8], [18.84, 26.91, 6.63]),
+    ([0.0, 1.0, 0.7, 0.8], [18.88, 25.00, 12.16]),
+    ([0.0, 1.0, 1.0, 0.8], [19.01, 23.61, 16.68]),
+    ([0.2, 0.0, 0.0, 0.8], [33.97, -2.60, -4.23]),
+    ([0.2, 0.0, 0.2, 0.8], [33.37, -3.79, 1.59]),
+    ([0.2, 0.0, 0.4, 0.8], [32.77, -4.95, 7.91]),
+    ([0.2, 0.0, 0.7, 0.8], [32.03, -6.32, 18.73]),
+    ([0.2, 0.0, 1.0, 0.8], [31.54, -7.34, 28.89]),
+    ([0.2, 0.2, 0.0, 0.8], [30.95, 1.69, -5.02]),
+    ([0.2, 0.2, 0.2, 0.8], [30.46, 0.65, 0.44]),
+    ([0.2, 0.2, 0.4, 0.8], [29.99, -0.37, 6.39]),
+    ([0.2, 0.2, 0.7, 0.8], [29.29, -1.65, 16.40]),
+    ([0.2, 0.2, 1.0, 0.8], [28.83, -2.67, 25.80]),
+    ([0.2, 0.4, 0.0, 0.8], [27.54, 7.04, -5.55]),
+    ([0.2, 0.4, 0.2, 0.8], [27.18, 6.09, -0.56]),
+    ([0.2, 0.4, 0.4, 0.8], [26.84, 5.06, 5.02]),
+    ([0.2, 0.4, 0.7, 0.8], [26.27, 3.86, 14.14]),
+    ([0.2, 0.4, 1.0, 0.8], [25.79, 2.84, 22.50]),
+    ([0.2, 0.7, 0.0, 0.8], [22.00, 16.70, -5.23]),
+    ([0.2, 0.7, 0.2, 0.8], [21.89, 15.65, -0.91]),
+    ([0.2, 0.7, 0.4, 0.8], [21.73, 14.59, 3.74]),
+    ([0.2, 0.7, 0.7, 0.8], [21.48, 13.18, 11.09]),
+    ([0.2, 0.7, 1.0, 0.8], [21.20, 12.13, 17.55]),
+    ([0.2, 1.0, 0.0, 0.8], [17.20, 26.18, -3.46]),
+    ([0.2, 1.0, 0.2, 0.8], [17.28, 24.79, -0.06]),
+    ([0.2, 1.0, 0.4, 0.8], [17.35, 23.25, 3.68]),
+    ([0.2, 1.0, 0.7, 0.8], [17.41, 21.28, 9.15]),
+    ([0.2, 1.0, 1.0, 0.8], [17.50, 19.86, 13.65]),
+    ([0.4, 0.0, 0.0, 0.8], [30.96, -5.64, -8.15]),
+    ([0.4, 0.0, 0.2, 0.8], [30.47, -7.08, -2.29]),
+    ([0.4, 0.0, 0.4, 0.8], [29.95, -8.48, 4.04]),
+    ([0.4, 0.0, 0.7, 0.8], [29.27, -9.95, 14.53]),
+    ([0.4, 0.0, 1.0, 0.8], [28.86, -11.19, 24.20]),
+    ([0.4, 0.2, 0.0, 0.8], [28.18, -1.30, -8.63]),
+    ([0.4, 0.2, 0.2, 0.8], [27.78, -2.64, -3.25]),
+    ([0.4, 0.2, 0.4, 0.8], [27.38, -3.81, 2.80]),
+    ([0.4, 0.2, 0.7, 0.8], [26.78, -5.32, 12.49]),
+    ([0.4, 0.2, 1.0, 0.8], [26.32, -6.52, 21.40]),
+    ([0.4, 0.4, 0.0, 0.8], [25.04, 3.87, -9.02]),
+    ([0.4, 0.4, 0.2, 0.8], [24.82, 2.66, -3.94]),
+    ([0.4, 0.4, 0.4, 0.8], [24.52, 1.50, 1.66]),
+    ([0.4, 0.4, 0.7, 0.8], [24.03, 0.05, 10.52]),
+    ([0.4, 0.4, 1.0, 0.8], [23.57, -1.00, 18.43]),
+    ([0.4, 0.7, 0.0, 0.8], [19.91, 13.32, -8.46]),
+    ([0.4, 0.7, 0.2, 0.8], [19.90, 12.02, -4.12]),
+    ([0.4, 0.7, 0.4, 0.8], [19.79, 10.77, 0.42]),
+    ([0.4, 0.7, 0.7, 0.8], [19.60, 9.14, 7.70]),
+    ([0.4, 0.7, 1.0, 0.8], [19.29, 7.89, 13.87]),
+    ([0.4, 1.0, 0.0, 0.8], [15.50, 22.29, -6.83]),
+    ([0.4, 1.0, 0.2, 0.8], [15.61, 20.77, -3.39]),
+    ([0.4, 1.0, 0.4, 0.8], [15.69, 19.11, 0.40]),
+    ([0.4, 1.0, 0.7, 0.8], [15.83, 16.96, 5.87]),
+    ([0.4, 1.0, 1.0, 0.8], [15.90, 15.35, 10.28]),
+    ([0.7, 0.0, 0.0, 0.8], [25.85, -11.01, -14.39]),
+    ([0.7, 0.0, 0.2, 0.8], [25.49, -13.05, -8.64]),
+    ([0.7, 0.0, 0.4, 0.8], [25.13, -14.89, -2.51]),
+    ([0.7, 0.0, 0.7, 0.8], [24.68, -16.98, 7.49]),
+    ([0.7, 0.0, 1.0, 0.8], [24.46, -18.37, 16.48]),
+    ([0.7, 0.2, 0.0, 0.8], [23.51, -6.65, -14.58]),
+    ([0.7, 0.2, 0.2, 0.8], [23.24, -8.50, -9.24]),
+    ([0.7, 0.2, 0.4, 0.8], [22.98, -10.20, -3.35]),
+    ([0.7, 0.2, 0.7, 0.8], [22.62, -12.24, 5.93]),
+    ([0.7, 0.2, 1.0, 0.8], [22.37, -13.55, 14.18]),
+    ([0.7, 0.4, 0.0, 0.8], [20.82, -1.43, -14.60]),
+    ([0.7, 0.4, 0.2, 0.8], [20.69, -3.17, -9.61]),
+    ([0.7, 0.4, 0.4, 0.8], [20.59, -4.82, -4.14]),
+    ([0.7, 0.4, 0.7, 0.8], [20.28, -6.78, 4.32]),
+    ([0.7, 0.4, 1.0, 0.8], [20.05, -8.11, 11.75]),
+    ([0.7, 0.7, 0.0, 0.8], [16.66, 7.52, -13.65]),
+    ([0.7, 0.7, 0.2, 0.8], [16.73, 5.78, -9.40]),
+    ([0.7, 0.7, 0.4, 0.8], [16.72, 4.07, -4.80]),
+    ([0.7, 0.7, 0.7, 0.8], [16.67, 1.87, 2.25]),
+    ([0.7, 0.7, 1.0, 0.8], [16.56, 0.37, 8.16]),
+    ([0.7, 1.0, 0.0, 0.8], [13.16, 15.64, -11.86]),
+    ([0.7, 1.0, 0.2, 0.8], [13.30, 13.57, -8.38]),
+    ([0.7, 1.0, 0.4, 0.8], [13.43, 11.53, -4.64]),
+    ([0.7, 1.0, 0.7, 0.8], [13.64, 8.98, 1.00]),
+    ([0.7, 1.0, 1.0, 0.8], [13.76, 7.12, 5.53]),
+    ([1.0, 0.0, 0.0, 0.8], [20.90, -16.64, -19.67]),
+    ([1.0, 0.0, 0.2, 0.8], [20.56, -19.28, -14.24]),
+    ([1.0, 0.0, 0.4, 0.8], [20.38, -21.80, -8.30]),
+    ([1.0, 0.0, 0.7, 0.8], [20.32, -24.50, 1.18]),
+    ([1.0, 0.0, 1.0, 0.8], [20.61, -26.06, 9.59]),
+    ([1.0, 0.2, 0.0, 0.8], [18.94, -12.17, -19.65]),
+    ([1.0, 0.2, 0.2, 0.8], [18.77, -14.68, -14.49]),
+    ([1.0, 0.2, 0.4, 0.8], [18.66, -17.00, -8.84]),
+    ([1.0, 0.2, 0.7, 0.8], [18.67, -19.56, 0.09]),
+    ([1.0, 0.2, 1.0, 0.8], [18.85, -21.24, 7.90]),
+    ([1.0, 0.4, 0.0, 0.8], [16.83, -6.91, -19.34]),
+    ([1.0, 0.4, 0.2, 0.8], [16.73, -9.21, -14.52]),
+    ([1.0, 0.4, 0.4, 0.8], [16.72, -11.39, -9.23]),
+    ([1.0, 0.4, 0.7, 0.8], [16.82, -14.05, -1.02]),
+    ([1.0, 0.4, 1.0, 0.8], [16.97, -15.67, 6.07]),
+    ([1.0, 0.7, 0.0, 0.8], [13.79, 1.90, -17.84]),
+    ([1.0, 0.7, 0.2, 0.8], [13.88, -0.53, -13.65]),
+    ([1.0, 0.7, 0.4, 0.8], [13.98, -2.73, -9.07]),
+    ([1.0, 0.7, 0.7, 0.8], [14.16, -5.47, -2.10]),
+    ([1.0, 0.7, 1.0, 0.8], [14.33, -7.23, 3.85]),
+    ([1.0, 1.0, 0.0, 0.8], [11.57, 9.09, -15.45]),
+    ([1.0, 1.0, 0.2, 0.8], [11.69, 6.55, -11.91]),
+    ([1.0, 1.0, 0.4, 0.8], [11.84, 4.01, -8.01]),
+    ([1.0, 1.0, 0.7, 0.8], [12.16, 0.98, -2.22]),
+    ([1.0, 1.0, 1.0, 0.8], [12.49, -1.07, 2.73]),
+    ([0.0, 0.0, 0.0, 1.0], [15.99, -0.06, 0.01]),
+    ([0.0, 0.0, 0.2, 1.0], [15.74, -0.67, 2.37]),
+    ([0.0, 0.0, 0.4, 1.0], [15.59, -1.36, 4.85]),
+    ([0.0, 0.0, 0.7, 1.0], [15.57, -2.23, 8.62]),
+    ([0.0, 0.0, 1.0, 1.0], [15.70, -3.05, 11.65]),
+    ([0.0, 0.2, 0.0, 1.0], [14.77, 2.47, -0.31]),
+    ([0.0, 0.2, 0.2, 1.0], [14.67, 1.74, 2.06]),
+    ([0.0, 0.2, 0.4, 1.0], [14.59, 1.14, 4.38]),
+    ([0.0, 0.2, 0.7, 1.0], [14.63, 0.14, 7.92]),
+    ([0.0, 0.2, 1.0, 1.0], [14.75, -0.78, 10.80]),
+    ([0.0, 0.4, 0.0, 1.0], [13.49, 5.23, -0.33]),
+    ([0.0, 0.4, 0.2, 1.0], [13.50, 4.57, 1.85]),
+    ([0.0, 0.4, 0.4, 1.0], [13.54, 3.83, 4.04]),
+    ([0.0, 0.4, 0.7, 1.0], [13.65, 2.69, 7.31]),
+    ([0.0, 0.4, 1.0, 1.0], [13.75, 1.75, 9.90]),
+    ([0.0, 0.7, 0.0, 1.0], [11.69, 9.81, 0.27]),
+    ([0.0, 0.7, 0.2, 1.0], [11.83, 8.98, 2.11]),
+    ([0.0, 0.7, 0.4, 1.0], [11.97, 8.07, 3.93]),
+    ([0.0, 0.7, 0.7, 1.0], [12.20, 6.68, 6.52]),
+    ([0.0, 0.7, 1.0, 1.0], [12.37, 5.49, 8.51]),
+    ([0.0, 1.0, 0.0, 1.0], [10.40, 13.98, 1.32]),
+    ([0.0, 1.0, 0.2, 1.0], [10.53, 12.83, 2.79]),
+    ([0.0, 1.0, 0.4, 1.0], [10.72, 11.65, 4.25]),
+    ([0.0, 1.0, 0.7, 1.0], [11.06, 9.88, 6.06]),
+    ([0.0, 1.0, 1.0, 1.0], [11.34, 8.52, 7.27]),
+    ([0.2, 0.0, 0.0, 1.0], [14.92, -1.38, -1.92]),
+    ([0.2, 0.0, 0.2, 1.0], [14.78, -2.19, 0.67]),
+    ([0.2, 0.0, 0.4, 1.0], [14.69, -2.94, 3.23]),
+    ([0.2, 0.0, 0.7, 1.0], [14.72, -3.92, 7.12]),
+    ([0.2, 0.0, 1.0, 1.0], [14.82, -4.82, 10.23]),
+    ([0.2, 0.2, 0.0, 1.0], [13.79, 1.04, -2.06]),
+    ([0.2, 0.2, 0.2, 1.0], [13.78, 0.30, 0.39]),
+    ([0.2, 0.2, 0.4, 1.0], [13.79, -0.44, 2.81]),
+    ([0.2, 0.2, 0.7, 1.0], [13.86, -1.51, 6.45]),
+    ([0.2, 0.2, 1.0, 1.0], [13.96, -2.52, 9.40]),
+    ([0.2, 0.4, 0.0, 1.0], [12.62, 3.86, -2.02]),
+    ([0.2, 0.4, 0.2, 1.0], [12.72, 3.11, 0.23]),
+    ([0.2, 0.4, 0.4, 1.0], [12.82, 2.29, 2.52]),
+    ([0.2, 0.4, 0.7, 1.0], [12.95, 1.11, 5.87]),
+    ([0.2, 0.4, 1.0, 1.0], [13.05, -0.02, 8.53]),
+    ([0.2, 0.7, 0.0, 1.0], [10.94, 8.47, -1.38]),
+    ([0.2, 0.7, 0.2, 1.0], [11.16, 7.53, 0.56]),
+    ([0.2, 0.7, 0.4, 1.0], [11.35, 6.55, 2.48]),
+    ([0.2, 0.7, 0.7, 1.0], [11.60, 5.08, 5.17]),
+    ([0.2, 0.7, 1.0, 1.0], [11.75, 3.84, 7.24]),
+    ([0.2, 1.0, 0.0, 1.0], [9.69, 12.60, -0.30]),
+    ([0.2, 1.0, 0.2, 1.0], [9.92, 11.36, 1.29]),
+    ([0.2, 1.0, 0.4, 1.0], [10.16, 10.03, 2.85]),
+    ([0.2, 1.0, 0.7, 1.0], [10.49, 8.22, 4.81]),
+    ([0.2, 1.0, 1.0, 1.0], [10.75, 6.84, 6.17]),
+    ([0.4, 0.0, 0.0, 1.0], [13.72, -2.93, -4.02]),
+    ([0.4, 0.0, 0.2, 1.0], [13.69, -3.88, -1.29]),
+    ([0.4, 0.0, 0.4, 1.0], [13.69, -4.78, 1.39]),
+    ([0.4, 0.0, 0.7, 1.0], [13.77, -5.82, 5.39]),
+    ([0.4, 0.0, 1.0, 1.0], [13.89, -6.87, 8.60]),
+    ([0.4, 0.2, 0.0, 1.0], [12.72, -0.41, -4.01]),
+    ([0.4, 0.2, 0.2, 1.0], [12.80, -1.32, -1.51]),
+    ([0.4, 0.2, 0.4, 1.0], [12.89, -2.14, 1.08]),
+    ([0.4, 0.2, 0.7, 1.0], [13.01, -3.34, 4.81]),
+    ([0.4, 0.2, 1.0, 1.0], [13.11, -4.47, 7.82]),
+    ([0.4, 0.4, 0.0, 1.0], [11.67, 2.39, -3.90]),
+    ([0.4, 0.4, 0.2, 1.0], [11.87, 1.50, -1.53]),
+    ([0.4, 0.4, 0.4, 1.0], [12.03, 0.60, 0.87]),
+    ([0.4, 0.4, 0.7, 1.0], [12.20, -0.71, 4.31]),
+    ([0.4, 0.4, 1.0, 1.0], [12.30, -1.86, 7.04]),
+    ([0.4, 0.7, 0.0, 1.0], [10.16, 7.01, -3.09]),
+    ([0.4, 0.7, 0.2, 1.0], [10.45, 5.94, -1.07]),
+    ([0.4, 0.7, 0.4, 1.0], [10.67, 4.87, 0.87]),
+    ([0.4, 0.7, 0.7, 1.0], [10.94, 3.30, 3.72]),
+    ([0.4, 0.7, 1.0, 1.0], [11.07, 1.97, 5.88]),
+    ([0.4, 1.0, 0.0, 1.0], [9.00, 10.98, -1.95]),
+    ([0.4, 1.0, 0.2, 1.0], [9.27, 9.66, -0.30]),
+    ([0.4, 1.0, 0.4, 1.0], [9.53, 8.27, 1.34]),
+    ([0.4, 1.0, 0.7, 1.0], [9.89, 6.42, 3.47]),
+    ([0.4, 1.0, 1.0, 1.0], [10.12, 4.98, 4.97]),
+    ([0.7, 0.0, 0.0, 1.0], [11.84, -5.56, -7.41]),
+    ([0.7, 0.0, 0.2, 1.0], [11.95, -6.88, -4.52]),
+    ([0.7, 0.0, 0.4, 1.0], [12.07, -7.97, -1.68]),
+    ([0.7, 0.0, 0.7, 1.0], [12.27, -9.21, 2.54]),
+    ([0.7, 0.0, 1.0, 1.0], [12.47, -10.20, 5.93]),
+    ([0.7, 0.2, 0.0, 1.0], [11.04, -2.92, -7.23]),
+    ([0.7, 0.2, 0.2, 1.0], [11.24, -4.12, -4.56]),
+    ([0.7, 0.2, 0.4, 1.0], [11.43, -5.19, -1.82]),
+    ([0.7, 0.2, 0.7, 1.0], [11.66, -6.53, 2.12]),
+    ([0.7, 0.2, 1.0, 1.0], [11.83, -7.59, 5.27]),
+    ([0.7, 0.4, 0.0, 1.0], [10.20, 0.03, -6.88]),
+    ([0.7, 0.4, 0.2, 1.0], [10.48, -1.15, -4.39]),
+    ([0.7, 0.4, 0.4, 1.0], [10.73, -2.27, -1.86]),
+    ([0.7, 0.4, 0.7, 1.0], [10.98, -3.71, 1.76]),
+    ([0.7, 0.4, 1.0, 1.0], [11.13, -4.87, 4.64]),
+    ([0.7, 0.7, 0.0, 1.0], [9.06, 4.60, -5.75]),
+    ([0.7, 0.7, 0.2, 1.0], [9.39, 3.32, -3.67]),
+    ([0.7, 0.7, 0.4, 1.0], [9.64, 2.07, -1.55]),
+    ([0.7, 0.7, 0.7, 1.0], [9.93, 0.38, 1.46]),
+    ([0.7, 0.7, 1.0, 1.0], [10.10, -0.91, 3.79]),
+    ([0.7, 1.0, 0.0, 1.0], [8.19, 8.34, -4.34]),
+    ([0.7, 1.0, 0.2, 1.0], [8.46, 6.79, -2.65]),
+    ([0.7, 1.0, 0.4, 1.0], [8.71, 5.32, -0.93]),
+    ([0.7, 1.0, 0.7, 1.0], [9.05, 3.46, 1.47]),
+    ([0.7, 1.0, 1.0, 1.0], [9.26, 2.08, 3.23]),
+    ([1.0, 0.0, 0.0, 1.0], [10.41, -8.16, -10.24]),
+    ([1.0, 0.0, 0.2, 1.0], [10.47, -9.93, -7.27]),
+    ([1.0, 0.0, 0.4, 1.0], [10.64, -11.26, -4.25]),
+    ([1.0, 0.0, 0.7, 1.0], [10.98, -12.33, 0.15]),
+    ([1.0, 0.0, 1.0, 1.0], [11.34, -12.96, 3.60]),
+    ([1.0, 0.2, 0.0, 1.0], [9.72, -5.44, -9.88]),
+    ([1.0, 0.2, 0.2, 1.0], [9.90, -7.05, -7.08]),
+    ([1.0, 0.2, 0.4, 1.0], [10.12, -8.30, -4.22]),
+    ([1.0, 0.2, 0.7, 1.0], [10.47, -9.50, -0.09]),
+    ([1.0, 0.2, 1.0, 1.0], [10.77, -10.34, 3.14]),
+    ([1.0, 0.4, 0.0, 1.0], [9.08, -2.43, -9.26]),
+    ([1.0, 0.4, 0.2, 1.0], [9.31, -3.88, -6.68]),
+    ([1.0, 0.4, 0.4, 1.0], [9.56, -5.12, -4.04]),
+    ([1.0, 0.4, 0.7, 1.0], [9.91, -6.55, -0.27]),
+    ([1.0, 0.4, 1.0, 1.0], [10.17, -7.50, 2.69]),
+    ([1.0, 0.7, 0.0, 1.0], [8.33, 2.27, -7.72]),
+    ([1.0, 0.7, 0.2, 1.0], [8.58, 0.73, -5.58]),
+    ([1.0, 0.7, 0.4, 1.0], [8.81, -0.60, -3.37]),
+    ([1.0, 0.7, 0.7, 1.0], [9.12, -2.20, -0.23]),
+    ([1.0, 0.7, 1.0, 1.0], [9.35, -3.30, 2.23]),
+    ([1.0, 1.0, 0.0, 1.0], [7.86, 5.84, -5.98]),
+    ([1.0, 1.0, 0.2, 1.0], [8.03, 4.22, -4.25]),
+    ([1.0, 1.0, 0.4, 1.0], [8.22, 2.73, -2.42]),
+    ([1.0, 1.0, 0.7, 1.0], [8.51, 1.03, 0.11]),
+    ([1.0, 1.0, 1.0, 1.0], [8.75, -0.15, 2.06]),
+];
@@ -0,0 +1,128 @@
+use crate::{ Observer, Xyz, Yxy };
+use crate::chromaticity::xyz_to_xy;
+
+/// The wavelength describing the hue of a color relative to a white point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Wavelength {
+    /// The color lies between the white point and the spectral locus at this wavelength.
+    Dominant(f64),
+    /// The color lies between the white point and the purple line, so it has no dominant
+    /// wavelength; this is the wavelength on the opposite side of the white point.
+    Complementary(f64),
+}
+
+pub trait DominantWavelength {
+    /// The dominant wavelength in nanometers, or the complementary wavelength for purples,
+    /// of the line from `white` through this color in the CIE 1931 xy diagram, or `None`
+    /// for the white point itself and colors without a chromaticity, such as black.
+    fn dominant_wavelength(&self, white: &Xyz) -> Option<Wavelength>;
+
+    /// The complementary wavelength in nanometers, where the line from this color through
+    /// `white` meets the spectral locus, or `None` if it meets the purple line instead.
+    fn complementary_wavelength(&self, white: &Xyz) -> Option<f64>;
+
+    /// Excitation purity (0 to 1), the distance from `white` to this color relative to
+    /// the distance from `white` to the spectral locus or purple line in the same direction.
+    fn excitation_purity(&self, white: &Xyz) -> f64;
+}
+
+/// Where a ray from the white point leaves the area enclosed by the spectral locus.
+enum Boundary {
+    Locus(f64, (f64, f64)),
+    Purple((f64, f64)),
+}
+
+const LOCUS_START: f64 = 380.0;
+const LOCUS_END: f64 = 780.0;
+
+/// Cast a ray from `white` in the direction `(dx, dy)` and find the first point of
+/// the spectral locus, sampled every nanometer, or of the purple line it crosses.
+fn boundary(white: (f64, f64), dx: f64, dy: f64) -> Option<Boundary> {
+    let observer = Observer::Cie1931;
+    let locus: Vec<(f64, f64)> = (0..=(LOCUS_END - LOCUS_START) as usize)
+        .map(|i| {
+            let (x, y, z) = observer.cmf(LOCUS_START + i as f64);
+            (x / (x + y + z), y / (x + y + z))
+        })
+        .collect();
+    let n = locus.len();
+    let mut best: Option<(f64, Boundary)> = None;
+    for i in 0..n {
+        // the last segment closes the locus along the purple line
+        let (p, q) = (locus[i], locus[(i + 1) % n]);
+        let (ex, ey) = (q.0 - p.0, q.1 - p.1);
+        let den = dx * ey - dy * ex;
+        if den == 0.0 {
+            continue;
+        }
+        let (wx, wy) = (p.0 - white.0, p.1 - white.1);
+        let t = (wx * ey - wy * ex) / den;
+        let s = (wx * dy - wy * dx) / den;
+        if t <= 0.0 || !(0.0..=1.0).contains(&s) || best.as_ref().is_some_and(|b| b.0 <= t) {
+            continue;
+        }
+        let point = (white.0 + t * dx, white.1 + t * dy);
+        let hit = match i + 1 == n {
+            true => Boundary::Purple(point),
+            false => Boundary::Locus(LOCUS_START + i as f64 + s, point),
+        };
+        best = Some((t, hit));
+    }
+    best.map(|b| b.1)
+}
+
+fn dominant_xy(x: f64, y: f64, white: &Xyz) -> Option<Wavelength> {
+    let w = xyz_to_xy(white);
+    match boundary(w, x - w.0, y - w.1)? {
+        Boundary::Locus(nm, _) => Some(Wavelength::Dominant(nm)),
+        Boundary::Purple(_) => complementary_xy(x, y, white).map(Wavelength::Complementary),
+    }
+}
+
+fn complementary_xy(x: f64, y: f64, white: &Xyz) -> Option<f64> {
+    let w = xyz_to_xy(white);
+    match boundary(w, w.0 - x, w.1 - y) {
+        Some(Boundary::Locus(nm, _)) => Some(nm),
+        _ => None,
+    }
+}
+
+fn purity_xy(x: f64, y: f64, white: &Xyz) -> f64 {
+    let w = xyz_to_xy(white);
+    let distance = |p: (f64, f64)| ((p.0 - w.0).powi(2) + (p.1 - w.1).powi(2)).sqrt();
+    match boundary(w, x - w.0, y - w.1) {
+        Some(Boundary::Locus(_, p)) | Some(Boundary::Purple(p)) => distance((x, y)) / distance(p),
+        None => 0.0,
+    }
+}
+
+impl DominantWavelength for Xyz {
+    fn dominant_wavelength(&self, white: &Xyz) -> Option<Wavelength> {
+        let (x, y) = xyz_to_xy(self);
+        dominant_xy(x, y, white)
+    }
+
+    fn complementary_wavelength(&self, white: &Xyz) -> Option<f64> {
+        let (x, y) = xyz_to_xy(self);
+        complementary_xy(x, y, white)
+    }
+
+    fn excitation_purity(&self, white: &Xyz) -> f64 {
+        let (x, y) = xyz_to_xy(self);
+        purity_xy(x, y, white)
+    }
+}
+
+impl DominantWavelength for Yxy {
+    fn dominant_wavelength(&self, white: &Xyz) -> Option<Wavelength> {
+        dominant_xy(self.x, self.y2, white)
+    }
+
+    fn complementary_wavelength(&self, white: &Xyz) -> Option<f64> {
+        complementary_xy(self.x, self.y2, white)
+    }
+
+    fn excitation_purity(&self, white: &Xyz) -> f64 {
+        purity_xy(self.x, self.y2, white)
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{ FromColor, FromRgb, Lab, Oklab, Oklch, RgbSpace, ToRgb };
+use crate::gamut_boundary::cusp;
+
+pub trait InGamut {
+    /// Whether this color is representable in `space`, allowing each encoded channel
+    /// (0 to 1) to fall outside its range by up to `tolerance`.
+    fn is_in_gamut(&self, space: &RgbSpace, tolerance: f64) -> bool;
+}
+
+impl<T: ToRgb> InGamut for T {
+    fn is_in_gamut(&self, space: &RgbSpace, tolerance: f64) -> bool {
+        space
+            .from_rgb(&self.to_rgb())
+            .iter()
+            .all(|&c| c >= -tolerance && c <= 1.0 + tolerance)
+    }
+}
+
+/// Strategy used to bring a color into the gamut of an RGB space.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GamutMapping {
+    /// Clamp each channel of the space to 0 to 1.
+    Clip,
+    /// The CSS Color 4 algorithm: binary search on Oklch chroma at constant lightness
+    /// and hue, stopping as soon as clipping the color moves it by less than the just
+    /// noticeable difference of 0.02 ΔEOK.
+    Css,
+    /// Minimum ΔE*ab (MINDE): the color of the gamut closest to the original in CIELAB.
+    Minde,
+    /// Ottosson's projection at constant Oklch hue towards the gray with the lightness
+    /// of the gamut cusp, which keeps saturated colors saturated.
+    Cusp,
+}
+
+pub trait GamutMap {
+    /// Map this color into the gamut of `space` with `method`, returning it as any
+    /// color type. Colors already in gamut are returned unchanged.
+    fn gamut_map<U: FromRgb>(&self, space: &RgbSpace, method: GamutMapping) -> U;
+}
+
+impl<T: ToRgb> GamutMap for T {
+    fn gamut_map<U: FromRgb>(&self, space: &RgbSpace, method: GamutMapping) -> U {
+        let rgb = self.to_rgb();
+        let encoded = space.from_rgb(&rgb);
+        let mapped = match inside(&encoded) {
+            true => encoded,
+            false => match method {
+                GamutMapping::Clip => clip(encoded),
+                GamutMapping::Css => css(space, Oklch::from_rgb(&rgb)),
+                GamutMapping::Minde => minde(space, Lab::from_rgb(&rgb), clip(encoded)),
+                GamutMapping::Cusp => cusp_projection(space, Oklch::from_rgb(&rgb)),
+            },
+        };
+        U::from_rgb(&space.to_rgb(mapped))
+    }
+}
+
+fn inside(encoded: &[f64; 3]) -> bool {
+    encoded.iter().all(|&c| (0.0..=1.0).contains(&c))
+}
+
+fn clip(encoded: [f64; 3]) -> [f64; 3] {
+    [encoded[0].clamp(0.0, 1.0), encoded[1].clamp(0.0, 1.0), encoded[2].clamp(0.0, 1.0)]
+}
+
+fn encode<T: ToRgb>(space: &RgbSpace, color: &T) -> [f64; 3] {
+    space.from_rgb(&color.to_rgb())
+}
+
+fn delta_eok(a: &Oklab, b: &Oklab) -> f64 {
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+const CSS_JND: f64 = 0.02;
+const CSS_EPSILON: f64 = 0.0001;
+
+fn css(space: &RgbSpace, origin: Oklch) -> [f64; 3] {
+    if origin.l >= 1.0 {
+        return [1.0; 3];
+    }
+    if origin.l <= 0.0 {
+        return [0.0; 3];
+    }
+    let difference = |current: &Oklch, clipped: &[f64; 3]| {
+        delta_eok(&Oklab::from_rgb(&space.to_rgb(*clipped)), &Oklab::from_color(current))
+    };
+    let mut current = origin;
+    let mut clipped = clip(encode(space, &current));
+    if difference(&current, &clipped) < CSS_JND {
+        return clipped;
+    }
+    let (mut min, mut max) = (0.0, origin.c);
+    let mut min_in_gamut = true;
+    while max - min > CSS_EPSILON {
+        current.c = (min + max) / 2.0;
+        let encoded = encode(space, &current);
+        if min_in_gamut && inside(&encoded) {
+            min = current.c;
+            continue;
+        }
+        clipped = clip(encoded);
+        let e = difference(&current, &clipped);
+        if e < CSS_JND {
+            if CSS_JND - e < CSS_EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = current.c;
+        } else {
+            max = current.c;
+        }
+    }
+    clipped
+}
+
+const MINDE_ITERATIONS: usize = 100;
+
+/// Projected Gauss-Newton search of the channel cube for the color closest to `target`.
+fn minde(space: &RgbSpace, target: Lab, start: [f64; 3]) -> [f64; 3] {
+    let residual = |x: &[f64; 3]| {
+        let lab = Lab::from_rgb(&space.to_rgb(*x));
+        [lab.l - target.l, lab.a - target.a, lab.b - target.b]
+    };
+    let norm = |r: &[f64; 3]| r[0] * r[0] + r[1] * r[1] + r[2] * r[2];
+    let mut x = start;
+    let mut r = residual(&x);
+    for _ in 0..MINDE_ITERATIONS {
+        let mut jacobian = [[0.0; 3]; 3];
+        for k in 0..3 {
+            let h = if x[k] > 0.5 { -1e-6 } else { 1e-6 };
+            let mut xh = x;
+            xh[k] += h;
+            let rh = residual(&xh);
+            for j in 0..3 {
+                jacobian[j][k] = (rh[j] - r[j]) / h;
+            }
+        }
+        // channels held at a bound by the gradient stay there for this step
+        let gradient: Vec<f64> = (0..3).map(|k| (0..3).map(|j| jacobian[j][k] * r[j]).sum()).collect();
+        let free: Vec<usize> = (0..3)
+            .filter(|&k| !((x[k] <= 0.0 && gradient[k] > 0.0) || (x[k] >= 1.0 && gradient[k] < 0.0)))
+            .collect();
+        if free.is_empty() {
+            break;
+        }
+        let n = free.len();
+        let mut system = vec![vec![0.0; n + 1]; n];
+        for (a, &i) in free.iter().enumerate() {
+            for (b, &k) in free.iter().enumerate() {
+                system[a][b] = (0..3).map(|j| jacobian[j][i] * jacobian[j][k]).sum();
+            }
+            system[a][n] = -gradient[i];
+        }
+        let step = match solve(system) {
+            Some(step) => step,
+            None => break,
+        };
+        let mut scale = 1.0;
+        let mut improved = false;
+        while scale > 1e-6 {
+            let mut next = x;
+            for (a, &k) in free.iter().enumerate() {
+                next[k] = (x[k] + scale * step[a]).clamp(0.0, 1.0);
+            }
+            let rn = residual(&next);
+            if norm(&rn) < norm(&r) {
+                improved = (norm(&r) - norm(&rn)) > 1e-14;
+                x = next;
+                r = rn;
+                break;
+            }
+            scale *= 0.5;
+        }
+        if !improved {
+            break;
+        }
+    }
+    x
+}
+
+/// Solve a small augmented linear system by Gaussian elimination with partial pivoting.
+fn solve(mut m: Vec<Vec<f64>>) -> Option<Vec<f64>> {
+    let n = m.len();
+    for k in 0..n {
+        let p = (k..n).max_by(|&a, &b| m[a][k].abs().partial_cmp(&m[b][k].abs()).unwrap())?;
+        if m[p][k].abs() < 1e-300 {
+            return None;
+        }
+        m.swap(k, p);
+        let (top, bottom) = m.split_at_mut(k + 1);
+        let pivot = &top[k];
+        for row in bottom.iter_mut() {
+            let f = row[k] / pivot[k];
+            for (value, &above) in row[k..].iter_mut().zip(&pivot[k..]) {
+                *value -= f * above;
+            }
+        }
+    }
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let sum: f64 = (i + 1..n).map(|j| m[i][j] * x[j]).sum();
+        x[i] = (m[i][n] - sum) / m[i][i];
+    }
+    Some(x)
+}
+
+fn cusp_projection(space: &RgbSpace, origin: Oklch) -> [f64; 3] {
+    let (l0, _) = cusp::<Oklch>(space, origin.h);
+    let at = |t: f64| Oklch::new(l0 + t * (origin.l - l0), t * origin.c, origin.h);
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..50 {
+        let mid = (lo + hi) / 2.0;
+        match inside(&encode(space, &at(mid))) {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    clip(encode(space, &at(lo)))
+}
@@ -0,0 +1,206 @@
+use crate::{ FromRgb, Lch, LchUv, Oklch, RgbSpace, ToRgb };
+use std::marker::PhantomData;
+
+/// A color type with lightness, chroma and hue coordinates.
+pub trait Polar: FromRgb + ToRgb {
+    /// Lightness of white.
+    const WHITE: f64;
+
+    /// Create a color from lightness, chroma and hue in degrees.
+    fn from_polar(l: f64, c: f64, h: f64) -> Self;
+
+    /// The lightness, chroma and hue in degrees of the color.
+    fn to_polar(&self) -> (f64, f64, f64);
+}
+
+impl Polar for Lch {
+    const WHITE: f64 = 100.0;
+
+    fn from_polar(l: f64, c: f64, h: f64) -> Self {
+        Lch::new(l, c, h)
+    }
+
+    fn to_polar(&self) -> (f64, f64, f64) {
+        (self.l, self.c, self.h)
+    }
+}
+
+impl Polar for LchUv {
+    const WHITE: f64 = 100.0;
+
+    fn from_polar(l: f64, c: f64, h: f64) -> Self {
+        LchUv::new(l, c, h)
+    }
+
+    fn to_polar(&self) -> (f64, f64, f64) {
+        (self.l, self.c, self.h)
+    }
+}
+
+impl Polar for Oklch {
+    const WHITE: f64 = 1.0;
+
+    fn from_polar(l: f64, c: f64, h: f64) -> Self {
+        Oklch::new(l, c, h)
+    }
+
+    fn to_polar(&self) -> (f64, f64, f64) {
+        (self.l, self.c, self.h)
+    }
+}
+
+fn in_gamut<T: Polar>(space: &RgbSpace, l: f64, c: f64, h: f64) -> bool {
+    space
+        .from_rgb(&T::from_polar(l, c, h).to_rgb())
+        .iter()
+        .all(|&v| (0.0..=1.0).contains(&v))
+}
+
+/// The largest chroma, in the terms of `T`, of a color of `space` with the given
+/// lightness and hue, found by bisection on the boundary of the space.
+pub fn max_chroma<T: Polar>(space: &RgbSpace, lightness: f64, hue: f64) -> f64 {
+    if lightness <= 0.0 || lightness >= T::WHITE {
+        return 0.0;
+    }
+    let mut hi = T::WHITE / 100.0;
+    for _ in 0..64 {
+        if !in_gamut::<T>(space, lightness, hi, hue) {
+            break;
+        }
+        hi *= 2.0;
+    }
+    let mut lo = 0.0;
+    for _ in 0..64 {
+        let mid = (lo + hi) / 2.0;
+        match in_gamut::<T>(space, lightness, mid, hue) {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    lo
+}
+
+/// The cusp of `space` at `hue`, the lightness and chroma of its most chromatic color
+/// of that hue, in the terms of `T`.
+///
+/// The cusp lies on the edges of the channel cube that join the primaries and
+/// secondaries, so those are searched for the hue rather than the whole boundary.
+pub fn cusp<T: Polar>(space: &RgbSpace, hue: f64) -> (f64, f64) {
+    const SAMPLES: usize = 32;
+    let rim = |t: f64| {
+        let f = t.fract();
+        let corner: [[f64; 3]; 7] = [
+            [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 1.0, 1.0],
+            [0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [1.0, 0.0, 0.0],
+        ];
+        let i = (t as usize).min(5);
+        let (a, b) = (corner[i], corner[i + 1]);
+        T::from_rgb(&space.to_rgb([
+            a[0] + (b[0] - a[0]) * f,
+            a[1] + (b[1] - a[1]) * f,
+            a[2] + (b[2] - a[2]) * f,
+        ])).to_polar()
+    };
+    let offset = |t: f64| {
+        let d = (rim(t).2 - hue).rem_euclid(360.0);
+        if d > 180.0 { d - 360.0 } else { d }
+    };
+    let mut best = (0.0, 0.0);
+    for i in 0..6 * SAMPLES {
+        let (mut lo, mut hi) = (i as f64 / SAMPLES as f64, (i + 1) as f64 / SAMPLES as f64);
+        let (dlo, dhi) = (offset(lo), offset(hi - 1e-12));
+        if dlo.abs() < 1e-9 {
+            // the hue of a primary or secondary
+            let (l, c, _) = rim(lo);
+            if c > best.1 {
+                best = (l, c);
+            }
+            continue;
+        }
+        // a sign change across a small interval, not the wrap around at 180 degrees
+        if dlo.signum() == dhi.signum() || (dlo - dhi).abs() > 90.0 {
+            continue;
+        }
+        for _ in 0..50 {
+            let mid = (lo + hi) / 2.0;
+            if offset(mid).signum() == dlo.signum() {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let (l, c, _) = rim((lo + hi) / 2.0);
+        if c > best.1 {
+            best = (l, c);
+        }
+    }
+    best
+}
+
+/// A segment maxima gamut boundary descriptor (Morovič and Luo) of an RGB space.
+///
+/// Lightness and hue are divided into segments, each holding the most chromatic color
+/// found in it on the surface of the channel cube. Queries are answered from the table,
+/// which is much faster than `max_chroma` but only as fine as the segments.
+#[derive(Clone, Debug)]
+pub struct GamutBoundary<T> {
+    lightness_segments: usize,
+    hue_segments: usize,
+    maxima: Vec<(f64, f64)>,
+    marker: PhantomData<T>,
+}
+
+impl<T: Polar> GamutBoundary<T> {
+    /// Describe `space` with the given number of lightness and hue segments, which must
+    /// both be at least 1.
+    pub fn new(space: &RgbSpace, lightness_segments: usize, hue_segments: usize) -> Self {
+        assert!(lightness_segments > 0 && hue_segments > 0);
+        let mut boundary = Self {
+            lightness_segments,
+            hue_segments,
+            maxima: vec![(0.0, 0.0); lightness_segments * hue_segments],
+            marker: PhantomData,
+        };
+        let n = 4 * lightness_segments.max(hue_segments);
+        for face in 0..6 {
+            for i in 0..=n {
+                for j in 0..=n {
+                    let (u, v) = (i as f64 / n as f64, j as f64 / n as f64);
+                    let fixed = (face / 3) as f64;
+                    let encoded = match face % 3 {
+                        0 => [fixed, u, v],
+                        1 => [u, fixed, v],
+                        _ => [u, v, fixed],
+                    };
+                    let (l, c, h) = T::from_rgb(&space.to_rgb(encoded)).to_polar();
+                    let index = boundary.index(l, h);
+                    if c > boundary.maxima[index].1 {
+                        boundary.maxima[index] = (l, c);
+                    }
+                }
+            }
+        }
+        boundary
+    }
+
+    fn index(&self, lightness: f64, hue: f64) -> usize {
+        let l = (lightness / T::WHITE * self.lightness_segments as f64).floor();
+        let h = (hue.rem_euclid(360.0) / 360.0 * self.hue_segments as f64).floor();
+        let l = (l.max(0.0) as usize).min(self.lightness_segments - 1);
+        let h = (h.max(0.0) as usize).min(self.hue_segments - 1);
+        l * self.hue_segments + h
+    }
+
+    /// The largest chroma of the segment holding `lightness` and `hue`.
+    pub fn max_chroma(&self, lightness: f64, hue: f64) -> f64 {
+        self.maxima[self.index(lightness, hue)].1
+    }
+
+    /// The lightness and chroma of the most chromatic color of the hue segment holding `hue`.
+    pub fn cusp(&self, hue: f64) -> (f64, f64) {
+        let h = self.index(0.0, hue);
+        (0..self.lightness_segments)
+            .map(|l| self.maxima[l * self.hue_segments + h])
+            .fold((0.0, 0.0), |best, m| if m.1 > best.1 { m } else { best })
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{ Lab, RgbSpace };
+use crate::chromaticity::xy_to_uv_prime;
+use crate::illuminant::D65_WHITE as WHITE;
+use crate::lab::{ lab_to_xyz, xyz_to_lab };
+use crate::rgb_space::{ inverse, mul_vec };
+
+/// A chromaticity diagram in which gamut areas are measured.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Chromaticity {
+    /// The CIE 1931 xy diagram.
+    Xy,
+    /// The CIE 1976 u′v′ uniform chromaticity scale diagram.
+    UvPrime,
+}
+
+impl Chromaticity {
+    fn project(&self, (x, y): (f64, f64)) -> (f64, f64) {
+        match self {
+            Chromaticity::Xy => (x, y),
+            Chromaticity::UvPrime => xy_to_uv_prime(x, y),
+        }
+    }
+}
+
+/// Edge subdivisions of each cube face when integrating the volume of a gamut.
+const FACE_STEPS: usize = 32;
+
+/// Grid cells along each axis of the bounding box when estimating volume coverage.
+const GRID_STEPS: usize = 64;
+
+impl RgbSpace {
+    fn triangle(&self, diagram: Chromaticity) -> Vec<(f64, f64)> {
+        vec![diagram.project(self.red), diagram.project(self.green), diagram.project(self.blue)]
+    }
+
+    /// The area of the triangle of primaries in the `diagram` chromaticity diagram.
+    pub fn gamut_area(&self, diagram: Chromaticity) -> f64 {
+        polygon_area(&self.triangle(diagram))
+    }
+
+    /// The percentage of the `reference` primaries triangle covered by the triangle of
+    /// this space in the `diagram` chromaticity diagram, as in "98% of DCI-P3".
+    pub fn area_coverage(&self, reference: &RgbSpace, diagram: Chromaticity) -> f64 {
+        let overlap = clip_polygon(&reference.triangle(diagram), &self.triangle(diagram));
+        100.0 * polygon_area(&overlap) / reference.gamut_area(diagram)
+    }
+
+    /// The volume of the gamut in CIELAB, relative to D65.
+    ///
+    /// Each face of the channel cube is tessellated into triangles and mapped to CIELAB,
+    /// and the volume is the sum of the tetrahedra joining each triangle to the origin.
+    pub fn gamut_volume(&self) -> f64 {
+        let mut volume = 0.0;
+        for axis in 0..3 {
+            for &level in &[0.0, 1.0] {
+                let point = |i: usize, j: usize| {
+                    let mut encoded = [0.0; 3];
+                    encoded[axis] = level;
+                    encoded[(axis + 1) % 3] = i as f64 / FACE_STEPS as f64;
+                    encoded[(axis + 2) % 3] = j as f64 / FACE_STEPS as f64;
+                    let lab = xyz_to_lab(&self.to_xyz(encoded), &WHITE);
+                    [lab.l, lab.a, lab.b]
+                };
+                // the parameter axes follow the axis cyclically, so the face normal
+                // points outward on the upper face and inward on the lower one
+                let sign = if level == 0.0 { -1.0 } else { 1.0 };
+                for i in 0..FACE_STEPS {
+                    for j in 0..FACE_STEPS {
+                        let (a, b, c, d) = (point(i, j), point(i + 1, j), point(i + 1, j + 1), point(i, j + 1));
+                        volume += sign * (triple(a, b, c) + triple(a, c, d)) / 6.0;
+                    }
+                }
+            }
+        }
+        volume.abs()
+    }
+
+    /// The percentage of the CIELAB gamut volume of `reference` that lies inside the gamut
+    /// of this space, estimated on a regular grid over the bounding box of `reference`.
+    pub fn volume_coverage(&self, reference: &RgbSpace) -> f64 {
+        let mut low = [f64::INFINITY; 3];
+        let mut high = [f64::NEG_INFINITY; 3];
+        for i in 0..=FACE_STEPS {
+            for j in 0..=FACE_STEPS {
+                let (u, v) = (i as f64 / FACE_STEPS as f64, j as f64 / FACE_STEPS as f64);
+                for encoded in &[[0.0, u, v], [1.0, u, v], [u, 0.0, v], [u, 1.0, v], [u, v, 0.0], [u, v, 1.0]] {
+                    let lab = xyz_to_lab(&reference.to_xyz(*encoded), &WHITE);
+                    for (k, &p) in [lab.l, lab.a, lab.b].iter().enumerate() {
+                        low[k] = low[k].min(p);
+                        high[k] = high[k].max(p);
+                    }
+                }
+            }
+        }
+        let (reference_matrix, matrix) = (from_xyz(reference), from_xyz(self));
+        let (mut inside, mut both) = (0usize, 0usize);
+        let step = |k: usize, n: usize| low[k] + (high[k] - low[k]) * (n as f64 + 0.5) / GRID_STEPS as f64;
+        for i in 0..GRID_STEPS {
+            for j in 0..GRID_STEPS {
+                for k in 0..GRID_STEPS {
+                    let xyz = lab_to_xyz(&Lab::new(step(0, i), step(1, j), step(2, k)), &WHITE);
+                    let xyz = [xyz.x, xyz.y, xyz.z];
+                    if contains(&reference_matrix, xyz) {
+                        inside += 1;
+                        if contains(&matrix, xyz) {
+                            both += 1;
+                        }
+                    }
+                }
+            }
+        }
+        100.0 * both as f64 / inside.max(1) as f64
+    }
+}
+
+/// The matrix of `RgbSpace::linear_from_xyz`, adaptation included, for XYZ from 0 to 100.
+fn from_xyz(space: &RgbSpace) -> [[f64; 3]; 3] {
+    let (r, g, b) = (
+        space.linear_to_xyz([1.0, 0.0, 0.0]),
+        space.linear_to_xyz([0.0, 1.0, 0.0]),
+        space.linear_to_xyz([0.0, 0.0, 1.0]),
+    );
+    inverse(&[[r.x, g.x, b.x], [r.y, g.y, b.y], [r.z, g.z, b.z]])
+}
+
+fn contains(matrix: &[[f64; 3]; 3], xyz: [f64; 3]) -> bool {
+    mul_vec(matrix, xyz).iter().all(|&c| (-1e-9..=1.0 + 1e-9).contains(&c))
+}
+
+fn triple(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> f64 {
+    a[0] * (b[1] * c[2] - b[2] * c[1])
+        + a[1] * (b[2] * c[0] - b[0] * c[2])
+        + a[2] * (b[0] * c[1] - b[1] * c[0])
+}
+
+fn polygon_area(points: &[(f64, f64)]) -> f64 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum::<f64>()
+        .abs()
+        / 2.0
+}
+
+/// Sutherland-Hodgman clipping of `subject` by the convex polygon `clip`.
+fn clip_polygon(subject: &[(f64, f64)], clip: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let orientation = {
+        let (a, b, c) = (clip[0], clip[1], clip[2]);
+        ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)).signum()
+    };
+    let mut output = subject.to_vec();
+    for i in 0..clip.len() {
+        let (a, b) = (clip[i], clip[(i + 1) % clip.len()]);
+        let side = |p: (f64, f64)| orientation * ((b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0));
+        let input = std::mem::take(&mut output);
+        for j in 0..input.len() {
+            let (p, q) = (input[j], input[(j + 1) % input.len()]);
+            let (sp, sq) = (side(p), side(q));
+            if sp >= 0.0 {
+                output.push(p);
+            }
+            if (sp >= 0.0) != (sq >= 0.0) {
+                let t = sp / (sp - sq);
+                output.push((p.0 + (q.0 - p.0) * t, p.1 + (q.1 - p.1) * t));
+            }
+        }
+        if output.is_empty() {
+            break;
+        }
+    }
+    output
+}
@@ -0,0 +1,234 @@
+use crate::{ FromRgb, HueInterpolation, Mix, Oklab, Rgb, ToRgb, mix_in };
+
+/// A space colors are interpolated in, for any color type that implements `Mix`.
+#[derive(Copy, Clone, Debug)]
+pub struct MixSpace {
+    mix: fn(&Rgb, &Rgb, f64, HueInterpolation) -> Rgb,
+}
+
+impl MixSpace {
+    /// Interpolate in the components of `S`.
+    pub fn of<S: Mix + FromRgb + ToRgb>() -> Self {
+        MixSpace { mix: mix_in::<S, Rgb> }
+    }
+
+    /// The color `t` of the way from `a` to `b`. Splines extrapolate, with `t` outside 0 to 1.
+    pub fn mix(&self, a: &Rgb, b: &Rgb, t: f64, hue: HueInterpolation) -> Rgb {
+        (self.mix)(a, b, t, hue)
+    }
+}
+
+/// A timing function shaping the progress along a segment, as in CSS.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// `cubic-bezier(0.25, 0.1, 0.25, 1)`
+    Ease,
+    /// `cubic-bezier(0.42, 0, 1, 1)`
+    EaseIn,
+    /// `cubic-bezier(0, 0, 0.58, 1)`
+    EaseOut,
+    /// `cubic-bezier(0.42, 0, 0.58, 1)`
+    EaseInOut,
+    /// A cubic Bézier from (0, 0) to (1, 1) with the control points (x1, y1) and (x2, y2),
+    /// where x1 and x2 are in 0 to 1.
+    CubicBezier(f64, f64, f64, f64),
+}
+
+impl Easing {
+    /// The eased progress at `t` (0 to 1).
+    pub fn apply(&self, t: f64) -> f64 {
+        let (x1, y1, x2, y2) = match *self {
+            Easing::Linear => return t,
+            Easing::Ease => (0.25, 0.1, 0.25, 1.0),
+            Easing::EaseIn => (0.42, 0.0, 1.0, 1.0),
+            Easing::EaseOut => (0.0, 0.0, 0.58, 1.0),
+            Easing::EaseInOut => (0.42, 0.0, 0.58, 1.0),
+            Easing::CubicBezier(x1, y1, x2, y2) => (x1, y1, x2, y2),
+        };
+        let bezier = |a: f64, b: f64, s: f64| 3.0 * a * s * (1.0 - s) * (1.0 - s) + 3.0 * b * s * s * (1.0 - s) + s * s * s;
+        // x is increasing in s when x1 and x2 are in 0 to 1
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..50 {
+            let mid = (lo + hi) / 2.0;
+            match bezier(x1, x2, mid) < t {
+                true => lo = mid,
+                false => hi = mid,
+            }
+        }
+        bezier(y1, y2, (lo + hi) / 2.0)
+    }
+}
+
+/// The path a gradient follows between two stops.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Path {
+    /// A straight line between the two stops.
+    #[default]
+    Linear,
+    /// A uniform Catmull-Rom spline through the stops, smooth across them.
+    CatmullRom,
+    /// A uniform cubic B-spline with the stops as control points. Smoother than
+    /// Catmull-Rom, it passes through the first and last stops but only near the others.
+    BSpline,
+}
+
+/// How a gradient goes from one stop to the next.
+#[derive(Copy, Clone, Debug)]
+pub struct Segment {
+    pub space: MixSpace,
+    pub hue: HueInterpolation,
+    pub easing: Easing,
+    pub path: Path,
+}
+
+impl Default for Segment {
+    /// A straight line in `Oklab`.
+    fn default() -> Self {
+        Segment {
+            space: MixSpace::of::<Oklab>(),
+            hue: HueInterpolation::Shorter,
+            easing: Easing::Linear,
+            path: Path::Linear,
+        }
+    }
+}
+
+/// A gradient through colors at positions, with its own interpolation for each segment
+/// between two stops.
+#[derive(Clone, Debug)]
+pub struct Gradient<T> {
+    /// The positions and colors of the stops, in increasing order of position. Two stops
+    /// at the same position make a hard transition.
+    pub stops: Vec<(f64, T)>,
+    /// The segments between consecutive stops, one fewer than the stops.
+    pub segments: Vec<Segment>,
+}
+
+impl<T: FromRgb + ToRgb> Gradient<T> {
+    /// Create a gradient through `stops`, sorted by position, with every segment following
+    /// `segment`. There must be at least one stop.
+    pub fn new(mut stops: Vec<(f64, T)>, segment: Segment) -> Self {
+        assert!(!stops.is_empty());
+        stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let segments = vec![segment; stops.len() - 1];
+        Self { stops, segments }
+    }
+
+    /// Create a gradient through `colors` spaced evenly from 0 to 1.
+    pub fn evenly(colors: Vec<T>, segment: Segment) -> Self {
+        let last = (colors.len().max(2) - 1) as f64;
+        let stops = colors.into_iter().enumerate().map(|(i, c)| (i as f64 / last, c)).collect();
+        Self::new(stops, segment)
+    }
+
+    /// The color at `position`. Positions before the first or after the last stop take
+    /// the color of that stop.
+    pub fn at(&self, position: f64) -> T {
+        let n = self.stops.len();
+        let i = self.stops.partition_point(|(p, _)| *p <= position);
+        if i == 0 {
+            return T::from_rgb(&self.stops[0].1.to_rgb());
+        }
+        if i == n {
+            return T::from_rgb(&self.stops[n - 1].1.to_rgb());
+        }
+        let ((from, _), (to, _)) = (&self.stops[i - 1], &self.stops[i]);
+        let segment = &self.segments[i - 1];
+        let t = segment.easing.apply((position - from) / (to - from));
+        let color = |k: usize| self.stops[k].1.to_rgb();
+        let mix = |a: &Rgb, b: &Rgb, t: f64| segment.space.mix(a, b, t, segment.hue);
+        let (p1, p2) = (color(i - 1), color(i));
+        let rgb = match segment.path {
+            Path::Linear => mix(&p1, &p2, t),
+            path => {
+                // the ends are reflected, so splines reach the first and last stops
+                let p0 = match i >= 2 {
+                    true => color(i - 2),
+                    false => mix(&p2, &p1, 2.0),
+                };
+                let p3 = match i + 1 < n {
+                    true => color(i + 1),
+                    false => mix(&p1, &p2, 2.0),
+                };
+                match path {
+                    Path::CatmullRom => {
+                        // Barry and Goldman's pyramid
+                        let a1 = mix(&p0, &p1, t + 1.0);
+                        let a2 = mix(&p1, &p2, t);
+                        let a3 = mix(&p2, &p3, t - 1.0);
+                        mix(&mix(&a1, &a2, (t + 1.0) / 2.0), &mix(&a2, &a3, t / 2.0), t)
+                    }
+                    _ => {
+                        // de Boor's algorithm with uniform knots
+                        let a1 = mix(&p0, &p1, (t + 2.0) / 3.0);
+                        let a2 = mix(&p1, &p2, (t + 1.0) / 3.0);
+                        let a3 = mix(&p2, &p3, t / 3.0);
+                        mix(&mix(&a1, &a2, (t + 1.0) / 2.0), &mix(&a2, &a3, t / 2.0), t)
+                    }
+                }
+            }
+        };
+        T::from_rgb(&rgb)
+    }
+
+    /// `count` colors at evenly spaced positions from the first to the last stop.
+    pub fn sample(&self, count: usize) -> Vec<T> {
+        let (first, last) = (self.stops[0].0, self.stops[self.stops.len() - 1].0);
+        (0..count)
+            .map(|i| {
+                let t = match count {
+                    1 => 0.0,
+                    _ => i as f64 / (count - 1) as f64,
+                };
+                self.at(first + (last - first) * t)
+            })
+            .collect()
+    }
+
+    /// `count` positions from the first to the last stop whose colors are separated by
+    /// equal `distance`, such as `|a, b| a.compare_cie2000(b)`, along the path.
+    ///
+    /// The cumulative arc length is measured between finely spaced samples, so hard stops
+    /// count with the difference across them.
+    pub fn even_positions<F: Fn(&T, &T) -> f64>(&self, count: usize, distance: F) -> Vec<f64> {
+        let (first, last) = (self.stops[0].0, self.stops[self.stops.len() - 1].0);
+        let steps = (count * ARC_SAMPLES).max(MIN_ARC_STEPS);
+        let positions: Vec<f64> = (0..=steps).map(|i| first + (last - first) * i as f64 / steps as f64).collect();
+        let colors: Vec<T> = positions.iter().map(|&p| self.at(p)).collect();
+        let mut lengths = vec![0.0];
+        for pair in colors.windows(2) {
+            let length = lengths[lengths.len() - 1] + distance(&pair[0], &pair[1]);
+            lengths.push(length);
+        }
+        let total = lengths[steps];
+        (0..count)
+            .map(|k| {
+                let target = match count {
+                    1 => 0.0,
+                    _ => total * k as f64 / (count - 1) as f64,
+                };
+                let i = lengths.partition_point(|&l| l < target).clamp(1, steps);
+                let (lo, hi) = (lengths[i - 1], lengths[i]);
+                let f = match hi > lo {
+                    true => ((target - lo) / (hi - lo)).clamp(0.0, 1.0),
+                    false => 0.0,
+                };
+                positions[i - 1] + (positions[i] - positions[i - 1]) * f
+            })
+            .collect()
+    }
+
+    /// `count` colors from the first to the last stop separated by equal `distance` along
+    /// the path, so the steps look equal rather than being equal in position.
+    pub fn sample_even<F: Fn(&T, &T) -> f64>(&self, count: usize, distance: F) -> Vec<T> {
+        self.even_positions(count, distance).into_iter().map(|p| self.at(p)).collect()
+    }
+}
+
+/// Arc length samples per requested color.
+const ARC_SAMPLES: usize = 16;
+
+/// Fewest arc length samples over the whole gradient.
+const MIN_ARC_STEPS: usize = 256;
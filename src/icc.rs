@@ -0,0 +1,725 @@
+use crate::{ Profile, RenderingIntent, Xyz };
+use crate::illuminant::D65_WHITE as WHITE;
+use crate::rgb_space::{ bradford, inverse, mul_vec, solve3 };
+use std::fmt;
+
+/// An error reading an ICC profile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IccError {
+    /// The data ends before a structure it declares.
+    Truncated,
+    /// The header does not carry the `acsp` signature.
+    InvalidSignature,
+    /// The major version is neither 2 nor 4.
+    UnsupportedVersion(u8),
+    /// A tag, named by its signature, has a type that cannot be used where it appears.
+    UnsupportedTagType([u8; 4], [u8; 4]),
+    /// The profile has neither an A2B table nor colorant and tone curve tags.
+    MissingTransform,
+    /// A value is outside the range the specification allows.
+    Malformed(&'static str),
+}
+
+impl fmt::Display for IccError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IccError::Truncated => write!(f, "the profile is truncated"),
+            IccError::InvalidSignature => write!(f, "the profile lacks the acsp signature"),
+            IccError::UnsupportedVersion(v) => write!(f, "unsupported profile version {}", v),
+            IccError::UnsupportedTagType(tag, kind) => write!(
+                f,
+                "unsupported type '{}' for tag '{}'",
+                String::from_utf8_lossy(kind),
+                String::from_utf8_lossy(tag)
+            ),
+            IccError::MissingTransform => write!(f, "the profile has no device to PCS transform"),
+            IccError::Malformed(what) => write!(f, "malformed profile: {}", what),
+        }
+    }
+}
+
+impl std::error::Error for IccError {}
+
+/// A tone reproduction curve.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Curve {
+    /// The identity.
+    Identity,
+    /// A pure power law.
+    Gamma(f64),
+    /// Values at equally spaced inputs from 0 to 1, linearly interpolated.
+    Table(Vec<f64>),
+    /// One of the five ICC parametric curves, with its parameters g, a, b, c, d, e, f.
+    Parametric(u16, [f64; 7]),
+}
+
+impl Curve {
+    /// Evaluate the curve at `x` (0 to 1).
+    pub fn eval(&self, x: f64) -> f64 {
+        let x = x.clamp(0.0, 1.0);
+        let pow = |v: f64, g: f64| v.max(0.0).powf(g);
+        match self {
+            Curve::Identity => x,
+            Curve::Gamma(g) => x.powf(*g),
+            Curve::Table(table) => {
+                let i = x * (table.len() - 1) as f64;
+                let i0 = (i.floor() as usize).min(table.len().saturating_sub(2));
+                match table.len() {
+                    1 => table[0],
+                    _ => table[i0] + (table[i0 + 1] - table[i0]) * (i - i0 as f64),
+                }
+            }
+            Curve::Parametric(kind, [g, a, b, c, d, e, f]) => match kind {
+                0 => pow(x, *g),
+                1 => if x >= -b / a { pow(a * x + b, *g) } else { 0.0 },
+                2 => if x >= -b / a { pow(a * x + b, *g) + c } else { *c },
+                3 => if x >= *d { pow(a * x + b, *g) } else { c * x },
+                _ => if x >= *d { pow(a * x + b, *g) + e } else { c * x + f },
+            },
+        }
+    }
+
+    /// The input giving `y`, found by bisection, assuming the curve is monotonic.
+    pub fn invert(&self, y: f64) -> f64 {
+        let rising = self.eval(1.0) >= self.eval(0.0);
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..48 {
+            let mid = (lo + hi) / 2.0;
+            if (self.eval(mid) < y) == rising {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        (lo + hi) / 2.0
+    }
+}
+
+/// A color lookup table with multilinear interpolation.
+#[derive(Clone, Debug)]
+struct Clut {
+    grid: Vec<usize>,
+    outputs: usize,
+    data: Vec<f64>,
+}
+
+impl Clut {
+    fn eval(&self, input: &[f64]) -> Vec<f64> {
+        let n = self.grid.len();
+        let mut base = vec![0usize; n];
+        let mut frac = vec![0.0; n];
+        let mut stride = vec![self.outputs; n];
+        for i in (0..n.saturating_sub(1)).rev() {
+            stride[i] = stride[i + 1] * self.grid[i + 1];
+        }
+        for i in 0..n {
+            let p = input[i].clamp(0.0, 1.0) * (self.grid[i] - 1) as f64;
+            base[i] = (p.floor() as usize).min(self.grid[i].saturating_sub(2));
+            frac[i] = p - base[i] as f64;
+        }
+        let mut out = vec![0.0; self.outputs];
+        for corner in 0..1usize << n {
+            let mut weight = 1.0;
+            let mut offset = 0;
+            for i in 0..n {
+                let upper = corner >> (n - 1 - i) & 1 == 1 && self.grid[i] > 1;
+                weight *= if upper { frac[i] } else { 1.0 - frac[i] };
+                offset += (base[i] + upper as usize) * stride[i];
+            }
+            if weight != 0.0 {
+                for (o, v) in out.iter_mut().zip(&self.data[offset..offset + self.outputs]) {
+                    *o += weight * v;
+                }
+            }
+        }
+        out
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Stage {
+    Curves(Vec<Curve>),
+    /// A 3×3 matrix followed by an offset.
+    Matrix([f64; 12]),
+    Clut(Clut),
+}
+
+/// How a pipeline encodes the profile connection space in 0 to 1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Encoding {
+    /// lut8Type, and the lutAtoB and lutBtoA types.
+    Standard,
+    /// lut16Type, with the ICC v2 Lab encoding.
+    Legacy,
+}
+
+#[derive(Clone, Debug)]
+struct Pipeline {
+    stages: Vec<Stage>,
+    encoding: Encoding,
+}
+
+impl Pipeline {
+    fn eval(&self, input: &[f64]) -> Vec<f64> {
+        let mut values = input.to_vec();
+        for stage in &self.stages {
+            values = match stage {
+                Stage::Curves(curves) => values.iter().zip(curves).map(|(v, c)| c.eval(*v)).collect(),
+                Stage::Matrix(m) => (0..3)
+                    .map(|r| m[3 * r] * values[0] + m[3 * r + 1] * values[1] + m[3 * r + 2] * values[2] + m[9 + r])
+                    .collect(),
+                Stage::Clut(clut) => clut.eval(&values),
+            };
+        }
+        values
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Model {
+    /// rXYZ, gXYZ, bXYZ columns and rTRC, gTRC, bTRC curves.
+    Matrix([[f64; 3]; 3], [Curve; 3]),
+    /// kTRC.
+    Gray(Curve),
+    None,
+}
+
+/// An ICC v2 or v4 profile.
+///
+/// Profiles convert between device channels (0 to 1) and the profile connection space,
+/// which is D50-relative; `IccProfile` adapts it with Bradford to the media-relative `Xyz`
+/// of the other conversions, so it can be used in a `Transform` like any `Profile`.
+#[derive(Clone, Debug)]
+pub struct IccProfile {
+    /// Major and minor version.
+    pub version: (u8, u8),
+    /// Device class signature, such as `mntr`, `prtr` or `scnr`.
+    pub class: [u8; 4],
+    /// Data color space signature, such as `RGB `, `CMYK` or `GRAY`.
+    pub color_space: [u8; 4],
+    /// Profile connection space signature, `XYZ ` or `Lab `.
+    pub pcs: [u8; 4],
+    /// The rendering intent from the header.
+    pub rendering_intent: u32,
+    /// The `desc` tag, in English when it is localized.
+    pub description: Option<String>,
+    channels: usize,
+    white: Xyz,
+    model: Model,
+    a2b: [Option<Pipeline>; 3],
+    b2a: [Option<Pipeline>; 3],
+}
+
+/// The D50 white of the profile connection space.
+pub(crate) const D50: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+fn bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8], IccError> {
+    data.get(offset..offset.checked_add(len).ok_or(IccError::Truncated)?).ok_or(IccError::Truncated)
+}
+
+fn u8_at(data: &[u8], offset: usize) -> Result<u8, IccError> {
+    Ok(bytes(data, offset, 1)?[0])
+}
+
+fn u16_at(data: &[u8], offset: usize) -> Result<u16, IccError> {
+    let b = bytes(data, offset, 2)?;
+    Ok(u16::from_be_bytes([b[0], b[1]]))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Result<u32, IccError> {
+    let b = bytes(data, offset, 4)?;
+    Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn s15_at(data: &[u8], offset: usize) -> Result<f64, IccError> {
+    Ok(u32_at(data, offset)? as i32 as f64 / 65536.0)
+}
+
+fn signature(data: &[u8], offset: usize) -> Result<[u8; 4], IccError> {
+    let b = bytes(data, offset, 4)?;
+    Ok([b[0], b[1], b[2], b[3]])
+}
+
+fn channels_of(color_space: &[u8; 4]) -> Result<usize, IccError> {
+    Ok(match color_space {
+        b"GRAY" => 1,
+        b"XYZ " | b"Lab " | b"Luv " | b"YCbr" | b"Yxy " | b"RGB " | b"HSV " | b"HLS " | b"CMY " => 3,
+        b"CMYK" => 4,
+        [a, b'C', b'L', b'R'] if a.is_ascii_hexdigit() => {
+            (*a as char).to_digit(16).filter(|&n| n >= 2).ok_or(IccError::Malformed("color space"))? as usize
+        }
+        _ => return Err(IccError::Malformed("color space")),
+    })
+}
+
+impl IccProfile {
+    /// Read a profile from its bytes.
+    pub fn parse(data: &[u8]) -> Result<Self, IccError> {
+        if data.len() < 132 {
+            return Err(IccError::Truncated);
+        }
+        if bytes(data, 36, 4)? != b"acsp" {
+            return Err(IccError::InvalidSignature);
+        }
+        let version = (u8_at(data, 8)?, u8_at(data, 9)? >> 4);
+        if version.0 != 2 && version.0 != 4 {
+            return Err(IccError::UnsupportedVersion(version.0));
+        }
+        let color_space = signature(data, 16)?;
+        let pcs = signature(data, 20)?;
+        if &pcs != b"XYZ " && &pcs != b"Lab " {
+            return Err(IccError::Malformed("connection space"));
+        }
+        let mut tags = Vec::new();
+        let count = u32_at(data, 128)? as usize;
+        for i in 0..count {
+            let entry = 132 + 12 * i;
+            let offset = u32_at(data, entry + 4)? as usize;
+            let size = u32_at(data, entry + 8)? as usize;
+            tags.push((signature(data, entry)?, bytes(data, offset, size)?));
+        }
+        let tag = |name: &[u8; 4]| tags.iter().find(|(sig, _)| sig == name).map(|(sig, d)| (*sig, *d));
+        let xyz = |name: &[u8; 4]| -> Result<Option<[f64; 3]>, IccError> {
+            match tag(name) {
+                Some((sig, d)) => {
+                    if bytes(d, 0, 4)? != b"XYZ " {
+                        return Err(IccError::UnsupportedTagType(sig, signature(d, 0)?));
+                    }
+                    Ok(Some([s15_at(d, 8)?, s15_at(d, 12)?, s15_at(d, 16)?]))
+                }
+                None => Ok(None),
+            }
+        };
+        let curve = |name: &[u8; 4]| -> Result<Option<Curve>, IccError> {
+            match tag(name) {
+                Some((sig, d)) => Ok(Some(parse_curve(sig, d)?.0)),
+                None => Ok(None),
+            }
+        };
+
+        let channels = channels_of(&color_space)?;
+        let model = match (xyz(b"rXYZ")?, xyz(b"gXYZ")?, xyz(b"bXYZ")?, curve(b"rTRC")?, curve(b"gTRC")?, curve(b"bTRC")?) {
+            (Some(r), Some(g), Some(b), Some(rc), Some(gc), Some(bc)) =>
+                Model::Matrix([[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]], [rc, gc, bc]),
+            _ => match curve(b"kTRC")? {
+                Some(k) => Model::Gray(k),
+                None => Model::None,
+            },
+        };
+        let lut = |name: &[u8; 4], device_input: bool| -> Result<Option<Pipeline>, IccError> {
+            let shape = if device_input { (3, channels) } else { (channels, 3) };
+            match tag(name) {
+                Some((sig, d)) => parse_lut(sig, d, shape, device_input && &pcs == b"XYZ ").map(Some),
+                None => Ok(None),
+            }
+        };
+        let a2b = [lut(b"A2B0", false)?, lut(b"A2B1", false)?, lut(b"A2B2", false)?];
+        let b2a = [lut(b"B2A0", true)?, lut(b"B2A1", true)?, lut(b"B2A2", true)?];
+        if a2b.iter().all(Option::is_none) && matches!(model, Model::None) {
+            return Err(IccError::MissingTransform);
+        }
+
+        // the measured media white: wtpt, with the chromatic adaptation of v4 undone
+        let mut white = xyz(b"wtpt")?.unwrap_or(D50);
+        if let Some((sig, d)) = tag(b"chad") {
+            if bytes(d, 0, 4)? != b"sf32" {
+                return Err(IccError::UnsupportedTagType(sig, signature(d, 0)?));
+            }
+            let mut chad = [[0.0; 3]; 3];
+            for (i, row) in chad.iter_mut().enumerate() {
+                for (j, v) in row.iter_mut().enumerate() {
+                    *v = s15_at(d, 8 + 4 * (3 * i + j))?;
+                }
+            }
+            white = mul_vec(&inverse(&chad), white);
+        }
+
+        Ok(Self {
+            version,
+            class: signature(data, 12)?,
+            color_space,
+            pcs,
+            rendering_intent: u32_at(data, 64)?,
+            description: match tag(b"desc") {
+                Some((sig, d)) => Some(parse_text(sig, d)?),
+                None => None,
+            },
+            channels,
+            white: Xyz::new(white[0] * 100.0, white[1] * 100.0, white[2] * 100.0),
+            model,
+            a2b,
+            b2a,
+        })
+    }
+
+    fn lab_pcs(&self) -> bool {
+        &self.pcs == b"Lab "
+    }
+
+    /// Convert device channels into media-relative `Xyz` with the table for `intent`,
+    /// falling back to the perceptual table and then to the matrix and tone curves.
+    pub fn device_to_xyz_with(&self, device: &[f64], intent: RenderingIntent) -> Xyz {
+        if let Some(pipeline) = pick(&self.a2b, intent) {
+            let out = pipeline.eval(device);
+            let pcs = decode_pcs([out[0], out[1], out[2]], self.lab_pcs(), pipeline.encoding);
+            return from_pcs(pcs, self.lab_pcs());
+        }
+        match &self.model {
+            Model::Matrix(m, curves) => {
+                let linear = [curves[0].eval(device[0]), curves[1].eval(device[1]), curves[2].eval(device[2])];
+                from_pcs(mul_vec(m, linear), false)
+            }
+            Model::Gray(k) => {
+                let y = k.eval(device[0]);
+                from_pcs([D50[0] * y, y, D50[2] * y], false)
+            }
+            Model::None => Xyz::new(0.0, 0.0, 0.0),
+        }
+    }
+
+    /// Convert media-relative `Xyz` into device channels with the table for `intent`,
+    /// falling back to the perceptual table, then to inverting the matrix and tone curves,
+    /// and finally to a numerical inversion of the A2B table.
+    pub fn xyz_to_device_with(&self, xyz: &Xyz, intent: RenderingIntent) -> Vec<f64> {
+        if let Some(pipeline) = pick(&self.b2a, intent) {
+            let pcs = encode_pcs(to_pcs(xyz, self.lab_pcs()), self.lab_pcs(), pipeline.encoding);
+            return pipeline.eval(&pcs);
+        }
+        match &self.model {
+            Model::Matrix(m, curves) => {
+                let linear = mul_vec(&inverse(m), to_pcs(xyz, false));
+                linear.iter().zip(curves).map(|(v, c)| c.invert(*v)).collect()
+            }
+            Model::Gray(k) => vec![k.invert(xyz.y / WHITE.y)],
+            Model::None => self.invert(xyz, intent),
+        }
+    }
+
+    /// Projected Gauss-Newton on the A2B table, with the minimum norm step when there
+    /// are more than three channels.
+    fn invert(&self, xyz: &Xyz, intent: RenderingIntent) -> Vec<f64> {
+        let target = [xyz.x, xyz.y, xyz.z];
+        let eval = |d: &[f64]| {
+            let x = self.device_to_xyz_with(d, intent);
+            [x.x, x.y, x.z]
+        };
+        let mut device = vec![0.5; self.channels];
+        for _ in 0..50 {
+            let current = eval(&device);
+            let r = [target[0] - current[0], target[1] - current[1], target[2] - current[2]];
+            if r.iter().map(|v| v * v).sum::<f64>() < 1e-12 {
+                break;
+            }
+            let mut jacobian = vec![[0.0; 3]; self.channels];
+            for (k, column) in jacobian.iter_mut().enumerate() {
+                let h = if device[k] > 0.5 { -1e-4 } else { 1e-4 };
+                let mut d = device.clone();
+                d[k] += h;
+                let shifted = eval(&d);
+                for j in 0..3 {
+                    column[j] = (shifted[j] - current[j]) / h;
+                }
+            }
+            let mut jjt = [[1e-9; 3]; 3];
+            for column in &jacobian {
+                for i in 0..3 {
+                    for j in 0..3 {
+                        jjt[i][j] += column[i] * column[j];
+                    }
+                }
+            }
+            let y = match solve3(&jjt, &r) {
+                Some(y) => y,
+                None => break,
+            };
+            for (d, column) in device.iter_mut().zip(&jacobian) {
+                *d = (*d + column[0] * y[0] + column[1] * y[1] + column[2] * y[2]).clamp(0.0, 1.0);
+            }
+        }
+        device
+    }
+}
+
+impl Profile for IccProfile {
+    fn channels(&self) -> usize {
+        self.channels
+    }
+
+    fn device_to_xyz(&self, device: &[f64]) -> Xyz {
+        self.device_to_xyz_with(device, RenderingIntent::RelativeColorimetric)
+    }
+
+    fn xyz_to_device(&self, xyz: &Xyz) -> Vec<f64> {
+        self.xyz_to_device_with(xyz, RenderingIntent::RelativeColorimetric)
+    }
+
+    fn media_white(&self) -> Xyz {
+        self.white
+    }
+}
+
+fn pick(tables: &[Option<Pipeline>; 3], intent: RenderingIntent) -> Option<&Pipeline> {
+    let index = match intent {
+        RenderingIntent::Perceptual => 0,
+        RenderingIntent::RelativeColorimetric | RenderingIntent::AbsoluteColorimetric => 1,
+        RenderingIntent::Saturation => 2,
+    };
+    tables[index].as_ref().or(tables[0].as_ref())
+}
+
+/// Convert connection space values, D50-relative XYZ with a white luminance of 1 or
+/// CIELAB, into media-relative `Xyz`.
+pub(crate) fn from_pcs(pcs: [f64; 3], lab: bool) -> Xyz {
+    let xyz = match lab {
+        true => {
+            let f = |t: f64| if t > 6.0 / 29.0 { t * t * t } else { 108.0 / 841.0 * (t - 4.0 / 29.0) };
+            let y = (pcs[0] + 16.0) / 116.0;
+            [D50[0] * f(y + pcs[1] / 500.0), f(y), D50[2] * f(y - pcs[2] / 200.0)]
+        }
+        false => pcs,
+    };
+    let white = [WHITE.x / 100.0, WHITE.y / 100.0, WHITE.z / 100.0];
+    let v = mul_vec(&bradford(D50, white), xyz);
+    Xyz::new(v[0] * 100.0, v[1] * 100.0, v[2] * 100.0)
+}
+
+pub(crate) fn to_pcs(xyz: &Xyz, lab: bool) -> [f64; 3] {
+    let white = [WHITE.x / 100.0, WHITE.y / 100.0, WHITE.z / 100.0];
+    let v = mul_vec(&bradford(white, D50), [xyz.x / 100.0, xyz.y / 100.0, xyz.z / 100.0]);
+    match lab {
+        true => {
+            let f = |t: f64| if t > 216.0 / 24389.0 { t.cbrt() } else { 841.0 / 108.0 * t + 4.0 / 29.0 };
+            let (x, y, z) = (f(v[0] / D50[0]), f(v[1]), f(v[2] / D50[2]));
+            [116.0 * y - 16.0, 500.0 * (x - y), 200.0 * (y - z)]
+        }
+        false => v,
+    }
+}
+
+/// Scale of the legacy 16-bit Lab encoding, where 0xFF00 is the top of the range.
+const LEGACY: f64 = 65535.0 / 65280.0;
+
+/// Scale of the XYZ encoding, where 0x8000 is 1.
+const XYZ_SCALE: f64 = 65535.0 / 32768.0;
+
+fn decode_pcs(v: [f64; 3], lab: bool, encoding: Encoding) -> [f64; 3] {
+    match (lab, encoding) {
+        (true, Encoding::Standard) => [v[0] * 100.0, v[1] * 255.0 - 128.0, v[2] * 255.0 - 128.0],
+        (true, Encoding::Legacy) => [
+            v[0] * LEGACY * 100.0,
+            v[1] * LEGACY * 255.0 - 128.0,
+            v[2] * LEGACY * 255.0 - 128.0,
+        ],
+        (false, _) => [v[0] * XYZ_SCALE, v[1] * XYZ_SCALE, v[2] * XYZ_SCALE],
+    }
+}
+
+fn encode_pcs(v: [f64; 3], lab: bool, encoding: Encoding) -> [f64; 3] {
+    match (lab, encoding) {
+        (true, Encoding::Standard) => [v[0] / 100.0, (v[1] + 128.0) / 255.0, (v[2] + 128.0) / 255.0],
+        (true, Encoding::Legacy) => [
+            v[0] / 100.0 / LEGACY,
+            (v[1] + 128.0) / 255.0 / LEGACY,
+            (v[2] + 128.0) / 255.0 / LEGACY,
+        ],
+        (false, _) => [v[0] / XYZ_SCALE, v[1] / XYZ_SCALE, v[2] / XYZ_SCALE],
+    }
+}
+
+/// Read a `desc`, `mluc` or `text` element. Of a `mluc` element the English record is
+/// read, or the first one when there is none.
+fn parse_text(tag: [u8; 4], d: &[u8]) -> Result<String, IccError> {
+    let ascii = |text: &[u8]| String::from_utf8_lossy(text.split(|&c| c == 0).next().unwrap_or(&[])).into_owned();
+    match bytes(d, 0, 4)? {
+        b"desc" => Ok(ascii(bytes(d, 12, u32_at(d, 8)? as usize)?)),
+        b"text" => Ok(ascii(bytes(d, 8, d.len().saturating_sub(8))?)),
+        b"mluc" => {
+            let count = u32_at(d, 8)? as usize;
+            let size = u32_at(d, 12)? as usize;
+            if count == 0 {
+                return Ok(String::new());
+            }
+            if size < 12 {
+                return Err(IccError::Malformed("localized text record"));
+            }
+            let record = (0..count)
+                .map_while(|i| i.checked_mul(size)?.checked_add(16))
+                .take_while(|&r| r < d.len())
+                .find(|&r| d.get(r..r.saturating_add(2)) == Some(b"en"))
+                .unwrap_or(16);
+            let text = bytes(d, u32_at(d, record + 8)? as usize, u32_at(d, record + 4)? as usize)?;
+            let units: Vec<u16> = text.chunks(2).filter(|c| c.len() == 2).map(|c| u16::from_be_bytes([c[0], c[1]])).collect();
+            Ok(String::from_utf16_lossy(&units))
+        }
+        _ => Err(IccError::UnsupportedTagType(tag, signature(d, 0)?)),
+    }
+}
+
+/// Read a `curv` or `para` element, returning it with its size padded to 4 bytes.
+fn parse_curve(tag: [u8; 4], d: &[u8]) -> Result<(Curve, usize), IccError> {
+    match bytes(d, 0, 4)? {
+        b"curv" => {
+            let n = u32_at(d, 8)? as usize;
+            let curve = match n {
+                0 => Curve::Identity,
+                1 => Curve::Gamma(u16_at(d, 12)? as f64 / 256.0),
+                _ => Curve::Table(
+                    (0..n).map(|i| Ok(u16_at(d, 12 + 2 * i)? as f64 / 65535.0)).collect::<Result<_, IccError>>()?,
+                ),
+            };
+            Ok((curve, (12 + 2 * n + 3) & !3))
+        }
+        b"para" => {
+            let kind = u16_at(d, 8)?;
+            let count = match kind {
+                0 => 1,
+                1 => 3,
+                2 => 4,
+                3 => 5,
+                4 => 7,
+                _ => return Err(IccError::Malformed("parametric curve type")),
+            };
+            let mut p = [0.0; 7];
+            for (i, v) in p.iter_mut().take(count).enumerate() {
+                *v = s15_at(d, 12 + 4 * i)?;
+            }
+            if (kind == 1 || kind == 2) && p[1] == 0.0 {
+                return Err(IccError::Malformed("parametric curve"));
+            }
+            Ok((Curve::Parametric(kind, p), 12 + 4 * count))
+        }
+        _ => Err(IccError::UnsupportedTagType(tag, signature(d, 0)?)),
+    }
+}
+
+fn parse_curves(tag: [u8; 4], d: &[u8], offset: usize, n: usize) -> Result<Vec<Curve>, IccError> {
+    let mut curves = Vec::with_capacity(n);
+    let mut at = offset;
+    for _ in 0..n {
+        let (curve, size) = parse_curve(tag, d.get(at..).ok_or(IccError::Truncated)?)?;
+        curves.push(curve);
+        at += size;
+    }
+    Ok(curves)
+}
+
+fn parse_clut(d: &[u8], grid: Vec<usize>, outputs: usize, data: usize, precision: usize) -> Result<Clut, IccError> {
+    if grid.iter().any(|&g| g < 2) {
+        return Err(IccError::Malformed("lookup table grid"));
+    }
+    let count = grid
+        .iter()
+        .try_fold(outputs, |n, &g| n.checked_mul(g))
+        .ok_or(IccError::Malformed("lookup table grid"))?;
+    let values = (0..count)
+        .map(|i| match precision {
+            1 => Ok(u8_at(d, data + i)? as f64 / 255.0),
+            _ => Ok(u16_at(d, data + 2 * i)? as f64 / 65535.0),
+        })
+        .collect::<Result<_, IccError>>()?;
+    Ok(Clut { grid, outputs, data: values })
+}
+
+fn table_curves(d: &[u8], offset: usize, n: usize, entries: usize, precision: usize) -> Result<Vec<Curve>, IccError> {
+    (0..n)
+        .map(|c| {
+            let start = offset + c * entries * precision;
+            let table = (0..entries)
+                .map(|i| match precision {
+                    1 => Ok(u8_at(d, start + i)? as f64 / 255.0),
+                    _ => Ok(u16_at(d, start + 2 * i)? as f64 / 65535.0),
+                })
+                .collect::<Result<_, IccError>>()?;
+            Ok(Curve::Table(table))
+        })
+        .collect()
+}
+
+/// Read a `mft1`, `mft2`, `mAB ` or `mBA ` table with the inputs and outputs of `shape`.
+/// The matrix of the first two only applies when their input is the XYZ connection space,
+/// as `xyz_input` says.
+fn parse_lut(tag: [u8; 4], d: &[u8], shape: (usize, usize), xyz_input: bool) -> Result<Pipeline, IccError> {
+    let kind = signature(d, 0)?;
+    let inputs = u8_at(d, 8)? as usize;
+    let outputs = u8_at(d, 9)? as usize;
+    if (inputs, outputs) != shape {
+        return Err(IccError::Malformed("lookup table channels"));
+    }
+    match &kind {
+        b"mft1" | b"mft2" => {
+            let grid = u8_at(d, 10)? as usize;
+            let mut matrix = [0.0; 12];
+            for (i, v) in matrix.iter_mut().take(9).enumerate() {
+                *v = s15_at(d, 12 + 4 * i)?;
+            }
+            let (precision, input_entries, output_entries, tables) = match &kind {
+                b"mft1" => (1, 256, 256, 48),
+                _ => (2, u16_at(d, 48)? as usize, u16_at(d, 50)? as usize, 52),
+            };
+            if input_entries < 2 || output_entries < 2 {
+                return Err(IccError::Malformed("lookup table entries"));
+            }
+            let clut_offset = tables + inputs * input_entries * precision;
+            let clut = parse_clut(d, vec![grid; inputs], outputs, clut_offset, precision)?;
+            let output_offset = clut_offset + clut.data.len() * precision;
+            let mut stages = Vec::new();
+            let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+            if xyz_input && inputs == 3 && matrix[..9] != identity {
+                stages.push(Stage::Matrix(matrix));
+            }
+            stages.push(Stage::Curves(table_curves(d, tables, inputs, input_entries, precision)?));
+            stages.push(Stage::Clut(clut));
+            stages.push(Stage::Curves(table_curves(d, output_offset, outputs, output_entries, precision)?));
+            let encoding = if precision == 1 { Encoding::Standard } else { Encoding::Legacy };
+            Ok(Pipeline { stages, encoding })
+        }
+        b"mAB " | b"mBA " => {
+            let offset = |i: usize| -> Result<usize, IccError> { Ok(u32_at(d, 12 + 4 * i)? as usize) };
+            let (b, matrix, m, clut, a) = (offset(0)?, offset(1)?, offset(2)?, offset(3)?, offset(4)?);
+            // the B curves face the connection space, the A curves the device
+            let a_to_b = &kind == b"mAB ";
+            let device = if a_to_b { inputs } else { outputs };
+            let a_curves = if a != 0 { Some(parse_curves(tag, d, a, device)?) } else { None };
+            let m_curves = if m != 0 { Some(parse_curves(tag, d, m, 3)?) } else { None };
+            let b_curves = parse_curves(tag, d, b, 3)?;
+            let matrix = if matrix != 0 {
+                let mut values = [0.0; 12];
+                for (i, v) in values.iter_mut().enumerate() {
+                    *v = s15_at(d, matrix + 4 * i)?;
+                }
+                Some(values)
+            } else {
+                None
+            };
+            let clut = if clut != 0 {
+                let grid = (0..inputs).map(|i| Ok(u8_at(d, clut + i)? as usize)).collect::<Result<_, IccError>>()?;
+                let precision = u8_at(d, clut + 16)? as usize;
+                if precision != 1 && precision != 2 {
+                    return Err(IccError::Malformed("lookup table precision"));
+                }
+                Some(parse_clut(d, grid, outputs, clut + 20, precision)?)
+            } else {
+                None
+            };
+            if clut.is_none() && inputs != outputs {
+                return Err(IccError::Malformed("lookup table channels"));
+            }
+            let mut stages = Vec::new();
+            if a_to_b {
+                stages.extend(a_curves.map(Stage::Curves));
+                stages.extend(clut.map(Stage::Clut));
+                stages.extend(m_curves.map(Stage::Curves));
+                stages.extend(matrix.map(Stage::Matrix));
+                stages.push(Stage::Curves(b_curves));
+            } else {
+                stages.push(Stage::Curves(b_curves));
+                stages.extend(matrix.map(Stage::Matrix));
+                stages.extend(m_curves.map(Stage::Curves));
+                stages.extend(clut.map(Stage::Clut));
+                stages.extend(a_curves.map(Stage::Curves));
+            }
+            Ok(Pipeline { stages, encoding: Encoding::Standard })
+        }
+        _ => Err(IccError::UnsupportedTagType(tag, kind)),
+    }
+}
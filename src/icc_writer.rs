@@ -0,0 +1,173 @@
+use crate::{ RgbSpace, TransferFunction };
+use crate::icc::D50;
+use crate::md5::md5;
+use crate::rgb_space::{ REC2020_ALPHA, REC2020_BETA, bradford, mul, white_xyz };
+
+/// Version of the ICC specification a profile is written for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IccVersion {
+    /// Version 2.1, for older readers: plain text tags, sampled tone curves and the
+    /// absolute white point.
+    V2,
+    /// Version 4.3, with localized text, parametric tone curves, a D50 white point with
+    /// its `chad` adaptation and an MD5 profile ID.
+    V4,
+}
+
+/// Entries of the tone curves sampled for version 2 profiles.
+const CURVE_ENTRIES: usize = 1024;
+
+fn s15(v: f64) -> [u8; 4] {
+    ((v * 65536.0).round() as i32).to_be_bytes()
+}
+
+fn xyz_tag(v: [f64; 3]) -> Vec<u8> {
+    [&b"XYZ \0\0\0\0"[..], &s15(v[0]), &s15(v[1]), &s15(v[2])].concat()
+}
+
+fn text_tag(text: &str, version: IccVersion) -> Vec<u8> {
+    match version {
+        IccVersion::V4 => {
+            let utf16: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+            let mut tag = b"mluc\0\0\0\0".to_vec();
+            tag.extend_from_slice(&1u32.to_be_bytes());
+            tag.extend_from_slice(&12u32.to_be_bytes());
+            tag.extend_from_slice(b"enUS");
+            tag.extend_from_slice(&(utf16.len() as u32).to_be_bytes());
+            tag.extend_from_slice(&28u32.to_be_bytes());
+            tag.extend(utf16);
+            tag
+        }
+        IccVersion::V2 => {
+            let ascii: Vec<u8> = text.chars().map(|c| if c.is_ascii() { c as u8 } else { b'?' }).collect();
+            [&b"text\0\0\0\0"[..], &ascii, &[0]].concat()
+        }
+    }
+}
+
+/// The version 2 `desc` tag, with an ASCII description and empty Unicode and Script Code ones.
+fn description_tag(text: &str) -> Vec<u8> {
+    let ascii: Vec<u8> = text.chars().map(|c| if c.is_ascii() { c as u8 } else { b'?' }).collect();
+    let mut tag = b"desc\0\0\0\0".to_vec();
+    tag.extend_from_slice(&(ascii.len() as u32 + 1).to_be_bytes());
+    tag.extend(ascii);
+    tag.push(0);
+    tag.extend_from_slice(&[0; 8]);
+    tag.extend_from_slice(&[0; 3 + 67]);
+    tag
+}
+
+fn curve_tag(transfer: &TransferFunction, version: IccVersion) -> Vec<u8> {
+    let para = |kind: u16, params: &[f64]| {
+        let mut tag = [&b"para\0\0\0\0"[..], &kind.to_be_bytes(), &[0, 0]].concat();
+        for &p in params {
+            tag.extend_from_slice(&s15(p));
+        }
+        tag
+    };
+    match (transfer, version) {
+        (TransferFunction::Linear, _) => b"curv\0\0\0\0\0\0\0\0".to_vec(),
+        (TransferFunction::Gamma(g), IccVersion::V2) => {
+            let mut tag = b"curv\0\0\0\0\0\0\0\x01".to_vec();
+            tag.extend_from_slice(&((g * 256.0).round() as u16).to_be_bytes());
+            tag
+        }
+        (TransferFunction::Gamma(g), IccVersion::V4) => para(0, &[*g]),
+        (TransferFunction::Srgb, IccVersion::V4) => {
+            para(3, &[2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045])
+        }
+        (TransferFunction::Rec2020, IccVersion::V4) => para(3, &[
+            1.0 / 0.45,
+            1.0 / REC2020_ALPHA,
+            (REC2020_ALPHA - 1.0) / REC2020_ALPHA,
+            1.0 / 4.5,
+            4.5 * REC2020_BETA,
+        ]),
+        (TransferFunction::ProPhoto, IccVersion::V4) => para(3, &[1.8, 1.0, 0.0, 1.0 / 16.0, 16.0 / 512.0]),
+        (_, IccVersion::V2) => {
+            let mut tag = b"curv\0\0\0\0".to_vec();
+            tag.extend_from_slice(&(CURVE_ENTRIES as u32).to_be_bytes());
+            for i in 0..CURVE_ENTRIES {
+                let v = transfer.decode(i as f64 / (CURVE_ENTRIES - 1) as f64);
+                tag.extend_from_slice(&((v.clamp(0.0, 1.0) * 65535.0).round() as u16).to_be_bytes());
+            }
+            tag
+        }
+    }
+}
+
+impl RgbSpace {
+    /// Serialize the space as an ICC display profile with matrix and tone curves.
+    pub fn to_icc(&self, version: IccVersion, description: &str, copyright: &str) -> Vec<u8> {
+        let white = white_xyz(self.white);
+        let adaptation = bradford(white, D50);
+        let colorants = mul(&adaptation, &self.to_xyz_matrix());
+        let column = |i: usize| [colorants[0][i], colorants[1][i], colorants[2][i]];
+        let curve = curve_tag(&self.transfer, version);
+
+        let mut tags: Vec<([u8; 4], Vec<u8>)> = vec![
+            (*b"desc", match version {
+                IccVersion::V4 => text_tag(description, version),
+                IccVersion::V2 => description_tag(description),
+            }),
+            (*b"cprt", text_tag(copyright, version)),
+        ];
+        match version {
+            IccVersion::V4 => {
+                let mut chad = b"sf32\0\0\0\0".to_vec();
+                for v in adaptation.iter().flatten() {
+                    chad.extend_from_slice(&s15(*v));
+                }
+                tags.push((*b"wtpt", xyz_tag(D50)));
+                tags.push((*b"chad", chad));
+            }
+            IccVersion::V2 => tags.push((*b"wtpt", xyz_tag(white))),
+        }
+        tags.push((*b"rXYZ", xyz_tag(column(0))));
+        tags.push((*b"gXYZ", xyz_tag(column(1))));
+        tags.push((*b"bXYZ", xyz_tag(column(2))));
+        tags.push((*b"rTRC", curve));
+
+        // the three tone curves share one element
+        let names: Vec<[u8; 4]> = tags.iter().map(|(name, _)| *name).chain(vec![*b"gTRC", *b"bTRC"]).collect();
+        let mut table = (names.len() as u32).to_be_bytes().to_vec();
+        let mut data = Vec::new();
+        let start = 128 + 4 + 12 * names.len();
+        for (name, tag) in &tags {
+            let entry = [&name[..], &((start + data.len()) as u32).to_be_bytes(), &(tag.len() as u32).to_be_bytes()].concat();
+            table.extend(entry);
+            data.extend_from_slice(tag);
+            while data.len() % 4 != 0 {
+                data.push(0);
+            }
+        }
+        let shared = table[table.len() - 8..].to_vec();
+        for name in &[*b"gTRC", *b"bTRC"] {
+            table.extend_from_slice(name);
+            table.extend_from_slice(&shared);
+        }
+
+        let mut header = vec![0u8; 128];
+        header[8..12].copy_from_slice(match version {
+            IccVersion::V2 => &[2, 0x10, 0, 0],
+            IccVersion::V4 => &[4, 0x30, 0, 0],
+        });
+        header[12..16].copy_from_slice(b"mntr");
+        header[16..20].copy_from_slice(b"RGB ");
+        header[20..24].copy_from_slice(b"XYZ ");
+        header[36..40].copy_from_slice(b"acsp");
+        header[68..72].copy_from_slice(&s15(D50[0]));
+        header[72..76].copy_from_slice(&s15(D50[1]));
+        header[76..80].copy_from_slice(&s15(D50[2]));
+        let mut profile = [header, table, data].concat();
+        let size = (profile.len() as u32).to_be_bytes();
+        profile[0..4].copy_from_slice(&size);
+        if version == IccVersion::V4 {
+            // the ID is the digest with the flags, rendering intent and ID fields zeroed,
+            // which they already are
+            let id = md5(&profile);
+            profile[84..100].copy_from_slice(&id);
+        }
+        profile
+    }
+}
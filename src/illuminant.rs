@@ -0,0 +1,317 @@
+use crate::{ Observer, Spectrum, Xyz };
+use crate::planckian::planck;
+use crate::temperature::daylight_xy;
+use std::sync::LazyLock;
+
+/// The CIE 1931 2° white point of illuminant D65, used as the reference white
+/// of the `Lab`, `Luv` and `Uvw` conversions, integrated from the 5 nm tables.
+pub(crate) static D65_WHITE: LazyLock<Xyz> = LazyLock::new(|| Illuminant::D65.white_point(Observer::Cie1931));
+
+/// A CIE standard illuminant.
+///
+/// The LED series of CIE 15:2018 is not built in; its published tables can be loaded with
+/// `Spectrum::uniform` and integrated the same way.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Illuminant {
+    /// Incandescent tungsten light, a blackbody at 2856K.
+    A,
+    /// Horizon daylight, 5003K.
+    D50,
+    /// Mid-morning daylight, 5503K.
+    D55,
+    /// Noon daylight, 6504K.
+    D65,
+    /// North sky daylight, 7504K.
+    D75,
+    /// The equal energy illuminant.
+    E,
+    /// Daylight fluorescent, 6430K.
+    F1,
+    /// Cool white fluorescent, 4230K.
+    F2,
+    /// White fluorescent, 3450K.
+    F3,
+    /// Warm white fluorescent, 2940K.
+    F4,
+    /// Daylight fluorescent, 6350K.
+    F5,
+    /// Lite white fluorescent, 4150K.
+    F6,
+    /// Broadband daylight fluorescent, 6500K.
+    F7,
+    /// Broadband fluorescent simulating D50, 5000K.
+    F8,
+    /// Broadband cool white deluxe fluorescent, 4150K.
+    F9,
+    /// Narrow tri-band fluorescent, 5000K.
+    F10,
+    /// Narrow tri-band fluorescent, 4000K.
+    F11,
+    /// Narrow tri-band fluorescent, 3000K.
+    F12,
+}
+
+impl Illuminant {
+    /// The relative spectral power distribution, 380 nm to 780 nm in 5 nm steps.
+    pub fn spectrum(&self) -> Spectrum {
+        let fluorescent = |i: usize| Spectrum::uniform(380.0, 5.0, FLUORESCENT[i].to_vec());
+        match self {
+            Illuminant::A => Spectrum::from_fn(380.0, 780.0, 5.0, |nm| {
+                // CIE 15 definition, with the radiation constant of the original standard
+                let c: f64 = 1.435e7 / 2848.0;
+                100.0 * (560.0 / nm).powf(5.0) * ((c / 560.0).exp() - 1.0) / ((c / nm).exp() - 1.0)
+            }),
+            Illuminant::D50 => Spectrum::daylight(5000.0 * 1.4388 / 1.4380),
+            Illuminant::D55 => Spectrum::daylight(5500.0 * 1.4388 / 1.4380),
+            Illuminant::D65 => Spectrum::uniform(380.0, 5.0, D65.to_vec()),
+            Illuminant::D75 => Spectrum::daylight(7500.0 * 1.4388 / 1.4380),
+            Illuminant::E => Spectrum::from_fn(380.0, 780.0, 5.0, |_| 100.0),
+            Illuminant::F1 => fluorescent(0),
+            Illuminant::F2 => fluorescent(1),
+            Illuminant::F3 => fluorescent(2),
+            Illuminant::F4 => fluorescent(3),
+            Illuminant::F5 => fluorescent(4),
+            Illuminant::F6 => fluorescent(5),
+            Illuminant::F7 => fluorescent(6),
+            Illuminant::F8 => fluorescent(7),
+            Illuminant::F9 => fluorescent(8),
+            Illuminant::F10 => fluorescent(9),
+            Illuminant::F11 => fluorescent(10),
+            Illuminant::F12 => fluorescent(11),
+        }
+    }
+
+    /// The white point for `observer`, normalized to a luminance of 100.
+    pub fn white_point(&self, observer: Observer) -> Xyz {
+        self.spectrum().to_xyz(observer)
+    }
+}
+
+impl Spectrum {
+    /// The relative spectral power distribution of CIE D-series daylight with a correlated
+    /// color temperature of `kelvin`, 380 nm to 780 nm in 5 nm steps. The daylight locus is
+    /// defined from 4000K to 25000K, and temperatures outside that range are clamped to it.
+    pub fn daylight(kelvin: f64) -> Self {
+        let (x, y) = daylight_xy(kelvin);
+        let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+        // CIE 15 rounds the basis weights to three decimals
+        let m1 = ((-1.3515 - 1.7703 * x + 5.9114 * y) / m * 1000.0).round() / 1000.0;
+        let m2 = ((0.0300 - 31.4424 * x + 30.0717 * y) / m * 1000.0).round() / 1000.0;
+        let basis = |i: usize| {
+            let s = DAYLIGHT_BASIS[i];
+            s[0] + m1 * s[1] + m2 * s[2]
+        };
+        // the basis is tabulated every 10 nm and linearly interpolated in between
+        let values = (0..81)
+            .map(|i| match i % 2 {
+                0 => basis(i / 2),
+                _ => (basis(i / 2) + basis(i / 2 + 1)) / 2.0,
+            })
+            .collect();
+        Spectrum::uniform(380.0, 5.0, values)
+    }
+
+    /// The relative spectral power distribution of a blackbody at `kelvin`,
+    /// 380 nm to 780 nm in 5 nm steps and normalized to 100 at 560 nm.
+    pub fn blackbody(kelvin: f64) -> Self {
+        let white = planck(560.0, kelvin);
+        Spectrum::from_fn(380.0, 780.0, 5.0, |nm| 100.0 * planck(nm, kelvin) / white)
+    }
+}
+
+/// CIE standard illuminant D65, 380 nm to 780 nm in 5 nm steps.
+const D65: [f64; 81] = [
+    49.9755, 52.3118, 54.6482, 68.7015, 82.7549, 87.1204, 91.486, 92.4589,
+    93.4318, 90.057, 86.6823, 95.7736, 104.865, 110.936, 117.008, 117.41,
+    117.812, 116.336, 114.861, 115.392, 115.923, 112.367, 108.811, 109.082,
+    109.354, 108.578, 107.802, 106.296, 104.79, 106.239, 107.689, 106.047,
+    104.405, 104.225, 104.046, 102.023, 100.0, 98.1671, 96.3342, 96.0611,
+    95.788, 92.2368, 88.6856, 89.3459, 90.0062, 89.8026, 89.5991, 88.6489,
+    87.6987, 85.4936, 83.2886, 83.4939, 83.6992, 81.863, 80.0268, 80.1207,
+    80.2146, 81.2462, 82.2778, 80.281, 78.2842, 74.0027, 69.7213, 70.6652,
+    71.6091, 72.979, 74.349, 67.9765, 61.604, 65.7448, 69.8856, 72.4863,
+    75.087, 69.3398, 63.5927, 55.0054, 46.4182, 56.6118, 66.8054, 65.0941,
+    63.3828,
+];
+
+/// CIE daylight basis functions (S0, S1, S2), 380 nm to 780 nm in 10 nm steps.
+const DAYLIGHT_BASIS: [[f64; 3]; 41] = [
+    [63.4, 38.5, 3.0],
+    [65.8, 35.0, 1.2],
+    [94.8, 43.4, -1.1],
+    [104.8, 46.3, -0.5],
+    [105.9, 43.9, -0.7],
+    [96.8, 37.1, -1.2],
+    [113.9, 36.7, -2.6],
+    [125.6, 35.9, -2.9],
+    [125.5, 32.6, -2.8],
+    [121.3, 27.9, -2.6],
+    [121.3, 24.3, -2.6],
+    [113.5, 20.1, -1.8],
+    [113.1, 16.2, -1.5],
+    [110.8, 13.2, -1.3],
+    [106.5, 8.6, -1.2],
+    [108.8, 6.1, -1.0],
+    [105.3, 4.2, -0.5],
+    [104.4, 1.9, -0.3],
+    [100.0, 0.0, 0.0],
+    [96.0, -1.6, 0.2],
+    [95.1, -3.5, 0.5],
+    [89.1, -3.5, 2.1],
+    [90.5, -5.8, 3.2],
+    [90.3, -7.2, 4.1],
+    [88.4, -8.6, 4.7],
+    [84.0, -9.5, 5.1],
+    [85.1, -10.9, 6.7],
+    [81.9, -10.7, 7.3],
+    [82.6, -12.0, 8.6],
+    [84.9, -14.0, 9.8],
+    [81.3, -13.6, 10.2],
+    [71.9, -12.0, 8.3],
+    [74.3, -13.3, 9.6],
+    [76.4, -12.9, 8.5],
+    [63.3, -10.6, 7.0],
+    [71.7, -11.6, 7.6],
+    [77.0, -12.2, 8.0],
+    [65.2, -10.2, 6.7],
+    [47.7, -7.8, 5.2],
+    [68.6, -11.2, 7.4],
+    [65.0, -10.4, 6.8],
+];
+
+/// CIE standard fluorescent illuminants F1 to F12, 380 nm to 780 nm in 5 nm steps.
+#[allow(clippy::approx_constant)]
+const FLUORESCENT: [[f64; 81]; 12] = [
+    [
+        1.87, 2.36, 2.94, 3.47, 5.17, 19.49, 6.13, 6.24, 7.01, 7.79,
+        8.56, 43.67, 16.94, 10.72, 11.35, 11.89, 12.37, 12.75, 13.00, 13.15,
+        13.23, 13.17, 13.13, 12.85, 12.52, 12.20, 11.83, 11.50, 11.22, 11.05,
+        11.03, 11.18, 11.53, 27.74, 17.05, 13.55, 14.33, 15.01, 15.52, 18.29,
+        19.55, 15.48, 14.91, 14.15, 13.22, 12.19, 11.12, 10.03, 8.95, 7.96,
+        7.02, 6.20, 5.42, 4.73, 4.15, 3.64, 3.20, 2.81, 2.47, 2.18,
+        1.93, 1.72, 1.67, 1.43, 1.29, 1.19, 1.08, 0.96, 0.88, 0.81,
+        0.77, 0.75, 0.73, 0.68, 0.69, 0.64, 0.68, 0.69, 0.61, 0.52,
+        0.43,
+    ],
+    [
+        1.18, 1.48, 1.84, 2.15, 3.44, 15.69, 3.85, 3.74, 4.19, 4.62,
+        5.06, 34.98, 11.81, 6.27, 6.63, 6.93, 7.19, 7.40, 7.54, 7.62,
+        7.65, 7.62, 7.62, 7.45, 7.28, 7.15, 7.05, 7.04, 7.16, 7.47,
+        8.04, 8.88, 10.01, 24.88, 16.64, 14.59, 16.16, 17.56, 18.62, 21.47,
+        22.79, 19.29, 18.66, 17.73, 16.54, 15.21, 13.80, 12.36, 10.95, 9.65,
+        8.40, 7.32, 6.31, 5.43, 4.68, 4.02, 3.45, 2.96, 2.55, 2.19,
+        1.89, 1.64, 1.53, 1.27, 1.10, 0.99, 0.88, 0.76, 0.68, 0.61,
+        0.56, 0.54, 0.51, 0.47, 0.47, 0.43, 0.46, 0.47, 0.40, 0.33,
+        0.27,
+    ],
+    [
+        0.82, 1.02, 1.26, 1.44, 2.57, 14.36, 2.70, 2.45, 2.73, 3.00,
+        3.28, 31.85, 9.47, 4.02, 4.25, 4.44, 4.59, 4.72, 4.80, 4.86,
+        4.87, 4.85, 4.88, 4.77, 4.67, 4.62, 4.62, 4.73, 4.99, 5.48,
+        6.25, 7.34, 8.78, 23.82, 16.14, 14.59, 16.63, 18.49, 19.95, 23.11,
+        24.69, 21.41, 20.85, 19.93, 18.67, 17.22, 15.65, 14.04, 12.45, 10.95,
+        9.51, 8.27, 7.11, 6.09, 5.22, 4.45, 3.80, 3.23, 2.75, 2.33,
+        1.99, 1.70, 1.55, 1.27, 1.09, 0.96, 0.83, 0.71, 0.62, 0.54,
+        0.49, 0.46, 0.43, 0.39, 0.39, 0.35, 0.38, 0.39, 0.33, 0.28,
+        0.21,
+    ],
+    [
+        0.57, 0.70, 0.87, 0.98, 2.01, 13.75, 1.95, 1.59, 1.76, 1.93,
+        2.10, 30.28, 8.03, 2.55, 2.70, 2.82, 2.91, 2.99, 3.04, 3.08,
+        3.09, 3.09, 3.14, 3.06, 3.00, 2.98, 3.01, 3.14, 3.41, 3.90,
+        4.69, 5.81, 7.32, 22.59, 15.11, 13.88, 16.33, 18.68, 20.64, 24.28,
+        26.26, 23.28, 22.94, 22.14, 20.91, 19.43, 17.74, 16.00, 14.42, 12.56,
+        10.93, 9.52, 8.18, 7.01, 6.00, 5.11, 4.36, 3.69, 3.13, 2.64,
+        2.24, 1.91, 1.70, 1.39, 1.18, 1.03, 0.88, 0.74, 0.64, 0.54,
+        0.49, 0.46, 0.42, 0.37, 0.37, 0.33, 0.35, 0.36, 0.31, 0.26,
+        0.19,
+    ],
+    [
+        1.87, 2.35, 2.92, 3.45, 5.10, 18.91, 6.00, 6.11, 6.85, 7.58,
+        8.31, 40.76, 16.06, 10.32, 10.91, 11.40, 11.83, 12.17, 12.40, 12.54,
+        12.58, 12.52, 12.47, 12.20, 11.89, 11.61, 11.33, 11.10, 10.96, 10.97,
+        11.16, 11.54, 12.12, 27.78, 17.73, 14.47, 15.20, 15.77, 16.10, 18.54,
+        19.50, 15.39, 14.64, 13.72, 12.69, 11.57, 10.45, 9.35, 8.29, 7.32,
+        6.41, 5.63, 4.90, 4.26, 3.72, 3.25, 2.83, 2.49, 2.19, 1.93,
+        1.71, 1.52, 1.48, 1.26, 1.13, 1.05, 0.96, 0.85, 0.78, 0.72,
+        0.68, 0.67, 0.65, 0.61, 0.62, 0.59, 0.62, 0.64, 0.55, 0.47,
+        0.40,
+    ],
+    [
+        1.05, 1.31, 1.63, 1.90, 3.11, 14.80, 3.43, 3.30, 3.68, 4.07,
+        4.45, 32.61, 10.74, 5.48, 5.78, 6.03, 6.25, 6.41, 6.52, 6.58,
+        6.59, 6.56, 6.56, 6.42, 6.28, 6.20, 6.19, 6.30, 6.60, 7.12,
+        7.94, 9.07, 10.49, 25.22, 17.46, 15.63, 17.22, 18.53, 19.43, 21.97,
+        23.01, 19.41, 18.56, 17.42, 16.09, 14.64, 13.15, 11.68, 10.25, 8.95,
+        7.74, 6.69, 5.71, 4.87, 4.16, 3.55, 3.02, 2.57, 2.20, 1.87,
+        1.60, 1.37, 1.29, 1.05, 0.91, 0.81, 0.71, 0.61, 0.54, 0.48,
+        0.44, 0.43, 0.40, 0.37, 0.38, 0.35, 0.39, 0.41, 0.33, 0.26,
+        0.21,
+    ],
+    [
+        2.56, 3.18, 3.84, 4.53, 6.15, 19.37, 7.37, 7.05, 7.71, 8.41,
+        9.15, 44.14, 17.52, 11.35, 12.00, 12.58, 13.08, 13.45, 13.71, 13.88,
+        13.95, 13.93, 13.82, 13.64, 13.43, 13.25, 13.08, 12.93, 12.78, 12.60,
+        12.44, 12.33, 12.26, 29.52, 17.05, 12.44, 12.58, 12.72, 12.83, 15.46,
+        16.75, 12.83, 12.67, 12.45, 12.19, 11.89, 11.60, 11.35, 11.12, 10.95,
+        10.76, 10.42, 10.11, 10.04, 10.02, 10.11, 9.87, 8.65, 7.27, 6.44,
+        5.83, 5.41, 5.04, 4.57, 4.12, 3.77, 3.46, 3.08, 2.73, 2.47,
+        2.25, 2.06, 1.90, 1.75, 1.62, 1.54, 1.45, 1.32, 1.17, 0.99,
+        0.81,
+    ],
+    [
+        1.21, 1.50, 1.81, 2.13, 3.17, 13.08, 3.83, 3.45, 3.86, 4.42,
+        5.09, 34.10, 12.42, 7.68, 8.60, 9.46, 10.24, 10.84, 11.33, 11.71,
+        11.98, 12.17, 12.28, 12.32, 12.35, 12.44, 12.55, 12.68, 12.77, 12.72,
+        12.60, 12.43, 12.22, 28.96, 16.51, 11.79, 11.76, 11.77, 11.84, 14.61,
+        16.11, 12.34, 12.53, 12.72, 12.92, 13.12, 13.34, 13.61, 13.87, 14.07,
+        14.20, 14.16, 14.13, 14.34, 14.50, 14.46, 14.00, 12.58, 10.99, 9.98,
+        9.22, 8.62, 8.07, 7.39, 6.71, 6.16, 5.63, 5.03, 4.46, 4.02,
+        3.66, 3.36, 3.09, 2.85, 2.65, 2.51, 2.37, 2.15, 1.89, 1.61,
+        1.32,
+    ],
+    [
+        0.90, 1.12, 1.36, 1.60, 2.59, 12.80, 3.05, 2.56, 2.86, 3.30,
+        3.82, 32.62, 10.77, 5.84, 6.57, 7.25, 7.86, 8.35, 8.75, 9.06,
+        9.31, 9.48, 9.61, 9.68, 9.74, 9.88, 10.04, 10.26, 10.48, 10.63,
+        10.76, 10.96, 11.18, 27.71, 16.29, 12.28, 12.74, 13.21, 13.65, 16.57,
+        18.14, 14.55, 14.65, 14.66, 14.61, 14.50, 14.39, 14.40, 14.47, 14.62,
+        14.72, 14.55, 14.40, 14.58, 14.88, 15.51, 15.47, 13.20, 10.57, 9.18,
+        8.25, 7.57, 7.03, 6.35, 5.72, 5.25, 4.80, 4.29, 3.80, 3.43,
+        3.12, 2.86, 2.64, 2.43, 2.26, 2.14, 2.02, 1.83, 1.61, 1.38,
+        1.12,
+    ],
+    [
+        1.11, 0.63, 0.62, 0.57, 1.48, 12.16, 2.12, 2.70, 3.74, 5.14,
+        6.75, 34.39, 14.86, 10.40, 10.76, 10.67, 10.11, 9.27, 8.29, 7.29,
+        7.91, 16.64, 16.73, 10.44, 5.94, 3.34, 2.35, 1.88, 1.59, 1.47,
+        1.80, 5.71, 40.98, 73.69, 33.61, 8.24, 3.38, 2.47, 2.14, 4.86,
+        11.45, 14.79, 12.16, 8.97, 6.52, 8.31, 44.12, 34.55, 12.09, 12.15,
+        10.52, 4.43, 1.95, 2.19, 3.19, 2.77, 2.29, 2.00, 1.52, 1.35,
+        1.47, 1.79, 1.74, 1.02, 1.14, 3.32, 4.49, 2.05, 0.49, 0.24,
+        0.21, 0.21, 0.24, 0.24, 0.21, 0.17, 0.21, 0.22, 0.17, 0.12,
+        0.09,
+    ],
+    [
+        0.91, 0.63, 0.46, 0.37, 1.29, 12.68, 1.59, 1.79, 2.46, 3.33,
+        4.49, 33.94, 12.13, 6.95, 7.19, 7.12, 6.72, 6.13, 5.46, 4.79,
+        5.66, 14.29, 14.96, 8.97, 4.72, 2.33, 1.47, 1.10, 0.89, 0.83,
+        1.18, 4.90, 39.59, 72.84, 32.61, 7.52, 2.83, 1.96, 1.67, 4.43,
+        11.28, 14.76, 12.73, 9.74, 7.33, 9.72, 55.27, 42.58, 13.18, 13.16,
+        12.26, 5.11, 2.07, 2.34, 3.58, 3.01, 2.48, 2.14, 1.54, 1.33,
+        1.46, 1.94, 2.00, 1.20, 1.35, 4.10, 5.58, 2.51, 0.57, 0.27,
+        0.23, 0.21, 0.24, 0.24, 0.20, 0.24, 0.32, 0.26, 0.16, 0.12,
+        0.09,
+    ],
+    [
+        0.96, 0.64, 0.40, 0.33, 1.19, 12.48, 1.12, 0.94, 1.08, 1.37,
+        1.78, 29.05, 7.90, 2.65, 2.71, 2.65, 2.49, 2.33, 2.10, 1.91,
+        3.01, 10.83, 11.88, 6.88, 3.43, 1.49, 0.92, 0.71, 0.60, 0.63,
+        1.10, 4.56, 34.40, 65.40, 29.48, 7.16, 3.08, 2.47, 2.27, 5.09,
+        11.96, 15.32, 14.27, 11.86, 9.28, 12.31, 68.53, 53.02, 14.67, 14.38,
+        14.71, 6.46, 2.57, 2.75, 4.18, 3.44, 2.81, 2.42, 1.64, 1.36,
+        1.49, 2.14, 2.34, 1.42, 1.61, 5.04, 6.98, 3.19, 0.71, 0.30,
+        0.26, 0.23, 0.28, 0.28, 0.21, 0.17, 0.21, 0.19, 0.15, 0.10,
+        0.05,
+    ],
+];
@@ -0,0 +1,163 @@
+use crate::{ FromColor, FromRgb, Rgb, Spectrum, ToRgb, ToSpectrum, Upsampling, Xyz };
+use crate::upsampling::{ reflectance_lab, SAMPLES, START, STEP };
+
+/// A pigment described by its Kubelka–Munk absorption and scattering spectra.
+///
+/// Mixtures are opaque layers: the absorption and scattering of a mixture are the sums of
+/// those of its pigments weighted by their concentrations, and its reflectance is that of
+/// an infinitely thick layer, `R = 1 + K/S − √((K/S)² + 2K/S)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pigment {
+    /// The absorption coefficients `K`.
+    pub absorption: Spectrum,
+    /// The scattering coefficients `S`, sampled at the same wavelengths as `absorption`.
+    pub scattering: Spectrum,
+}
+
+impl Pigment {
+    /// Create a pigment from its absorption and scattering spectra, interpolating the
+    /// scattering at the wavelengths of the absorption.
+    pub fn new(absorption: Spectrum, scattering: Spectrum) -> Self {
+        let scattering = match absorption.wavelengths == scattering.wavelengths {
+            true => scattering,
+            false => Spectrum::new(
+                absorption.wavelengths.clone(),
+                absorption.wavelengths.iter().map(|&nm| scattering.value_at(nm)).collect()
+            ),
+        };
+        Self { absorption, scattering }
+    }
+
+    /// The single-constant pigment with the masstone `reflectance`: scattering is 1 and
+    /// absorption is `K/S = (1 − R)² / 2R`. Reflectances are kept above `MIN_REFLECTANCE`
+    /// so black has a finite absorption.
+    pub fn from_reflectance(reflectance: &Spectrum) -> Self {
+        let absorption = Spectrum::new(
+            reflectance.wavelengths.clone(),
+            reflectance.values.iter().map(|&r| absorption_ratio(r)).collect()
+        );
+        let scattering = Spectrum::new(reflectance.wavelengths.clone(), vec![1.0; reflectance.values.len()]);
+        Self { absorption, scattering }
+    }
+
+    /// The reflectance of the pigment on its own.
+    pub fn reflectance(&self) -> Spectrum {
+        mix_pigments(&[(self, 1.0)])
+    }
+}
+
+/// Smallest reflectance turned into an absorption, keeping `K/S` finite.
+const MIN_REFLECTANCE: f64 = 1e-2;
+
+/// Scattering of a black `PigmentLatent`; lighter colors scatter up to 1 more.
+const MIN_SCATTERING: f64 = 0.05;
+
+/// The ratio `K/S` of an opaque layer with the reflectance `r`.
+fn absorption_ratio(r: f64) -> f64 {
+    let r = r.clamp(MIN_REFLECTANCE, 1.0);
+    (1.0 - r) * (1.0 - r) / (2.0 * r)
+}
+
+/// The reflectance of an opaque layer with the ratio `K/S` of `ks`.
+fn layer_reflectance(ks: f64) -> f64 {
+    1.0 + ks - (ks * ks + 2.0 * ks).sqrt()
+}
+
+/// The reflectance of a mixture of pigments with relative concentrations, sampled at the
+/// wavelengths of the first pigment.
+pub fn mix_pigments(pigments: &[(&Pigment, f64)]) -> Spectrum {
+    let wavelengths = match pigments.first() {
+        Some((pigment, _)) => pigment.absorption.wavelengths.clone(),
+        None => return Spectrum::new(Vec::new(), Vec::new()),
+    };
+    let values = wavelengths
+        .iter()
+        .map(|&nm| {
+            let (mut k, mut s) = (0.0, 0.0);
+            for (pigment, concentration) in pigments {
+                k += concentration * pigment.absorption.value_at(nm);
+                s += concentration * pigment.scattering.value_at(nm);
+            }
+            match s > 0.0 {
+                true => layer_reflectance(k / s),
+                false => 0.0,
+            }
+        })
+        .collect();
+    Spectrum::new(wavelengths, values)
+}
+
+/// A color as paint, in the manner of Mixbox, for mixing colors without spectral data.
+///
+/// The color becomes a pigment whose masstone is the Jakob-Hanika reflectance of the
+/// color, with a scattering that grows with its luminance, as light paints are mostly
+/// white pigment and dominate mixtures less than their absorption alone suggests. The
+/// residual between the color and that of the pigment makes the conversion from and back
+/// to `Rgb` exact.
+///
+/// Latents mix linearly, so a weighted average of latents mixes colors like pigments.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PigmentLatent {
+    /// The absorption `K` from 380 nm to 780 nm in 5 nm steps.
+    pub absorption: [f64; SAMPLES],
+    /// The scattering `S`, the same at every wavelength.
+    pub scattering: f64,
+    /// The `Rgb` (0 to 255) to add to the color of the spectrum.
+    pub residual: [f64; 3],
+}
+
+impl PigmentLatent {
+    /// The latent `t` (0 to 1) of the way from this one to `other`.
+    pub fn mix(&self, other: &PigmentLatent, t: f64) -> PigmentLatent {
+        let mut absorption = [0.0; SAMPLES];
+        for ((a, x), y) in absorption.iter_mut().zip(&self.absorption).zip(&other.absorption) {
+            *a = x + (y - x) * t;
+        }
+        let mut residual = [0.0; 3];
+        for ((r, x), y) in residual.iter_mut().zip(&self.residual).zip(&other.residual) {
+            *r = x + (y - x) * t;
+        }
+        let scattering = self.scattering + (other.scattering - self.scattering) * t;
+        PigmentLatent { absorption, scattering, residual }
+    }
+
+    /// The color of the spectrum alone, without the residual.
+    fn spectral_rgb(&self) -> Rgb {
+        let values: Vec<f64> = self.absorption.iter().map(|&k| layer_reflectance(k / self.scattering)).collect();
+        Rgb::from_color(&reflectance_lab(&values))
+    }
+}
+
+impl FromRgb for PigmentLatent {
+    fn from_rgb(rgb: &Rgb) -> Self {
+        let reflectance = rgb.to_spectrum(Upsampling::JakobHanika);
+        let scattering = MIN_SCATTERING + Xyz::from_rgb(rgb).y.max(0.0) / 100.0;
+        let mut absorption = [0.0; SAMPLES];
+        for (i, k) in absorption.iter_mut().enumerate() {
+            *k = scattering * absorption_ratio(reflectance.value_at(START + STEP * i as f64));
+        }
+        let mut latent = PigmentLatent { absorption, scattering, residual: [0.0; 3] };
+        let color = latent.spectral_rgb();
+        latent.residual = [rgb.r - color.r, rgb.g - color.g, rgb.b - color.b];
+        latent
+    }
+}
+
+impl ToRgb for PigmentLatent {
+    fn to_rgb(&self) -> Rgb {
+        let color = self.spectral_rgb();
+        Rgb::new(
+            (color.r + self.residual[0]).clamp(0.0, 255.0),
+            (color.g + self.residual[1]).clamp(0.0, 255.0),
+            (color.b + self.residual[2]).clamp(0.0, 255.0)
+        )
+    }
+}
+
+/// Mix two colors like paint, `t` (0 to 1) of the way from `a` to `b`, through their
+/// `PigmentLatent`. Blue and yellow make green rather than the gray of a linear mix.
+pub fn mix_paint<T: ToRgb + FromRgb>(a: &T, b: &T, t: f64) -> T {
+    let a = PigmentLatent::from_rgb(&a.to_rgb());
+    let b = PigmentLatent::from_rgb(&b.to_rgb());
+    T::from_rgb(&a.mix(&b, t).to_rgb())
+}
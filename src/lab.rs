@@ -1,4 +1,5 @@
 use crate::{ Rgb, FromRgb, ToRgb, Xyz, approx };
+use crate::illuminant::D65_WHITE as WHITE;
 
 /// A CIELAB color.
 #[derive(Copy, Clone, Debug, Default)]
@@ -32,32 +33,41 @@ impl PartialEq for Lab {
 
 impl FromRgb for Lab {
     fn from_rgb(rgb: &Rgb) -> Self {
-        let xyz = Xyz::from_rgb(rgb);
-        let x = xyz.x / 95.047;
-        let y = xyz.y / 100.0;
-        let z = xyz.z / 108.883;
-        let x = if x > 0.008856 { x.cbrt() } else { 7.787 * x + 16.0 / 116.0 };
-        let y = if y > 0.008856 { y.cbrt() } else { 7.787 * y + 16.0 / 116.0 };
-        let z = if z > 0.008856 { z.cbrt() } else { 7.787 * z + 16.0 / 116.0 };
-        Self::new(
-            (116.0 * y) - 16.0,
-            500.0 * (x - y),
-            200.0 * (y - z)
-        )
+        xyz_to_lab(&Xyz::from_rgb(rgb), &WHITE)
     }
 }
 
 impl ToRgb for Lab {
     fn to_rgb(&self) -> Rgb {
-        let y = (self.l + 16.0) / 116.0;
-		let x = self.a / 500.0 + y;
-        let z = y - self.b / 200.0;
-        let x3 = x.powf(3.0);
-        let y3 = y.powf(3.0);
-        let z3 = z.powf(3.0);
-        let x = 95.047 * if x3 > 0.008856 { x3 } else { (x - 16.0 / 116.0) / 7.787 };
-        let y = 100.0 * if y3 > 0.008856 { y3 } else { (y - 16.0 / 116.0) / 7.787 };
-        let z = 108.883 * if z3 > 0.008856 { z3 } else { (z - 16.0 / 116.0) / 7.787 };
-        Xyz::new(x, y, z).to_rgb()
+        lab_to_xyz(self, &WHITE).to_rgb()
     }
+}
+
+/// CIELAB of the tristimulus values `xyz` relative to `white`.
+pub(crate) fn xyz_to_lab(xyz: &Xyz, white: &Xyz) -> Lab {
+    let x = xyz.x / white.x;
+    let y = xyz.y / white.y;
+    let z = xyz.z / white.z;
+    let x = if x > 0.008856 { x.cbrt() } else { 7.787 * x + 16.0 / 116.0 };
+    let y = if y > 0.008856 { y.cbrt() } else { 7.787 * y + 16.0 / 116.0 };
+    let z = if z > 0.008856 { z.cbrt() } else { 7.787 * z + 16.0 / 116.0 };
+    Lab::new(
+        (116.0 * y) - 16.0,
+        500.0 * (x - y),
+        200.0 * (y - z)
+    )
+}
+
+/// The tristimulus values of the CIELAB color `lab` relative to `white`.
+pub(crate) fn lab_to_xyz(lab: &Lab, white: &Xyz) -> Xyz {
+    let y = (lab.l + 16.0) / 116.0;
+    let x = lab.a / 500.0 + y;
+    let z = y - lab.b / 200.0;
+    let x3 = x.powf(3.0);
+    let y3 = y.powf(3.0);
+    let z3 = z.powf(3.0);
+    let x = white.x * if x3 > 0.008856 { x3 } else { (x - 16.0 / 116.0) / 7.787 };
+    let y = white.y * if y3 > 0.008856 { y3 } else { (y - 16.0 / 116.0) / 7.787 };
+    let z = white.z * if z3 > 0.008856 { z3 } else { (z - 16.0 / 116.0) / 7.787 };
+    Xyz::new(x, y, z)
 }
\ No newline at end of file
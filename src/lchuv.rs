@@ -0,0 +1,51 @@
+use crate::{ Rgb, FromRgb, ToRgb, Luv, approx };
+
+/// A CIE LCh(uv) color, the cylindrical form of CIELUV.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LchUv {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl LchUv {
+    /// Create a new CIE LCh(uv) color.
+    /// 
+    /// `l`: luminance component (0 to 100).
+    /// 
+    /// `c`: chroma component (0 to about 180).
+    /// 
+    /// `h`: hue component (0 to 360).
+    #[inline]
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+}
+
+impl PartialEq for LchUv {
+    fn eq(&self, other: &Self) -> bool {
+        approx(self.l, other.l) &&
+        approx(self.c, other.c) &&
+        approx(self.h, other.h)
+    }
+}
+
+impl FromRgb for LchUv {
+    fn from_rgb(rgb: &Rgb) -> Self {
+        let luv = Luv::from_rgb(rgb);
+        let c = (luv.u * luv.u + luv.v * luv.v).sqrt();
+        let h = luv.v.atan2(luv.u).to_degrees();
+        Self::new(luv.l, c, (h + 360.0) % 360.0)
+    }
+}
+
+impl ToRgb for LchUv {
+    fn to_rgb(&self) -> Rgb {
+        let h = self.h.to_radians();
+        Luv::new(
+            self.l,
+            h.cos() * self.c,
+            h.sin() * self.c
+        ).to_rgb()
+    }
+}
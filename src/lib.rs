@@ -35,12 +35,48 @@ mod hsv;
 mod hunter_lab;
 mod lab;
 mod lch;
+mod lchuv;
 mod luv;
+mod oklab;
+mod oklch;
 mod rgb;
+mod ucs1960;
+mod ucs1976;
+mod uvw;
 mod xyz;
 mod yxy;
 mod approx;
 mod compare;
+mod chromaticity;
+mod cmf;
+mod spectrum;
+mod illuminant;
+mod planckian;
+mod cct;
+mod temperature;
+mod upsampling;
+mod upsampling_tables;
+mod metamerism;
+mod cam02;
+mod color_rendering;
+mod dominant_wavelength;
+mod rgb_space;
+mod gamut;
+mod gamut_boundary;
+mod gamut_volume;
+mod rendering_intent;
+mod icc;
+mod icc_writer;
+mod md5;
+mod separation;
+mod cmyk_model;
+mod cmyk_tables;
+mod cgats;
+mod qc;
+mod kubelka_munk;
+mod mix;
+mod gradient;
+mod colormap;
 
 pub use cmy::Cmy;
 pub use cmyk::Cmyk;
@@ -49,12 +85,43 @@ pub use hsv::Hsv;
 pub use hunter_lab::HunterLab;
 pub use lab::Lab;
 pub use lch::Lch;
+pub use lchuv::LchUv;
 pub use luv::Luv;
+pub use oklab::Oklab;
+pub use oklch::Oklch;
 pub use rgb::Rgb;
+pub use ucs1960::Ucs1960;
+pub use ucs1976::Ucs1976;
+pub use uvw::Uvw;
 pub use xyz::Xyz;
 pub use yxy::Yxy;
 pub(crate) use approx::approx;
 pub use compare::{ CompareEuclidean, CompareCie1976, CompareCie2000, CompareCmc };
+pub use cmf::Observer;
+pub use spectrum::Spectrum;
+pub use illuminant::Illuminant;
+pub use cct::{ Cct, CctMethod };
+pub use temperature::{ FromTemperature, Locus };
+pub use upsampling::{ SigmoidTable, ToSpectrum, Upsampling, mallett_yuksel_basis };
+pub use metamerism::{ metamerism_index, metamerism_indices };
+pub use color_rendering::{ Cri, Tm30, cri, tm30, test_color_sample };
+pub use dominant_wavelength::{ DominantWavelength, Wavelength };
+pub use rgb_space::{ RgbSpace, TransferFunction };
+pub use gamut::{ GamutMap, GamutMapping, InGamut };
+pub use gamut_boundary::{ GamutBoundary, Polar, cusp, max_chroma };
+pub use gamut_volume::Chromaticity;
+pub use rendering_intent::{ Profile, RenderingIntent, Transform };
+pub use icc::{ Curve, IccError, IccProfile };
+pub use icc_writer::IccVersion;
+pub use separation::Separation;
+pub use cmyk_model::{ BlackGeneration, CmykModel };
+pub use cgats::{ Cgats, CgatsError, CgatsFields };
+pub use qc::{ DeltaE, Patch, QcReport };
+pub use kubelka_munk::{ Pigment, PigmentLatent, mix_paint, mix_pigments };
+pub use mix::{ Alpha, HueInterpolation, Mix, mix_in };
+pub use gradient::{ Easing, Gradient, MixSpace, Path, Segment };
+pub use colormap::{ Colormap, ColormapAnalysis, analyze_colormap };
+pub use chromaticity::{ xy_to_uv, uv_to_xy, xy_to_uv_prime, uv_prime_to_xy, uv_to_uv_prime, uv_prime_to_uv };
 
 pub trait FromRgb {
     /// Convert from an `Rgb` color.
@@ -97,14 +164,20 @@ macro_rules! impl_from {
     };
 }
 
-impl_from!(Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, Luv, Rgb, Xyz, Yxy);
-impl_from!(Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, Luv, Rgb, Xyz, Yxy, Cmy);
-impl_from!(Hsl, Hsv, HunterLab, Lab, Lch, Luv, Rgb, Xyz, Yxy, Cmy, Cmyk);
-impl_from!(Hsv, HunterLab, Lab, Lch, Luv, Rgb, Xyz, Yxy, Cmy, Cmyk, Hsl);
-impl_from!(HunterLab, Lab, Lch, Luv, Rgb, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv);
-impl_from!(Lab, Lch, Luv, Rgb, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab);
-impl_from!(Lch, Luv, Rgb, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab);
-impl_from!(Luv, Rgb, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch);
-impl_from!(Rgb, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, Luv);
-impl_from!(Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, Luv, Rgb);
-impl_from!(Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, Luv, Rgb, Xyz);
\ No newline at end of file
+impl_from!(Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy);
+impl_from!(Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy);
+impl_from!(Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk);
+impl_from!(Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl);
+impl_from!(HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv);
+impl_from!(Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab);
+impl_from!(Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab);
+impl_from!(LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch);
+impl_from!(Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv);
+impl_from!(Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv);
+impl_from!(Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab);
+impl_from!(Rgb, Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch);
+impl_from!(Ucs1960, Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb);
+impl_from!(Ucs1976, Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960);
+impl_from!(Uvw, Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976);
+impl_from!(Xyz, Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw);
+impl_from!(Yxy, Cmy, Cmyk, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, Ucs1960, Ucs1976, Uvw, Xyz);
\ No newline at end of file
@@ -1,4 +1,5 @@
 use crate::{ Rgb, FromRgb, ToRgb, Xyz, approx };
+use crate::illuminant::D65_WHITE as WHITE;
 
 /// A CIELUV color (luminance, )
 #[derive(Copy, Clone, Debug, Default)]
@@ -32,7 +33,6 @@ impl PartialEq for Luv {
 
 const EPS: f64 = 216.0 / 24389.0;
 const KAPPA: f64 = 24389.0 / 27.0;
-const WHITE: Xyz = Xyz{ x: 95.047, y: 100.000, z: 108.883 };
 
 impl FromRgb for Luv {
     fn from_rgb(rgb: &Rgb) -> Self {
@@ -0,0 +1,69 @@
+use crate::{ Illuminant, Observer, Spectrum, Xyz };
+use crate::illuminant::D65_WHITE as WHITE;
+
+impl Spectrum {
+    /// The `Xyz` color of this reflectance under `illuminant`, scaled so the perfect
+    /// reflector lands on the D65 white point of the other conversions.
+    ///
+    /// `Lab` only depends on the ratio of each component to its white, so comparing
+    /// these colors with any `compare_*` metric gives the color difference evaluated
+    /// relative to the white of `illuminant`, as CIE 15 prescribes.
+    pub fn to_xyz_relative(&self, illuminant: Illuminant, observer: Observer) -> Xyz {
+        let light = illuminant.spectrum();
+        let xyz = self.to_xyz_reflective(&light, observer);
+        let white = light.to_xyz(observer);
+        Xyz::new(
+            xyz.x * WHITE.x / white.x,
+            xyz.y * WHITE.y / white.y,
+            xyz.z * WHITE.z / white.z
+        )
+    }
+}
+
+/// The CIE 15 special metamerism index (change in illuminant) of two reflectances
+/// that match under `reference`, measured under `test` with the difference `compare`,
+/// for example `|a, b| a.compare_cie2000(b)`.
+///
+/// Any residual mismatch under the reference illuminant is removed with the
+/// multiplicative correction, scaling the tristimulus values of `b` by the ratio of
+/// the two samples under the reference illuminant.
+pub fn metamerism_index<F>(
+    a: &Spectrum,
+    b: &Spectrum,
+    reference: Illuminant,
+    test: Illuminant,
+    observer: Observer,
+    compare: F
+) -> f64
+where
+    F: Fn(&Xyz, &Xyz) -> f64,
+{
+    metamerism_indices(a, b, reference, &[test], observer, compare)[0].1
+}
+
+/// The special metamerism index of `a` and `b` under each of the `tests` illuminants.
+pub fn metamerism_indices<F>(
+    a: &Spectrum,
+    b: &Spectrum,
+    reference: Illuminant,
+    tests: &[Illuminant],
+    observer: Observer,
+    compare: F
+) -> Vec<(Illuminant, f64)>
+where
+    F: Fn(&Xyz, &Xyz) -> f64,
+{
+    let ar = a.to_xyz_relative(reference, observer);
+    let br = b.to_xyz_relative(reference, observer);
+    let ratio = |a: f64, b: f64| if b == 0.0 { 1.0 } else { a / b };
+    let (fx, fy, fz) = (ratio(ar.x, br.x), ratio(ar.y, br.y), ratio(ar.z, br.z));
+    tests
+        .iter()
+        .map(|&test| {
+            let at = a.to_xyz_relative(test, observer);
+            let bt = b.to_xyz_relative(test, observer);
+            let bt = Xyz::new(bt.x * fx, bt.y * fy, bt.z * fz);
+            (test, compare(&at, &bt))
+        })
+        .collect()
+}
@@ -0,0 +1,163 @@
+use crate::{
+    Cmy, Cmyk, FromRgb, Hsl, Hsv, HunterLab, Lab, Lch, LchUv, Luv, Oklab, Oklch, Rgb, ToRgb,
+    Ucs1960, Ucs1976, Uvw, Xyz, Yxy,
+};
+
+/// How hues are interpolated, as in CSS Color 4.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum HueInterpolation {
+    /// Take the shorter arc between the hues, at most 180°.
+    #[default]
+    Shorter,
+    /// Take the longer arc between the hues, at least 180°.
+    Longer,
+    /// Go counterclockwise, with the hue increasing.
+    Increasing,
+    /// Go clockwise, with the hue decreasing.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// The hue `t` of the way from `from` to `to`, in degrees from 0 to 360.
+    pub fn interpolate(&self, from: f64, to: f64, t: f64) -> f64 {
+        let (mut from, mut to) = (from.rem_euclid(360.0), to.rem_euclid(360.0));
+        let delta = to - from;
+        match self {
+            HueInterpolation::Shorter if delta > 180.0 => from += 360.0,
+            HueInterpolation::Shorter if delta < -180.0 => to += 360.0,
+            HueInterpolation::Longer if delta > 0.0 && delta < 180.0 => from += 360.0,
+            HueInterpolation::Longer if delta > -180.0 && delta <= 0.0 => to += 360.0,
+            HueInterpolation::Increasing if to < from => to += 360.0,
+            HueInterpolation::Decreasing if from < to => from += 360.0,
+            _ => {}
+        }
+        (from + (to - from) * t).rem_euclid(360.0)
+    }
+}
+
+/// Interpolation between two colors of the same type, in the components of that type.
+///
+/// Hues follow a `HueInterpolation`. The hue of an achromatic color is powerless: it
+/// takes the hue of the other color, so mixing with white or gray does not sweep through
+/// unrelated hues.
+pub trait Mix: Sized {
+    /// Interpolate the components `t` (0 to 1) of the way to `other`, and the hue, if the
+    /// type has one, `hue_t` of the way. The separate weights let `Alpha` premultiply.
+    fn mix_components(&self, other: &Self, t: f64, hue_t: f64, hue: HueInterpolation) -> Self;
+
+    /// The color `t` (0 to 1) of the way to `other`.
+    fn mix(&self, other: &Self, t: f64, hue: HueInterpolation) -> Self {
+        self.mix_components(other, t, t, hue)
+    }
+
+    /// The color `t` (0 to 1) of the way to `other`, taking the shorter hue arc.
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self.mix(other, t, HueInterpolation::Shorter)
+    }
+}
+
+#[inline]
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// The hues to interpolate between, with powerless hues replaced by the other one.
+fn hues(from: f64, from_powerless: bool, to: f64, to_powerless: bool) -> (f64, f64) {
+    match (from_powerless, to_powerless) {
+        (true, false) => (to, to),
+        (false, true) | (true, true) => (from, from),
+        (false, false) => (from, to),
+    }
+}
+
+macro_rules! impl_mix {
+    ($type:ident, $($field:ident), *) => {
+        impl Mix for $type {
+            fn mix_components(&self, other: &Self, t: f64, _: f64, _: HueInterpolation) -> Self {
+                Self { $($field: lerp(self.$field, other.$field, t)), * }
+            }
+        }
+    };
+}
+
+macro_rules! impl_mix_hue {
+    ($type:ident, $chroma:ident < $achromatic:expr, $($field:ident), *) => {
+        impl Mix for $type {
+            fn mix_components(&self, other: &Self, t: f64, hue_t: f64, hue: HueInterpolation) -> Self {
+                let (from, to) = hues(self.h, self.$chroma < $achromatic, other.h, other.$chroma < $achromatic);
+                Self { h: hue.interpolate(from, to, hue_t), $($field: lerp(self.$field, other.$field, t)), * }
+            }
+        }
+    };
+}
+
+impl_mix!(Rgb, r, g, b);
+impl_mix!(Cmy, c, m, y);
+impl_mix!(Cmyk, c, m, y, k);
+impl_mix!(Xyz, x, y, z);
+impl_mix!(Yxy, y1, x, y2);
+impl_mix!(Lab, l, a, b);
+impl_mix!(Luv, l, u, v);
+impl_mix!(Oklab, l, a, b);
+impl_mix!(HunterLab, l, a, b);
+impl_mix!(Ucs1960, y, u, v);
+impl_mix!(Ucs1976, y, u, v);
+impl_mix!(Uvw, u, v, w);
+// chroma thresholds are above the residual chroma of sRGB grays, which for Lch and LchUv
+// do not land exactly on the D65 white
+impl_mix_hue!(Hsl, s < 1e-6, s, l);
+impl_mix_hue!(Hsv, s < 1e-6, s, v);
+impl_mix_hue!(Lch, c < 0.05, l, c);
+impl_mix_hue!(LchUv, c < 0.05, l, c);
+impl_mix_hue!(Oklch, c < 1e-4, l, c);
+
+/// Mix two colors `t` (0 to 1) of the way from `a` to `b`, interpolating in the space `S`.
+///
+/// ```
+/// use color_space::{ HueInterpolation, Oklch, Rgb, mix_in };
+///
+/// let red = Rgb::new(255.0, 0.0, 0.0);
+/// let blue = Rgb::new(0.0, 0.0, 255.0);
+/// let purple = mix_in::<Oklch, _>(&red, &blue, 0.5, HueInterpolation::Shorter);
+/// ```
+pub fn mix_in<S, T>(a: &T, b: &T, t: f64, hue: HueInterpolation) -> T
+where
+    S: Mix + FromRgb + ToRgb,
+    T: FromRgb + ToRgb,
+{
+    let (a, b) = (S::from_rgb(&a.to_rgb()), S::from_rgb(&b.to_rgb()));
+    T::from_rgb(&a.mix(&b, t, hue).to_rgb())
+}
+
+/// A color with an opacity.
+///
+/// Mixing premultiplies the components by the alpha, as in CSS Color 4, so a transparent
+/// color contributes its alpha but not its color. Hues are not premultiplied.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Alpha<T> {
+    pub color: T,
+    pub alpha: f64,
+}
+
+impl<T> Alpha<T> {
+    /// Create a new color with an opacity.
+    ///
+    /// `alpha`: opacity (0 to 1)
+    #[inline]
+    pub fn new(color: T, alpha: f64) -> Self {
+        Self { color, alpha }
+    }
+}
+
+impl<T: Mix> Mix for Alpha<T> {
+    fn mix_components(&self, other: &Self, t: f64, hue_t: f64, hue: HueInterpolation) -> Self {
+        let alpha = lerp(self.alpha, other.alpha, t);
+        // premultiplied components divided by the mixed alpha are a lerp with a weight
+        // shifted towards the more opaque color
+        let weight = match alpha > 0.0 {
+            true => other.alpha * t / alpha,
+            false => t,
+        };
+        Self { color: self.color.mix_components(&other.color, weight, hue_t, hue), alpha }
+    }
+}
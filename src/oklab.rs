@@ -0,0 +1,61 @@
+use crate::{ Rgb, FromRgb, ToRgb, approx };
+use crate::xyz::{ srgb_to_linear, linear_to_srgb };
+
+/// An Oklab color.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Oklab {
+    /// Create a new Oklab color.
+    /// 
+    /// `l`: perceived lightness (0 to 1).
+    /// 
+    /// `a`: green (negative) and red (positive) component.
+    /// 
+    /// `b`: blue (negative) and yellow (positive) component.
+    #[inline]
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl PartialEq for Oklab {
+    fn eq(&self, other: &Self) -> bool {
+        approx(self.l, other.l) &&
+        approx(self.a, other.a) &&
+        approx(self.b, other.b)
+    }
+}
+
+impl FromRgb for Oklab {
+    fn from_rgb(rgb: &Rgb) -> Self {
+        let r = srgb_to_linear(rgb.r / 255.0);
+        let g = srgb_to_linear(rgb.g / 255.0);
+        let b = srgb_to_linear(rgb.b / 255.0);
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        Self::new(
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s
+        )
+    }
+}
+
+impl ToRgb for Oklab {
+    fn to_rgb(&self) -> Rgb {
+        let l = (self.l + 0.3963377774 * self.a + 0.2158037573 * self.b).powi(3);
+        let m = (self.l - 0.1055613458 * self.a - 0.0638541728 * self.b).powi(3);
+        let s = (self.l - 0.0894841775 * self.a - 1.2914855480 * self.b).powi(3);
+        Rgb::new(
+            255.0 * linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            255.0 * linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            255.0 * linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s)
+        )
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{ Rgb, FromRgb, ToRgb, Oklab, approx };
+
+/// An Oklch color, the cylindrical form of Oklab.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Oklch {
+    /// Create a new Oklch color.
+    /// 
+    /// `l`: perceived lightness (0 to 1).
+    /// 
+    /// `c`: chroma component (0 to about 0.4).
+    /// 
+    /// `h`: hue component (0 to 360).
+    #[inline]
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self { l, c, h }
+    }
+}
+
+impl PartialEq for Oklch {
+    fn eq(&self, other: &Self) -> bool {
+        approx(self.l, other.l) &&
+        approx(self.c, other.c) &&
+        approx(self.h, other.h)
+    }
+}
+
+impl FromRgb for Oklch {
+    fn from_rgb(rgb: &Rgb) -> Self {
+        let lab = Oklab::from_rgb(rgb);
+        let c = (lab.a * lab.a + lab.b * lab.b).sqrt();
+        let h = lab.b.atan2(lab.a).to_degrees();
+        Self::new(lab.l, c, (h + 360.0) % 360.0)
+    }
+}
+
+impl ToRgb for Oklch {
+    fn to_rgb(&self) -> Rgb {
+        let h = self.h.to_radians();
+        Oklab::new(
+            self.l,
+            h.cos() * self.c,
+            h.sin() * self.c
+        ).to_rgb()
+    }
+}
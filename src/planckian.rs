@@ -0,0 +1,29 @@
+use crate::cmf::{ CIE1931, CMF_START, CMF_STEP };
+
+/// Second radiation constant in meter kelvin, as used by CIE 15.
+const C2: f64 = 1.4388e-2;
+
+/// Relative spectral radiance of a blackbody at `kelvin`, for `nm`.
+pub(crate) fn planck(nm: f64, kelvin: f64) -> f64 {
+    let m = nm * 1e-9;
+    1.0 / (m.powf(5.0) * ((C2 / (m * kelvin)).exp() - 1.0))
+}
+
+/// CIE 1931 tristimulus values of a blackbody at `kelvin`, normalized to `Y = 1`.
+pub(crate) fn planckian_xyz(kelvin: f64) -> (f64, f64, f64) {
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    for (i, cmf) in CIE1931.iter().enumerate() {
+        let p = planck(CMF_START + CMF_STEP * i as f64, kelvin);
+        x += p * cmf[0];
+        y += p * cmf[1];
+        z += p * cmf[2];
+    }
+    (x / y, 1.0, z / y)
+}
+
+/// CIE 1960 (u, v) chromaticity of a blackbody at `kelvin`.
+pub(crate) fn planckian_uv(kelvin: f64) -> (f64, f64) {
+    let (x, y, z) = planckian_xyz(kelvin);
+    let temp = x + 15.0 * y + 3.0 * z;
+    (4.0 * x / temp, 6.0 * y / temp)
+}
@@ -0,0 +1,150 @@
+use crate::{ Cgats, CompareCie1976, CompareCie2000, CompareCmc, Lab };
+use std::fmt;
+
+/// The color difference used by a `QcReport`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DeltaE {
+    /// ΔE*ab, the CIE 1976 distance.
+    Cie1976,
+    /// ΔE00, the CIE 2000 distance.
+    Cie2000,
+    /// ΔE CMC(2:1), with the reference as the standard.
+    Cmc,
+}
+
+impl DeltaE {
+    /// The difference between `reference` and `measured`.
+    pub fn compare(&self, reference: &Lab, measured: &Lab) -> f64 {
+        match self {
+            DeltaE::Cie1976 => reference.compare_cie1976(measured),
+            DeltaE::Cie2000 => reference.compare_cie2000(measured),
+            DeltaE::Cmc => reference.compare_cmc(measured),
+        }
+    }
+}
+
+/// The difference of one patch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Patch {
+    pub id: String,
+    pub reference: Lab,
+    pub measured: Lab,
+    pub delta_e: f64,
+}
+
+/// A comparison of measured patches with their references, in the manner of ISO 12647
+/// and G7 conformance reports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QcReport {
+    /// The metric of the differences.
+    pub metric: DeltaE,
+    /// The largest difference for a patch to pass.
+    pub tolerance: f64,
+    /// The matched patches, in the order of the reference.
+    pub patches: Vec<Patch>,
+    /// The IDs of reference patches that were not measured, or whose colors are not finite.
+    pub missing: Vec<String>,
+}
+
+impl QcReport {
+    /// Compare the colors of `measured` with those of `reference` that have the same ID.
+    pub fn new<I: AsRef<str>>(
+        reference: &[(I, Lab)],
+        measured: &[(I, Lab)],
+        metric: DeltaE,
+        tolerance: f64
+    ) -> Self {
+        let mut patches = Vec::new();
+        let mut missing = Vec::new();
+        for (id, expected) in reference {
+            let id = id.as_ref();
+            let found = measured.iter().find(|(other, _)| other.as_ref() == id).map(|(_, found)| found);
+            // a measurement that is not a number cannot pass or be ranked, so it counts as missing
+            match found.map(|found| (found, metric.compare(expected, found))) {
+                Some((found, delta_e)) if delta_e.is_finite() => patches.push(Patch {
+                    id: id.into(),
+                    reference: *expected,
+                    measured: *found,
+                    delta_e,
+                }),
+                _ => missing.push(id.into()),
+            }
+        }
+        Self { metric, tolerance, patches, missing }
+    }
+
+    /// Compare the `Lab` rows of two measurement files, matched by sample ID.
+    pub fn from_cgats(reference: &Cgats, measured: &Cgats, metric: DeltaE, tolerance: f64) -> Self {
+        let rows = |cgats: &Cgats| -> Vec<(String, Lab)> {
+            (0..cgats.rows.len())
+                .filter_map(|row| Some((cgats.sample_id(row)?.to_string(), cgats.lab(row)?)))
+                .collect()
+        };
+        Self::new(&rows(reference), &rows(measured), metric, tolerance)
+    }
+
+    /// The mean difference, zero without patches.
+    pub fn mean(&self) -> f64 {
+        match self.patches.len() {
+            0 => 0.0,
+            n => self.patches.iter().map(|p| p.delta_e).sum::<f64>() / n as f64,
+        }
+    }
+
+    /// The largest difference, zero without patches.
+    pub fn max(&self) -> f64 {
+        self.patches.iter().map(|p| p.delta_e).fold(0.0, f64::max)
+    }
+
+    /// The difference below which `percent` (0 to 100) of the patches fall, interpolated
+    /// linearly between ranks. Zero without patches.
+    pub fn percentile(&self, percent: f64) -> f64 {
+        let mut values: Vec<f64> = self.patches.iter().map(|p| p.delta_e).collect();
+        if values.is_empty() {
+            return 0.0;
+        }
+        values.sort_by(f64::total_cmp);
+        let rank = percent.clamp(0.0, 100.0) / 100.0 * (values.len() - 1) as f64;
+        let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+        values[lo] + (values[hi] - values[lo]) * (rank - lo as f64)
+    }
+
+    /// The number of patches over the tolerance.
+    pub fn over_tolerance(&self) -> usize {
+        self.patches.iter().filter(|p| p.delta_e > self.tolerance).count()
+    }
+
+    /// Whether every reference patch was measured and is within the tolerance.
+    pub fn passes(&self) -> bool {
+        self.missing.is_empty() && self.over_tolerance() == 0
+    }
+
+    /// The `count` patches with the largest differences, largest first.
+    pub fn worst(&self, count: usize) -> Vec<&Patch> {
+        let mut patches: Vec<&Patch> = self.patches.iter().collect();
+        patches.sort_by(|a, b| b.delta_e.total_cmp(&a.delta_e));
+        patches.truncate(count);
+        patches
+    }
+}
+
+/// Patches listed by `Display`.
+const WORST: usize = 5;
+
+/// A summary with the statistics and the worst patches.
+impl fmt::Display for QcReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let metric = match self.metric {
+            DeltaE::Cie1976 => "ΔE*ab",
+            DeltaE::Cie2000 => "ΔE00",
+            DeltaE::Cmc => "ΔE CMC",
+        };
+        writeln!(f, "{} patches, {} missing", self.patches.len(), self.missing.len())?;
+        writeln!(f, "mean {:.2}  max {:.2}  95th percentile {:.2}  ({})", self.mean(), self.max(), self.percentile(95.0), metric)?;
+        writeln!(f, "{} over tolerance {:.2}", self.over_tolerance(), self.tolerance)?;
+        for patch in self.worst(WORST) {
+            writeln!(f, "{}\t{:.2}", patch.id, patch.delta_e)?;
+        }
+        Ok(())
+    }
+}
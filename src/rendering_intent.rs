@@ -0,0 +1,275 @@
+use crate::{ FromColor, Lab, Oklch, RgbSpace, Xyz };
+use crate::illuminant::D65_WHITE as WHITE;
+use crate::lab::{ lab_to_xyz, xyz_to_lab };
+use crate::rgb_space::solve3;
+
+/// A device color space that can be converted to and from the profile connection space,
+/// in the manner of an ICC profile.
+///
+/// The connection space is `Xyz` relative to the media white, which is placed on the
+/// D65 white of the other conversions. Device channels are in 0 to 1.
+pub trait Profile {
+    /// The number of device channels.
+    fn channels(&self) -> usize;
+
+    /// Convert device channels into media-relative `Xyz`.
+    fn device_to_xyz(&self, device: &[f64]) -> Xyz;
+
+    /// Convert media-relative `Xyz` into device channels, which may fall outside 0 to 1
+    /// for colors out of gamut.
+    fn xyz_to_device(&self, xyz: &Xyz) -> Vec<f64>;
+
+    /// The absolute `Xyz` of the media white, on the scale where a perfect diffuser has
+    /// a luminance of 100.
+    fn media_white(&self) -> Xyz;
+
+    /// Whether device channels can be reproduced.
+    fn contains(&self, device: &[f64]) -> bool {
+        device.iter().all(|&c| (-1e-9..=1.0 + 1e-9).contains(&c))
+    }
+
+    /// The media-relative black point, estimated as in ISO 18619 for output profiles and
+    /// kept neutral, on the chromaticity of the white.
+    ///
+    /// The initial estimate converts the connection space black to the device and back. A
+    /// ramp of neutral lightness is then round tripped through the device: if it follows
+    /// the input above its darkest fifth, the initial estimate is kept; otherwise the dark
+    /// end of the ramp is fitted with a quadratic, whose root is the black lightness.
+    fn black_point(&self) -> Xyz {
+        let round_trip = |xyz: &Xyz| {
+            let device: Vec<f64> = self.xyz_to_device(xyz).iter().map(|c| c.clamp(0.0, 1.0)).collect();
+            self.device_to_xyz(&device)
+        };
+        let initial = round_trip(&Xyz::new(0.0, 0.0, 0.0)).y.max(0.0) / WHITE.y;
+        let neutral = |y: f64| Xyz::new(WHITE.x * y, WHITE.y * y, WHITE.z * y);
+
+        let input: Vec<f64> = (0..RAMP).map(|i| 100.0 * i as f64 / (RAMP - 1) as f64).collect();
+        let mut output: Vec<f64> = input
+            .iter()
+            .map(|&l| xyz_to_lab(&round_trip(&lab_to_xyz(&Lab::new(l, 0.0, 0.0), &WHITE)), &WHITE).l)
+            .collect();
+        for i in (0..RAMP - 1).rev() {
+            output[i] = output[i].min(output[i + 1]);
+        }
+        let (min, max) = (output[0], output[RAMP - 1]);
+        if min >= max {
+            return neutral(initial);
+        }
+        let straight = input
+            .iter()
+            .zip(&output)
+            .all(|(&l, &out)| l <= min + 0.2 * (max - min) || (l - out).abs() < 4.0);
+        if straight {
+            return neutral(initial);
+        }
+
+        // the shadow section of the ramp, scaled so the black is 0 and the white 1
+        let (x, y): (Vec<f64>, Vec<f64>) = input
+            .iter()
+            .zip(&output)
+            .map(|(&l, &out)| (l, (out - min) / (max - min)))
+            .filter(|&(_, y)| (0.1..0.5).contains(&y))
+            .unzip();
+        match quadratic_root(&x, &y) {
+            Some(l) => neutral(lab_to_xyz(&Lab::new(l, 0.0, 0.0), &WHITE).y / WHITE.y),
+            None => neutral(initial),
+        }
+    }
+}
+
+/// Steps of the lightness ramp of `Profile::black_point`.
+const RAMP: usize = 256;
+
+/// The root, clamped to 0 to 50, of the least squares quadratic through the points, or
+/// `None` with fewer than three points.
+fn quadratic_root(x: &[f64], y: &[f64]) -> Option<f64> {
+    if x.len() < 3 {
+        return None;
+    }
+    // normal equations for y = a x² + b x + c
+    let mut m = [[0.0; 3]; 3];
+    let mut v = [0.0; 3];
+    for (&x, &y) in x.iter().zip(y) {
+        let powers = [x * x, x, 1.0];
+        for j in 0..3 {
+            for k in 0..3 {
+                m[j][k] += powers[j] * powers[k];
+            }
+            v[j] += powers[j] * y;
+        }
+    }
+    let [a, b, c] = solve3(&m, &v)?;
+    let root = match a.abs() < 1e-10 {
+        true => -c / b,
+        false => {
+            let d = b * b - 4.0 * a * c;
+            if d <= 0.0 {
+                return Some(0.0);
+            }
+            (-b + d.sqrt()) / (2.0 * a)
+        }
+    };
+    Some(root.clamp(0.0, 50.0))
+}
+
+impl Profile for RgbSpace {
+    fn channels(&self) -> usize {
+        3
+    }
+
+    fn device_to_xyz(&self, device: &[f64]) -> Xyz {
+        self.to_xyz([device[0], device[1], device[2]])
+    }
+
+    fn xyz_to_device(&self, xyz: &Xyz) -> Vec<f64> {
+        self.from_xyz(xyz).to_vec()
+    }
+
+    fn media_white(&self) -> Xyz {
+        let (x, y) = self.white;
+        Xyz::new(100.0 * x / y, 100.0, 100.0 * (1.0 - x - y) / y)
+    }
+
+    fn black_point(&self) -> Xyz {
+        Xyz::new(0.0, 0.0, 0.0)
+    }
+}
+
+/// The ICC rendering intents.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RenderingIntent {
+    /// Black point compensation followed by a soft compression of chroma at constant
+    /// Oklch lightness and hue, so the whole source gamut fits the destination while
+    /// colors well inside both are left alone.
+    Perceptual,
+    /// Media-relative colorimetry: colors in both gamuts match relative to the media
+    /// white, the others are clipped.
+    RelativeColorimetric,
+    /// Black point compensation followed by scaling chroma by the ratio of the two gamut
+    /// boundaries, so saturated colors stay fully saturated.
+    Saturation,
+    /// Colorimetry relative to the absolute white, simulating the source media.
+    AbsoluteColorimetric,
+}
+
+/// A conversion from one profile to another with a rendering intent.
+pub struct Transform<'a, S: ?Sized, D: ?Sized> {
+    source: &'a S,
+    destination: &'a D,
+    intent: RenderingIntent,
+    scale: [f64; 3],
+    offset: [f64; 3],
+}
+
+impl<'a, S: Profile + ?Sized, D: Profile + ?Sized> Transform<'a, S, D> {
+    /// Create a transform from `source` to `destination`.
+    ///
+    /// `black_point_compensation` applies the ISO 18619 scaling of the source black point
+    /// onto the destination black point, both estimated by `Profile::black_point`. It is always applied for the perceptual and
+    /// saturation intents and never for the absolute colorimetric intent.
+    pub fn new(source: &'a S, destination: &'a D, intent: RenderingIntent, black_point_compensation: bool) -> Self {
+        let bpc = match intent {
+            RenderingIntent::Perceptual | RenderingIntent::Saturation => true,
+            RenderingIntent::RelativeColorimetric => black_point_compensation,
+            RenderingIntent::AbsoluteColorimetric => false,
+        };
+        let (mut scale, mut offset) = ([1.0; 3], [0.0; 3]);
+        if bpc {
+            let (from, to) = (source.black_point(), destination.black_point());
+            let white = [WHITE.x, WHITE.y, WHITE.z];
+            let (from, to) = ([from.x, from.y, from.z], [to.x, to.y, to.z]);
+            for k in 0..3 {
+                // the line through the two whites and from the source to the destination black
+                let t = from[k] - white[k];
+                scale[k] = (to[k] - white[k]) / t;
+                offset[k] = -white[k] * (to[k] - from[k]) / t;
+            }
+        }
+        if intent == RenderingIntent::AbsoluteColorimetric {
+            let (from, to) = (source.media_white(), destination.media_white());
+            scale = [from.x / to.x, from.y / to.y, from.z / to.z];
+        }
+        Self { source, destination, intent, scale, offset }
+    }
+
+    /// Convert the device channels of a source color into destination device channels.
+    pub fn apply(&self, device: &[f64]) -> Vec<f64> {
+        let xyz = self.source.device_to_xyz(device);
+        let xyz = Xyz::new(
+            xyz.x * self.scale[0] + self.offset[0],
+            xyz.y * self.scale[1] + self.offset[1],
+            xyz.z * self.scale[2] + self.offset[2]
+        );
+        let xyz = match self.intent {
+            RenderingIntent::Perceptual | RenderingIntent::Saturation => self.map_chroma(&xyz),
+            _ => xyz,
+        };
+        self.destination.xyz_to_device(&xyz).iter().map(|c| c.clamp(0.0, 1.0)).collect()
+    }
+
+    fn map_chroma(&self, xyz: &Xyz) -> Xyz {
+        let color = Oklch::from_color(xyz);
+        if color.c < 1e-9 {
+            return *xyz;
+        }
+        // the source boundary is found after black point compensation, as seen by the destination
+        let source = |x: &Xyz| {
+            let x = Xyz::new(
+                (x.x - self.offset[0]) / self.scale[0],
+                (x.y - self.offset[1]) / self.scale[1],
+                (x.z - self.offset[2]) / self.scale[2]
+            );
+            reproduces(self.source, &x)
+        };
+        let destination = |x: &Xyz| reproduces(self.destination, x);
+        let from = boundary(&color, source).max(color.c);
+        let to = boundary(&color, destination);
+        let c = match self.intent {
+            RenderingIntent::Saturation => color.c * to / from,
+            _ if from <= to => color.c,
+            _ => {
+                let knee = KNEE * to;
+                match color.c <= knee {
+                    true => color.c,
+                    false => knee + (color.c - knee) * (to - knee) / (from - knee),
+                }
+            }
+        };
+        Xyz::from_color(&Oklch::new(color.l, c, color.h))
+    }
+}
+
+/// Whether `profile` reproduces `xyz`: its device channels are in range and convert back
+/// to the same color, which catches inversions that clamp.
+fn reproduces<P: Profile + ?Sized>(profile: &P, xyz: &Xyz) -> bool {
+    let device = profile.xyz_to_device(xyz);
+    let back = profile.device_to_xyz(&device);
+    profile.contains(&device)
+        && (back.x - xyz.x).abs() < REPRODUCTION
+        && (back.y - xyz.y).abs() < REPRODUCTION
+        && (back.z - xyz.z).abs() < REPRODUCTION
+}
+
+/// Largest difference in `Xyz` (0 to 100) for a color to count as reproduced.
+const REPRODUCTION: f64 = 0.01;
+
+/// Fraction of the destination chroma left untouched by the perceptual intent.
+const KNEE: f64 = 0.8;
+
+/// The largest Oklch chroma at the lightness and hue of `color` accepted by `inside`.
+fn boundary<F: Fn(&Xyz) -> bool>(color: &Oklch, inside: F) -> f64 {
+    let at = |c: f64| Xyz::from_color(&Oklch::new(color.l, c, color.h));
+    let mut hi = 0.1;
+    while inside(&at(hi)) && hi < 10.0 {
+        hi *= 2.0;
+    }
+    let mut lo = 0.0;
+    for _ in 0..40 {
+        let mid = (lo + hi) / 2.0;
+        match inside(&at(mid)) {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    lo
+}
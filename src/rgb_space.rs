@@ -0,0 +1,270 @@
+use crate::{ FromRgb, Rgb, ToRgb, Xyz };
+
+/// Transfer function between linear light and the encoded channels of an RGB space.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TransferFunction {
+    /// No encoding, channels are linear light.
+    Linear,
+    /// The sRGB piecewise curve, also used by Display P3.
+    Srgb,
+    /// A pure power law with the given exponent.
+    Gamma(f64),
+    /// The ITU-R BT.2020 (and BT.709) camera curve.
+    Rec2020,
+    /// The ROMM RGB (ProPhoto) curve, a power of 1.8 with a linear toe.
+    ProPhoto,
+}
+
+impl TransferFunction {
+    /// Encode a linear channel value. Negative values are mirrored, so out of gamut
+    /// colors round trip.
+    pub fn encode(&self, linear: f64) -> f64 {
+        let (sign, v) = (linear.signum(), linear.abs());
+        sign * match self {
+            TransferFunction::Linear => v,
+            TransferFunction::Srgb => match v <= 0.0031308 {
+                true => 12.92 * v,
+                false => 1.055 * v.powf(1.0 / 2.4) - 0.055,
+            },
+            TransferFunction::Gamma(g) => v.powf(1.0 / g),
+            TransferFunction::Rec2020 => match v < REC2020_BETA {
+                true => 4.5 * v,
+                false => REC2020_ALPHA * v.powf(0.45) - (REC2020_ALPHA - 1.0),
+            },
+            TransferFunction::ProPhoto => match v < 1.0 / 512.0 {
+                true => 16.0 * v,
+                false => v.powf(1.0 / 1.8),
+            },
+        }
+    }
+
+    /// Decode an encoded channel value into linear light.
+    pub fn decode(&self, encoded: f64) -> f64 {
+        let (sign, v) = (encoded.signum(), encoded.abs());
+        sign * match self {
+            TransferFunction::Linear => v,
+            TransferFunction::Srgb => match v <= 0.04045 {
+                true => v / 12.92,
+                false => ((v + 0.055) / 1.055).powf(2.4),
+            },
+            TransferFunction::Gamma(g) => v.powf(*g),
+            TransferFunction::Rec2020 => match v < 4.5 * REC2020_BETA {
+                true => v / 4.5,
+                false => ((v + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45),
+            },
+            TransferFunction::ProPhoto => match v < 16.0 / 512.0 {
+                true => v / 16.0,
+                false => v.powf(1.8),
+            },
+        }
+    }
+}
+
+pub(crate) const REC2020_ALPHA: f64 = 1.099_296_826_809_44;
+pub(crate) const REC2020_BETA: f64 = 0.018_053_968_510_807;
+
+/// An RGB working space, defined by the chromaticities of its primaries and white point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RgbSpace {
+    /// CIE 1931 xy chromaticity of the red primary.
+    pub red: (f64, f64),
+    /// CIE 1931 xy chromaticity of the green primary.
+    pub green: (f64, f64),
+    /// CIE 1931 xy chromaticity of the blue primary.
+    pub blue: (f64, f64),
+    /// CIE 1931 xy chromaticity of the white point.
+    pub white: (f64, f64),
+    /// Encoding of the channels.
+    pub transfer: TransferFunction,
+}
+
+const D65_XY: (f64, f64) = (0.3127, 0.3290);
+const D50_XY: (f64, f64) = (0.3457, 0.3585);
+
+impl RgbSpace {
+    /// IEC 61966-2-1 sRGB, the space of the `Rgb` type.
+    pub const SRGB: RgbSpace = RgbSpace {
+        red: (0.64, 0.33),
+        green: (0.30, 0.60),
+        blue: (0.15, 0.06),
+        white: D65_XY,
+        transfer: TransferFunction::Srgb,
+    };
+
+    /// Display P3, with the DCI-P3 primaries, a D65 white and the sRGB curve.
+    pub const DISPLAY_P3: RgbSpace = RgbSpace {
+        red: (0.680, 0.320),
+        green: (0.265, 0.690),
+        blue: (0.150, 0.060),
+        white: D65_XY,
+        transfer: TransferFunction::Srgb,
+    };
+
+    /// ITU-R BT.2020.
+    pub const REC2020: RgbSpace = RgbSpace {
+        red: (0.708, 0.292),
+        green: (0.170, 0.797),
+        blue: (0.131, 0.046),
+        white: D65_XY,
+        transfer: TransferFunction::Rec2020,
+    };
+
+    /// Adobe RGB (1998).
+    pub const ADOBE_RGB: RgbSpace = RgbSpace {
+        red: (0.64, 0.33),
+        green: (0.21, 0.71),
+        blue: (0.15, 0.06),
+        white: D65_XY,
+        transfer: TransferFunction::Gamma(563.0 / 256.0),
+    };
+
+    /// ROMM RGB (ProPhoto), with a D50 white.
+    pub const PROPHOTO_RGB: RgbSpace = RgbSpace {
+        red: (0.7347, 0.2653),
+        green: (0.1596, 0.8404),
+        blue: (0.0366, 0.0001),
+        white: D50_XY,
+        transfer: TransferFunction::ProPhoto,
+    };
+
+    /// The matrix from linear RGB to XYZ relative to the white of this space, with
+    /// the white at a luminance of 1.
+    pub fn to_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        let column = |(x, y): (f64, f64)| [x / y, 1.0, (1.0 - x - y) / y];
+        let (r, g, b) = (column(self.red), column(self.green), column(self.blue));
+        let primaries = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+        let s = mul_vec(&inverse(&primaries), column(self.white));
+        let mut m = primaries;
+        for row in m.iter_mut() {
+            for (value, s) in row.iter_mut().zip(s.iter()) {
+                *value *= s;
+            }
+        }
+        m
+    }
+
+    /// The matrix from XYZ relative to the white of this space to linear RGB.
+    pub fn from_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        inverse(&self.to_xyz_matrix())
+    }
+
+    /// Convert a D65-relative `Xyz` color (0 to 100) into linear channels (0 to 1) of this
+    /// space, with a Bradford adaptation when its white point is not D65.
+    pub fn linear_from_xyz(&self, xyz: &Xyz) -> [f64; 3] {
+        let v = [xyz.x / 100.0, xyz.y / 100.0, xyz.z / 100.0];
+        mul_vec(&self.from_xyz_matrix(), mul_vec(&bradford(white_xyz(D65_XY), white_xyz(self.white)), v))
+    }
+
+    /// Convert linear channels (0 to 1) of this space into a D65-relative `Xyz` color.
+    pub fn linear_to_xyz(&self, linear: [f64; 3]) -> Xyz {
+        let v = mul_vec(&bradford(white_xyz(self.white), white_xyz(D65_XY)), mul_vec(&self.to_xyz_matrix(), linear));
+        Xyz::new(v[0] * 100.0, v[1] * 100.0, v[2] * 100.0)
+    }
+
+    /// Convert a D65-relative `Xyz` color into encoded channels (0 to 1) of this space.
+    pub fn from_xyz(&self, xyz: &Xyz) -> [f64; 3] {
+        let linear = self.linear_from_xyz(xyz);
+        [self.transfer.encode(linear[0]), self.transfer.encode(linear[1]), self.transfer.encode(linear[2])]
+    }
+
+    /// Convert encoded channels (0 to 1) of this space into a D65-relative `Xyz` color.
+    pub fn to_xyz(&self, encoded: [f64; 3]) -> Xyz {
+        let t = &self.transfer;
+        self.linear_to_xyz([t.decode(encoded[0]), t.decode(encoded[1]), t.decode(encoded[2])])
+    }
+
+    /// Convert an `Rgb` color into encoded channels (0 to 1) of this space. For sRGB this
+    /// is exact, without the rounding of a trip through `Xyz`.
+    pub fn from_rgb(&self, rgb: &Rgb) -> [f64; 3] {
+        match *self == RgbSpace::SRGB {
+            true => [rgb.r / 255.0, rgb.g / 255.0, rgb.b / 255.0],
+            false => self.from_xyz(&Xyz::from_rgb(rgb)),
+        }
+    }
+
+    /// Convert encoded channels (0 to 1) of this space into an `Rgb` color.
+    pub fn to_rgb(&self, encoded: [f64; 3]) -> Rgb {
+        match *self == RgbSpace::SRGB {
+            true => Rgb::new(encoded[0] * 255.0, encoded[1] * 255.0, encoded[2] * 255.0),
+            false => self.to_xyz(encoded).to_rgb(),
+        }
+    }
+}
+
+pub(crate) fn white_xyz((x, y): (f64, f64)) -> [f64; 3] {
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+const BRADFORD: [[f64; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// The Bradford chromatic adaptation matrix from the white `from` to the white `to`.
+pub(crate) fn bradford(from: [f64; 3], to: [f64; 3]) -> [[f64; 3]; 3] {
+    let (s, d) = (mul_vec(&BRADFORD, from), mul_vec(&BRADFORD, to));
+    let scale = [
+        [d[0] / s[0], 0.0, 0.0],
+        [0.0, d[1] / s[1], 0.0],
+        [0.0, 0.0, d[2] / s[2]],
+    ];
+    mul(&inverse(&BRADFORD), &mul(&scale, &BRADFORD))
+}
+
+pub(crate) fn mul_vec(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+pub(crate) fn mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut m = [[0.0; 3]; 3];
+    for (i, row) in m.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    m
+}
+
+pub(crate) fn inverse(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let adjugate = [
+        [cofactor(1, 2, 1, 2), -cofactor(0, 2, 1, 2), cofactor(0, 1, 1, 2)],
+        [-cofactor(1, 2, 0, 2), cofactor(0, 2, 0, 2), -cofactor(0, 1, 0, 2)],
+        [cofactor(1, 2, 0, 1), -cofactor(0, 2, 0, 1), cofactor(0, 1, 0, 1)],
+    ];
+    let det = m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+    let mut inv = adjugate;
+    for row in inv.iter_mut() {
+        for value in row.iter_mut() {
+            *value /= det;
+        }
+    }
+    inv
+}
+
+/// Solve the 3x3 linear system `m * x = v` by Cramer's rule, or `None` when `m` is singular.
+pub(crate) fn solve3(m: &[[f64; 3]; 3], v: &[f64; 3]) -> Option<[f64; 3]> {
+    let det = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det(m);
+    if d.abs() < 1e-300 {
+        return None;
+    }
+    let mut x = [0.0; 3];
+    for (k, xk) in x.iter_mut().enumerate() {
+        let mut mk = *m;
+        for j in 0..3 {
+            mk[j][k] = v[j];
+        }
+        *xk = det(&mk) / d;
+    }
+    Some(x)
+}
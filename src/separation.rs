@@ -0,0 +1,141 @@
+use crate::{ Cmy, Cmyk, FromRgb, ToRgb };
+
+/// Settings for separating colors into printable CMYK.
+///
+/// The separation follows the multiplicative model of `Cmyk`, where a channel of the
+/// composite CMY is `c · (1 − k) + k`. Black replaces part of the gray component
+/// `min(c, m, y)` of the composite, so colors are preserved until the ink limits apply.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Separation {
+    /// Gray component replacement: the fraction (0 to 1) of the gray component of every
+    /// color that black replaces.
+    pub gcr: f64,
+    /// Under-color removal: the fraction (0 to 1) of the gray component that black
+    /// additionally replaces in neutral colors, fading out as colors get more chromatic.
+    pub ucr: f64,
+    /// The gray component (0 to 1) below which no black is generated.
+    pub black_start: f64,
+    /// The fraction (0 to 1) of the range above `black_start` over which black eases in
+    /// to its full amount. Zero starts it abruptly.
+    pub black_width: f64,
+    /// The largest black (0 to 1).
+    pub max_black: f64,
+    /// The total area coverage limit, the largest sum of the four inks (0 to 4).
+    pub total_ink: f64,
+}
+
+impl Separation {
+    /// The separation of `Cmyk::from_rgb`: full gray component replacement and no limits.
+    pub const NAIVE: Separation = Separation {
+        gcr: 1.0,
+        ucr: 0.0,
+        black_start: 0.0,
+        black_width: 0.0,
+        max_black: 1.0,
+        total_ink: 4.0,
+    };
+
+    /// The black (0 to 1) generated for a composite with the gray component `gray` and
+    /// the chromatic part `chroma`, the spread between its largest and smallest channel.
+    pub fn black(&self, gray: f64, chroma: f64) -> f64 {
+        let end = self.black_start + self.black_width * (1.0 - self.black_start);
+        let ease = match gray {
+            g if g <= self.black_start => 0.0,
+            g if g >= end => 1.0,
+            g => {
+                let t = (g - self.black_start) / (end - self.black_start);
+                t * t * (3.0 - 2.0 * t)
+            }
+        };
+        let neutral = 1.0 - (chroma / (gray + chroma).max(1e-9)).min(1.0);
+        let amount = (self.gcr + self.ucr * neutral).min(1.0);
+        (gray * ease * amount).min(self.max_black)
+    }
+
+    /// Separate a color into CMYK, then apply the ink limits.
+    pub fn separate<T: ToRgb>(&self, color: &T) -> Cmyk {
+        let cmy = Cmy::from_rgb(&color.to_rgb());
+        let cmy = [cmy.c.clamp(0.0, 1.0), cmy.m.clamp(0.0, 1.0), cmy.y.clamp(0.0, 1.0)];
+        let gray = cmy[0].min(cmy[1]).min(cmy[2]);
+        let chroma = cmy[0].max(cmy[1]).max(cmy[2]) - gray;
+        let k = self.black(gray, chroma);
+        let cmyk = from_composite(cmy, k);
+        cmyk.limit_ink(self.total_ink, self.max_black)
+    }
+}
+
+impl Default for Separation {
+    /// Medium gray component replacement with black starting in the quarter tones,
+    /// 95% black and 300% total coverage, typical of coated offset.
+    fn default() -> Self {
+        Separation {
+            gcr: 0.5,
+            ucr: 0.5,
+            black_start: 0.25,
+            black_width: 0.5,
+            max_black: 0.95,
+            total_ink: 3.0,
+        }
+    }
+}
+
+/// The CMYK with black `k` and the composite channels `cmy`.
+fn from_composite(cmy: [f64; 3], k: f64) -> Cmyk {
+    match k >= 1.0 - 1e-9 {
+        true => Cmyk::new(0.0, 0.0, 0.0, 1.0),
+        false => {
+            let ink = |v: f64| ((v - k) / (1.0 - k)).clamp(0.0, 1.0);
+            Cmyk::new(ink(cmy[0]), ink(cmy[1]), ink(cmy[2]), k)
+        }
+    }
+}
+
+/// Black levels tried when replacing more of the gray component to meet an ink limit.
+const LIMIT_STEPS: usize = 64;
+
+impl Cmyk {
+    /// The total area coverage, the sum of the four inks (0 to 4).
+    pub fn total_ink(&self) -> f64 {
+        self.c + self.m + self.y + self.k
+    }
+
+    /// Bring the total area coverage within `total_ink` (0 to 4), keeping black within
+    /// `max_black`.
+    ///
+    /// Black first replaces more of the gray component, which keeps the color. When that
+    /// is not enough, cyan, magenta and yellow are scaled down together, which keeps the
+    /// hue. Colors within the limits are returned unchanged.
+    pub fn limit_ink(&self, total_ink: f64, max_black: f64) -> Cmyk {
+        if self.total_ink() <= total_ink && self.k <= max_black {
+            return *self;
+        }
+        let composite = [
+            self.c * (1.0 - self.k) + self.k,
+            self.m * (1.0 - self.k) + self.k,
+            self.y * (1.0 - self.k) + self.k,
+        ];
+        let k = self.k.min(max_black);
+        let current = from_composite(composite, k);
+        if current.total_ink() <= total_ink {
+            return current;
+        }
+        let most = composite[0].min(composite[1]).min(composite[2]).min(max_black).max(k);
+        let mut best = current;
+        for i in 1..=LIMIT_STEPS {
+            let candidate = from_composite(composite, k + (most - k) * i as f64 / LIMIT_STEPS as f64);
+            if candidate.total_ink() < best.total_ink() {
+                best = candidate;
+            }
+            if best.total_ink() <= total_ink {
+                return best;
+            }
+        }
+        let k = best.k.min(total_ink);
+        let cmy = best.c + best.m + best.y;
+        let scale = match cmy > 0.0 {
+            true => ((total_ink - k) / cmy).clamp(0.0, 1.0),
+            false => 0.0,
+        };
+        Cmyk::new(best.c * scale, best.m * scale, best.y * scale, k)
+    }
+}
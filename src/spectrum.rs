@@ -0,0 +1,114 @@
+use crate::{ Observer, Xyz };
+use std::ops::Mul;
+
+/// A sampled spectral distribution, such as a reflectance or a spectral power distribution.
+///
+/// `wavelengths` are in nanometers and must be sorted in increasing order, with one
+/// entry in `values` for each wavelength.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Spectrum {
+    pub wavelengths: Vec<f64>,
+    pub values: Vec<f64>,
+}
+
+impl Spectrum {
+    /// Create a new spectrum from `values` sampled at `wavelengths`.
+    pub fn new(wavelengths: Vec<f64>, values: Vec<f64>) -> Self {
+        assert_eq!(wavelengths.len(), values.len());
+        Self { wavelengths, values }
+    }
+
+    /// Create a new spectrum from `values` sampled every `step` nanometers from `start`.
+    pub fn uniform(start: f64, step: f64, values: Vec<f64>) -> Self {
+        let wavelengths = (0..values.len()).map(|i| start + step * i as f64).collect();
+        Self { wavelengths, values }
+    }
+
+    /// Create a new spectrum from a function evaluated every `step` nanometers from
+    /// `start` to `end`, inclusive.
+    pub fn from_fn<F: Fn(f64) -> f64>(start: f64, end: f64, step: f64, f: F) -> Self {
+        let count = ((end - start) / step).round() as usize + 1;
+        let wavelengths: Vec<f64> = (0..count).map(|i| start + step * i as f64).collect();
+        let values = wavelengths.iter().map(|&nm| f(nm)).collect();
+        Self { wavelengths, values }
+    }
+
+    /// The value at `nm`, linearly interpolated between samples. Outside the sampled
+    /// range the nearest sample is used, as recommended by CIE 15.
+    pub fn value_at(&self, nm: f64) -> f64 {
+        let n = self.wavelengths.len();
+        if n == 0 {
+            return 0.0;
+        }
+        if nm <= self.wavelengths[0] {
+            return self.values[0];
+        }
+        if nm >= self.wavelengths[n - 1] {
+            return self.values[n - 1];
+        }
+        let i = self.wavelengths.partition_point(|&w| w <= nm) - 1;
+        let (w0, w1) = (self.wavelengths[i], self.wavelengths[i + 1]);
+        let f = (nm - w0) / (w1 - w0);
+        self.values[i] + (self.values[i + 1] - self.values[i]) * f
+    }
+
+    /// Resample the spectrum every `step` nanometers from `start` to `end`, inclusive.
+    pub fn resample(&self, start: f64, end: f64, step: f64) -> Self {
+        Self::from_fn(start, end, step, |nm| self.value_at(nm))
+    }
+
+    /// Multiply every value by `factor`.
+    pub fn scale(&self, factor: f64) -> Self {
+        Self::new(self.wavelengths.clone(), self.values.iter().map(|v| v * factor).collect())
+    }
+
+    /// Convert an emission spectrum into an `Xyz` color, normalized to a luminance of 100.
+    pub fn to_xyz(&self, observer: Observer) -> Xyz {
+        integrate(observer, |nm| self.value_at(nm), |nm| self.value_at(nm))
+    }
+
+    /// Convert a reflectance (or transmittance) spectrum into the `Xyz` color it has
+    /// under `illuminant`, normalized so the perfect reflector has a luminance of 100.
+    pub fn to_xyz_reflective(&self, illuminant: &Spectrum, observer: Observer) -> Xyz {
+        integrate(
+            observer,
+            |nm| self.value_at(nm) * illuminant.value_at(nm),
+            |nm| illuminant.value_at(nm)
+        )
+    }
+}
+
+/// Weighted summation of the color matching functions at their tabulated 5 nm
+/// interval, as in ASTM E308, with `k` chosen so `white` integrates to `Y = 100`.
+fn integrate<F, W>(observer: Observer, stimulus: F, white: W) -> Xyz
+where
+    F: Fn(f64) -> f64,
+    W: Fn(f64) -> f64,
+{
+    let (mut x, mut y, mut z, mut n) = (0.0, 0.0, 0.0, 0.0);
+    for (nm, cmf) in observer.wavelengths().zip(observer.table().iter()) {
+        let s = stimulus(nm);
+        x += s * cmf[0];
+        y += s * cmf[1];
+        z += s * cmf[2];
+        n += white(nm) * cmf[1];
+    }
+    if n == 0.0 {
+        return Xyz::new(0.0, 0.0, 0.0);
+    }
+    let k = 100.0 / n;
+    Xyz::new(x * k, y * k, z * k)
+}
+
+impl Mul for &Spectrum {
+    type Output = Spectrum;
+
+    /// Multiply two spectra, sampled at the wavelengths of the left-hand side.
+    fn mul(self, other: &Spectrum) -> Spectrum {
+        let values = self.wavelengths.iter()
+            .zip(self.values.iter())
+            .map(|(&nm, v)| v * other.value_at(nm))
+            .collect();
+        Spectrum::new(self.wavelengths.clone(), values)
+    }
+}
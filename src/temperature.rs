@@ -0,0 +1,96 @@
+use crate::{ Rgb, ToRgb, Xyz, Yxy };
+use crate::chromaticity::{ uv_to_xy, xy_to_uv };
+use crate::planckian::planckian_uv;
+use crate::xyz::srgb_to_linear;
+
+/// Locus of white points to generate colors from a temperature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Locus {
+    /// The Planckian (blackbody) locus.
+    Planckian,
+    /// The CIE D-series daylight locus, defined from 4000K to 25000K. Temperatures
+    /// outside that range are clamped to it.
+    Daylight,
+}
+
+pub trait FromTemperature {
+    /// Create a white of the given temperature in kelvin, offset by `duv`
+    /// perpendicular to the `locus` in the CIE 1960 UCS diagram (positive above it).
+    fn from_temperature(kelvin: f64, duv: f64, locus: Locus) -> Self;
+}
+
+/// Range of temperatures over which CIE 15 defines the daylight locus.
+const DAYLIGHT_RANGE: (f64, f64) = (4000.0, 25000.0);
+
+/// CIE 1931 (x, y) chromaticity of the CIE D-series daylight at `kelvin`, clamped to
+/// `DAYLIGHT_RANGE`.
+pub(crate) fn daylight_xy(kelvin: f64) -> (f64, f64) {
+    let t = kelvin.clamp(DAYLIGHT_RANGE.0, DAYLIGHT_RANGE.1);
+    let x = if t <= 7000.0 {
+        -4.6070e9 / t.powf(3.0) + 2.9678e6 / (t * t) + 0.09911e3 / t + 0.244063
+    } else {
+        -2.0064e9 / t.powf(3.0) + 1.9018e6 / (t * t) + 0.24748e3 / t + 0.237040
+    };
+    (x, -3.0 * x * x + 2.870 * x - 0.275)
+}
+
+fn daylight_uv(kelvin: f64) -> (f64, f64) {
+    let (x, y) = daylight_xy(kelvin);
+    xy_to_uv(x, y)
+}
+
+fn locus_uv(kelvin: f64, locus: Locus) -> (f64, f64) {
+    match locus {
+        Locus::Planckian => planckian_uv(kelvin),
+        Locus::Daylight => daylight_uv(kelvin),
+    }
+}
+
+fn temperature_xy(kelvin: f64, duv: f64, locus: Locus) -> (f64, f64) {
+    let kelvin = match locus {
+        Locus::Planckian => kelvin,
+        Locus::Daylight => kelvin.clamp(DAYLIGHT_RANGE.0, DAYLIGHT_RANGE.1),
+    };
+    let (u, v) = locus_uv(kelvin, locus);
+    if duv == 0.0 {
+        return uv_to_xy(u, v);
+    }
+    let dt = kelvin * 1e-4;
+    let (u0, v0) = locus_uv(kelvin - dt, locus);
+    let (u1, v1) = locus_uv(kelvin + dt, locus);
+    let (du, dv) = (u1 - u0, v1 - v0);
+    let len = (du * du + dv * dv).sqrt();
+    // the normal pointing to increasing v, as u always decreases with temperature
+    let (nu, nv) = match du < 0.0 {
+        true => (dv / len, -du / len),
+        false => (-dv / len, du / len),
+    };
+    uv_to_xy(u + nu * duv, v + nv * duv)
+}
+
+impl FromTemperature for Yxy {
+    /// The resulting color has a luminance of 100.
+    fn from_temperature(kelvin: f64, duv: f64, locus: Locus) -> Self {
+        let (x, y) = temperature_xy(kelvin, duv, locus);
+        Yxy::new(100.0, x, y)
+    }
+}
+
+impl FromTemperature for Xyz {
+    /// The resulting color has a luminance of 100.
+    fn from_temperature(kelvin: f64, duv: f64, locus: Locus) -> Self {
+        let (x, y) = temperature_xy(kelvin, duv, locus);
+        Xyz::new(x / y * 100.0, 100.0, (1.0 - x - y) / y * 100.0)
+    }
+}
+
+impl FromTemperature for Rgb {
+    /// The resulting color is scaled so its brightest channel is 255. Temperatures
+    /// outside the sRGB gamut (such as those below about 1900K) have negative channels.
+    fn from_temperature(kelvin: f64, duv: f64, locus: Locus) -> Self {
+        let xyz = Xyz::from_temperature(kelvin, duv, locus);
+        let rgb = xyz.to_rgb();
+        let max = srgb_to_linear(rgb.r.max(rgb.g).max(rgb.b) / 255.0);
+        Xyz::new(xyz.x / max, xyz.y / max, xyz.z / max).to_rgb()
+    }
+}
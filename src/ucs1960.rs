@@ -0,0 +1,55 @@
+use crate::{ Rgb, FromRgb, ToRgb, Xyz, approx };
+
+/// A CIE 1960 UCS color (luminance, u, v).
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Ucs1960 {
+    pub y: f64,
+    pub u: f64,
+    pub v: f64,
+}
+
+impl Ucs1960 {
+    /// Create a new CIE 1960 UCS color.
+    /// 
+    /// `y`: luminance component (0 to 100).
+    /// 
+    /// `u`: chromaticity coordinate (0 to 0.62).
+    /// 
+    /// `v`: chromaticity coordinate (0 to 0.36).
+    #[inline]
+    pub fn new(y: f64, u: f64, v: f64) -> Self {
+        Self { y, u, v }
+    }
+}
+
+impl PartialEq for Ucs1960 {
+    fn eq(&self, other: &Self) -> bool {
+        approx(self.y, other.y) &&
+        approx(self.u, other.u) &&
+        approx(self.v, other.v)
+    }
+}
+
+impl FromRgb for Ucs1960 {
+    fn from_rgb(rgb: &Rgb) -> Self {
+        let xyz = Xyz::from_rgb(rgb);
+        let temp = xyz.x + 15.0 * xyz.y + 3.0 * xyz.z;
+        match temp == 0.0 {
+            true => Self::new(xyz.y, 0.0, 0.0),
+            false => Self::new(xyz.y, 4.0 * xyz.x / temp, 6.0 * xyz.y / temp),
+        }
+    }
+}
+
+impl ToRgb for Ucs1960 {
+    fn to_rgb(&self) -> Rgb {
+        if self.v == 0.0 {
+            return Xyz::new(0.0, self.y, 0.0).to_rgb();
+        }
+        Xyz::new(
+            1.5 * self.u / self.v * self.y,
+            self.y,
+            (4.0 - self.u - 10.0 * self.v) / (2.0 * self.v) * self.y
+        ).to_rgb()
+    }
+}
@@ -0,0 +1,483 @@
+use crate::{ FromColor, Illuminant, Lab, Observer, Rgb, Spectrum, ToRgb, Xyz };
+use crate::xyz::srgb_to_linear;
+use crate::rgb_space::solve3;
+use crate::upsampling_tables::{ SRGB_MALLETT_YUKSEL, SRGB_SIGMOID };
+use std::sync::OnceLock;
+
+/// Method used to turn a color into a reflectance spectrum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Upsampling {
+    /// Smits (1999) piecewise basis of white, cyan, magenta, yellow, red, green and blue
+    /// spectra. Fast, but only approximately reproduces the input color.
+    Smits,
+    /// Jakob and Hanika (2019) sigmoid of a quadratic polynomial, fitted so the color
+    /// of the spectrum under D65 matches the input.
+    JakobHanika,
+    /// Mallett and Yuksel (2019) weighted sum of three smooth primary spectra, which
+    /// exactly reproduces any color inside the sRGB gamut.
+    MallettYuksel,
+}
+
+pub trait ToSpectrum {
+    /// Create a reflectance spectrum whose color under D65 matches this color.
+    /// Channels outside the sRGB gamut are clamped, as reflectances lie in 0 to 1.
+    fn to_spectrum(&self, method: Upsampling) -> Spectrum;
+}
+
+impl<T: ToRgb> ToSpectrum for T {
+    fn to_spectrum(&self, method: Upsampling) -> Spectrum {
+        let rgb = self.to_rgb();
+        let linear = clamped_linear(&rgb);
+        match method {
+            Upsampling::Smits => smits(linear),
+            Upsampling::JakobHanika => {
+                let coefficients = SigmoidTable::srgb().fetch(&rgb);
+                sigmoid_spectrum(&coefficients)
+            }
+            Upsampling::MallettYuksel => {
+                let basis = &SRGB_MALLETT_YUKSEL;
+                let values = (0..SAMPLES)
+                    .map(|i| linear[0] * basis[0][i] + linear[1] * basis[1][i] + linear[2] * basis[2][i])
+                    .collect();
+                Spectrum::uniform(START, STEP, values)
+            }
+        }
+    }
+}
+
+fn clamped_linear(rgb: &Rgb) -> [f64; 3] {
+    [
+        srgb_to_linear(rgb.r.clamp(0.0, 255.0) / 255.0),
+        srgb_to_linear(rgb.g.clamp(0.0, 255.0) / 255.0),
+        srgb_to_linear(rgb.b.clamp(0.0, 255.0) / 255.0),
+    ]
+}
+
+pub(crate) const START: f64 = 380.0;
+pub(crate) const STEP: f64 = 5.0;
+pub(crate) const SAMPLES: usize = 81;
+
+/// The color of a reflectance under D65, as seen by the CIE 1931 observer.
+///
+/// The tristimulus values are scaled so a perfect reflector lands exactly on the white of
+/// the sRGB matrix, which differs from the integrated D65 table in the fourth significant
+/// digit, so sRGB white upsamples to a perfect reflector.
+pub(crate) fn reflectance_lab(values: &[f64]) -> Lab {
+    let d65 = d65_weights();
+    let target = linear_xyz([1.0; 3]);
+    let mut xyz = [0.0; 3];
+    let mut white = [0.0; 3];
+    for (value, w) in values.iter().zip(d65.iter()) {
+        for k in 0..3 {
+            xyz[k] += value * w[k];
+            white[k] += w[k];
+        }
+    }
+    Lab::from_color(&Xyz::new(
+        xyz[0] * target[0] / white[0],
+        xyz[1] * target[1] / white[1],
+        xyz[2] * target[2] / white[2]
+    ))
+}
+
+/// D65 multiplied by the CIE 1931 color matching functions, normalized to `Y = 100`.
+fn d65_weights() -> &'static [[f64; 3]; SAMPLES] {
+    static WEIGHTS: OnceLock<[[f64; 3]; SAMPLES]> = OnceLock::new();
+    WEIGHTS.get_or_init(|| {
+        let d65 = Illuminant::D65.spectrum();
+        let table = Observer::Cie1931.table();
+        let mut weights = [[0.0; 3]; SAMPLES];
+        let mut n = 0.0;
+        for i in 0..SAMPLES {
+            for (k, weight) in weights[i].iter_mut().enumerate() {
+                *weight = d65.values[i] * table[i][k];
+            }
+            n += weights[i][1];
+        }
+        for weight in weights.iter_mut() {
+            for w in weight.iter_mut() {
+                *w *= 100.0 / n;
+            }
+        }
+        weights
+    })
+}
+
+const SMITS_WAVELENGTHS: [f64; 10] = [
+    380.0, 417.7778, 455.5556, 493.3333, 531.1111, 568.8889, 606.6667, 644.4444, 682.2222, 720.0
+];
+const SMITS_WHITE: [f64; 10] = [1.0000, 1.0000, 0.9999, 0.9993, 0.9992, 0.9998, 1.0000, 1.0000, 1.0000, 1.0000];
+const SMITS_CYAN: [f64; 10] = [0.9710, 0.9426, 1.0007, 1.0007, 1.0007, 1.0007, 0.1564, 0.0000, 0.0000, 0.0000];
+const SMITS_MAGENTA: [f64; 10] = [1.0000, 1.0000, 0.9685, 0.2229, 0.0000, 0.0458, 0.8369, 1.0000, 1.0000, 0.9959];
+const SMITS_YELLOW: [f64; 10] = [0.0001, 0.0000, 0.1088, 0.6651, 1.0000, 1.0000, 0.9996, 0.9586, 0.9685, 0.9840];
+const SMITS_RED: [f64; 10] = [0.1012, 0.0515, 0.0000, 0.0000, 0.0000, 0.0000, 0.8325, 1.0149, 1.0149, 1.0149];
+const SMITS_GREEN: [f64; 10] = [0.0000, 0.0000, 0.0273, 0.7937, 1.0000, 0.9418, 0.1719, 0.0000, 0.0000, 0.0025];
+const SMITS_BLUE: [f64; 10] = [1.0000, 1.0000, 0.8916, 0.3323, 0.0000, 0.0000, 0.0003, 0.0369, 0.0483, 0.0496];
+
+fn smits(rgb: [f64; 3]) -> Spectrum {
+    let [r, g, b] = rgb;
+    // the smallest channel is white, the middle one a secondary and the rest a primary
+    let (white, secondary, primary) = if r <= g && r <= b {
+        match g <= b {
+            true => ((r, SMITS_WHITE), (g - r, SMITS_CYAN), (b - g, SMITS_BLUE)),
+            false => ((r, SMITS_WHITE), (b - r, SMITS_CYAN), (g - b, SMITS_GREEN)),
+        }
+    } else if g <= r && g <= b {
+        match r <= b {
+            true => ((g, SMITS_WHITE), (r - g, SMITS_MAGENTA), (b - r, SMITS_BLUE)),
+            false => ((g, SMITS_WHITE), (b - g, SMITS_MAGENTA), (r - b, SMITS_RED)),
+        }
+    } else {
+        match r <= g {
+            true => ((b, SMITS_WHITE), (r - b, SMITS_YELLOW), (g - r, SMITS_GREEN)),
+            false => ((b, SMITS_WHITE), (g - b, SMITS_YELLOW), (r - g, SMITS_RED)),
+        }
+    };
+    let values = (0..10)
+        .map(|i| white.0 * white.1[i] + secondary.0 * secondary.1[i] + primary.0 * primary.1[i])
+        .collect();
+    Spectrum::new(SMITS_WAVELENGTHS.to_vec(), values)
+}
+
+#[inline]
+fn sigmoid(x: f64) -> f64 {
+    0.5 + x / (2.0 * (1.0 + x * x).sqrt())
+}
+
+/// Evaluate the sigmoid polynomial at `nm`, with wavelengths normalized to 0 to 1
+/// over the visible range so the coefficients stay well conditioned.
+#[inline]
+fn sigmoid_eval(c: &[f64; 3], nm: f64) -> f64 {
+    let t = (nm - START) / (STEP * (SAMPLES - 1) as f64);
+    sigmoid((c[0] * t + c[1]) * t + c[2])
+}
+
+fn sigmoid_spectrum(c: &[f64; 3]) -> Spectrum {
+    Spectrum::from_fn(START, START + STEP * (SAMPLES - 1) as f64, STEP, |nm| sigmoid_eval(c, nm))
+}
+
+fn sigmoid_lab(c: &[f64; 3]) -> Lab {
+    let values: Vec<f64> = (0..SAMPLES)
+        .map(|i| sigmoid_eval(c, START + STEP * i as f64))
+        .collect();
+    reflectance_lab(&values)
+}
+
+const FIT_ITERATIONS: usize = 50;
+const FIT_TOLERANCE: f64 = 1e-6;
+
+/// Gauss-Newton fit of sigmoid coefficients reproducing the CIELAB color `target`.
+fn sigmoid_fit(target: &Lab, mut c: [f64; 3]) -> [f64; 3] {
+    let residual = |c: &[f64; 3]| {
+        let lab = sigmoid_lab(c);
+        [target.l - lab.l, target.a - lab.a, target.b - lab.b]
+    };
+    let mut r = residual(&c);
+    for _ in 0..FIT_ITERATIONS {
+        let norm = (r[0] * r[0] + r[1] * r[1] + r[2] * r[2]).sqrt();
+        if norm < FIT_TOLERANCE {
+            break;
+        }
+        let mut jacobian = [[0.0; 3]; 3];
+        for k in 0..3 {
+            let h = 1e-5 * c[k].abs().max(1.0);
+            let mut ch = c;
+            ch[k] += h;
+            let rh = residual(&ch);
+            for j in 0..3 {
+                jacobian[j][k] = (r[j] - rh[j]) / h;
+            }
+        }
+        let step = match solve3(&jacobian, &r) {
+            Some(step) => step,
+            None => break,
+        };
+        // halve the step until the residual decreases
+        let mut scale = 1.0;
+        loop {
+            let next = [c[0] + step[0] * scale, c[1] + step[1] * scale, c[2] + step[2] * scale];
+            let rn = residual(&next);
+            if rn[0] * rn[0] + rn[1] * rn[1] + rn[2] * rn[2] < norm * norm || scale < 1e-4 {
+                c = next;
+                r = rn;
+                break;
+            }
+            scale *= 0.5;
+        }
+    }
+    c
+}
+
+/// A table of Jakob-Hanika sigmoid coefficients over the sRGB cube.
+///
+/// Like the tables of the original paper, the cube is split into three slices by its
+/// largest channel, with the brightness axis spaced more densely near black and white.
+#[derive(Clone, Debug)]
+pub struct SigmoidTable {
+    resolution: usize,
+    scale: Vec<f64>,
+    coefficients: Vec<[f64; 3]>,
+}
+
+impl SigmoidTable {
+    /// Fit a table with `resolution` entries along each axis, which must be at least 2.
+    pub fn new(resolution: usize) -> Self {
+        assert!(resolution >= 2);
+        let n = resolution;
+        let scale = Self::scale(n);
+        let mut coefficients = vec![[0.0; 3]; 3 * n * n * n];
+        let start = (n / 5).max(1);
+        for l in 0..3 {
+            for j in 0..n {
+                let y = j as f64 / (n - 1) as f64;
+                for i in 0..n {
+                    let x = i as f64 / (n - 1) as f64;
+                    // walk away from a mid brightness, seeding each fit with its neighbour;
+                    // black cannot be reached by a sigmoid, so it reuses the darkest fit
+                    let order = (start..n).chain((1..start).rev());
+                    let mut c = [0.0; 3];
+                    for k in order {
+                        if k + 1 == start {
+                            c = coefficients[Self::index(n, l, start, j, i)];
+                        }
+                        let z = scale[k];
+                        let mut linear = [0.0; 3];
+                        linear[l] = z;
+                        linear[(l + 1) % 3] = x * z;
+                        linear[(l + 2) % 3] = y * z;
+                        c = sigmoid_fit(&linear_lab(linear), c);
+                        coefficients[Self::index(n, l, k, j, i)] = c;
+                    }
+                    coefficients[Self::index(n, l, 0, j, i)] = coefficients[Self::index(n, l, 1, j, i)];
+                }
+            }
+        }
+        Self { resolution, scale, coefficients }
+    }
+
+    /// The table for sRGB, precomputed at a resolution of 16.
+    pub fn srgb() -> &'static SigmoidTable {
+        static TABLE: OnceLock<SigmoidTable> = OnceLock::new();
+        TABLE.get_or_init(|| SigmoidTable {
+            resolution: 16,
+            scale: Self::scale(16),
+            coefficients: SRGB_SIGMOID.to_vec(),
+        })
+    }
+
+    /// The brightness of each slice, spaced more densely near black and white.
+    fn scale(n: usize) -> Vec<f64> {
+        let smoothstep = |x: f64| x * x * (3.0 - 2.0 * x);
+        (0..n).map(|i| smoothstep(smoothstep(i as f64 / (n - 1) as f64))).collect()
+    }
+
+    /// The fitted coefficients, indexed by the largest channel, the brightness and the
+    /// two other channels relative to the largest.
+    pub fn coefficients(&self) -> &[[f64; 3]] {
+        &self.coefficients
+    }
+
+    #[inline]
+    fn index(n: usize, l: usize, k: usize, j: usize, i: usize) -> usize {
+        ((l * n + k) * n + j) * n + i
+    }
+
+    /// The sigmoid coefficients for `rgb`, interpolated from the table and refined
+    /// so the resulting spectrum reproduces the color.
+    pub fn fetch(&self, rgb: &Rgb) -> [f64; 3] {
+        let linear = clamped_linear(rgb);
+        let n = self.resolution;
+        let l = match linear[0] >= linear[1] && linear[0] >= linear[2] {
+            true => 0,
+            false => match linear[1] >= linear[2] {
+                true => 1,
+                false => 2,
+            },
+        };
+        let z = linear[l];
+        if z <= 0.0 {
+            return sigmoid_fit(&linear_lab(linear), self.coefficients[Self::index(n, l, 0, 0, 0)]);
+        }
+        let x = linear[(l + 1) % 3] / z * (n - 1) as f64;
+        let y = linear[(l + 2) % 3] / z * (n - 1) as f64;
+        let k = (self.scale.partition_point(|&s| s <= z).max(1) - 1).min(n - 2);
+        let fz = ((z - self.scale[k]) / (self.scale[k + 1] - self.scale[k])).clamp(0.0, 1.0);
+        let i = (x.floor() as usize).min(n - 2);
+        let j = (y.floor() as usize).min(n - 2);
+        let (fx, fy) = (x - i as f64, y - j as f64);
+        let mut c = [0.0; 3];
+        for (dk, wk) in [(0, 1.0 - fz), (1, fz)] {
+            for (dj, wj) in [(0, 1.0 - fy), (1, fy)] {
+                for (di, wi) in [(0, 1.0 - fx), (1, fx)] {
+                    let entry = self.coefficients[Self::index(n, l, k + dk, j + dj, i + di)];
+                    for m in 0..3 {
+                        c[m] += wk * wj * wi * entry[m];
+                    }
+                }
+            }
+        }
+        sigmoid_fit(&linear_lab(linear), c)
+    }
+
+    /// The reflectance spectrum for a set of coefficients from `fetch`.
+    pub fn spectrum(coefficients: &[f64; 3]) -> Spectrum {
+        sigmoid_spectrum(coefficients)
+    }
+}
+
+fn linear_lab(linear: [f64; 3]) -> Lab {
+    let [x, y, z] = linear_xyz(linear);
+    Lab::from_color(&Xyz::new(x, y, z))
+}
+
+/// Fit the red, green and blue basis spectra of Mallett and Yuksel for primaries whose red
+/// and green have the colors `red` and `green` under D65, scaled so white has `Y = 100`.
+/// `Upsampling::MallettYuksel` uses the basis fitted for sRGB, which is precomputed.
+///
+/// Following the paper, the basis is the smoothest set of three spectra that lie in 0 to 1,
+/// sum to a constant 1 (so white is a perfect reflector) and reproduce the red and green
+/// primaries. The quadratic program is solved with ADMM.
+pub fn mallett_yuksel_basis(red: &Xyz, green: &Xyz) -> [Spectrum; 3] {
+    let basis = fit_mallett_yuksel([red.x, red.y, red.z], [green.x, green.y, green.z]);
+    basis.map(|values| Spectrum::uniform(START, STEP, values.to_vec()))
+}
+
+fn fit_mallett_yuksel(red: [f64; 3], green: [f64; 3]) -> [[f64; SAMPLES]; 3] {
+    const N: usize = 2 * SAMPLES;
+    const M: usize = N + 6;
+    const RHO: f64 = 1.0;
+    let weights = d65_weights();
+
+    // smoothness of red, green and blue = 1 - red - green, as sums of squared differences
+    let mut kkt = vec![vec![0.0; M]; M];
+    for i in 0..SAMPLES - 1 {
+        for (a, b, w) in [(0, 0, 4.0), (0, 1, 2.0), (1, 0, 2.0), (1, 1, 4.0)] {
+            let (ra, rb) = (a * SAMPLES, b * SAMPLES);
+            kkt[ra + i][rb + i] += w;
+            kkt[ra + i + 1][rb + i + 1] += w;
+            kkt[ra + i][rb + i + 1] -= w;
+            kkt[ra + i + 1][rb + i] -= w;
+        }
+    }
+    for (i, row) in kkt.iter_mut().enumerate().take(N) {
+        row[i] += RHO;
+    }
+    let mut target = [0.0; 6];
+    for k in 0..3 {
+        for i in 0..SAMPLES {
+            kkt[N + k][i] = weights[i][k];
+            kkt[i][N + k] = weights[i][k];
+            kkt[N + 3 + k][SAMPLES + i] = weights[i][k];
+            kkt[SAMPLES + i][N + 3 + k] = weights[i][k];
+        }
+        target[k] = red[k];
+        target[3 + k] = green[k];
+    }
+    let lu = Lu::new(kkt);
+
+    let mut z = vec![1.0 / 3.0; N];
+    let mut u = vec![0.0; N];
+    let mut rhs = vec![0.0; M];
+    for _ in 0..20000 {
+        for i in 0..N {
+            rhs[i] = RHO * (z[i] - u[i]);
+        }
+        rhs[N..].copy_from_slice(&target);
+        let x = lu.solve(&rhs);
+        let mut primal = 0.0;
+        let mut dual = 0.0;
+        for i in 0..SAMPLES {
+            let (r, g) = project_triangle(x[i] + u[i], x[SAMPLES + i] + u[SAMPLES + i]);
+            for (j, value) in [(i, r), (SAMPLES + i, g)] {
+                dual += (value - z[j]).powi(2);
+                z[j] = value;
+                u[j] += x[j] - value;
+                primal += (x[j] - value).powi(2);
+            }
+        }
+        if primal < 1e-20 && dual < 1e-20 {
+            break;
+        }
+    }
+
+    let mut basis = [[0.0; SAMPLES]; 3];
+    for i in 0..SAMPLES {
+        basis[0][i] = z[i];
+        basis[1][i] = z[SAMPLES + i];
+        basis[2][i] = 1.0 - z[i] - z[SAMPLES + i];
+    }
+    basis
+}
+
+fn linear_xyz(linear: [f64; 3]) -> [f64; 3] {
+    let [r, g, b] = linear;
+    [
+        (0.4124 * r + 0.3576 * g + 0.1805 * b) * 100.0,
+        (0.2126 * r + 0.7152 * g + 0.0722 * b) * 100.0,
+        (0.0193 * r + 0.1192 * g + 0.9505 * b) * 100.0
+    ]
+}
+
+/// Closest point to `(r, g)` with `r`, `g` and `1 - r - g` all non-negative.
+fn project_triangle(r: f64, g: f64) -> (f64, f64) {
+    if r >= 0.0 && g >= 0.0 && r + g <= 1.0 {
+        return (r, g);
+    }
+    let t = ((r - g + 1.0) / 2.0).clamp(0.0, 1.0);
+    let candidates = [(0.0, g.clamp(0.0, 1.0)), (r.clamp(0.0, 1.0), 0.0), (t, 1.0 - t)];
+    let distance = |p: &(f64, f64)| (p.0 - r).powi(2) + (p.1 - g).powi(2);
+    let mut best = candidates[0];
+    for p in candidates.iter().skip(1) {
+        if distance(p) < distance(&best) {
+            best = *p;
+        }
+    }
+    best
+}
+
+/// LU decomposition with partial pivoting of a dense square matrix.
+struct Lu {
+    lu: Vec<Vec<f64>>,
+    pivot: Vec<usize>,
+}
+
+impl Lu {
+    fn new(mut lu: Vec<Vec<f64>>) -> Self {
+        let n = lu.len();
+        let mut pivot: Vec<usize> = (0..n).collect();
+        for k in 0..n {
+            let p = (k..n)
+                .max_by(|&a, &b| lu[a][k].abs().partial_cmp(&lu[b][k].abs()).unwrap())
+                .unwrap();
+            lu.swap(k, p);
+            pivot.swap(k, p);
+            let (top, bottom) = lu.split_at_mut(k + 1);
+            let row_k = &top[k];
+            for row in bottom.iter_mut() {
+                let f = row[k] / row_k[k];
+                row[k] = f;
+                for (value, &above) in row[k + 1..].iter_mut().zip(&row_k[k + 1..]) {
+                    *value -= f * above;
+                }
+            }
+        }
+        Self { lu, pivot }
+    }
+
+    fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.lu.len();
+        let mut x: Vec<f64> = self.pivot.iter().map(|&p| b[p]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.lu[i][j] * x[j];
+            }
+            x[i] /= self.lu[i][i];
+        }
+        x
+    }
+}
@@ -0,0 +1,12347 @@
+//! Upsampling tables for sRGB, generated by `SigmoidTable::new(16)` and
+//! `mallett_yuksel_basis` with the sRGB red and green primaries.
+
+/// Jakob-Hanika sigmoid coefficients over the sRGB cube at a resolution of 16, in the
+/// order of `SigmoidTable::coefficients`.
+pub(crate) static SRGB_SIGMOID: [[f64; 3]; 3 * 16 * 16 * 16] = [
+    [-323.66516815493446, 601.3392933434301, -279.4165256437093],
+    [-340.0244800455576, 567.9636806736399, -245.38808125208163],
+    [-386.79294352738515, 569.4803165356205, -226.85948544416388],
+    [-431.55352855203233, 577.1803756664177, -214.64249894918515],
+    [-469.30227925276984, 584.7505304336606, -205.63229193631508],
+    [-500.27155010028497, 590.8748539710883, -198.51669476303022],
+    [-525.5111763429675, 595.4021214837425, -192.6314910071526],
+    [-546.0626581484155, 598.4858016174513, -187.6010498468535],
+    [-562.8006986389413, 600.3426371977358, -183.1954734980435],
+    [-576.4281829107114, 601.1811489416707, -179.26538054611268],
+    [-587.5040089958666, 601.1826052405028, -175.70902097544277],
+    [-596.4731678718164, 600.4988428123775, -172.45437977834683],
+    [-603.6920142465626, 599.2552301604609, -169.4488305733187],
+    [-609.4480264866703, 597.5547879301056, -166.6528534214673],
+    [-613.9749068329066, 595.4821404136824, -164.036062590817],
+    [-617.4640665814022, 593.1069174991345, -161.57460765699264],
+    [188.8685496389354, -78.78634263097781, -75.10336279659872],
+    [63.076266842461166, 47.11475076977687, -90.63266712106282],
+    [-2.2904130134482505, 110.26417507042626, -97.265095420317],
+    [-43.84454722991551, 148.2111465018018, -100.35717560185375],
+    [-91.6864160958348, 186.12279123393898, -103.5289320241022],
+    [-150.5370505330222, 229.31681585186357, -107.61217022779539],
+    [-201.83558663904856, 265.90126149415687, -110.93369799464529],
+    [-245.68822663549557, 296.45141178848826, -113.55211958324178],
+    [-283.1035252241062, 321.9704807986977, -115.58871409307496],
+    [-315.0962439989396, 343.3552418244436, -117.15319595194993],
+    [-342.54316208661544, 361.34055157224503, -118.33543326246414],
+    [-366.17177436273744, 376.5163392408071, -119.20724698550013],
+    [-386.5788888553298, 389.35512584078845, -119.82578727978242],
+    [-404.25366972482215, 400.2369811396774, -120.23666077202138],
+    [-419.59841604046693, 409.4696320551174, -120.47646692263508],
+    [-432.9457057014613, 417.30407107162887, -120.57477024891111],
+    [297.3847006577152, -221.33336620349215, -32.87996207721038],
+    [171.13000742637524, -93.34343571566883, -49.629486194805395],
+    [98.13541999938823, -20.432481099675865, -59.02771846096909],
+    [50.59158007080954, 26.367894173616175, -64.7869931569821],
+    [15.209977378909048, 60.01770140866228, -68.67554358585058],
+    [-18.71500193890863, 89.66132522437648, -71.97358624846336],
+    [-67.7441717821595, 127.56000686571868, -76.41087623360967],
+    [-112.65397438216148, 161.4374127968011, -80.3370307539184],
+    [-152.24385661975768, 190.8318464260012, -83.68405395607265],
+    [-186.9890080473142, 216.29300921236566, -86.52204887869848],
+    [-217.49914834283513, 238.39158819307036, -88.92511075597206],
+    [-244.34742959793275, 257.62871049820603, -90.95880985971594],
+    [-268.0351413995199, 274.4268530159763, -92.67868913561286],
+    [-288.99030194500335, 289.1381194042498, -94.13118249956251],
+    [-307.5755000340243, 302.0555525630796, -95.35500830261977],
+    [-324.0975365767616, 313.4236958549672, -96.38250760744894],
+    [339.2888618269544, -278.4230465121885, -14.827065918674144],
+    [221.51412993585956, -159.6160354782736, -29.978718854607187],
+    [149.84566652127918, -87.63402315015824, -39.41306498086796],
+    [101.4857424619846, -39.34480639662086, -45.705568549726706],
+    [65.38997205805373, -3.976373851016622, -50.21548664768443],
+    [35.69612469403082, 24.125288483747468, -53.69859784480842],
+    [5.234305323513584, 50.68978530016191, -56.936576119365725],
+    [-36.573305786997096, 83.37092596993554, -60.99117736775629],
+    [-75.11751360845291, 112.92693516891202, -64.64099182860316],
+    [-109.57605375701336, 139.04181603393837, -67.83977628328596],
+    [-140.2747243398878, 162.0903490594993, -70.63443718025009],
+    [-167.63718525299456, 182.4692071842244, -73.07588490772262],
+    [-192.07005988165886, 200.53482793564038, -75.210470269111],
+    [-213.9362931329109, 216.5944150675787, -77.07852298594841],
+    [-233.55169391606708, 230.90930025125778, -78.71460824329992],
+    [-251.18849159364183, 243.70113072675966, -80.14822061525027],
+    [355.7097688718114, -303.3217068865819, -5.6990030813936094],
+    [249.1771694599193, -196.76957953760785, -18.559501861151148],
+    [181.14419901964328, -128.3595944955049, -27.40987459612869],
+    [133.31292029225327, -80.59109477618412, -33.67666211486393],
+    [97.39782347091108, -45.08038358022614, -38.323021174012794],
+    [68.57167860917156, -17.108661478054472, -41.9397272413439],
+    [42.300103280158375, 7.189214824354607, -45.0339282593152],
+    [12.307982578470265, 32.48183493245607, -48.23352788180994],
+    [-24.19452467862072, 61.019147998526485, -51.8628532334461],
+    [-57.5204682420438, 86.73631664643156, -55.12653362058464],
+    [-87.54683520028767, 109.70639674409087, -58.03039844203225],
+    [-114.5533140076901, 130.2242371105003, -60.61058521211366],
+    [-138.86461957639946, 148.58683717242795, -62.90451793905809],
+    [-160.7887263809337, 165.06112255537593, -64.9463862285844],
+    [-180.60168019193748, 179.87927775513967, -66.76629872800925],
+    [-198.54573564371682, 193.2410908038784, -68.3904239231649],
+    [356.49806773838395, -308.59628291295166, -1.9256143014395597],
+    [265.2585320019334, -219.1422600469581, -11.274756300620497],
+    [201.25565765719443, -154.9326525236011, -19.378971329584296],
+    [154.6499951235819, -108.54957133069891, -25.409479791651044],
+    [119.5189427300656, -73.68529195390485, -29.987056537868007],
+    [91.31272498437191, -46.04425970452226, -33.61180218784455],
+    [66.77419182754042, -22.70018939621057, -36.649987081154165],
+    [42.266443080349184, -0.7802116280251576, -39.47649107541927],
+    [11.732152462729573, 23.998800582091093, -42.66308793084864],
+    [-20.17298328608049, 48.91782759487288, -45.87310948166257],
+    [-49.240246671515756, 71.41639603953244, -48.770765255791346],
+    [-75.57566132900939, 91.67006614052497, -51.37551222971914],
+    [-99.42851259002644, 109.92117249519107, -53.71659802537386],
+    [-121.05971379900372, 126.40161245382784, -55.822779565552274],
+    [-140.71196163581942, 141.31847120803027, -57.7202007322175],
+    [-158.6024039176958, 154.8525905983656, -59.43201230433006],
+    [349.5239053064369, -303.93897188915656, -0.9128879611537233],
+    [274.4418917397681, -232.73689714389366, -6.430656037881678],
+    [214.63540116030933, -173.04699296877996, -13.698521055949957],
+    [169.70969780093026, -128.5087761568122, -19.3968771417468],
+    [135.76364515102884, -94.72657581765196, -23.806857462851873],
+    [108.20326939318778, -67.61317633389142, -27.368078633096662],
+    [84.43010293842535, -44.76034381146184, -30.366365104418577],
+    [62.004790048713744, -24.122621037406493, -33.059408959615745],
+    [37.92743030788519, -3.4478785119679745, -35.74118546130774],
+    [7.797868456023997, 20.34463636982843, -38.82404634111329],
+    [-20.21860107155828, 42.20981603706639, -41.663229130849615],
+    [-45.77113232439186, 62.02569547452882, -44.23915166865826],
+    [-69.03138238523307, 79.97952866154084, -46.57307627289447],
+    [-90.21814779529494, 96.27188644963675, -48.68894708087489],
+    [-109.54549953506934, 111.08808881739999, -50.60946004813933],
+    [-127.20929349157991, 124.59264506630156, -52.35513590278673],
+    [341.5987556484393, -297.7347469365787, -0.4750428008343659],
+    [278.70857110430626, -240.10223374056616, -3.3041801858131055],
+    [223.64688758360876, -185.67393876212196, -9.536763231120222],
+    [180.6332148734701, -143.2082370401389, -14.85694273233296],
+    [147.9376436620457, -110.63802771058289, -19.063149169675036],
+    [120.97598783938072, -84.11867284850436, -22.52928237899858],
+    [97.97537903616174, -61.849188325104514, -25.453204906726924],
+    [76.84075516852995, -42.06130362326288, -28.047973936220554],
+    [55.31149167786771, -23.012690340623294, -30.534585471753015],
+    [29.373216959820926, -1.9214148995566365, -33.27581169069573],
+    [2.437765781585023, 19.236703714473617, -36.03072599891813],
+    [-22.30253143755225, 38.5403915714123, -38.551625696032296],
+    [-44.92279878513886, 56.11090608794912, -40.85065055116515],
+    [-65.60202265034992, 72.11953057908903, -42.947342197867904],
+    [-84.5289977557846, 86.73231944925969, -44.86137086170527],
+    [-101.88116869011812, 100.09953288751574, -46.610966485282],
+    [333.94775161678933, -291.56483515793013, -0.1804757289974187],
+    [278.6749235276815, -242.06391127060692, -1.6451520384521867],
+    [229.6022962605148, -194.46811863786104, -6.432231818312393],
+    [188.65666814635745, -154.2359044323622, -11.338847858395825],
+    [157.1872889183011, -122.90966678850815, -15.327274289868525],
+    [130.8655709968835, -97.06305014181224, -18.676516282860554],
+    [108.51713141871289, -75.35242487618032, -21.516561418586654],
+    [88.36034205710452, -56.25983507059795, -24.02100432609702],
+    [68.20695764760457, -38.12623005911581, -26.393743582463728],
+    [46.298169907541116, -19.627125729777063, -28.805733119819905],
+    [20.52165329154366, 0.7455495185861409, -31.457675403247908],
+    [-3.4112639519467995, 19.508435563374587, -33.91056279814226],
+    [-25.383748615118314, 36.65799611692196, -36.16026443149421],
+    [-45.534460926277895, 52.33644229248696, -38.22208217044156],
+    [-64.02922983274944, 66.69237079245079, -40.11298350743667],
+    [-81.02927689350498, 79.86342353067741, -41.849184323669405],
+    [326.7813452695638, -285.7457627322581, 0.07243449295485133],
+    [275.9696789373093, -240.6616884198221, -0.9082814138582096],
+    [233.21695619300533, -200.34249386892822, -4.128382467295681],
+    [194.55360773572386, -162.58148424877828, -8.563831490765477],
+    [164.30136472232235, -132.52256181387145, -12.325302942035398],
+    [138.68424638256735, -107.4183882706626, -15.542692219023905],
+    [116.98130341906031, -86.29279153739546, -18.288532615179417],
+    [97.50433413436176, -67.7376287781957, -20.715113734920667],
+    [78.55061060715506, -50.42866755265437, -22.979579819832978],
+    [58.97218549658991, -33.44937365645169, -25.19691315203288],
+    [35.19810129458212, -14.40359363180978, -27.67375892148057],
+    [12.056024688288417, 3.8106148231202854, -30.052299357826158],
+    [-9.2793250057352, 20.526304410707397, -32.245344901058004],
+    [-28.90145585819893, 35.85410260289766, -34.26376400146111],
+    [-46.95470608691935, 49.92638541238268, -36.122057687156996],
+    [-63.58575888463746, 62.8694582578077, -37.83465016962805],
+    [320.16464421850696, -280.3991040657862, 0.33109207037632993],
+    [272.3992107821753, -238.13081773577494, -0.5110199167817913],
+    [234.78383868655197, -203.69233883573824, -2.50800550699939],
+    [198.82944894916918, -168.88723440715341, -6.352303977193096],
+    [169.80810367133427, -140.130376673784, -9.876111667090738],
+    [144.9390137339467, -115.81289146239465, -12.952552732061381],
+    [123.89194330108245, -95.29659761901817, -15.597226596389877],
+    [104.97211509285825, -77.22157694114325, -17.94850855873149],
+    [86.9497884264465, -60.583918716692374, -20.120108324587],
+    [68.4521445493589, -44.34860236425138, -22.237766478446044],
+    [47.253818250538195, -26.983759435345462, -24.49534741328581],
+    [24.88986130268612, -9.320628142934396, -26.796223856137757],
+    [4.172720010475463, 6.961190672347421, -28.9292137591089],
+    [-14.931233479020422, 21.932093098759445, -30.899792009567367],
+    [-32.545558571544596, 35.70895453504711, -32.720151658044756],
+    [-48.80371108473924, 48.40775905505485, -34.40310394708787],
+    [314.16977749692165, -275.6448847585626, 0.6394698613105522],
+    [268.6320441019377, -235.30760044853616, -0.233255179493742],
+    [234.45453420534318, -204.73903785423582, -1.501903779776663],
+    [201.8131180352737, -173.56893531232822, -4.588309192001502],
+    [174.06655021157684, -146.17996806436452, -7.855970816172454],
+    [149.97062886841158, -122.67684169279148, -10.785359707769539],
+    [129.56364287060475, -102.771073410438, -13.326360353706006],
+    [111.18018821509877, -85.17500257942444, -15.599837369376264],
+    [93.80647885889164, -69.0437525356317, -17.696323084974686],
+    [76.16861329557788, -53.42243681681689, -19.728521436792214],
+    [57.21513941736571, -37.523587587951965, -21.794875709963865],
+    [35.651553213857426, -20.430678704516726, -24.014124383003725],
+    [15.530629475480913, -4.57607876456423, -26.085887827203447],
+    [-3.0706487445104473, 10.03961634627188, -28.006582816880247],
+    [-20.25494317420357, 23.517955158742996, -29.786174776960497],
+    [-36.14350649222124, 35.96540812926309, -31.436005915005918],
+    [308.9141119374196, -271.6518379113732, 1.0516221407946869],
+    [264.876092677927, -232.46562213472905, 0.00043873248519725504],
+    [232.79602174322173, -204.17844965935052, -0.9265393756292866],
+    [203.69809937847927, -176.8717299384703, -3.202681948260319],
+    [177.32542617899534, -150.98320687383202, -6.178406723813488],
+    [154.02215693148412, -128.31846198464447, -8.954779716847014],
+    [134.22907345166976, -109.01449870289322, -11.391574322525448],
+    [116.34520277375006, -91.88218752431084, -13.58746859335334],
+    [99.51819401714924, -76.20123496826271, -15.614102663105886],
+    [82.62134121211078, -61.12465751629055, -17.568183082307378],
+    [65.29432646100166, -46.303881925708325, -19.492086785867883],
+    [44.75119872613394, -29.918407958477832, -21.612289863574322],
+    [25.20436651950443, -14.48129318320946, -23.622909461085705],
+    [7.088030878510543, -0.21468860175773277, -25.49311844703089],
+    [-9.678355126558362, 12.9671041765388, -27.2306418374478],
+    [-25.204636260525938, 25.161683457889243, -28.845468713024776],
+    [304.32667602153344, -268.3535850870292, 1.5597862824356037],
+    [261.21063355355733, -229.723207515356, 0.22563567529021442],
+    [230.5415877322777, -202.86097228864818, -0.5678247428467122],
+    [204.57171770282173, -178.9092984561723, -2.161530157535729],
+    [179.75644522282383, -154.75766402974082, -4.782787081542481],
+    [157.27337031426566, -132.96502423221244, -7.397455172610216],
+    [138.07268641614453, -114.25555308215577, -9.72957135649309],
+    [120.65404341389377, -97.57091796199154, -11.848765793417662],
+    [104.34810423831202, -82.32841274790515, -13.806094114279185],
+    [88.09472548912456, -67.73960008664265, -15.688257240649111],
+    [71.64005643008444, -53.531345411203716, -17.526842835253316],
+    [52.49529725480645, -38.08244615291262, -19.520556881454052],
+    [33.50232066989365, -23.052305096633596, -21.4707663264955],
+    [15.852525084511003, -9.12626837745989, -23.290757984010057],
+    [-0.5095736530796827, 3.7639207628635227, -24.985869188644116],
+    [-15.683016297172195, 15.70749180189701, -26.56482868315422],
+    [299.84035366837634, -265.0776801705298, 2.0013874150416586],
+    [257.68250586711673, -227.15756613674, 0.4668801194880369],
+    [228.1289424512068, -201.2716728921597, -0.3068293609368433],
+    [204.51204467297126, -179.78319277048027, -1.4350463918334935],
+    [181.4719683440715, -157.64871526911537, -3.627989211509243],
+    [159.86026699624514, -136.78717396320857, -6.06611321701828],
+    [141.23850409127587, -118.67006154681344, -8.292236418924455],
+    [124.26466947170309, -102.42410917317102, -10.335049221545933],
+    [108.42863841632968, -87.59144604555546, -12.225935970108045],
+    [92.74286264595167, -73.4457705635791, -14.041183537600523],
+    [76.90556079770765, -59.69421191687919, -15.813201873217599],
+    [59.11657787082526, -45.150391375402506, -17.68536218935855],
+    [40.66126311320435, -30.517899418049083, -19.576111581118983],
+    [23.459789259774894, -16.922696139337248, -21.346679752682928],
+    [7.4878446151633336, -4.317475323699479, -22.99963698637652],
+    [-7.343244454238045, 7.379007809414438, -24.54252487585017],
+    [295.16470271674655, -261.49474893604287, 2.301600554176886],
+    [254.34583127391682, -224.83712710047683, 0.7438565495141016],
+    [225.74717886578355, -199.6259485382132, -0.0894518570817062],
+    [203.732642507878, -179.75888515944595, -0.9526004612150999],
+    [182.53486483780267, -159.74236020063046, -2.6886319997889303],
+    [161.88581891477517, -139.91327795537526, -4.925338562562825],
+    [143.83736501388123, -122.39332442361263, -7.042633738128613],
+    [127.29866883523535, -106.58296806233024, -9.008758490643126],
+    [111.88298061482006, -92.131939136456, -10.836157585346193],
+    [96.6968671984343, -78.38896071077357, -12.589144946624764],
+    [81.39103461582317, -65.04226626277368, -14.300743617624871],
+    [64.79227613325419, -51.29725479897018, -16.06525688353719],
+    [46.8662054773489, -37.05693439501235, -17.89727290888085],
+    [30.09547939240976, -23.782334154926037, -19.619520797369013],
+    [14.499608431031414, -11.45453542411246, -21.230959252273784],
+    [-2.043379347578331e-10, 1.8586159160817463e-10, -22.737998889246587],
+    [-323.66516815493446, 601.3392933434301, -279.4165256437093],
+    [-340.0244800455576, 567.9636806736399, -245.38808125208163],
+    [-386.79294352738515, 569.4803165356205, -226.85948544416388],
+    [-431.55352855203233, 577.1803756664177, -214.64249894918515],
+    [-469.30227925276984, 584.7505304336606, -205.63229193631508],
+    [-500.27155010028497, 590.8748539710883, -198.51669476303022],
+    [-525.5111763429675, 595.4021214837425, -192.6314910071526],
+    [-546.0626581484155, 598.4858016174513, -187.6010498468535],
+    [-562.8006986389413, 600.3426371977358, -183.1954734980435],
+    [-576.4281829107114, 601.1811489416707, -179.26538054611268],
+    [-587.5040089958666, 601.1826052405028, -175.70902097544277],
+    [-596.4731678718164, 600.4988428123775, -172.45437977834683],
+    [-603.6920142465626, 599.2552301604609, -169.4488305733187],
+    [-609.4480264866703, 597.5547879301056, -166.6528534214673],
+    [-613.9749068329066, 595.4821404136824, -164.036062590817],
+    [-617.4640665814022, 593.1069174991345, -161.57460765699264],
+    [188.8685496389354, -78.78634263097781, -75.10336279659872],
+    [63.076266842461166, 47.11475076977687, -90.63266712106282],
+    [-2.2904130134482505, 110.26417507042626, -97.265095420317],
+    [-43.84454722991551, 148.2111465018018, -100.35717560185375],
+    [-91.6864160958348, 186.12279123393898, -103.5289320241022],
+    [-150.5370505330222, 229.31681585186357, -107.61217022779539],
+    [-201.83558663904856, 265.90126149415687, -110.93369799464529],
+    [-245.68822663549557, 296.45141178848826, -113.55211958324178],
+    [-283.1035252241062, 321.9704807986977, -115.58871409307496],
+    [-315.0962439989396, 343.3552418244436, -117.15319595194993],
+    [-342.54316208661544, 361.34055157224503, -118.33543326246414],
+    [-366.17177436273744, 376.5163392408071, -119.20724698550013],
+    [-386.5788888553298, 389.35512584078845, -119.82578727978242],
+    [-404.25366972482215, 400.2369811396774, -120.23666077202138],
+    [-419.59841604046693, 409.4696320551174, -120.47646692263508],
+    [-432.9457057014613, 417.30407107162887, -120.57477024891111],
+    [297.3847006577152, -221.33336620349215, -32.87996207721038],
+    [171.13000742637524, -93.34343571566883, -49.629486194805395],
+    [98.13541999938823, -20.432481099675865, -59.02771846096909],
+    [50.59158007080954, 26.367894173616175, -64.7869931569821],
+    [15.209977378909048, 60.01770140866228, -68.67554358585058],
+    [-18.71500193890863, 89.66132522437648, -71.97358624846336],
+    [-67.7441717821595, 127.56000686571868, -76.41087623360967],
+    [-112.65397438216148, 161.4374127968011, -80.3370307539184],
+    [-152.24385661975768, 190.8318464260012, -83.68405395607265],
+    [-186.9890080473142, 216.29300921236566, -86.52204887869848],
+    [-217.49914834283513, 238.39158819307036, -88.92511075597206],
+    [-244.34742959793275, 257.62871049820603, -90.95880985971594],
+    [-268.0351413995199, 274.4268530159763, -92.67868913561286],
+    [-288.99030194500335, 289.1381194042498, -94.13118249956251],
+    [-307.5755000340243, 302.0555525630796, -95.35500830261977],
+    [-324.0975365767616, 313.4236958549672, -96.38250760744894],
+    [339.2888618269544, -278.4230465121885, -14.827065918674144],
+    [221.51412993585956, -159.6160354782736, -29.978718854607187],
+    [149.84566652127918, -87.63402315015824, -39.41306498086796],
+    [101.4857424619846, -39.34480639662086, -45.705568549726706],
+    [65.38997205805373, -3.976373851016622, -50.21548664768443],
+    [35.69612469403082, 24.125288483747468, -53.69859784480842],
+    [5.234305323513584, 50.68978530016191, -56.936576119365725],
+    [-36.573305786997096, 83.37092596993554, -60.99117736775629],
+    [-75.11751360845291, 112.92693516891202, -64.64099182860316],
+    [-109.57605375701336, 139.04181603393837, -67.83977628328596],
+    [-140.2747243398878, 162.0903490594993, -70.63443718025009],
+    [-167.63718525299456, 182.4692071842244, -73.07588490772262],
+    [-192.07005988165886, 200.53482793564038, -75.210470269111],
+    [-213.9362931329109, 216.5944150675787, -77.07852298594841],
+    [-233.55169391606708, 230.90930025125778, -78.71460824329992],
+    [-251.18849159364183, 243.70113072675966, -80.14822061525027],
+    [355.7097688718114, -303.3217068865819, -5.6990030813936094],
+    [249.1771694599193, -196.76957953760785, -18.559501861151148],
+    [181.14419901964328, -128.3595944955049, -27.40987459612869],
+    [133.31292029225327, -80.59109477618412, -33.67666211486393],
+    [97.39782347091108, -45.08038358022614, -38.323021174012794],
+    [68.57167860917156, -17.108661478054472, -41.9397272413439],
+    [42.300103280158375, 7.189214824354607, -45.0339282593152],
+    [12.307982578470265, 32.48183493245607, -48.23352788180994],
+    [-24.19452467862072, 61.019147998526485, -51.8628532334461],
+    [-57.5204682420438, 86.73631664643156, -55.12653362058464],
+    [-87.54683520028767, 109.70639674409087, -58.03039844203225],
+    [-114.5533140076901, 130.2242371105003, -60.61058521211366],
+    [-138.86461957639946, 148.58683717242795, -62.90451793905809],
+    [-160.7887263809337, 165.06112255537593, -64.9463862285844],
+    [-180.60168019193748, 179.87927775513967, -66.76629872800925],
+    [-198.54573564371682, 193.2410908038784, -68.3904239231649],
+    [356.49806773838395, -308.59628291295166, -1.9256143014395597],
+    [265.2585320019334, -219.1422600469581, -11.274756300620497],
+    [201.25565765719443, -154.9326525236011, -19.378971329584296],
+    [154.6499951235819, -108.54957133069891, -25.409479791651044],
+    [119.5189427300656, -73.68529195390485, -29.987056537868007],
+    [91.31272498437191, -46.04425970452226, -33.61180218784455],
+    [66.77419182754042, -22.70018939621057, -36.649987081154165],
+    [42.266443080349184, -0.7802116280251576, -39.47649107541927],
+    [11.732152462729573, 23.998800582091093, -42.66308793084864],
+    [-20.17298328608049, 48.91782759487288, -45.87310948166257],
+    [-49.240246671515756, 71.41639603953244, -48.770765255791346],
+    [-75.57566132900939, 91.67006614052497, -51.37551222971914],
+    [-99.42851259002644, 109.92117249519107, -53.71659802537386],
+    [-121.05971379900372, 126.40161245382784, -55.822779565552274],
+    [-140.71196163581942, 141.31847120803027, -57.7202007322175],
+    [-158.6024039176958, 154.8525905983656, -59.43201230433006],
+    [349.5239053064369, -303.93897188915656, -0.9128879611537233],
+    [274.4418917397681, -232.73689714389366, -6.430656037881678],
+    [214.63540116030933, -173.04699296877996, -13.698521055949957],
+    [169.70969780093026, -128.5087761568122, -19.3968771417468],
+    [135.76364515102884, -94.72657581765196, -23.806857462851873],
+    [108.20326939318778, -67.61317633389142, -27.368078633096662],
+    [84.43010293842535, -44.76034381146184, -30.366365104418577],
+    [62.004790048713744, -24.122621037406493, -33.059408959615745],
+    [37.92743030788519, -3.4478785119679745, -35.74118546130774],
+    [7.797868456023997, 20.34463636982843, -38.82404634111329],
+    [-20.21860107155828, 42.20981603706639, -41.663229130849615],
+    [-45.77113232439186, 62.02569547452882, -44.23915166865826],
+    [-69.03138238523307, 79.97952866154084, -46.57307627289447],
+    [-90.21814779529494, 96.27188644963675, -48.68894708087489],
+    [-109.54549953506934, 111.08808881739999, -50.60946004813933],
+    [-127.20929349157991, 124.59264506630156, -52.35513590278673],
+    [341.5987556484393, -297.7347469365787, -0.4750428008343659],
+    [278.70857110430626, -240.10223374056616, -3.3041801858131055],
+    [223.64688758360876, -185.67393876212196, -9.536763231120222],
+    [180.6332148734701, -143.2082370401389, -14.85694273233296],
+    [147.9376436620457, -110.63802771058289, -19.063149169675036],
+    [120.97598783938072, -84.11867284850436, -22.52928237899858],
+    [97.97537903616174, -61.849188325104514, -25.453204906726924],
+    [76.84075516852995, -42.06130362326288, -28.047973936220554],
+    [55.31149167786771, -23.012690340623294, -30.534585471753015],
+    [29.373216959820926, -1.9214148995566365, -33.27581169069573],
+    [2.437765781585023, 19.236703714473617, -36.03072599891813],
+    [-22.30253143755225, 38.5403915714123, -38.551625696032296],
+    [-44.92279878513886, 56.11090608794912, -40.85065055116515],
+    [-65.60202265034992, 72.11953057908903, -42.947342197867904],
+    [-84.5289977557846, 86.73231944925969, -44.86137086170527],
+    [-101.88116869011812, 100.09953288751574, -46.610966485282],
+    [333.94775161678933, -291.56483515793013, -0.1804757289974187],
+    [278.6749235276815, -242.06391127060692, -1.6451520384521867],
+    [229.6022962605148, -194.46811863786104, -6.432231818312393],
+    [188.65666814635745, -154.2359044323622, -11.338847858395825],
+    [157.1872889183011, -122.90966678850815, -15.327274289868525],
+    [130.8655709968835, -97.06305014181224, -18.676516282860554],
+    [108.51713141871289, -75.35242487618032, -21.516561418586654],
+    [88.36034205710452, -56.25983507059795, -24.02100432609702],
+    [68.20695764760457, -38.12623005911581, -26.393743582463728],
+    [46.298169907541116, -19.627125729777063, -28.805733119819905],
+    [20.52165329154366, 0.7455495185861409, -31.457675403247908],
+    [-3.4112639519467995, 19.508435563374587, -33.91056279814226],
+    [-25.383748615118314, 36.65799611692196, -36.16026443149421],
+    [-45.534460926277895, 52.33644229248696, -38.22208217044156],
+    [-64.02922983274944, 66.69237079245079, -40.11298350743667],
+    [-81.02927689350498, 79.86342353067741, -41.849184323669405],
+    [326.7813452695638, -285.7457627322581, 0.07243449295485133],
+    [275.9696789373093, -240.6616884198221, -0.9082814138582096],
+    [233.21695619300533, -200.34249386892822, -4.128382467295681],
+    [194.55360773572386, -162.58148424877828, -8.563831490765477],
+    [164.30136472232235, -132.52256181387145, -12.325302942035398],
+    [138.68424638256735, -107.4183882706626, -15.542692219023905],
+    [116.98130341906031, -86.29279153739546, -18.288532615179417],
+    [97.50433413436176, -67.7376287781957, -20.715113734920667],
+    [78.55061060715506, -50.42866755265437, -22.979579819832978],
+    [58.97218549658991, -33.44937365645169, -25.19691315203288],
+    [35.19810129458212, -14.40359363180978, -27.67375892148057],
+    [12.056024688288417, 3.8106148231202854, -30.052299357826158],
+    [-9.2793250057352, 20.526304410707397, -32.245344901058004],
+    [-28.90145585819893, 35.85410260289766, -34.26376400146111],
+    [-46.95470608691935, 49.92638541238268, -36.122057687156996],
+    [-63.58575888463746, 62.8694582578077, -37.83465016962805],
+    [320.16464421850696, -280.3991040657862, 0.33109207037632993],
+    [272.3992107821753, -238.13081773577494, -0.5110199167817913],
+    [234.78383868655197, -203.69233883573824, -2.50800550699939],
+    [198.82944894916918, -168.88723440715341, -6.352303977193096],
+    [169.80810367133427, -140.130376673784, -9.876111667090738],
+    [144.9390137339467, -115.81289146239465, -12.952552732061381],
+    [123.89194330108245, -95.29659761901817, -15.597226596389877],
+    [104.97211509285825, -77.22157694114325, -17.94850855873149],
+    [86.9497884264465, -60.583918716692374, -20.120108324587],
+    [68.4521445493589, -44.34860236425138, -22.237766478446044],
+    [47.253818250538195, -26.983759435345462, -24.49534741328581],
+    [24.88986130268612, -9.320628142934396, -26.796223856137757],
+    [4.172720010475463, 6.961190672347421, -28.9292137591089],
+    [-14.931233479020422, 21.932093098759445, -30.899792009567367],
+    [-32.545558571544596, 35.70895453504711, -32.720151658044756],
+    [-48.80371108473924, 48.40775905505485, -34.40310394708787],
+    [314.16977749692165, -275.6448847585626, 0.6394698613105522],
+    [268.6320441019377, -235.30760044853616, -0.233255179493742],
+    [234.45453420534318, -204.73903785423582, -1.501903779776663],
+    [201.8131180352737, -173.56893531232822, -4.588309192001502],
+    [174.06655021157684, -146.17996806436452, -7.855970816172454],
+    [149.97062886841158, -122.67684169279148, -10.785359707769539],
+    [129.56364287060475, -102.771073410438, -13.326360353706006],
+    [111.18018821509877, -85.17500257942444, -15.599837369376264],
+    [93.80647885889164, -69.0437525356317, -17.696323084974686],
+    [76.16861329557788, -53.42243681681689, -19.728521436792214],
+    [57.21513941736571, -37.523587587951965, -21.794875709963865],
+    [35.651553213857426, -20.430678704516726, -24.014124383003725],
+    [15.530629475480913, -4.57607876456423, -26.085887827203447],
+    [-3.0706487445104473, 10.03961634627188, -28.006582816880247],
+    [-20.25494317420357, 23.517955158742996, -29.786174776960497],
+    [-36.14350649222124, 35.96540812926309, -31.436005915005918],
+    [308.9141119374196, -271.6518379113732, 1.0516221407946869],
+    [264.876092677927, -232.46562213472905, 0.00043873248519725504],
+    [232.79602174322173, -204.17844965935052, -0.9265393756292866],
+    [203.69809937847927, -176.8717299384703, -3.202681948260319],
+    [177.32542617899534, -150.98320687383202, -6.178406723813488],
+    [154.02215693148412, -128.31846198464447, -8.954779716847014],
+    [134.22907345166976, -109.01449870289322, -11.391574322525448],
+    [116.34520277375006, -91.88218752431084, -13.58746859335334],
+    [99.51819401714924, -76.20123496826271, -15.614102663105886],
+    [82.62134121211078, -61.12465751629055, -17.568183082307378],
+    [65.29432646100166, -46.303881925708325, -19.492086785867883],
+    [44.75119872613394, -29.918407958477832, -21.612289863574322],
+    [25.20436651950443, -14.48129318320946, -23.622909461085705],
+    [7.088030878510543, -0.21468860175773277, -25.49311844703089],
+    [-9.678355126558362, 12.9671041765388, -27.2306418374478],
+    [-25.204636260525938, 25.161683457889243, -28.845468713024776],
+    [304.32667602153344, -268.3535850870292, 1.5597862824356037],
+    [261.21063355355733, -229.723207515356, 0.22563567529021442],
+    [230.5415877322777, -202.86097228864818, -0.5678247428467122],
+    [204.57171770282173, -178.9092984561723, -2.161530157535729],
+    [179.75644522282383, -154.75766402974082, -4.782787081542481],
+    [157.27337031426566, -132.96502423221244, -7.397455172610216],
+    [138.07268641614453, -114.25555308215577, -9.72957135649309],
+    [120.65404341389377, -97.57091796199154, -11.848765793417662],
+    [104.34810423831202, -82.32841274790515, -13.806094114279185],
+    [88.09472548912456, -67.73960008664265, -15.688257240649111],
+    [71.64005643008444, -53.531345411203716, -17.526842835253316],
+    [52.49529725480645, -38.08244615291262, -19.520556881454052],
+    [33.50232066989365, -23.052305096633596, -21.4707663264955],
+    [15.852525084511003, -9.12626837745989, -23.290757984010057],
+    [-0.5095736530796827, 3.7639207628635227, -24.985869188644116],
+    [-15.683016297172195, 15.70749180189701, -26.56482868315422],
+    [299.84035366837634, -265.0776801705298, 2.0013874150416586],
+    [257.68250586711673, -227.15756613674, 0.4668801194880369],
+    [228.1289424512068, -201.2716728921597, -0.3068293609368433],
+    [204.51204467297126, -179.78319277048027, -1.4350463918334935],
+    [181.4719683440715, -157.64871526911537, -3.627989211509243],
+    [159.86026699624514, -136.78717396320857, -6.06611321701828],
+    [141.23850409127587, -118.67006154681344, -8.292236418924455],
+    [124.26466947170309, -102.42410917317102, -10.335049221545933],
+    [108.42863841632968, -87.59144604555546, -12.225935970108045],
+    [92.74286264595167, -73.4457705635791, -14.041183537600523],
+    [76.90556079770765, -59.69421191687919, -15.813201873217599],
+    [59.11657787082526, -45.150391375402506, -17.68536218935855],
+    [40.66126311320435, -30.517899418049083, -19.576111581118983],
+    [23.459789259774894, -16.922696139337248, -21.346679752682928],
+    [7.4878446151633336, -4.317475323699479, -22.99963698637652],
+    [-7.343244454238045, 7.379007809414438, -24.54252487585017],
+    [295.16470271674655, -261.49474893604287, 2.301600554176886],
+    [254.34583127391682, -224.83712710047683, 0.7438565495141016],
+    [225.74717886578355, -199.6259485382132, -0.0894518570817062],
+    [203.732642507878, -179.75888515944595, -0.9526004612150999],
+    [182.53486483780267, -159.74236020063046, -2.6886319997889303],
+    [161.88581891477517, -139.91327795537526, -4.925338562562825],
+    [143.83736501388123, -122.39332442361263, -7.042633738128613],
+    [127.29866883523535, -106.58296806233024, -9.008758490643126],
+    [111.88298061482006, -92.131939136456, -10.836157585346193],
+    [96.6968671984343, -78.38896071077357, -12.589144946624764],
+    [81.39103461582317, -65.04226626277368, -14.300743617624871],
+    [64.79227613325419, -51.29725479897018, -16.06525688353719],
+    [46.8662054773489, -37.05693439501235, -17.89727290888085],
+    [30.09547939240976, -23.782334154926037, -19.619520797369013],
+    [14.499608431031414, -11.45453542411246, -21.230959252273784],
+    [-2.043379347578331e-10, 1.8586159160817463e-10, -22.737998889246587],
+    [-80.79818330214638, 155.87263559904142, -73.59944954317149],
+    [-89.74275638835587, 150.45368307551632, -65.0815263737929],
+    [-102.41701641765525, 151.05931261739326, -60.19352597424265],
+    [-114.41655636882108, 153.19808516548125, -56.96458185592907],
+    [-124.52442315679069, 155.2785713566721, -54.583293888613994],
+    [-132.82024624661207, 156.96526968886076, -52.703439984529815],
+    [-139.5884202014915, 158.22250603607063, -51.1493797962066],
+    [-145.10782652056903, 159.09294188228554, -49.821691411402604],
+    [-149.61187586240828, 159.63510226052074, -48.659496252725766],
+    [-153.28784978717587, 159.90491790473047, -47.62322655955552],
+    [-156.28454913647042, 159.9508770415795, -46.68592699012037],
+    [-158.72039462492373, 159.81353834083947, -45.82851789857105],
+    [-160.69019245811268, 159.52636354043875, -45.03705583614559],
+    [-162.27041088345635, 159.1168364207413, -44.3010697363614],
+    [-163.52320676087407, 158.60752660841936, -43.6125087086915],
+    [-164.49948557405844, 158.01700190604117, -42.96505354097398],
+    [78.3841178602263, -39.16666429725822, -17.592696463688252],
+    [33.16605525563198, 1.5800285892911707, -22.581950828341668],
+    [8.434819279822394, 23.170560195385242, -24.965855598363],
+    [-8.446660511110007, 37.20356011613862, -26.32415507456381],
+    [-24.16205582796288, 49.34956537515982, -27.456322734543082],
+    [-39.88048692319228, 60.889887034161106, -28.55060902399372],
+    [-53.56761503704824, 70.65685173165437, -29.440147602862],
+    [-65.27037306359283, 78.81622638024659, -30.142300217435658],
+    [-75.26036101667817, 85.63705960554115, -30.68962928717262],
+    [-83.80842883656169, 91.35846646511997, -31.11139697070503],
+    [-91.14802155781234, 96.17603208979708, -31.431523186514916],
+    [-97.47263509275265, 100.24664109844554, -31.669107022115032],
+    [-102.94092127887309, 103.69590142557853, -31.839339064952206],
+    [-107.6828772582349, 106.62482073964485, -31.95433839736506],
+    [-111.80539605555515, 109.11516919057937, -32.02382912371029],
+    [-115.39683559164953, 111.23363580496432, -32.05566556195723],
+    [109.65704343910059, -77.58498928478008, -6.506848958944328],
+    [65.6955193422949, -37.67124422905749, -11.53979929709878],
+    [39.754167093882124, -14.315833577851963, -14.494640934607023],
+    [21.972939570585112, 1.3951678113496062, -16.432312605654268],
+    [8.09115372649832, 13.27328760462786, -17.848940165330028],
+    [-4.708470537436081, 23.680470281967857, -19.06634043907554],
+    [-17.90755867748667, 33.87201114100617, -20.26210707293309],
+    [-29.89319521823465, 42.91524232998218, -21.31124647909613],
+    [-40.45921886062013, 50.7632348659382, -22.206010074068192],
+    [-49.73575007293408, 57.56461802778626, -22.965361445311274],
+    [-57.886009064795935, 63.47182580394868, -23.60908768332421],
+    [-65.06276851118248, 68.61832317535134, -24.154646741568392],
+    [-71.39942928520962, 73.11649445354755, -24.61680882962268],
+    [-77.00980315165243, 77.05994709264917, -25.007914233281348],
+    [-81.9902610969172, 80.52656021813546, -25.33824913008958],
+    [-86.42232456832532, 83.58131006378139, -25.616403625968267],
+    [120.39125703583196, -91.68957670497203, -2.0229423668565127],
+    [80.41927474185171, -55.704005490760736, -6.349298999646785],
+    [55.39845192398666, -33.126882289509936, -9.2114405467889],
+    [37.8387597100068, -17.426611774790814, -11.214582045155923],
+    [24.277888270285708, -5.5238242346634125, -12.720980959448104],
+    [12.738144942430317, 4.303066323981808, -13.94944379277541],
+    [1.6053009172391985, 13.360155724914964, -15.074081801894184],
+    [-9.64817608558038, 22.145358400130167, -16.165107982671852],
+    [-19.937841505585236, 30.03643099974392, -17.140116569404842],
+    [-29.137270817527035, 37.01012252619636, -17.994933131015728],
+    [-37.33591778405251, 43.167902280534655, -18.742262987808108],
+    [-44.647285920805544, 48.61577228174245, -19.39570651175145],
+    [-51.1798046215362, 53.44868558411226, -19.967606274828107],
+    [-57.03008315629553, 57.74837432846174, -20.46868745289045],
+    [-62.282109760980845, 61.58432016115099, -20.908136036519313],
+    [-67.00824656295319, 65.01543089926366, -21.29378739822525],
+    [122.7753579514326, -96.02363628683692, -0.14218329731069632],
+    [88.07640784742317, -65.41624963369779, -3.408618686558957],
+    [64.4885744422997, -44.21244230577165, -6.033166288564333],
+    [47.5362370806957, -29.02881937426979, -7.964908676580257],
+    [34.379233536963106, -17.38275837212454, -9.456871736773937],
+    [23.358742528847543, -7.834015862772904, -10.67813118898079],
+    [13.371790352827588, 0.5523040268463233, -11.746291590805562],
+    [3.3813087011465366, 8.589589818135428, -12.768557521508916],
+    [-6.380331193333107, 16.218827590243976, -13.739139085610047],
+    [-15.279270239241995, 23.08660949170298, -14.611028705087742],
+    [-23.298445130861925, 29.22249899564454, -15.387102601030435],
+    [-30.513944819595828, 34.70598361536454, -16.077106252495128],
+    [-37.01268263714586, 39.616382698301976, -16.69103124968635],
+    [-42.87676725551769, 44.0247941682414, -17.23798036257986],
+    [-48.179675381408906, 47.99298970599113, -17.725960129791662],
+    [-52.985835197100116, 51.5740885053536, -18.16192480588673],
+    [122.25579070597968, -96.91459356881822, 0.7983212916741546],
+    [92.08367670623882, -70.85933428197546, -1.6101295955701833],
+    [70.13624005351826, -51.26952041859243, -3.9391721804784563],
+    [53.93028245079352, -36.78065926601857, -5.753811224832933],
+    [41.231559227341386, -25.51397166482471, -7.191331716836833],
+    [30.633933589192036, -16.260075584844863, -8.379963539715803],
+    [21.224598484539293, -8.239028260992685, -9.411547860753364],
+    [12.22590492972518, -0.8242133317063634, -10.364880331131445],
+    [3.1932288439303815, 6.3569835894455435, -11.289057151968603],
+    [-5.3312334948283535, 13.01426994941935, -12.14679814237897],
+    [-13.095390863242995, 19.02439590157389, -12.921065407736368],
+    [-20.131775014569655, 24.436810047098835, -13.617386599138888],
+    [-26.507637845803213, 29.3165510173661, -14.243612675367556],
+    [-32.29271309989797, 33.725493340409265, -14.807414204331224],
+    [-37.55167616787127, 37.718778806237914, -15.315749734735457],
+    [-42.34231664734234, 41.34451397491039, -15.77477480310459],
+    [120.95287904167292, -96.79953546916629, 1.427367555286471],
+    [93.81416471567908, -73.65435829622072, -0.5142490739457767],
+    [73.72324082064657, -55.92667546119303, -2.486135697769727],
+    [58.320583462613605, -42.20684840461806, -4.164892183400548],
+    [46.105094695886606, -31.373325193523065, -5.531294910791494],
+    [35.896760066074506, -22.429620256955246, -6.67509543155661],
+    [26.89850393046096, -14.699666969756269, -7.669935004493857],
+    [18.52396514961617, -7.695657695852962, -8.573790195580965],
+    [10.225995816803524, -0.9893187900022873, -9.440669750632411],
+    [2.1242650057525836, 5.399656333090884, -10.268545970435005],
+    [-5.361283618147644, 11.241594772335255, -11.027223280329661],
+    [-12.189197364793595, 16.537125692938076, -11.715744916731934],
+    [-18.40683439919164, 21.337118503938974, -12.339900213675337],
+    [-24.07290878711194, 25.695234321371217, -12.906095704442059],
+    [-29.244557684903352, 29.660876425106093, -13.420380101029926],
+    [-33.973947561704385, 33.27783479426021, -13.888213548303945],
+    [119.32101912426101, -96.21154022424923, 1.8876226031862438],
+    [94.21924087277137, -74.90525010389817, 0.16983183515555758],
+    [75.95200326149309, -59.0078453824911, -1.451750033850348],
+    [61.39304061392334, -46.10875729733612, -2.9813387241800187],
+    [49.67025655240262, -35.732240204585466, -4.269201563610596],
+    [39.83158225130647, -27.104430572210536, -5.3626537877817855],
+    [31.179978527193093, -19.64186358996977, -6.318883584926705],
+    [23.23214186322596, -12.937012329663744, -7.183188206173415],
+    [15.512367461413081, -6.616559607469923, -8.000668519009857],
+    [7.880957173939788, -0.5390591817610346, -8.78917406659208],
+    [0.6801567852379581, 5.116381130753359, -9.525588461643693],
+    [-5.9318129520954885, 10.275534897121494, -10.199403988430436],
+    [-11.978857678500319, 14.97312304341891, -10.81416278541133],
+    [-17.509364592432345, 19.255185211193147, -11.375122765172833],
+    [-22.57383479899495, 23.166058322882332, -11.887536097589809],
+    [-27.219579438027594, 26.745771209069797, -12.356258013383625],
+    [117.48577305632304, -95.3077306316955, 2.225545664157782],
+    [94.06151290848896, -75.48165399940898, 0.6647848998941505],
+    [77.21308270163887, -60.97016820279544, -0.7132707591164141],
+    [63.545119137630785, -48.94914960294439, -2.078878905565],
+    [52.31940910586562, -39.042435268248326, -3.2841015402722564],
+    [42.836535535227156, -30.73104779839816, -4.324799280441238],
+    [34.49615140280185, -23.52274555778805, -5.241569125609045],
+    [26.880016693508146, -17.065259905258788, -6.070450563452883],
+    [19.601745266213307, -11.050237372085585, -6.846809160745119],
+    [12.416270380383025, -5.274714901767991, -7.595263144432705],
+    [5.505734836058294, 0.1829867403851376, -8.305668724944674],
+    [-0.8919720323228648, 5.198461946932885, -8.961379964623124],
+    [-6.766537196566752, 9.783813879621688, -9.562945478963638],
+    [-12.156061949457797, 13.977628930477621, -10.114531756513294],
+    [-17.105044605678323, 17.81967040367659, -10.620681906222549],
+    [-21.656525804229876, 21.346641759604505, -11.085720177541381],
+    [115.57427710347086, -94.2432448368807, 2.4852847693606295],
+    [93.58998451719569, -75.66938199415593, 1.044716998154066],
+    [77.79471416631682, -62.147453260568675, -0.18261965217008208],
+    [65.02172688615329, -51.01250588740153, -1.38151926706312],
+    [54.29896916020524, -41.58739790928439, -2.500459174101493],
+    [45.161545298656804, -33.59092312036362, -3.487355633689292],
+    [37.10877278361426, -26.627024930051117, -4.364606745225878],
+    [29.77380476280246, -20.389900317718116, -5.160116074934171],
+    [22.83993554845402, -14.622845402027995, -5.901459679934644],
+    [16.037434944929995, -9.112578710752219, -6.61355297875576],
+    [9.425612638840391, -3.8619297547722735, -7.295565444913497],
+    [3.2370702088805867, 1.0082554734058726, -7.931560851736224],
+    [-2.4680179558092474, 5.478093056624761, -8.518016865668766],
+    [-7.716670991725782, 9.578342827941105, -9.057990452307635],
+    [-12.547811516651976, 13.344547827088018, -9.555382710264613],
+    [-17.00066209998084, 16.810430067156435, -10.014046521427776],
+    [113.6515001120377, -93.10078922076461, 2.6918895471792874],
+    [92.92784645087688, -75.61079615093188, 1.3470512082731778],
+    [77.96838801042314, -62.8486775898551, 0.2195397180148143],
+    [65.98333047504552, -52.48488696181492, -0.8397716784797475],
+    [55.77170954101691, -43.55419999912698, -1.8686419739830822],
+    [46.97236485759625, -35.87115493906414, -2.8011148979591307],
+    [39.190020491462434, -29.143555091633104, -3.6391859147738796],
+    [32.10516867410273, -23.110005735319582, -4.402699379459594],
+    [25.451937764244544, -17.553168560328665, -5.113094279927906],
+    [18.972301480492586, -12.272037540081099, -5.792853725798532],
+    [12.650459355731321, -7.2248364580808895, -6.446340850046854],
+    [6.665590405298282, -2.4992296620816745, -7.061918932316353],
+    [1.124781819596782, 1.855229649510175, -7.632386907587204],
+    [-3.9858789287110463, 5.860361928934736, -8.159590951907411],
+    [-8.699966462152652, 9.547695484294655, -8.64683033545587],
+    [-13.053244427939688, 12.94824977281774, -9.097536088405107],
+    [111.73492307264921, -91.90805090653046, 2.8560752641425213],
+    [92.1371873297632, -75.37881656204715, 1.5915186241619064],
+    [77.8996726824945, -63.26153726478114, 0.5411290462172526],
+    [66.55178649328833, -53.50666327926956, -0.41670487299934017],
+    [56.84947574402486, -45.07128106374652, -1.3546915037136489],
+    [48.383623624928795, -37.701309243722626, -2.2320788275463608],
+    [40.85873992381063, -31.203337844099572, -3.0314322823898636],
+    [34.0027240479539, -25.361469193180483, -3.7640620908424127],
+    [27.58880620744814, -19.99029851333415, -4.446203204687414],
+    [21.384745956910447, -14.909577092795955, -5.096950157364796],
+    [15.328442740301954, -10.051358280820196, -5.723457908328034],
+    [9.542961544236556, -5.469101996283649, -6.318349120294813],
+    [4.16035993712729, -1.2280754637471432, -6.8725500577840934],
+    [-0.8165183919003529, 2.6824700386976654, -7.386458554020024],
+    [-5.416001762312408, 6.290188599936964, -7.862809512061332],
+    [-9.670668388046163, 9.623608831712069, -8.304648571081364],
+    [109.83965142194455, -90.68840742051678, 2.986823060499388],
+    [91.26102461793921, -75.0243627659685, 1.7920817050365148],
+    [77.66653820219003, -63.47508051068634, 0.8051690978187328],
+    [66.83756910494888, -54.20341365806008, -0.07992999584540317],
+    [57.612668526153115, -46.23087230206252, -0.93430989998343],
+    [49.47726325812996, -39.17408489341796, -1.755977087838631],
+    [42.199966991628315, -32.90041811256863, -2.517038724451152],
+    [35.55713133203372, -27.24112027094475, -3.2197438687492843],
+    [29.354618731583376, -22.038855361721982, -3.8755341585083607],
+    [23.38818053154156, -17.13527120271817, -4.500038174520536],
+    [17.568723205518832, -12.44844559447993, -5.101655383588859],
+    [11.978412045778496, -8.00754593757681, -5.675916590169769],
+    [6.747802052795215, -3.8771297973442405, -6.213909341410741],
+    [1.8998939131525288, -0.05945432151380236, -6.714381436181937],
+    [-2.5882833049421663, 3.469246683515618, -7.1795137624805845],
+    [-6.746351876887225, 6.735195254809315, -7.612000008043118],
+    [107.98197152220332, -89.46508314476961, 3.0924975210098773],
+    [90.3319379760045, -74.58622462889322, 1.9594686773749748],
+    [77.31697835418498, -63.54437256638473, 1.0260190340978945],
+    [66.93096682196503, -54.67687085716648, 0.19645598915952633],
+    [58.123931842063485, -47.10456314889834, -0.5887690557766402],
+    [50.31358469450273, -40.35774126680533, -1.3549753125900683],
+    [43.276305853521336, -34.30420276324646, -2.078080691662795],
+    [36.8346239609425, -28.820210034676144, -2.7516935972770695],
+    [30.823669519897468, -23.774719421276327, -3.382572218649234],
+    [25.065121130522407, -19.029883195104482, -3.9830743357649223],
+    [19.45658439768491, -14.498343927396032, -4.561785565756943],
+    [14.053788838481703, -10.194190998442874, -5.115935695018193],
+    [8.969172376616674, -6.171185591805472, -5.63797203671916],
+    [4.245249524740534, -2.4440320401516233, -6.125096015034149],
+    [-0.13531023612225343, 1.007029723713161, -6.578932315319907],
+    [-4.1993539331562015, 4.206061876550933, -7.001849097079108],
+    [106.17334025303107, -88.25479938063057, 3.179424436794364],
+    [89.37013777039094, -74.08913903026337, 2.1007966814668353],
+    [76.88180719576395, -63.5050073189502, 1.2130774219483027],
+    [66.88909912404817, -54.99141861872975, 0.4288599236872901],
+    [58.43739593848047, -47.75369085630421, -0.3022258316800677],
+    [50.938445094456696, -41.30419144018471, -1.0155439793424008],
+    [44.134964398235816, -35.46713162331238, -1.7010282713662745],
+    [37.88494381318916, -30.15252054882329, -2.3462661302997727],
+    [32.050759159356474, -25.25456491140505, -2.953380464832672],
+    [26.47722851644809, -20.654186699217263, -3.531664036588749],
+    [21.058336754860722, -16.26456619074219, -4.089161218183046],
+    [15.83174443435352, -12.090031631697563, -4.62410480748336],
+    [10.887489997158305, -8.171127338317731, -5.130544251962],
+    [6.282610767776308, -4.5318237944019435, -5.604547708889703],
+    [2.0058482965184186, -1.1565777232170238, -6.047169440011122],
+    [-1.9670147564736153, 1.9766339359164158, -6.460474004772112],
+    [104.4192341022404, -87.06690310591335, 3.2518026081190023],
+    [88.38814811652642, -73.5490745787488, 2.2209117117315937],
+    [76.38247456195445, -63.38192154594541, 1.3730095666662692],
+    [66.74645463549344, -55.18614801869924, 0.6273275937133876],
+    [58.60056302213937, -48.23159576756885, -0.06107850797322317],
+    [51.388389776811806, -42.05473898779672, -0.7269659183704427],
+    [44.81233846891934, -36.429696969960844, -1.3754455405297266],
+    [38.746292565807614, -31.27951240024942, -1.992935779474255],
+    [33.07743523191849, -26.52177014648349, -2.577234266365153],
+    [27.67127751454478, -22.054687608400393, -3.134728365024864],
+    [22.424392015304868, -17.79578102728707, -3.672463513284427],
+    [17.36122604124121, -13.742707997044654, -4.189269564708009],
+    [12.552012359661713, -9.924563727999406, -4.680536423885413],
+    [8.061394176377853, -6.370300260697634, -5.141729604070673],
+    [3.8846257078867867, -3.0688073150575748, -5.5733176618011],
+    [-5.50753258014726e-10, 4.833306864741706e-10, -5.97707999185309],
+    [-33.51276703659285, 70.65779636921107, -34.518983290992345],
+    [-41.82012203978293, 71.13119616373231, -30.916895230398666],
+    [-48.35035893435389, 71.80070939375797, -28.64401339740707],
+    [-54.283231923200944, 72.99128141882856, -27.130295867095402],
+    [-59.25745458208228, 74.10968378017652, -26.013948504215975],
+    [-63.345202574307756, 75.02220632358035, -25.13390709751953],
+    [-66.69274479471454, 75.72017394431035, -24.407709306917173],
+    [-69.43749345977236, 76.22746866261504, -23.788497866735856],
+    [-71.69295314590744, 76.5737988507062, -23.247524639487132],
+    [-73.54967434531015, 76.78687613287775, -22.76608474194886],
+    [-75.0793858538941, 76.89048090762142, -22.331427368474916],
+    [-76.33907162004934, 76.90440067131203, -21.93452394807582],
+    [-77.37430207782567, 76.8449110071273, -21.568774983639063],
+    [-78.22180690722116, 76.72535527388062, -21.229223943852688],
+    [-78.91142783434502, 76.556678837391, -20.912059778826503],
+    [-79.46759781384296, 76.34788079417757, -20.61429124606268],
+    [49.67252423678702, -26.812851296017378, -7.316317059595692],
+    [22.73464921181491, -3.8775912292012946, -10.114567464985099],
+    [7.602290653815967, 8.673988837319135, -11.545018493849316],
+    [-2.7266506448952335, 16.93777684094674, -12.406511030388149],
+    [-11.195598947582631, 23.415527218760992, -13.041753688202393],
+    [-18.86219765124302, 29.05046147958755, -13.582074790330166],
+    [-25.511358223316616, 33.80537441980866, -14.020100383907925],
+    [-31.200249759485672, 37.78347935732246, -14.367448972137284],
+    [-36.06561892688473, 41.118160400223495, -14.64033309438242],
+    [-40.23933892852239, 43.925380690700386, -14.85295987815137],
+    [-43.83399340063175, 46.299324209011466, -15.016850555107371],
+    [-46.9425233548803, 48.31530179562175, -15.141172405673395],
+    [-49.6409747911782, 50.033510100933775, -15.233203264251262],
+    [-51.99158472267522, 51.5023101709348, -15.298743305317295],
+    [-54.04549203841653, 52.76083014556136, -15.342443587299272],
+    [-55.84494926316688, 53.84097085820023, -15.36805949872261],
+    [64.87171360423231, -44.91336518863019, -2.1450874958699484],
+    [39.41854434489481, -23.184178416498153, -4.808409380081096],
+    [24.08447175155959, -10.15725103803403, -6.437936471064922],
+    [13.412519483442624, -1.2232906959114707, -7.542908586636642],
+    [5.162518918077994, 5.53470885294502, -8.36163693809204],
+    [-1.9038346201074972, 11.154632190219516, -9.030465823883498],
+    [-8.39725346478309, 16.1614882175892, -9.620682124631186],
+    [-14.225484557622325, 20.56231215126923, -10.1331869050742],
+    [-19.363455244745765, 24.383912361263057, -10.57093666111895],
+    [-23.88059682716505, 27.70216787272793, -10.9436296994894],
+    [-27.857263118805385, 30.591493642734957, -11.260918689482963],
+    [-31.367446570975478, 33.11632424465654, -11.531222841991859],
+    [-34.475354274208435, 35.33066856831115, -11.761631794054138],
+    [-37.23559645827273, 37.2794064074555, -11.958054660749902],
+    [-39.694334733647295, 38.99982638871063, -12.125410190285763],
+    [-41.89057665910137, 40.52301353870984, -12.267802624401947],
+    [69.39533622603805, -50.931708270583776, -0.1705695057265798],
+    [46.69992288628968, -31.81003959867449, -2.3583350038718995],
+    [32.14008327226051, -19.44318544618207, -3.890864684272025],
+    [21.75887673002847, -10.681707143880274, -4.99538661349701],
+    [13.71662471023334, -3.9799309510606364, -5.841070485007136],
+    [7.01918533193724, 1.4979002576765057, -6.528862145217133],
+    [1.0412395482338226, 6.269414870724413, -7.124856468168629],
+    [-4.487811549964304, 10.578734211821649, -7.661203168874385],
+    [-9.496449693643727, 14.42133420665573, -8.136980509597501],
+    [-13.97511836851373, 17.819587765829446, -8.554631839838935],
+    [-17.97192959895346, 20.825510823825798, -8.920681239444203],
+    [-21.542919911854362, 23.49093354740304, -9.2417711408191],
+    [-24.740686107011737, 25.861739756286372, -9.523857128498449],
+    [-27.611771560697516, 27.9772299846954, -9.772088351946293],
+    [-30.196501506207436, 29.870728791338593, -9.990863575499779],
+    [-32.52955123497913, 31.570446625987145, -10.183929004304936],
+    [70.4151458271819, -52.99290097888291, 0.7593971509868583],
+    [50.29044920893111, -36.28794017490135, -0.9989073811851005],
+    [36.71207938803477, -24.82170776906335, -2.3744250045916253],
+    [26.78980559264731, -16.44809896832198, -3.4198156418837256],
+    [19.031082583912898, -9.9510306197303, -4.2429829236293894],
+    [12.60131538010788, -4.6355365819944545, -4.91981550448642],
+    [6.971030888266616, -0.06382058523608215, -5.502426335722122],
+    [1.8107277329775044, 4.038655367634218, -6.025152170675275],
+    [-2.9631844598137294, 7.76657577572641, -6.4999345779563305],
+    [-7.299010659203496, 11.11380105861665, -6.925453652265435],
+    [-11.208527197470826, 14.107408569666454, -7.304766931436374],
+    [-14.731388706036402, 16.787521011661315, -7.64279981781432],
+    [-17.910352000352447, 19.192834596521575, -7.944421393382262],
+    [-20.785217099090136, 21.357687269843876, -8.214021273810667],
+    [-23.391379555776396, 23.311778859546568, -8.455442564171179],
+    [-25.759775813159212, 25.080587048446706, -8.672014939960192],
+    [70.26216846929465, -53.69140954363113, 1.3126042768876565],
+    [52.02969111486297, -38.69574309679172, -0.1798105928383924],
+    [39.47110282019116, -28.179506915040854, -1.3855800753313334],
+    [30.054325626626774, -20.257132469906832, -2.35462756938314],
+    [22.607082830094512, -14.0181003615084, -3.1382610317197086],
+    [16.423397361627725, -8.884953283479994, -3.7913347402799316],
+    [11.048387205996782, -4.4837413940039745, -4.354887784120406],
+    [6.181234017155637, -0.5666780946058627, -4.858140451105967],
+    [1.6650535853046906, 3.00447265741812, -5.3179552367037],
+    [-2.4966069763568073, 6.253511615996484, -5.7368578892489674],
+    [-6.283868525359402, 9.186126562925933, -6.115027732578062],
+    [-9.719629841904428, 11.83067982864247, -6.455709019179123],
+    [-12.837927980399643, 14.219475315391742, -6.76280409827489],
+    [-15.67287940422879, 16.38259155323602, -7.040038011793964],
+    [-18.25577877273104, 18.346628463791575, -7.2907625343427895],
+    [-20.614446375867317, 20.13470526053072, -7.517930948977781],
+    [69.59714637928396, -53.76485693386357, 1.6799559403265023],
+    [52.80246997188004, -40.01975104810299, 0.3547459707421862],
+    [41.15889119925696, -30.34800907403831, -0.7054506174249647],
+    [32.2548043976524, -22.89177087984265, -1.5935730860630983],
+    [25.12445257322375, -16.92770811988182, -2.331822910546016],
+    [19.176221910250934, -11.984643357278735, -2.9559560324815393],
+    [14.016430543000952, -7.7423465043217305, -3.4977070710457525],
+    [9.376912277432211, -3.9815689324160934, -3.9812639920600423],
+    [5.091964396304501, -0.5625780832118908, -4.4228837097913285],
+    [1.1115297593952667, 2.5709619317498773, -4.8290241299000805],
+    [-2.543509530133341, 5.4233039949019775, -5.199637892241641],
+    [-5.878768204549806, 8.010958131624395, -5.5363363891643695],
+    [-8.920020505765715, 10.360246958008645, -5.8421384160914975],
+    [-11.696420102133112, 12.49751877389051, -6.120190917261003],
+    [-14.235779203564336, 14.446709710527596, -6.373421887390004],
+    [-16.56329088691245, 16.22891626959611, -6.604458216660931],
+    [68.68759503116738, -53.514892155841416, 1.940894079239791],
+    [53.06505568698985, -40.76394092199994, 0.7336076889574922],
+    [42.17226405632301, -31.768795271567914, -0.21917028356887816],
+    [33.76206880336916, -24.763506977641587, -1.0292057122181275],
+    [26.944218653730655, -19.07618548571924, -1.7206454643341815],
+    [21.22230750336138, -14.323601547644277, -2.3140622181784063],
+    [16.25547031230219, -10.232759348830342, -2.833007173401434],
+    [11.804965770857315, -6.610196532077321, -3.2972111296847144],
+    [7.714127528600098, -3.3259180095217666, -3.720979138201628],
+    [3.907144182398364, -0.30934056737640775, -4.112226920930938],
+    [0.3847836497197988, 2.4556618251425815, -4.472344003746151],
+    [-2.8469723418597, 4.9775814321869065, -4.801853702391429],
+    [-5.805666561982326, 7.27689970863457, -5.102939294720272],
+    [-8.515954865375855, 9.376601694720824, -5.378235530477557],
+    [-11.002619935740226, 11.298295241298307, -5.630302780991918],
+    [-13.288599166148737, 13.061323131629807, -5.861485421017064],
+    [67.66082476501354, -53.08756638566781, 2.135004528041769],
+    [53.03031984041852, -41.16345459433539, 1.0169108466625736],
+    [42.757565660169185, -32.71360140762449, 0.14298367213445048],
+    [34.79440251047518, -26.113468694130216, -0.599074133218058],
+    [28.27862391044549, -20.69608040415745, -1.244625165557226],
+    [22.77393254158411, -16.13043626169096, -1.8071729073953564],
+    [17.985073115343287, -12.184625013982679, -2.3032565748519986],
+    [13.699729466162081, -8.688472203983375, -2.7486314804451397],
+    [9.773669955667522, -5.523589401061975, -3.155513691727088],
+    [6.1234482033361735, -2.61673884051621, -3.5317884590795123],
+    [2.729846108438019, 0.05974479040520238, -3.880170538488406],
+    [-0.3998622750449858, 2.5129458745574045, -4.200957931163753],
+    [-3.275429643929204, 4.757895500372735, -4.495575576613962],
+    [-5.917302593949509, 6.8144634063981835, -4.766194427906352],
+    [-8.347570183909873, 8.702186009987185, -5.0150519316628674],
+    [-10.587199854745666, 10.438865186131192, -5.244246040516086],
+    [66.58208096362313, -52.55982277683624, 2.284059732087055],
+    [52.811156282897024, -41.34335729521182, 1.2368067423359383],
+    [43.06734052235376, -33.34970257022848, 0.4235443719229019],
+    [35.49300290191232, -27.09679913060959, -0.2633507485450294],
+    [29.262122290305495, -21.934338555891227, -0.8659994087216495],
+    [23.96514515523344, -17.549649999848146, -1.3984236796387453],
+    [19.342792105021076, -13.742741499027398, -1.8721066059063887],
+    [15.206311752525353, -10.36426308842782, -2.299305887012658],
+    [11.423934298073403, -7.307158011292264, -2.6902694854586113],
+    [7.912778242779265, -4.500637301712252, -3.0522361913197855],
+    [4.640862920508776, -1.9101936860786999, -3.38861388299584],
+    [1.6098053127411673, 0.47419872679738445, -3.70001654747847],
+    [-1.1843743368638495, 2.663479154482913, -3.9873043883905464],
+    [-3.758119938908096, 4.674589661319755, -4.252222556078484],
+    [-6.131083005523247, 6.525196217357595, -4.496721487321968],
+    [-8.322485795827477, 8.231730167148255, -4.722682157728952],
+    [65.48703087720665, -51.97538454506743, 2.401138118288226],
+    [52.47356227236519, -41.377121602150545, 1.4123479814685902],
+    [43.19203312809754, -33.776156835346576, 0.6476550852247066],
+    [35.95507760520128, -27.819679129633656, 0.004974139742388088],
+    [29.985342735536744, -22.889702919530816, -0.5595438747288516],
+    [24.88553504876726, -18.677785814404935, -1.063269696562484],
+    [20.420027164365422, -15.003670638120377, -1.515318438549004],
+    [16.420282393400687, -11.735769327124332, -1.925077430802008],
+    [12.766266016597324, -8.777586523445093, -2.3010066478263838],
+    [9.378962733361508, -6.0627278710946255, -2.64949342147109],
+    [6.219748769848662, -3.5537218117433587, -2.97414202570238],
+    [3.2830309011496874, -1.2366874688479184, -3.275980053367851],
+    [0.5674449683296827, 0.8972501862798922, -3.5555696010494025],
+    [-1.9397509211954422, 2.862347468131558, -3.814273615347507],
+    [-4.2559703532464175, 4.674558686249047, -4.053783954434826],
+    [-6.398871802571176, 6.349083767716769, -4.275788861976517],
+    [64.39586976599657, -51.360378773334396, 2.4946131246429637],
+    [52.05832909175219, -41.310505088694995, 1.5555556441282252],
+    [43.187570597544926, -34.05427394140774, 0.8309044359995004],
+    [36.247571662009314, -28.354921774486435, 0.2243029641125015],
+    [30.512638598789184, -23.63202229461259, -0.3075307512702005],
+    [25.59780186707372, -19.58232905300387, -0.7846200428480828],
+    [21.280334365007104, -16.03468498521353, -1.2160256690645044],
+    [17.407665584636828, -12.87120656353446, -1.6091309746034665],
+    [13.87043153330463, -10.004905058140972, -1.9708389648338285],
+    [10.594616042544711, -7.374336305944659, -2.3066581932382153],
+    [7.538863112642837, -4.941483495136726, -2.6200945977297354],
+    [4.691627941886681, -2.6894376068835446, -2.9124735528135552],
+    [2.051389220671903, -0.6096414318202716, -3.1842807454976696],
+    [-0.39145743024821417, 1.30986993043813, -3.4365561458942486],
+    [-2.652263688239509, 3.083479823740176, -3.670758277340863],
+    [-4.747274803808645, 4.725273071694194, -3.8884029509435125],
+    [63.32008963191224, -50.73087197058805, 2.570094814661061],
+    [51.59169267320766, -41.17325198840023, 1.6743826651582994],
+    [43.09053901151016, -34.22424021667301, 0.9835469698748323],
+    [36.41584153205928, -28.751774327835104, 0.4070503796595985],
+    [30.891169090775893, -24.21227818956009, -0.09710266776350333],
+    [26.147576714391125, -20.31230036489294, -0.550113432640269],
+    [21.969570320045552, -16.884358554121114, -0.9620734693981434],
+    [18.215823368416622, -13.819687393904019, -1.339352478314948],
+    [14.786218834655795, -11.039403373048858, -1.6876013903055227],
+    [11.61191221675774, -8.486938545960724, -2.0115063068093204],
+    [8.651123573426684, -6.125108633298518, -2.314319477646666],
+    [5.888186444661641, -3.93512603324146, -2.597516645159735],
+    [3.3198978171090934, -1.9078053593961224, -2.8616232789621945],
+    [0.9389303437258119, -0.03290573212410399, -3.1074345632435096],
+    [-1.2681808099434067, 1.7025208810312173, -3.3361989733876993],
+    [-3.3163967364722375, 3.311544157314775, -3.549277251455053],
+    [62.26622534618565, -50.09705464381091, 2.631515680809382],
+    [51.091082137087554, -40.98539752422694, 1.7743057422643511],
+    [42.92585135779238, -34.313505933242574, 1.1126265281792551],
+    [36.49100487207655, -29.04402188633608, 0.5617336559679272],
+    [31.155690100849604, -24.667924544321004, 0.0811404219918485],
+    [26.569123114404974, -20.904417483893244, -0.35053371937061484],
+    [22.521866967899765, -17.588841443308507, -0.7444382809415817],
+    [18.879791024294622, -14.61762779307581, -1.106762088392272],
+    [15.550269145229182, -11.918270509544943, -1.4422797215028127],
+    [12.469331235067653, -9.438671576989265, -1.7549614154452546],
+    [9.596058929364366, -7.143194106530336, -2.047738578365473],
+    [6.912193913887273, -5.012107569391614, -2.3221235703416787],
+    [4.4124441966036905, -3.0353493804570673, -2.5787104159562597],
+    [2.090804643539452, -1.2037892093789806, -2.8181304339226663],
+    [-0.06450435020098866, 0.49423405921284935, -3.0414459024532894],
+    [-2.067274555177115, 2.070863641947427, -3.249877730343034],
+    [61.23792254669475, -49.465570470926295, 2.681744353841852],
+    [50.56839648628798, -40.76085756902923, 1.8592303775668562],
+    [42.710979120293906, -34.34140768927287, 1.2231435282383814],
+    [36.49503191319697, -29.255537774106084, 0.6943806932159252],
+    [31.33172165628272, -25.02641384718244, 0.2340700327206563],
+    [26.888680341195403, -21.38672475115197, -0.17887322668679587],
+    [22.963305330452528, -18.175734192723112, -0.5562506624243855],
+    [19.426164793275046, -15.29271087463601, -0.9045397548156241],
+    [16.19025917867692, -12.669697687615052, -1.2280334355678706],
+    [13.195919433719226, -10.258444062080628, -1.5301302769301548],
+    [10.40372500263335, -8.025150625830992, -1.8134311652089736],
+    [7.793929870215867, -5.949795823351506, -2.0794115483721347],
+    [5.3593597178976475, -4.02156434997566, -2.328718189995022],
+    [3.094516079903259, -2.2319185696401727, -2.5618853554272207],
+    [0.9890619352385395, -0.5703141505961271, -2.779814727299302],
+    [-0.969731676450378, 0.9745471649485973, -2.9836011380987695],
+    [60.237067193078495, -48.840809363717405, 2.7229357449650973],
+    [50.03193780588876, -40.50954693807322, 1.9320252768902768],
+    [42.45846924313536, -34.32191316347749, 1.3187460904815018],
+    [36.44386351225463, -29.40367000922554, 0.80938398643075],
+    [31.438087118818505, -25.307983304488964, 0.36674750103018783],
+    [27.126499749618183, -21.780817576073147, -0.029755429691256264],
+    [23.314232877794797, -18.666541150560764, -0.39217535704231143],
+    [19.875586153602743, -15.86643536886505, -0.7273966399056931],
+    [16.72756273857339, -13.315512445767336, -1.0395644015774046],
+    [13.814058710361927, -10.968613924990505, -1.3316737150095757],
+    [11.097372211112484, -8.793790737614207, -1.6060242058666738],
+    [8.55702277663383, -6.771149893589341, -1.8640133969057961],
+    [6.184367716257192, -4.889366630563785, -2.106312665434754],
+    [3.9738591737300952, -3.140143220350406, -2.3334044793356865],
+    [1.9163262488706612, -1.513864609316475, -2.546056803973844],
+    [3.159226161704605e-14, -1.8964688078179085e-14, -2.745251178470558],
+    [-15.488111762912407, 39.51144169003683, -20.482307686222523],
+    [-24.01415622169162, 42.429135082690564, -18.67955024132499],
+    [-28.767979776343207, 43.434998259797645, -17.3839422365151],
+    [-32.696743523906996, 44.41061149964274, -16.498703409593215],
+    [-35.949835142102785, 45.27186503700643, -15.845063683426522],
+    [-38.628269400488385, 45.980518677223344, -15.331391661131324],
+    [-40.83832299275463, 46.54446138701648, -14.909421666948333],
+    [-42.67073631214011, 46.98371640164491, -14.551421091042965],
+    [-44.19812832881008, 47.31912958981129, -14.240280607281766],
+    [-45.477616998017886, 47.56915349797166, -13.964833636506038],
+    [-46.55410998631427, 47.74926235639261, -13.7174544427565],
+    [-47.46312680114861, 47.87217565691263, -13.492737483281754],
+    [-48.232994293706575, 47.94828112310631, -13.286728988836796],
+    [-48.886503011009346, 47.98605804034815, -13.096458449918092],
+    [-49.442148150428814, 47.99244351469277, -12.919641547664915],
+    [-49.915061984615264, 47.97313276442283, -12.754485418618373],
+    [38.63551302712443, -21.912767082298195, -3.7115473758618736],
+    [18.433265913439374, -5.398655283646102, -5.718439662448039],
+    [7.094882277151695, 3.7094118989866107, -6.779122477538134],
+    [-0.5151883204756034, 9.668832597442615, -7.428877320569461],
+    [-6.341915016955505, 14.094983246467658, -7.882109763326986],
+    [-11.266571495413583, 17.72235547433595, -8.23839268946639],
+    [-15.496501421014438, 20.761123670763126, -8.52527189927361],
+    [-19.11941690262172, 23.31079568321938, -8.754872076131534],
+    [-22.23033368206906, 25.460837116370428, -8.938177026315607],
+    [-24.91401063494598, 27.285034829123997, -9.084253925433623],
+    [-27.24112322627613, 28.842276274212715, -9.20031338413013],
+    [-29.269421470296063, 30.17927379198217, -9.292041962703873],
+    [-31.045907474890495, 31.333207448117705, -9.363933113188255],
+    [-32.60893141323416, 32.33387434187595, -9.419558365759531],
+    [-33.98994650115033, 33.20534752861213, -9.461777119746017],
+    [-35.214910329219336, 33.967236347255316, -9.492896000401869],
+    [47.74108243735372, -32.59815595926222, -0.6583796482803927],
+    [29.124259793751477, -17.427084749711046, -2.4668760413274],
+    [17.86720191606551, -8.234135929953935, -3.6059574466883038],
+    [10.071545906562672, -1.9193289504339648, -4.3881526439128455],
+    [4.148019186353738, 2.814629967841627, -4.96727375658923],
+    [-0.6992253248234892, 6.620458724330646, -5.425767054338985],
+    [-4.90448817223111, 9.856761077018653, -5.810575853247549],
+    [-8.620402021876675, 12.667050491505307, -6.140589961990728],
+    [-11.89533400411011, 15.110348183388936, -6.423328282655397],
+    [-14.783147613449401, 17.24070465849333, -6.665725055379589],
+    [-17.336834097686157, 19.106216016693487, -6.874011324191617],
+    [-19.60333204508301, 20.74742541025976, -7.053473131759118],
+    [-21.62274843401738, 22.19793218490351, -7.2085101529775395],
+    [-23.42893362226871, 23.485493056472468, -7.342767538132799],
+    [-25.050376505099774, 24.63309196011822, -7.459269067414763],
+    [-26.511086849732916, 25.65984935267735, -7.560532489451058],
+    [50.188939854356114, -35.98771744557243, 0.5004564330719281],
+    [33.631896921586296, -22.669330900231728, -0.986247283071765],
+    [23.054429460859062, -14.056202676457794, -2.0339579758545265],
+    [15.528011736516206, -7.93169141219951, -2.7991567621194715],
+    [9.747589183450318, -3.2585519800013185, -3.3875321983893376],
+    [5.038399967937095, 0.5089789966740741, -3.861792374231107],
+    [1.0088820003601, 3.689329482831667, -4.260827921125702],
+    [-2.564115139058587, 6.46778255445367, -4.608063581964888],
+    [-5.764397180024897, 8.924960492028735, -4.913698768460001],
+    [-8.626710989947037, 11.101221104101581, -5.18272435448146],
+    [-11.188685157375376, 13.033777619498803, -5.419823053011814],
+    [-13.487491921920057, 14.756243210669673, -5.629294357316296],
+    [-15.556616722313484, 16.29755043310769, -5.814882528162158],
+    [-17.42520290374628, 17.682184432143757, -5.9797853586503305],
+    [-19.11828802632551, 18.93077599833807, -6.126717068670835],
+    [-20.65729780852684, 20.06072539739313, -6.2579806037120775],
+    [50.72189401985741, -37.259939926580245, 1.1013894803822615],
+    [35.769957888718444, -25.336215297925886, -0.16835718237400252],
+    [25.949780181397212, -17.392422614156498, -1.1020979229364976],
+    [18.79268938645935, -11.578617115961789, -1.818241346267054],
+    [13.228923191638422, -7.070036916968718, -2.3852218092805204],
+    [8.680412500181358, -3.4075367038215783, -2.8502155081261966],
+    [4.8048540976231084, -0.3157829168811255, -3.244335724308022],
+    [1.3890818044654096, 2.378384331136425, -3.5882380859302407],
+    [-1.684361435098374, 4.774856007580261, -3.894147491286881],
+    [-4.460544242699652, 6.9194294463351005, -4.167594762702752],
+    [-6.967051537962451, 8.84189458506653, -4.41215306249986],
+    [-9.233159573243942, 10.570061196486234, -4.631246154683284],
+    [-11.28698749337124, 12.12888189478586, -4.828007750532525],
+    [-13.15382799553211, 13.539980830648156, -5.005193914371061],
+    [-14.855840158232468, 14.821858560750574, -5.165190517248737],
+    [-16.412238291898873, 15.990291292219634, -5.310052037680939],
+    [50.54192765806435, -37.71493239072772, 1.4752491468531825],
+    [36.80805755046452, -26.808176392501505, 0.34199157241444234],
+    [27.66999133205817, -19.46149639762993, -0.49381101141849787],
+    [20.89522079712774, -13.978500693210284, -1.154998361594877],
+    [15.566376465686599, -9.663845926429678, -1.691619522170484],
+    [11.185708462402435, -6.129811241372518, -2.1386209932693534],
+    [7.45194236682241, -3.13712304387589, -2.5208161369166406],
+    [4.172158374514621, -0.5308120990526336, -2.8555499993610955],
+    [1.2255178370159714, 1.7883631552839816, -3.1544172752042465],
+    [-1.4496467782483462, 3.875447634309734, -3.423911641371362],
+    [-3.8808687679691536, 5.7593552888764235, -3.667374819041591],
+    [-6.091569391836363, 7.463496568492797, -3.8875593595773315],
+    [-8.105493047423314, 9.009524219721992, -4.087086513290289],
+    [-9.944792094540887, 10.416672189881732, -4.2683343787775545],
+    [-11.629270560201636, 11.70164393370776, -4.433401867177632],
+    [-13.176318168066969, 12.878826094162266, -4.584120739700519],
+    [50.044609866333936, -37.782679683133594, 1.7323771356584814],
+    [37.29023757008616, -27.67062487485578, 0.6931309793962286],
+    [28.720139279934987, -20.80938063984389, -0.06928627868221018],
+    [22.304845857949825, -15.63768663752236, -0.6793761739824544],
+    [17.208618551415157, -11.519906894768889, -1.1839928672055648],
+    [12.993921487468866, -8.120106840207832, -1.6102099988322511],
+    [9.393742630688878, -5.228984143870784, -1.9778244782707595],
+    [6.234146731511643, -2.7082201287658325, -2.3013055932438875],
+    [3.4007482524760757, -0.46532891296951406, -2.5909152193600566],
+    [0.8250744632906446, 1.557576036074899, -2.8532526824592437],
+    [-1.5263911899545677, 3.3923897699709116, -3.0919230704968967],
+    [-3.674368192980165, 5.060227978822452, -3.3093027717987002],
+    [-5.639100486769017, 6.580067764706426, -3.5075984825558972],
+    [-7.44013718260907, 7.969137825670164, -3.6888713175291366],
+    [-9.095326564682638, 9.242625685850292, -3.854976742078036],
+    [-10.620538388730589, 10.413738468099345, -4.007555306906748],
+    [49.3942214560364, -37.64438291778522, 1.920365553570908],
+    [37.46263294714523, -28.187539509208104, 0.9516350783155147],
+    [29.367560656074005, -21.722649432191126, 0.2439677596048826],
+    [23.271843342522583, -16.824544235113738, -0.3231245829342387],
+    [18.39583484567061, -12.894239797729162, -0.7973341689969019],
+    [14.340676784774965, -9.626467857257321, -1.2024938096118445],
+    [10.866641585330484, -6.835300235928878, -1.5548059344872627],
+    [7.816292917479657, -4.3967240320827035, -1.86638153481638],
+    [5.083496802072358, -2.225831427254066, -2.146147989799885],
+    [2.599667096069718, -0.26609674888455204, -2.400295745392345],
+    [0.3262446791892166, 1.51672755474179, -2.6326041096067017],
+    [-1.7579562035726728, 3.1435224375723956, -2.845349503426884],
+    [-3.6707006390654073, 4.631297073170138, -3.0404304268960174],
+    [-5.4293729309642655, 5.995596135541029, -3.219643749898414],
+    [-7.050171843313816, 7.250315771544867, -3.3846386224557605],
+    [-8.54768120174953, 8.407653417300327, -3.5368921923994985],
+    [48.66943442840158, -37.38893240009047, 2.0634216696519516],
+    [37.449279687438924, -28.492074582680292, 1.1511501234181265],
+    [29.761254609156687, -22.360181787689584, 0.48583266758902743],
+    [23.944268829613744, -17.69686956558535, -0.0464801714517144],
+    [19.270327889622784, -13.937921449506554, -0.49355104732717625],
+    [15.365649947568054, -10.795769820878347, -0.8786308858785967],
+    [12.010392403426737, -8.100739409016253, -1.2158668378092752],
+    [9.060835672971631, -5.740505371996156, -1.5155740169511056],
+    [6.418822064087785, -3.6372068988901174, -1.785515132723742],
+    [4.018319558053328, -1.7372272550710641, -2.031318510731751],
+    [1.8185345893507299, -0.0057258381685276515, -2.2567190700609516],
+    [-0.20356158245583553, 1.5788312725712312, -2.464010351248742],
+    [-2.0644527815055853, 3.0322861528848564, -2.6548962728262016],
+    [-3.779770904109561, 4.368797833153486, -2.830957534572202],
+    [-5.364311526320134, 5.601154734835181, -2.9936679961878068],
+    [-6.831556106330489, 6.740681328562944, -3.144364611799408],
+    [47.911895819313266, -37.064973728468736, 2.1754227113132263],
+    [37.318803027843884, -28.658212016834277, 1.3105033148538132],
+    [29.987329460510466, -22.81359769596757, 0.6792380346166911],
+    [24.41466402544499, -18.35279720084566, 0.175102996290814],
+    [19.922752855627976, -14.746793125520227, -0.24864207725585316],
+    [16.15783868863017, -11.721401344296627, -0.6153084262733726],
+    [12.913893265785283, -9.1174027107611, -0.9382052493903461],
+    [10.057979693343912, -6.831376707086584, -1.226446210890865],
+    [7.4990083002367935, -4.791549064290226, -1.4868553477768234],
+    [5.174306169496238, -2.947546744326713, -1.7245055089472423],
+    [3.04284931487815, -1.2651383525155133, -1.94295201456283],
+    [1.0799660458368265, 0.2777537752203355, -2.1444954968261354],
+    [-0.7305656654501393, 1.6964756542901727, -2.330737891693168],
+    [-2.4030575378291466, 3.004104270451027, -2.5030904846516235],
+    [-3.9511137031421217, 4.212478300725168, -2.662878459698349],
+    [-5.3872620711065915, 5.332161665858783, -2.8113179481575554],
+    [47.145138754683046, -36.70121195027428, 2.2650238283790887],
+    [37.111937748086504, -28.73018812170861, 1.4410854259037158],
+    [30.098253713301744, -23.13872030296084, 0.8381581751220517],
+    [24.742415586826112, -18.8550976196098, 0.35725114899881444],
+    [20.413703871567712, -15.38466064081839, -0.04672651968352628],
+    [16.777103939793076, -12.466012577865238, -0.3969640451618289],
+    [13.636879628006552, -9.947150389772204, -0.7065514360711583],
+    [10.868192426977489, -7.731016012037811, -0.9839344271391838],
+    [8.385873492874364, -5.75079074715626, -1.2352473338970358],
+    [6.130640879774332, -3.9591755520499117, -1.4650799164212387],
+    [4.06228518556784, -2.323120045125483, -1.6767586612894696],
+    [2.1552190320139433, -0.8204294374061382, -1.8725447396552148],
+    [0.39293277793185943, 0.564112418866241, -2.0539895920928197],
+    [-1.2380042519600838, 1.8428014651597107, -2.222379660533734],
+    [-2.7501995506672574, 3.0266703714137155, -2.3789152142609997],
+    [-4.155352686020963, 4.125617707929011, -2.524708019520337],
+    [46.382954810853775, -36.31544872412751, 2.3379515784528757],
+    [36.85426442009365, -28.736065330929105, 1.5502379542538007],
+    [30.12761085581419, -23.371337924986094, 0.9715780129505764],
+    [24.966643210765618, -19.24505196126261, 0.5102176793296829],
+    [20.78483274402594, -15.89524194170736, 0.12302387892163742],
+    [17.265337113673162, -13.073324517520033, -0.21278918039051725],
+    [14.221231462383882, -10.633300147842922, -0.5102487134424221],
+    [11.533797128093326, -8.48263131360597, -0.7775017270098207],
+    [9.1226182066648, -6.558330441711931, -1.0202324491489272],
+    [6.931500468351294, -4.81578395665692, -1.242647796510938],
+    [4.921493630285063, -3.223303980534542, -1.4478480461501881],
+    [3.066744155238854, -1.7589426850252607, -1.6380196987237352],
+    [1.350333403241117, -0.40751678099626903, -1.8146808652099353],
+    [-0.24067023093121878, 0.8427483474009421, -1.9790310354489957],
+    [-1.7180644574084962, 2.0022150072416696, -2.132167416732536],
+    [-3.0928297969360488, 3.0801996010130273, -2.275111728484831],
+    [45.6335388063863, -35.91909099357902, 2.3981626250278834],
+    [36.562586662950295, -28.694515002812125, 1.6429412240096135],
+    [30.097800871343647, -23.535396346767104, 1.0855411719567956],
+    [25.113916533618855, -19.55068690162416, 0.6409615475996775],
+    [21.065323799147066, -16.30910116720169, 0.2681546994038447],
+    [17.652660524425666, -13.574692874580435, -0.05506492940699174],
+    [14.6972895825487, -11.207183355757863, -0.34161609564229795],
+    [12.085454350731503, -9.117524327646384, -0.5995303590171253],
+    [9.740493717207809, -7.245615348678872, -0.8342359996194175],
+    [7.608866774286015, -5.549063105515518, -1.049663938974788],
+    [5.652996110935609, -3.9975107378142414, -1.248718387960492],
+    [3.847186831110661, -2.5694718377114225, -1.4334969380452447],
+    [2.1742154060527845, -1.2498365703303702, -1.6054849902345159],
+    [0.6213797796274098, -0.02716537096913361, -1.7658228457424574],
+    [-0.8224858618554766, 1.1083611735562764, -1.9155247541384557],
+    [-2.1677414641226287, 2.165552034260293, -2.055535138749122],
+    [44.90172111428819, -35.519603056426156, 2.448483710457626],
+    [36.248338280834695, -28.618439682678765, 1.7227154396104425],
+    [30.024295058794966, -23.64751006487675, 1.1842722769136582],
+    [25.202824965246496, -19.791623704712688, 0.7543609579985489],
+    [21.276015501984972, -16.648034378073714, 0.3940373039029143],
+    [17.961138154772648, -13.99303577414193, 0.08183156867833448],
+    [15.087582017393473, -11.69202215891197, -0.19497968361118578],
+    [12.54599575075411, -9.65899096032627, -0.44436426373584115],
+    [10.262757414478658, -7.836069131813875, -0.6716218941030705],
+    [8.186537423579548, -6.182637776283869, -0.8805041097930806],
+    [6.281073057837207, -4.669533108244799, -1.0737615739279212],
+    [4.521058883578938, -3.2758188660473615, -1.2534054660453715],
+    [2.8891294520278614, -1.9865320220123455, -1.4208859198743382],
+    [1.3726624844095328, -0.7904574476824902, -1.5773019841183402],
+    [-0.03903628851838841, 0.32179595104348435, -1.723602887329258],
+    [-1.355796773733529, 1.3585981449215525, -1.8606673146858825],
+    [44.19024638479957, -35.121927923109716, 2.4909895292947035],
+    [35.91951275153725, -28.51702601706738, 1.792132072083865],
+    [29.918101584818444, -23.71957015570669, 1.2708234059175756],
+    [25.246725129451846, -19.98198228104834, 0.8539360168234436],
+    [21.432087495726933, -16.927911287716025, 0.5045805248338915],
+    [18.207172045072596, -14.345363785457923, 0.20206391603701823],
+    [15.409139793708349, -12.10534273184362, -0.06606995330181539],
+    [12.932797052607414, -10.124744129880042, -0.30771342212377234],
+    [10.707126253806184, -8.347540299852394, -0.5281016670373904],
+    [8.682638026589366, -6.734524939020083, -0.7308811690417566],
+    [6.824250669793493, -5.2575508734829475, -0.9186934606434206],
+    [5.107147110201257, -3.896237722639327, -1.0934761268475797],
+    [3.5139603180321615, -2.635825750984893, -1.2566442184972553],
+    [2.0320879583773284, -1.465269289149251, -1.4092665132539959],
+    [0.6511739713669475, -0.37550912101484385, -1.552242503805944],
+    [-0.6381651137349365, 0.6414487598153202, -1.6863957031073145],
+    [43.50054137704628, -34.7293527608234, 2.5272373939475754],
+    [35.581814560781105, -28.396971844625806, 1.8531204182837004],
+    [29.787252780886856, -23.76031494006117, 1.3474625265578413],
+    [25.255442080877977, -20.132174027110775, 0.9422928696823273],
+    [21.544816722833755, -17.160523916573794, 0.6026904527650253],
+    [18.40312896013254, -14.644490101012833, 0.30876391825693916],
+    [15.675016514569329, -12.460559120845625, 0.048373068871478526],
+    [13.259307429978831, -10.52847957421049, -0.1862685542690711],
+    [11.087356949540718, -8.793885623586254, -0.4003514352628994],
+    [9.11124958764486, -7.218732173568067, -0.5974647048292419],
+    [7.296936511887286, -5.775718749901701, -0.7801796620509132],
+    [5.620109004100735, -4.444980426886511, -0.9503778314341306],
+    [4.0634934823847315, -3.211988059469298, -1.1094431776657372],
+    [2.614498842855449, -2.065841517404188, -1.25842142188731],
+    [1.2630054601270226, -0.9977373948895946, -1.3981748211962073],
+    [-5.28044058865703e-14, 3.479518260661504e-14, -1.5294813607276274],
+    [-5.238249736475936, 23.681317182307623, -13.7279639512787],
+    [-14.704358112958596, 28.367746124245862, -12.855420259673359],
+    [-19.163213584271695, 29.933668681081922, -12.076276983133203],
+    [-22.35488045353565, 30.963809626584805, -11.507859633514943],
+    [-24.924233956348885, 31.801467024309183, -11.084922102061888],
+    [-27.039784249408626, 32.492859518407776, -10.754210976058456],
+    [-28.803721973921533, 33.06444817423173, -10.485056036393257],
+    [-30.290520989950377, 33.538601156210035, -10.259280555742563],
+    [-31.556292280136038, 33.93398833643201, -10.065507222333979],
+    [-32.643941343770294, 34.26581564558641, -9.896253194130795],
+    [-33.58669907755507, 34.546339861720696, -9.746386734964089],
+    [-34.410624214323356, 34.785424473515675, -9.612262154776175],
+    [-35.13639409037914, 34.99102976698717, -9.491210660240988],
+    [-35.78059805085392, 35.169612321849996, -9.381227605462845],
+    [-36.356682131810054, 35.32644038604915, -9.28077319475905],
+    [-36.87564750682067, 35.46583984731884, -9.18864138389906],
+    [34.55034312141109, -20.322449728492952, -1.9319586064393264],
+    [17.068783941707533, -6.504490756167182, -3.5982497245526126],
+    [7.353923186832373, 1.105355272831482, -4.498197171274595],
+    [0.9514665220442649, 6.038525516356865, -5.0547162494976545],
+    [-3.762946828272435, 9.59821503821653, -5.435221263335013],
+    [-7.54983702444613, 12.395182655903993, -5.72053082298681],
+    [-10.7463988253262, 14.70811850855152, -5.947604831455363],
+    [-13.485489578735265, 16.655844800785033, -6.131658697070636],
+    [-15.852320264257754, 18.31395739698602, -6.282136200850882],
+    [-17.91326824001245, 19.73904842265071, -6.406041031397468],
+    [-19.721130485826617, 20.974702742713575, -6.508728169596529],
+    [-21.318143009595964, 22.054928297332733, -6.594344957086329],
+    [-22.73830606878443, 23.006560666128273, -6.6661380396115755],
+    [-24.009192808832694, 23.85100509751123, -6.72667512660525],
+    [-25.15334065628951, 24.605511672840485, -6.7780069701191],
+    [-26.189316882425775, 25.284117077431812, -6.8217867028848485],
+    [40.5648843316038, -27.393781445129978, 0.1156610978223398],
+    [24.822224457882704, -15.050044256154274, -1.3153484446085817],
+    [15.358630640549856, -7.563509810755041, -2.23309543279378],
+    [8.861529562929478, -2.4335506726400546, -2.8682925477549426],
+    [4.001303597667421, 1.3785982483899253, -3.3379652243590257],
+    [0.12800136916079574, 4.38720556058891, -3.704633043993583],
+    [-3.119553507793971, 6.879735888917726, -4.004761486157789],
+    [-5.938144378783819, 9.016202669756028, -4.259099070598251],
+    [-8.418975368115765, 10.876200952975045, -4.477958279725066],
+    [-10.617003022960844, 12.509153667725169, -4.66771308267427],
+    [-12.575646093386673, 13.953014552838857, -4.833276535900264],
+    [-14.33071987967726, 15.238191750849667, -4.978603450479347],
+    [-15.91190607298822, 16.38932196858242, -5.106910186183368],
+    [-17.343871251624364, 17.426521735433028, -5.2208302354916505],
+    [-18.647198048803197, 18.366341412847774, -5.322534225420503],
+    [-19.83914865018355, 19.22250032438172, -5.413823393358575],
+    [42.061270763601826, -29.63054855273841, 0.924031622103305],
+    [27.981668198149737, -18.69627467339507, -0.2838233619521839],
+    [19.163788341299185, -11.753064146281458, -1.1144366358163664],
+    [12.93069295549517, -6.823475763331131, -1.7249251621832304],
+    [8.191014845718671, -3.078732028181808, -2.1950644925845486],
+    [4.38951722419422, -0.08788342394783068, -2.5721591580532355],
+    [1.209638232508504, 2.3975494151767847, -2.885425062183629],
+    [-1.5417598323075892, 4.530500085744842, -3.153621523044522],
+    [-3.9772229948098055, 6.402436876234384, -3.388226202803317],
+    [-6.155705780265687, 8.064325148203604, -3.5956588463520904],
+    [-8.115410961619931, 9.550047912017236, -3.780201996749296],
+    [-9.887031510595085, 10.88624938107662, -3.94525740367797],
+    [-11.49629526365194, 12.09472637229018, -4.093636035832675],
+    [-12.964890450233609, 13.193500097014477, -4.227682093842297],
+    [-14.311145510176535, 14.197570638527191, -4.349360969079696],
+    [-15.550600940985953, 15.119503239152065, -4.460329051508588],
+    [42.314334167353685, -30.506801438598593, 1.37205916540896],
+    [29.463902102770795, -20.565885808665286, 0.2979196074371464],
+    [21.26708384553224, -14.14645635112381, -0.44895939397914003],
+    [15.358527411500281, -9.485875972108987, -1.0169030562493317],
+    [10.80332410856747, -5.88497370797882, -1.4667559819625589],
+    [7.121380706387302, -2.9776647527955866, -1.8348002326349737],
+    [4.033554268444463, -0.547824286642788, -2.144552857985809],
+    [1.3653009829596325, 1.5410699940481665, -2.411762176462994],
+    [-0.9953237250506227, 3.3776889387900035, -2.64704695095751],
+    [-3.1153955411948906, 5.016965879397342, -2.857108798783387],
+    [-5.033824185536398, 6.492474330681742, -3.046063143625048],
+    [-6.778391128330641, 7.82845191865602, -3.2169084121246323],
+    [-8.371959871913797, 9.044471336008504, -3.372099468643122],
+    [-9.83390150820649, 10.156788268068174, -3.5137015889904784],
+    [-11.180715511881838, 11.179027540531166, -3.643465229280628],
+    [-12.426493344126635, 12.122677980579752, -3.7628804859639113],
+    [42.09202028973744, -30.83487813875461, 1.664522133896323],
+    [30.202898819555624, -21.643862724031443, 0.6775023959362574],
+    [22.521820111574357, -15.647969575038331, -0.007319274232417729],
+    [16.924006644479253, -11.24611455955255, -0.5343480844878814],
+    [12.563217741481395, -7.803770237120712, -0.9594848344880956],
+    [9.013067750665495, -4.999069106399771, -1.3125872738532793],
+    [6.024408792750867, -2.641331322811726, -1.6130033144134277],
+    [3.43949012779981, -0.6084177005243387, -1.8740162093082144],
+    [1.1540110251500935, 1.18128402409889, -2.1049358913603555],
+    [-0.9003319265247943, 2.7822113158049726, -2.3121623086624274],
+    [-2.765460228579229, 4.229108647228827, -2.4998344666619796],
+    [-4.468487046914538, 5.54528551567677, -2.670749322346328],
+    [-6.030401732784009, 6.748752844056133, -2.827098196222281],
+    [-7.468830056037968, 7.854379081444736, -2.970718890061055],
+    [-8.798830241127101, 8.874662194471165, -3.10317837439352],
+    [-10.033325429010874, 9.820187772238123, -3.2258203867318307],
+    [41.656324625904, -30.895093125816985, 1.8733281941147535],
+    [30.56335089336042, -22.312326376219087, 0.9501291663823096],
+    [23.3085812488218, -16.656820006100713, 0.3103159024687926],
+    [17.984512027135867, -12.479542897313083, -0.18298155142979783],
+    [13.80838786208164, -9.18867976969424, -0.5844857651762343],
+    [10.388597608520847, -6.488571745309455, -0.9214947434388769],
+    [7.4985734724543684, -4.2069661944774905, -1.2107200119950483],
+    [4.994406329304455, -2.233318827799805, -1.4636010905907972],
+    [2.779717148571093, -0.49284843072133727, -1.688294769735512],
+    [0.7888703941405523, 1.066039451157236, -1.890643566926247],
+    [-1.0213909489980986, 2.478219017531933, -2.0746843698843374],
+    [-2.6788723881221883, 3.7669767408248496, -2.2431396294139074],
+    [-4.203597789265303, 4.949381426008391, -2.3980282140999516],
+    [-5.611917975118542, 6.039244259773029, -2.541011319288279],
+    [-6.917755503644503, 7.048151544145139, -2.6735058372908793],
+    [-8.133085731346737, 7.985938613559255, -2.796732353967758],
+    [41.120261847656046, -30.81099331582545, 2.031176872763554],
+    [30.709914973156163, -22.74372142513227, 1.1587550771841533],
+    [23.817385769450176, -17.37104690710052, 0.5530323981778809],
+    [18.729704977054205, -13.383874269655042, 0.08634474497977651],
+    [14.720840952068533, -10.229119136158339, -0.29462056617194043],
+    [11.423889265597277, -7.62822351976398, -0.6163496611580218],
+    [8.628292267140106, -5.4211829895162245, -0.8942302335744818],
+    [6.200985808451169, -3.506234681798558, -1.138466463260572],
+    [4.052489184331942, -1.8143575783522192, -1.3563194864833565],
+    [2.120772058955525, -0.29719643898734743, -1.5530832305663935],
+    [0.3631030452997659, 1.0791469526024813, -1.7325730948707359],
+    [-1.2490263085413074, 2.3379780951641447, -1.8974520073272352],
+    [-2.7353796405094966, 3.495906177956699, -2.0496379922322365],
+    [-4.11143978776089, 4.5659734776628085, -2.1906610164118727],
+    [-5.390238495593668, 5.559032751833735, -2.321817114255366],
+    [-6.582976305576306, 6.484295397122057, -2.4442246188132555],
+    [40.53986883522299, -30.645757474380048, 2.1555071229941194],
+    [30.72701790950296, -23.02679235169045, 1.32561707585282],
+    [24.150343146950316, -17.896757459889862, 0.7470282608872324],
+    [19.26846711335568, -14.071974254242072, 0.3014704663558249],
+    [15.408319727769012, -11.036596679255558, -0.062357641476077766],
+    [12.22404083433611, -8.526348794433137, -0.3704453630570122],
+    [9.516818303718175, -6.389585488665796, -0.6376262618631202],
+    [7.1617594423414594, -4.530833923819571, -0.8733936107994477],
+    [5.075024993997837, -2.8856266766420395, -1.084378031974663],
+    [3.1980569833443853, -1.4085532963311267, -1.275421536053459],
+    [1.4895310148896395, -0.06714593582417318, -1.4500892467878137],
+    [-0.07917895323193808, 1.1615958185947046, -1.6109555680583092],
+    [-1.5279040565362696, 2.294059180389755, -1.7598762500816976],
+    [-2.8716038395764767, 3.3427620121810366, -1.8982885781320824],
+    [-4.122624651104711, 4.317974650004708, -2.027392913406277],
+    [-5.291531285893388, 5.228388682058923, -2.1482231694609797],
+    [39.945257821125125, -30.43478239330593, 2.2566128123463267],
+    [30.66234410995165, -23.212013290553504, 1.4634991967555369],
+    [24.36643563336497, -18.29521738515422, 0.9074268845503851],
+    [19.66646259644867, -14.611668128773646, 0.4790453428312271],
+    [15.938456866172837, -11.680783356420129, 0.12939328936123629],
+    [12.856152593715091, -9.251827945598235, -0.16690270071665844],
+    [10.230432724125201, -7.179795228805431, -0.42439539275632443],
+    [7.942636930870132, -5.373734727883629, -0.6522202713091974],
+    [5.913383492545924, -3.7726241873985056, -0.8566113568975072],
+    [4.087129146147668, -2.3335055251330066, -1.0420759379951012],
+    [2.424183142088716, -1.025373123578948, -1.2119551443877425],
+    [0.8963077470509035, 0.17420607709476138, -1.3687165857386654],
+    [-0.5163942760842359, 1.2814273486987071, -1.5141656261506455],
+    [-1.82860943576899, 2.3084653788334504, -1.6496762565385548],
+    [-3.052174861458041, 3.265138261734513, -1.7763749353869867],
+    [-4.1971354220928685, 4.159705712067973, -1.8952264466436033],
+    [39.35336979958388, -30.199123623146253, 2.3410129149195735],
+    [30.54484603101036, -23.330334540721356, 1.5803569487969686],
+    [24.50195386703969, -18.60418106499739, 1.043573357736171],
+    [19.965094261058475, -15.045644247236314, 0.6295245587326701],
+    [16.35537217720618, -12.206971712052468, 0.2916898112733896],
+    [13.365061713322639, -9.850517973884463, 0.0054556770167196456],
+    [10.813868912995035, -7.837322031457429, -0.24347585814914494],
+    [8.5882398634539, -6.079980461629513, -0.46405405529297833],
+    [6.6123196043077614, -4.520064268223939, -0.6622878290939679],
+    [4.833065516306974, -3.1165643502230025, -0.8424595866220654],
+    [3.2123480092290686, -1.8397836703952897, -1.007734418971894],
+    [1.7225802690126795, -0.6679603164346339, -1.160476064348683],
+    [0.34393832094731713, 0.4148550932189349, -1.3024412623419812],
+    [-0.9381281214195114, 1.4206021320607196, -1.4349601643802288],
+    [-2.1350940935430884, 2.3587564556503238, -1.5591038653791685],
+    [-3.2565837233018224, 3.237221891932169, -1.6757813260796952],
+    [38.77394403978033, -29.95182959381344, 2.4130565488589255],
+    [30.393071099982755, -23.401835305424335, 1.6814467778733178],
+    [24.580431988188156, -18.848210169575907, 1.1615786357730105],
+    [20.19159161259043, -15.401965568157271, 0.7597794465988702],
+    [16.68876637029028, -12.645582660225331, 0.43193629637125447],
+    [13.781695871879734, -10.353953755667016, 0.15428313440986471],
+    [11.298441019701048, -8.393934892100884, -0.08717898939375872],
+    [9.129993432216512, -6.681236991364357, -0.30126404313377914],
+    [7.203411043440529, -5.159483108795335, -0.49385549320596794],
+    [5.4677055245237955, -3.7891936514757814, -0.6690972658545471],
+    [3.886127612533536, -2.5417703120522206, -0.830029336577058],
+    [2.431820607164117, -1.396111710614251, -0.9789283738112176],
+    [1.085169157709194, -0.3365637546732634, -1.117505192173762],
+    [-0.16828537144498948, 0.6486278197251464, -1.2470579298651172],
+    [-1.339769605508006, 1.5686835533263654, -1.3686175262495313],
+    [-2.4385728292134443, 2.4312177931449783, -1.4830488193940643],
+    [38.21257398999783, -29.701215100304665, 2.475764450983128],
+    [30.219388042773893, -23.440113858396384, 1.7704060998422213],
+    [24.617804022267396, -19.044011381834313, 1.2656294963286903],
+    [20.364553113280213, -15.699813245626666, 0.8745132530239634],
+    [16.959231409161625, -13.017680825887238, 0.5552518643170629],
+    [14.127925544958487, -10.784383994721251, 0.2849622324859328],
+    [11.706665860733002, -8.87242052648677, 0.04998949446220572],
+    [9.590704787399153, -7.200440990923303, -0.15834166041134254],
+    [7.709662513158821, -5.7138332287535505, -0.34583186989433756],
+    [6.014278858521064, -4.374351813079213, -0.5165429983538147],
+    [4.468991948091858, -3.154299040035987, -0.6734333627138979],
+    [3.047655663153308, -2.033167308689272, -0.8187145160162138],
+    [1.7309454108677813, -0.9956141769034357, -0.9540579686160146],
+    [0.5044998704452133, -0.030052348401221875, -1.0807375627702795],
+    [-0.6427378633811269, 0.8725480312390541, -1.1997560500568272],
+    [-1.719795880104544, 1.7195695610474735, -1.3119444295644938],
+    [37.67237808973803, -29.452673347685135, 2.531303309055291],
+    [30.032301739035113, -23.45469367474648, 1.8498459109475385],
+    [24.625244630134926, -19.20337064951051, 1.3587064360106225],
+    [20.497108739447686, -15.952751032975584, 0.9770613318207156],
+    [17.181444324067417, -13.338273697822485, 0.6652825240784996],
+    [14.419563776976897, -11.15786979338599, 0.40137044919138126],
+    [12.05507732047821, -9.289476285956662, 0.1720435609294635],
+    [9.987311931247445, -7.6545938690491955, -0.031216463822348432],
+    [8.148261533417037, -6.200241548715719, -0.21413672357893968],
+    [6.490186627048459, -4.889230210258046, -0.38072638171847295],
+    [4.978553599243066, -3.6946089003324643, -0.5338931703586406],
+    [3.5878688187818994, -2.596382484058393, -0.6758043886534453],
+    [2.2991309478537807, -1.5795083247703083, -0.8081014915004758],
+    [1.0980968534390163, -0.6325578468236587, -0.932040201617205],
+    [-0.026168903686964937, 0.25335277784011495, -1.0486042768344097],
+    [-1.0824945356100462, 1.085423592250219, -1.1585998121845595],
+    [37.15496045091219, -29.209734033047315, 2.5812688522685874],
+    [29.837800602745563, -23.452426063695203, 1.921697104163663],
+    [24.610819892807942, -19.33485603226418, 1.4429995182956323],
+    [20.59879637277974, -16.17065637984995, 1.0698625448292998],
+    [17.366134485172967, -13.61833522340404, 0.7646963874675977],
+    [14.668285484929717, -11.486257884882859, 0.5063645448914574],
+    [12.356036663108513, -9.65756560958447, 0.28197264135581784],
+    [10.332625411483894, -8.05653185894925, 0.08317655065712687],
+    [8.532308391453212, -6.631744237089197, -0.09567223059106555],
+    [6.908746285924566, -5.3469764006012035, -0.2585407264363457],
+    [5.428322256660836, -4.175921994395525, -0.4083044020582548],
+    [4.066133545172826, -3.0990265233532375, -0.5471025456677341],
+    [2.80350414196643, -2.101521241263306, -0.6765560970458545],
+    [1.6263272469723882, -1.17212797399463, -0.7979083130568],
+    [0.523762623366803, -0.30207882875051045, -0.9121310170743765],
+    [-0.5128670170536765, 0.5156843857886656, -1.0200130141356103],
+    [36.660985292540374, -28.97470799567891, 2.626862780056004],
+    [29.64018005471035, -23.438349441230883, 1.9874234321098856],
+    [24.58049266732732, -19.44485404862465, 1.5201612276842964],
+    [20.676717038983117, -16.360906757116453, 1.154747966807475],
+    [17.521326454142145, -13.866080032931297, 0.8554940572924896],
+    [14.88288167353558, -11.778464877214716, 0.6020939951059706],
+    [12.618940237841336, -9.986151541543247, 0.38204301350261544],
+    [10.636484195402742, -8.416099263153372, 0.18718287263766312],
+    [8.871950220130516, -7.01842518685554, 0.01195236848415918],
+    [7.2803293063566485, -5.75782028513982, -0.1475736646225206],
+    [5.828851941825759, -4.60856389477692, -0.2942466064727602],
+    [4.493157367625775, -3.5514899336713426, -0.4301881964773813],
+    [3.2548843031402166, -2.5720759250571543, -0.5570068189036933],
+    [2.100073158247028, -1.659183930857599, -0.6759383498106231],
+    [1.0179662540789611, -0.8041415937937926, -0.7879481322844405],
+    [-6.399017348275175e-14, 4.269551513915897e-14, -0.8938140093540586],
+    [2.868757067431089, 13.309196964951521, -9.799687121951951],
+    [-8.358098000047756, 19.872540533453606, -9.560259206876264],
+    [-13.29002769576646, 22.21070530922878, -9.129875110757279],
+    [-16.37296958856791, 23.49072582397801, -8.766169819007754],
+    [-18.723580121330535, 24.435298477894428, -8.486471258954639],
+    [-20.64416251931648, 25.206974656906215, -8.26872863665692],
+    [-22.261382858445785, 25.861675465494937, -8.094686173680877],
+    [-23.650587412454865, 26.430121592241164, -7.952404316965165],
+    [-24.86337203522541, 26.933075691027966, -7.8341057217034615],
+    [-25.937362252384037, 27.385798659499912, -7.734581013392122],
+    [-26.90099719427727, 27.79997382115594, -7.650231469550731],
+    [-27.776316726408144, 28.184776000135987, -7.578500556131005],
+    [-28.580744416455012, 28.547552408995116, -7.5175284055973055],
+    [-29.328292569246553, 28.89428877264189, -7.465935455267808],
+    [-30.03040712806267, 29.22994211969362, -7.422682960400201],
+    [-30.696576595964906, 29.55868520864837, -7.3869806587350455],
+    [34.252731077503746, -20.689449032172607, -0.8154530053119838],
+    [17.487769752267845, -7.836202647688353, -2.3421414827679023],
+    [8.316714901009643, -0.8090945019323144, -3.180770839863696],
+    [2.358117089151813, 3.7162229551062693, -3.705142426390997],
+    [-1.9354693663193947, 6.937984110293281, -4.06375697753631],
+    [-5.274159137556362, 9.409283625776458, -4.3278927387168515],
+    [-8.0296693841045, 11.420577485294041, -4.535658349895213],
+    [-10.384929192423668, 13.118263244987538, -4.7061647109905085],
+    [-12.435482145398705, 14.581022288662476, -4.849458370101018],
+    [-14.24409898107437, 15.860472597073798, -4.972018197522434],
+    [-15.85697724522449, 16.994049652166886, -5.0784898326180175],
+    [-17.30973432464284, 18.010145151905604, -5.172365383854355],
+    [-18.630530946888257, 18.93086775330228, -5.256343238424246],
+    [-19.842028080599718, 19.773770248425823, -5.332550992094386],
+    [-20.96272610358145, 20.553015244311656, -5.402694918701184],
+    [-22.00792475841519, 21.280196619914634, -5.468164782229582],
+    [38.37756918642449, -25.67320829105679, 0.677617167443518],
+    [23.538069245285094, -14.413577486699012, -0.5961278931923902],
+    [14.781240657157802, -7.675708111009049, -1.4110277835490677],
+    [8.820800469229368, -3.0728149175817423, -1.9792637464949954],
+    [4.406113760466338, 0.33297428768937637, -2.4008753648677192],
+    [0.9366862447814298, 3.0000330889495457, -2.729445389308045],
+    [-1.9185366169779046, 5.183290865579089, -2.996311876185708],
+    [-4.358307794197673, 7.0366992751973045, -3.221013455036847],
+    [-6.499218219626823, 8.65217407704268, -3.4154093427332337],
+    [-8.407514286808336, 10.083677300108805, -3.5864850832466386],
+    [-10.126754903018899, 11.367219937304622, -3.7389098441747657],
+    [-11.689461560430079, 12.529574173615622, -3.8761669728738055],
+    [-13.121307535668945, 13.591662524369148, -4.000989377389213],
+    [-14.443123135234002, 14.570279708325904, -4.115578975620176],
+    [-15.672105618341291, 15.479154830591579, -4.221742025740554],
+    [-16.82264505316085, 16.32967617998968, -4.320981850008066],
+    [39.29666878660957, -27.278257921247484, 1.3087242558345793],
+    [25.934315478444546, -17.189069238581528, 0.19697514567973734],
+    [17.816909620601628, -10.973960734834835, -0.536236971450026],
+    [12.137157377760488, -6.589645805346273, -1.0740244622573956],
+    [7.84855241702188, -3.2676237530851893, -1.4893772273227106],
+    [4.437426198914624, -0.6235545021801949, -1.8229116376260441],
+    [1.6147670892978427, 1.5617085019762975, -2.0995354199647625],
+    [-0.7971364998383421, 3.424038392710101, -2.3354472804847473],
+    [-2.9132924206187, 5.052020262295733, -2.541561378883003],
+    [-4.806423295986589, 6.502655785746881, -2.7250392351839405],
+    [-6.521527915870643, 7.81224217478387, -2.890483262121584],
+    [-8.089569827814763, 9.006148047108088, -3.0411355710298267],
+    [-9.53420593136128, 10.103745936019555, -3.1794945059125035],
+    [-10.874462453790935, 11.120514126978552, -3.3075742686679135],
+    [-12.1260500302888, 12.069133786293152, -3.427039104070211],
+    [-13.30216255185906, 12.960175012521672, -3.5392872482048867],
+    [39.344032305059336, -27.92398038064898, 1.6806583279942162],
+    [27.074257814864872, -18.659004936366134, 0.663114730855368],
+    [19.497030313737245, -12.874488253364401, -0.007928847023343592],
+    [14.119325406312315, -8.733628302187887, -0.5084336084462268],
+    [10.005164504215212, -5.548331474375175, -0.9038283386227086],
+    [6.701016933391587, -2.982818690585174, -1.2275032244660924],
+    [3.95012672217097, -0.8447413697157259, -1.4999356911372002],
+    [1.5930601869348109, 0.9865104281970875, -1.7346993872962395],
+    [-0.4754795914999099, 2.591180187835909, -1.941205923229402],
+    [-2.3268904386886353, 4.024164264657029, -2.1260887028618116],
+    [-4.008291908004641, 5.322381672749829, -2.2938915979377006],
+    [-5.550874597093784, 6.510876851256477, -2.447731569134476],
+    [-6.977273565083166, 7.6080447267481, -2.5899234076567432],
+    [-8.305278397332998, 8.628341586502106, -2.7223079969768667],
+    [-9.549464553500915, 9.583555010185046, -2.846403904218558],
+    [-10.72205446480481, 10.483510946611313, -2.96349094765585],
+    [39.06026871575767, -28.174080643448665, 1.935143369053246],
+    [27.66793349548488, -19.55356954035558, 0.9824133901294695],
+    [20.52514291297666, -14.103170412519207, 0.3538808341911658],
+    [15.413579034787412, -10.173344032251073, -0.11598868767972652],
+    [11.471852957267135, -7.12517974715578, -0.49072898833861045],
+    [8.283707435929493, -4.650067546341786, -0.8010955001811302],
+    [5.615367637306205, -2.573787595877431, -1.0650339715402997],
+    [3.321542679531497, -0.7872113362046907, -1.2943197780174418],
+    [1.305695801919409, 0.7826970177743123, -1.4971736353495069],
+    [-0.49908369972636224, 2.1869083890218124, -1.6795316706849437],
+    [-2.1394826427230984, 3.4613775550420836, -1.845687625609873],
+    [-3.647350058767415, 4.631123782442125, -1.9986778226256618],
+    [-5.045064365136211, 5.714073483506194, -2.1407045489670393],
+    [-6.349664990209526, 6.723983619263482, -2.2734798786648565],
+    [-7.574910829486503, 7.67195556786969, -2.398404024965384],
+    [-8.732277007125978, 8.567212192914186, -2.5166554876557212],
+    [38.63730759618942, -28.232843419231976, 2.1250480824037252],
+    [27.983047454662636, -20.14729432934347, 1.2223626300241728],
+    [21.201395278454417, -14.96692249092341, 0.6244064095204193],
+    [16.315062631344215, -11.211092169382331, 0.17763119202799646],
+    [12.52819263966257, -8.284655362250167, -0.17950855783676115],
+    [9.45081702784087, -5.896529892647018, -0.4769449196306825],
+    [6.864700482347344, -3.8837720868023196, -0.7315334870978083],
+    [4.6349332943010015, -2.1452766924977644, -0.9539922866664663],
+    [2.671923507001697, -0.6135359905836235, -1.151718998170888],
+    [0.9131787116927431, 0.7588081075433946, -1.3300733518638819],
+    [-0.6860164423257752, 2.0058897451221975, -1.4930251572297617],
+    [-2.1574480998613113, 3.152296391409467, -1.6434921921589207],
+    [-3.5235690286803574, 4.215730475400886, -1.7836052392103992],
+    [-4.801034938438226, 5.209508885460883, -1.9149883399601468],
+    [-6.003039199922282, 6.144225409548219, -2.03895165985162],
+    [-7.140488260318528, 7.028626410392469, -2.156592290349568],
+    [38.15962994375408, -28.193157692083442, 2.2754631015576408],
+    [28.14175233764973, -20.565807014656126, 1.4139891169756957],
+    [21.670330846793593, -15.612925087541607, 0.8395233255882527],
+    [16.976419838660245, -12.002105792323674, 0.4102399824537296],
+    [13.324566368436855, -9.179713919078253, 0.06714815768114996],
+    [10.34752915323822, -6.8696829834747, -0.21906873365080665],
+    [7.838513173542498, -4.9167538146043634, -0.46485602087251804],
+    [5.670050426867187, -3.225178139487991, -0.6804248714615458],
+    [3.7578008514759427, -1.7314343699701644, -0.872675757211394],
+    [2.042929604942512, -0.3910336932037628, -1.0465613449384097],
+    [0.4829458280802121, 0.8283212723808507, -1.205766941980075],
+    [-0.9531648270553637, 1.9504101947591803, -1.3530639602246883],
+    [-2.2878345819423007, 2.992691073305748, -1.4905198830812343],
+    [-3.5375605233219742, 3.9682112909893004, -1.619700416179162],
+    [-4.715165351912999, 4.887201753732942, -1.7418502215636513],
+    [-5.831127873261818, 5.758037621666688, -1.8580029822069948],
+    [37.668929404866965, -28.10301977858767, 2.4000892699461307],
+    [28.20796405501362, -20.87529702345316, 1.5738077873257426],
+    [22.009157086787976, -16.11971969504141, 1.018359672439375],
+    [17.4828301159881, -12.6326317068262, 0.6027301511382552],
+    [13.948675171956014, -9.899036292071825, 0.27071305243689],
+    [11.060635778911523, -7.657182542744746, -0.006241662627354262],
+    [8.621704081309373, -5.758241558961714, -0.24432386721489915],
+    [6.510087320142963, -4.110284423274754, -0.4535281061964167],
+    [4.645371741284225, -2.6525876573178198, -0.6404991667938333],
+    [2.9715838670659713, -1.3428008825547495, -0.8099373803725293],
+    [1.4482087041694363, -0.15016742354710394, -0.9653198586433973],
+    [0.04528316647910808, 0.9481892283587917, -1.109281830625295],
+    [-1.259359184078693, 1.9693730142495207, -1.2438256071633775],
+    [-2.4821325915916557, 2.926238081744961, -1.3704760230713464],
+    [-3.635632738560498, 3.8287640489397035, -1.4904322788916753],
+    [-4.730024235660938, 4.68504244440744, -1.6046802778146563],
+    [37.1871411573592, -27.989316854648052, 2.5071356626484524],
+    [28.218478150078546, -21.114261525154852, 1.7115663036280757],
+    [22.262840813908195, -16.533113013808354, 1.17209829607789],
+    [17.88499855657628, -13.153896165457667, 0.767477766612881],
+    [14.454490832948268, -10.496971434504815, 0.44427597290835835],
+    [11.64510998744863, -8.314279571870362, 0.17483078548073747],
+    [9.268944379234377, -6.463051354002622, -0.05676200253098454],
+    [7.209043211747857, -4.854524700617612, -0.2603726518980774],
+    [5.388071967409514, -3.430042452873023, -0.4425275122013944],
+    [3.7522721447053904, -2.1488153573669497, -0.6077910785164073],
+    [2.262746501816342, -0.9812821941277661, -0.7595079642102287],
+    [0.8905697331725863, 0.09461680066487639, -0.9002092054150664],
+    [-0.38598493323892835, 1.0955697524861687, -1.0318367366937102],
+    [-1.5832228318062078, 2.034250422435143, -1.1558838884752127],
+    [-2.7136047992542025, 2.9204654501816387, -1.2735198484850148],
+    [-3.7870729529107545, 3.7620957637989125, -1.385696223900193],
+    [36.726178104956524, -27.86795971796512, 2.6018609633298957],
+    [28.195973822674908, -21.306746432276732, 1.8334667136774918],
+    [22.459443552002988, -16.881781266835066, 1.3077747578383554],
+    [18.214907810152027, -13.59818937835893, 0.9122803601600113],
+    [14.876754194320492, -11.008409080183421, 0.5962122183790506],
+    [12.137223942161306, -8.877291656910423, 0.33284497290701864],
+    [9.817024184656258, -7.067922049085866, 0.10661274656347222],
+    [7.803675680336069, -5.4944796925628605, -0.09223175381566831],
+    [6.022477106726023, -4.100019693625405, -0.27014964169260525],
+    [4.4214491537075755, -2.844938894616657, -0.43163958761755683],
+    [2.9629931518556605, -1.7005813629797, -0.5799728857445945],
+    [1.6190921374612874, -0.6455559073392763, -0.717612566258211],
+    [0.368516250623617, 0.33641941463306213, -0.846453299532604],
+    [-0.8048749933214082, 1.2578322490606972, -0.9679637900849589],
+    [-1.9134535077592443, 2.1283648832427176, -1.0832945869258117],
+    [-2.9670111545232793, 2.955747606244007, -1.1933745223113121],
+    [36.292547014696986, -27.748741174031682, 2.6878117319933734],
+    [28.155162252126857, -21.468603454743683, 1.943686990038312],
+    [22.617464654673196, -17.184716311873515, 1.4300812723258296],
+    [18.493770133697183, -13.986938776815279, 1.0423185624974622],
+    [15.238768723432145, -11.45671598704072, 0.7321211133764177],
+    [12.561735734684548, -9.370938657783478, 0.4736994406616494],
+    [10.291521763170985, -7.598309806467502, 0.2518594583008852],
+    [8.31989891222721, -6.055852900301437, 0.05699868205261533],
+    [6.574584570886428, -4.688194865157268, -0.11728616993843281],
+    [5.00512988919112, -3.4567197532154768, -0.2754587424038481],
+    [3.5750055365043973, -2.33347791772153, -0.4207543419406483],
+    [2.2569589683528624, -1.2976061685205236, -0.5555981267687363],
+    [1.0302383260427053, -0.3331777257748446, -0.6818538718777989],
+    [-0.12109192480411012, 0.5721115027150121, -0.8009729920360142],
+    [-1.2093287486114546, 1.42784724267373, -0.9140957864536132],
+    [-2.2441673531612225, 2.2416565934304193, -1.0221379458674014],
+    [35.88972256125061, -27.637872191319428, 2.767482284670329],
+    [28.105978440228053, -21.610740422861035, 2.0451723006290834],
+    [22.749653303203885, -17.45508249161682, 1.542298317627204],
+    [18.73627229579992, -14.335001059949796, 1.1611933732518387],
+    [15.55676646421118, -11.858156450882852, 0.85589781039272],
+    [12.936079868337446, -9.812593547760143, 0.601529774717774],
+    [10.710709586123317, -8.072350287518892, 0.3832798109333465],
+    [8.776482786720296, -6.557206186202698, 0.19170933444837027],
+    [7.063416076928362, -5.213302869104937, 0.0204817606496599],
+    [5.5224627643296245, -4.002923956294866, -0.1348383543956823],
+    [4.118023082636667, -2.8987130523591196, -0.27746818840633497],
+    [2.8234948134566493, -1.8802316831662433, -0.4098149743120667],
+    [1.6185567710021158, -0.931848896627738, -0.5337261902028989],
+    [0.4874926807053965, -0.04142931284367896, -0.6506436706561771],
+    [-0.5819179461490065, 0.8005327749176173, -0.7617039027340334],
+    [-1.5993100155012225, 1.6015969310570897, -0.8678172292669533],
+    [35.51944303573065, -27.539392516977518, 2.842691066344603],
+    [28.05536196882485, -21.740937714406712, 2.140078382645353],
+    [22.86512792548311, -17.702358006743864, 1.6468109437526204],
+    [18.952966210671057, -14.653072236315053, 1.271506322697007],
+    [15.842445943597177, -12.224450807939672, 0.9703501210816496],
+    [13.272900233124409, -10.214838175905836, 0.7193265008499532],
+    [11.087970646921534, -8.503297570020308, 0.5040092027038725],
+    [9.187334936544634, -7.012257780677192, 0.3151343429687857],
+    [7.503214193201282, -5.689335369923796, 0.1464425959377328],
+    [5.987893416951364, -4.4976773968047885, -0.006469702995910402],
+    [4.6066260918565725, -3.410467097156897, -0.14680647602129196],
+    [3.3333860598433547, -2.407628686879395, -0.2769670835414785],
+    [2.148237099235372, -1.4737792755740349, -0.39879277999036394],
+    [1.0356796742515013, -0.5969324278415854, -0.5137225154890832],
+    [-0.01642426343684755, 0.2323487258759196, -0.6228939331189691],
+    [-1.0176710319411255, 1.0215813316519315, -0.7272180406576036],
+    [35.18244947840523, -27.455994458114542, 2.914807070337209],
+    [28.008308657719592, -21.864923850803287, 2.2300351675324706],
+    [22.97062404243703, -17.933594735950116, 1.7454122557078562],
+    [19.151682998951237, -14.949110049927341, 1.3752008992793034],
+    [16.104502509446355, -12.564313064739673, 1.077567606893879],
+    [13.58162424123021, -10.587044701559636, 0.8293152673413691],
+    [11.43334428016557, -8.901077081998508, 0.6163908001792138],
+    [9.562977886261493, -7.431364201069059, 0.4297085314255934],
+    [7.904855335988658, -6.126952080454737, 0.2630938075575417],
+    [6.412542265826506, -4.951830433326271, 0.11218068966721682],
+    [5.052103246521074, -3.879699513701405, -0.026219924834032782],
+    [3.798045887411236, -2.890815570787218, -0.15450244723917245],
+    [2.630786773649354, -1.9700139293609311, -0.27450703292505485],
+    [1.5350354769904015, -1.105440398432754, -0.38767426494407364],
+    [0.49874263092638715, -0.287719422650673, -0.495146431029537],
+    [-0.4876597576534028, 0.4906397909402807, -0.5978394940737266],
+    [34.87892158559119, -27.38952288477686, 2.9848920632529405],
+    [27.96852087809996, -21.987043035642348, 2.3163112931123497],
+    [23.071265025634705, -18.154196207720272, 1.8394907960362712],
+    [19.33840691265625, -15.22921163319352, 1.4737727620203285],
+    [16.34958440229243, -12.884409020469421, 1.1791507758386972],
+    [13.869467284133082, -10.936381200985656, 0.9331974960247004],
+    [11.754538693479546, -9.273298629111519, 0.7222182272498245],
+    [9.91153612175133, -7.822506622159774, 0.5373039006557676],
+    [8.276798085070448, -6.5344254853523065, 0.3723681947757192],
+    [6.805121941289354, -5.373866206967426, 0.2230877805566975],
+    [5.463354472089727, -4.315034614851889, 0.08629191977917508],
+    [4.226513624940574, -3.3385062486886987, -0.040407888820257795],
+    [3.0753503255691697, -2.4293202225588457, -0.15885197441834828],
+    [1.9947776552569254, -1.5757448744462146, -0.2704845823960074],
+    [0.9728413488520157, -0.7684624149363365, -0.376454533012006],
+    [6.802561590870302e-14, -8.055616406173519e-14, -0.4776853589533545],
+    [11.208568271023246, 4.6996628526527, -7.1210531769575525],
+    [-2.8703711074381952, 13.674124075785723, -7.432982881655068],
+    [-8.813595373241581, 16.999873672427565, -7.284956566276001],
+    [-12.254556244002385, 18.73936850075118, -7.088430775742191],
+    [-14.691675907211092, 19.91231323559826, -6.918429791468425],
+    [-16.635672355485884, 20.84250687715864, -6.7846780242778175],
+    [-18.277979935032445, 21.639232989135685, -6.681531245917404],
+    [-19.71294355913258, 22.35142932429522, -6.602707177367526],
+    [-20.997901732856693, 23.007224481467794, -6.54348130345967],
+    [-22.172104407639747, 23.625450947647174, -6.500535004326401],
+    [-23.264424976350835, 24.219961108585462, -6.471574000568195],
+    [-24.29719694534395, 24.801630065262703, -6.455028648277538],
+    [-25.288407524487393, 25.379449477075195, -6.449850244024116],
+    [-26.25308130962862, 25.961203321043254, -6.455377806207852],
+    [-27.20422110588761, 26.553928574600512, -6.471252576385267],
+    [-28.153490472076612, 27.164257880069933, -6.497364801953411],
+    [36.76409751047104, -22.600992654015567, 0.03732756272940659],
+    [19.32753489137614, -9.630728318457031, -1.4631661314540412],
+    [10.00437601956391, -2.635922491077402, -2.299309566130521],
+    [4.01908588318533, 1.8457269256402884, -2.828601153787574],
+    [-0.24678823181781392, 5.023807217450366, -3.194640537954325],
+    [-3.5133727895062705, 7.442695670347152, -3.465501087551896],
+    [-6.161086438086574, 9.39099827598261, -3.6782597198559106],
+    [-8.40864505269313, 11.03510169749745, -3.854578654292328],
+    [-10.377943779324683, 12.469083954785463, -4.006668988106023],
+    [-12.140907105995238, 13.749315894614421, -4.141758536376723],
+    [-13.745907308216418, 14.913850886606879, -4.264677807850152],
+    [-15.228547926717765, 15.990593591436282, -4.3789500916454696],
+    [-16.61654720433778, 17.00113451465329, -4.487299388455092],
+    [-17.93236735410694, 17.962864776597602, -4.5919286070421546],
+    [-19.194829995195835, 18.890290147317017, -4.694691837208017],
+    [-20.42020758230426, 19.795921039443485, -4.797211234983465],
+    [39.5410388754687, -26.226992571327724, 1.2012627435496799],
+    [24.241481183782582, -14.944718551921486, -0.05048268976956302],
+    [15.5184686174624, -8.402204885088658, -0.8286792503543581],
+    [9.639830270976873, -3.954899868493332, -1.3741725907967584],
+    [5.310190377759867, -0.6666885577741442, -1.781910289700303],
+    [1.9253624728984786, 1.9080967013733927, -2.1018644348373385],
+    [-0.8418739714866583, 4.0138031394964635, -2.3630903673257104],
+    [-3.1884992389395825, 5.798521428059876, -2.5838891482413904],
+    [-5.241803379885679, 7.358362241443421, -2.7764681999183343],
+    [-7.083533999733834, 8.755741839241276, -2.9488903629387733],
+    [-8.765689598498476, 10.031128535152453, -3.106429962666388],
+    [-10.324121439927982, 11.21279910770565, -3.25280046847718],
+    [-11.785801908852502, 12.322128487767925, -3.3908342402873677],
+    [-13.172282465355156, 13.376196957519257, -3.5228187377651543],
+    [-14.501545424686835, 14.389233802337271, -3.650683414420165],
+    [-15.78913897927388, 15.37352294577743, -3.7761180228557754],
+    [39.99499823907607, -27.40859170729457, 1.7354915632866978],
+    [26.156652231878812, -17.20303779894378, 0.6081068816493658],
+    [18.080336723547784, -11.165916982830224, -0.0967724195268907],
+    [12.514225740304092, -6.961137310610983, -0.607285377402722],
+    [8.334388735823136, -3.7810415197981375, -1.0025887599278864],
+    [5.019003007515456, -1.2467742918891935, -1.3218987981798584],
+    [2.281737064306359, 0.8522290396560427, -1.5884639341510707],
+    [-0.05139902294455915, 2.645171261723945, -1.8172754716799804],
+    [-2.0944670781161605, 4.217294339338606, -2.018603604840096],
+    [-3.926200928733023, 5.627730485602297, -2.199785184590853],
+    [-5.6008113486486515, 6.917623967177182, -2.3660344620602913],
+    [-7.155251882798722, 8.115520560989092, -2.521024199591901],
+    [-8.61620161897734, 9.242328955158671, -2.6674828774870036],
+    [-10.004401541259409, 10.314424924644351, -2.807580058141637],
+    [-11.336917867976986, 11.345322434542691, -2.943135425874908],
+    [-12.628416614602816, 12.346646227341653, -3.075741458688083],
+    [39.82804987794491, -27.881484139419623, 2.068876944772073],
+    [27.09607878179785, -18.45922737280144, 1.0166130992584932],
+    [19.521888718140843, -12.797007349784478, 0.3584074794566322],
+    [14.246288086188427, -8.816546558390092, -0.12021309123136494],
+    [10.242598660607815, -5.772549636657697, -0.49579634293800534],
+    [7.036403668052675, -3.3203853562152554, -0.8039196359655226],
+    [4.36923516029976, -1.271198632521787, -1.0646903277345319],
+    [2.0838570684812785, 0.49082049098006447, -1.290962550956261],
+    [0.07698504478211635, 2.042280289060937, -1.4915738775864635],
+    [-1.7233708172293492, 3.4368543417510895, -1.6729249125869516],
+    [-3.3692447653555315, 4.713543017549654, -1.8397973653084285],
+    [-4.898036382599843, 5.900689873359444, -1.995742398787816],
+    [-6.336641738459305, 7.019014472453505, -2.1434026632005128],
+    [-7.705445680620188, 8.084430724557194, -2.284844226588225],
+    [-9.02091351523524, 9.1098919434611, -2.4217805857301045],
+    [-10.29705110308027, 10.106461036271229, -2.5557040116903877],
+    [39.44816214171209, -28.069674976381272, 2.3089564606384285],
+    [27.616993847708002, -19.274366772450254, 1.3113460057732105],
+    [20.443943741568656, -13.900229635837498, 0.6836839695537844],
+    [15.407484938976182, -10.09821871624797, 0.22770326159043372],
+    [11.562983779375, -7.175781622072207, -0.1308974462145061],
+    [8.466638430112358, -4.807823537919525, -0.42690131563695133],
+    [5.877329802266704, -2.8176125057680217, -0.6793036595883877],
+    [3.649265393621004, -1.097919948786562, -0.8998503448840426],
+    [1.6869695371926088, 0.42182646506967136, -1.0964901422056235],
+    [-0.07603123779322793, 1.790996691352233, -1.2749503555101487],
+    [-1.6882818978944896, 3.0457921469425044, -1.4395501663475383],
+    [-3.1860607931429827, 4.213427036825092, -1.5936183252956033],
+    [-4.596304705028998, 5.314351571268657, -1.739709205692248],
+    [-5.939311275950315, 6.364208748248568, -1.8798149108922453],
+    [-7.231203164511847, 7.375568522686217, -2.015568583691547],
+    [-8.485530273881261, 8.359070220989326, -2.148381736237206],
+    [39.00267352097065, -28.132046656933422, 2.4979675680894617],
+    [27.929550630010354, -19.860353021297062, 1.543911403417148],
+    [21.09174191837647, -14.720793556547244, 0.938099757035444],
+    [16.252881982019908, -11.061210158817685, 0.49788298369086287],
+    [12.54323320783325, -8.238852231934802, 0.15198753426595296],
+    [9.545158830517382, -5.945178135791581, -0.13379033822472888],
+    [7.029702841635538, -4.011130396644157, -0.3781687893205682],
+    [4.8586416965347246, -2.3346056178567576, -0.5924952650748144],
+    [2.941898327628547, -0.8489307828615031, -0.7842737298583584],
+    [1.2170260790318663, 0.49225582198661444, -0.9588255301337638],
+    [-0.3615225135131605, 1.7228744146035853, -1.1201343380950373],
+    [-1.828241029040116, 2.8687032882791064, -1.2712996286162608],
+    [-3.209560207809435, 3.9496358049364733, -1.4147664104544266],
+    [-4.525712917063843, 4.981093837201764, -1.5524688234700998],
+    [-5.792646533990078, 5.975395559048072, -1.6859835094470166],
+    [-7.023611131869556, 6.942875241108522, -1.816661705958194],
+    [38.555447030661405, -28.141387183254515, 2.656532818508871],
+    [28.131675120836608, -20.31660452479152, 1.7387615865015047],
+    [21.582214707010476, -15.37538419311519, 1.1496908092714517],
+    [16.910684209521452, -11.83296891345047, 0.7207959519496473],
+    [13.314703134255316, -9.09232712046579, 0.3840728842148656],
+    [10.401016508801922, -6.860810481035357, 0.10613204933762554],
+    [7.95117532047733, -4.9759002789255335, -0.13156608096526015],
+    [5.832554697541935, -3.33896357622881, -0.34027063733944024],
+    [3.9588076194497064, -1.8857717382461758, -0.5273305927372709],
+    [2.2703135529702747, -0.5719282233075914, -0.6978724724507783],
+    [0.723764761506319, 0.6348701903940002, -0.8556816809855895],
+    [-0.713669421283566, 1.759161149999225, -1.003686950568618],
+    [-2.06756193958932, 2.8201301185784535, -1.1442240385356888],
+    [-3.35793428905006, 3.832927602639648, -1.2791740207257092],
+    [-4.600636420626648, 4.809697882147461, -1.4100748131381795],
+    [-5.808733697359544, 5.76056468959246, -1.5382354210766274],
+    [38.136950341357206, -28.135233558794322, 2.7961499069942244],
+    [28.27571455165214, -20.69684681300734, 1.9093106028400826],
+    [21.97852787438165, -15.927089765802803, 1.333641746989613],
+    [17.451676887540394, -12.483644537541073, 0.9131527277932378],
+    [13.95265655509694, -9.810616173831354, 0.5830324687990126],
+    [11.110821141553545, -7.630426546445412, 0.31084134944112296],
+    [8.717592500591278, -5.786860637252704, 0.07830049220572499],
+    [6.645229163638049, -4.184265496453198, -0.12578528297541125],
+    [4.8102436493354785, -2.760143096588788, -0.30873453080848023],
+    [3.155030342177778, -1.4713714154124964, -0.47561221076365134],
+    [1.6378842520959251, -0.28671892543708216, -0.6301136339965661],
+    [0.22727671131880883, 0.8174426037078527, -0.7750676572135857],
+    [-1.1014736956826958, 1.8596401861505656, -0.9127264219928436],
+    [-2.3680244476360923, 2.8547024433686605, -1.044923625964936],
+    [-3.588132086185569, 3.814632334062497, -1.1731706301622735],
+    [-4.774757720243943, 4.749415762825352, -1.2987494344824704],
+    [37.76254467913511, -28.134621605048146, 2.9238612936736605],
+    [28.39247101347391, -21.033134813375668, 2.063712039991373],
+    [22.318263982872182, -16.41354921418077, 1.4990506632373077],
+    [17.918301741600693, -13.055241159005686, 1.0849465038848598],
+    [14.503086607763544, -10.439028977801284, 0.7595828401998975],
+    [11.722667866504032, -8.301317939659096, 0.49149921561395754],
+    [9.377772926746319, -6.491973982122716, 0.2627431575305756],
+    [7.3452655618691685, -4.91821960544289, 0.06220719810033443],
+    [5.5441729390573204, -3.5190547346887002, -0.11741707176035394],
+    [3.9184720374603823, -2.252286967468211, -0.2811906502490267],
+    [2.4276295164486634, -1.0873979609284634, -0.43278571835786933],
+    [1.0410784806726074, -0.0013773483728613213, -0.5749887833116345],
+    [-0.26510825721520304, 1.0237910647847412, -0.7100036174181215],
+    [-1.5101623712221006, 2.002619104661702, -0.8396287755413702],
+    [-2.709714050477137, 2.946977938412817, -0.9653581922078193],
+    [-3.8766698570210925, 3.866768624441732, -1.0884593744161346],
+    [37.4402796211547, -28.152197153613034, 3.044315563351603],
+    [28.501477796663735, -21.346116915001897, 2.2073258559173583],
+    [22.625570260544528, -16.85903768894245, 1.6518191604641652],
+    [18.33788526328341, -13.574794707531805, 1.2426102567698425],
+    [14.9958320939816, -11.006889118328026, 0.920640565601734],
+    [12.268253902659547, -8.904533833843036, 0.6554007154805954],
+    [9.964544851097997, -7.123342003854464, 0.4292906935971684],
+    [7.965950525420439, -5.573336777445123, 0.23132389301923983],
+    [6.19387429229353, -4.194964249701231, 0.05421831345864363],
+    [4.5936937470032655, -2.946842534140226, -0.10709242043786138],
+    [3.1258124098511524, -1.798984862709076, -0.2562861573034559],
+    [1.7603852857998723, -0.7288025248919245, -0.3961427658355198],
+    [0.474073897604865, 0.28135799203224104, -0.5288477898039463],
+    [-0.751951048174068, 1.2457438168902175, -0.6561809519049555],
+    [-1.9331621929824088, 2.176103061040473, -0.7796271425551584],
+    [-3.0824356858147204, 3.082287932642131, -0.9004493630960242],
+    [37.1745709141702, -28.196122300991934, 3.1607869339380343],
+    [28.615962783998715, -21.64999558535863, 2.3439119799462076],
+    [22.917006586338353, -17.28026958050792, 1.7960355302343771],
+    [18.729155747505054, -14.060835686083488, 1.3905564310951153],
+    [15.451365059974679, -11.534277510244205, 1.0709328841916264],
+    [12.769477053707348, -9.461458248302169, 0.8075520661083978],
+    [10.50087629116195, -7.703336301635001, 0.5831653515397744],
+    [8.530932028734087, -6.172611321269292, 0.38692338951017463],
+    [6.783319780261365, -4.811145572141729, 0.21158841913241996],
+    [5.204741708979686, -3.57834015154707, 0.052097662976808255],
+    [3.756434423577033, -2.444674731510686, -0.0952366453688153],
+    [2.4091405543476783, -1.387881648019413, -0.2332027520730533],
+    [1.1399823534519482, -0.39056494578480433, -0.3639869222804001],
+    [-0.06952558781003847, 0.5613209375243589, -0.48936457011746287],
+    [-1.234686229781369, 1.4794169540435864, -0.6108194174368489],
+    [-2.3683512802474205, 2.3735489822888396, -0.7296175553691072],
+    [36.96806664541524, -28.272110842295188, 3.275724161804066],
+    [28.7454753453323, -21.95515739434353, 2.476264881098673],
+    [23.204626323959516, -17.689450170904845, 1.9347005179034817],
+    [19.10570210564095, -14.52680768952811, 1.531987924727532],
+    [15.884494810669583, -12.035694545627976, 1.213867198620271],
+    [13.242190667426689, -9.987529458798786, 0.9515560315304408],
+    [11.003491696664621, -8.248190388808872, 0.7281409780247605],
+    [9.057602253005717, -6.7328876331494625, 0.5329124352995569],
+    [7.330348163944487, -5.384844991282294, 0.35868663273213935],
+    [5.769700484193617, -4.164232178821447, 0.200414579327236],
+    [4.337683320490077, -3.041979615563146, 0.054404082306428306],
+    [3.0055648542857587, -1.9961038172946213, -0.08214315699884085],
+    [1.7508528153156402, -1.0094114366713338, -0.21142175488724546],
+    [0.5553518345994872, -0.06801071057332614, -0.3352118005847757],
+    [-0.5960737560244507, 0.8396498352364522, -0.45500184979664676],
+    [-1.7162459889986452, 1.7233815381782762, -0.5720662027320162],
+    [36.82265290537017, -28.384561138440617, 3.3910676574881866],
+    [28.897382427291735, -22.269674718694212, 2.6065773217771153],
+    [23.497725600346573, -18.096005894637884, 2.070138143135266],
+    [19.477937716466965, -14.983002952999259, 1.6693556543622372],
+    [16.30649964948456, -12.52215957091207, 1.3520271424511332],
+    [13.698425635280225, -10.494429862017888, 1.0901299956671637],
+    [11.485087531660785, -8.770186799465009, 0.8670616741112808],
+    [9.559223591305479, -7.2669628769595365, 0.6722459642822416],
+    [7.848667190067971, -5.92926300478904, 0.49855503153760794],
+    [6.302592159044514, -4.717996993302816, 0.3409593789850652],
+    [4.883776223333374, -3.604537535846417, 0.19576848193745217],
+    [3.563983933318374, -2.567175964901514, 0.06017764970198287],
+    [2.321072069228193, -1.5889015341295742, -0.06801627474592273],
+    [1.1371016705535755, -0.6559517954527729, -0.19060105307089706],
+    [-0.002900856011009333, 0.24314650010863567, -0.3090729335592518],
+    [-1.1117154208191142, 1.1181922632258081, -0.4247168239869424],
+    [36.74003057746716, -28.537229039615315, 3.508445111281877],
+    [29.07777768918637, -22.600220810181394, 2.7366629636251725],
+    [23.803904133068027, -18.507632124010946, 2.204244073374787],
+    [19.8542868366098, -15.437728500236725, 1.8046333035371087],
+    [16.726420606443256, -13.002480584151744, 1.4874712794654374],
+    [14.147760641207707, -10.991430564075886, 1.2254223671471747],
+    [11.955734335863689, -9.279033884507271, 1.0021662158240285],
+    [10.046312510110601, -7.784947611092586, 0.807248358089457],
+    [8.349180189848221, -6.454860086600713, 0.6335933253162136],
+    [6.814634185601236, -5.250376445992911, 0.47619209809910235],
+    [5.406164025870284, -4.143293150330474, 0.3313589913291487],
+    [4.096007820032538, -3.112170429869472, 0.19628670451491043],
+    [2.862352603239553, -2.140174879768665, 0.06876620976369642],
+    [1.687499163046438, -1.2136659077715877, -0.05299696966503491],
+    [0.5566346759372702, -0.3212465569082131, -0.17050651993128416],
+    [-0.5429611143802487, 0.5468630110825066, -0.2850576595874751],
+    [36.722073600529434, -28.73365861598831, 3.629300606882539],
+    [29.292071215097067, -22.95266443103601, 2.868101473838758],
+    [24.12975064481238, -18.930968773877666, 2.3386453947101575],
+    [20.241945950935918, -15.898052162149439, 1.9394924045505946],
+    [17.15189038129819, -13.484064827332517, 1.6219229328252611],
+    [14.598206346593026, -11.486255411830752, 1.3592148590410669],
+    [12.423796344329729, -9.782764543890323, 1.135298589578275],
+    [10.527568478731146, -8.295174160174716, 0.9398265115324204],
+    [8.840898399970454, -6.9702493014868265, 0.7657684539181502],
+    [7.315113768511359, -5.770231730082406, 0.6081330641124425],
+    [5.914365324142835, -4.667312683324453, 0.46323960640970124],
+    [4.611335653480002, -3.640307775465212, 0.3282798453116227],
+    [3.3845251770125864, -2.672555973862488, 0.20104124941868648],
+    [2.2164644253685752, -1.7505371764585445, 0.0797248608029594],
+    [1.0925029864313462, -0.8629345872734314, -0.03717836320125284],
+    [-2.1457411496319232e-14, 1.6475645761807512e-14, -0.15097163922517257],
+    [21.325396596129757, -4.051539999141969, -4.991007379809091],
+    [2.8355970261281658, 8.272158029341956, -5.885463226039495],
+    [-4.62378994406339, 12.84090613188505, -5.999960876964501],
+    [-8.79967765672528, 15.23541339252603, -5.960687034216472],
+    [-11.603976694639579, 16.782161678594615, -5.893343761700859],
+    [-13.752024926067854, 17.95769203736275, -5.834263370565523],
+    [-15.548228494662013, 18.95569356032404, -5.792989194672013],
+    [-17.132770903024745, 19.860567959718743, -5.7702949836591495],
+    [-18.58274557958381, 20.716743142785056, -5.765115951210591],
+    [-19.947944655082622, 21.552436452317696, -5.776424797766115],
+    [-21.264676591785516, 22.388480115394234, -5.803649069911981],
+    [-22.562105651211496, 23.24223111763679, -5.846736423926318],
+    [-23.865710272892137, 24.12967313721364, -5.9061557104902445],
+    [-25.199528546366214, 25.066811165191886, -5.982911267305592],
+    [-26.587911091183358, 26.07082750626058, -6.0785949226604155],
+    [-28.057172024010068, 27.161259037793236, -6.19549018726129],
+    [41.94199460633437, -26.049753966551684, 0.8088974237232088],
+    [22.507215020973064, -12.022742965093244, -0.7561494581800468],
+    [12.491851579245191, -4.6663338007650035, -1.6289648490048136],
+    [6.143889193153921, 0.018016381665632682, -2.1878449672457734],
+    [1.6464450490113547, 3.340445003796419, -2.58008794390045],
+    [-1.7830940036725942, 5.875132036773188, -2.8750156404590568],
+    [-4.546674177070965, 7.919499310967957, -3.109852046178737],
+    [-6.879484207826222, 9.648123415545413, -3.3069483255350374],
+    [-8.930283727568314, 11.17165075524292, -3.4806905903026757],
+    [-10.792498431061798, 12.56027317157595, -3.6402666175286806],
+    [-12.527215647325281, 13.860543927148015, -3.791797626403289],
+    [-14.17857694996198, 15.10649603229148, -3.9398064917171407],
+    [-15.781732105410716, 16.325486192380104, -4.088000474569566],
+    [-17.367144441580493, 17.541443464684313, -4.239713061035927],
+    [-18.963324824007916, 18.776991162672033, -4.398200978548377],
+    [-20.59894324377847, 20.055129262559113, -4.566890273541582],
+    [43.563903946832504, -28.6722910022782, 1.7720009038040019],
+    [26.55483381877121, -16.434398974875403, 0.4325813151902003],
+    [17.349523991614117, -9.69145612802127, -0.35494010039365315],
+    [11.239562623674361, -5.1587398837095, -0.9051121018187197],
+    [6.759176602948914, -1.807169553504379, -1.3197346765398537],
+    [3.257543888659971, 0.8283765924686793, -1.6488534438031734],
+    [0.39012239718585107, 2.997880466043686, -1.9211905800399522],
+    [-2.0469438202892127, 4.850829927222849, -2.154712649906977],
+    [-4.185658896284585, 6.484703105635277, -2.361544784872331],
+    [-6.1183127884900825, 7.967949865037652, -2.550453200494019],
+    [-7.910840462768839, 9.349897439354786, -2.727911639604853],
+    [-9.61048183134005, 10.666482444324613, -2.8987424136441415],
+    [-11.253330247839003, 11.945658137222647, -3.0667891560430824],
+    [-12.869599768142331, 13.21118155712754, -3.2354093068635],
+    [-14.486891664555117, 14.485030344017215, -3.4077999393072083],
+    [-16.13249220460176, 15.78916742530612, -3.5872472912565723],
+    [43.50554193345887, -29.47962580142051, 2.249223810595532],
+    [28.129015947292064, -18.364269510828276, 1.0148380724712576],
+    [19.593568998371875, -12.113685525818546, 0.28977688274678315],
+    [13.834966198852774, -7.846385311267787, -0.22320225236144847],
+    [9.540571978651773, -4.632335224126238, -0.6197346455474408],
+    [6.135145831413385, -2.0627572982377593, -0.9424172328205523],
+    [3.314572106570623, 0.08042344915255656, -1.2149175661814808],
+    [0.8976730606131753, 1.9285071405676444, -1.452150005537953],
+    [-1.2330246147583952, 3.567431262626481, -1.6642749863909747],
+    [-3.1595010451760643, 5.057615026351577, -1.8586937427864645],
+    [-4.942978375576451, 6.444433674504092, -2.0411508471997934],
+    [-6.630581958725158, 7.763135468961453, -2.216246439341164],
+    [-8.258907511807374, 9.041560962376632, -2.3877221053341886],
+    [-9.85799448639583, 10.303008404467594, -2.5588061239009976],
+    [-11.454705328747298, 11.56866548118763, -2.7325267282145087],
+    [-13.075193508269766, 12.859441452637606, -2.9119606958801634],
+    [43.048489455854416, -29.77270522484255, 2.563606946964066],
+    [28.93139293510098, -19.501942947662837, 1.3978106962898036],
+    [20.898421660806658, -13.600595747879858, 0.7081806155344462],
+    [15.426139060058086, -9.541682912915068, 0.22116055052413336],
+    [11.314423478272953, -6.464062212053539, -0.15667263844384433],
+    [8.028408466460585, -3.9839640221863735, -0.46690478499528504],
+    [5.286957813999619, -1.8993077435795807, -0.7315781121378578],
+    [2.923632062532417, -0.08982374043159068, -0.9640966339315177],
+    [0.8307642158422499, 1.5228707848738403, -1.1734661633699268],
+    [-1.0664391740949877, 2.99365693926218, -1.3662031501896974],
+    [-2.8235522609317836, 4.363600023841742, -1.5473495669678687],
+    [-4.484541855732664, 5.665393817350859, -1.7210564489548323],
+    [-6.085273334137101, 6.925992829088548, -1.8908595161998265],
+    [-7.655610681676569, 8.168240275927408, -2.059854079539867],
+    [-9.221978791952726, 9.412737732453545, -2.2309239902507434],
+    [-10.80977751396515, 10.679609671391946, -2.406973343874735],
+    [42.50872502410686, -29.886510317366373, 2.8029494461218323],
+    [29.414467735675732, -20.29557195265485, 1.689215359481061],
+    [21.78380798637547, -14.663692694363863, 1.0221250495876033],
+    [16.536892231625856, -10.76099183722433, 0.551134392632499],
+    [12.575327729748116, -7.791430434058502, 0.18642793450050343],
+    [9.396708109491794, -5.39070083406902, -0.11316020148991465],
+    [6.734098578464629, -3.36515559201607, -0.36951776459617297],
+    [4.429744728531811, -1.6001711661681641, -0.5956576777938932],
+    [2.3821672908432836, -0.021731845798308932, -0.8000957352604382],
+    [0.521266950491294, 1.4215894782356557, -0.9888791339527908],
+    [-1.204635072793911, 2.767963353135811, -1.1666208896610886],
+    [-2.8362510150548323, 4.047654223474623, -1.3370933494612065],
+    [-4.40759544928861, 5.286124890222878, -1.5035750735380504],
+    [-5.947948684927, 6.50556857352546, -1.6690182229216466],
+    [-7.483407096784367, 7.7261219836348065, -1.8361899135311617],
+    [-9.03888193764971, 8.967346393957998, -2.007862281180833],
+    [41.996960608367615, -29.947315317330197, 3.0031074502650936],
+    [29.752787491772523, -20.91916164590633, 1.9312617190515924],
+    [22.457457165108924, -15.506609750812784, 1.2799006950222962],
+    [17.393421503774327, -11.725385358119379, 0.8189129814689045],
+    [13.552347656883208, -8.83852433021592, 0.4624951375410509],
+    [10.462042894514713, -6.500597930535755, 0.1703071447111615],
+    [7.867638629725946, -4.525005058580514, -0.07948376629363477],
+    [5.617304548806302, -2.8004748074173103, -0.29991732294525775],
+    [3.6133487148468277, -1.255279397496125, -0.4994351873208946],
+    [1.788576422150736, 0.16008963861338651, -0.6839244854101642],
+    [0.09378435231707627, 1.4820728324317334, -0.8577951364367151],
+    [-1.509439974030697, 2.7392898510230395, -1.0245942588561439],
+    [-3.0531780000936593, 3.955784020193187, -1.1873871042115531],
+    [-4.565690446852046, 5.152905337810412, -1.3489788412050137],
+    [-6.0726831504813115, 6.350346316960539, -1.5120373078630915],
+    [-7.598733452615482, 7.56725570836964, -1.6792348015419716],
+    [41.558500538887905, -30.01268367417537, 3.1821224750685175],
+    [30.030042516932998, -21.457124721780957, 2.144750262116895],
+    [23.019912531996876, -16.227907524405285, 1.5048945742351112],
+    [18.108143578883087, -12.544234127600419, 1.050181865499272],
+    [14.364835352760004, -9.7212556946583, 0.6986747221279279],
+    [11.345472353907123, -7.431290999309532, 0.411047808719938],
+    [8.806611627061242, -5.494690037150633, 0.16567900023414217],
+    [6.6016573271185806, -3.8031372029900217, -0.05050261072555125],
+    [4.6356840718056205, -2.286382109711226, -0.24599963938432506],
+    [2.8433270472551255, -0.8959331589329825, -0.4267113629862105],
+    [1.1768862762714134, 0.403728483928586, -0.5970009572725851],
+    [-0.40060853107393735, 1.6402679843730967, -0.7603205972217219],
+    [-1.919787923394888, 2.8367263794024145, -0.919607473856451],
+    [-3.4077893991877897, 4.013629602036166, -1.0775446234785642],
+    [-4.8897991433575605, 5.190206344983074, -1.236713593612739],
+    [-6.390136993206311, 6.385291324763791, -1.3997118149878591],
+    [41.21390081074641, -30.112733355295383, 3.3504690542904125],
+    [30.292679163148126, -21.956977528265547, 2.341607759142633],
+    [23.527385397340144, -16.882788920870645, 1.7102452798648031],
+    [18.744187977249997, -13.278801634595423, 1.2592899142003176],
+    [15.081143426702312, -10.505676865398021, 0.9103466703407841],
+    [12.118564585944748, -8.251859582482538, 0.6251152648628293],
+    [9.623664244732439, -6.344356126720578, 0.3822690568803873],
+    [7.454865514102406, -4.67781040318742, 0.16877880573059786],
+    [5.519765907998082, -3.1833384468796764, -0.02390447560611395],
+    [3.7544052643987738, -1.8131487080324427, -0.20173792890513775],
+    [2.1120482468858306, -0.5322104722763802, -0.3691158232221814],
+    [0.5565879632854198, 0.6866510140111576, -0.5294809864769053],
+    [-0.9416756142047781, 1.8658904335456972, -0.6857199107636048],
+    [-2.4089502122981923, 3.025405990768242, -0.8404386399867302],
+    [-3.8698346894755287, 4.183979717493965, -0.9961497721619019],
+    [-5.348414758283381, 5.360194739746905, -1.1553955626956578],
+    [40.973776725336165, -30.26550820955951, 3.5149838333161108],
+    [30.569763057850572, -22.4488425420232, 2.529521248643472],
+    [24.01518963778929, -17.505998477621038, 1.9042483666804395],
+    [19.341029774589302, -13.967320962776576, 1.4551747509122614],
+    [15.744135034846845, -11.233044728838976, 1.1070742234168705],
+    [12.826837349436486, -9.006003470733392, 0.8226020987256069],
+    [10.366042924453888, -7.119329339185792, 0.5807400695727314],
+    [8.224899051120715, -5.4705271224349845, 0.36854111880105783],
+    [6.313446121893691, -3.992102054395352, 0.1774489233153694],
+    [4.569038435085309, -2.636941339787082, 0.001464599906995378],
+    [2.9457097070572087, -1.370370536566775, -0.1638514419931682],
+    [1.4079141428047446, -0.16547531299312465, -0.3219704629698277],
+    [-0.07348507303102415, 0.9998966874516667, -0.47577800133729287],
+    [-1.5240493387758811, 2.1452493277009035, -0.6278474322234918],
+    [-2.967832773093257, 3.288996301215054, -0.7806454706479348],
+    [-4.428688549998712, 4.4495142057863495, -0.9366756580775005],
+    [40.84500257476665, -30.483621624732596, 3.680629973661863],
+    [30.88171609623885, -22.954029819175062, 2.713978832584161],
+    [24.507982090968202, -18.121794846415415, 2.092700552290016],
+    [19.925926658656028, -14.636122893817069, 1.6439748697644316],
+    [16.383205034885673, -11.931540091276892, 1.2953600402523269],
+    [13.501536611936618, -9.723573646628694, 1.0103633857350203],
+    [11.06649448063363, -7.85084675263967, 0.7682490726006382],
+    [8.945538130528671, -6.213497685278478, 0.5561575321366622],
+    [7.051011034311672, -4.745375529437863, 0.36554510941031876],
+    [5.321548589471501, -3.4000672944752717, 0.1903939234634138],
+    [3.7119187789100927, -2.143260843367543, 0.026232843927181452],
+    [2.1870355057671214, -0.9482441751744264, -0.13044038742195227],
+    [0.7181364017181214, 0.20696644778517895, -0.2825296566010448],
+    [-0.7199198922269394, 1.3416517077111487, -0.4326043681295953],
+    [-2.150751792676312, 2.4739616975045906, -0.5831075824595935],
+    [-3.5979837028780497, 3.6221056947747012, -0.7365178426419979],
+    [40.833638320838716, -30.77751181408225, 3.851398478440464],
+    [31.244698932234943, -23.489344790218958, 2.899295705482613],
+    [25.024857852900766, -18.748896789419913, 2.280057822573576],
+    [20.519617968353597, -15.305155205491175, 1.830317864853879],
+    [17.020419172042512, -12.622216187320968, 1.4800349923616591],
+    [14.16593446464491, -10.426688928525177, 1.1934480348754857],
+    [11.749382960078654, -8.562021781790035, 0.9500581293661223],
+    [9.642064240531958, -6.930687572551111, 0.7370770925277115],
+    [7.758407176370598, -5.467753650669798, 0.5459735010053117],
+    [6.038246983321763, -4.127476874204504, 0.37072002811612553],
+    [4.437065350661729, -2.8759199290962054, 0.2068275852206204],
+    [2.9202188360129946, -1.6865841875762544, 0.0507737266858772],
+    [1.4592572692613124, -0.5376262233718477, -0.10036208951576744],
+    [0.029336920926753093, 0.5900914409492609, -0.2491547919950288],
+    [-1.392829537019054, 1.7145369370366934, -0.39803732051580765],
+    [-2.830646476681107, 2.8537821397064134, -0.5494722916217043],
+    [40.94653659101461, -31.157279892896504, 4.030832443315515],
+    [31.67310034519483, -24.06953284874589, 3.089196073341597],
+    [25.58220842890961, -19.403245440575454, 2.470078773511383],
+    [21.13949249282935, -15.991034474019052, 2.018028180492491],
+    [17.673944740411624, -13.322302981840748, 1.6650241179273697],
+    [14.83893293243581, -11.133211382790146, 1.3759053625604216],
+    [12.434328978423148, -9.27136281658925, 1.1303529933710397],
+    [10.334776504948476, -7.641230906111426, 0.9156220608550821],
+    [8.45652681401109, -6.178926921925568, 0.7231798915311023],
+    [6.740476296520711, -4.839291128127663, 0.5469856872293579],
+    [5.1427588467158865, -3.5887317109377905, 0.3825357005193988],
+    [3.629153006458284, -2.4009659353993777, 0.22629507785535702],
+    [2.171504934015069, -1.2542976296691577, 0.07533454756954278],
+    [0.7452237369566416, -0.1297167444112995, -0.07292314634069147],
+    [-0.6727080909552371, 0.990598456225967, -0.22090308468109163],
+    [-2.1054757116874097, 2.1245912347567217, -0.37105380043500985],
+    [41.19244022760608, -31.63393731899678, 4.222381047116233],
+    [32.18117351342971, -24.70887618287777, 3.287190461591727],
+    [26.195551776872218, -20.09976010511749, 2.666229841729577],
+    [21.801577757126367, -16.708948586286063, 2.2105643725246735],
+    [18.360186188499956, -14.047249217439928, 1.8538158468226882],
+    [15.537319547597393, -11.858905080099271, 1.5612816425446425],
+    [13.138535396780732, -9.99500305469981, 1.3127569606625151],
+    [11.041316730438869, -8.361665154737478, 1.0955041579900233],
+    [9.163448228953767, -6.895843209979679, 0.9009677613225082],
+    [7.446711502996882, -5.5528372449465095, 0.7230803688375841],
+    [5.847788244181213, -4.299329798980961, 0.5573170318543322],
+    [4.332822180974024, -3.1092202282992685, 0.4001295856872435],
+    [2.873926822359207, -1.9609500953587349, 0.24858343598266852],
+    [1.4467418468904283, -0.8356356947745966, 0.10010424552201558],
+    [0.028492284613872102, 0.28440317293682876, -0.04772085142424998],
+    [-1.4037747906136082, 1.416967060287578, -0.19732122602157703],
+    [41.58296018019659, -32.22046315846166, 4.429684482666499],
+    [32.784326486526986, -25.42243530686311, 3.496863722898981],
+    [26.880911036074785, -20.853645944445034, 2.871982669979598],
+    [22.521992313870854, -17.47403137168648, 2.4113309487751264],
+    [19.095311800280328, -14.812168248999017, 2.0497903438253844],
+    [16.277364351629654, -12.618950495482423, 1.7529646471341984],
+    [13.87843986748977, -10.748270277021936, 1.5006887600372851],
+    [11.778347650649893, -9.107529242395108, 1.2801899056233486],
+    [9.896099147562117, -7.634296421989102, 1.082862571105352],
+    [8.17416395764442, -6.284185759631155, 0.9025939504106109],
+    [6.569636743798877, -5.024053628357138, 0.7348245030081341],
+    [5.048931993464062, -3.827912047247453, 0.575983757084248],
+    [3.5843687891577547, -2.6742977751331263, 0.42312737203753104],
+    [2.151775282274432, -1.5444301661170616, 0.27368354113845267],
+    [0.7285911096002253, -0.4207621207081992, 0.12525462834843412],
+    [-0.7078943275021315, 0.7143468393263995, -0.024562650186186537],
+    [42.13367436833223, -32.93291511689994, 4.65685054015593],
+    [33.50036582675879, -26.22722194905926, 3.7221397978099446],
+    [27.656069390457763, -21.681567078747456, 3.091075737538872],
+    [23.31821627124591, -18.30255011987426, 2.6239433964618195],
+    [19.896550543947335, -15.63305216194622, 2.256491297796198],
+    [17.076149228625347, -13.429191594109602, 1.9544637248231356],
+    [14.67107659136463, -11.546967108259027, 1.6976509615351592],
+    [12.562939692778057, -9.89467021167675, 1.4731958221616999],
+    [10.671653665036244, -8.41024544862108, 1.272410490150124],
+    [8.940160417085007, -7.049456589503638, 1.0891135344819338],
+    [7.32581194509775, -5.779213442412119, 0.9186928377341322],
+    [5.795175468315697, -4.573548194284059, 0.7575412588325687],
+    [4.320681296965169, -3.4110193144217664, 0.6026932898407321],
+    [2.878271519070262, -2.2728934018262668, 0.45157161742998236],
+    [1.4455406098162007, -1.1417164943289424, 0.30178953470173553],
+    [1.3857750892266076e-12, -9.28230277607988e-13, 0.15097160501713666],
+    [34.53814648374928, -14.19924101383061, -3.0481916792935095],
+    [9.336637319630507, 2.9177111487042473, -4.634975650515598],
+    [-0.1742238923652065, 9.028164636081428, -5.015635049008128],
+    [-5.362387767070585, 12.222354281913786, -5.1295589550337555],
+    [-8.768528609018974, 14.271968766722125, -5.165841919856854],
+    [-11.297086403445782, 15.790610549726999, -5.182222637068542],
+    [-13.364535614517486, 17.053632323195767, -5.201541408820808],
+    [-15.183603564327603, 18.19855606844788, -5.233447096017768],
+    [-16.870745199993245, 19.299553844075497, -5.282098896884843],
+    [-18.499754339035373, 20.40398039073846, -5.349991900550704],
+    [-20.12591841964681, 21.54864541487756, -5.439547297943383],
+    [-21.797733176357152, 22.7677422328848, -5.553840503347493],
+    [-23.564070082020137, 24.097907192360008, -5.697112259698446],
+    [-25.480254384947106, 25.582845544877387, -5.875352172238515],
+    [-27.615149621636814, 27.279107022667635, -6.097168514868996],
+    [-30.061523918764596, 29.264881553198872, -6.375240203306211],
+    [50.06773376322193, -31.258207518928987, 1.6107587754649293],
+    [26.95512992310844, -15.042967287830738, -0.13118142947647216],
+    [15.789243160036163, -7.017577994977304, -1.0668506678616458],
+    [8.834225027080853, -1.961998663566812, -1.6704347003117448],
+    [3.9371988636192787, 1.621561626391103, -2.099445458139766],
+    [0.20423648485270868, 4.369946900614315, -2.4276775941225615],
+    [-2.81249773390849, 6.606591267914541, -2.6944928096984895],
+    [-5.3682685229521265, 8.517640137167994, -2.9232644332939035],
+    [-7.626115944277973, 10.2228586919218, -3.1294314563746433],
+    [-9.70113141663978, 11.80739365026069, -3.3242737284632233],
+    [-11.677726787804689, 13.334699310205108, -3.516444577007536],
+    [-13.621671545166048, 14.855514040638086, -3.7131098254543566],
+    [-15.590845230294404, 16.4157932038018, -3.921033409643363],
+    [-17.64359487719231, 18.06309485425773, -4.147509532447051],
+    [-19.846458768036456, 19.852802431453, -4.401334010641089],
+    [-22.283694961778558, 21.856117273168337, -4.69411386485571],
+    [50.40439739052379, -32.94753958298898, 2.4425501137774406],
+    [30.272774879150614, -18.76567095573179, 0.9035912509788068],
+    [20.13008209087703, -11.491072121590692, 0.06823062391663516],
+    [13.564622385587365, -6.708567803674397, -0.5043119724927382],
+    [8.785407135061122, -3.183995859232343, -0.9374168690814751],
+    [5.049660793428034, -0.4002769257392685, -1.2851046100847683],
+    [1.9756958191193632, 1.912507049150548, -1.5774241392659938],
+    [-0.6590868720045054, 3.9141092303488025, -1.8331189638357515],
+    [-2.997296659393696, 5.708320183800974, -2.0649049280556877],
+    [-5.138828509819526, 7.368876054310632, -2.282186968782304],
+    [-7.161254907570865, 8.953808828524336, -2.492678250276886],
+    [-9.129995482150113, 10.51288079474369, -2.70327447438117],
+    [-11.103266339480003, 12.091535252237161, -2.9205425303442807],
+    [-13.138015925724138, 13.735527219581611, -3.151363199309049],
+    [-15.29711834224439, 15.496602020083055, -3.4037797037837767],
+    [-17.65829682123653, 17.439817480845527, -3.6881528456994106],
+    [49.62476648362079, -33.30136738160261, 2.8786257963411237],
+    [31.56142684959945, -20.465770341120386, 1.4442182981859257],
+    [22.143615713110616, -13.687348365390202, 0.6604032508036681],
+    [15.96766167101949, -9.185419548654597, 0.1236865884004838],
+    [11.416043257004912, -5.827103996975752, -0.2871779960980148],
+    [7.813402994777759, -3.138942537650056, -0.6229100652039135],
+    [4.816643910103287, -0.879221391385694, -0.9098239997117827],
+    [2.225836105831155, 1.094346816657208, -1.163955536807084],
+    [-0.08777496697861821, 2.874635138945419, -1.3962176342970893],
+    [-2.214329718156064, 4.5277106276637795, -1.6147191205180196],
+    [-4.222650088042215, 6.104935120891589, -1.8260561078880595],
+    [-6.171176301967608, 7.650704141778094, -2.0362007358751106],
+    [-8.114726990052178, 9.207434541007927, -2.251116807839544],
+    [-10.108340406344322, 10.818752116553283, -2.477190356186473],
+    [-12.212373078950762, 12.533700556846272, -2.721848756075546],
+    [-14.500461781839281, 14.41326259280383, -2.9945628616204005],
+    [48.711155283965724, -33.33873260508042, 3.1806775243123213],
+    [32.24198524835156, -21.52973297953535, 1.820409140081097],
+    [23.367986978907123, -15.103847740177596, 1.064298612394725],
+    [17.48280096070971, -10.798703029184914, 0.5474337443588969],
+    [13.120685010378283, -7.574476154748338, 0.15287637439496302],
+    [9.650147948411323, -4.9825392598443194, -0.16997563835957583],
+    [6.747386699802403, -2.7924239325902374, -0.44718567296863354],
+    [4.2246346261324135, -0.8699007985293192, -0.6940793163380916],
+    [1.9615839684040581, 0.8717941741690058, -0.9207862243875491],
+    [-0.1258399163022566, 2.4941244574915067, -1.1347275986306005],
+    [-2.1015262144045144, 4.044645489658566, -1.3418867488471233],
+    [-4.018848558550868, 5.563913765719654, -1.5476027163955826],
+    [-5.927853347808927, 7.090686695661534, -1.7572102404152068],
+    [-7.88055846473001, 8.665973661283681, -1.9765798911712442],
+    [-9.935145488243213, 10.336601871291027, -2.2126392155744714],
+    [-12.162551410373004, 12.160754708249893, -2.4742154104087972],
+    [47.892888469294874, -33.32396216419595, 3.424504203076963],
+    [32.696091125906456, -22.33169317929586, 2.1217261908900253],
+    [24.257531403309443, -16.179123666380736, 1.3827461408379804],
+    [18.595743358482054, -12.016391426401764, 0.8762892270052853],
+    [14.377579140077142, -8.887791956785444, 0.49074325101395155],
+    [11.012091161636725, -6.368932112141232, 0.17632564665386743],
+    [8.19046101264806, -4.237533438153932, -0.09314806643607554],
+    [5.731917583136988, -2.36305211326189, -0.3331350044173439],
+    [3.520603811327223, -0.6613370730043965, -0.553697343153644],
+    [1.4758316878128437, 0.9268105845279799, -0.7620503565452473],
+    [-0.46348633134087425, 2.4469014159389815, -0.9639086516804111],
+    [-2.347988703912573, 3.9374193488644873, -1.1642907158855615],
+    [-4.224419472622381, 5.434635707066232, -1.3681219549512922],
+    [-6.141524623356169, 6.977009308942952, -1.5808197387821779],
+    [-8.15520844575332, 8.609315628482012, -1.8088825190780282],
+    [-10.334435725942992, 10.38768495608729, -2.060650013226525],
+    [47.2397622568526, -33.3536341288117, 3.6421475362920335],
+    [33.07595436337281, -23.02287649663566, 2.385005955610504],
+    [24.995761797206068, -17.090079737007994, 1.657181855406125],
+    [19.511716587051193, -13.034859822359923, 1.1557808454114507],
+    [15.403470468216813, -9.97415919369464, 0.7743084142861086],
+    [12.116836073415275, -7.506297598265124, 0.464135620084343],
+    [9.357295697777321, -5.417302437275478, 0.19923593078225432],
+    [6.9501698251461335, -3.579826074091754, -0.03597238047500782],
+    [4.782511895546037, -1.9110935497839125, -0.25169800026369926],
+    [2.775404200654576, -0.3527917023704566, -0.4552205269460516],
+    [0.8691596748433179, 1.1397410510686166, -0.652223633725675],
+    [-0.985502305791098, 2.6040267750506856, -0.8476142343905362],
+    [-2.8336089818418535, 4.0750482776372525, -1.0461173367865175],
+    [-4.721524237325612, 5.589462765962724, -1.2528422508042665],
+    [-6.7029275817478275, 7.190220233711002, -1.4739310884345236],
+    [-8.845191482389577, 8.93185608987758, -1.717340613098281],
+    [46.77610450640481, -33.47182749238727, 3.8505731911374608],
+    [33.45807129153875, -23.680522218516742, 2.6294491310342494],
+    [25.67537837340471, -17.926176046487456, 1.9086036066243215],
+    [20.334787103473584, -13.953298295373546, 1.4088066317052517],
+    [16.311159577942316, -10.94063504097883, 1.0281343778632577],
+    [13.082534036398515, -8.506665030947296, 0.7191027563528529],
+    [10.367559075541891, -6.445301096747724, 0.45596399399485005],
+    [7.997499807423756, -4.63253845514712, 0.2231412766644072],
+    [5.862180245967235, -2.98699672799464, 0.010334265457400299],
+    [3.88399435171411, -1.4509160844389646, -0.18984393652924192],
+    [2.003965372743541, 0.020084626744766878, -0.3831501000763333],
+    [0.1733663826758218, 1.4632294678769997, -0.5745049146828433],
+    [-1.6521244286276435, 2.9129993126046854, -0.7685732494745486],
+    [-3.51760664220479, 4.405098563880695, -0.970303205576113],
+    [-5.475016890396442, 5.9811121451255005, -1.1855764236986635],
+    [-7.590402429077842, 7.694324809916934, -1.4220472889256124],
+    [46.51308946886362, -33.704082877546796, 4.0605268926443285],
+    [33.888777967858495, -24.351943277457828, 2.866983830960089],
+    [26.352245069103905, -18.74132871500628, 2.1496777772489954],
+    [21.126944938985478, -14.83057813279037, 1.6489036490082776],
+    [17.16778863979263, -11.85058674362427, 1.2666673257719354],
+    [13.980566040781161, -9.43723620832222, 0.956488786184714],
+    [11.295536880872039, -7.391433939043956, 0.6928875516638261],
+    [8.94944006025798, -5.592331403842044, 0.46033585668727484],
+    [6.834840454224243, -3.9600398954245133, 0.2485071668666712],
+    [4.875487744926284, -2.437463864729319, 0.049957304827488454],
+    [3.01301458227592, -0.9804580167074829, -0.14113604411719793],
+    [1.19893987668254, 0.4481407836349519, -0.32974446389530676],
+    [-0.610886610929107, 1.8827286040895743, -0.5205448155950798],
+    [-2.461122779242355, 3.3586738721974103, -0.7184273781740346],
+    [-4.4026808603229854, 4.9167668235449575, -0.9291140617646386],
+    [-6.500596920108159, 6.6093820748170025, -1.1600295501951212],
+    [46.46004050320301, -34.069979173719354, 4.2800215412710125],
+    [34.40180389430262, -25.071504987315166, 3.1062610910917794],
+    [27.065977453416636, -19.573613454297288, 2.3893099341133603],
+    [21.931173276483307, -15.70729997215668, 1.8853501458212956],
+    [18.019210772651956, -12.747023755279683, 1.4996428228351883],
+    [14.8594430155325, -10.343372754102843, 1.1865157296683881],
+    [12.192069279872607, -8.303185480670972, 0.9206837835953123],
+    [9.858576665472897, -6.508325309966957, 0.686647166514426],
+    [7.754002403016317, -4.880262642483586, 0.4740642478962735],
+    [5.803433301050616, -3.362611439677137, 0.2754665922881637],
+    [3.94918634498155, -1.9115687783667168, 0.08500456838325635],
+    [2.143046410958246, -0.4900810260912305, -0.10232194340411012],
+    [0.3408833893501173, 0.9362614776050849, -0.29121026154037033],
+    [-1.5019519494288305, 2.4028039573755287, -0.48653972013982966],
+    [-3.4359952606917403, 3.9500220180701273, -0.6939490415174772],
+    [-5.525664425274761, 5.629735126241813, -0.9206991142409687],
+    [46.62941783569821, -34.588972808679266, 4.516038132864406],
+    [35.026578391979044, -25.868647756572102, 3.354540584579575],
+    [27.849525350496787, -20.45438457219751, 2.6347457911926675],
+    [22.782124660490332, -16.6159275288564, 2.1254915891613737],
+    [18.90152822610361, -13.663553793103052, 1.7346000145323734],
+    [15.756735102192268, -11.25995029475815, 1.4169761774055074],
+    [13.096208082336233, -9.216766010434812, 1.1474279993653038],
+    [10.765347702151633, -7.418012631576659, 0.9104295483327159],
+    [8.661221834630755, -5.786222011595195, 0.6955963188997678],
+    [6.710046711502909, -4.265587696235126, 0.4954291300764926],
+    [4.854781649078425, -2.812646273767549, 0.30406445257289166],
+    [3.0475344917602, -1.3905297624847959, 0.11650060902757703],
+    [1.2442453561174924, 0.0351142116417041, -0.07195463810271653],
+    [-0.599834156660971, 1.4996575309401188, -0.2661656938988582],
+    [-2.535254063754717, 3.043523552603661, -0.47172045519536177],
+    [-4.626196295183798, 4.718278412228801, -0.6957626018161012],
+    [47.03999540565221, -35.28406430546431, 4.775581360721803],
+    [35.7931844026427, -26.77262863127008, 3.6187931130713764],
+    [28.7346767049065, -21.41346101838832, 2.892751624888098],
+    [23.712100488860575, -17.586408719759003, 2.3760160303809297],
+    [19.847511831074627, -14.630419646862478, 1.9782514184436084],
+    [16.70582570075487, -12.217719898286989, 1.6546785803402124],
+    [14.042095808898084, -10.163609417537646, 1.3800735264660284],
+    [11.704750648191068, -8.353622711489255, 1.1388112584754606],
+    [9.592358106209131, -6.710969039553334, 0.9204156517487426],
+    [7.631924551765513, -5.180170949555532, 0.717323266371844],
+    [5.766846399244581, -3.717953873682588, 0.5236377398192645],
+    [3.9494689795162405, -2.2875816811035294, 0.33435433370108647],
+    [2.1358164449352084, -0.8547920203371815, 0.14480213474444112],
+    [0.2810712821017308, 0.6157556745290499, -0.049844178369553065],
+    [-1.665456796989969, 2.164479074019482, -0.25511179677061435],
+    [-3.7679604156220603, 3.8428487664477684, -0.4780385900055566],
+    [47.71998947878107, -36.18510031944407, 5.0665490204747226],
+    [36.73632682011483, -27.816241858098156, 3.90654287912997],
+    [29.756179022493658, -22.482948387859256, 3.170466703577448],
+    [24.75533329731074, -18.650144900800417, 2.64383918062651],
+    [20.89106933180945, -15.678646856577041, 2.237409695650539],
+    [17.74056320699114, -13.247634644733921, 1.9064162849671369],
+    [15.06375451507383, -11.17483840754839, 1.6254543730177953],
+    [12.711165802721963, -9.346634185269474, 1.378710911292553],
+    [10.582278436227595, -7.686469866463774, 1.1555560303217538],
+    [8.60447457841848, -6.138877983270308, 0.9483132052119043],
+    [6.721271104161939, -4.660522652732278, 0.7510127706144505],
+    [4.885010174425524, -3.214607163572134, 0.5586156230676768],
+    [3.051645538170196, -1.7668396519801912, 0.36645547209044554],
+    [1.17623009284701, -0.2818799129830957, 0.16974986041304863],
+    [-0.792104026183574, 1.2806696295762765, -0.03694788788987668],
+    [-2.9176474128267564, 2.972193750123562, -0.2605480337529348],
+    [48.711234380140006, -37.332800754918665, 5.398682195599339],
+    [37.89963943819646, -29.039758183131383, 4.226729257214228],
+    [30.955898457850413, -23.701027572243948, 3.476228408216082],
+    [25.9520943934459, -19.84373926600125, 2.9369238488207055],
+    [22.07137255271269, -16.843820224571814, 2.5198170190148703],
+    [18.89944813146634, -14.384691865046651, 2.179814865517984],
+    [16.199346583437332, -12.285185617638238, 1.891153196861243],
+    [13.822674402687216, -10.431761201365314, 1.6377230211146603],
+    [11.669179957700527, -8.747610098790284, 1.408665711661557],
+    [9.666151080069124, -7.1769059167202025, 1.1961319786724744],
+    [7.756839434082312, -5.675940927268215, 0.9940255793689676],
+    [5.89323761165443, -4.207572751696105, 0.7972221372640658],
+    [4.030910199831179, -2.7372147129290654, 0.6010122399347676],
+    [2.124509949796955, -1.2293055355510953, 0.400618945385112],
+    [0.12279584974644205, 0.3566716437694895, 0.19066116186133047],
+    [-2.038730715748041, 2.072050814682521, -0.035627367907517665],
+    [50.0756632591191, -38.78468250705177, 5.784859113961352],
+    [39.341551968742706, -30.49619379545352, 4.590828944833536],
+    [32.38820843983808, -25.116799170618606, 3.820608221095017],
+    [27.35382356947439, -21.213623831685982, 3.2652762571680833],
+    [23.43786205320376, -18.170613824210253, 2.8351267806331735],
+    [20.23059409948203, -15.672438754429974, 2.4843140112197735],
+    [17.4961418161706, -13.53751947283017, 2.186492439548537],
+    [15.086057679150702, -11.651523350442796, 1.925123232549853],
+    [12.899622353516788, -9.93681145304511, 1.6890272488188136],
+    [10.863493301102476, -8.3367722791254, 1.4701109395909626],
+    [8.920212208268818, -6.806965596081623, 1.2620880265403396],
+    [7.021004045098871, -5.309557625330486, 1.0596855539936807],
+    [5.120611334444982, -3.8093030711537117, 0.8580827084659334],
+    [3.1728199068918737, -2.270024472136825, 0.6524338202897793],
+    [1.125530026042894, -0.650681487302574, 0.43735103990523494],
+    [-1.0863943301764378, 1.1003383537464875, 0.20616500174173],
+    [51.90621561511166, -40.62477517584146, 6.243131706201011],
+    [41.14450522686561, -32.25950308728769, 5.0145742543745815],
+    [34.12823264316239, -26.797640981390686, 4.217969316514645],
+    [29.03083002449662, -22.822962276916595, 3.6424203083718933],
+    [25.05764861229651, -19.71945148441177, 3.1963368303696287],
+    [21.79897710097828, -17.16951962590753, 2.832585490738411],
+    [19.017709752262437, -14.989362655539122, 2.5239540402096803],
+    [16.563995648439242, -13.062792579304777, 2.2533015227521678],
+    [14.335772732247378, -11.310644125228762, 2.009011971626954],
+    [12.25843285958472, -9.675010085456963, 1.7826593718064225],
+    [10.273285819953706, -8.11029300813308, 1.5676914591057556],
+    [8.330299620488157, -6.57756392236194, 1.3586121101856161],
+    [6.382874625834841, -5.040483563459495, 1.1504066194527223],
+    [4.383319925446696, -3.461741567266453, 0.9380612821048628],
+    [2.277923252459642, -1.7991323907092516, 0.716058612743041],
+    [-4.534231654925267e-14, 2.8690186422010046e-14, 0.47768538031065866],
+    [51.65652473103811, -26.372367839408483, -1.148312693342136],
+    [16.6376507571483, -2.5701923961428856, -3.565299965275041],
+    [4.5967315496004195, 5.344694586332846, -4.217919891401634],
+    [-1.7666700809986253, 9.411029417981577, -4.47718583489456],
+    [-5.877468191923009, 12.00657362860251, -4.61061977664853],
+    [-8.891177047636747, 13.920102694829419, -4.700541446354128],
+    [-11.317235479981305, 15.493742715485954, -4.779826048975801],
+    [-13.429409824171232, 16.90980938730542, -4.865184055254777],
+    [-15.393959529917476, 18.27976988443879, -4.966827235021772],
+    [-17.32260162808641, 19.680903494906257, -5.092302512291221],
+    [-19.304556260554293, 21.178641699685773, -5.249030389348184],
+    [-21.427189718139186, 22.84158935438225, -5.44619853077922],
+    [-23.793255489974708, 24.75518098976068, -5.696757805788],
+    [-26.54256864236237, 27.040207699339046, -6.020466608880362],
+    [-29.889359589486737, 29.885962309107796, -6.449643993358823],
+    [-34.200932823891044, 33.62099613948188, -7.041708606607566],
+    [61.28823240358444, -38.34657866620116, 2.5013196619809466],
+    [32.33512207776951, -18.516837426383272, 0.4357418110005457],
+    [19.656036240018548, -9.59238181977788, -0.5797862996443652],
+    [11.959306745671084, -4.079597802010404, -1.2328492724731317],
+    [6.594502718886896, -0.19086941871055974, -1.7002111248555654],
+    [2.5155123870628486, 2.7988658571668035, -2.0621558876524286],
+    [-0.7908225947029154, 5.252135056895606, -2.3615612091695506],
+    [-3.613676600675891, 7.376550792061469, -2.624088618932831],
+    [-6.135420535514418, 9.305475012696895, -2.866878379836213],
+    [-8.485573377986318, 11.135861729601723, -3.1029462744653267],
+    [-10.770687594526095, 12.949668863437843, -3.3438669035619486],
+    [-13.090163739829975, 14.826010362681064, -3.6014199934141398],
+    [-15.54884425966281, 16.851702847944136, -3.889183874593224],
+    [-18.275570762204364, 19.137050397652175, -4.225083724993986],
+    [-21.454244083484372, 21.84294698501987, -4.635934275128387],
+    [-25.38559439585538, 25.2357210551141, -5.166801600082429],
+    [59.850744021008076, -38.90032959161372, 3.2263653687522544],
+    [35.00634427971068, -21.70328817567354, 1.369488610895269],
+    [23.554067606693984, -13.632203268838643, 0.4547734063117161],
+    [16.398076005266777, -8.5009088214362, -0.15076443704490877],
+    [11.261515008252877, -4.759053744413513, -0.6060008321413535],
+    [7.262156694079633, -1.8032343248436222, -0.9736227864492121],
+    [3.963999064234918, 0.668753999374196, -1.2865438169440042],
+    [1.1157412335798271, 2.8343217037226176, -1.5652291303257095],
+    [-1.4446729318004663, 4.81041858634772, -1.8239434083440107],
+    [-3.8331648603850397, 6.683062261035891, -2.073788093123856],
+    [-6.142784039266919, 8.52375152207646, -2.3245508466640814],
+    [-8.460115422241195, 10.401512910438997, -2.5862375431992923],
+    [-10.880096747541444, 12.394414042941458, -2.8707153366468074],
+    [-13.521024279184513, 14.602446056637321, -3.1937377841854815],
+    [-16.549485562946703, 17.169564932235044, -3.578551994178591],
+    [-20.233123306734626, 20.330287715721305, -4.063610253554888],
+    [57.96676845115088, -38.59567536811325, 3.617531029974621],
+    [36.01123081005262, -23.216482087336296, 1.8887139848480792],
+    [25.388257720526536, -15.676764778346696, 1.0175743217134143],
+    [18.65812300161529, -10.834186832033744, 0.4435524783292278],
+    [13.78645489249976, -7.279156210104705, 0.011938715920061542],
+    [9.959135329116766, -4.44629309999533, -0.33955632010961695],
+    [6.775225520287533, -2.055975871895548, -0.6419684888580331],
+    [4.005678979984872, 0.0531611859733222, -0.9136361844481246],
+    [1.50266793578306, 1.9871674205948544, -1.1671305469894149],
+    [-0.840397436385869, 3.824556367540645, -1.4122773091911403],
+    [-3.109747614495535, 5.631075797285194, -1.657814545777555],
+    [-5.384824220117095, 7.469754066022218, -1.9126298053011435],
+    [-7.750903795397488, 9.410765696151007, -2.1871256992717716],
+    [-10.315737649634595, 11.544962516219638, -2.495249998134784],
+    [-13.23395733267474, 14.004917694026538, -2.8578239814006356],
+    [-16.754535357669077, 17.006774481505822, -3.3092950983550513],
+    [56.330326960033666, -38.227796654291446, 3.9013131717762084],
+    [36.552819740162164, -24.217810816101366, 2.2678776732598176],
+    [26.5586966381845, -17.063614459408473, 1.4202160612839076],
+    [20.13512150809199, -12.4135059591672, 0.860624935581577],
+    [15.459037003175883, -8.987647585219216, 0.441863648947506],
+    [11.773946746668072, -6.253442503084198, 0.10228790809161095],
+    [8.699175097186439, -3.9417765129728117, -0.18946488616356721],
+    [6.015910216055951, -1.896880720188217, -0.4517405830281585],
+    [3.5834372352777293, -0.01728853026077156, -0.6967435495034754],
+    [1.3005724008601767, 1.7715553065400422, -0.9337764434122534],
+    [-0.9147443703555284, 3.532007943056401, -1.171014822025489],
+    [-3.1384782115676706, 5.32397015866332, -1.4167422986233995],
+    [-5.451696110632688, 7.213771952699002, -1.6805765869044886],
+    [-7.955155030288779, 9.286209722378235, -1.975248857833804],
+    [-10.79442065835456, 11.665615922322372, -2.3198268550301906],
+    [-14.206928815848531, 14.556614102949835, -2.746098436103409],
+    [55.05316112650577, -37.98223425231993, 4.145457215616369],
+    [36.964900756072026, -25.034239832759628, 2.5856954430150227],
+    [27.471273103269112, -18.17868577881751, 1.7521483003477987],
+    [21.28054707085465, -13.665358536795875, 1.1984036278632841],
+    [16.745614471126352, -10.324768330577417, 0.7846624157127626],
+    [13.1623134394257, -7.655622190651744, 0.45069702823310676],
+    [10.169073110788217, -5.399437745800112, 0.16523245955985147],
+    [7.5548256981555335, -3.404285105749029, -0.090303481646618],
+    [5.182576614673465, -1.5704351173305622, -0.3282911081026674],
+    [2.953593036556336, 0.1752925126003593, -0.558034426518922],
+    [0.7878283005358198, 1.893800015128307, -0.7875472833129602],
+    [-1.3889444124204342, 3.6434230284238396, -1.0248273228212383],
+    [-3.656148941582974, 5.488706238987119, -1.2790786218983725],
+    [-6.111815110352016, 7.511618631533291, -1.5623585258915416],
+    [-8.896093395710386, 9.83139019676616, -1.8925596721918245],
+    [-12.239388243007458, 12.645416831469266, -2.2996346067871642],
+    [54.145143363747316, -37.91896479191798, 4.37965437261871],
+    [37.38582955345528, -25.804308133856974, 2.8764208144868557],
+    [28.29595419275433, -19.183752470873745, 2.050938505578924],
+    [22.28628019688851, -14.77001606453486, 1.498040348487061],
+    [17.854341941297335, -11.485206852539868, 1.0844904268985198],
+    [14.341050992257243, -8.855415224967961, 0.7515034241633559],
+    [11.402380622522172, -6.63234152830515, 0.4681147131815708],
+    [8.8349386689782, -4.668245232408836, 0.21574187235855224],
+    [6.505189217453979, -2.8650976733746476, -0.01810910677047104],
+    [4.315954755882577, -1.150387989372977, -0.24285688562013816],
+    [2.187976135216991, 0.5363903670047763, -0.46655840818669886],
+    [0.047533590996764546, 2.253110219639617, -0.6971459695735119],
+    [-2.1844753887958235, 4.063701535609565, -0.9436375388789758],
+    [-4.60577099704003, 6.049209549727827, -1.2177440886035726],
+    [-7.354869923836227, 8.326689410202093, -1.536670606632773],
+    [-10.6590243779901, 11.089720615523941, -1.9291419134314238],
+    [53.60065287639758, -38.066119646457935, 4.620884797444852],
+    [37.89086125358395, -26.604609292462797, 3.1593612519254117],
+    [29.124942378028212, -20.166606756160807, 2.3369994925900133],
+    [23.2550433170477, -15.823563691150046, 1.781364783657755],
+    [18.89693618795922, -12.57273465092981, 1.3647015456852059],
+    [15.429358365815984, -9.963210682487727, 1.029435998120937],
+    [12.523359777323387, -7.755466932941554, 0.744881279364189],
+    [9.982491200647603, -5.805652988084065, 0.4925023879108154],
+    [7.676683510540564, -4.017622537902936, 0.2597931942546287],
+    [5.510455379194608, -2.3197509111717056, 0.03730281967915532],
+    [3.4052023252456984, -0.6518564672533168, -0.18306036925966862],
+    [1.2872104463653429, 1.0438973965264622, -0.40923629583935356],
+    [-0.9230905075851396, 2.8315256095621275, -0.6501872871796014],
+    [-3.3244080983108946, 4.7922406064299805, -0.9174707369139742],
+    [-6.056412786810242, 7.0430579948619565, -1.2279464900562975],
+    [-9.346926492427878, 9.776757568304134, -1.6096021459864744],
+    [53.419785234290785, -38.446234564346696, 4.88140377040192],
+    [38.53304125216004, -27.488894845546795, 3.4480140834919104],
+    [30.020999760737837, -21.18667698372952, 2.6240654818697737],
+    [24.254768686455165, -16.889117999030702, 2.062606355644761],
+    [19.945855485411972, -13.65415655206507, 1.6402024270826232],
+    [16.50407666438266, -11.049593197025297, 1.3001624925185091],
+    [13.612878566523184, -8.84296930005163, 1.011943340370816],
+    [11.081523355450383, -6.893542030770234, 0.7569978004296299],
+    [8.782883310351048, -5.1067412493593345, 0.5227993308034095],
+    [6.623090478448718, -3.411843368247665, 0.29990205319637014],
+    [4.5243400478011, -1.7491712353035351, 0.0802411322790289],
+    [2.4130175661525284, -0.0610377984919915, -0.14408774985182185],
+    [0.20888691958437364, 1.7168138069153518, -0.3820044204063278],
+    [-2.1884077737339136, 3.6663194082368893, -0.6449997434973819],
+    [-4.921448385835634, 5.905818661709255, -0.9497964759764168],
+    [-8.221957097043251, 8.629866523825953, -1.3240290125906093],
+    [53.61582486526379, -39.08671152025071, 5.172218040226726],
+    [39.36030251663064, -28.504400651618948, 3.7538768986625377],
+    [31.037239230205742, -22.293577137778403, 2.92341614901897],
+    [25.340699934509207, -18.01747142957919, 2.3530724132373413],
+    [21.05827273342759, -14.781698595815977, 1.9225200298116316],
+    [17.624530618905396, -12.168587054354596, 1.5755562368403577],
+    [14.732624081158978, -9.950939135950552, 1.2815983320927782],
+    [12.196116997352558, -7.9901484060006505, 1.0219765332604314],
+    [9.889973655762747, -6.192618139348753, 0.7840683324864401],
+    [7.721422568166934, -4.4881566745312576, 0.5583872023268207],
+    [5.613230116007184, -2.8174458605390895, 0.33688790393598383],
+    [3.491911602900233, -1.1230147602440135, 0.11173639357945403],
+    [1.2766913284025905, 0.6594909686526589, -0.1258916252611554],
+    [-1.1345400754657313, 2.6127840801128697, -0.38739978176450246],
+    [-3.8881931627095923, 4.85721360064217, -0.6894510156459435],
+    [-7.222553281427736, 7.591099511800469, -1.059586906893897],
+    [54.2206449612718, -40.02672465084154, 5.505245645027774],
+    [40.42555170375057, -29.701341089926164, 4.088625562768407],
+    [32.228157680699745, -23.537307253728912, 3.2461610749997356],
+    [26.567271769389272, -19.25804730660822, 2.66359336261332],
+    [22.288794353137412, -16.00473818450655, 2.2224191354929723],
+    [18.845942670502257, -13.370042271794233, 1.8664620037436694],
+    [15.938841415109275, -11.130119404695876, 1.5648747573155068],
+    [13.383856878734507, -9.147425591119351, 1.298724865018507],
+    [11.057053191724972, -7.328601280070736, 1.055186893940271],
+    [8.865990715612178, -5.603399739492717, 0.8246409604596793],
+    [6.733381576061593, -3.9123723957564343, 0.5989819422933389],
+    [4.585421887608016, -2.197918987357749, 0.37039543130132996],
+    [2.3406203199735924, -0.39548033704240804, 0.13015565200917395],
+    [-0.10483399387387583, 1.5783299007957796, -0.13299565354778814],
+    [-2.9014080594309544, 3.8459569900720583, -0.4356126988484285],
+    [-6.29571273688154, 6.610706633237789, -0.8052418276047653],
+    [55.29205184470072, -41.32488786445454, 5.895317785608561],
+    [41.79579856264363, -31.14125238372005, 4.46595588603698],
+    [33.658738373668506, -24.97649390064596, 3.605036551661273],
+    [27.99733989721281, -20.667258029913942, 3.0063473299194223],
+    [23.698952362519968, -17.378414637843374, 2.5517871865412123],
+    [20.229247856849565, -14.708555879138842, 2.184653803441352],
+    [17.29244326589214, -12.435115757925676, 1.8735581913476465],
+    [14.706085050693293, -10.420414446958917, 1.5991336195880808],
+    [12.346240052861384, -8.57049647519976, 1.3482224811485852],
+    [10.119906545363609, -6.814360738681821, 1.1109546608425718],
+    [7.948888618421832, -5.091743920968635, 0.8790449865924156],
+    [5.758184488296709, -3.344203618147557, 0.6445721043054312],
+    [3.464751210263632, -1.506303968796712, 0.3987960980819949],
+    [0.9625206988043453, 0.506424644390064, 0.1305601731170032],
+    [-1.9031979489110864, 2.8185409852728056, -0.17650995921970872],
+    [-5.388135904469676, 5.638712146444763, -0.5498762138417972],
+    [56.92639078175275, -43.07093984415163, 6.362813145223167],
+    [43.56412875511668, -32.907643665018995, 4.903847358024174],
+    [35.41551756090207, -26.68822452796011, 4.0164973634568675],
+    [29.712825232421473, -22.31798973381435, 3.3968891405447614],
+    [25.367718646585956, -18.973026562723458, 2.9256555614840773],
+    [21.851650551720134, -16.252934528940166, 2.544879537208246],
+    [18.86970643471041, -13.934011252392551, 2.2222768771752324],
+    [16.23876671086626, -11.877038663953055, 1.9378304091664191],
+    [13.833650899795282, -9.9864981662706, 1.6778927403112578],
+    [11.559778345906984, -8.189831267493503, 1.4322052984482472],
+    [9.337046455629865, -6.4251559689589985, 1.1921583660595458],
+    [7.088111200780031, -4.632253334812861, 0.9495518404856303],
+    [4.726967121175786, -2.7436514408650363, 0.695419184492057],
+    [2.1436622869856086, -0.6724715569107507, 0.41847441616753445],
+    [-0.822076824869377, 1.7089942578913688, 0.10239200614686889],
+    [-4.43583763177804, 4.615080422215982, -0.28008143763924187],
+    [59.28211139842807, -45.40654875402972, 6.938017352515727],
+    [45.86940602563397, -35.1232667956485, 5.428154150200108],
+    [37.62410687533723, -28.78346204873677, 4.5039503381180515],
+    [31.831124133944723, -24.314097682984976, 3.8572170287921783],
+    [27.407381245561687, -20.888107521981887, 3.3651985634724224],
+    [23.822308528706927, -18.10014632320696, 2.9678510137186755],
+    [20.777981661160858, -15.72238532993605, 2.6315211222891235],
+    [18.088366832273756, -13.612317386851425, 2.3352542982544153],
+    [15.625520434214009, -11.67166805066393, 2.0647107405378375],
+    [13.29207087595999, -9.825357281808266, 1.8090734895917429],
+    [11.004837432636634, -8.008941882523768, 1.5592367515976362],
+    [8.682774590414523, -6.159320805353453, 1.3065161855038832],
+    [6.235096306742562, -4.205493272940166, 1.0414391438663508],
+    [3.5453579450867374, -2.0560161994779387, 0.7521909004463687],
+    [0.44419976246005743, 0.4228474291137383, 0.4219879640487982],
+    [-3.347132527309319, 3.4536348120086857, 0.023469853173994026],
+    [62.626942992886384, -48.56620707997625, 7.6694404364283955],
+    [48.93351934182326, -37.982557752559366, 6.0792864568156],
+    [40.48178677434594, -31.43559755560787, 5.103710132988619],
+    [34.53537401613143, -26.817047845515695, 4.421385615419315],
+    [29.992679702421242, -23.278177096978958, 3.903201995236007],
+    [26.311043733181698, -20.400788295390726, 3.485687756067484],
+    [23.184439814356782, -17.948899287215553, 3.133143069696864],
+    [20.420952766955345, -15.774355265517602, 2.8232706309962268],
+    [17.887889188271142, -13.774541316052739, 2.5407599422646645],
+    [15.483527623346697, -11.870609489012317, 2.274011755095395],
+    [13.120139934662637, -9.9943774341741, 2.0132080374510055],
+    [10.711306782044915, -8.078514457150625, 1.7489289126300807],
+    [8.159212742007108, -6.046664062624624, 1.4708518938674966],
+    [5.337475719137432, -3.7999903189641504, 1.1661005770855954],
+    [2.0619502399261527, -1.1939968231441123, 0.8165513136121233],
+    [-1.9681220840818603, 2.0093815350895414, 0.39344506367915183],
+    [67.44100278229446, -52.9660215062337, 8.641675195207236],
+    [53.140540703324994, -41.820411418554656, 6.926323692379513],
+    [44.32633078240326, -34.94065047142051, 5.877533537982408],
+    [38.13836120051199, -30.102107230292305, 5.147332055070882],
+    [33.42252909588929, -26.40724338999799, 4.5956312070002605],
+    [29.609504592402303, -23.413296633036378, 4.153503127889702],
+    [26.377757716716598, -20.8701640431232, 3.7821536596278076],
+    [23.52519677867597, -18.620578307235256, 3.4573273221782657],
+    [20.911537030219314, -16.55529860143087, 3.1623326312127333],
+    [18.428519136984374, -14.589960191692311, 2.8844707341046596],
+    [15.981849762449293, -12.651039424187264, 2.6129174553502295],
+    [13.477482267620125, -10.665190970222715, 2.3371864873994737],
+    [10.807577896001504, -8.548320100109942, 2.04566002793328],
+    [7.831219435014043, -6.190497327419043, 1.7237038795252266],
+    [4.341462522877194, -3.4299075938474535, 1.350616909826677],
+    [1.2362519353041057e-12, -7.836085247576144e-13, 0.8938140051494035],
+    [71.39342668925745, -39.56369013255039, 0.5469199052325865],
+    [23.882426609298296, -7.723397565110661, -2.6861261710857485],
+    [9.162731641321297, 2.035326845232639, -3.5913799098050863],
+    [1.6549215618052742, 6.918665157753319, -3.9748017032944083],
+    [-3.1075601570555382, 9.999458816404148, -4.187351548914594],
+    [-6.562829977552438, 12.258607087543355, -4.336551650435658],
+    [-9.32647788580561, 14.11231269641483, -4.46566934080492],
+    [-11.71792677948861, 15.77678072780967, -4.59653259388063],
+    [-13.936453871990294, 17.389791988444863, -4.743612593659898],
+    [-16.12929915013526, 19.058249693609508, -4.919442398100112],
+    [-18.42569221786146, 20.883198411533378, -5.137664512234765],
+    [-20.96632580237049, 22.98333420417719, -5.41644313410561],
+    [-23.940199724566302, 25.527135416474263, -5.783780179928515],
+    [-27.650117302522784, 28.79319191490994, -6.2881466093764695],
+    [-32.66676088930555, 33.315048570051914, -7.024590070387354],
+    [-40.29552021175639, 40.322987138560634, -8.215013946066883],
+    [74.57119857722735, -46.68718583843651, 3.443224166205387],
+    [37.786805726949204, -21.945073574211033, 0.9185427020410871],
+    [23.452010424210158, -12.027021900412567, -0.1844704408084669],
+    [15.032770578786973, -6.073013827312932, -0.8811167811201711],
+    [9.245782259272923, -1.9120554495661297, -1.3796993987053208],
+    [4.877390010616024, 1.2783945823453742, -1.7675420822413566],
+    [1.3450291242366783, 3.9027121703620318, -2.09120596077908],
+    [-1.6774787026912898, 6.192478691958722, -2.3789424584809837],
+    [-4.397578044256231, 8.299235646213562, -2.6502672858348033],
+    [-6.965661755119328, 10.33739787235857, -2.9208409640235202],
+    [-9.509333557635129, 12.409351710302808, -3.2056583612397413],
+    [-12.16073684655744, 14.626998233266766, -3.522169341374661],
+    [-15.086875553357839, 17.137980778358347, -3.894502979825099],
+    [-18.538108485666356, 20.170680772888517, -4.3611509078063975],
+    [-22.960447271536783, 24.139871121723566, -4.993307981301714],
+    [-29.324329684137798, 29.957535781749915, -5.949177550025296],
+    [70.65558715871492, -45.73113007848124, 4.05355433916616],
+    [39.88936282080682, -24.7182677755262, 1.790875417178669],
+    [26.986014630085798, -15.74267540383747, 0.7824154305870874],
+    [19.229038384227458, -10.245604933891617, 0.14119417834734163],
+    [13.768777385554891, -6.303708520400823, -0.3334731629383117],
+    [9.558722356891856, -3.208233791566472, -0.7159540980036488],
+    [6.101757513217178, -0.6188220716649352, -1.0430225618700417],
+    [3.1163299273103933, 1.6614201715888401, -1.337166556793763],
+    [0.4199224145769396, 3.764139792903742, -1.6144550967403108],
+    [-2.1222518646374344, 5.790788688242405, -1.8882015723696592],
+    [-4.625733620730171, 7.83315074851485, -2.1713755263821706],
+    [-7.208262624424132, 9.99032722233697, -2.4789347833960154],
+    [-10.014561908267373, 12.390314705619264, -2.831171194176038],
+    [-13.260087430696785, 15.229746708168259, -3.2602147417828555],
+    [-17.327886486631336, 18.864175311041983, -3.8251523734723842],
+    [-23.04174473120746, 24.06609881863089, -4.655661171528849],
+    [67.23529337197567, -44.514220418756835, 4.382616288998155],
+    [40.60321320036749, -26.063280815665067, 2.2990669143180353],
+    [28.676993803880144, -17.685452964969016, 1.3307604273440534],
+    [21.38304200963298, -12.484763232743864, 0.7162952359142062],
+    [16.21427667219708, -8.739541249529607, 0.26407606607479545],
+    [12.207086136466238, -5.785469874882063, -0.10036159639370716],
+    [8.89797333343288, -3.300982773519286, -0.41339370252607577],
+    [6.026672366459058, -1.1031753456674964, -0.6961289394291837],
+    [3.425833214657364, 0.9285258158988281, -0.9630720447160618],
+    [0.9719571694327421, 2.886726841767569, -1.22607591794741],
+    [-1.441278988930711, 4.8554698407326295, -1.4966869583917428],
+    [-3.922927011235912, 6.9259544591222735, -1.7882729096201961],
+    [-6.607401729387192, 9.21617695963865, -2.1189872003161727],
+    [-9.69253660298651, 11.905901547566952, -2.5173551960498166],
+    [-13.52650084830184, 15.31780710924815, -3.035482383982201],
+    [-18.85811112885183, 20.1525725798532, -3.7875934432060383],
+    [64.60706822013063, -43.575421404827196, 4.6336223899132625],
+    [40.98418611655304, -26.996930960099018, 2.683891478223643],
+    [29.80336573314256, -19.060480826474148, 1.7382065171247658],
+    [22.841368028510338, -14.055997737513094, 1.134261233412147],
+    [17.87473786693529, -10.435457657460741, 0.691499961380625],
+    [14.015511000377884, -7.578238383857168, 0.3370448045223448],
+    [10.825855970013624, -5.176297676201113, 0.03436910866861951],
+    [8.056311683031442, -3.0520341335156416, -0.23790959051157942],
+    [5.546189507120956, -1.088605358606037, -0.49422110894403104],
+    [3.177627558714035, 0.8027372209340797, -0.7459869876384487],
+    [0.8497215209677926, 2.701765420929065, -1.0040521680886667],
+    [-1.5409081989183464, 4.694712947812329, -1.2808093421547104],
+    [-4.122145739188944, 6.893285485349191, -1.5930265118417244],
+    [-7.083327145260937, 9.468282559756059, -1.9670778703015712],
+    [-10.757537149089536, 12.726312970091845, -2.4511049973734123],
+    [-15.860286920405295, 17.333451706377996, -3.150684315859758],
+    [62.69888075572547, -42.98895521872921, 4.866967016000144],
+    [41.33305431760314, -27.816948834343535, 3.0182056075841435],
+    [30.739584990501502, -20.220414105483414, 2.0859638242767233],
+    [24.028324338718214, -15.35425963085559, 1.484656536455666],
+    [19.203914192219464, -11.812237442691305, 1.0436797708436358],
+    [15.443595936346217, -9.012500681330753, 0.692106267525397],
+    [12.33363444891283, -6.660638101689163, 0.39377045329462906],
+    [9.634773169326605, -4.584440738666784, 0.12724111554438858],
+    [7.191061491640494, -2.6693834383623734, -0.12203771496748714],
+    [4.88739581654953, -0.8281719555724643, -0.3655103653276766],
+    [2.625287152574353, 1.0172860945453737, -0.6138295613209953],
+    [0.3041173462291737, 2.9508307019701796, -0.8789190620499723],
+    [-2.200617309767371, 5.080757302909277, -1.1767226982900227],
+    [-5.0742728499276035, 7.573105924396113, -1.5322619141828029],
+    [-8.646024624653352, 10.727999457998902, -1.991398229116684],
+    [-13.628074802577395, 15.202024584861089, -2.6553155714315197],
+    [61.42780422371089, -42.761322147267045, 5.109943300160731],
+    [41.779475608870925, -28.65569637501779, 3.3355430793838394],
+    [31.649857657698842, -21.320319756432017, 2.409885185112364],
+    [25.129366768733753, -16.55137790910221, 1.8062553439962226],
+    [20.405020626121498, -13.056742402887917, 1.3623582413183888],
+    [16.7080795780267, -10.286854777310152, 1.008954498104665],
+    [13.645469061852552, -7.959129727323422, 0.7102587106398015],
+    [10.987301685342741, -5.90654986566149, 0.4449261688418621],
+    [8.582369945908585, -4.017178827077996, 0.19842446646904857],
+    [6.318081442430025, -2.205111829567845, -0.04067032098327003],
+    [4.0973214115785, -0.39312667867410134, -0.2829444518058151],
+    [1.8203672384757676, 1.5017690479080155, -0.5401300351416201],
+    [-0.6365996282390155, 3.586730172851008, -0.8277558487257335],
+    [-3.4589778850612114, 6.026466759012171, -1.1700935597174256],
+    [-6.979683291224211, 9.121105285159503, -1.6118148371163519],
+    [-11.930130390372126, 13.535930880675954, -2.2525684437059676],
+    [60.74227202081599, -42.89663522807989, 5.3795829599216765],
+    [42.40150100544584, -29.593545644126618, 3.656473817353265],
+    [32.63115065865418, -22.451551691694046, 2.7310492507278505],
+    [26.251197795031846, -17.745634177907345, 2.121091368372808],
+    [21.593266530024295, -14.274102509727776, 1.6708193140455407],
+    [17.93231455075785, -11.513307451186629, 1.3122304280947412],
+    [14.891998427507971, -9.190055688851325, 1.0097418525664505],
+    [12.250007937966013, -7.141415534133756, 0.7420164513940474],
+    [9.859088598192008, -5.257596797490658, 0.4945362931811171],
+    [7.608938007211796, -3.454046607959638, 0.2559450617361176],
+    [5.403716021369584, -1.6544458292779325, 0.015775485840511423],
+    [3.1440167584248755, 0.2237316662133281, -0.23754189760618638],
+    [0.7050593211078816, 2.287765099046388, -0.5193097768256704],
+    [-2.101994905210614, 4.703708156916936, -0.8534692674996731],
+    [-5.620719095995136, 7.777165179997852, -1.2843347863452956],
+    [-10.620716362215779, 12.196906798752162, -1.9121355398709992],
+    [60.626281461225304, -43.41301590316752, 5.690228907407077],
+    [43.26432511335405, -30.695744119981466, 3.997209801608357],
+    [33.75889555034535, -23.68425330907949, 3.0653958929005416],
+    [27.473347843174963, -19.00951300049245, 2.445214359095257],
+    [22.851826027648077, -15.539482585561558, 1.9855245523209144],
+    [19.203471587055827, -12.77027425345539, 1.6190040301830493],
+    [16.16475444219318, -10.435514257387261, 1.3100178825750772],
+    [13.518858251107865, -8.374942459830827, 1.0370747071542337],
+    [11.121128835038691, -6.47996429349378, 0.7855480857691638],
+    [8.86259347134776, -4.6666999225202135, 0.544060319120571],
+    [6.648050508809086, -2.859315703114563, 0.3022231868272948],
+    [4.377978108013098, -0.9755258662459353, 0.048637507761062564],
+    [1.9258582935149946, 1.0925313073689265, -0.23178692583832866],
+    [-0.902907516643344, 3.5138817951059385, -0.5628454133197761],
+    [-4.468748201347037, 6.604170968894731, -0.9890866911780086],
+    [-9.597412669298064, 11.089070780774932, -1.6130223266194084],
+    [61.102154972969494, -44.35139912286421, 6.057462962325801],
+    [44.43914300011009, -32.030187558723064, 4.373710879440438],
+    [35.108196660167195, -25.08683452208976, 3.428076506011151],
+    [28.87135906729639, -20.410897211917142, 2.7933960064659455],
+    [24.25693549723345, -16.920999316816857, 2.3211911312445257],
+    [20.59916374679321, -14.126893793227245, 1.9441579587334683],
+    [17.543391578555326, -11.766328204417086, 1.6262937178939623],
+    [14.876110476635596, -9.680168320865722, 1.3457498453887053],
+    [12.453717811880976, -7.759877860668897, 1.087623329159774],
+    [10.167209669638412, -5.9212209321753875, 0.8403504435063963],
+    [7.9206737063955615, -4.087824041352145, 0.5934560499129311],
+    [5.613156194360692, -2.17667787099589, 0.33557032386873703],
+    [3.115193847235491, -0.07855302654612872, 0.05174469414297732],
+    [0.2247296004961872, 2.379555537499367, -0.2817060035847319],
+    [-3.4405179922612033, 5.526658031578609, -0.7098471750141159],
+    [-8.779891449804262, 10.137809103587108, -1.3387970002125589],
+    [62.23918089909915, -45.78639557423949, 6.501454217113859],
+    [46.018633446466076, -33.681535347236355, 4.804811998336991],
+    [36.76949835941884, -26.74003513568091, 3.836506950689728],
+    [30.532836467298374, -22.027456300506028, 3.1822502389968306],
+    [25.894567739678322, -18.49467700332482, 2.6940388689765773],
+    [22.204853981273228, -15.658655381877823, 2.3037840211613765],
+    [19.11376699058817, -13.258295511667228, 1.974720317701651],
+    [16.408755119685225, -11.133871253286237, 1.6843944018924322],
+    [13.945589275418614, -9.175643434890489, 1.4174286198203532],
+    [11.613702334295272, -7.2978393285579655, 1.1618746593779217],
+    [9.31474546052633, -5.422198557339612, 0.9069367199099273],
+    [6.944156445975385, -3.4632985817188615, 0.6409852525996229],
+    [4.366834663778586, -1.3085229902230582, 0.348897954851841],
+    [1.3702839286607658, 1.221127509584294, 0.006884628910804188],
+    [-2.4541184323751786, 4.4707159764778055, -0.4307181171450434],
+    [-8.095560468132122, 9.275649753343767, -1.0744087685945767],
+    [64.1737183322564, -47.84538881652394, 7.051438005506941],
+    [48.137427952343245, -35.76924247180474, 5.316034425424707],
+    [38.86744492147173, -28.753484968209058, 4.313859804626602],
+    [32.57561192144684, -23.962626402283508, 3.6336191202170607],
+    [27.87845609387479, -20.360341284018265, 3.125173183902679],
+    [24.132068341171568, -17.463492869368537, 2.7186209887166144],
+    [20.98653716449321, -15.008652769014184, 2.3759190707069155],
+    [18.227568959351512, -12.833469594679647, 2.0736924430284693],
+    [15.708403688553505, -10.825540443344368, 1.7958478395794852],
+    [13.315203077714779, -8.896224005052417, 1.529821465052568],
+    [10.945229426979655, -6.963835364076272, 1.2642226365849774],
+    [8.48761100447747, -4.938409164945753, 0.9867819709108318],
+    [5.797479226498458, -2.7007176457297586, 0.6816452081036495],
+    [2.6457863949916542, -0.061155318767577786, 0.32419617713975146],
+    [-1.4101037120835915, 3.3465707486580123, -0.1323216179832894],
+    [-7.464552369396281, 8.42782273797749, -0.8029475016583657],
+    [67.15264891446567, -50.74660418590608, 7.753676672401387],
+    [51.007937072043205, -38.478555267288264, 5.945965994205942],
+    [41.59242956501245, -31.293087653578006, 4.894749413553326],
+    [35.177254712730466, -26.3713211324732, 4.179954674835789],
+    [30.378740785819186, -22.666657007835727, 3.645860349754113],
+    [26.546864881727718, -19.686734812655455, 3.219338328901762],
+    [23.325893603386053, -17.16131957753558, 2.860349752568573],
+    [20.496418634861424, -14.922823909077609, 2.544164694646556],
+    [17.906803175070316, -12.854348208223458, 2.25365862752163],
+    [15.437897466154773, -10.862775920577516, 1.9753659610293866],
+    [12.98030162368014, -8.861199043909561, 1.6969736614263637],
+    [10.41366243290722, -6.752596800050256, 1.4051136250310894],
+    [7.5780921242208645, -4.4069794129526505, 1.082452838325179],
+    [4.218394779683441, -1.616615852445427, 0.7022493150915319],
+    [-0.15856559870160858, 2.0184919774733494, 0.21476850831588748],
+    [-6.774603577469296, 7.488433117792241, -0.5003057408873965],
+    [71.63057140077264, -54.882191449540706, 8.688156615938208],
+    [54.99427620658623, -42.124018327477145, 6.759196430262451],
+    [45.2645695343477, -34.636308598691336, 5.63664433309455],
+    [38.634478259585734, -29.511510526377275, 4.8750722646385],
+    [33.679468107267496, -25.661233853446404, 4.308052694010482],
+    [29.72697825028801, -22.57105291698579, 3.857090602989096],
+    [26.40737971334646, -19.957571591749737, 3.4790698038416275],
+    [23.491492164223764, -17.64428396273058, 3.1472648777710805],
+    [20.81961793259183, -15.507270495754955, 2.8430708484074088],
+    [18.2646082845869, -13.44681008300548, 2.5517324072609497],
+    [15.70749887071019, -11.36847525234757, 2.25958889588719],
+    [13.01447789798527, -9.165045073284062, 1.9515888252385059],
+    [10.003993574997661, -6.690509373063542, 1.6079513240406513],
+    [6.381599310321467, -3.708626815008226, 1.1979346101622244],
+    [1.574595277118075, 0.23728208946344537, 0.6648737540375764],
+    [-5.8203381368712765, 6.2631050100929455, -0.12231476371356048],
+    [78.51022131311296, -61.02217452184906, 10.009056399091554],
+    [60.78866488101574, -47.300640452290224, 7.87699991660717],
+    [50.485437342511716, -39.30311873520932, 6.64685533102745],
+    [43.50437871203813, -33.866726104757916, 5.819673253685755],
+    [38.31622099742177, -29.81046751357924, 5.20955861524627],
+    [34.199932193619006, -26.577112259924075, 4.7290189958506295],
+    [30.759307907044796, -23.85993448419976, 4.330059025733146],
+    [27.748199683459095, -21.467742918838418, 3.982924748310987],
+    [24.994062467043598, -19.26578319539254, 3.666839576365547],
+    [22.357836526447187, -17.144642052680737, 3.3651918709216364],
+    [19.70686490454137, -14.99914457227273, 3.062373746368741],
+    [16.88826239811817, -12.707493627867134, 2.74083202400612],
+    [13.689414555380793, -10.100309034815814, 2.3769654593975433],
+    [9.757866917834365, -6.898052714275809, 1.9332976039373502],
+    [4.396035848717428, -2.551204744510137, 1.3396788315658688],
+    [-4.108151350252277, 4.284522332772383, 0.4368217874971951],
+    [89.85285138917477, -70.91566406476535, 12.063573542128152],
+    [69.91849709420396, -55.31806750263666, 9.565375269130367],
+    [58.573269767699884, -46.431408254684136, 8.159902090816168],
+    [51.012818119108154, -40.500179091045254, 7.235150196821153],
+    [45.47967321096398, -36.15088049412789, 6.5678896101481925],
+    [41.15544214904946, -32.74382051699807, 6.05447880598612],
+    [37.59424374445516, -29.930235435627026, 5.638659744481044],
+    [34.520538669364306, -27.49428102632862, 5.285855563257302],
+    [31.741295653783045, -25.28437702281908, 4.972055115484598],
+    [29.09979706223999, -23.177078785482564, 4.678036395273899],
+    [26.443794607190778, -21.052160188035582, 4.385501961473597],
+    [23.592902863115707, -18.767126497826208, 4.073321807877706],
+    [20.288203981121534, -16.117947493387568, 3.7120430513340676],
+    [16.086964228975887, -12.757301040447032, 3.252970886242996],
+    [10.086225843023808, -7.9801066783556065, 2.600927080124249],
+    [-3.251502369025553e-14, 2.3786817521603493e-14, 1.529481360760704],
+    [89.62487875051723, -51.1833973009326, 1.7935241594770581],
+    [29.52938364751345, -11.613409547645908, -2.0771673686672525],
+    [12.617988622202926, -0.38275859235437454, -3.170945448817772],
+    [4.243269615948184, 5.104522909146412, -3.640768711414418],
+    [-0.9832957683734466, 8.5231097384643, -3.905425863990518],
+    [-4.734136747507239, 11.010362305184266, -4.092012302034563],
+    [-7.712377647753469, 13.042564047213677, -4.251883758849525],
+    [-10.278035898961052, 14.865481100621695, -4.411175290100065],
+    [-12.651020910837012, 16.63430844070816, -4.5871857277450045],
+    [-14.996511639572386, 18.473586823955117, -4.795575439870016],
+    [-17.468977481941344, 20.510603741702628, -5.054796178257566],
+    [-20.249249213859603, 22.907769963564807, -5.391202003416829],
+    [-23.601627383517318, 25.917449841571692, -5.848690032620287],
+    [-27.998961663815127, 30.00782654774766, -6.511792450467561],
+    [-34.48780413712477, 36.2389127836497, -7.575805921317212],
+    [-46.22152980563444, 47.87304757314261, -9.650341695983728],
+    [86.73048196476377, -54.302879265269524, 4.25836286672225],
+    [42.05094877580359, -24.590794990512137, 1.261605193393658],
+    [26.328073732095095, -13.833497549455622, 0.08346152785985195],
+    [17.367295003173986, -7.551909776683992, -0.6434513985176029],
+    [11.290851172300151, -3.206545952527671, -1.161505809301134],
+    [6.744949856570962, 0.10720534986591136, -1.56410982813535],
+    [3.0937165580409443, 2.825729564120291, -1.9004847235809204],
+    [-0.015252115497306212, 5.197800670534218, -2.2006570712223885],
+    [-2.8054635235686605, 7.387374647693089, -2.4857544423202347],
+    [-5.440339557375539, 9.52138430973934, -2.7733973563437444],
+    [-8.061711501951212, 11.718817792775196, -3.0814873688372604],
+    [-10.820515366080489, 14.117430301741, -3.4322987156813474],
+    [-13.917013002445332, 16.913635986994997, -3.8592892156615437],
+    [-17.6786837342978, 20.446596158079604, -4.422192909527013],
+    [-22.757978931042526, 25.427764878004023, -5.249392070702996],
+    [-30.771510420425805, 33.78492195167377, -6.699185131198561],
+    [80.07731379757996, -51.69656621022148, 4.74746152444696],
+    [43.718178671370524, -27.076170133552704, 2.100051288916607],
+    [29.611518143867, -17.34360049875379, 1.0117612142394337],
+    [21.39047153596822, -11.559716606616721, 0.34193292851828705],
+    [15.70682036931005, -7.478162968382391, -0.14589370462444837],
+    [11.375674204875835, -4.300616290293523, -0.5362675023805473],
+    [7.850339968911187, -1.6548290489218045, -0.8694271347354613],
+    [4.828184822964815, 0.6702401879675401, -1.1693560636655036],
+    [2.1158286753232702, 2.815072945538405, -1.4532055914210202],
+    [-0.4284162685572889, 4.889230712423497, -1.7355290097904457],
+    [-2.9260296727493142, 6.994986778793333, -2.031161018710824],
+    [-5.502342389870735, 9.248668117361355, -2.358340649005101],
+    [-8.314735057273941, 11.810942203411454, -2.7437784568850825],
+    [-11.600279199034906, 14.947175770434832, -3.2334882366224],
+    [-15.77045161211477, 19.178365876408584, -3.9213700519413943],
+    [-21.437694096385034, 25.687909809544077, -5.038092870160865],
+    [75.04484810980942, -49.514740456282425, 5.006011308427344],
+    [44.18509942091874, -28.285849052603417, 2.602059882414615],
+    [31.200090333004535, -19.22291217261633, 1.553981021551543],
+    [23.47500071553737, -13.744302403550996, 0.9076590994077444],
+    [18.098891529847503, -9.862771010871402, 0.4404076525740041],
+    [13.988483646052675, -6.83510952354191, 0.06823969738796888],
+    [10.632846525402448, -4.30770708966704, -0.24926218830125707],
+    [7.750219137848001, -2.0821137304415815, -0.5352564469000559],
+    [5.163312439990407, -0.029005620249200406, -0.8054779095689623],
+    [2.744399378037225, 1.9507200686168162, -1.0728291011397637],
+    [0.38623298627774033, 3.9482149167993468, -1.3502020108218857],
+    [-2.0184137929099886, 6.065425196651747, -1.653201718410424],
+    [-4.597890726051265, 8.440105549531602, -2.0042932467173586],
+    [-7.53065145600532, 11.292593678259005, -2.441269552706297],
+    [-11.070446427842818, 15.028844262363972, -3.0378869181086436],
+    [-15.134478052928836, 20.369859350100675, -3.950497861290216],
+    [71.41084036765065, -47.98405227679422, 5.216667883395895],
+    [44.420456341449196, -29.156280622961827, 2.9909771277532835],
+    [32.29122494378042, -20.591771873721168, 1.966715073868946],
+    [24.923533833018436, -15.316903367567662, 1.3287307576520306],
+    [19.75600663067283, -11.557859375522051, 0.8685864293463702],
+    [15.796344866206216, -8.624232869940732, 0.5046132700608095],
+    [12.56488620474253, -6.1793305500797215, 0.1965616987466807],
+    [9.79280100210036, -4.03115331912035, -0.07893451622893888],
+    [7.310183452304966, -2.0542608447471564, -0.3376094789997994],
+    [4.9958936942820005, -0.15376179675307375, -0.5919518693281902],
+    [2.7504517898697123, 1.7558367853998755, -0.853973370361886],
+    [0.4774266445546539, 3.7682101401793724, -1.137793151934647],
+    [-1.933794311790475, 6.007521843034952, -1.4633263950307012],
+    [-4.626035004929932, 8.66776717038217, -1.8634568821819604],
+    [-7.758444205981304, 12.089857492908806, -2.400407145023943],
+    [-10.82836033388339, 16.740698613858523, -3.1906769277419644],
+    [68.87299768466937, -47.042450756966744, 5.431608013083759],
+    [44.703090766051716, -29.968178030467083, 3.3369178268539357],
+    [33.24199617291978, -21.784956572263454, 2.326883498236336],
+    [26.143473853995257, -16.65334450619058, 1.6897390632042741],
+    [21.12136364678471, -12.969744279108719, 1.22932788263654],
+    [17.259259886597818, -10.088615814969613, 0.8663272087571466],
+    [14.105423278851573, -7.689066808984462, 0.5610013224428441],
+    [11.403366623936673, -5.585805573928814, 0.2900793564192335],
+    [8.989459234309534, -3.656704642534896, 0.037837472142393],
+    [6.7465150228080715, -1.809060242366103, -0.20813504494673737],
+    [4.578811506820444, 0.04017142343232797, -0.4595508970538247],
+    [2.395148533021981, 1.9808091237409406, -0.7298319638146208],
+    [0.09377374800207303, 4.13030052778406, -1.0375233356836802],
+    [-2.4505595444432275, 6.669905517735237, -1.4128392939723058],
+    [-5.351802440415685, 9.911623455406847, -1.9121902361596994],
+    [-7.897079472863778, 14.215798459914689, -2.634655680838938],
+    [67.23665601066851, -46.63176454710568, 5.674793771840514],
+    [45.157039372468354, -30.851161668771987, 3.673559693176912],
+    [34.215128543625816, -22.955581138669743, 2.6698453490127014],
+    [27.318331796450188, -17.92219225774237, 2.0284561461147477],
+    [22.396305549878385, -14.281537608099583, 1.5631413044255558],
+    [18.59409570495444, -11.424416679434737, 1.1964594337448184],
+    [15.482920352892979, -9.04287812345974, 0.8890621797418107],
+    [12.816705182935749, -6.95732308163019, 0.6177747252547614],
+    [10.43740424265543, -5.048707372223091, 0.3669538948962575],
+    [8.23129817921833, -3.226359894595707, 0.12431914305848447],
+    [6.105112502941866, -1.4088312838710153, -0.12163636870429856],
+    [3.9697457027735954, 0.4921249835591804, -0.3839894155299023],
+    [1.725862048776494, 2.5919647645617534, -0.6806624542377628],
+    [-0.748369478715411, 5.069369398791358, -1.0407564122321318],
+    [-3.561377315299079, 8.235037821716414, -1.5189137139248319],
+    [-5.962475449840101, 12.458700977422511, -2.213030634404609],
+    [66.39584759971756, -46.7265197126115, 5.962583198808216],
+    [45.863949704627686, -31.89024156897101, 4.022932562552507],
+    [35.313642006612625, -24.20002953855985, 3.0177258601823183],
+    [28.56035849592112, -19.225780127445464, 2.367619083294511],
+    [23.701039721682164, -15.601630429179096, 1.8937758783428535],
+    [19.929181232827123, -12.746622146159789, 1.520000155219146],
+    [16.83390284039963, -10.362558138761964, 1.2070575202521052],
+    [14.176942426935613, -8.273845003800956, 0.9316996022187863],
+    [11.804195773705038, -6.36343664129875, 0.6782437991942919],
+    [9.60424004130261, -4.541947538957124, 0.4344648384975931],
+    [7.485214013956021, -2.728913881164944, 0.1890185524997121],
+    [5.358438054952347, -0.8366339906594245, -0.07092218806605521],
+    [3.1228616385769667, 1.2511034377638268, -0.3630062795711727],
+    [0.6490999304341369, 3.717849044758318, -0.7162375545878543],
+    [-2.1994370183306846, 6.89555069968444, -1.1865093864071345],
+    [-4.792096817731199, 11.266397758801459, -1.8837943719787333],
+    [66.31529536096774, -47.33807362542661, 6.310995434882429],
+    [46.90133651668624, -33.16219984295611, 4.403936534891017],
+    [36.62597051259141, -25.59948742065438, 3.388748101549283],
+    [29.961195840636353, -20.646538283860213, 2.7252522266168056],
+    [25.13009768494128, -17.01429974081834, 2.239454639431416],
+    [21.362610928757768, -14.142231441856817, 1.8556391831676609],
+    [18.260810638963036, -11.738613009142798, 1.5343355246512382],
+    [15.5915322482128, -9.62999593914215, 1.25199292160419],
+    [13.202643263362184, -7.699929457977629, 0.9926917270071004],
+    [10.983206568634571, -5.859000127883, 0.7440636283086007],
+    [8.84085671327779, -4.02636547461593, 0.49472648282293785],
+    [6.685091417607734, -2.113373251083775, 0.23191406779972318],
+    [4.409682710824996, -0.0009011812093941362, -0.061971091904133],
+    [1.8691200461792161, 2.5048685338903405, -0.4164227456770359],
+    [-1.1311193853338248, 5.7762884740984175, -0.8908373761033025],
+    [-4.239961072397491, 10.510383426089234, -1.6193410473720247],
+    [67.0272063233514, -48.52132395047896, 6.74018294853914],
+    [48.364472319574666, -34.75598349786274, 4.83700326154641],
+    [38.24980062649774, -27.241495920696224, 3.8019728077087556],
+    [31.617342430687742, -22.26990515618045, 3.1196748777133037],
+    [26.779583875210264, -18.604185276869426, 2.6182132378416685],
+    [22.991227994726763, -15.696270511656182, 2.2214319674867604],
+    [19.862261635686075, -13.25742345584765, 1.8891897509298057],
+    [17.16179936651633, -11.114334742784433, 1.5973580203186415],
+    [14.737610976286213, -9.149629501593283, 1.3295490009721862],
+    [12.477229543074058, -7.272354783906722, 1.0730051044932714],
+    [10.285327893266905, -5.399447736279563, 0.8160020247349662],
+    [8.066188365867168, -3.438833272366091, 0.545445572481251],
+    [5.70342781511431, -1.2650793177268265, 0.2433284791337781],
+    [3.0265262696274045, 1.3314464432645392, -0.12090265641925632],
+    [-0.24480262840960115, 4.780674103172371, -0.6118138880881739],
+    [-4.213376700746544, 10.1051302052355, -1.3995672249588331],
+    [68.64494056075701, -50.39116799079157, 7.279337099924678],
+    [50.38942610063262, -36.79324307540858, 5.348531766129775],
+    [40.31422931670034, -29.239455896171986, 4.281443980142567],
+    [33.65211181838855, -24.203644433189364, 3.57361016925283],
+    [28.7694891486657, -20.47593356295091, 3.052086882390709],
+    [24.93362712430299, -17.51205576991954, 2.6391245544893738],
+    [21.75691143193167, -15.022272368981813, 2.2933499096437533],
+    [19.00762200853589, -12.831117337648584, 1.9897113102693542],
+    [16.531187958922754, -10.818601758242027, 1.7110804505096273],
+    [14.211469551152998, -8.89059304556023, 1.4440317705523467],
+    [11.947490461260388, -6.95974760248973, 1.1761426165499544],
+    [9.63419309618196, -4.927451059535209, 0.8934638392359489],
+    [7.137933739710165, -2.6567735594110484, 0.576760270202526],
+    [4.250153154635482, 0.08659999387790945, 0.19324740978223354],
+    [0.573280152631412, 3.8097019923217132, -0.3286206950481495],
+    [-4.651114398173386, 9.987637770621058, -1.2072376108236125],
+    [71.40346596696236, -53.15928019585443, 7.974854581841599],
+    [53.190229550899836, -39.460458336454046, 5.977530461146958],
+    [43.012846172289876, -31.761214489949538, 4.862116628685797],
+    [36.24686964256597, -26.60490332532377, 4.119830837228696],
+    [31.274303680208508, -22.78077495042337, 3.5726840734663847],
+    [27.36086625754369, -19.737902862034336, 3.139775845543084],
+    [24.114741846208194, -17.180589210565515, 2.777731577093433],
+    [21.299617519861222, -14.928349259655432, 2.460112530198355],
+    [18.755939404177035, -12.856539548004301, 2.168703071911214],
+    [16.361477092741005, -10.865961266308377, 1.889076100837167],
+    [14.00663901178652, -8.862675908712585, 1.6076954578726037],
+    [11.57235994238758, -6.737925264369511, 1.309119960448574],
+    [8.898854167059653, -4.336566206779042, 0.971751166275386],
+    [5.720218483698242, -1.3855671258176185, 0.5584138989189443],
+    [1.4745851922089355, 2.730599305356396, -0.013134203885749193],
+    [-5.502007410193089, 10.097287512528924, -1.0231694923789751],
+    [75.75712432217142, -57.21778530791137, 8.907303075669143],
+    [57.13389693963806, -43.072965096878875, 6.788607812314266],
+    [46.6685297488253, -35.08463164136359, 5.601261878072555],
+    [39.70125410163539, -29.732063463690455, 4.811899205757531],
+    [34.58147441297591, -25.767006771438226, 4.231713211281529],
+    [30.554798546748295, -22.617613604591966, 3.7743367441852897],
+    [27.216341987988663, -19.975350596309383, 3.393246794492102],
+    [24.32018190945965, -17.650788060578574, 3.0599429992291007],
+    [21.698338146252773, -15.511912347735976, 2.7546401304623953],
+    [19.219468959110003, -13.452064133322978, 2.4614706991299924],
+    [16.761816302477577, -11.367896742378532, 2.165261237683608],
+    [14.186774017436383, -9.136160521338942, 1.8482770388531595],
+    [11.297539837248816, -6.575078853715898, 1.4850559783264765],
+    [7.744518999798367, -3.35297621576485, 1.0306962057053037],
+    [2.7240207184557144, 1.3100097257231977, 0.3841562999030095],
+    [-6.686436796748277, 10.339525980773516, -0.8175137078647201],
+    [82.62693821351091, -63.347528979218694, 10.232540986350372],
+    [62.918385127679116, -48.22617534872549, 7.902458555846975],
+    [51.885482206730366, -39.72764458787224, 6.605085062983657],
+    [44.574514880813425, -34.0662172487799, 5.749285905263237],
+    [39.22928194593932, -29.898891479997857, 5.125751761690178],
+    [35.046747498082276, -26.610369086013947, 4.638766065520267],
+    [31.595370352823302, -23.868494419902742, 4.2367671756216305],
+    [28.61204942210889, -21.468861278577368, 3.888116804965258],
+    [25.9153924023377, -19.268101743452064, 3.57073195326935],
+    [23.360788189041124, -17.148772360226644, 3.2666798173756475],
+    [20.809598793329396, -14.994360074650684, 2.9584284027463155],
+    [18.096405799297496, -12.661357418595374, 2.6248438732507537],
+    [14.972916728255402, -9.929972678323487, 2.234468812953232],
+    [10.969272941376, -6.381496829383532, 1.7295961241570104],
+    [4.915277247202563, -0.9772297201254518, 0.974892057542504],
+    [-7.980364998791837, 10.476942865859757, -0.5245061702314605],
+    [94.13417102450993, -73.33311103900061, 12.302245682585811],
+    [72.08191042915297, -56.22777362038307, 9.582843126069495],
+    [59.98283935581064, -46.81895315973281, 8.104555141665307],
+    [52.09167029191888, -40.660099125520446, 7.149537418571084],
+    [46.4085351879739, -36.20297336251533, 6.468439928679765],
+    [42.02891663734813, -32.74637582075955, 5.948609804452514],
+    [38.47028054773976, -29.91494921847821, 5.529922546826379],
+    [35.43958857416708, -27.47907328184661, 5.175750083550229],
+    [32.73440230131741, -25.277957059467564, 4.860606365710886],
+    [30.19114787924443, -23.17891929922211, 4.563667673668858],
+    [27.648126644101982, -21.047504516022688, 4.264131796783097],
+    [24.903020788299393, -18.712142137320704, 3.9359484281864523],
+    [21.634203483828315, -15.898014552168783, 3.538372460191185],
+    [17.192799884675143, -12.053485272382835, 2.9922259274707477],
+    [9.828203203080303, -5.714349253748678, 2.0980326103144322],
+    [-8.456004568180767, 9.603331157364934, 0.07604465948847823],
+    [116.54941435752546, -92.43183104475538, 16.146552351449937],
+    [88.95573860899431, -70.75375029827921, 12.569664809296086],
+    [74.65204263399853, -59.51430497471567, 10.745164754194878],
+    [65.71966696093297, -52.491817389585215, 9.628435786005802],
+    [59.5624546380591, -47.64721734113639, 8.878280534860096],
+    [55.04956895307768, -44.09228223657417, 8.34674907517819],
+    [51.59745712781723, -41.36832050762158, 7.958003886382498],
+    [48.864920354961576, -39.20657666772629, 7.668189459845562],
+    [46.627038591005274, -37.42905486389018, 7.448911758707933],
+    [44.71055447629081, -35.89746371904391, 7.278852444097348],
+    [42.94726416479663, -34.476028591692256, 7.137841493438649],
+    [41.11672130273626, -32.985363091934516, 6.999757409880022],
+    [38.82906272070981, -31.108195801964605, 6.818314593942245],
+    [35.184636475691896, -28.11769467098456, 6.486749492503622],
+    [27.378759174418438, -21.777200062439288, 5.679224829576894],
+    [-2.7803185086910594e-12, 2.075130221910251e-12, 2.745251177315428],
+    [101.0811527066558, -58.32572027041652, 2.4847919326187933],
+    [32.548513044007926, -13.658407426875966, -1.7718780315725629],
+    [14.429265082090026, -1.6286054440762645, -2.963785060199842],
+    [5.600478421088015, 4.170741144921676, -3.476741803942054],
+    [0.14170778554263727, 7.7563688558316315, -3.7663703408611844],
+    [-3.7485860354563636, 10.350922290860499, -3.9701256383583514],
+    [-6.82008106468725, 12.462377528063689, -4.143521190800294],
+    [-9.454208121487119, 14.352068735039348, -4.314918088354117],
+    [-11.882031638965646, 16.184715660988825, -4.5031486345951235],
+    [-14.274896152355272, 18.092448594937615, -4.725298681246788],
+    [-16.793910719591903, 20.21311875727452, -5.001960465943327],
+    [-19.630792013856425, 22.728204406061646, -5.363454585684687],
+    [-23.070381250896858, 25.929353708399972, -5.862012695761979],
+    [-27.630419809813354, 30.38027287401843, -6.602833404725718],
+    [-34.455082557112284, 37.42640356268357, -7.84548255689859],
+    [-45.976110057383885, 51.14840047606931, -10.447033009114511],
+    [94.0528447885378, -58.88460140728894, 4.7368318390870785],
+    [44.33028096061837, -25.9963994315523, 1.4367274857699495],
+    [27.830486861632373, -14.767387860587071, 0.21573982891995294],
+    [18.587682759789907, -8.315982296105147, -0.5263493792799114],
+    [12.370852036200024, -3.8818973538684225, -1.0532217429888509],
+    [7.74761811588267, -0.5140663888688669, -1.4618979689356835],
+    [4.054659222015102, 2.2403139618993686, -1.8028934223908186],
+    [0.9277266599327715, 4.6378939486624775, -2.1069912919838725],
+    [-1.861756777088245, 6.847222066799134, -2.3958992740910445],
+    [-4.478273710989995, 8.998598923004721, -2.687840707094088],
+    [-7.060900517537755, 11.21428033146971, -3.0015414354640697],
+    [-9.751199117049897, 13.635897057732965, -3.360613823100178],
+    [-12.72122773339439, 16.463311943044037, -3.8007832249072364],
+    [-16.19864079346161, 20.026993914991188, -4.385154170807617],
+    [-20.306334329463056, 24.887700252721963, -5.2361906143021235],
+    [-19.19612378318558, 29.49629744892937, -6.316946742921287],
+    [85.518266393626, -55.14412707637979, 5.141322979351415],
+    [45.760166788030254, -28.332156876474645, 2.2599895337098013],
+    [30.98858254244613, -18.179952263752508, 1.1271191549524568],
+    [22.522525717584838, -12.243512886020147, 0.441881908072776],
+    [16.729623011289192, -8.093027208646271, -0.05235690162288561],
+    [12.348977741906467, -4.880935356660677, -0.44574567500514545],
+    [8.807595083627037, -2.2177968358562326, -0.7804457408619163],
+    [5.793508615052988, 0.11400082134254032, -1.0811357356400468],
+    [3.1113642450786956, 2.257524057719236, -1.365274042173282],
+    [0.6226990581456245, 4.322826740224345, -1.6475815165657879],
+    [-1.7839340416416614, 6.4105769133393515, -1.9429674081913277],
+    [-4.20995354093821, 8.631347424798964, -2.269535023753059],
+    [-6.751134843246896, 11.127455802033426, -2.652965993445255],
+    [-9.44649877990736, 14.09301969779442, -3.1334043345534193],
+    [-11.75024025798294, 17.637199652563535, -3.7618290792722275],
+    [-2.9287418988571137, 17.3315057104116, -4.1006171232890205],
+    [79.43315507187971, -52.32808990466575, 5.351137756626006],
+    [46.08595994452772, -29.46567078123403, 2.7589048888773986],
+    [32.5235700094581, -20.028395415818245, 1.667164899022554],
+    [24.572870505219846, -14.403383794308306, 1.0039461744545544],
+    [19.09516774024233, -10.453933308476925, 0.5291252288311101],
+    [14.942800798643004, -7.394467683400544, 0.15365063555565864],
+    [11.580908633223654, -4.855120415462467, -0.1649204032316733],
+    [8.718548564322349, -2.6304125274637804, -0.4506820244991589],
+    [6.177036163353043, -0.5884684116583778, -0.7197703096414576],
+    [3.8336103972830284, 1.3693607312551583, -0.9851643490074053],
+    [1.5947035837818437, 3.330395226121468, -1.2595287698883137],
+    [-0.6152529015786958, 5.386052547694806, -1.5576580089155323],
+    [-2.8442560804786337, 7.644380412238977, -1.8994000588441653],
+    [-5.016681844844523, 10.221640484424018, -2.3122428592604347],
+    [-6.190543521530936, 12.980156387391828, -2.810350649711001],
+    [7.369523611371478, 9.923962573497496, -2.764421940790385],
+    [75.16269541334538, -50.41816587338467, 5.53362567810777],
+    [46.23566919261422, -30.297767667660867, 3.1496158632584748],
+    [33.59465322506753, -21.393878673262506, 2.0829288028901036],
+    [26.016719364144862, -15.978064459641102, 1.4271494838792627],
+    [20.751641938751394, -12.150509816720756, 0.9583132134523935],
+    [16.751257487963038, -9.183425087266345, 0.5900632735725811],
+    [13.515100275825464, -6.725733186202108, 0.28029540315137863],
+    [10.766660159099189, -4.579294643418025, 0.004793851155303024],
+    [8.335416141453692, -2.6164854069747485, -0.2525643938248693],
+    [6.106006087564745, -0.7432104327929351, -0.5043375444851629],
+    [3.994338356580326, 1.1213075542659863, -0.7622201276383087],
+    [1.939608500980496, 3.0580166711270733, -1.039246210505597],
+    [-0.07849360121423515, 5.1559052691869605, -1.3520208088528842],
+    [-1.9184038884739445, 7.488965642719754, -1.721208580847184],
+    [-2.4076502912751283, 9.78847515163056, -2.142377810697899],
+    [14.18117917322372, 5.149835107125492, -1.8920341869226158],
+    [72.23321774541303, -49.25088115749163, 5.734606442757371],
+    [46.47675732759757, -31.101130545941587, 3.501189763871841],
+    [34.551349988700935, -22.603607365511145, 2.449566434154076],
+    [27.25378430191951, -17.334791405892656, 1.7938773619162554],
+    [22.136597628323756, -13.58174344723493, 1.32383200647592],
+    [18.23347848985206, -10.66491963186582, 0.9556342954695748],
+    [15.07386722371177, -8.250121957196297, 0.6477525242838699],
+    [12.394648807815921, -6.14651957820647, 0.3761182759776988],
+    [10.032433854590478, -4.230325999997091, 0.12468481827643066],
+    [7.876654359693505, -2.410127883000871, -0.11896264628397644],
+    [5.848006570187361, -0.6081236153449502, -0.36615894937505455],
+    [3.8927970787061343, 1.2519020023125158, -0.6291483941353183],
+    [2.0042027439285937, 3.250154701529218, -0.9229548256966871],
+    [0.3560282297354096, 5.441794311283599, -1.265030010136749],
+    [0.23950903034491164, 7.501233410680822, -1.6432344526968345],
+    [18.794143664943846, 1.9805014396180112, -1.290530480841166],
+    [70.37003910384821, -48.72120476478504, 5.975269976808247],
+    [46.93024770359205, -32.004250474446, 3.847540006703301],
+    [35.55629399358397, -23.810430292470475, 2.802375812426764],
+    [28.467324308159174, -18.6412329073054, 2.1415817755403292],
+    [23.450975089657366, -14.929426303040056, 1.665714906781105],
+    [19.606525985227048, -12.034064412544542, 1.2929703550781306],
+    [16.48757901544478, -9.634453774641084, 0.9821981117386624],
+    [13.841889528435985, -7.545628875964294, 0.7094097322377134],
+    [11.511952733192592, -5.647005041685492, 0.4586374467983075],
+    [9.391040726797506, -3.8493896920373363, 0.2176188312353608],
+    [7.402839356046103, -2.0768466972621025, -0.024734175935394807],
+    [5.496508836540216, -0.25499008754918157, -0.28028909786868744],
+    [3.668934346837366, 1.6943153271299336, -0.5634812833756976],
+    [2.1013523339402966, 3.8239553551918988, -0.8909248762297993],
+    [2.12058303674733, 5.804627724938729, -1.2500215343097827],
+    [21.930946282769266, -0.13611262100637522, -0.858794838448657],
+    [69.42901278962539, -48.78231912372293, 6.271153668128941],
+    [47.67993651392466, -33.09495832857207, 4.211587447335047],
+    [36.7163408014501, -25.11398822790793, 3.164173141602123],
+    [29.77320679826803, -20.00249431158715, 2.4935138121963316],
+    [24.8182918421244, -16.304291135121918, 2.008097421682985],
+    [21.001929630451937, -13.40795227254715, 1.6273688369775514],
+    [17.896236138475057, -11.002715995272343, 1.3102401410355684],
+    [15.256854440119424, -8.907532957976407, 1.0325958165089424],
+    [12.930106107192115, -7.003636842103649, 0.7783784285228544],
+    [10.811353642506592, -5.202890364190824, 0.5353331184503695],
+    [8.825426856574644, -3.4300560808710507, 0.29248820522010344],
+    [6.921481398239858, -1.610819374103303, 0.038163011195185635],
+    [5.093523589087591, 0.335037995722521, -0.24199256023351537],
+    [3.5114177123591817, 2.4700780905954502, -0.5652804453325198],
+    [3.469584085039738, 4.5034427933800965, -0.9239212651224519],
+    [24.004870637888637, -1.5085277759708577, -0.5399480767261517],
+    [69.36115800795181, -49.44009180462158, 6.639096981875303],
+    [48.810728284342126, -34.45691810212321, 4.613827416047531],
+    [38.12779357873819, -26.602135667095066, 3.554557971340068],
+    [31.270459682706903, -21.50708057809367, 2.868907638672206],
+    [26.34006246290279, -17.79624535294674, 2.3702994325539053],
+    [22.52453290178888, -14.87892656879514, 1.9785400923620242],
+    [19.408988711062168, -12.450631083075809, 1.6522021994644478],
+    [16.753935630622053, -10.332150917629729, 1.3667836654737704],
+    [14.407281491283362, -8.404946632893036, 1.1059107433673094],
+    [12.264286634527334, -6.58035352836501, 0.8570945312465655],
+    [10.248308068116218, -4.781978537538604, 0.6092003899352122],
+    [8.30491112486968, -2.933176367223098, 0.35040853709421615],
+    [6.419114505970858, -0.9478403068277035, 0.06601772518708465],
+    [4.733898454539901, 1.2556527432145483, -0.2629779622300782],
+    [4.450513009904718, 3.46157700102769, -0.637726651717922],
+    [25.232499094623332, -2.300807407189282, -0.3012455101117427],
+    [70.20470753491048, -50.757896722442, 7.102021140397225],
+    [50.432764181768206, -36.191958761937194, 5.077402355095034],
+    [39.90220445421116, -28.374083038538664, 3.994960881635534],
+    [33.06847814519973, -23.251175753751227, 3.2882442575493163],
+    [28.12468773333941, -19.500106855717288, 2.7723935537128237],
+    [24.28316481977962, -16.54187253370072, 2.3665026659284067],
+    [21.136355818655893, -14.074301042292243, 2.0283010821327996],
+    [18.446480944149457, -11.917779945728533, 1.732586841157311],
+    [16.060729697893045, -9.952306570849807, 1.4624111236771493],
+    [13.871977825978213, -8.086987131228053, 1.2047739017584633],
+    [11.799172355657184, -6.242096671725996, 0.948020326207111],
+    [9.779785406384473, -4.335427524379037, 0.6796864496968584],
+    [7.782055054048641, -2.2697291714572105, 0.3839947841801927],
+    [5.905370029099516, 0.06602224059732074, 0.03908758200514415],
+    [5.196653992363874, 2.567583070585413, -0.368754435266443],
+    [25.67684030255574, -2.5733297141877243, -0.12492617115349695],
+    [72.1025022210201, -52.87711348123828, 7.694933055919422],
+    [52.70992800024515, -38.44522725900621, 5.633470273867982],
+    [42.19320149172303, -30.56380398295913, 4.513571635659195],
+    [35.31324768705205, -25.361515304948526, 3.7780741830370657],
+    [30.31386123929255, -21.538652498777626, 3.2400763288169228],
+    [26.417713160019186, -18.517857306730967, 2.816586681454833],
+    [23.21826345742875, -15.994689679484836, 2.4638306788391207],
+    [20.475916091681505, -13.786517111550566, 2.1555063087862965],
+    [18.034630788231844, -11.76997528992793, 1.8737800455293554],
+    [15.782507277148966, -9.85010998902492, 1.6048274348294091],
+    [13.631012627573169, -7.941521333593792, 1.3360683946467191],
+    [11.50451636485518, -5.952698523648008, 1.0537681691712684],
+    [9.344758926508932, -3.768287417539996, 0.7399944389812741],
+    [7.185410810242975, -1.2331180519364835, 0.36809344909056924],
+    [5.845148144706729, 1.7050697836913395, -0.09282721421774046],
+    [25.242251484521027, -2.2889426652115947, -0.004292640433445691],
+    [75.35867296873163, -56.06779958487172, 8.475937274803055],
+    [55.90965029286842, -41.447903804936274, 6.329958634001459],
+    [45.24026894468531, -33.37761001421331, 5.153076934480008],
+    [38.22842018153846, -28.030732731806022, 4.378345985999703],
+    [33.12271034896707, -24.09723286947333, 3.8118825406760912],
+    [29.139372253877664, -20.988886357761864, 3.3667039727071244],
+    [25.865097332438808, -18.393104414893443, 2.9966011368020067],
+    [23.05411381777201, -16.12089328443777, 2.673620900929799],
+    [20.544147756354917, -14.043189797054698, 2.3786412864458897],
+    [18.21581362807937, -12.058847891178925, 2.09663026849296],
+    [15.969754636062689, -10.074165437257548, 1.8136042324444046],
+    [13.711734551056312, -7.984076818240399, 1.5138017349010482],
+    [11.345550571403841, -5.646558320672076, 1.1757007003539997],
+    [8.80956996181043, -2.8412957982899685, 0.7647063469602414],
+    [6.588834891721091, 0.7086903152979953, 0.22465951271892587],
+    [23.610416741369818, -1.283828280681535, 0.05691762114229438],
+    [80.58274698564904, -60.85089185029777, 9.550721190114507],
+    [60.509943108101844, -45.60801735478869, 7.249879977554264],
+    [49.46044087037243, -37.17236687252899, 5.986636883817004],
+    [42.2004468896307, -31.590271170042392, 5.157438832288247],
+    [36.92260615223152, -27.494892708388022, 4.553945820749574],
+    [32.81360505212379, -24.26929262175605, 4.082234449585129],
+    [29.442503419766567, -21.58426435015751, 3.69222609100569],
+    [26.55133123829515, -19.239710288080506, 3.35348961597157],
+    [23.967589582900423, -17.0976879420257, 3.0450393400955065],
+    [21.56102475875427, -15.048132969604943, 2.7501226453427994],
+    [19.21750048765818, -12.98584313555732, 2.4527375886984566],
+    [16.817763662760886, -10.787072529603801, 2.134123745028817],
+    [14.213922166522797, -8.272412056169822, 1.7672327441996],
+    [11.210205713022665, -5.127372736964044, 1.3047279997748977],
+    [7.797340950784648, -0.7390567098703233, 0.6498010484467374],
+    [20.06181569488288, 0.8233385340055411, 0.042420916213496834],
+    [89.07899839111421, -68.32401353796973, 11.136409397846402],
+    [67.47175355369603, -51.74072571646408, 8.557542837147462],
+    [55.679587095663194, -42.65242135800236, 7.1580256804239974],
+    [47.99391074182487, -36.69456811201212, 6.250100095608858],
+    [42.452341185994555, -32.36556646386812, 5.597594157456226],
+    [38.17421008645243, -28.989814795578884, 5.094421480435189],
+    [34.6933952972415, -26.207507902494395, 4.684158846535454],
+    [31.730332182620316, -23.79991289345997, 4.332538406802693],
+    [29.09647016501921, -21.615497190868915, 4.015834750608966],
+    [26.646178182858748, -19.53158734035971, 3.7148562744432803],
+    [24.245610485617373, -17.42740504067673, 3.41075636283738],
+    [21.742548300539983, -15.153967559186963, 3.0804404701240125],
+    [18.91971962772874, -12.480139220731651, 2.688516336278583],
+    [15.394014214767688, -8.955501450361643, 2.1672797316396415],
+    [10.410171917124163, -3.445565498926243, 1.3498390752360756],
+    [13.026688530388231, 4.858061884795356, -0.0771600327066306],
+    [104.13270023570237, -81.23405696277753, 13.770550435161764],
+    [79.10292806229192, -61.79802984009663, 10.644800591723229],
+    [65.86372909103689, -51.49193732394117, 9.008469642318929],
+    [57.43940913126643, -44.909254421771465, 7.978655156399593],
+    [51.50486686876133, -40.247229874365885, 7.261699256466289],
+    [47.03576862757363, -36.710241101908075, 6.7283217685211625],
+    [43.49744919780171, -33.88102647325065, 6.310907307595638],
+    [40.572910843313174, -31.509496053598205, 5.969056621952013],
+    [38.04992507746486, -29.424451686332965, 5.6752090448894865],
+    [35.76378530673948, -27.487708503588276, 5.40719015846844],
+    [33.55819515483864, -25.5607360324347, 5.142829856347141],
+    [31.24197129135473, -23.46436166535618, 4.853555389004465],
+    [28.508168148444128, -20.898895705994665, 4.491956258695946],
+    [24.708104283704483, -17.21416042735794, 3.9569653534412454],
+    [17.90788185436907, -10.41497386800007, 2.949813745805257],
+    [-0.8747741519435854, 12.478789156454233, -0.2706050392285474],
+    [137.3719576012225, -109.27455492473922, 19.340737651508753],
+    [103.04717889905413, -82.23531846841321, 14.805264706649687],
+    [86.47817768823992, -69.19192365494892, 12.658953410100123],
+    [76.65909048677277, -61.46822612766713, 11.421502036549127],
+    [70.26592020619373, -56.44544744144886, 10.648016515077822],
+    [65.92747009048428, -53.04392899759704, 10.156400654547541],
+    [62.9789680637972, -50.74064931872035, 9.85977702720201],
+    [61.072159136078774, -49.261989921914946, 9.714338358926875],
+    [60.02687469994579, -48.4668043667115, 9.69980615159705],
+    [59.769650407613256, -48.29802031525005, 9.81183659171208],
+    [60.311197092416954, -48.76466744865504, 10.060154653786759],
+    [61.745054462839406, -49.939964230238694, 10.470647279644131],
+    [64.2502209398801, -51.96013269451351, 11.09070255506094],
+    [67.98835712917452, -54.9288824814672, 11.988170720596312],
+    [71.59749525291016, -57.641014631644175, 13.0933522253747],
+    [-3.803323924494135e-12, 3.529955485535057e-12, 5.977080004848892],
+    [104.74291831933127, -60.59196458357052, 2.6958044947797184],
+    [33.431486790751634, -14.252424858743751, -1.6849143319268332],
+    [14.954312238121382, -1.987357229393117, -2.9051673976320425],
+    [5.993944728508149, 3.9019168026927953, -3.430376284879944],
+    [0.4693066672715173, 7.534709324622796, -3.7269638051251235],
+    [-3.4593385836297137, 10.158778964407443, -3.9353961671119913],
+    [-6.5551665127803505, 12.291261410062996, -4.112374629529625],
+    [-9.20561325140017, 14.19787166121075, -4.286881397791133],
+    [-11.644768538728503, 16.04598066066298, -4.478176455945995],
+    [-14.04525487978787, 17.96965744459826, -4.703724708228774],
+    [-16.568636800467406, 20.10903289227495, -4.984637426419787],
+    [-19.40661261799191, 22.64950629335457, -5.352186532040942],
+    [-22.842020038966144, 25.890314208916227, -5.860577364117948],
+    [-27.378602111030286, 30.410416627478195, -6.619610154586315],
+    [-34.03267183388194, 37.56471102326088, -7.899100683319382],
+    [-40.73198842247962, 49.3655725510217, -10.32500220425326],
+    [96.3220895700184, -60.30405325828519, 4.883720617855019],
+    [44.996700752123495, -26.406448821500028, 1.4870554087978465],
+    [28.265100929841427, -15.036465864022334, 0.2531820419367446],
+    [18.940698628891475, -8.535996037695579, -0.4932427252387631],
+    [12.684638538373054, -4.077204517982837, -1.0225008490487806],
+    [8.041053587448872, -0.6950655255719478, -1.4327274878930363],
+    [4.338780499782739, 2.067908482969646, -1.7747974454370938],
+    [1.2104860081710314, 4.470441958541716, -2.0796825905388436],
+    [-1.5732322627164672, 6.68204085692598, -2.369207601165635],
+    [-4.175948152094554, 8.833290113949214, -2.661677997295104],
+    [-6.733433191720997, 11.045990052798789, -2.975873357526138],
+    [-9.378722237032866, 13.459693686694964, -3.3353757430002924],
+    [-12.25968869004488, 16.266565661276765, -3.7754419509370174],
+    [-15.514637520861221, 19.761871247409243, -4.355931746264175],
+    [-18.72390552847388, 24.2510392317408, -5.170090473291254],
+    [-5.0532271678363925, 22.454272681781166, -5.459907724551236],
+    [87.16816420423949, -56.18986759288805, 5.26001055025699],
+    [46.35614486414595, -28.69856672792018, 2.3061508331970844],
+    [31.38746160162142, -18.42185173911954, 1.1600189112375567],
+    [22.85021077949948, -12.44095885505767, 0.4702687879720241],
+    [17.026634453776133, -8.271029595570898, -0.025771694134047545],
+    [12.633437187440133, -5.04999947925039, -0.41989708884808724],
+    [9.090033768068333, -2.3834333879141103, -0.7548318855380849],
+    [6.082064575823396, -0.05197490393869104, -1.0554385798614636],
+    [3.4142839274947456, 2.0878132107494904, -1.3392166681515343],
+    [0.9504997681884422, 4.145384127512843, -1.62084032885992],
+    [-1.4150068062745822, 6.219386802897006, -1.9150420995457669],
+    [-3.769973519031234, 8.415046465907746, -2.2394188471956458],
+    [-6.172892390263607, 10.858862813336746, -2.6180271620308377],
+    [-8.530981229857824, 13.684338405924962, -3.0842724690614864],
+    [-9.549235861369414, 16.62942257652512, -3.645039818638752],
+    [15.221006940089902, 8.313787943211173, -3.024215732337012],
+    [80.74591101853797, -53.170091378712776, 5.453829260622161],
+    [46.63937063326942, -29.809189837992236, 2.8041567314757034],
+    [32.90682544562926, -20.261536806807776, 1.6995355098967175],
+    [24.890851731314402, -14.594064484513174, 1.0314038342485234],
+    [19.38464351866892, -10.625412563631986, 0.5544333473613391],
+    [15.22169369710752, -7.557608622357327, 0.1780959474106832],
+    [11.860385197048176, -5.016180518410378, -0.1406216800820547],
+    [9.007489563652655, -2.7938209361577364, -0.42605814174398754],
+    [6.484613488500334, -0.7582933709536605, -0.6944197075637758],
+    [4.1718067925761835, 1.1881739028769422, -0.958616113651109],
+    [1.9824080649815161, 3.130200400129429, -1.2310499433212199],
+    [-0.14285128431567398, 5.152333156830975, -1.5258107015383813],
+    [-2.207963287320973, 7.342999641478095, -1.8606377246751968],
+    [-3.983520547578732, 9.746021249668331, -2.2548657111812336],
+    [-3.6664181952501393, 11.797581884037033, -2.6727713016036216],
+    [27.741673373837664, 0.018280008834351567, -1.6218338677551056],
+    [76.27490106849947, -51.14006675712057, 5.627100985243951],
+    [46.76299292123562, -30.629447787859924, 3.1953279238437924],
+    [33.97184836975924, -21.625969595014222, 2.1161957136416083],
+    [26.333348097838112, -16.169462231535054, 1.4552730801782283],
+    [21.041029037642332, -12.322612233999184, 0.9839773399189842],
+    [17.030412316846522, -9.346707093916589, 0.614583781910068],
+    [13.795190691465613, -6.886605124662732, 0.3044649364659084],
+    [11.057031344156776, -4.742614166374748, 0.029188294305339476],
+    [8.645973940474477, -2.786885121207434, -0.2274308723029634],
+    [6.449739839157214, -0.926314899640394, -0.4778783668996813],
+    [4.391689966286925, 0.916912809019569, -0.7335652204996153],
+    [2.42862279409978, 2.8162191930544167, -1.0067517895303866],
+    [0.587712054409566, 4.839256636447437, -1.3117554706888561],
+    [-0.8238924498817557, 6.982134583532686, -1.6605815347629618],
+    [0.2929375046342835, 8.522956903133794, -1.9969339458190443],
+    [36.04633013961135, -5.230875328400187, -0.7349450275472704],
+    [73.22314830148858, -49.90173788583077, 5.823414861805579],
+    [46.99113410999094, -31.42976038746999, 3.5484678336069626],
+    [34.93001926657724, -22.840351030449455, 2.484695424694871],
+    [27.57532376304658, -17.532068303124156, 1.8236691605353532],
+    [22.43171168675038, -13.75953691821256, 1.3509077804676828],
+    [18.518335953951503, -10.833298573671547, 0.9813113518154915],
+    [15.359367029740278, -8.415396203843908, 0.6728395777458888],
+    [12.690157350193694, -6.31360456011452, 0.401218508052629],
+    [10.348116592840285, -4.404050871831702, 0.1503554118943111],
+    [8.226011516183599, -2.596453530509306, -0.09207511775757803],
+    [6.252314078760397, -0.8161212750497941, -0.33711046713346654],
+    [4.391532074341097, 1.0054029390078023, -0.5962073683742973],
+    [2.685889722844454, 2.926441403709816, -0.882079920288182],
+    [1.4804960627353074, 4.922445442957571, -1.2034887624585917],
+    [3.0318938155107116, 6.2028022087478245, -1.49634205652918],
+    [42.00625367103329, -8.796501537196738, -0.1257407674088628],
+    [71.28929864176946, -49.334364097823006, 6.062976546334245],
+    [47.44390916435672, -32.33830855392213, 3.8975743145630597],
+    [35.944016554634175, -24.057532644075103, 2.8403361286385578],
+    [28.800046872229792, -18.849378175656124, 2.17397346918295],
+    [23.75758436544431, -15.117672007762852, 1.695139716215355],
+    [19.902607062144625, -12.2122277071936, 1.3207595668703769],
+    [16.783795216922993, -9.808753643378012, 1.0091718439808919],
+    [14.14749886872638, -7.720932854819868, 0.7361760089480616],
+    [11.837079252229389, -5.828109632563571, 0.4857536286509035],
+    [9.749223853153126, -4.042248849099784, 0.245731619714643],
+    [7.815523089084972, -2.2905655860568994, 0.005322050369284844],
+    [6.0035383135530065, -0.5064492644817327, -0.24656184403197676],
+    [4.359598929239122, 1.3664633375437074, -0.5220988520572679],
+    [3.238012749809392, 3.3017532163950962, -0.8294433177817583],
+    [4.952705699117637, 4.5022486245251105, -1.1049601620091505],
+    [46.73593575955189, -11.43481414264753, 0.3314764201144422],
+    [70.31662503404415, -49.384015363522735, 6.36100581183337],
+    [48.20575431935035, -33.44377662538794, 4.265839748446486],
+    [37.12188186968973, -25.37816812972857, 3.2061500561214706],
+    [30.124515536463882, -20.227374492468563, 2.5296023559681773],
+    [25.143206676348356, -16.508502867281845, 2.0409369200055134],
+    [21.315754392061688, -13.601262563131765, 1.6583294380843914],
+    [18.209536434884612, -11.191347895365293, 1.3401657991074862],
+    [15.578809938217146, -9.096329123183748, 1.0621020822398786],
+    [13.270721975541408, -7.19730899632577, 0.8080137716014727],
+    [11.183970928920706, -5.407220757349309, 0.5657170203316396],
+    [9.251168681848913, -3.6538493834787, 0.32451685822016973],
+    [7.439664964155332, -1.870424009853632, 0.07347412185610902],
+    [5.792443578160065, 0.0020909734380889274, -0.19960854042635634],
+    [4.651421106377264, 1.9488533864051942, -0.5039576341692887],
+    [6.308078473939341, 3.2143818093067495, -0.7822710209638848],
+    [50.937653046549784, -13.589320948404136, 0.7077099597253772],
+    [70.25160709755218, -50.05464995983055, 6.734564578391261],
+    [49.36382601381059, -34.8319216595633, 4.674243793275961],
+    [38.56232659314643, -26.892149886164756, 3.6021568515822135],
+    [31.650024685924024, -21.756426070238447, 2.9101612273336017],
+    [26.69224154697027, -18.023670634961935, 2.4079576164913647],
+    [22.864707412944647, -15.094400401652846, 2.0140479517726138],
+    [19.74781511430275, -12.660516807867314, 1.686448145318313],
+    [17.100619072211988, -10.541344416710821, 1.400403218828526],
+    [14.771744481350938, -8.618106802292498, 1.1394491188287474],
+    [12.659616642514747, -6.803060706879912, 0.8911401256381521],
+    [10.695075595639132, -5.022587695998788, 0.6445767072009666],
+    [8.841293919083485, -3.207107072414344, 0.3886055124011786],
+    [7.130947830436377, -1.290591240381302, 0.11050989341451688],
+    [5.875639788390174, 0.7343950750233488, -0.20115719417118738],
+    [7.273499579742762, 2.195959240495049, -0.5000044791422541],
+    [55.078402335436124, -15.527391491273002, 1.0446788788493924],
+    [71.13431099865359, -51.412234075501324, 7.2074234509253285],
+    [51.03286597431206, -36.60854327615617, 5.146761161002279],
+    [40.381180742735026, -28.70227797817957, 4.050514052436103],
+    [33.48995176814232, -23.536026388228898, 3.3367953310839775],
+    [28.516897355700316, -19.761136890933987, 2.8169022328088187],
+    [24.662018076859308, -16.78958131379897, 2.408540713632146],
+    [21.51286119330853, -14.31538561058446, 2.068832556776688],
+    [18.830037021507735, -12.157314688016314, 1.7722876466087594],
+    [16.461285226622532, -10.194979734232192, 1.5018366325805188],
+    [14.30241719810806, -8.338218113081984, 1.2444882462703013],
+    [12.279461715177462, -6.509762825016328, 0.9887707306824661],
+    [10.3466532557493, -4.633823361594541, 0.7227875225175249],
+    [8.51780872551664, -2.6316488400337645, 0.43251497393676014],
+    [7.054993528549179, -0.4617176923554432, 0.10297242717471554],
+    [7.990495387374407, 1.3301564121981175, -0.2345853297928464],
+    [59.53102547111812, -17.431661762128588, 1.3702756879808937],
+    [73.11701973906376, -53.60657167735703, 7.816425125962238],
+    [53.38550631059691, -38.92615061477403, 5.716035130863003],
+    [42.73975525527785, -30.948948278508638, 4.580701636941279],
+    [35.797383165808064, -25.69883425641604, 3.8372468158117576],
+    [30.765679845724833, -21.84935095162009, 3.294609613060213],
+    [26.854280915681993, -18.813444111468577, 2.868259450855924],
+    [23.65133024903949, -16.28249065460879, 2.5137324932392637],
+    [20.915343624896185, -14.071987458717821, 2.2043871995436626],
+    [18.490595992928522, -12.057980708341688, 1.9222293034280193],
+    [16.267843090498666, -10.14601585286111, 1.6534007186322848],
+    [14.16516458784407, -8.25278165364721, 1.3854427713475208],
+    [12.122677143496444, -6.2924175337292, 1.1050702156558556],
+    [10.125026447109484, -4.166102496950764, 0.7958212734406194],
+    [8.360032165726567, -1.7819013869545792, 0.4370493318244031],
+    [8.605009528356735, 0.4942354114777372, 0.03935690903327068],
+    [64.68748056589995, -19.459199860312296, 1.7080576163238024],
+    [76.52651082162281, -56.92674085682401, 8.623465044896447],
+    [56.706336927730646, -42.03023801720675, 6.43283907680785],
+    [45.89221280230958, -33.85073546772479, 5.237864141551043],
+    [38.80949626260002, -28.448797593557522, 4.453745781450453],
+    [33.66674408049921, -24.48456520479866, 3.881821143739038],
+    [29.66560509773041, -21.358795302425133, 3.433306138865924],
+    [26.386673413540137, -18.75404984230698, 3.061170605753365],
+    [23.581834151447413, -16.479074070009084, 2.7370443238224142],
+    [21.08880265229803, -14.403933731861418, 2.441588676500944],
+    [18.79051877045504, -12.427700628719146, 2.159683909592206],
+    [16.59372238832253, -10.458340776143833, 1.877393154404086],
+    [14.418790950478286, -8.39556388605238, 1.57926328026274],
+    [12.209257775289274, -6.111543090329824, 1.2448979633943336],
+    [10.046462502433451, -3.441193364334362, 0.8446112829477781],
+    [9.32609174153063, -0.48956856834010126, 0.3588371489762316],
+    [71.08099851795424, -21.78602740610411, 2.0844347793875295],
+    [82.02464402384561, -61.936943468661795, 9.742627676013019],
+    [61.51010115740868, -46.35941160614136, 7.3862376912383105],
+    [50.28660287251912, -37.79045273044854, 6.1003660613697415],
+    [42.94134874156998, -32.141361585514886, 5.259526351726878],
+    [37.619194332723794, -28.00912788849575, 4.649409044689614],
+    [33.48920712311791, -24.763297232202838, 4.173819050962006],
+    [30.112931761666438, -22.068638131542947, 3.7816326338163626],
+    [27.229303851709076, -19.72215720585467, 3.4418777496137474],
+    [24.66535457175555, -17.584638689043416, 3.133270658306719],
+    [22.29278165710558, -15.545896060405495, 2.838900278942474],
+    [20.003033999974143, -13.50193875560056, 2.5427201253789464],
+    [17.690319864275452, -11.332840694277728, 2.226105752482975],
+    [15.24295367900586, -8.87067537099516, 1.8626995487729456],
+    [12.594114068988123, -5.8460239006318195, 1.4086199801449881],
+    [10.566967603801377, -1.9725727007758778, 0.7963946903464159],
+    [79.59211563293756, -24.654900368893024, 2.5376413480746804],
+    [91.05077905005813, -69.84608087533967, 11.412476460253785],
+    [68.84860861242774, -52.80453360826388, 8.755523992647346],
+    [56.82504931080143, -43.536394938205596, 7.324838855392918],
+    [49.02824683879306, -37.490499900336324, 6.4014233982893405],
+    [43.43117014624121, -33.115477213992655, 5.740653944649164],
+    [39.12933142394362, -29.717181054695775, 5.233244598509308],
+    [35.64637606595886, -26.927555503586987, 4.821310240587853],
+    [32.69835066411186, -24.52388499191232, 4.4698322822587135],
+    [30.095612326442428, -22.352802407079476, 4.1546704005222885],
+    [27.69418356704358, -20.291184014107994, 3.8563942337326225],
+    [25.365560361315893, -18.219130830815438, 3.556021619253881],
+    [22.970259600203022, -15.990690334166853, 3.2304076402592883],
+    [20.324033488424092, -13.38341447969535, 2.8443114615033567],
+    [17.156462963255542, -9.97939029832577, 2.331447279418772],
+    [13.42337358075744, -4.88459424747664, 1.5424334491866278],
+    [91.88041691939158, -28.405012409014848, 3.13316856020875],
+    [107.33276251338128, -83.76583395525171, 14.240787077367482],
+    [81.3151353695717, -63.55593615831064, 10.979818276977188],
+    [67.7124168825991, -52.96361036482706, 9.293028497459462],
+    [59.12505890810295, -46.251293774001226, 8.240384759670269],
+    [53.120185115708836, -41.53179297701125, 7.5134797486181215],
+    [48.63493458744805, -37.97906619481828, 6.977660088606279],
+    [45.11869141697019, -35.1629554551489, 6.562994731026673],
+    [42.24811150165305, -32.82776076503008, 6.228032960691977],
+    [39.80994512639533, -30.80042655955774, 5.944788929417521],
+    [37.64264229316968, -28.94338028035517, 5.691025618318067],
+    [35.598022315623496, -27.120910765696184, 5.444742907374442],
+    [33.501567049771, -25.159425378589695, 5.177661874456023],
+    [31.083180251312942, -22.76967467953664, 4.842559041653873],
+    [27.799315893308236, -19.32524508813533, 4.337648029666249],
+    [22.262986382906863, -12.944112988032154, 3.3606536436044276],
+    [110.4515634361946, -32.6054919809527, 3.947372540685473],
+    [144.8912326359901, -115.36534722863503, 20.495788744179528],
+    [107.96923745802725, -86.25406314984515, 15.588263717982608],
+    [90.59256121332258, -72.56714860129095, 13.327054992588645],
+    [80.49117482857991, -64.62145309454773, 12.052101407847188],
+    [74.06578755031364, -59.57861312390384, 11.279087278028145],
+    [69.86238998474752, -56.29312636367012, 10.814247600341794],
+    [67.19727339475087, -54.22741345044201, 10.568487645581865],
+    [65.73773148495528, -53.12125763051372, 10.501133253805937],
+    [65.35159536140206, -52.873509184766874, 10.600337432882572],
+    [66.0608847752009, -53.50622626941024, 10.878153688537477],
+    [68.06108299761031, -55.18126379783654, 11.375814377856573],
+    [71.82896946260195, -58.28842151631735, 12.184348896979463],
+    [78.45175407850041, -63.713139832999, 13.505149719906608],
+    [90.77741789064025, -73.77049175009338, 15.860187547052844],
+    [119.53449085280579, -97.16369575584135, 21.21244978822617],
+    [1.4528400214517795e-8, -1.0622236606430416e-8, 22.737998981754345],
+    [105.03175456647199, -60.770445143744304, 2.7122797209098386],
+    [33.49951005765786, -14.298114027406294, -1.6782562468377045],
+    [14.994674658945042, -2.014893887901852, -2.900686246024532],
+    [6.02419375181068, 3.881282968300446, -3.426832400409432],
+    [0.4945175671873448, 7.517679139373329, -3.7239499672223313],
+    [-3.4370368977518306, 10.14398826663619, -3.9327363868231693],
+    [-6.534683773990773, 12.278050327751568, -4.109983994410044],
+    [-9.186315752412487, 14.185913796472805, -4.284722175663459],
+    [-11.626247989303033, 16.03514929487597, -4.476243177937129],
+    [-14.027188767914325, 17.95996822288935, -4.702040148283718],
+    [-16.55070292885114, 20.100657742728348, -4.983261748271062],
+    [-19.38840385597634, 22.642874049901504, -5.351247272367087],
+    [-22.822742484827106, 25.88631650953948, -5.860338574356902],
+    [-27.35548056645666, 30.410653036568753, -6.620631410426898],
+    [-33.98653624520681, 37.56804488403831, -7.90228356102365],
+    [-39.96297101566408, 49.02381217511075, -10.287812864810714],
+    [96.49949505447239, -60.415015864046005, 4.895179569974112],
+    [45.04803726403407, -26.43802050328567, 1.4909172658340903],
+    [28.29849481000234, -15.05712184885053, 0.25604463986941306],
+    [18.967821206799428, -8.552882165855875, -0.4907124640564173],
+    [12.708772398384596, -4.09220989066458, -1.0201509037396839],
+    [8.063661720499532, -0.7089965083783113, -1.4304928546715665],
+    [4.360724241188065, 2.0546048903133127, -1.7726405596212107],
+    [1.2323989286556802, 4.457472549836878, -2.0775795882969748],
+    [-1.5507671437010893, 6.669178590119734, -2.36714259384869],
+    [-4.152251019847266, 8.820314422041886, -2.659639358495114],
+    [-6.707512205548463, 11.032612684002762, -2.9738492708977526],
+    [-9.348786641624718, 13.445386737867288, -3.3333415661355397],
+    [-12.221656048331553, 16.249970475292603, -3.773305789378166],
+    [-15.455751573221095, 19.737929365361545, -4.353222548247481],
+    [-18.576074916009663, 24.187580686512483, -5.163113631934057],
+    [-3.4378009374522702, 21.635072866839327, -5.359026197497471],
+    [87.29642301395238, -56.27116648469382, 5.26922403322772],
+    [46.402032544808414, -28.726775818763187, 2.309696135082476],
+    [31.418116700954446, -18.44043626238739, 1.1625385211735673],
+    [22.875391168552223, -12.456122772568536, 0.472440737417308],
+    [17.049475042492098, -8.284708591367082, -0.023737233531105295],
+    [12.655346218455998, -5.063011212136973, -0.4179167540749952],
+    [9.111835877308426, -2.3962109651224517, -0.7528657470522896],
+    [6.104409099953721, -0.06482204274288256, -1.0534603114415682],
+    [3.4378419087648937, 2.0746138948805988, -1.337202165745694],
+    [0.9761458725310991, 4.131487654356208, -1.6187598325069883],
+    [-1.3858939203013638, 6.204257826090426, -1.912847786939168],
+    [-3.7348036003797223, 8.397651046504715, -2.237012866038675],
+    [-6.125717415347426, 10.836684140626643, -2.615153012643071],
+    [-8.453651401644336, 13.649084464830084, -3.0800151307382966],
+    [-9.350551348666176, 16.536035923919773, -3.6340660867410626],
+    [17.20026477805878, 7.331043916100811, -2.9075860942409633],
+    [80.84760937908742, -53.23532764199381, 5.461775140999111],
+    [46.681954106616026, -29.835623186543756, 2.807631678382353],
+    [32.936277176543264, -20.279450860706117, 1.7020161213580818],
+    [24.915289527924816, -14.608715154085147, 1.0335066347146185],
+    [19.406907514295007, -10.638596241860524, 0.5563717496198901],
+    [15.243173639430523, -7.570167732332986, 0.17996980133527968],
+    [11.881953920262962, -5.028604865317235, -0.1387561383453025],
+    [9.029854157713013, -2.8064654068975856, -0.4241627622856822],
+    [6.50851706246704, -0.7714925213910847, -0.6924608266486654],
+    [4.198238139135463, 1.1740019824017525, -0.9565527618383882],
+    [2.01295259796762, 3.114394432380649, -1.228816633836564],
+    [-0.10518832446252539, 5.133614162457402, -1.523276767125207],
+    [-2.1562765498330707, 7.3183049481425275, -1.8574767142092177],
+    [-3.896885571337468, 9.705560182776079, -2.249983269415307],
+    [-3.441129239816423, 11.69020765930872, -2.6602039904200487],
+    [29.905922487515415, -1.0241976851947523, -1.503314078824739],
+    [76.36086642887119, -51.195870162366035, 5.634317580925366],
+    [46.803547153315634, -30.654957140386934, 3.198837046598346],
+    [34.00082934982698, -21.64380124053091, 2.118745469702524],
+    [26.35768177775853, -16.18416982762168, 1.4574278721924585],
+    [21.06328781335538, -12.335847087208633, 0.9859442005481495],
+    [17.05191396376583, -9.359280384267889, 0.6164645419738223],
+    [13.816806429526332, -6.899016912012441, 0.30632139752184595],
+    [11.079502692216762, -4.755251022228218, 0.03106632926723039],
+    [8.670099790203107, -2.8001241382536066, -0.22548915898581642],
+    [6.476586344575626, -0.9406257536407997, -0.4758233265521809],
+    [4.422962667846826, 0.9007973861786566, -0.731321167238418],
+    [2.4675496823529435, 2.7968985081896522, -1.0041729086220412],
+    [0.6416995523468397, 4.813413542931197, -1.3084872780571029],
+    [-0.7324475459002767, 6.939294432110907, -1.6554656449066667],
+    [0.5327248850918755, 8.409067115454569, -1.983808372612061],
+    [38.33731734365129, -6.3024609368314986, -0.6178995127843346],
+    [73.29954426607962, -49.951970761836456, 5.830260616649625],
+    [47.03067609655034, -31.455024375413327, 3.5520960505177532],
+    [34.95910889658927, -22.8585375193571, 2.4873880296206905],
+    [27.60003251126096, -17.54722683362281, 1.8259522351042272],
+    [22.454411435224323, -13.773210611586984, 1.352983603787485],
+    [18.54027787302984, -10.846266075926849, 0.9832816877186],
+    [15.381400878446472, -8.428149197599362, 0.6747673361943921],
+    [12.713025369495918, -6.326533206302218, 0.40315147595171086],
+    [10.372636344081984, -4.417546131752567, 0.15233872808123033],
+    [8.253287097501673, -2.611009472987074, -0.08998753277550203],
+    [6.284114043152348, -0.8325062304127462, -0.3348376966796698],
+    [4.431194039273592, 0.9857357903903264, -0.5935976485415194],
+    [2.7410534921792626, 2.900082698630082, -0.8787722162020042],
+    [1.574262906492727, 4.878691216963852, -1.1983197159704724],
+    [3.2793131336828125, 6.086381995995527, -1.4831579870203777],
+    [44.43937534880769, -9.90657294167452, -0.008442917810943746],
+    [71.3601674271743, -49.381637454914205, 6.069730446223728],
+    [47.4833852305038, -32.363982130535675, 3.901413304277541],
+    [35.97379888300652, -24.076512810261146, 2.8432459193079005],
+    [28.825614569853244, -18.865371479563926, 2.176456264949971],
+    [23.781168914727726, -15.132150002812741, 1.697396269124389],
+    [19.925413169388662, -12.225948707817022, 1.3228925918314638],
+    [16.806656875976415, -9.822203690978252, 1.011245276912368],
+    [14.171148588696612, -7.7344974898328696, 0.7382378690340502],
+    [11.86233084832992, -5.842176731446045, 0.48784893764978154],
+    [9.77718332910761, -4.057310807460246, 0.24791405046525114],
+    [7.847970119674322, -2.3073913415006015, 0.007672273684422188],
+    [6.043836342192656, -0.5264924846308515, -0.2438932200008468],
+    [4.415444281141014, 1.3398047866789973, -0.5187571061752172],
+    [3.33271604557063, 3.257825550203585, -0.8242930965342602],
+    [5.2036637331225, 4.385520445474677, -1.0919595777938926],
+    [49.38070989274964, -12.61945892468774, 0.4537856821027195],
+    [70.38501128317377, -49.430374735791965, 6.3679207770447],
+    [48.2461601916155, -33.470580916546055, 4.27000228893046],
+    [37.15303342703709, -25.39845988046727, 3.2093682515218975],
+    [30.151513208214958, -20.24465438687318, 2.5323692136439493],
+    [25.16820000087575, -16.524208904323963, 2.0434560054813273],
+    [21.339929171241344, -13.616150882032434, 1.6607066669238033],
+    [18.233718162006536, -11.205904248234397, 1.3424668693356845],
+    [15.60372538904221, -9.110937296166439, 1.0643756084729903],
+    [13.29717606237325, -7.2123504194025525, 0.8103040846583736],
+    [11.213054397563573, -5.4231744105973405, 0.5680756243070171],
+    [9.284635868255434, -3.6714601351658653, 0.32702028134782085],
+    [7.480836676384986, -1.8911018990577326, 0.07626540123955976],
+    [5.848935896994327, -0.024959013716643438, -0.19619128335196376],
+    [4.74639798703101, 1.905023910627291, -0.49882852660277965],
+    [6.560073411433247, 3.0985898998021106, -0.7695569420356326],
+    [53.89955301295962, -14.899351084766527, 0.8410451798327757],
+    [70.32019504988146, -50.101987622354905, 6.741910279236277],
+    [49.40633249924475, -34.86074045124038, 4.678880287617847],
+    [38.59571134074776, -26.914429677143154, 3.605807342835162],
+    [31.67919991940989, -21.775589511728086, 2.913325394709862],
+    [26.719337728316177, -18.041165227451497, 2.4108476190465558],
+    [22.89091691051666, -15.110998219436988, 2.0167755357240456],
+    [19.773971042091343, -12.676714814579265, 1.6890824920895],
+    [17.127450909309587, -10.55753097511038, 1.4029945494451415],
+    [14.8000517063011, -8.634659855836745, 1.1420415928905445],
+    [12.69047163672565, -6.820444357762983, 0.8937829335287784],
+    [10.730191451100804, -5.041512763666323, 0.6473408107276502],
+    [8.883904692959609, -3.228911842562205, 0.39162237354731466],
+    [7.188480117625993, -1.3184209692666173, 0.1140922975841488],
+    [5.97081603305335, 0.690545082919824, -0.1959910669946483],
+    [7.52500798686532, 2.081750638761701, -0.4875932400413644],
+    [58.503439442136866, -17.029141055146802, 1.196274521735309],
+    [71.20592724516891, -51.46264396987618, 7.2155357111972345],
+    [51.07900293454973, -36.640569968895484, 5.152086849195703],
+    [40.417996285751826, -28.72750202946202, 4.05477734872042],
+    [33.5223627976489, -23.557928327280262, 3.340521809058435],
+    [28.547086417632478, -19.781225008351083, 2.820320390398612],
+    [24.691219180986366, -16.80866930968132, 2.4117721735081648],
+    [21.541935642840496, -14.333996873657156, 2.0719524371377234],
+    [18.859730469023468, -12.175852173734532, 1.7753494051079008],
+    [16.49240085833509, -10.213824747006775, 1.5048853722646802],
+    [14.33601413151895, -8.357823874019145, 1.2475715033819312],
+    [12.317209971056881, -6.5308054867967975, 0.9919538751842064],
+    [10.391680440476387, -4.657557391749594, 0.7261888353458559],
+    [8.577277783880335, -2.6610142458681776, 0.4364160555483029],
+    [7.1509244264084035, -0.5061455310882945, 0.10830647323079433],
+    [8.240696366776174, 1.217726958799437, -0.22242015832309012],
+    [63.63790542304889, -19.221267670445727, 1.5501240328215857],
+    [73.19523398964041, -53.66280971675114, 7.825783763097754],
+    [53.43748913690056, -38.96315499985384, 5.72238142579424],
+    [42.78180059320502, -30.97857513394023, 4.585859089074883],
+    [35.83464407405262, -25.72479296344268, 3.8417937505569757],
+    [30.800485346209665, -21.873281485214786, 3.2988024971179404],
+    [26.887956248000002, -18.836239348334182, 2.872236087950067],
+    [23.68479566157178, -16.304723680019357, 2.5175778680928724],
+    [20.94938255419006, -14.094091982677678, 2.208160697576081],
+    [18.526031991571937, -12.080353549335973, 1.9259789159754395],
+    [16.30573535070642, -10.169108694510898, 1.6571737114775282],
+    [14.207149308227201, -8.277242030506695, 1.3892996759531355],
+    [12.171774127016404, -6.319414670109693, 1.109115707053981],
+    [10.188094013946932, -4.198344365549573, 0.8003020338254477],
+    [8.458163889523282, -1.8281185095971095, 0.4427975365668709],
+    [8.853831830323578, 0.383246353831184, 0.051431569390619776],
+    [69.83853847350598, -21.693547549180387, 1.9321039879402484],
+    [76.6166849251031, -56.99306636095024, 8.634849077077297],
+    [56.76773264355284, -42.07511646743998, 6.44076118624322],
+    [45.942441824357886, -33.88718779902332, 5.244390199191568],
+    [38.85428531877872, -28.481020876627305, 4.459550571360029],
+    [33.70871517951195, -24.51444459195641, 3.887208776934455],
+    [29.70625494841148, -21.387365192115087, 3.438441547719436],
+    [26.4270330442527, -18.781973072986833, 3.0661556670236942],
+    [23.622763074887736, -16.506844200200476, 2.741949353000419],
+    [21.131176471387995, -14.431986402939266, 2.446468377039998],
+    [18.83542537375654, -12.456505962288315, 2.1645882729666175],
+    [16.64279091741333, -10.488534831869625, 1.8823800241100506],
+    [14.474954809574156, -8.42824187586648, 1.584424420218906],
+    [12.27906297660325, -6.149169662641513, 1.2504419807623945],
+    [10.149889671179142, -3.4916710022329336, 0.8512514740045359],
+    [9.574848768960436, -0.6005067638343831, 0.37117819412325787],
+    [77.9495416486954, -24.75479275334735, 2.3818460199878326],
+    [82.13631149132544, -62.02106132569617, 9.757484095097901],
+    [61.58739106209273, -46.41748467213892, 7.396770283360176],
+    [50.35042746115376, -37.83820809282265, 6.109147066189087],
+    [42.99861152157636, -32.18395823242187, 5.2674106693446445],
+    [37.67307986045815, -28.048909451006736, 4.656786750653154],
+    [33.54153553629115, -24.80156005395765, 4.180904061229057],
+    [30.164946850160835, -22.10621519913956, 3.788558308341231],
+    [27.282020395059877, -19.759663441792675, 3.4487363374518214],
+    [24.71976991946343, -17.622601245717266, 3.1401326712080064],
+    [22.350080468352044, -15.584852783193465, 2.845826397136191],
+    [20.06491020632142, -13.542562913364321, 2.549772483634245],
+    [17.759698135513595, -11.376200519972588, 2.233368401103797],
+    [15.326134995786541, -8.919020955179988, 1.870342739625699],
+    [12.709861633369593, -5.906322909559258, 1.4172148213906128],
+    [10.820747361358352, -2.087744001682625, 0.8099035245206652],
+    [89.63727716374972, -28.984116181331157, 2.9711892616057107],
+    [91.20436307052145, -69.96465915122079, 11.43397749061671],
+    [68.95556149568907, -52.8871894131809, 8.770901922753158],
+    [56.91399039953832, -43.605050652647755, 7.337789925191597],
+    [49.10859674567716, -37.55234671619989, 6.413176406212332],
+    [43.5072823282066, -33.173804331780225, 5.751774678302347],
+    [39.203702705960325, -29.77383141419346, 5.244049112009153],
+    [35.72071348999329, -26.983733778677642, 4.832001273315508],
+    [32.77403318952965, -24.5804942176113, 4.480555273372713],
+    [30.173957785792876, -22.41061733638687, 4.165540442000976],
+    [27.77668301556546, -20.350970604945985, 3.86751124849781],
+    [25.454212293949794, -18.281775808433267, 3.567481539987666],
+    [23.068272173246406, -16.057436590783595, 3.24231700689785],
+    [20.437827514232033, -13.45652175115352, 2.8568282015792104],
+    [17.303910121942128, -10.065011939480712, 2.3449921373708014],
+    [13.701066226856245, -5.018956350497635, 1.5600032881821624],
+    [109.14122052703858, -35.824938194941886, 3.875836126994874],
+    [107.58507697285059, -83.96551386179455, 14.27787053635269],
+    [81.48883528619224, -63.69401855677563, 11.006132679423445],
+    [67.8574831257351, -53.07914418628497, 9.315367068141123],
+    [59.25742282684206, -46.35672781014202, 8.260945837130372],
+    [53.24720742660193, -41.632858919511676, 7.5332874465884005],
+    [48.760936695773175, -38.07909495940603, 6.9973141536167125],
+    [45.24675444924357, -35.264273627661, 6.582914402281343],
+    [42.380846878172655, -32.932274803702086, 6.248558347452541],
+    [39.949933513971324, -30.909931743826373, 5.966232494725696],
+    [37.79279836423812, -29.059778564911174, 5.713704261818572],
+    [35.76206087440525, -27.246431839453933, 5.4690030073318585],
+    [33.68486351835578, -25.296955732783992, 5.203903978606451],
+    [31.294939573744994, -22.923468902703124, 4.871270695227853],
+    [28.06051459827673, -19.503350828476513, 4.3694945366075615],
+    [22.664705506380706, -13.177221620355951, 3.3975622386710786],
+    [153.25536851877501, -50.95144853242261, 5.778263807164435],
+    [145.5049267003894, -115.86261772026612, 20.59008963211559],
+    [108.36703538090975, -86.57901623518927, 15.651588160203456],
+    [90.92469938224545, -72.8397801470328, 13.381035462578176],
+    [80.801138677992, -64.87668046362533, 12.103160812789826],
+    [74.37435113337297, -59.833232063514096, 11.330392555920715],
+    [70.18370560869813, -56.55867291913114, 10.868036621398511],
+    [67.54426917149257, -54.514504877309975, 10.626872697427153],
+    [66.1251876571626, -53.44209527803451, 10.56658379992708],
+    [65.79930900022619, -53.24448372885819, 10.676202951690364],
+    [66.5986814780984, -53.95206718784765, 10.969512288845488],
+    [68.73888965511303, -55.74339472071881, 11.491191918933948],
+    [72.74089374343173, -59.044943186165135, 12.339837251003091],
+    [79.8063347787796, -64.83713160511459, 13.736426898757337],
+    [93.17527354484791, -75.76040658748815, 16.270020916731557],
+    [125.97094441754399, -102.50453996671865, 22.313142532616926],
+    [4447.8789243735955, -2217.8734243757867, 4605.79519831759],
+    [-1419.020478677501, 1155.4969119645668, -254.91456935566592],
+    [-1035.7230767087208, 824.7023960301436, -184.70580139745826],
+    [-826.7532738921539, 644.4773724094565, -146.5921366021989],
+    [-692.2699351584881, 528.531579896849, -122.13024650443576],
+    [-597.3836983126316, 446.725954658998, -104.89616213103227],
+    [-526.3929291026459, 385.5042286561648, -92.00752054732769],
+    [-471.0780802851703, 337.77410545092397, -81.95972768240667],
+    [-426.6755519516433, 299.4277889254339, -73.8831624669838],
+    [-390.21398135194715, 267.9045814743745, -67.23679819399587],
+    [-359.733641105179, 241.51667153558301, -61.66470457025461],
+    [-333.88413291785326, 219.1017703101485, -56.92221724966502],
+    [-311.70144705560074, 199.83068262732223, -52.83509152427192],
+    [-292.47705616783185, 183.0943552752401, -49.275556619119065],
+    [-275.6773236731439, 168.43436334915384, -46.147596722790375],
+    [-260.8918901133696, 155.49840716797868, -43.377538893388376],
+    [-247.79949367217415, 144.0108521468134, -40.90782956521508],
+    [-1308.3415571613816, 1079.5314587513167, -242.53491793819688],
+    [-953.7092187075164, 770.3168948042063, -176.26446485506645],
+    [-758.7939127281758, 600.4461867156499, -139.9827304063373],
+    [-632.8586014327097, 490.70603405979915, -116.59478870399984],
+    [-543.8090560258493, 413.0921364018684, -100.07398010464651],
+    [-477.1018867341292, 354.92127488781193, -87.69752970724804],
+    [-425.08945331842494, 309.52773524744543, -78.03768306565358],
+    [-383.32538935125, 273.0382865741249, -70.2665495944483],
+    [-349.02957295620877, 243.03258587594917, -63.867805575457375],
+    [-320.36501423743414, 217.91231767452226, -58.50112410561139],
+    [-296.0640313255489, 196.57516237452498, -53.932208495785545],
+    [-275.2205874877814, 178.23374027741772, -49.993988520749845],
+    [-257.1681132789866, 162.3091225329904, -46.563826462591514],
+    [-241.4041643075689, 148.36518530873562, -43.5494826333674],
+    [-227.54210242291538, 136.06652361215794, -40.88012825527421],
+    [-215.2790601762876, 125.15055743586295, -38.500395736595465],
+    [-1213.2630215473234, 1013.9955708203786, -231.83093114789165],
+    [-882.8011965101264, 723.1178792061445, -168.92824795458827],
+    [-699.8402749753676, 562.1206574187582, -134.22563356996307],
+    [-581.2048577309769, 457.71927365820534, -111.76665641265267],
+    [-497.150253585649, 383.7189180101748, -95.86392129754023],
+    [-434.1129784536856, 328.181001962513, -83.9317133113074],
+    [-384.93125316214173, 284.8050771130751, -74.60850289263126],
+    [-345.4290083271277, 249.9193119591082, -67.10245029569698],
+    [-312.9894284496741, 221.2241180120078, -60.918608769538224],
+    [-285.88051525116026, 197.198299458139, -55.73017105067794],
+    [-262.90544372561806, 176.79127804087116, -51.31183233470751],
+    [-243.20789657831241, 159.25178982722224, -47.50274963666304],
+    [-226.15732461541185, 144.02694675076944, -44.184743335092094],
+    [-211.27808449020253, 130.69991716491157, -41.26885497623398],
+    [-198.20393519337767, 118.94992052291614, -38.68672820554549],
+    [-186.6478363802581, 108.52568331742562, -36.384896323600614],
+    [-1130.7371196396298, 956.8664438961113, -222.47483286757011],
+    [-820.9470347434682, 681.7895207628538, -162.49271667211966],
+    [-648.2840807958811, 528.4922356989081, -129.16844783955995],
+    [-535.9571768719239, 428.73759223278836, -107.52244465479097],
+    [-456.2259288119152, 357.8869962545922, -92.1612419493629],
+    [-396.3673954324186, 304.6456511227747, -80.61844825435335],
+    [-349.63802181375524, 263.0299466674179, -71.59035528380652],
+    [-312.094978977931, 229.54314204371244, -64.31665867469658],
+    [-281.2626275001034, 201.9908019647567, -58.321135520599086],
+    [-255.4998628630771, 178.91897119688923, -53.28883143081213],
+    [-233.67127361728203, 159.32222981675088, -49.00233046758353],
+    [-214.9636771075071, 142.4808603141586, -45.306255924740086],
+    [-198.77779942803085, 127.8647361097095, -42.08634311581504],
+    [-184.66128417409104, 115.07389790019181, -39.25651596499283],
+    [-172.26562397781487, 103.80036143447857, -36.750590534706404],
+    [-161.3175463611428, 93.80275044194843, -34.51677067231691],
+    [-1058.4588811442613, 906.6133895599554, -214.21925238894164],
+    [-766.5662895164683, 645.317243873031, -156.80067271916056],
+    [-602.8734807306031, 498.7747856703483, -124.69271659821581],
+    [-496.0557406738058, 403.1061741439538, -103.76550734420192],
+    [-420.1043814676202, 335.02808532716006, -88.88343471906927],
+    [-363.02616034691016, 283.8089369596445, -77.68522163900275],
+    [-318.44168024191424, 243.74306901470064, -68.91821746470278],
+    [-282.61162000799936, 211.48762689730833, -61.85000616722421],
+    [-253.1837078743664, 184.94071744380153, -56.02093979845843],
+    [-228.59636154552686, 162.70762141302865, -51.12656373334826],
+    [-207.7679510488877, 143.82273272524034, -46.95645371378793],
+    [-189.92309777288952, 127.59416441027832, -43.36007932298985],
+    [-174.48995652145004, 113.51188076523138, -40.226647635787494],
+    [-161.03660565473004, 101.1908217115387, -37.4726391114535],
+    [-149.23010743229526, 90.33430705035822, -35.03379687222752],
+    [-138.8092827136532, 80.70970637073546, -32.859806744132975],
+    [-994.6530288497588, 862.056559803897, -206.8739925578048],
+    [-718.4225543552616, 612.9063553392481, -151.7293042363227],
+    [-562.6201193712321, 472.34670485890115, -120.70502417026297],
+    [-460.6583831433937, 380.3038005677742, -100.41912682555413],
+    [-388.04153082170313, 314.68751023651924, -85.96467353496875],
+    [-333.41647315511335, 265.2640782606472, -75.07394224489042],
+    [-290.72410586034647, 226.5741054816883, -66.53981623797016],
+    [-256.404415781033, 195.41129022006788, -59.65478059655387],
+    [-228.21396783459255, 169.755947095332, -53.973998097741294],
+    [-204.66140437961388, 148.26602015447713, -49.20240084407667],
+    [-184.7126263148256, 130.01132133328022, -45.13581705005566],
+    [-167.62568719597155, 114.32473356178272, -41.6280510617074],
+    [-152.85301201261194, 100.7141096733843, -38.571415634836036],
+    [-139.9807695213895, 88.80765999236674, -35.884678326695],
+    [-128.68981134655263, 78.31877542628361, -33.50530625185663],
+    [-118.72967309342552, 69.02261939478214, -31.384311026158606],
+    [-937.9303640914213, 822.2718504026041, -200.29043187104736],
+    [-675.5351082224083, 583.9256655261383, -147.18130432366038],
+    [-526.7337316396887, 448.7101881520293, -117.13074703344512],
+    [-429.0879012384302, 359.91061463772775, -97.42166509891761],
+    [-359.43639833266246, 296.49735404035874, -83.35183600721972],
+    [-306.99294305197844, 248.68068482185237, -72.73755958565951],
+    [-265.9826623441991, 211.2213062549026, -64.41268285445744],
+    [-233.00490154048256, 181.03512116952066, -57.69211442851478],
+    [-205.91305756923248, 156.1760792749084, -52.144361721383724],
+    [-183.27830285017333, 135.34932624347414, -47.48281713380683],
+    [-164.10888869599762, 117.65643403693997, -43.50894524787645],
+    [-147.6926730378196, 102.45250550131838, -40.08045834086521],
+    [-133.50372740821223, 89.26144524395875, -37.092469081157965],
+    [-121.14436190075226, 77.72337436413295, -34.46580688552784],
+    [-110.30773635203845, 67.56072494787304, -32.13949298209706],
+    [-100.75295344763681, 58.55565849071543, -30.065734033128514],
+    [-887.1884190050052, 786.5251388207245, -194.3507615712492],
+    [-637.1163890889217, 557.8675891397426, -143.07860036586018],
+    [-494.57522397421906, 427.46199710720526, -113.90958079937676],
+    [-400.793890354695, 341.5847617329062, -94.72305876369416],
+    [-333.79861869774624, 280.15684826650255, -81.00160607461055],
+    [-283.3091191657296, 233.78777150346502, -70.63758560557538],
+    [-243.80475148402235, 197.4364758130772, -62.501982515120865],
+    [-212.02758472549758, 168.1290562520729, -55.93005084805812],
+    [-185.91781913887507, 143.98590783040132, -50.502410976850946],
+    [-164.10271935415702, 123.7547904882927, -45.9401362566449],
+    [-145.62854727481175, 106.56596003500873, -42.049808768578835],
+    [-129.80992286963576, 91.79459261629398, -38.69268891141785],
+    [-116.1403619792622, 78.97909703570375, -35.76643129901388],
+    [-104.23667879980636, 67.770358765439, -33.193737050313665],
+    [-93.80309640051844, 57.89898212625034, -30.91503800318063],
+    [-84.60731938135599, 49.153454983391676, -28.883625369349602],
+    [-841.5412490713284, 754.2258205553459, -188.96039745910872],
+    [-602.5269096662904, 534.3193714449648, -139.35784215037214],
+    [-465.6224341003257, 408.272119034432, -110.99228222567555],
+    [-375.3246526831239, 325.04518451862305, -92.28224371111416],
+    [-310.72436475926054, 265.4178305783631, -78.87833065564283],
+    [-261.99627673165094, 220.36109001617768, -68.7422510043965],
+    [-223.848759998857, 185.01370546794976, -60.778891888861224],
+    [-193.15259309567855, 156.50180496755652, -54.3420919876394],
+    [-167.92631694053105, 133.00613880166838, -49.02353963379139],
+    [-146.84784862423643, 113.31319064538499, -44.551327194852306],
+    [-128.99778953900895, 96.57928847994951, -40.73671313423614],
+    [-113.71494568831106, 82.1978576265969, -37.44419983605556],
+    [-100.51041106206696, 69.72049501699509, -34.57376422848701],
+    [-89.01411561952413, 58.80787496000456, -32.049817141831596],
+    [-78.94028934529885, 49.1980341898972, -29.814078719274693],
+    [-70.06441947844435, 40.685214813966944, -27.820830514170087],
+    [-800.2688684967904, 724.8933476555861, -184.04252657706195],
+    [-571.24220144953, 512.9419849014873, -135.9671000716749],
+    [-439.4447549770203, 390.8679420219162, -108.33825884618282],
+    [-352.30621760517727, 310.05876422514507, -90.06523491029462],
+    [-289.8782592281241, 252.07380904570454, -76.95241175742566],
+    [-242.74739898303972, 208.2135539136593, -67.0251151731375],
+    [-205.82961115487115, 173.7808198445043, -59.21937053773115],
+    [-176.11246657179208, 145.99309627830195, -52.906095426185296],
+    [-151.6856403375674, 123.0862837950063, -47.68715155742342],
+    [-131.2730642002687, 103.88226101644464, -43.29708313847783],
+    [-113.98654601423345, 87.56119145721061, -39.55144676422828],
+    [-99.1868774865526, 73.53318619939523, -36.317724125215754],
+    [-86.40113554111397, 61.361901553599985, -33.49802590321097],
+    [-75.27117837349718, 50.71696350215782, -31.018333501050602],
+    [-65.52033887686005, 41.343206015853994, -28.8215502773343],
+    [-56.93118546591194, 33.04013405134078, -26.862867155257735],
+    [-762.7802081542839, 698.1327147338607, -179.53412022788714],
+    [-542.828209249077, 493.45441400995503, -132.8634116225763],
+    [-415.6840636159055, 375.02235376379207, -105.91376027261096],
+    [-331.42646576729675, 296.4305817443836, -88.0436754064761],
+    [-270.9796125504904, 239.95163369750384, -75.19908481389368],
+    [-225.3049353237583, 197.1879129771259, -65.46400525831034],
+    [-189.50767980944323, 163.5928069041399, -57.80321958009102],
+    [-160.6819891645056, 136.46770241004484, -51.603425796684895],
+    [-136.98248473429956, 114.0991652203666, -46.47588692216782],
+    [-117.17512641553266, 95.34159777756045, -42.161109132491596],
+    [-100.4002329846393, 79.39706840426038, -38.47862024850056],
+    [-86.03868723108754, 65.69102339288429, -35.29865412020509],
+    [-73.63217443706745, 53.79820274763141, -32.52529205009565],
+    [-62.83345711483435, 43.396460095123935, -30.085965590467346],
+    [-53.37419116135007, 34.236877044199794, -27.924670331338085],
+    [-45.04342073146874, 26.123796065246317, -25.99743644654311],
+    [-728.5855606532282, 673.6162206546295, -175.38297355885896],
+    [-516.9227237635291, 475.6217902991196, -130.01093282816385],
+    [-394.04020499007174, 360.54467593418576, -103.69050339061582],
+    [-312.42296996864167, 283.99645173249326, -86.19372644326096],
+    [-253.791831255646, 228.90507993395912, -73.59747962073772],
+    [-209.45134145038023, 187.1510857823913, -64.04019730479648],
+    [-174.68019655392587, 154.32671699576315, -56.51335260605679],
+    [-146.67027963437968, 127.81078424807022, -50.41829565256259],
+    [-123.63580452560483, 105.93662454456911, -45.37501937566654],
+    [-104.38130686707201, 87.58866945457473, -41.12956570006047],
+    [-88.07327994363257, 71.98921327022028, -37.50514909257535],
+    [-74.11105412084007, 58.577862340111864, -34.37455773068679],
+    [-62.049729791750835, 46.93959087477428, -31.643701399240477],
+    [-51.55208431890825, 36.75984940313033, -29.241356835077443],
+    [-42.35741869877485, 27.79548815441513, -27.112532530834194],
+    [-34.260726292735505, 19.855316254349678, -25.214036761964383],
+    [-697.2757959309866, 651.0697081280972, -171.5454763001542],
+    [-493.22119475964985, 459.24632098219666, -127.37952776629933],
+    [-374.25981400650653, 347.27367727529355, -101.64461499353048],
+    [-295.0735795559003, 272.6171353884305, -84.4952089840413],
+    [-238.11417988486542, 218.8098521220294, -72.12989073761166],
+    [-195.001694544598, 177.98973007814504, -62.737777715810076],
+    [-161.17451604358638, 145.8776630395401, -55.33522533202937],
+    [-133.91458004637164, 119.92423108566273, -49.337248405900894],
+    [-111.49102861048155, 98.50613870558838, -44.37198195572842],
+    [-92.74396267046656, 80.53566426565361, -40.19063030049799],
+    [-76.8640110304405, 65.25385839501662, -36.619845162152934],
+    [-63.26751620199253, 52.11345810290875, -33.534795601732576],
+    [-51.52195070670746, 40.70892721516761, -30.84309522164695],
+    [-41.29932870620111, 30.73275996323304, -28.474773846312477],
+    [-32.346002874787914, 21.947154833223355, -26.375783245902458],
+    [-24.46245452744091, 14.165061975678277, -24.50365611191246],
+    [-668.5064839898336, 630.2620485377377, -167.98491243260813],
+    [-471.46576704351395, 444.1602734128007, -124.94367985250122],
+    [-356.127615741817, 335.0721307099124, -99.75580973616695],
+    [-279.1890550709353, 262.17380852880854, -82.9309328454892],
+    [-223.77531093601652, 209.55965485353963, -70.78120503450852],
+    [-181.79787392273914, 169.606747755393, -61.54314055955879],
+    [-148.84279703377507, 138.15565534824702, -54.25638531157827],
+    [-122.27533438724598, 112.72375662990369, -48.348749075430604],
+    [-100.41546675454497, 91.72812936767528, -43.455991006938454],
+    [-82.13621873649383, 74.10697774987626, -39.334148731605346],
+    [-66.65056403546187, 59.11881426964487, -35.813091321075675],
+    [-53.39059572974633, 46.228599314742354, -32.770215726828255],
+    [-41.93524100567497, 35.03962959307277, -30.114729354092265],
+    [-31.96506581742535, 25.250954783520996, -27.777833809969867],
+    [-23.232951558364967, 16.629750332808413, -25.706362648202532],
+    [-15.544460626262259, 8.992819587611145, -23.858525542240763],
+    [-641.9856250732116, 610.9970125003944, -164.6701480144515],
+    [-451.43671797370547, 430.2204919999725, -122.68164253427733],
+    [-339.4595876902358, 323.8225311797541, -98.00674464700657],
+    [-264.60725255403827, 252.56447912731903, -81.48616744596191],
+    [-210.6281374528055, 201.0630756568825, -69.53844842253706],
+    [-169.70393528814364, 161.9185030819711, -60.444588335887126],
+    [-137.55776243056675, 131.08307561200493, -53.26611338410485],
+    [-111.63225715314451, 106.13657595153417, -47.44285766695592],
+    [-90.29463236725906, 85.53380593720864, -42.61774542422057],
+    [-72.44850494945162, 68.23719497673846, -38.55135584586704],
+    [-57.32761199345593, 53.521570784652255, -35.07658034680059],
+    [-44.37868195074323, 40.86331290491175, -32.072908044431195],
+    [-33.19128494341944, 29.87396838405533, -29.451042511790636],
+    [-23.453932004888912, 20.25870887316861, -27.143284944534575],
+    [-14.925569507599661, 11.789356827569952, -25.097296046086033],
+    [-7.4164796478187975, 4.286312740805392, -23.271920303688614],
+    [-617.4640665814022, 593.1069174991345, -161.57460765699264],
+    [-432.9457057014613, 417.30407107162887, -120.57477024891111],
+    [-324.0975365767616, 313.4236958549672, -96.38250760744894],
+    [-251.18849159364183, 243.70113072675966, -80.14822061525027],
+    [-198.54573564371682, 193.2410908038784, -68.3904239231649],
+    [-158.6024039176958, 154.8525905983656, -59.43201230433006],
+    [-127.20929349157991, 124.59264506630156, -52.35513590278673],
+    [-101.88116869011812, 100.09953288751574, -46.610966485282],
+    [-81.02927689350498, 79.86342353067741, -41.849184323669405],
+    [-63.58575888463746, 62.8694582578077, -37.83465016962805],
+    [-48.80371108473924, 48.40775905505485, -34.40310394708787],
+    [-36.14350649222124, 35.96540812926309, -31.436005915005918],
+    [-25.204636260525938, 25.161683457889243, -28.845468713024776],
+    [-15.683016297172195, 15.70749180189701, -26.56482868315422],
+    [-7.343244454238045, 7.379007809414438, -24.54252487585017],
+    [-2.043379347578331e-10, 1.8586159160817463e-10, -22.737998889246587],
+    [-1419.020478677501, 1155.4969119645668, -254.91456935566592],
+    [-1035.7230767087208, 824.7023960301436, -184.70580139745826],
+    [-826.7532738921539, 644.4773724094565, -146.5921366021989],
+    [-692.2699351584881, 528.531579896849, -122.13024650443576],
+    [-597.3836983126316, 446.725954658998, -104.89616213103227],
+    [-526.3929291026459, 385.5042286561648, -92.00752054732769],
+    [-471.0780802851703, 337.77410545092397, -81.95972768240667],
+    [-426.6755519516433, 299.4277889254339, -73.8831624669838],
+    [-390.21398135194715, 267.9045814743745, -67.23679819399587],
+    [-359.733641105179, 241.51667153558301, -61.66470457025461],
+    [-333.88413291785326, 219.1017703101485, -56.92221724966502],
+    [-311.70144705560074, 199.83068262732223, -52.83509152427192],
+    [-292.47705616783185, 183.0943552752401, -49.275556619119065],
+    [-275.6773236731439, 168.43436334915384, -46.147596722790375],
+    [-260.8918901133696, 155.49840716797868, -43.377538893388376],
+    [-247.79949367217415, 144.0108521468134, -40.90782956521508],
+    [-1308.3415571613816, 1079.5314587513167, -242.53491793819688],
+    [-953.7092187075164, 770.3168948042063, -176.26446485506645],
+    [-758.7939127281758, 600.4461867156499, -139.9827304063373],
+    [-632.8586014327097, 490.70603405979915, -116.59478870399984],
+    [-543.8090560258493, 413.0921364018684, -100.07398010464651],
+    [-477.1018867341292, 354.92127488781193, -87.69752970724804],
+    [-425.08945331842494, 309.52773524744543, -78.03768306565358],
+    [-383.32538935125, 273.0382865741249, -70.2665495944483],
+    [-349.02957295620877, 243.03258587594917, -63.867805575457375],
+    [-320.36501423743414, 217.91231767452226, -58.50112410561139],
+    [-296.0640313255489, 196.57516237452498, -53.932208495785545],
+    [-275.2205874877814, 178.23374027741772, -49.993988520749845],
+    [-257.1681132789866, 162.3091225329904, -46.563826462591514],
+    [-241.4041643075689, 148.36518530873562, -43.5494826333674],
+    [-227.54210242291538, 136.06652361215794, -40.88012825527421],
+    [-215.2790601762876, 125.15055743586295, -38.500395736595465],
+    [-1213.2630215473234, 1013.9955708203786, -231.83093114789165],
+    [-882.8011965101264, 723.1178792061445, -168.92824795458827],
+    [-699.8402749753676, 562.1206574187582, -134.22563356996307],
+    [-581.2048577309769, 457.71927365820534, -111.76665641265267],
+    [-497.150253585649, 383.7189180101748, -95.86392129754023],
+    [-434.1129784536856, 328.181001962513, -83.9317133113074],
+    [-384.93125316214173, 284.8050771130751, -74.60850289263126],
+    [-345.4290083271277, 249.9193119591082, -67.10245029569698],
+    [-312.9894284496741, 221.2241180120078, -60.918608769538224],
+    [-285.88051525116026, 197.198299458139, -55.73017105067794],
+    [-262.90544372561806, 176.79127804087116, -51.31183233470751],
+    [-243.20789657831241, 159.25178982722224, -47.50274963666304],
+    [-226.15732461541185, 144.02694675076944, -44.184743335092094],
+    [-211.27808449020253, 130.69991716491157, -41.26885497623398],
+    [-198.20393519337767, 118.94992052291614, -38.68672820554549],
+    [-186.6478363802581, 108.52568331742562, -36.384896323600614],
+    [-1130.7371196396298, 956.8664438961113, -222.47483286757011],
+    [-820.9470347434682, 681.7895207628538, -162.49271667211966],
+    [-648.2840807958811, 528.4922356989081, -129.16844783955995],
+    [-535.9571768719239, 428.73759223278836, -107.52244465479097],
+    [-456.2259288119152, 357.8869962545922, -92.1612419493629],
+    [-396.3673954324186, 304.6456511227747, -80.61844825435335],
+    [-349.63802181375524, 263.0299466674179, -71.59035528380652],
+    [-312.094978977931, 229.54314204371244, -64.31665867469658],
+    [-281.2626275001034, 201.9908019647567, -58.321135520599086],
+    [-255.4998628630771, 178.91897119688923, -53.28883143081213],
+    [-233.67127361728203, 159.32222981675088, -49.00233046758353],
+    [-214.9636771075071, 142.4808603141586, -45.306255924740086],
+    [-198.77779942803085, 127.8647361097095, -42.08634311581504],
+    [-184.66128417409104, 115.07389790019181, -39.25651596499283],
+    [-172.26562397781487, 103.80036143447857, -36.750590534706404],
+    [-161.3175463611428, 93.80275044194843, -34.51677067231691],
+    [-1058.4588811442613, 906.6133895599554, -214.21925238894164],
+    [-766.5662895164683, 645.317243873031, -156.80067271916056],
+    [-602.8734807306031, 498.7747856703483, -124.69271659821581],
+    [-496.0557406738058, 403.1061741439538, -103.76550734420192],
+    [-420.1043814676202, 335.02808532716006, -88.88343471906927],
+    [-363.02616034691016, 283.8089369596445, -77.68522163900275],
+    [-318.44168024191424, 243.74306901470064, -68.91821746470278],
+    [-282.61162000799936, 211.48762689730833, -61.85000616722421],
+    [-253.1837078743664, 184.94071744380153, -56.02093979845843],
+    [-228.59636154552686, 162.70762141302865, -51.12656373334826],
+    [-207.7679510488877, 143.82273272524034, -46.95645371378793],
+    [-189.92309777288952, 127.59416441027832, -43.36007932298985],
+    [-174.48995652145004, 113.51188076523138, -40.226647635787494],
+    [-161.03660565473004, 101.1908217115387, -37.4726391114535],
+    [-149.23010743229526, 90.33430705035822, -35.03379687222752],
+    [-138.8092827136532, 80.70970637073546, -32.859806744132975],
+    [-994.6530288497588, 862.056559803897, -206.8739925578048],
+    [-718.4225543552616, 612.9063553392481, -151.7293042363227],
+    [-562.6201193712321, 472.34670485890115, -120.70502417026297],
+    [-460.6583831433937, 380.3038005677742, -100.41912682555413],
+    [-388.04153082170313, 314.68751023651924, -85.96467353496875],
+    [-333.41647315511335, 265.2640782606472, -75.07394224489042],
+    [-290.72410586034647, 226.5741054816883, -66.53981623797016],
+    [-256.404415781033, 195.41129022006788, -59.65478059655387],
+    [-228.21396783459255, 169.755947095332, -53.973998097741294],
+    [-204.66140437961388, 148.26602015447713, -49.20240084407667],
+    [-184.7126263148256, 130.01132133328022, -45.13581705005566],
+    [-167.62568719597155, 114.32473356178272, -41.6280510617074],
+    [-152.85301201261194, 100.7141096733843, -38.571415634836036],
+    [-139.9807695213895, 88.80765999236674, -35.884678326695],
+    [-128.68981134655263, 78.31877542628361, -33.50530625185663],
+    [-118.72967309342552, 69.02261939478214, -31.384311026158606],
+    [-937.9303640914213, 822.2718504026041, -200.29043187104736],
+    [-675.5351082224083, 583.9256655261383, -147.18130432366038],
+    [-526.7337316396887, 448.7101881520293, -117.13074703344512],
+    [-429.0879012384302, 359.91061463772775, -97.42166509891761],
+    [-359.43639833266246, 296.49735404035874, -83.35183600721972],
+    [-306.99294305197844, 248.68068482185237, -72.73755958565951],
+    [-265.9826623441991, 211.2213062549026, -64.41268285445744],
+    [-233.00490154048256, 181.03512116952066, -57.69211442851478],
+    [-205.91305756923248, 156.1760792749084, -52.144361721383724],
+    [-183.27830285017333, 135.34932624347414, -47.48281713380683],
+    [-164.10888869599762, 117.65643403693997, -43.50894524787645],
+    [-147.6926730378196, 102.45250550131838, -40.08045834086521],
+    [-133.50372740821223, 89.26144524395875, -37.092469081157965],
+    [-121.14436190075226, 77.72337436413295, -34.46580688552784],
+    [-110.30773635203845, 67.56072494787304, -32.13949298209706],
+    [-100.75295344763681, 58.55565849071543, -30.065734033128514],
+    [-887.1884190050052, 786.5251388207245, -194.3507615712492],
+    [-637.1163890889217, 557.8675891397426, -143.07860036586018],
+    [-494.57522397421906, 427.46199710720526, -113.90958079937676],
+    [-400.793890354695, 341.5847617329062, -94.72305876369416],
+    [-333.79861869774624, 280.15684826650255, -81.00160607461055],
+    [-283.3091191657296, 233.78777150346502, -70.63758560557538],
+    [-243.80475148402235, 197.4364758130772, -62.501982515120865],
+    [-212.02758472549758, 168.1290562520729, -55.93005084805812],
+    [-185.91781913887507, 143.98590783040132, -50.502410976850946],
+    [-164.10271935415702, 123.7547904882927, -45.9401362566449],
+    [-145.62854727481175, 106.56596003500873, -42.049808768578835],
+    [-129.80992286963576, 91.79459261629398, -38.69268891141785],
+    [-116.1403619792622, 78.97909703570375, -35.76643129901388],
+    [-104.23667879980636, 67.770358765439, -33.193737050313665],
+    [-93.80309640051844, 57.89898212625034, -30.91503800318063],
+    [-84.60731938135599, 49.153454983391676, -28.883625369349602],
+    [-841.5412490713284, 754.2258205553459, -188.96039745910872],
+    [-602.5269096662904, 534.3193714449648, -139.35784215037214],
+    [-465.6224341003257, 408.272119034432, -110.99228222567555],
+    [-375.3246526831239, 325.04518451862305, -92.28224371111416],
+    [-310.72436475926054, 265.4178305783631, -78.87833065564283],
+    [-261.99627673165094, 220.36109001617768, -68.7422510043965],
+    [-223.848759998857, 185.01370546794976, -60.778891888861224],
+    [-193.15259309567855, 156.50180496755652, -54.3420919876394],
+    [-167.92631694053105, 133.00613880166838, -49.02353963379139],
+    [-146.84784862423643, 113.31319064538499, -44.551327194852306],
+    [-128.99778953900895, 96.57928847994951, -40.73671313423614],
+    [-113.71494568831106, 82.1978576265969, -37.44419983605556],
+    [-100.51041106206696, 69.72049501699509, -34.57376422848701],
+    [-89.01411561952413, 58.80787496000456, -32.049817141831596],
+    [-78.94028934529885, 49.1980341898972, -29.814078719274693],
+    [-70.06441947844435, 40.685214813966944, -27.820830514170087],
+    [-800.2688684967904, 724.8933476555861, -184.04252657706195],
+    [-571.24220144953, 512.9419849014873, -135.9671000716749],
+    [-439.4447549770203, 390.8679420219162, -108.33825884618282],
+    [-352.30621760517727, 310.05876422514507, -90.06523491029462],
+    [-289.8782592281241, 252.07380904570454, -76.95241175742566],
+    [-242.74739898303972, 208.2135539136593, -67.0251151731375],
+    [-205.82961115487115, 173.7808198445043, -59.21937053773115],
+    [-176.11246657179208, 145.99309627830195, -52.906095426185296],
+    [-151.6856403375674, 123.0862837950063, -47.68715155742342],
+    [-131.2730642002687, 103.88226101644464, -43.29708313847783],
+    [-113.98654601423345, 87.56119145721061, -39.55144676422828],
+    [-99.1868774865526, 73.53318619939523, -36.317724125215754],
+    [-86.40113554111397, 61.361901553599985, -33.49802590321097],
+    [-75.27117837349718, 50.71696350215782, -31.018333501050602],
+    [-65.52033887686005, 41.343206015853994, -28.8215502773343],
+    [-56.93118546591194, 33.04013405134078, -26.862867155257735],
+    [-762.7802081542839, 698.1327147338607, -179.53412022788714],
+    [-542.828209249077, 493.45441400995503, -132.8634116225763],
+    [-415.6840636159055, 375.02235376379207, -105.91376027261096],
+    [-331.42646576729675, 296.4305817443836, -88.0436754064761],
+    [-270.9796125504904, 239.95163369750384, -75.19908481389368],
+    [-225.3049353237583, 197.1879129771259, -65.46400525831034],
+    [-189.50767980944323, 163.5928069041399, -57.80321958009102],
+    [-160.6819891645056, 136.46770241004484, -51.603425796684895],
+    [-136.98248473429956, 114.0991652203666, -46.47588692216782],
+    [-117.17512641553266, 95.34159777756045, -42.161109132491596],
+    [-100.4002329846393, 79.39706840426038, -38.47862024850056],
+    [-86.03868723108754, 65.69102339288429, -35.29865412020509],
+    [-73.63217443706745, 53.79820274763141, -32.52529205009565],
+    [-62.83345711483435, 43.396460095123935, -30.085965590467346],
+    [-53.37419116135007, 34.236877044199794, -27.924670331338085],
+    [-45.04342073146874, 26.123796065246317, -25.99743644654311],
+    [-728.5855606532282, 673.6162206546295, -175.38297355885896],
+    [-516.9227237635291, 475.6217902991196, -130.01093282816385],
+    [-394.04020499007174, 360.54467593418576, -103.69050339061582],
+    [-312.42296996864167, 283.99645173249326, -86.19372644326096],
+    [-253.791831255646, 228.90507993395912, -73.59747962073772],
+    [-209.45134145038023, 187.1510857823913, -64.04019730479648],
+    [-174.68019655392587, 154.32671699576315, -56.51335260605679],
+    [-146.67027963437968, 127.81078424807022, -50.41829565256259],
+    [-123.63580452560483, 105.93662454456911, -45.37501937566654],
+    [-104.38130686707201, 87.58866945457473, -41.12956570006047],
+    [-88.07327994363257, 71.98921327022028, -37.50514909257535],
+    [-74.11105412084007, 58.577862340111864, -34.37455773068679],
+    [-62.049729791750835, 46.93959087477428, -31.643701399240477],
+    [-51.55208431890825, 36.75984940313033, -29.241356835077443],
+    [-42.35741869877485, 27.79548815441513, -27.112532530834194],
+    [-34.260726292735505, 19.855316254349678, -25.214036761964383],
+    [-697.2757959309866, 651.0697081280972, -171.5454763001542],
+    [-493.22119475964985, 459.24632098219666, -127.37952776629933],
+    [-374.25981400650653, 347.27367727529355, -101.64461499353048],
+    [-295.0735795559003, 272.6171353884305, -84.4952089840413],
+    [-238.11417988486542, 218.8098521220294, -72.12989073761166],
+    [-195.001694544598, 177.98973007814504, -62.737777715810076],
+    [-161.17451604358638, 145.8776630395401, -55.33522533202937],
+    [-133.91458004637164, 119.92423108566273, -49.337248405900894],
+    [-111.49102861048155, 98.50613870558838, -44.37198195572842],
+    [-92.74396267046656, 80.53566426565361, -40.19063030049799],
+    [-76.8640110304405, 65.25385839501662, -36.619845162152934],
+    [-63.26751620199253, 52.11345810290875, -33.534795601732576],
+    [-51.52195070670746, 40.70892721516761, -30.84309522164695],
+    [-41.29932870620111, 30.73275996323304, -28.474773846312477],
+    [-32.346002874787914, 21.947154833223355, -26.375783245902458],
+    [-24.46245452744091, 14.165061975678277, -24.50365611191246],
+    [-668.5064839898336, 630.2620485377377, -167.98491243260813],
+    [-471.46576704351395, 444.1602734128007, -124.94367985250122],
+    [-356.127615741817, 335.0721307099124, -99.75580973616695],
+    [-279.1890550709353, 262.17380852880854, -82.9309328454892],
+    [-223.77531093601652, 209.55965485353963, -70.78120503450852],
+    [-181.79787392273914, 169.606747755393, -61.54314055955879],
+    [-148.84279703377507, 138.15565534824702, -54.25638531157827],
+    [-122.27533438724598, 112.72375662990369, -48.348749075430604],
+    [-100.41546675454497, 91.72812936767528, -43.455991006938454],
+    [-82.13621873649383, 74.10697774987626, -39.334148731605346],
+    [-66.65056403546187, 59.11881426964487, -35.813091321075675],
+    [-53.39059572974633, 46.228599314742354, -32.770215726828255],
+    [-41.93524100567497, 35.03962959307277, -30.114729354092265],
+    [-31.96506581742535, 25.250954783520996, -27.777833809969867],
+    [-23.232951558364967, 16.629750332808413, -25.706362648202532],
+    [-15.544460626262259, 8.992819587611145, -23.858525542240763],
+    [-641.9856250732116, 610.9970125003944, -164.6701480144515],
+    [-451.43671797370547, 430.2204919999725, -122.68164253427733],
+    [-339.4595876902358, 323.8225311797541, -98.00674464700657],
+    [-264.60725255403827, 252.56447912731903, -81.48616744596191],
+    [-210.6281374528055, 201.0630756568825, -69.53844842253706],
+    [-169.70393528814364, 161.9185030819711, -60.444588335887126],
+    [-137.55776243056675, 131.08307561200493, -53.26611338410485],
+    [-111.63225715314451, 106.13657595153417, -47.44285766695592],
+    [-90.29463236725906, 85.53380593720864, -42.61774542422057],
+    [-72.44850494945162, 68.23719497673846, -38.55135584586704],
+    [-57.32761199345593, 53.521570784652255, -35.07658034680059],
+    [-44.37868195074323, 40.86331290491175, -32.072908044431195],
+    [-33.19128494341944, 29.87396838405533, -29.451042511790636],
+    [-23.453932004888912, 20.25870887316861, -27.143284944534575],
+    [-14.925569507599661, 11.789356827569952, -25.097296046086033],
+    [-7.4164796478187975, 4.286312740805392, -23.271920303688614],
+    [-617.4640665814022, 593.1069174991345, -161.57460765699264],
+    [-432.9457057014613, 417.30407107162887, -120.57477024891111],
+    [-324.0975365767616, 313.4236958549672, -96.38250760744894],
+    [-251.18849159364183, 243.70113072675966, -80.14822061525027],
+    [-198.54573564371682, 193.2410908038784, -68.3904239231649],
+    [-158.6024039176958, 154.8525905983656, -59.43201230433006],
+    [-127.20929349157991, 124.59264506630156, -52.35513590278673],
+    [-101.88116869011812, 100.09953288751574, -46.610966485282],
+    [-81.02927689350498, 79.86342353067741, -41.849184323669405],
+    [-63.58575888463746, 62.8694582578077, -37.83465016962805],
+    [-48.80371108473924, 48.40775905505485, -34.40310394708787],
+    [-36.14350649222124, 35.96540812926309, -31.436005915005918],
+    [-25.204636260525938, 25.161683457889243, -28.845468713024776],
+    [-15.683016297172195, 15.70749180189701, -26.56482868315422],
+    [-7.343244454238045, 7.379007809414438, -24.54252487585017],
+    [-2.043379347578331e-10, 1.8586159160817463e-10, -22.737998889246587],
+    [-378.1238410755161, 307.88281840555726, -67.83487442070944],
+    [-276.11130368811456, 219.84221044199037, -49.14980699350117],
+    [-220.48616830703654, 171.8666080467812, -39.00415727277888],
+    [-184.681254474043, 140.9950050936747, -32.49079462255755],
+    [-159.41320749157157, 119.20811140683928, -27.900609509822967],
+    [-140.50417219815236, 102.89894765518275, -24.466745114736355],
+    [-125.76707077878132, 90.1803969055089, -21.78889245131262],
+    [-113.93435043132914, 79.95941972830298, -19.63567300129311],
+    [-104.21532373394494, 71.55461292559164, -17.86312981589133],
+    [-96.08847707855598, 64.51682669657352, -16.37655147798664],
+    [-89.19443023616583, 58.53674374606132, -15.110830176638405],
+    [-83.27661853080681, 53.39368015645271, -14.019594751280584],
+    [-78.14646200996577, 48.92553208309302, -13.068839923848206],
+    [-73.66192156791803, 45.01027967491576, -12.233010208657273],
+    [-69.71376519898175, 41.554145456272074, -11.49249568301672],
+    [-66.21647300294785, 38.48375518982764, -10.831976080403916],
+    [-348.62657175448163, 287.636959750534, -64.5356534671373],
+    [-254.24709618451027, 205.34333581124181, -46.89951431408748],
+    [-202.36454747513656, 160.12535385932296, -37.24183003358157],
+    [-168.8359631945604, 130.90655848502382, -31.014558134038513],
+    [-145.12226167803428, 110.23614146912475, -26.614399641333236],
+    [-127.35399251494961, 94.739612112643, -23.31699955438101],
+    [-113.4963592392398, 82.6434513606768, -20.742513614344244],
+    [-102.36627992749838, 72.91708545159213, -18.670677861291235],
+    [-93.22399073711526, 64.91648935304349, -16.964115088816545],
+    [-85.58066151222276, 58.21634803673632, -15.532270120440327],
+    [-79.09893906937216, 52.52331594887544, -14.312796904035812],
+    [-73.53769723100044, 47.627846449900325, -13.261235540143158],
+    [-68.71953002316116, 43.37585945903578, -12.344948259045461],
+    [-64.51070554557427, 39.651275374977054, -11.539385637583473],
+    [-60.80830954301521, 36.364817087533204, -10.825695559664311],
+    [-57.53172129459139, 33.446587955675184, -10.18914022920608],
+    [-323.2883293724429, 270.1720058634083, -61.68325259318352],
+    [-235.34434519392858, 192.7611098300459, -44.94397107612919],
+    [-186.64475829365466, 149.90605970249902, -35.70685555309167],
+    [-155.05990595749844, 122.10901424680668, -29.727020282756186],
+    [-132.67624595328516, 102.40096082742146, -25.491505299699583],
+    [-115.88521139780525, 87.60567166165497, -22.312452261892673],
+    [-102.78135859590348, 76.0468834041376, -19.827652023895983],
+    [-92.25356908115401, 66.7476482294191, -17.826441070679024],
+    [-83.60555848615039, 59.096095020234706, -16.177135225463196],
+    [-76.37645091994689, 52.68744453288234, -14.792779997905418],
+    [-70.24775959265422, 47.242129675007945, -13.61342427617445],
+    [-64.99159707814812, 42.56022164085249, -12.596266845297796],
+    [-60.44014879232333, 38.49457544087158, -11.709857458782123],
+    [-56.46681957891203, 34.93424950943457, -10.930520779812813],
+    [-52.97412682232043, 31.793861782004818, -10.240063357020967],
+    [-49.88566458918866, 29.006527086944843, -9.624253829019334],
+    [-301.29612806309757, 254.94830038056523, -59.19022357774648],
+    [-218.85547018575414, 181.74432588142446, -43.22861113248365],
+    [-172.89751987588133, 140.9394656745485, -34.35854773835068],
+    [-142.99226248351408, 114.37974586798964, -28.595229895911338],
+    [-121.75971237655304, 95.51045683899822, -24.503954085893376],
+    [-105.8150571977556, 81.32676340027322, -21.42863153832696],
+    [-93.36418599472982, 70.23675312502205, -19.022448423059995],
+    [-83.35807075321922, 61.3100932372307, -17.083138117244733],
+    [-75.13801019479246, 53.9629088439714, -15.48400227182561],
+    [-68.2673263206763, 47.80832362371503, -14.1412427741161],
+    [-62.44388244425118, 42.57879442175507, -12.997009404214959],
+    [-57.451275198145, 38.08279236056143, -12.009956757571103],
+    [-53.13001689475638, 34.17923342763814, -11.149677292924867],
+    [-49.35971258434387, 30.761669026548876, -10.393263408627265],
+    [-46.04760139861917, 27.748124766131113, -9.723100318147766],
+    [-43.120943314027095, 25.07435121626178, -9.125401665879988],
+    [-282.035166939663, 241.55748811216176, -56.99056947463742],
+    [-204.358804760403, 172.02223507907001, -41.71148356620515],
+    [-160.78876748137762, 133.01574049036816, -33.165289151682025],
+    [-132.35016042699556, 107.54393991136736, -27.593387039619174],
+    [-112.12394224050477, 89.41289048103617, -23.629717484852325],
+    [-96.9195283526447, 75.76768152641058, -20.646174917869317],
+    [-85.03973815173664, 65.09039039789843, -18.309538399557084],
+    [-75.48971360337399, 56.49164957418196, -16.424966257442765],
+    [-67.64359236454733, 49.41222009274031, -14.870173259263227],
+    [-61.08586483135381, 43.48099558262496, -13.564157025636614],
+    [-55.52871105874656, 38.441032609152785, -12.450929085423654],
+    [-50.7657914663319, 34.10821194080913, -11.490434576411952],
+    [-46.644920328680634, 30.346798776683716, -10.653190518564134],
+    [-43.0511428806627, 27.05431158024633, -9.916970173444525],
+    [-39.89584331226349, 24.151785862620414, -9.264671999526225],
+    [-37.10950122624081, 21.577300996887097, -8.682904364015432],
+    [-265.0320146481954, 229.6848679123675, -55.03355590067716],
+    [-191.52453994789428, 163.38281119588487, -40.35982086741802],
+    [-150.05480598150953, 125.96900395331183, -32.10215110156748],
+    [-122.9088692534246, 101.46247953877068, -26.70101608582206],
+    [-103.57034766817296, 83.98688642538096, -22.85122460914069],
+    [-89.01903461772663, 70.81981972362378, -19.949574432134394],
+    [-77.64299645095909, 60.5089045931573, -17.674965700977587],
+    [-68.49509936708961, 52.201121031991306, -15.839185702632154],
+    [-60.978449439847445, 45.359112744950906, -14.32389218961944],
+    [-54.696228823250195, 39.62579212905778, -13.050581895235336],
+    [-49.37326923068562, 34.753644212014635, -11.964931088179947],
+    [-44.812130191963995, 30.565143856371513, -11.0280391095912],
+    [-40.86708645318193, 26.92931858473897, -10.211249960042997],
+    [-37.42799880559384, 23.747219830539763, -9.49294593791427],
+    [-34.40992213076799, 20.94256439099496, -8.85648460778925],
+    [-31.74618828754156, 18.45550667324326, -8.288827414248455],
+    [-249.91625524733723, 219.08398980863768, -53.27954427398154],
+    [-180.09111516437622, 155.657680572099, -39.1476649143157],
+    [-140.48482978529617, 119.66643716613373, -31.149223320691497],
+    [-114.48771358238076, 96.02332432864166, -25.901669235750195],
+    [-95.93856606612556, 79.13424983732692, -22.154298376549374],
+    [-81.96802605265742, 66.39498645858639, -19.3262721365041],
+    [-71.03980307377154, 56.41173840207312, -17.10739416389923],
+    [-62.24917456477812, 48.36401113876982, -15.315420597488925],
+    [-55.02501780051374, 41.73404739925803, -13.835561538740881],
+    [-48.987157302365574, 36.17732336703103, -12.591565973115754],
+    [-43.871696604770044, 31.454777991238974, -11.530610727644563],
+    [-39.489128015911064, 27.394800831000644, -10.614835594517714],
+    [-35.69948784918991, 23.870692321844224, -9.816330059170024],
+    [-32.396933211002086, 20.78667455982658, -9.114025418625491],
+    [-29.49979505169298, 18.068871149775386, -8.491693056558962],
+    [-26.94395168353508, 15.659299437847622, -7.936612636374558],
+    [-236.3939745717145, 209.55910605498195, -51.697119893901956],
+    [-169.84851784964096, 148.71146296188655, -38.05419178891805],
+    [-131.9083866997323, 114.00047059895189, -30.290418634875554],
+    [-106.93987289032044, 91.13526534067016, -25.18198960132593],
+    [-89.09777423328835, 74.77472243066893, -21.527380840579983],
+    [-75.6473797013952, 62.42086137433832, -18.765997330694727],
+    [-65.1200538132333, 52.732645132088265, -16.597527125159797],
+    [-56.64905553898027, 44.918905260556926, -14.84514160377039],
+    [-49.68636270777057, 38.47956848157489, -13.397275075530034],
+    [-43.86672774282494, 33.08145284369859, -12.179721134075042],
+    [-38.93637020488421, 28.49313177536025, -11.141018909920557],
+    [-34.712904888886406, 24.548337669511124, -10.244252765320185],
+    [-31.061543099075887, 21.124231565368188, -9.462188921949345],
+    [-27.88030213518219, 18.127900764702957, -8.774256992577282],
+    [-25.090457352191716, 15.48764412633865, -8.164603926903304],
+    [-22.630176367297107, 13.147162918600023, -7.620798148282734],
+    [-224.2290048080352, 200.9527486097379, -50.261062674468675],
+    [-160.62624471117337, 142.43408641480207, -37.062506731977535],
+    [-124.18623171768961, 108.88308215859973, -29.512602541283954],
+    [-100.14487546771953, 86.72332682053347, -24.53102299205906],
+    [-82.94025379543264, 70.84209471864824, -20.960960280419794],
+    [-69.95872831205031, 58.83760768277884, -18.260273645272033],
+    [-59.792603169005176, 49.41667492658902, -16.137673559206746],
+    [-51.60938736825099, 41.814748537309214, -14.421277611734531],
+    [-44.88190593666306, 35.547817307908694, -13.002465943492325],
+    [-39.258390695426435, 30.293004954397404, -11.808900409815834],
+    [-34.49419964242818, 25.82582375549391, -10.790364919095007],
+    [-30.41338405253035, 21.98486304414131, -9.910806833751714],
+    [-26.885833215125984, 18.650793870451547, -9.143610584925192],
+    [-23.8130548450575, 15.733308840228649, -8.468660929265727],
+    [-21.11898732967452, 13.162684940456291, -7.870447782774717],
+    [-18.743869331940967, 10.884154894541917, -7.336803213638526],
+    [-213.2294420208098, 193.13681055083345, -48.950893916984946],
+    [-152.28447669746424, 136.73515197228605, -36.15876246086065],
+    [-117.2035501401203, 104.24156997159686, -28.8049493793613],
+    [-94.00299409159527, 82.7253303457798, -23.93970488682068],
+    [-77.37655801478944, 67.28128321610149, -20.4471415241801],
+    [-64.82017927936074, 55.59531242786743, -17.802047503315876],
+    [-54.98140192903924, 46.41788756964154, -15.721419641392218],
+    [-47.05881220681753, 39.00877214084647, -14.037920689067073],
+    [-40.54416365218293, 32.89863093273812, -12.645638336323403],
+    [-35.09793266158449, 27.774006844817855, -11.473951616631073],
+    [-30.48378146182867, 23.41675533681533, -10.47378845008935],
+    [-26.5316119107683, 19.669906278602387, -9.609889215157482],
+    [-23.115566488488422, 16.417340073480876, -8.856206309979088],
+    [-20.140319073770574, 13.571126479779146, -8.193041783621489],
+    [-17.532247906616323, 11.063362869595823, -7.605202268406769],
+    [-15.233465729939436, 8.84067569956615, -7.08076081848413],
+    [-203.23775959181617, 186.0060005375771, -47.7498116178254],
+    [-144.7075112945746, 131.53973801283468, -35.33150413222582],
+    [-110.86486552085344, 100.01537395865012, -28.158459308864188],
+    [-88.43100599769026, 79.0892931580089, -23.400472847565673],
+    [-72.33183543592763, 64.04610502943835, -19.979319560618276],
+    [-60.16304368555655, 52.65202858796133, -17.38540462077234],
+    [-50.62253469157861, 43.697596169057114, -15.343377166012688],
+    [-42.93725126076316, 36.46489533938839, -13.690099300568559],
+    [-36.61622811394012, 30.49807263496473, -12.322160627780177],
+    [-31.33112491915018, 25.492325613576728, -11.170526879935771],
+    [-26.85319049031946, 21.235338774227582, -10.187182909331499],
+    [-23.01767283493308, 17.574222856066797, -9.337601598735295],
+    [-19.702595534740862, 14.395803367855061, -8.59625907760904],
+    [-16.815556038828333, 11.614355008820272, -7.943846008830814],
+    [-14.285120697755193, 9.163619389904595, -7.365456714464591],
+    [-12.055135841213438, 6.99151741408071, -6.849388791510112],
+    [-194.1234572185469, 179.47297627275177, -46.64390050794014],
+    [-137.79880732652296, 126.78523303280843, -34.57117588923435],
+    [-105.09016456736664, 96.15365515658458, -27.565591229734114],
+    [-83.35894619297106, 75.77143414194686, -22.90697011584121],
+    [-67.74300109018847, 61.097563672248775, -19.55192870223149],
+    [-55.92930925340669, 49.97226070642794, -17.0053512430822],
+    [-46.66192315921764, 41.22300387182244, -14.998988734841436],
+    [-39.19379110204151, 34.152481265361686, -13.373602163028355],
+    [-33.04980386443806, 28.317284453514755, -12.028104247747432],
+    [-27.911885369210054, 23.420601432962904, -10.894933905015785],
+    [-23.558249059073304, 19.255498727087705, -9.927057121899022],
+    [-19.829044642168476, 15.672849231629376, -9.090625395884024],
+    [-16.60588846928421, 12.562220700868941, -8.360605302609729],
+    [-13.799016489494488, 9.839888371256997, -7.71804141822725],
+    [-11.339039194498357, 7.441136647974124, -7.148299141932501],
+    [-9.171385558492968, 5.315074759511325, -6.6398831283775275],
+    [-185.77751688024682, 173.4646733214907, -45.62153727596783],
+    [-131.47719943721404, 122.41891456793296, -33.86974441327126],
+    [-99.8119136221266, 92.61343037119906, -27.019980498573187],
+    [-78.72759374885155, 72.73462837260423, -22.45381637620973],
+    [-63.55653673338089, 58.4025145499533, -19.160247737528326],
+    [-52.0696680434882, 47.525781530961595, -16.657643590147455],
+    [-43.053527938747116, 38.96613512546897, -14.684375396385052],
+    [-35.78502411542068, 32.04535871575628, -13.084840098112014],
+    [-29.803661951155522, 26.331582750118425, -11.760116999634294],
+    [-24.800828339326724, 21.53540467399519, -10.64401877486048],
+    [-20.561158631081252, 17.454881768372793, -9.690426127210742],
+    [-16.929330970686586, 13.94438211939412, -8.866124373571727],
+    [-13.790249287331761, 10.895995443229133, -8.14653302905504],
+    [-11.056609841871975, 8.22787309893013, -7.513030470694418],
+    [-8.66089907413788, 5.87672232993865, -6.951233093780785],
+    [-6.550005833982912, 3.792753981904126, -6.449838307024783],
+    [-178.1081681144698, 167.9194999000984, -44.672936932326785],
+    [-125.67397281183324, 118.39607689220665, -33.22040836093112],
+    [-94.97273699941644, 89.35811930295691, -26.516219505524603],
+    [-74.48650585729719, 69.94719717798739, -22.03642866233328],
+    [-59.72676351261224, 55.932615820140406, -18.80024707996341],
+    [-48.54196255418677, 45.28669851195045, -16.338653612112296],
+    [-39.75792772850942, 36.90299011810234, -14.396216378720073],
+    [-32.67373389880166, 30.121046225941257, -12.820736711422176],
+    [-26.842412647645872, 24.51973912897631, -11.515322586237307],
+    [-21.964110792918564, 19.816564361648616, -10.41507280544113],
+    [-17.829409041370464, 15.814225371441045, -9.474724229461472],
+    [-14.287177434184356, 12.370343388389584, -8.661655173535461],
+    [-11.225365070863981, 9.379356433299261, -7.951708968276191],
+    [-8.558945170366382, 6.761161286318879, -7.326576025455806],
+    [-6.222140982250608, 4.453789203234474, -6.772107359891442],
+    [-4.163192964316743, 2.408476019810081, -6.2771804979884545],
+    [-171.03761596001576, 162.7851685473676, -43.78980280433306],
+    [-120.33057912906392, 114.67856814427547, -32.61737177304725],
+    [-90.52359250608605, 86.3564016960649, -26.04968540776033],
+    [-70.59246572354893, 67.3819517922436, -21.650880081127422],
+    [-56.214473308599814, 53.66349607610726, -18.468467758225668],
+    [-45.30995075898614, 43.2327109400451, -16.04526239145025],
+    [-36.74118716562169, 35.01287010086835, -14.131653342776538],
+    [-29.827845310584742, 28.36013149120881, -12.57864116129132],
+    [-24.135523202001835, 22.863404111190043, -11.291240274532392],
+    [-19.372507162376458, 18.24662893131778, -10.205757935088048],
+    [-15.334902397105186, 14.316850357591461, -9.277735235956554],
+    [-11.875473664129773, 10.934728412667981, -8.475107305979927],
+    [-8.885000214311606, 7.996895031621197, -7.7741153942757],
+    [-6.280570202385283, 5.424876622464677, -7.15674265545918],
+    [-3.998020335137997, 3.1579406507406107, -6.609060176506261],
+    [-1.9868465754454188, 1.1482797968160467, -6.120114370447771],
+    [-164.49948557405844, 158.01700190604117, -42.96505354097398],
+    [-115.39683559164953, 111.23363580496432, -32.05566556195723],
+    [-86.42232456832532, 83.58131006378139, -25.616403625968267],
+    [-67.00824656295319, 65.01543089926366, -21.29378739822525],
+    [-52.985835197100116, 51.5740885053536, -18.16192480588673],
+    [-42.34231664734234, 41.34451397491039, -15.77477480310459],
+    [-33.973947561704385, 33.27783479426021, -13.888213548303945],
+    [-27.219579438027594, 26.745771209069797, -12.356258013383625],
+    [-21.656525804229876, 21.346641759604505, -11.085720177541381],
+    [-17.00066209998084, 16.810430067156435, -10.014046521427776],
+    [-13.053244427939688, 12.94824977281774, -9.097536088405107],
+    [-9.670668388046163, 9.623608831712069, -8.304648571081364],
+    [-6.746351876887225, 6.735195254809315, -7.612000008043118],
+    [-4.1993539331562015, 4.206061876550933, -7.001849097079108],
+    [-1.9670147564736153, 1.9766339359164158, -6.460474004772112],
+    [-5.50753258014726e-10, 4.833306864741706e-10, -5.97707999185309],
+    [-182.81358903904098, 148.81717491112667, -32.63424553836232],
+    [-133.71370199603956, 106.43966900527055, -23.642046386868056],
+    [-106.92546005139634, 83.33184587682447, -18.755583200187044],
+    [-89.66992654320744, 68.45001897778124, -15.615485862242481],
+    [-77.48284107231996, 57.93800339033315, -13.4001882873846],
+    [-68.35520181640368, 50.061393610498044, -11.741070659053928],
+    [-61.235243811729624, 43.91271255431148, -10.445692629973308],
+    [-55.513332570598294, 38.96627761579659, -9.402808757272448],
+    [-50.80912622720237, 34.89432601945784, -8.54319343992725],
+    [-46.87171916328587, 31.48077311089286, -7.821293254578815],
+    [-43.52817738839323, 28.576778701038318, -7.205786703564048],
+    [-40.655017671352134, 26.07613078950514, -6.674361099418222],
+    [-38.161459358973026, 23.900795177014363, -6.210650282170209],
+    [-35.979112425265264, 21.992021371137646, -5.802352074613988],
+    [-34.0553725742011, 20.304647850598815, -5.440024323821379],
+    [-32.34904678706063, 18.80333176482158, -5.11628870014454],
+    [-168.54314306463112, 139.0220936775557, -31.03828159489328],
+    [-123.12466881742226, 99.41741771367032, -22.55237331227998],
+    [-98.14149373166089, 77.64025289946352, -17.90150790495966],
+    [-81.98394464621524, 63.55611196071414, -14.899585866789211],
+    [-70.54665730779381, 53.583024038060955, -12.776094516395517],
+    [-61.96933262257012, 46.098722155032725, -11.182922051554597],
+    [-55.27363241298499, 40.25052531010084, -9.9375040919983],
+    [-49.890653398234186, 35.54288409038453, -8.933959582774849],
+    [-45.46461067110216, 31.666068766063894, -8.106239140114267],
+    [-41.76036119243561, 28.415495198729577, -7.410796036473498],
+    [-38.615595369755034, 25.65003079287719, -6.8176424495361685],
+    [-35.91427736671963, 23.268838920716448, -6.305388837157303],
+    [-33.57101740414577, 21.1977585999635, -5.858329682042457],
+    [-31.521433734012266, 19.38090722912938, -5.4646489322809115],
+    [-29.715970766564602, 17.775296334868383, -5.115270676606613],
+    [-28.11579990520834, 16.347262502240266, -4.8030981439314635],
+    [-156.2874554322699, 130.57496080530632, -29.658951843897594],
+    [-113.97129688469734, 93.32490555491874, -21.605701611716388],
+    [-90.52257278232285, 72.68738275103034, -17.15778976402689],
+    [-75.30217590357971, 59.28909269950819, -14.275316128190322],
+    [-64.50622714366594, 49.78033652648751, -12.231331430154514],
+    [-56.40015338039566, 42.63442587431048, -10.695324395345434],
+    [-50.06795587675223, 37.04556745764565, -9.493236320484357],
+    [-44.97541434162686, 32.54406709994903, -8.523817234544396],
+    [-40.787700654262416, 28.835699496407344, -7.72376445013791],
+    [-37.283146865944275, 25.72578399988184, -7.051268775797116],
+    [-34.30853873941848, 23.079833657744864, -6.477498308922872],
+    [-31.754223460965402, 20.80165137823151, -5.981864230603236],
+    [-29.53943622350019, 18.82042206117792, -5.549234408661194],
+    [-27.603236989002497, 17.082742329209243, -5.168214918485572],
+    [-25.898689853567255, 15.547503414309961, -4.83004800250043],
+    [-24.38899921028317, 14.182495392242563, -4.527881558087868],
+    [-145.65168458024593, 123.21349477661498, -28.453746728632552],
+    [-105.98736882016163, 87.99125189746496, -20.77548004960704],
+    [-83.8598201209539, 68.34211291551327, -16.504617129851592],
+    [-69.44892540404848, 55.54046574437226, -13.726621577753123],
+    [-59.20784595755256, 46.43625201937667, -11.752262453385189],
+    [-51.50979416408694, 39.58536956500625, -10.26634182534437],
+    [-45.492402765143865, 34.22267213197606, -9.10222273128788],
+    [-40.65136322251441, 29.900930098946745, -8.162703759789888],
+    [-36.669954365657155, 26.339411499257718, -7.386886568255739],
+    [-33.33816754562064, 23.352065436941665, -6.7344843715776594],
+    [-30.510667329037833, 20.810201627487807, -6.177678780735156],
+    [-28.083335425175672, 18.621673074493298, -5.696583426276973],
+    [-25.97942041557651, 16.718587410871383, -5.276569392729132],
+    [-24.140970907469015, 15.049708137401408, -4.9066133389978726],
+    [-22.523327179538583, 13.575570140289527, -4.5782368778751765],
+    [-21.091460955232378, 12.26523495704849, -4.284802786116264],
+    [-136.33747596135964, 116.73938535044304, -27.390609230698725],
+    [-98.96805649456067, 83.28483603247713, -20.041312904308864],
+    [-77.99083119033526, 64.50235153141712, -15.9266074176217],
+    [-64.28658107852299, 52.225116592915725, -13.240942817521756],
+    [-54.530441132226095, 43.47684349663922, -11.32815877962135],
+    [-47.18915010975838, 36.88564191156913, -9.886539745817057],
+    [-41.44701644723411, 31.722005005715232, -8.755998731563729],
+    [-36.825817857341065, 27.558436871605558, -7.842912726216594],
+    [-33.0246484694805, 24.12607442881965, -7.0885106405165725],
+    [-29.84369532955345, 21.246465219128186, -6.453853570616619],
+    [-27.144519286865474, 18.796026866209917, -5.912020887018883],
+    [-24.827850165760086, 16.686183457471408, -5.443748654009219],
+    [-22.82045448656376, 14.85162098589361, -5.034853957115932],
+    [-21.067002023182113, 13.243015312563587, -4.674641955459184],
+    [-19.524830329638736, 11.822352417718285, -4.354881930922959],
+    [-18.16046256003686, 10.559816665309054, -4.06912716508717],
+    [-128.11523577644937, 110.99990840614188, -26.44491240316509],
+    [-92.75326775910831, 79.10265939777403, -19.38728047532962],
+    [-72.78751069510882, 61.087454216382724, -15.411641469066213],
+    [-59.70590418205202, 49.275366128452525, -12.808320063623839],
+    [-50.3774259764726, 40.843028639007734, -10.950469808950684],
+    [-43.350826282118234, 34.48234676590178, -9.54836829361116],
+    [-37.851450485771956, 29.495376268257047, -8.447767244252898],
+    [-33.424050892422954, 25.472124114435612, -7.558237199956439],
+    [-29.78166823392131, 22.15426580062929, -6.822906977196595],
+    [-26.733489349161925, 19.37010102696452, -6.20404208150843],
+    [-24.1471672076159, 17.000589186081072, -5.675524329062523],
+    [-21.92771714973121, 14.96033115330603, -5.218646238916772],
+    [-20.0050170606219, 13.186307465164186, -4.819623661295103],
+    [-18.326056010454053, 11.63089635871364, -4.468055474981343],
+    [-16.849939512357196, 10.25737589424826, -4.155932405904576],
+    [-15.544564502869767, 9.036930421950169, -3.876978059343316],
+    [-120.80543574716788, 105.87553520421415, -25.597426839360587],
+    [-87.21612053011694, 75.36298271522693, -18.800779349839754],
+    [-68.14752589531282, 58.03289499044642, -14.950046403135826],
+    [-55.619137667492396, 46.63675165368691, -12.420759166878282],
+    [-46.6708647728635, 38.48704599666797, -10.612301624974087],
+    [-39.924049276480446, 32.33256576830549, -9.245718521455174],
+    [-34.640438322035585, 27.503563090827072, -8.17201166794402],
+    [-30.38519515439199, 23.60570224251716, -7.303626147272831],
+    [-26.88376419831761, 20.390094419329948, -6.585401863280969],
+    [-23.953342951154397, 17.691088675461227, -5.980689380995839],
+    [-21.467010412338755, 15.393715338931559, -5.464093565220623],
+    [-19.33360086681795, 13.41542375483841, -5.017408364450674],
+    [-17.48576603653768, 11.695252431740773, -4.6272089592620596],
+    [-15.87256345992383, 10.187106796115621, -4.2833588863825645],
+    [-14.454675922582432, 8.855424222826407, -3.9780483856455433],
+    [-13.201226325024146, 7.67228641665132, -3.7051544982445033],
+    [-114.26569635864166, 101.27137430352549, -24.83291986855222],
+    [-82.2547968818554, 72.0001224650705, -18.27170468831149],
+    [-63.98818383443771, 55.28644746082848, -14.53401144253208],
+    [-51.95501095837124, 44.26496226496542, -12.07177162574004],
+    [-43.347221011703425, 36.36988559373709, -10.308037667588762],
+    [-36.850936695777634, 30.401129786516073, -8.973597581504373],
+    [-31.760454493767984, 25.714334595725298, -7.92421111029502],
+    [-27.659212058161682, 21.929277386893112, -7.074930454866722],
+    [-24.283769264612687, 18.80558060483092, -6.372148062599844],
+    [-21.458507147034272, 16.183052690390213, -5.780199210024601],
+    [-19.06137304275801, 13.950399181151624, -5.274344978690619],
+    [-17.004629122488474, 12.02764165970192, -4.836834384206943],
+    [-15.223417788204852, 10.355677388500924, -4.454568683539821],
+    [-13.668654460719694, 8.889791345184843, -4.117651546365837],
+    [-12.302438999114656, 7.595468257951733, -3.8184542078400723],
+    [-11.094996013712558, 6.44560058838572, -3.550992876394359],
+    [-108.38166870350132, 97.11113141746452, -24.13916898543965],
+    [-77.78667623918611, 68.96070255545685, -17.79186251119536],
+    [-60.241968561121475, 52.805400121120044, -14.15716291893381],
+    [-48.65507931308139, 42.12359686992405, -11.756039499351411],
+    [-40.354211125986836, 34.45938610569134, -10.033058494520056],
+    [-34.08372147481762, 28.658959274532112, -8.727887427133467],
+    [-29.16721877434886, 24.100973988644792, -7.700627839467167],
+    [-25.204616024898858, 20.418015370026513, -6.8687125720361415],
+    [-21.94250204321828, 17.377434483961718, -6.179952169915656],
+    [-19.21174287887592, 14.823999071830485, -5.599581431716175],
+    [-16.894682703071204, 12.649754088993197, -5.103460866208476],
+    [-14.906680637907021, 10.777058748429887, -4.674255128514331],
+    [-13.185129950145656, 9.148499737807517, -4.299163252289841],
+    [-11.682625467481792, 7.720616015467051, -3.968508156334274],
+    [-10.362548271040412, 6.459844383191923, -3.674826293695913],
+    [-9.1961192592781, 5.339814329086272, -3.4122608779429515],
+    [-103.06047363625886, 93.33276358079853, -23.506253488684113],
+    [-73.74403815496159, 66.20090794327545, -17.354540023547173],
+    [-56.85323729611739, 50.55449363119104, -13.814250174097106],
+    [-45.67098687713942, 40.18248383060856, -11.469164490355023],
+    [-37.648443588112706, 32.72880573173387, -9.783531804631616],
+    [-31.582658829284686, 27.08181153316898, -8.505163115922498],
+    [-26.823806186194027, 22.64115823153362, -7.498146555918057],
+    [-22.986744896169206, 19.05112507552378, -6.682102025728641],
+    [-19.82716793871907, 16.08612319303043, -6.006143129518899],
+    [-17.18183114382008, 13.595451968070776, -5.436331230008391],
+    [-14.937073334247282, 11.474208351977277, -4.949077589922893],
+    [-13.011066034398334, 9.646886775141223, -4.527428440831443],
+    [-11.34325327497446, 8.05762166079204, -4.158856913387221],
+    [-9.887753544912893, 6.664095889676376, -3.833886822855094],
+    [-8.609113180281794, 5.43361676336553, -3.5452063251760744],
+    [-7.479459249834085, 4.340487576061255, -3.2870752034634747],
+    [-98.22589947944003, 89.88529798825482, -22.926037003776955],
+    [-70.0708665368532, 63.684441312843695, -16.95418660976674],
+    [-53.77573990311163, 48.50437105971631, -13.50091008710481],
+    [-42.96239942693581, 38.41641130631581, -11.2074788793305],
+    [-35.19362471034633, 31.15573503424969, -9.556253073926278],
+    [-29.314428582738937, 25.649323425306566, -8.302554294157856],
+    [-24.69919884691927, 21.316098622382807, -7.314151394558563],
+    [-20.976430233667553, 17.811075911338587, -6.512684481449914],
+    [-17.910126193567265, 14.91515066636102, -5.848470976090299],
+    [-15.342420947408144, 12.48178523753815, -5.28833581992543],
+    [-13.163302131033353, 10.40888059170792, -4.809198982968583],
+    [-11.293513351470938, 8.622894803728428, -4.394459367389947],
+    [-9.67436644680074, 7.069380046285879, -4.031842424363847],
+    [-8.261376324660592, 5.707073080591589, -3.7120578835839124],
+    [-7.020154117937042, 4.504080778232476, -3.427933832845421],
+    [-5.923655137940262, 3.4353253883732218, -3.17383756064125],
+    [-93.81483224650744, 86.72646690329252, -22.391783510453923],
+    [-66.72044003436896, 61.38098158534293, -16.586173667214172],
+    [-50.97073250319037, 46.63039841118788, -13.213488242215842],
+    [-40.49542231421529, 36.80415463056973, -10.967900981753486],
+    [-32.95917921899752, 29.72126021038032, -9.34852317723508],
+    [-27.250901932314324, 24.344271598607076, -8.117638401306918],
+    [-22.767164303514825, 20.109874316775375, -7.146430766835963],
+    [-19.148964268068767, 16.68298907584719, -6.3584156543392245],
+    [-16.16792937040645, 13.850495825221381, -5.70502804329815],
+    [-13.671129566677099, 11.469699699681485, -5.153801676919336],
+    [-11.55190170501493, 9.441090166637485, -4.682128646710049],
+    [-9.733350336658956, 7.692937830300597, -4.2737350509289165],
+    [-8.158502819937793, 6.172103982560587, -3.916580440945818],
+    [-6.784155905304055, 4.838297932978212, -3.6015469056275227],
+    [-5.576898523891161, 3.660364547373131, -3.321592245317275],
+    [-4.510446619662957, 2.6137973210699523, -3.0711833686136867],
+    [-89.77456468008756, 83.8209243526127, -21.897868375434747],
+    [-63.65349225823554, 59.265006228282516, -16.246611483815453],
+    [-48.40552604832469, 44.911756334487656, -12.948901495154804],
+    [-38.24137662993661, 35.32772319170138, -10.747823477034494],
+    [-30.919178373931828, 28.409312179549115, -9.158053394808425],
+    [-25.368179700405655, 23.151994864788335, -7.94835747154094],
+    [-21.005378061006777, 19.00891028218859, -6.993102997563903],
+    [-17.483289515307018, 15.654159401399612, -6.217553848397566],
+    [-14.580567986948964, 12.880170161209012, -5.574187069057776],
+    [-12.148833368401023, 10.54781052743579, -5.031197243044018],
+    [-10.08450890729253, 8.559968623007421, -4.56641626758517],
+    [-8.31288041910736, 6.846599226084692, -4.163875931155861],
+    [-6.778553015617329, 5.355773571449385, -3.81175308457723],
+    [-5.43950679427374, 4.048103615761393, -3.501090570940456],
+    [-4.263232397705946, 2.8931182259726222, -3.2249669389699065],
+    [-3.2241472391251036, 1.8668402915498263, -2.9779417988488093],
+    [-86.0607417713943, 81.13888415160127, -21.439558066672515],
+    [-60.83679087694978, 57.31488106038115, -15.932207980357118],
+    [-46.05235752232096, 43.330733076077735, -12.70453120140998],
+    [-36.17584258508636, 33.97177110423779, -10.54502619885801],
+    [-29.051498863597896, 27.206155210354417, -8.982890941255393],
+    [-23.64583516939024, 22.05993887980466, -7.792952694828743],
+    [-19.394730802940195, 18.001564592917234, -6.852557670008741],
+    [-15.961357350324587, 14.713676376424031, -6.088606613226462],
+    [-13.13087127543975, 11.99386627482582, -5.454552143743167],
+    [-10.759104034133278, 9.70631877553873, -4.919207428612967],
+    [-8.745337679518855, 7.756156151782677, -4.46081623037038],
+    [-7.016872304904224, 6.07489646763975, -4.063695449570323],
+    [-5.5197787843258475, 4.611741276873133, -3.7162260308383934],
+    [-4.213130926901694, 3.3281409665856976, -3.40960079898954],
+    [-3.0652541127654582, 2.1942607634232227, -3.1370111399761704],
+    [-2.0512151098203075, 1.1866160023927907, -2.893103257992101],
+    [-82.63577386859056, 78.65506746698202, -21.012840238136974],
+    [-58.24202861505718, 55.51214930785874, -15.64015857981841],
+    [-43.88750334841555, 41.872168600502825, -12.478139432889561],
+    [-34.27790473272348, 32.72313170478585, -10.357607385690896],
+    [-27.337156784679454, 26.099981502254998, -8.821360045964623],
+    [-22.0663127303363, 21.05729405694374, -7.649912488435607],
+    [-17.91877674704109, 17.07779936242599, -6.723408961003058],
+    [-14.567616075571602, 13.85212126088735, -5.9702882085899445],
+    [-11.804027922699246, 11.182676388527145, -5.344919516828225],
+    [-9.487756805296678, 8.936747799164893, -4.816697190521843],
+    [-7.520741023342109, 7.021544892871926, -4.364251812600079],
+    [-5.832152407402759, 5.370046089704183, -3.972167991081066],
+    [-4.369423571267918, 3.932508501347579, -3.6290182075897524],
+    [-3.092644420351733, 2.6711653965158746, -3.3261359941572253],
+    [-1.970915762627445, 1.5567760769256789, -3.0568185521009306],
+    [-0.9799075271483485, 0.5663155055476546, -2.815793256502505],
+    [-79.46759781384296, 76.34788079417757, -20.61429124606268],
+    [-55.84494926316688, 53.84097085820023, -15.36805949872261],
+    [-41.89057665910137, 40.52301353870984, -12.267802624401947],
+    [-32.52955123497913, 31.570446625987145, -10.183929004304936],
+    [-25.759775813159212, 25.080587048446706, -8.672014939960192],
+    [-20.614446375867317, 20.13470526053072, -7.517930948977781],
+    [-16.56329088691245, 16.22891626959611, -6.604458216660931],
+    [-13.288599166148737, 13.061323131629807, -5.861485421017064],
+    [-10.587199854745666, 10.438865186131192, -5.244246040516086],
+    [-8.322485795827477, 8.231730167148255, -4.722682157728952],
+    [-6.398871802571176, 6.349083767716769, -4.275788861976517],
+    [-4.747274803808645, 4.725273071694194, -3.8884029509435125],
+    [-3.3163967364722375, 3.311544157314775, -3.549277251455053],
+    [-2.067274555177115, 2.070863641947427, -3.249877730343034],
+    [-0.969731676450378, 0.9745471649485973, -2.9836011380987695],
+    [3.159226161704605e-14, -1.8964688078179085e-14, -2.745251178470558],
+    [-115.0465650681541, 93.59973135428494, -20.313597969415074],
+    [-84.45629299383415, 67.19443704012521, -14.713082726844146],
+    [-67.74778551562903, 52.776669190235744, -11.664670411859989],
+    [-56.968790146398376, 43.47505830681001, -9.701600387589222],
+    [-49.34304941982262, 36.89182205736725, -8.313436461464576],
+    [-43.621393763558764, 31.948660746342284, -7.2711819252891585],
+    [-39.149799809632675, 28.081336215875435, -6.455272769809446],
+    [-35.54908959256253, 24.962919593941404, -5.796572973908547],
+    [-32.58262506562733, 22.38950515859733, -5.25204533183232],
+    [-30.09424112834968, 20.22661228177363, -4.793357718843762],
+    [-27.976257794924106, 18.38157086574155, -4.401020722488946],
+    [-26.151752093033384, 16.788221932600184, -4.0611438348539],
+    [-24.56414653570852, 15.397935447044379, -3.7635333663343413],
+    [-23.170797716021692, 14.174080997314185, -3.500522925861559],
+    [-21.93888899062484, 13.088486800671076, -3.2662253117416236],
+    [-20.84270961737979, 12.119094824928949, -3.0560376563968195],
+    [-106.05139548486825, 87.42480852111959, -19.307772825452965],
+    [-77.7661300666391, 62.75717569684553, -14.024821485503514],
+    [-62.18766003493454, 49.17337947516832, -11.124262392529578],
+    [-52.09610166907202, 40.371854120409665, -9.247964054300793],
+    [-44.93983958473304, 34.12657617910305, -7.917488282033102],
+    [-39.562756649781875, 29.429474505238407, -6.916692392083641],
+    [-35.35677279384344, 25.750627081212766, -6.132203369157666],
+    [-31.96819763178365, 22.781978718003526, -5.49824896103134],
+    [-29.175761747765147, 20.330920740635644, -4.9737842762095665],
+    [-26.833166671641298, 18.27017891181278, -4.53173636663392],
+    [-24.83938420458976, 16.51191410643514, -4.153453002442757],
+    [-23.122155120968962, 14.993337450675456, -3.8256233797634165],
+    [-21.62827857668257, 13.668247889658717, -3.53846882570961],
+    [-20.317622322589383, 12.501813993664932, -3.2846284432740784],
+    [-19.15928006942035, 11.467227423295741, -3.058445263948939],
+    [-18.12902289387301, 10.543484106229684, -2.8554934727796497],
+    [-98.33044178437726, 82.10358000115531, -18.439230327762463],
+    [-71.98532298846382, 58.90969120005615, -13.427308130247097],
+    [-57.36641843799883, 46.039306396561685, -10.653960360584648],
+    [-47.86099645635797, 37.6672746380333, -8.85258699548492],
+    [-41.10591547235995, 31.71286932595709, -7.572016140257546],
+    [-36.02363713497401, 27.22779945524428, -6.607121951961837],
+    [-32.04504093247062, 23.71146930275217, -5.849856418121031],
+    [-28.8381136995298, 20.871997460912713, -5.2373474673125],
+    [-26.19468608946103, 18.52647602522302, -4.730270893448867],
+    [-23.97688446108393, 16.55382864714109, -4.302642643424042],
+    [-22.089368335520568, 14.870376315675804, -3.936535166613375],
+    [-20.463870899909463, 13.41623522636294, -3.619139845602546],
+    [-19.050084684824075, 12.147299893222566, -3.341037746082477],
+    [-17.810030957858835, 11.030292017325834, -3.0951329014973656],
+    [-16.714442850510267, 10.039581058867894, -2.8759680810508357],
+    [-15.740363858396256, 9.155073842619153, -2.6792711154294726],
+    [-91.63245285803325, 77.46886511771277, -17.680861555571212],
+    [-66.9440925175096, 55.54280396679129, -12.903575345690077],
+    [-53.15060134234374, 43.290509753250404, -10.241083367670639],
+    [-44.15103009066003, 35.29174577842287, -8.505178536203902],
+    [-37.74275598112048, 29.590528494104483, -7.268268780250153],
+    [-32.915535969928825, 25.29016266729447, -6.334802169641346],
+    [-29.13373243714749, 21.915447384028223, -5.6013717230235835],
+    [-26.084008160716156, 19.188538426238573, -5.007637844443027],
+    [-23.569500828418057, 16.934961049571562, -4.5157808344104415],
+    [-21.45961886099489, 15.039033669698345, -4.100769635273713],
+    [-19.66394360705104, 13.42070188261571, -3.745311375935633],
+    [-18.117666284216078, 12.022614992999857, -3.4370374869404543],
+    [-16.772987466812125, 10.80249593637985, -3.1668443820812437],
+    [-15.59379635834579, 9.728422253066121, -2.9278683993326817],
+    [-14.55224888924214, 8.775792024141724, -2.714827482531079],
+    [-13.626493359992683, 7.925307869665753, -2.52358422987609],
+    [-85.76793010525704, 73.39449652870624, -17.012260997497034],
+    [-62.51206681587133, 52.572585558001165, -12.440614688331724],
+    [-49.436656429020424, 40.861769817143724, -9.87580526204409],
+    [-40.878321993912, 33.190806317321254, -8.197705623086573],
+    [-34.77293241481019, 27.712181919833366, -6.999376230293171],
+    [-30.168583918460484, 23.574271121846152, -6.093685554039515],
+    [-26.55875291596795, 20.324117447466747, -5.381318299017657],
+    [-23.646374973159784, 17.69619542641419, -4.804170966714424],
+    [-21.24445489617924, 15.523440959312948, -4.325753625889785],
+    [-19.228763274059872, 13.694917836562547, -3.9218781089472015],
+    [-17.51318554542035, 12.133762394026174, -3.575813162207276],
+    [-16.035939228573927, 10.78485516925563, -3.2755797335284464],
+    [-14.751421185878202, 9.607530386962921, -3.0123534325128882],
+    [-13.62515721831988, 8.571060864360765, -2.7794762950785237],
+    [-12.630550387443447, 7.651751757742796, -2.5718213842362028],
+    [-11.746717743073342, 6.831007605312712, -2.38537063496551],
+    [-80.59128238295102, 69.78346708524417, -16.417777605410713],
+    [-58.58752214035841, 49.933471952802364, -12.02828959001512],
+    [-46.143055529155475, 38.70166291252583, -9.550398294743587],
+    [-37.973244695782014, 31.32123576026842, -7.923809970952663],
+    [-32.134798613572265, 26.040046961431376, -6.759873970077157],
+    [-27.726930813115754, 22.046254891637417, -5.878942520743686],
+    [-24.2686936652305, 18.90658516874585, -5.185344211322069],
+    [-21.47734313785066, 16.36642899473001, -4.622969331724099],
+    [-19.174578400339175, 14.265296575468291, -4.156514520631056],
+    [-17.241781471340527, 12.496464847044528, -3.762544092255277],
+    [-15.596636884753705, 10.98590469054629, -3.4248283188907607],
+    [-14.180035021402237, 9.680479684139131, -3.1317360460347454],
+    [-12.948313499419381, 8.540956599421747, -2.874692264615498],
+    [-11.868444899036355, 7.5376712115164946, -2.647222741612649],
+    [-10.91493514062865, 6.647735489435126, -2.4443390842678054],
+    [-10.06775819311208, 5.853178383732074, -2.2621297997026195],
+    [-75.98882550933807, 66.55996243606117, -15.88520759317253],
+    [-55.08995808791688, 47.57351166996328, -11.658587053518435],
+    [-43.204771646849366, 36.76911063917346, -9.258704330378677],
+    [-35.37995505172572, 29.64831134066501, -7.678396160358844],
+    [-29.778699927223766, 24.54363552576602, -6.545363843102897],
+    [-25.5454313928351, 20.678684318331793, -5.686672218786145],
+    [-22.22187392685032, 17.637753603715783, -5.009923888422796],
+    [-19.537997106416974, 15.175992625994942, -4.4608022995330305],
+    [-17.323227226427708, 13.138793317179909, -4.005071859844376],
+    [-15.463937478506718, 11.423204256210408, -3.619974237956273],
+    [-13.881193662517974, 9.957734966071332, -3.289731228012618],
+    [-12.518266230589896, 8.69102562923584, -3.003024898360297],
+    [-11.33322164902464, 7.585126735036672, -2.7515047555189946],
+    [-10.29432172192705, 6.611326738928714, -2.5288619595612385],
+    [-9.377055382991173, 5.747460793014387, -2.330232813422914],
+    [-8.562160559690502, 4.97611756490738, -2.151801723102894],
+    [-71.87051032302374, 63.663870800392104, -15.404895573421005],
+    [-51.954862611868094, 45.45101515347723, -11.325091199256422],
+    [-40.56933251929876, 35.03091330027496, -8.995757747269572],
+    [-33.05317091751452, 28.143828436076216, -7.457335071708208],
+    [-27.664218805651604, 23.19808577359366, -6.3522681071422396],
+    [-23.58722298467807, 19.449119599838166, -5.513691515402152],
+    [-20.38416809040452, 16.497034861602497, -4.85217278377989],
+    [-17.79639956516197, 14.10577040356905, -4.315020497591101],
+    [-15.660264061310325, 12.126019956574547, -3.8689671810811865],
+    [-13.866607811240383, 10.45823350958884, -3.491868753465698],
+    [-12.339528408025961, 9.033210784335544, -3.1683564809006706],
+    [-11.024431223465395, 7.8011950589626435, -2.887396466358665],
+    [-9.880933981463752, 6.725395336337052, -2.6408417822349337],
+    [-8.87845556541195, 5.7779599061710165, -2.4225335195704054],
+    [-7.993367336765558, 4.937376644084438, -2.2277209919860077],
+    [-7.20709311385203, 4.186739394065513, -2.0526754927514483],
+    [-68.16409524181243, 61.04691586279345, -14.969102747797928],
+    [-49.12994975248093, 43.532146263990974, -11.022605665102352],
+    [-38.193950679685585, 33.45995530914741, -8.757511689265504],
+    [-30.95580743530153, 26.78464794081745, -7.257246763210145],
+    [-25.75814184055374, 21.98292979694066, -6.177648183160784],
+    [-21.82192813024116, 18.339033586591437, -5.357378650965429],
+    [-18.72738613755598, 15.467388850349307, -4.709709425326949],
+    [-16.22611178826416, 13.139906207336823, -4.1834320709851625],
+    [-14.160692310507237, 11.21209070874865, -3.7461628022307076],
+    [-12.4260098287459, 9.587480233880882, -3.376318267196301],
+    [-10.94889855549109, 8.19895409476682, -3.0589035052409863],
+    [-9.67669152748372, 6.998211446516663, -2.7831438535991664],
+    [-8.570407468031567, 5.949513488872142, -2.541078137159797],
+    [-7.60051045543228, 5.025789452003005, -2.3266842220444897],
+    [-6.744169063660082, 4.2061200278356194, -2.1353144846067527],
+    [-5.983426829149499, 3.474057840033311, -1.9633194293676637],
+    [-64.8109605869184, 58.66987927648228, -14.57155403854916],
+    [-46.57240909603815, 41.78916086446109, -10.746878043348023],
+    [-36.043403125549354, 32.03387854266831, -8.54063614078618],
+    [-29.057217667533436, 25.551614202430905, -7.075339098459089],
+    [-24.032937814074934, 20.88117021315871, -6.019069150750685],
+    [-20.224302582017838, 17.333000676295423, -5.215555790678666],
+    [-17.22805042287313, 14.534596467086176, -4.580551359466444],
+    [-14.805072079144106, 12.265142900465445, -4.064209001878851],
+    [-12.803617287522133, 10.384537747520735, -3.634956446638086],
+    [-11.122231900822394, 8.799138917461578, -3.2717252739088214],
+    [-9.690235214461541, 7.443725212769829, -2.9598637259674865],
+    [-8.456711169090545, 6.271325674369662, -2.688835122266812],
+    [-7.383951245266232, 5.247162650284108, -2.4508487662570855],
+    [-6.443369165938281, 4.344878707205741, -2.240008012700032],
+    [-5.6128579038063675, 3.5440959924972897, -2.0517597622594757],
+    [-4.875024144650352, 2.8287861139529396, -1.882527139506231],
+    [-61.763049493718896, 56.500570598816175, -14.207107743265663],
+    [-44.246863650522116, 40.19909848293469, -10.494395621459944],
+    [-34.088442255623, 30.734087404966452, -8.342366811955046],
+    [-27.331865254372712, 24.428737959285197, -6.90928616054864],
+    [-22.46560349763767, 19.87857918431566, -5.874497112927327],
+    [-18.773205585838564, 16.418078383640285, -5.086399680644392],
+    [-15.866460612834532, 13.686703360039408, -4.46303570216588],
+    [-13.514735640011207, 11.470314929950696, -3.9558153502641282],
+    [-11.571447218517498, 9.632843371873015, -3.5339156556235714],
+    [-9.938485478197714, 8.083235916523199, -3.1767436266739963],
+    [-8.547438703454077, 6.758015615512414, -2.8699643224308815],
+    [-7.348989771922651, 5.611432720886709, -2.6032606910576424],
+    [-6.306592596428223, 4.609592147676299, -2.368999317337434],
+    [-5.392528297505101, 3.7267914219387674, -2.1613992885360926],
+    [-4.585351539592112, 2.943149775495914, -1.9759946460797277],
+    [-3.868183420433795, 2.2430234074009148, -1.8092754329804357],
+    [-58.980596954459074, 54.51231998503266, -13.871510729200795],
+    [-42.12383237121457, 38.74279675487888, -10.262231823572847],
+    [-32.30458940101032, 29.544992846413837, -8.160390553564671],
+    [-25.758310597472807, 23.40257167054017, -6.757135491325886],
+    [-21.036777961639153, 18.963159937969905, -5.742220525318272],
+    [-17.450806653116516, 15.58333039260392, -4.968372897808358],
+    [-14.625970974657418, 12.913589486611203, -4.35575779899718],
+    [-12.339407815425986, 10.745954500503288, -3.8569516880578374],
+    [-10.449272229131767, 8.948075908358987, -3.44182686731332],
+    [-8.860522352055954, 7.4312900780161995, -3.090231445719755],
+    [-7.506828430984009, 6.133729517522047, -2.7881243586590667],
+    [-6.340340667048389, 5.010771274317968, -2.5253922192056493],
+    [-5.325579799959693, 4.029334523975849, -2.294547410043886],
+    [-4.435623036821539, 3.164320984473005, -2.0899162587789823],
+    [-3.649632339702433, 2.3963083696562477, -1.9071135186897146],
+    [-2.951200925216564, 1.7100075381986468, -1.7426911855311724],
+    [-56.43041991728173, 52.68284297143179, -13.561214405726354],
+    [-40.17855784228553, 37.40413946631857, -10.047929276227586],
+    [-30.671207921811956, 28.45343106180253, -7.992757424075655],
+    [-24.31842776684254, 22.461726527883105, -6.617236517874397],
+    [-19.73005554576287, 18.124728600672196, -5.620789232041618],
+    [-16.241968092707083, 14.819454842362932, -4.8601703856741265],
+    [-13.492426094116968, 12.206632606104893, -4.2575233789035645],
+    [-11.26572209732229, 10.083982848629297, -3.766511640716048],
+    [-9.424376974577799, 8.322603581693222, -3.3576554946154085],
+    [-7.876176291431478, 6.836045427921107, -3.011214117049184],
+    [-6.556709282230345, 5.563932527636974, -2.713420241709793],
+    [-5.419478713505748, 4.462686150709902, -2.4543501697487873],
+    [-4.429988744382339, 3.499979966776561, -2.2266520244800594],
+    [-3.5620504249003977, 2.6512754470605753, -2.024751934298274],
+    [-2.7953855160748544, 1.8975749850731338, -1.8443397309785423],
+    [-2.114022407688499, 1.2239178814839886, -1.682025011001099],
+    [-54.08461316612358, 50.993374357888385, -13.273234576169335],
+    [-38.39010197021138, 36.169476100443816, -9.8494097134492],
+    [-29.170783320555348, 27.448211603197795, -7.837812464947498],
+    [-22.99679332927642, 21.596495100220682, -6.488184743639752],
+    [-18.531447603582993, 17.354586202496808, -5.508966745768202],
+    [-15.133759705902106, 14.118491812714872, -4.7606774706855495],
+    [-12.45371614248871, 11.558442720827365, -4.167310878433372],
+    [-10.282227701803897, 9.477465993325813, -3.6835475826430546],
+    [-8.485854703185574, 7.749867622265713, -3.2805143417808877],
+    [-6.9749993141051965, 6.291258747739703, -2.938854959000138],
+    [-5.687026846332805, 5.042651512144797, -2.6450582847531443],
+    [-4.5766919448145975, 3.9614387485903775, -2.389377996422018],
+    [-3.610408969070749, 3.0159960075805836, -2.16458909657873],
+    [-2.7626681451573574, 2.1823053786026763, -1.9652109580692978],
+    [-2.0137092979390614, 1.4417641566911399, -1.7870035257078223],
+    [-1.347963515447804, 0.7797169817297227, -1.6266301601782454],
+    [-51.919542948231694, 49.42799955427587, -13.00504343345761],
+    [-36.74064096995109, 35.02716896104455, -9.664903811215252],
+    [-27.788358532698833, 26.51976255747789, -7.69414223046132],
+    [-21.780204790960237, 20.7985536777734, -6.368777811468318],
+    [-17.428956912994174, 16.645259025497847, -5.405692546403149],
+    [-14.115073940133524, 13.473591065860202, -4.6689365957709],
+    [-11.499423303482484, 10.962650670978348, -4.08424151297916],
+    [-9.37906093028907, 8.920419839692544, -3.6072433245627855],
+    [-7.62429907182981, 7.224200828487318, -3.2096384058123713],
+    [-6.147970654609495, 5.791529360870192, -2.8724317727824986],
+    [-4.889091020672426, 4.564713911071715, -2.582352732196487],
+    [-3.8035778381949576, 3.502054574428189, -2.329821430887555],
+    [-2.8586910177551075, 2.5725822022923617, -2.107731899049288],
+    [-2.029551668268814, 1.7527648417128006, -1.910690943673262],
+    [-1.2968807919455925, 1.024368306498648, -1.7345242215214092],
+    [-0.6454834610169539, 0.3730221013850516, -1.5759454567073252],
+    [-49.915061984615264, 47.97313276442283, -12.754485418618373],
+    [-35.214910329219336, 33.967236347255316, -9.492896000401869],
+    [-26.511086849732916, 25.65984935267735, -7.560532489451058],
+    [-20.65729780852684, 20.06072539739313, -6.2579806037120775],
+    [-16.412238291898873, 15.990291292219634, -5.310052037680939],
+    [-13.176318168066969, 12.878826094162266, -4.584120739700519],
+    [-10.620538388730589, 10.413738468099345, -4.007555306906748],
+    [-8.54768120174953, 8.407653417300327, -3.5368921923994985],
+    [-6.831556106330489, 6.740681328562944, -3.144364611799408],
+    [-5.3872620711065915, 5.332161665858783, -2.8113179481575554],
+    [-4.155352686020963, 4.125617707929011, -2.524708019520337],
+    [-3.0928297969360488, 3.0801996010130273, -2.275111728484831],
+    [-2.1677414641226287, 2.165552034260293, -2.055535138749122],
+    [-1.355796773733529, 1.3585981449215525, -1.8606673146858825],
+    [-0.6381651137349365, 0.6414487598153202, -1.6863957031073145],
+    [-5.28044058865703e-14, 3.479518260661504e-14, -1.5294813607276274],
+    [-85.29928301410767, 69.3285184264743, -14.787739207837847],
+    [-63.00694744527204, 50.08260522379989, -10.709246605095156],
+    [-50.81305822794385, 39.55454331385872, -8.483901923386266],
+    [-42.92881102951775, 32.743984935357425, -7.046050025727018],
+    [-37.33635878672495, 27.90867772092017, -6.025377212936302],
+    [-33.128258634673784, 24.265516600080687, -5.255823909541666],
+    [-29.829413170135624, 21.404730132579, -4.650682005558589],
+    [-27.164299369363683, 19.08881867657816, -4.15979600285372],
+    [-24.960882512739182, 17.169578437595465, -3.7519266060598757],
+    [-23.105556122368263, 15.549200408928186, -3.406493790952553],
+    [-21.519925768186237, 14.16023301701969, -3.1093291881291467],
+    [-20.147936613505063, 12.954476499655756, -2.850325000780125],
+    [-18.948318141866604, 11.89646606008542, -2.6220558029452294],
+    [-17.889928153570978, 10.959457471427688, -2.418930632190916],
+    [-16.948769688512776, 10.122855898170911, -2.2366504970644407],
+    [-16.106014255074026, 9.370512668160869, -2.0718494000071366],
+    [-78.60704460963392, 64.73296682208493, -14.039480086298566],
+    [-58.01112645139251, 46.76795958377284, -10.195448189718583],
+    [-46.64824086225497, 36.8544250459002, -8.079312362246391],
+    [-39.26938654815082, 30.412415908790237, -6.705608023055483],
+    [-34.02201268365323, 25.82621863069642, -5.72761893253698],
+    [-30.067091229289403, 22.364433816759973, -4.988762156921294],
+    [-26.96329215926857, 19.642549789757147, -4.40689422668314],
+    [-24.45382402571977, 17.436960075826292, -3.9343404461212654],
+    [-22.37794742061045, 15.607777807648642, -3.5413313639973643],
+    [-20.62931121735761, 14.062502533810061, -3.2082166299156976],
+    [-19.1344098698044, 12.737239741796332, -2.9214458254922424],
+    [-17.84061924830026, 11.586273380284812, -2.671339483510384],
+    [-16.709152590231852, 10.575928682746202, -2.4507788441835703],
+    [-15.710714986718006, 9.68078727473183, -2.2543987126403175],
+    [-14.822714773244686, 8.881259113743772, -2.078070236466032],
+    [-14.027413352528162, 8.161972057667075, -1.918558220095247],
+    [-72.8695132212638, 60.77877517142506, -13.394479848453193],
+    [-53.69819134894602, 43.897469271737535, -9.750045760442031],
+    [-43.03943452452556, 34.50841735773106, -7.7276495416460875],
+    [-36.090632439299696, 28.382246165018284, -6.40921104897884],
+    [-31.13758181469995, 24.010011531274884, -5.468068487959228],
+    [-27.39889207953373, 20.704216989585987, -4.75574004196071],
+    [-24.46180162376655, 18.101884587848524, -4.193998425178929],
+    [-22.085413923964527, 15.991284653355208, -3.7373025061408893],
+    [-20.11859641198993, 14.239655278185925, -3.357149221534177],
+    [-18.461177111894777, 12.759049158433871, -3.0346899565560004],
+    [-17.043823209841996, 11.488628880618451, -2.756908113123222],
+    [-15.816840021937479, 10.384813613223638, -2.5144942706344344],
+    [-14.743564071187905, 9.41546902771678, -2.3005948103835445],
+    [-13.796280847655238, 8.556318452668092, -2.1100398129799913],
+    [-12.953601704880757, 7.788637510451213, -1.9388476262536027],
+    [-12.198721540601007, 7.097723319943598, -1.7838962391587316],
+    [-67.89616196678034, 57.33876160424507, -12.832095954651395],
+    [-49.93894198854729, 41.38776270182161, -9.360077552025347],
+    [-39.88475513567097, 32.45220893745844, -7.419199619808195],
+    [-33.306458644934075, 26.60001157684433, -6.148957314352178],
+    [-28.607431414581374, 22.41368851689033, -5.239991585288354],
+    [-25.055543600137682, 19.24355297766174, -4.550844687277811],
+    [-22.26253699013467, 16.745233854652785, -4.006694884734938],
+    [-20.00118164462607, 14.717286921004229, -3.563859919005409],
+    [-18.128622213766597, 13.033132372666728, -3.194941825626763],
+    [-16.55001550925347, 11.608776262472702, -2.8817918923158623],
+    [-15.199632894105791, 10.386039451537282, -2.611860092091188],
+    [-14.030311875074728, 9.323198848824406, -2.3761605353886375],
+    [-13.007225667604141, 8.389461157888432, -2.1680714918643034],
+    [-12.10402765424896, 7.561544061870256, -1.982593860933035],
+    [-11.300370941648014, 6.8214773730360205, -1.8158747660463976],
+    [-10.580259095234572, 6.155141421215501, -1.664891212317045],
+    [-63.543807495024446, 54.31729228151948, -12.33684564459708],
+    [-46.63453523318772, 39.17499090836414, -9.015642527224223],
+    [-37.10545692232044, 30.63602757489122, -7.146464372770163],
+    [-30.84985474559609, 25.024033475156838, -5.91870318381225],
+    [-26.37237058809287, 21.000902411873398, -5.038123573980899],
+    [-22.983503693471622, 17.94991166798719, -4.369433151644882],
+    [-20.31626483739879, 15.542957837552837, -3.8408066828544336],
+    [-18.155312039433635, 13.587606451132318, -3.4102000988817713],
+    [-16.364998934740775, 11.962698662765558, -3.051190044195202],
+    [-14.855131547828012, 10.587712751364354, -2.746245866934702],
+    [-13.563126943227033, 9.406804699766573, -2.4832295039188272],
+    [-12.444034687572424, 8.379884540777027, -2.253440864753963],
+    [-11.464633404029115, 7.477338353089274, -2.0504630930506798],
+    [-10.599772659231562, 6.676758565380247, -1.8694480118768027],
+    [-9.830016868678237, 5.9608385886790645, -1.7066565144017034],
+    [-9.140077202378581, 5.315971966479645, -1.559153070589346],
+    [-59.70278012199354, 51.64106503241651, -11.89688993485309],
+    [-43.70809599433522, 37.20943863671724, -8.709050132937469],
+    [-34.63975162321109, 29.020764216590667, -6.903566548738149],
+    [-28.667916043074893, 23.621354452962184, -5.713602824804267],
+    [-24.38544664240815, 19.742771975335334, -4.85829199661918],
+    [-21.140136413453156, 16.797342706525217, -4.20781141923858],
+    [-18.583651261991506, 14.471332457941047, -3.692999620103531],
+    [-16.511096487999733, 12.580279078507523, -3.273270904257347],
+    [-14.793166696386878, 11.007823481041896, -2.9230697920898057],
+    [-13.343762726375031, 9.676521961430161, -2.6254165126772366],
+    [-12.103073920089106, 8.532601359548304, -2.368540227650313],
+    [-11.028100422173026, 7.537420587284497, -2.1439956372380244],
+    [-10.087037119159248, 6.662412330051068, -1.9455485299059685],
+    [-9.255789190699728, 5.8859454201397545, -1.7684852630979493],
+    [-8.515725475023023, 5.1913023136818754, -1.6091681260253912],
+    [-7.8521818727629755, 4.56533211670492, -1.4647395205603433],
+    [-56.28767231095969, 49.25294664752809, -11.503022034748003],
+    [-41.098959207530804, 35.45182566547646, -8.434238013228377],
+    [-32.43850236944525, 27.57527197782264, -6.6858364041417335],
+    [-26.71834224849499, 22.365580414941512, -5.529784733664637],
+    [-22.60896112141588, 18.616068166000808, -4.697149230719995],
+    [-19.491091848738876, 15.764901118073023, -4.063005540796097],
+    [-17.032915612210846, 13.511153862558809, -3.560581874106968],
+    [-15.038798814904586, 11.677478487561663, -3.1506018419357544],
+    [-13.385065603293555, 10.151804964607694, -2.808289973861481],
+    [-11.989253545814785, 8.859440418572277, -2.517161504890523],
+    [-10.794015933480473, 7.748463476948943, -2.265775668678399],
+    [-9.758087322108869, 6.781529005664534, -2.0459161740100353],
+    [-8.850922330949205, 5.931005979341516, -1.85151295206469],
+    [-8.049365314612576, 5.175957845804334, -1.6779732405207242],
+    [-7.33550093011713, 4.500194053581177, -1.5217504077382302],
+    [-6.6952210668385685, 3.8909712563958245, -1.3800568340214134],
+    [-53.23099043521755, 47.107745313902264, -11.147974829987438],
+    [-38.75862889658687, 33.870710832107974, -8.186364196828551],
+    [-30.462161842523614, 26.274444889985634, -6.489518240897165],
+    [-24.96692510644002, 21.23533124038396, -5.364120004400202],
+    [-21.012313665484225, 17.601902636449715, -4.5519795952426785],
+    [-18.008402135034956, 14.835502286115767, -3.9325955658995446],
+    [-15.638112520766766, 12.646714367208839, -3.4413571171169286],
+    [-13.714088123636962, 10.864590673573193, -3.0401728100832495],
+    [-12.117688908187839, 9.380922175124242, -2.7049733825959064],
+    [-10.769707729631666, 8.12349341669127, -2.419722311481621],
+    [-9.615004433237216, 7.042051238627089, -2.1732777180086194],
+    [-8.613867335039641, 6.100418176596705, -1.957630657006978],
+    [-7.736879853925366, 5.271806867274065, -1.766859642700365],
+    [-6.961728046190801, 4.535906647380653, -1.596481301506953],
+    [-6.271138166313635, 3.8770017807985178, -1.4430313925660327],
+    [-5.651500479117354, 3.2827160753167437, -1.3037855733816834],
+    [-50.4786998844216, 45.16924434311794, -10.825935952568438],
+    [-36.6478857884629, 32.44063346508119, -7.96151573685679],
+    [-28.678555218530782, 25.097826033081244, -6.311558257512178],
+    [-23.385714468449972, 20.213109050115936, -5.214053424019789],
+    [-19.570418339844974, 16.68476364244835, -4.420557866320753],
+    [-16.66907590988859, 13.995075900534527, -3.8145931108658933],
+    [-14.377862916071022, 11.86504656765173, -3.3335165307428234],
+    [-12.51687306890469, 10.129524850611142, -2.94031661194069],
+    [-10.97200277224545, 8.683801345865776, -2.6115678868936256],
+    [-9.666978917595664, 7.457906912107786, -2.331642480493256],
+    [-8.548650888133125, 6.403101264950507, -2.0896709727510205],
+    [-7.578708305232191, 5.4842659581829745, -1.8778333898241673],
+    [-6.728752801259456, 4.675378777064336, -1.6903436210815894],
+    [-5.977229751577242, 3.9566966534682724, -1.5228179209460084],
+    [-5.30744424188792, 3.312934386106833, -1.3718670558502983],
+    [-4.706236169708128, 2.7320486428663022, -1.2348242637981826],
+    [-47.987035862800546, 43.40807870602505, -10.532201314926649],
+    [-34.73468020483513, 31.140758928332488, -7.756496810731423],
+    [-27.06124841059485, 24.028582489097328, -6.149448635843664],
+    [-21.951658802235595, 19.284458588349956, -5.077478429730058],
+    [-18.26252314886192, 15.851796739641088, -4.30104394459265],
+    [-15.454045427878604, 13.231931377075428, -3.7073497018586123],
+    [-13.234397874750929, 11.155352553804656, -3.2355573582087565],
+    [-11.43042265073654, 9.462193136150514, -2.8496455154591964],
+    [-9.932129079126552, 8.050935886493592, -2.526779322606247],
+    [-8.66590505005907, 6.8536608644768915, -2.251705728448335],
+    [-7.5804048594127185, 5.823008049755276, -2.0138051757469215],
+    [-6.638589626311929, 4.924825185113059, -1.805430940984506],
+    [-5.812984970673724, 4.133788078694527, -1.6209209813979215],
+    [-5.0827253491538755, 3.430671390143898, -1.4559828190157764],
+    [-4.431641138525691, 2.800582555950636, -1.3072959009530842],
+    [-3.8469802753247624, 2.2317817449087722, -1.1722461634169983],
+    [-45.72017921300432, 41.80018739311299, -10.262923080842695],
+    [-32.99257251564241, 29.953875676833498, -7.5686721241280175],
+    [-25.58832929045646, 23.052739181762608, -6.0011112413693555],
+    [-20.64558324573282, 18.43733552003218, -4.952643225135799],
+    [-17.071318239313754, 15.092260847229046, -4.191903371187522],
+    [-14.347369987375355, 12.536278037029115, -3.6094877614000134],
+    [-12.19283126852074, 10.508569327049797, -3.1462210464153033],
+    [-10.44069492858657, 8.854112804395632, -2.7669952401878537],
+    [-8.984719781391213, 7.47431842967061, -2.4495201683165977],
+    [-7.753720720126294, 6.3031459615322625, -2.1788884713490577],
+    [-6.697998593140135, 5.29450151788369, -1.9447109803804827],
+    [-5.78167459408825, 4.415119086430627, -1.7395006651854066],
+    [-4.978117397602521, 3.640314655502125, -1.5577097951691325],
+    [-4.267090179487657, 2.9513376200009067, -1.3951299438636076],
+    [-3.63290257439053, 2.333655369081508, -1.2485037768501777],
+    [-3.0631747839642096, 1.7758062447983873, -1.1152657128506527],
+    [-43.64853529462725, 40.32566712845208, -10.014923346088795],
+    [-31.399562230854468, 28.86564183000024, -7.395849500769031],
+    [-24.241485148385607, 22.15859848553249, -5.86480969594491],
+    [-19.451411596540137, 17.661625060861027, -4.838079383062963],
+    [-15.982253930414425, 14.397111853723121, -4.091846606771044],
+    [-13.335620498506866, 11.899852436861941, -3.5198468495518225],
+    [-11.240599713501775, 9.91703377571944, -3.064445662679229],
+    [-9.535818685120333, 8.29809877840772, -2.69138174874097],
+    [-8.118472565958024, 6.947155617432694, -2.378869852882699],
+    [-6.919601188645907, 5.799896896205623, -2.112323027973169],
+    [-5.891015100991069, 4.811395926368019, -1.8815655855399394],
+    [-4.997899216859162, 3.949203589400749, -1.6792584127676244],
+    [-4.214395237291812, 3.189225849364504, -1.499959613841533],
+    [-3.5208428014415794, 2.5131495047507255, -1.3395385412335121],
+    [-2.9019909336582455, 1.9067735062807378, -1.1947963300229678],
+    [-2.345801107722472, 1.358892364477858, -1.0632122161170199],
+    [-41.74744089110612, 38.96791091974501, -9.785554373508699],
+    [-29.937197399750563, 27.86401175060388, -7.236190690921943],
+    [-23.005295857057995, 21.336295161373208, -5.739082081117997],
+    [-18.355567112085716, 16.948770871290026, -4.732546910721586],
+    [-14.983013346290907, 13.758680264109486, -3.999782118907478],
+    [-12.407405881135853, 11.315632715783357, -3.437442800170695],
+    [-10.36702674728146, 9.374221638904736, -2.989328899015392],
+    [-8.705689008252342, 7.788023392815444, -2.621967564634386],
+    [-7.323752057244, 6.463644734586294, -2.3140437402164937],
+    [-6.154304957348649, 5.338383001236, -2.0512688031456743],
+    [-5.150548871950879, 4.368392331182239, -1.8236657659816282],
+    [-4.278648540989853, 3.5219799323891454, -1.6240331359944455],
+    [-3.5134576862589397, 2.775597878465576, -1.4470274367397178],
+    [-2.8358468650920354, 2.111337739798727, -1.28859031143177],
+    [-2.2309697284386076, 1.5153052040332893, -1.1455772088943537],
+    [-1.6871019884893925, 0.9765317056579473, -1.0155089747689348],
+    [-39.99617989471088, 37.71295208453062, -9.572592355584012],
+    [-28.589889158484723, 26.93879440497935, -7.088142802514748],
+    [-21.866686257099786, 20.577451236705667, -5.622688850543088],
+    [-17.346506095066694, 16.291485888304802, -4.634991525662926],
+    [-14.063100390404697, 13.170419061901976, -3.914779759913072],
+    [-11.552999982454931, 10.777612580787792, -3.3614353286565666],
+    [-9.562980333478409, 8.87454210506643, -2.9200990221430674],
+    [-7.941648688806508, 7.318626879383709, -2.558035258955049],
+    [-6.592290709701822, 6.018797085788244, -2.2543686591947947],
+    [-5.449890911869034, 4.913842321860827, -1.9950895022873008],
+    [-4.468936789389571, 3.9609216889813776, -1.77040650158756],
+    [-3.6164993980951192, 3.1290454944274266, -1.573246723939522],
+    [-2.868090997343848, 2.3951733542690583, -1.3983585857156406],
+    [-2.2050731136129302, 1.7417729096422732, -1.2417511894374416],
+    [-1.6129735187120071, 1.1552347433662589, -1.1003306387374887],
+    [-1.0803584306770937, 0.6248102776490961, -0.9716565649982728],
+    [-38.37722505875459, 36.54895872283823, -9.37415568485275],
+    [-27.344378433565222, 26.08130950202952, -6.950385007518963],
+    [-20.814497547997913, 19.87490563040906, -5.514572096799408],
+    [-16.414351264972964, 15.683524777742466, -4.544511098242778],
+    [-13.21351476088976, 12.626704601966834, -3.8360419024725214],
+    [-10.764046827591333, 10.280622660090549, -3.291102503017875],
+    [-8.820601234621249, 8.413174696662047, -2.8560918512367346],
+    [-7.2362349387806315, 6.885366498511553, -2.4989663884312177],
+    [-5.916950480372308, 5.608297058719308, -2.199263420652196],
+    [-4.799492434265815, 4.522148905092947, -1.9432349489894418],
+    [-3.8395427526737405, 3.58501907061761, -1.7212638850267699],
+    [-3.0050140088244364, 2.766573909470387, -1.5263978362882196],
+    [-2.272029827778613, 2.0442464714940236, -1.3534713341934526],
+    [-1.6224074507727206, 1.4008550743485682, -1.198556662468439],
+    [-1.0420218355827369, 0.8230558635287258, -1.0586073391806965],
+    [-0.5197087404290549, 0.30030525357455473, -0.9312192804059684],
+    [-36.87564750682067, 35.46583984731884, -9.18864138389906],
+    [-26.189316882425775, 25.284117077431812, -6.8217867028848485],
+    [-19.83914865018355, 19.22250032438172, -5.413823393358575],
+    [-15.550600940985953, 15.119503239152065, -4.460329051508588],
+    [-12.426493344126635, 12.122677980579752, -3.7628804859639113],
+    [-10.033325429010874, 9.820187772238123, -3.2258203867318307],
+    [-8.133085731346737, 7.985938613559255, -2.796732353967758],
+    [-6.582976305576306, 6.484295397122057, -2.4442246188132555],
+    [-5.291531285893388, 5.228388682058923, -2.1482231694609797],
+    [-4.1971354220928685, 4.159705712067973, -1.8952264466436033],
+    [-3.2565837233018224, 3.237221891932169, -1.6757813260796952],
+    [-2.4385728292134443, 2.4312177931449783, -1.4830488193940643],
+    [-1.719795880104544, 1.7195695610474735, -1.3119444295644938],
+    [-1.0824945356100462, 1.085423592250219, -1.1585998121845595],
+    [-0.5128670170536765, 0.5156843857886656, -1.0200130141356103],
+    [-6.399017348275175e-14, 4.269551513915897e-14, -0.8938140093540586],
+    [-71.44000318504506, 57.974672572536214, -12.071933505506188],
+    [-53.23005771540781, 42.25091555352295, -8.744917472999068],
+    [-43.26069695000912, 33.63671435496761, -6.925157564574293],
+    [-36.79950273263015, 28.04687356362302, -5.744437526330683],
+    [-32.20236625170109, 24.062675634598786, -4.902008956917427],
+    [-28.730954190431216, 21.047308748244955, -4.263177874491502],
+    [-25.998860362921945, 18.667675370513805, -3.757633432041067],
+    [-23.781938987668834, 16.730662558831046, -3.3446905260449893],
+    [-21.940128752579938, 15.115671655605388, -2.9989870934491503],
+    [-20.380825499416154, 13.743014985040245, -2.703793489846276],
+    [-19.040017582818102, 12.557643423392761, -2.447571788944038],
+    [-17.871832682518086, 11.520132337098037, -2.222072074589829],
+    [-16.8423974498024, 10.601388285118635, -2.0212158058503324],
+    [-15.926054093307469, 9.779388608390565, -1.8404087834470109],
+    [-15.102934261490423, 9.037091409377112, -1.6761012142097507],
+    [-14.357349543773223, 8.36104951558849, -1.525496267339121],
+    [-65.79743742039213, 54.096763628317255, -11.440759542106655],
+    [-48.99824668257058, 39.44086417312234, -8.30967034771459],
+    [-39.71809804582584, 31.33796744628043, -6.581113789034455],
+    [-33.675458983344946, 26.054545036664948, -5.453994457660057],
+    [-29.363751697907123, 22.277352487311024, -4.647258503166318],
+    [-26.10145059900646, 19.41259801138109, -4.034110940173771],
+    [-23.530157934637632, 17.14818145387422, -3.5480437223078978],
+    [-21.441256510551376, 15.302533787059389, -3.150438303734104],
+    [-19.704047011110234, 13.761966035371533, -2.817157177330747],
+    [-18.23193584867011, 12.451198707924975, -2.532245001372327],
+    [-16.964963639049447, 11.318137849648085, -2.2846787766857024],
+    [-15.86009879710328, 10.325419756911229, -2.0665650572565],
+    [-14.88552372175406, 9.445435173213625, -1.8720802443564515],
+    [-14.017107919577846, 8.6572589281293, -1.6968174060529353],
+    [-13.236142941981583, 7.944678709786129, -1.5373673729221091],
+    [-12.527837728204597, 7.294886043701032, -1.3910408511395274],
+    [-60.97104713285586, 50.76970622713148, -10.898457703275378],
+    [-45.35164395546598, 37.01328911430284, -7.933408120751364],
+    [-36.65325913287161, 29.3450041755662, -6.28280962331645],
+    [-30.965515010901946, 24.32317125975733, -5.201688449631435],
+    [-26.896445750655584, 20.723134287481564, -4.425649192297733],
+    [-23.812185746685554, 17.98746834133682, -3.83461858102828],
+    [-21.377937336724063, 15.82189611522423, -3.3653366525285184],
+    [-19.398207677488678, 14.054675030766623, -2.980954385786884],
+    [-17.75022785782811, 12.57800998570801, -2.6583846679900605],
+    [-16.35250459731466, 11.320387473593623, -2.3823371743262034],
+    [-15.148521761794882, 10.232241171043244, -2.142231549866649],
+    [-14.097664158730735, 9.27797169978032, -1.9304809593461354],
+    [-13.169864322704584, 8.431241191885446, -1.741481156797766],
+    [-12.34229285087213, 7.672064998024289, -1.5709863444924244],
+    [-11.597229890953182, 6.984941889486945, -1.4157083284319667],
+    [-10.920649489738242, 6.357610873996707, -1.2730502847291691],
+    [-56.79445434496073, 47.88183783387692, -10.426873784442154],
+    [-42.176935852543345, 34.8946504433575, -7.604688365442626],
+    [-33.976423041030664, 27.600830981427762, -6.0216373128646],
+    [-28.593501267621214, 22.80516137344087, -4.980492778396251],
+    [-24.73325182066176, 19.358572700349, -4.231172875689861],
+    [-21.802396227904964, 16.73483927780067, -3.6594091541245404],
+    [-19.48630781723883, 14.655026118746953, -3.2047551940284786],
+    [-17.600739522971775, 12.955869072593174, -2.831897799363252],
+    [-16.029730331024137, 11.534666803402587, -2.5186627496579095],
+    [-14.69617151380116, 10.323164509168649, -2.2503382102829628],
+    [-13.546510192656141, 9.273984980914241, -2.0167291051798086],
+    [-12.54221246665197, 8.353057851650558, -1.81051556145],
+    [-11.654719272337008, 7.535147539321129, -1.6262843382803767],
+    [-10.862323134468406, 6.801085013380559, -1.4599299296108228],
+    [-10.148156982527116, 6.135985744575738, -1.3082694326610618],
+    [-9.498855066444234, 5.528063539738132, -1.168786462870827],
+    [-53.143402299023116, 45.34972322706708, -10.012476229393934],
+    [-39.387979978842935, 33.02898898481185, -7.314825620738267],
+    [-31.618669011126975, 26.06163115866265, -5.790995580051177],
+    [-26.50052920989064, 21.463669947568043, -4.7849808678764765],
+    [-22.82194943805176, 18.15140768616398, -4.059165167515133],
+    [-20.024664497107345, 15.625727681920047, -3.5043572485583647],
+    [-17.81150254044194, 13.621064002091275, -3.0625777420544495],
+    [-16.007968625729717, 11.981554523696518, -2.6998622495808395],
+    [-14.504014480402923, 10.608950890011787, -2.394839082773954],
+    [-13.22634377255252, 9.43784975220089, -2.1333052850225642],
+    [-12.12398242046802, 8.422790704131975, -1.905404816257107],
+    [-11.160208965854526, 7.531042781234664, -1.704052752102936],
+    [-10.307778540425135, 6.738336243171509, -1.5240039371729455],
+    [-9.545961502655997, 6.026216792619543, -1.3612762887981409],
+    [-8.858634251505253, 5.380340314904182, -1.212779495269887],
+    [-8.233006780061555, 4.789336664321536, -1.0760678711873963],
+    [-49.9232191428079, 43.10975272571891, -9.644972910966743],
+    [-36.91814026724082, 31.3729720611154, -7.057109991788023],
+    [-29.52621929921682, 24.693171059530876, -5.585739081629976],
+    [-24.640373914627784, 20.26973280540515, -4.610897101982933],
+    [-21.121360905761996, 17.076161086996176, -3.9059512354414174],
+    [-18.441462222198005, 14.637157039619048, -3.366200559585471],
+    [-16.318775030718744, 12.69892927045113, -2.9358521373070894],
+    [-14.587350617291037, 11.112145111285793, -2.582138435768244],
+    [-13.142328915891666, 9.782488425438398, -2.2844004293173374],
+    [-11.913761933362808, 8.647075667097168, -2.028887238837937],
+    [-10.85294202999615, 7.662141092761246, -1.8060440651365484],
+    [-9.924741450476535, 6.796138245411863, -1.608994730857476],
+    [-9.103071326643105, 6.025651635261818, -1.4326433872808422],
+    [-8.368062370199864, 5.3328591202490765, -1.2731175445394847],
+    [-7.704245276048709, 4.703894532688759, -1.1274090666863974],
+    [-7.099336873186376, 4.127755672817954, -0.9931349839974042],
+    [-47.060510124620386, 41.11257651695255, -9.316396629735122],
+    [-34.715075174567005, 29.892524840771046, -6.826276905990906],
+    [-27.656511171836012, 23.468319978485038, -5.401799241503174],
+    [-22.97625675479133, 19.20027039271691, -4.454858243300181],
+    [-19.598590170835475, 16.112446373039564, -3.7685975149149886],
+    [-17.02270937955829, 13.750680100118686, -3.2423261317312915],
+    [-14.98019680847146, 11.871648331111992, -2.8222071709573426],
+    [-13.312663155237527, 10.331831008213054, -2.476544895210608],
+    [-11.91984235291673, 9.040415470983831, -2.1853188098624674],
+    [-10.73475348033123, 7.936764442449469, -1.9351831802769126],
+    [-9.710698325529265, 6.978623356223796, -1.7168531747983167],
+    [-8.813962624421064, 6.135502163914951, -1.5236396577742242],
+    [-8.01948304305221, 5.384747249722749, -1.3505805172487342],
+    [-7.308153682690981, 4.709100222055164, -1.1939013831058884],
+    [-6.665085900654907, 4.095121101553717, -1.0506676555521723],
+    [-6.078446463619261, 3.532135794336336, -0.9185524639483722],
+    [-44.497501661609256, 39.31931651497137, -9.02048388059425],
+    [-32.737109222910405, 28.56048476511919, -6.618138638943406],
+    [-25.975427194833102, 22.365300677503797, -5.235917320551265],
+    [-21.47855699539435, 18.236666800430015, -4.314141365281557],
+    [-18.227045988146344, 15.243757646151963, -3.64473418606575],
+    [-15.744017484024479, 12.951314736701068, -3.1306167090376436],
+    [-13.773065348613118, 11.125399414454893, -2.719716493168508],
+    [-12.162542373803674, 9.627708273932583, -2.381305411482751],
+    [-10.81628340436978, 8.370575024299141, -2.095939641141809],
+    [-9.669957705405983, 7.2953804744848, -1.8506393723779555],
+    [-8.678662591442444, 6.361227971544309, -1.6363635759746284],
+    [-7.809947136229034, 5.538576759372361, -1.4465919802320826],
+    [-7.039664732004311, 4.805457784378015, -1.2764831611607939],
+    [-6.349391944043601, 4.14511912365071, -1.122351249785928],
+    [-5.724759312528897, 3.5445036375639583, -0.9813279409636548],
+    [-5.154336036838176, 2.993231987952003, -0.8511369234497975],
+    [-42.188098400161536, 37.69892584813853, -8.752242646621362],
+    [-30.950653535108273, 27.354933654163318, -6.4293228110277605],
+    [-24.455304108667583, 21.366431057880714, -5.085452803379698],
+    [-20.123153995120227, 17.363739655945736, -4.186530425461632],
+    [-16.985001468772488, 14.456586518120808, -3.532425977558142],
+    [-14.585403469824525, 12.226764199955682, -3.0293383809706524],
+    [-12.678733958083797, 10.44880993008549, -2.626798702169087],
+    [-11.119403536220958, 8.98913617362095, -2.2949587060294956],
+    [-9.81493443388563, 7.762922446599328, -2.0148990831757287],
+    [-8.703379968485994, 6.71337532856689, -1.773972838847265],
+    [-7.74145311662314, 5.800826756076863, -1.563360634152773],
+    [-6.89783989383433, 4.996594790312248, -1.3766956862138882],
+    [-6.149218741877793, 4.279329586760937, -1.2092462153340606],
+    [-5.477780456069529, 3.6327372439946424, -1.05740671112571],
+    [-4.869622897872974, 3.0441067944104683, -0.9183690349151103],
+    [-4.313677623067509, 2.503325458560013, -0.7899027526033707],
+    [-40.09507616740379, 36.22630904766292, -8.507645284223056],
+    [-29.328337690902046, 26.25798931395491, -6.2570832921162385],
+    [-23.073476466724482, 20.45720322492416, -4.948243435041297],
+    [-18.890206336033913, 16.568980922778294, -4.070203412060628],
+    [-15.854527720728292, 13.739767758327945, -3.4300766311588458],
+    [-13.530333535972172, 11.566836854860487, -2.9370571440412867],
+    [-11.681738728803689, 9.832431664238156, -2.5421428778423993],
+    [-10.16863319268414, 8.407255629943366, -2.2162909169731497],
+    [-8.901876401198969, 7.2090786962314555, -1.9410620692299039],
+    [-7.821680089652176, 6.182769608024098, -1.7041139421769385],
+    [-6.886220226086349, 5.289778754197807, -1.4968300281649438],
+    [-6.065212368041788, 4.502205887472226, -1.3129839097420677],
+    [-5.336082033803672, 3.799264617023007, -1.1479440199177653],
+    [-4.6815761663951, 3.1650777066005986, -0.9981782514177676],
+    [-4.088215774975179, 2.587249052395531, -0.8609333990275362],
+    [-3.5452606240186393, 2.0559083646068372, -0.7340209122064554],
+    [-38.18804594210497, 34.88095844254618, -8.283406157063222],
+    [-27.84763405271183, 25.254915286035143, -6.0991611544217585],
+    [-21.81119472624997, 19.625598866203067, -4.822501372182589],
+    [-17.763239276923798, 15.841988950435956, -3.963648084471695],
+    [-14.820693009541719, 13.083987400396179, -3.336357228813872],
+    [-12.565002272401445, 10.963008244202282, -2.8525760580471955],
+    [-10.769138124657529, 9.268343350228465, -2.4646523072083335],
+    [-9.297976292727517, 7.874623453799285, -2.1442844225070026],
+    [-8.065414426141633, 6.701990058633286, -1.8734752117982543],
+    [-7.013629719935496, 5.696833700556643, -1.6401626396336668],
+    [-6.102130423377848, 4.821628623368497, -1.4359168086290997],
+    [-5.301569583420402, 4.049189962908854, -1.25464037061653],
+    [-4.590052892270987, 3.3592468041232975, -1.0917938481779765],
+    [-3.950833553292455, 2.736302962268945, -0.9439125448610141],
+    [-3.3708177459384587, 2.168250223195537, -0.808293681432298],
+    [-2.839564059671565, 1.6454400463860954, -0.6827871541326155],
+    [-36.44195223577245, 33.64594828700057, -8.0768179068471],
+    [-26.489829453492803, 24.333455031067384, -5.953680872994217],
+    [-20.652810120535438, 18.861573054324587, -4.706735030067913],
+    [-16.72845338203651, 15.174037786773592, -3.8655982009972463],
+    [-13.870953366421272, 12.481408056828275, -3.2501517061601137],
+    [-11.677782118101407, 10.408086257631341, -2.7748872960492417],
+    [-9.930007015795518, 8.749845821412311, -2.393401410085974],
+    [-8.497065295875844, 7.384930965319775, -2.078078557160782],
+    [-7.295635291051192, 6.235665683187269, -1.8113305583887223],
+    [-6.269693325752178, 5.249840908926355, -1.581354735103868],
+    [-5.379967125478399, 4.390872848725135, -1.3798937385259635],
+    [-4.5979676867577695, 3.632234557346696, -1.2009694895149345],
+    [-3.9024229922797233, 2.9541289723740785, -1.0401275478268521],
+    [-3.277049283789959, 2.3414100319449043, -0.8939654228105749],
+    [-2.7091049582673037, 1.7822339634619073, -0.7598268453899062],
+    [-2.188421890353513, 1.2671559303833193, -0.6355971739612478],
+    [-34.83594889763961, 32.50718151507746, -7.885629066750679],
+    [-25.23924603264334, 23.483327128375795, -5.819071772282449],
+    [-19.585152586678607, 18.156659983980767, -4.599689501752405],
+    [-15.774194170511349, 14.557746478055387, -3.7749846454635234],
+    [-12.994683200602788, 11.92538000337006, -3.1705149215842177],
+    [-10.858797820188004, 9.895952020058267, -2.7031351137935187],
+    [-9.155044379624957, 8.271225351008301, -2.3276023704280315],
+    [-7.757054032623676, 6.932784914717125, -2.0169390932100892],
+    [-6.584062462553936, 5.804972671585965, -1.7539373593666885],
+    [-5.581700687629797, 4.836874207699483, -1.527035524325729],
+    [-4.711817828612523, 3.9927762733842385, -1.328136508280475],
+    [-3.9467135746387867, 3.2467596652981965, -1.1513729348751112],
+    [-3.2656877152186095, 2.579464778639064, -0.9923692308616551],
+    [-2.6528817610014945, 1.97606858956096, -0.8477805535461854],
+    [-2.0958774640178772, 1.4249711985062528, -0.71499371265596],
+    [-1.5847575490834487, 0.9169156121514865, -0.5919269119277958],
+    [-33.3525460043413, 31.45281794658814, -7.707951322414621],
+    [-24.08264349188232, 22.69583775856574, -5.694007832044664],
+    [-18.59705037307911, 17.503668239971816, -4.5003005918771875],
+    [-14.890540560192322, 13.986822182132585, -3.690897534721985],
+    [-12.182809601814192, 11.410215830553327, -3.096640015673664],
+    [-10.099593749206212, 9.421357068983104, -2.63658692353204],
+    [-8.436265640386079, 7.827567803173044, -2.2665789886308976],
+    [-7.070329715611121, 6.513534917082582, -1.9602342560404546],
+    [-5.923383983193476, 5.405474201573161, -1.7006998177959873],
+    [-4.94258786873849, 4.453673149645038, -1.476638967221752],
+    [-4.0908260072679985, 3.623226336904508, -1.2801040952679446],
+    [-3.3411260319654295, 2.8887781693212107, -1.1053309868327559],
+    [-2.673315162439816, 2.231374430511027, -0.9480174965212407],
+    [-2.0719268896061496, 1.6364912173764685, -0.8048724061549674],
+    [-1.5248407977166214, 1.0927542932003376, -0.6733225707821511],
+    [-1.0223705372671297, 0.5910804193826064, -0.5513167221702289],
+    [-31.976954435050022, 30.472834825733948, -7.542188361071815],
+    [-23.008755265127625, 21.963579791406136, -5.577361028970151],
+    [-17.678954946363824, 16.89644264406283, -4.4076589590165804],
+    [-14.06898200390925, 13.455858427426689, -3.612556517293839],
+    [-11.427524312353269, 10.931012744666873, -3.0278327240580647],
+    [-9.39287094206417, 8.979762831506141, -2.5746104489821784],
+    [-7.766758689518476, 7.414611041856877, -2.209745962857356],
+    [-6.430283841755229, 6.123135963492872, -1.9074156597889593],
+    [-5.307235345979105, 5.033300144803739, -1.6510993498095394],
+    [-4.346189905410071, 4.096511120912717, -1.4296710325516215],
+    [-3.5109919686130717, 3.2786158486721266, -1.23532301120546],
+    [-2.7753434526317626, 2.5547832571379483, -1.062387546577434],
+    [-2.1195597057362967, 1.9064360516201984, -0.9066310913081277],
+    [-1.5285366228211674, 1.3193281617488908, -0.7648124722477574],
+    [-0.9904288555750702, 0.7822947536981634, -0.6343958854575888],
+    [-0.4957631249633056, 0.286413715959142, -0.5133585200658649],
+    [-30.696576595964906, 29.55868520864837, -7.3869806587350455],
+    [-22.00792475841519, 21.280196619914634, -5.468164782229582],
+    [-16.82264505316085, 16.32967617998968, -4.320981850008066],
+    [-13.30216255185906, 12.960175012521672, -3.5392872482048867],
+    [-10.72205446480481, 10.483510946611313, -2.96349094765585],
+    [-8.732277007125978, 8.567212192914186, -2.5166554876557212],
+    [-7.140488260318528, 7.028626410392469, -2.156592290349568],
+    [-5.831127873261818, 5.758037621666688, -1.8580029822069948],
+    [-4.730024235660938, 4.68504244440744, -1.6046802778146563],
+    [-3.7870729529107545, 3.7620957637989125, -1.385696223900193],
+    [-2.9670111545232793, 2.955747606244007, -1.1933745223113121],
+    [-2.2441673531612225, 2.2416565934304193, -1.0221379458674014],
+    [-1.5993100155012225, 1.6015969310570897, -0.8678172292669533],
+    [-1.0176710319411255, 1.0215813316519315, -0.7272180406576036],
+    [-0.4876597576534028, 0.4906397909402807, -0.5978394940737266],
+    [6.802561590870302e-14, -8.055616406173519e-14, -0.4776853589533545],
+    [-66.15309484565456, 53.56877066755918, -10.834281980160307],
+    [-49.81067604897394, 39.45849320556537, -7.856334610105345],
+    [-40.87843208221741, 31.733492919947413, -6.226199746411536],
+    [-35.0832605508567, 26.709607031873162, -5.164398212654663],
+    [-30.950647157204063, 23.115911943414282, -4.40266204767105],
+    [-27.820501977669174, 20.38369548614757, -3.8211943836764592],
+    [-25.347787076727222, 18.215827420683173, -3.3575240469557945],
+    [-23.332323811521356, 16.44001188773438, -2.9754896723950646],
+    [-21.648853631085263, 14.948493922616818, -2.652512939781712],
+    [-20.214329616566626, 13.669868540101275, -2.373655287510243],
+    [-18.971084997218586, 12.554577753525125, -2.1285638954640844],
+    [-17.877505430928075, 11.566875541989276, -1.9097815563867786],
+    [-16.902541647678497, 10.68010233574385, -1.7117537504682543],
+    [-16.022316038843268, 9.873763559520203, -1.5302154221005857],
+    [-15.217930637185674, 9.13164294263367, -1.3617953481141503],
+    [-14.473992916812863, 8.440533856318458, -1.2037503456027405],
+    [-60.86225421405122, 49.926090488038525, -10.241410166834465],
+    [-45.825393130019016, 36.80749424894282, -7.445970645433074],
+    [-37.52662237212776, 29.554606842727733, -5.900484483054432],
+    [-32.114546387657725, 24.812800993687866, -4.888388738895551],
+    [-28.242214293512994, 21.409207837412154, -4.159747170656208],
+    [-25.301991605057673, 18.814941415396817, -3.6020892803388573],
+    [-22.97463536648207, 16.75226265587981, -3.156464227794267],
+    [-21.074244142413107, 15.059519490394555, -2.7886213589662763],
+    [-19.484155515750395, 13.635319157163568, -2.477113238714639],
+    [-18.126833758508578, 12.412308907324041, -2.2077151523677063],
+    [-16.948320680729935, 11.343640546230862, -1.97054758147788],
+    [-15.909594213685422, 10.395450167355309, -1.7584792393544062],
+    [-14.981472385006764, 9.542424528875667, -1.5661865803443764],
+    [-14.14145475280695, 8.765053788913791, -1.3895716350812495],
+    [-13.371677352813606, 8.04785315777517, -1.2253855392908757],
+    [-12.657533741063993, 7.378163920195562, -1.0709749438026193],
+    [-56.35678249908579, 46.81743416242611, -9.734988446305325],
+    [-42.403885162608915, 34.52781831862223, -7.0930158065490465],
+    [-34.636371949112075, 27.673468690753523, -5.619376357252294],
+    [-29.547180452163875, 23.170928245538118, -4.649656222280469],
+    [-25.894876224793236, 19.929041456625974, -3.9493001612188445],
+    [-23.115522385528653, 17.452337536755987, -3.4120242374497316],
+    [-20.911461908267167, 15.479416913426817, -2.9818619489402667],
+    [-19.108778730187062, 13.85762254273618, -2.6261866262048645],
+    [-17.598043383980354, 12.490949501358257, -2.3245136782982234],
+    [-16.30636535540585, 11.315481686242148, -2.06322682040237],
+    [-15.182916259338798, 10.286671201177436, -1.832850136350648],
+    [-14.190863646851954, 9.372252541870761, -1.6265302221053541],
+    [-13.302603599994367, 8.548055743768447, -1.4391402825875201],
+    [-12.496799721492344, 7.795406607813996, -1.2667244092870882],
+    [-11.7564619604355, 7.099440247241146, -1.1061374442041387],
+    [-11.067648189200574, 6.447961389246826, -0.9548018193133638],
+    [-52.47084504908272, 44.13056584968737, -9.296719076342061],
+    [-39.43280398717347, 32.545256439325506, -6.785906402517719],
+    [-32.11742130640828, 26.032247532976264, -5.374138890033387],
+    [-27.304138070995645, 21.735420905952232, -4.441036848099649],
+    [-23.840295979810946, 18.632870378049706, -3.765168591177964],
+    [-21.19894449399867, 16.25760589226949, -3.2455570375812997],
+    [-19.10076686668787, 14.362203976538527, -2.828803840680309],
+    [-17.382056981561416, 12.80171982522909, -2.483682679996799],
+    [-15.93955849626496, 11.48477554545686, -2.190540282078323],
+    [-14.704350894850887, 10.350409625633693, -1.9362860493465248],
+    [-13.628283254641042, 9.356053063134476, -1.7117928394720654],
+    [-12.67639746751525, 8.470815685378582, -1.510447282663589],
+    [-11.822440896077856, 7.671510984004413, -1.3272923495313855],
+    [-11.046074005978564, 6.940183045751179, -1.158494470415402],
+    [-10.331055172570558, 6.262499202846963, -1.0009975347055753],
+    [-9.664011429577045, 5.6266615640750635, -0.8522887898160901],
+    [-49.08186627406912, 41.78250053679313, -8.913105708291795],
+    [-36.82685547143414, 30.803925048221913, -6.515955011907448],
+    [-29.9011777392505, 24.586951175804323, -5.158141899026265],
+    [-25.326496127714208, 20.469082315731765, -4.2570570674097885],
+    [-22.025939008582704, 17.487948619086694, -3.6026280937708903],
+    [-19.504294222820846, 15.201166093981282, -3.0984921923903213],
+    [-17.498033913526303, 13.373421870999177, -2.6934898780418384],
+    [-15.85226078624172, 11.866466903210712, -2.357617566539665],
+    [-14.46905266356831, 10.592945623533243, -2.071948533709602],
+    [-13.28292319461897, 9.494467024890433, -1.8238518048085328],
+    [-12.248039222772029, 8.530184410534147, -1.6045051547125375],
+    [-11.33106587445934, 7.670404597662136, -1.4075049363797552],
+    [-10.506920982483123, 6.8927966600701245, -1.228042266803917],
+    [-9.756128539637366, 6.18002909942639, -1.0623901312112698],
+    [-9.063095371675653, 5.518231485041143, -0.9075697775011717],
+    [-8.414942132636817, 4.895950888103152, -0.7611246006213956],
+    [-46.09725760886538, 39.710528097438534, -8.5739730865813],
+    [-34.52061932953577, 29.260926786942363, -6.2765082914261185],
+    [-27.93457793848345, 23.30351177777284, -4.966262673789655],
+    [-23.568404813099338, 19.342934189884005, -4.093462501757113],
+    [-20.410746547940185, 16.46865014209734, -3.4579901321645865],
+    [-17.993953391291484, 14.25978613428543, -2.9675423922004573],
+    [-16.06824699317658, 12.491642899263296, -2.572933807111383],
+    [-14.486415146779596, 11.031848740370805, -2.245240220322821],
+    [-13.15519517066418, 9.796582259026204, -1.9661767107670922],
+    [-12.012103026785127, 8.729712227033241, -1.7235183799006304],
+    [-11.013332898731479, 7.791906316484952, -1.5087123181582138],
+    [-10.126970102611761, 6.954523624238399, -1.3155399712256952],
+    [-9.32895659254451, 6.195984982695335, -1.1393230336371587],
+    [-8.600571940155696, 5.499507129434502, -0.9764282371756189],
+    [-7.9267905720741165, 4.851625220552173, -0.8239447441704877],
+    [-7.295165892758586, 4.241189123661797, -0.6794651636911636],
+    [-43.44575896973192, 37.86635975889851, -8.271503401991191],
+    [-32.463071975557256, 27.88277567006024, -6.062384104256006],
+    [-26.17591983355464, 22.15512689199695, -4.794479938244596],
+    [-21.993640504515945, 18.334054407979593, -3.9468954521415975],
+    [-18.96214901657362, 15.554620457642882, -3.328331616973013],
+    [-16.637984026359653, 13.414951198699924, -2.8500938621991416],
+    [-14.78346252992, 11.69974212747452, -2.464754607007745],
+    [-13.258143236826106, 10.281830630755909, -2.1443518654869536],
+    [-11.972872793890906, 9.080529945360656, -1.8711730280328145],
+    [-10.867816603133399, 8.04171381439432, -1.633355286443012],
+    [-9.90095846382099, 7.127393488739099, -1.4225857617173951],
+    [-9.0416374387096, 6.309861342327684, -1.232810715181657],
+    [-8.266699506540771, 5.5682045338704205, -1.0594679554265392],
+    [-7.558091196881621, 4.886125380330932, -0.8990074335812162],
+    [-6.901288217499899, 4.250518144569556, -0.7485785008338753],
+    [-6.28422694100287, 3.650501106934236, -0.605817368914546],
+    [-41.071618779334564, 36.21219457561685, -7.9995898817074185],
+    [-30.61382163824639, 26.64293957690332, -5.869485277157212],
+    [-24.591961865645555, 21.120408429993606, -4.639591703547427],
+    [-20.573186931658487, 17.424059079401314, -3.8146689192653067],
+    [-17.653957470848667, 14.729470900844063, -3.2113042638398674],
+    [-15.412237573607936, 12.651705735228035, -2.7440401129366436],
+    [-13.62108035158188, 10.983848620775733, -2.367028149359297],
+    [-12.146062259799448, 9.60339347103572, -2.053171411361572],
+    [-10.901684995284798, 8.43245710130725, -1.7852719159982189],
+    [-9.830471461756511, 7.418706701987553, -1.5517923736541703],
+    [-8.891998050781, 6.525355097772814, -1.3446355553488323],
+    [-8.056719402648273, 5.725528864212437, -1.1578956425240547],
+    [-7.302285688152161, 4.998910301289748, -0.9871145060276031],
+    [-6.611236661244156, 4.329635104096999, -0.8288165580956008],
+    [-5.969494132876761, 3.7049179919494386, -0.6802049410270686],
+    [-5.365336084603916, 3.1141172092802774, -0.5389548701937135],
+    [-38.93057880757401, 34.71800611991558, -7.753391706786963],
+    [-28.940460085678954, 25.520111253917324, -5.694528356298879],
+    [-23.155860262186685, 20.182065818702903, -4.4990149199620495],
+    [-19.28350238069862, 16.598014508411037, -3.6946049314174374],
+    [-16.46484467961869, 13.979837394116656, -3.104997434055022],
+    [-14.296986768469306, 11.957814968487153, -2.647661793324331],
+    [-12.562587933489924, 10.332583140773588, -2.2781795938689027],
+    [-11.132614733605056, 8.985830276119842, -1.970237500418231],
+    [-9.924844547838726, 7.84219936563104, -1.7071037374207432],
+    [-8.883913499413712, 6.850973478334321, -1.4775357880974533],
+    [-7.970825389130075, 5.976447597115036, -1.27363154891231],
+    [-7.157034046511407, 5.1924988519505995, -1.0896188527376598],
+    [-6.420910513021234, 4.479345099491974, -0.9211335453171697],
+    [-5.745525160389892, 3.8215110725150776, -0.7647670765261809],
+    [-5.117199334907069, 3.2064993740587564, -0.6177710176145244],
+    [-4.524517663194183, 2.623884288857395, -0.4778557647190158],
+    [-36.987040222282, 33.35962750657476, -7.529020463145415],
+    [-27.416661320081687, 24.49696663783337, -5.534849178433128],
+    [-21.845673603240556, 19.325950831972605, -4.370640432195464],
+    [-18.10525491387294, 15.843642509619064, -3.5849166929694185],
+    [-15.377230648726732, 13.294689309423356, -3.0078376237623896],
+    [-13.275917499206694, 11.323146516534106, -2.5595382669632865],
+    [-11.592631680066836, 9.73649384749953, -2.1969039047246386],
+    [-10.203201372064507, 8.420223713799597, -1.894335921544478],
+    [-9.028359524338322, 7.301270440511407, -1.635527690137132],
+    [-8.014648911385681, 6.330382354910359, -1.4095053517272036],
+    [-7.124360414294743, 5.472834868283436, -1.208544425679388],
+    [-6.329849859896898, 4.703185562062779, -1.026994434687468],
+    [-5.6101355870474015, 4.002135292360047, -0.8605763137725471],
+    [-4.94876634727614, 3.3545607742851695, -0.7059423676441783],
+    [-4.332423002555508, 2.748224554159275, -0.5603880262745186],
+    [-3.7499666342445113, 2.1728964199393723, -0.42165562774868065],
+    [-35.212022615435366, 32.11737223563737, -7.323314748589357],
+    [-26.02079125168841, 23.55925655815873, -5.388260917392527],
+    [-20.643259403433646, 18.54035240785905, -4.252726107337821],
+    [-17.022383095615606, 15.150729818553328, -3.4841211801398866],
+    [-14.376450698918802, 12.664813122216344, -2.9185135339848403],
+    [-12.335372973240364, 10.739206333869419, -2.4784814046934485],
+    [-10.698317954560514, 9.187631161567632, -2.122106108300181],
+    [-9.345526209948211, 7.8990510811939085, -1.824444862131477],
+    [-8.200413540159843, 6.802491038212112, -1.5695810373529042],
+    [-7.211252952434634, 5.850035598212922, -1.3467870356639005],
+    [-6.341502916393662, 5.0078537287370635, -1.1485010457723155],
+    [-5.564335155549851, 4.251121328763454, -0.9691836752646915],
+    [-4.85935487401219, 3.5609789125671303, -0.804633637169473],
+    [-4.210542578643808, 2.922622159767219, -0.6515586004265846],
+    [-3.6049036774129934, 2.324049220205995, -0.50729390574993],
+    [-3.0315490022495326, 1.7552073428343902, -0.36961103128717526],
+    [-33.581667351399304, 30.97502278817021, -7.13367519914918],
+    [-24.734873790455634, 22.695131013600054, -5.252948688672372],
+    [-19.5334464456416, 17.815467367146958, -4.143816788290372],
+    [-16.021386860641186, 14.510681862037313, -3.3909732470690517],
+    [-13.450123973698178, 12.082420110064817, -2.8359192750844664],
+    [-11.463777238588365, 10.198784410537373, -2.4034851621042614],
+    [-9.86867765346795, 8.679221730030882, -2.052855598034754],
+    [-8.549092935731164, 7.415880110243283, -1.7596928650823913],
+    [-7.430887294266432, 6.339702030228857, -1.5084399718606991],
+    [-6.463911857640399, 5.403996730897742, -1.288596191303035],
+    [-5.612688858715587, 4.5757510863546464, -1.092749353509591],
+    [-4.851132265771275, 3.8307060197694334, -0.9154620623725931],
+    [-4.159379990882442, 3.1504028813811624, -0.7526042722722602],
+    [-3.5218035539226897, 2.520327483431686, -0.6009342485156531],
+    [-2.9257016513586604, 1.9286919052899538, -0.45782376138389524],
+    [-2.360410533068531, 1.365604532151279, -0.32107093560947814],
+    [-32.0761218669185, 29.91907658913303, -6.9579417409291775],
+    [-23.543809757426466, 21.89462846156724, -5.127390059676056],
+    [-18.503404010975355, 17.14299678129473, -4.04268338802625],
+    [-15.090783288066037, 13.916180016402738, -3.304415137887864],
+    [-12.58766581883502, 11.540843135226094, -2.759110585306141],
+    [-10.651187543056556, 9.695679349484891, -2.333686495535129],
+    [-9.094248269243149, 8.205413616261472, -1.988350531977953],
+    [-7.8048098106323796, 6.965130022254345, -1.6993259121423696],
+    [-6.710981515496011, 5.9075380411544565, -1.451388832826252],
+    [-5.764060250784859, 4.987074225346233, -1.2342484988027902],
+    [-4.9295412013568205, 4.1714770374743, -1.040630958178644],
+    [-4.182015689340965, 3.4370054069742526, -0.8651928023614581],
+    [-3.5021061959574484, 2.7655670076088916, -0.7038694346741168],
+    [-2.874538575486431, 2.1429118773076787, -0.5534654604556052],
+    [-2.2868761658336805, 1.5574462508799354, -0.4113859393574432],
+    [-1.7286577255817996, 0.9994243852312413, -0.2754533505163088],
+    [-30.678695088188682, 28.93817514474911, -6.794300856559894],
+    [-22.43477767893625, 21.149283861323696, -5.010294183701232],
+    [-17.542153378579115, 16.51583317284732, -3.948275810197274],
+    [-14.220681688203198, 13.3609137794361, -3.223537146132831],
+    [-11.779904222715658, 11.034297892392503, -2.6872704662465163],
+    [-9.888939767836666, 9.224479951705712, -2.268334475386308],
+    [-8.366740689221702, 7.761072877282113, -1.9278895172781143],
+    [-7.104672765905511, 6.54187957199377, -1.6426810867005721],
+    [-6.032912798938499, 5.501244497405965, -1.3977953357591044],
+    [-5.1040867826410325, 4.594645493402467, -1.1831364119173746],
+    [-4.2845835130337555, 3.7905146300698007, -0.9915586183931149],
+    [-3.5496127527051744, 3.0655860245872746, -0.8178052270489343],
+    [-2.8802383689613755, 2.402102759318496, -0.6578719317143805],
+    [-2.2615068443955315, 1.7860553497735017, -0.5086058053731035],
+    [-1.6812192454654737, 1.2060256975896726, -0.36744229591107036],
+    [-1.1290946540496025, 0.6523992338594391, -0.23222606368978496],
+    [-29.375208557751666, 28.022665430079936, -6.6412144983940005],
+    [-21.396766890458533, 20.45182314309194, -4.900554478561749],
+    [-16.64018294393792, 15.927813908394542, -3.8596859436845223],
+    [-13.402445423862387, 12.839367431496928, -3.147546385748227],
+    [-11.018771649500177, 10.557690885443705, -2.619681651866544],
+    [-9.169361033577191, 8.78038796172975, -2.206765328586954],
+    [-7.678766925289736, 7.341617104647216, -1.8708485491565945],
+    [-6.441504556479855, 6.141708794531172, -1.5891649700499189],
+    [-5.389661640657528, 5.116525803970602, -1.3470901249016258],
+    [-4.4770905597843385, 4.222511306236649, -1.1347098695041287],
+    [-3.6710008728129373, 3.4287374697759088, -0.944997519747226],
+    [-2.947169403451196, 2.712376392066665, -0.772776740521621],
+    [-2.2870611431267815, 2.0559776868067545, -0.6140986979685812],
+    [-1.67601174131718, 1.4457502263607351, -0.46584935061002436],
+    [-1.1020343843805478, 0.8704340445649081, -0.3254918095912365],
+    [-0.5550069389359711, 0.3205315138552008, -0.19089083233944779],
+    [-28.153490472076612, 27.164257880069933, -6.497364801953411],
+    [-20.42020758230426, 19.795921039443485, -4.797211234983465],
+    [-15.78913897927388, 15.37352294577743, -3.7761180228557754],
+    [-12.628416614602816, 12.346646227341653, -3.075741458688083],
+    [-10.29705110308027, 10.106461036271229, -2.5557040116903877],
+    [-8.485530273881261, 8.359070220989326, -2.148381736237206],
+    [-7.023611131869556, 6.942875241108522, -1.816661705958194],
+    [-5.808733697359544, 5.76056468959246, -1.5382354210766274],
+    [-4.774757720243943, 4.749415762825352, -1.2987494344824704],
+    [-3.8766698570210925, 3.866768624441732, -1.0884593744161346],
+    [-3.0824356858147204, 3.082287932642131, -0.9004493630960242],
+    [-2.3683512802474205, 2.3735489822888396, -0.7296175553691072],
+    [-1.7162459889986452, 1.7233815381782762, -0.5720662027320162],
+    [-1.1117154208191142, 1.1181922632258081, -0.4247168239869424],
+    [-0.5429611143802487, 0.5468630110825066, -0.2850576595874751],
+    [-2.1457411496319232e-14, 1.6475645761807512e-14, -0.15097163922517257],
+    [-66.87120469367163, 54.00022523363019, -10.58376521354617],
+    [-50.89619687432161, 40.21621801316182, -7.686974550244029],
+    [-42.22821867142809, 32.714202856260194, -6.107071464998736],
+    [-36.61950907559164, 27.840042897759158, -5.076336079071016],
+    [-32.623106987664094, 24.34916490490999, -4.333803278328168],
+    [-29.595335617969276, 21.688089172063048, -3.7636224306225894],
+    [-27.200679729120296, 19.56841265284901, -3.3055219917624856],
+    [-25.24451445137429, 17.82288921067914, -2.9245983160630273],
+    [-23.60473196115812, 16.346608925662476, -2.598982097796327],
+    [-22.199854477158905, 15.069570699288988, -2.314093428026243],
+    [-20.97262409182943, 13.942569250763027, -2.059688499859585],
+    [-19.880864127572092, 12.929336045186872, -1.828216304204677],
+    [-18.89202521419825, 12.001847841392824, -1.613838104957751],
+    [-17.97969634326598, 11.137322780419987, -1.4117994565354628],
+    [-17.121181352346195, 10.316129094951938, -1.217992284140286],
+    [-16.295618365896743, 9.520154967118563, -1.0286121338936711],
+    [-61.400505890546135, 50.22051424915189, -9.968094212760684],
+    [-46.767987508981655, 37.46081209358562, -7.260432934045132],
+    [-38.741876457577604, 30.44007539594866, -5.767373701421502],
+    [-33.517625245387535, 25.85126016829406, -4.78741706580786],
+    [-29.780161592212583, 22.55143772763748, -4.078608221777119],
+    [-26.93957001052323, 20.02806591509671, -3.532636658393215],
+    [-24.68662845591742, 18.01257022750583, -3.0928375038307565],
+    [-22.84120577402904, 16.348545234431402, -2.7262579236433737],
+    [-21.28988700012944, 14.937534820912342, -2.4121788028871687],
+    [-19.95674124245768, 13.713625990287914, -2.1367419597752084],
+    [-18.78821674267046, 12.630322677129213, -1.8901769398639927],
+    [-17.744696464937476, 11.653207710742123, -1.6652519095067169],
+    [-16.79543570186496, 10.755542422791903, -1.4563451173620485],
+    [-15.915302855211921, 9.915430822658164, -1.2588463288952336],
+    [-15.082492843991432, 9.113825244091474, -1.068735135089027],
+    [-14.276726325137119, 8.33294815778994, -0.8822458369291007],
+    [-56.780281025146, 47.02558845620583, -9.447573012179591],
+    [-43.24871332109893, 35.11110902223566, -6.896863872617048],
+    [-35.755087589125736, 28.492000395390033, -5.4766575115496785],
+    [-30.85167749648057, 24.14267022296671, -4.5395262949548245],
+    [-27.331131959501207, 21.00377740676505, -3.859251304233103],
+    [-24.647789094533266, 18.596697641113078, -3.333807871486539],
+    [-22.51417757595821, 16.6693615628433, -2.9095507302900727],
+    [-20.76219717002412, 15.074415571149071, -2.5551647311157124],
+    [-19.285666332818188, 13.71880344380403, -2.250897816465668],
+    [-18.013277484436138, 12.54003646675896, -1.9834988419546191],
+    [-16.89457065426565, 11.493887864762163, -1.743595019470359],
+    [-15.892063990467852, 10.547488778410393, -1.5242216967278692],
+    [-14.976516086221778, 9.675170470571587, -1.3199373345857761],
+    [-14.123857517855482, 8.855768528246944, -1.1262494951351545],
+    [-13.31301832999633, 8.070709249170855, -0.9392066207414455],
+    [-12.524194941154999, 7.302475300220082, -0.7550692641349671],
+    [-52.82077223223299, 44.28545184863661, -9.00092044888115],
+    [-40.20874691618727, 33.08117919642144, -6.582843416592837],
+    [-33.164143485488154, 26.802681578576472, -5.2247462218533185],
+    [-28.53260746704382, 22.65739312646267, -4.324278765482128],
+    [-25.19644713623463, 19.656007731895578, -3.6684926867140755],
+    [-22.647077475249855, 17.34849813527993, -3.1606956808887414],
+    [-20.615326026396268, 15.496764248844277, -2.7498129096462662],
+    [-18.94324263716534, 13.961132584622456, -2.405926179467822],
+    [-17.53076019544654, 12.653135535809108, -2.110109177323592],
+    [-16.310484018306816, 11.513196111681028, -1.8496290491563439],
+    [-15.234572149190827, 10.499016384184253, -1.615452003015966],
+    [-14.267350031936953, 9.579041026960335, -1.4008402535736528],
+    [-13.380846696218663, 8.728505109322429, -1.200504035780172],
+    [-12.551887505848546, 7.9268570279951005, -1.0100484598030894],
+    [-11.760018741127235, 7.155913681516048, -0.8255767487769485],
+    [-10.985833937337626, 6.398364722332182, -0.6433670456639373],
+    [-49.38408097956, 41.905642854289724, -8.612710185021626],
+    [-37.55210370873876, 31.307375995482, -6.308452146012086],
+    [-30.891481077162286, 25.321763291681496, -5.004042707553423],
+    [-26.493374165451435, 21.3525841580102, -4.135373169711787],
+    [-23.315927009961644, 18.470152811611523, -3.5008656165790777],
+    [-20.88209246115407, 16.248916176164492, -3.008419304562777],
+    [-18.93831758616316, 14.46276742039629, -2.6091789316572878],
+    [-17.33533613281007, 12.978639771176631, -2.2744344425874923],
+    [-15.97832012125825, 11.712017270177261, -1.985974080254898],
+    [-14.803230474623842, 10.605831144913603, -1.7315132879180295],
+    [-13.764470733811638, 9.61944378274274, -1.5023109324537824],
+    [-12.827915415039916, 8.722428039998965, -1.2918242588994548],
+    [-11.966680548851786, 7.89079179773137, -1.0948928889084415],
+    [-11.158354812241454, 7.104503174057272, -0.9072037025822677],
+    [-10.383008397102115, 6.345705569361377, -0.7249040605163074],
+    [-9.621573158092534, 5.597257227777738, -0.5442837800803307],
+    [-46.36748541608574, 39.8157978592006, -8.271478886896716],
+    [-35.20612206816187, 29.741502533151227, -6.066196146560157],
+    [-28.877863264651896, 24.010767744316862, -4.808755396515794],
+    [-24.682473678616574, 20.19530714857692, -3.9679768106302045],
+    [-21.643137528091156, 17.416889812441315, -3.3521588826404187],
+    [-19.309988661187614, 15.271181476815672, -2.873205989160754],
+    [-17.442975725167177, 13.542500736869385, -2.4842032960159286],
+    [-15.900352997401509, 12.103532710988905, -2.157498189960075],
+    [-14.591834926862582, 10.873204299623746, -1.875504484371786],
+    [-13.456293257732453, 9.796631839085261, -1.6263297851118192],
+    [-12.450079015294671, 8.83462258810271, -1.401488406979249],
+    [-11.540408825061444, 7.9577287233832505, -1.1946070624694258],
+    [-10.701338580909448, 7.142625822540468, -1.0006368415879074],
+    [-9.911115031184508, 6.369727454504973, -0.8153339025910084],
+    [-9.15025911814479, 5.621453370431334, -0.6348815939789739],
+    [-8.399996053332506, 4.880802394365872, -0.4555774333155322],
+    [-43.692823099296035, 37.96237621589846, -7.968524712721986],
+    [-33.11471644022931, 28.346345204920876, -5.850302872020086],
+    [-27.077194029565167, 22.839738249968747, -4.634385937128696],
+    [-23.059603184400835, 19.159773194749416, -3.8183147222922735],
+    [-20.14158942012464, 16.47315990756156, -3.2190688470186393],
+    [-17.896983699449013, 14.394170123378208, -2.752085922569764],
+    [-16.097535259930254, 12.71628411157338, -2.3721661226868807],
+    [-14.608081318159043, 11.317247529828126, -2.052592147872382],
+    [-13.34231556161809, 10.119017466154123, -1.7763310808881552],
+    [-12.241659995233098, 9.06863263040566, -1.5318369851754032],
+    [-11.264170889116846, 8.128169574123131, -1.3108492816458623],
+    [-10.37823830979343, 7.269038274770801, -1.1071433003039515],
+    [-9.558737851481741, 6.46849618206194, -0.9157670221666309],
+    [-8.784488369911305, 5.707341918914943, -0.7325358247262642],
+    [-8.036402134416472, 4.968231475831075, -0.5536627023846682],
+    [-7.295962021894772, 4.234283243693953, -0.37545022633654956],
+    [-41.299493748278636, 36.303867665202965, -7.697117145976713],
+    [-31.23382778905299, 27.092662296584056, -5.656247234665569],
+    [-25.452978523073035, 21.784947408090062, -4.477380038257568],
+    [-21.59267621745617, 18.22543966691152, -3.6833873290326804],
+    [-18.782105806076533, 15.62051353936965, -3.098958891784336],
+    [-16.615968953417294, 13.600919174979188, -2.6426803446538885],
+    [-14.876429997784014, 11.968265494055881, -2.270882156645277],
+    [-13.43414285527626, 10.604791755823731, -1.9576819953999682],
+    [-12.206318745928733, 9.435145836701626, -1.6865406552457374],
+    [-11.136633396918002, 8.408072112608991, -1.4462209204179997],
+    [-10.184648393454344, 7.48677118178331, -1.2286622151850655],
+    [-9.319787475379883, 6.643410000811406, -1.0277712150436051],
+    [-8.517645917670624, 5.855756518989601, -0.8386807274299919],
+    [-7.757542814204384, 5.104944199438817, -0.6572570870833172],
+    [-7.020731919897505, 4.37383220330872, -0.4797378757573311],
+    [-6.288923709917445, 3.64564176968808, -0.3024289114932654],
+    [-39.139707723514015, 34.807539160334265, -7.451961637010368],
+    [-29.52827123882731, 25.957097649174127, -5.48042391038349],
+    [-23.97584201074067, 20.8272907443617, -4.334883235541687],
+    [-20.2557140710463, 17.375667039076237, -3.5607713785657706],
+    [-17.540951228757883, 14.843934061244616, -2.9896887896573716],
+    [-15.444803090983866, 12.87756546169363, -2.543050519458393],
+    [-13.758706198002066, 11.285443656803503, -2.1785640804742346],
+    [-12.358496935093534, 9.953830673467012, -1.8710986144761868],
+    [-11.164520552743799, 8.80978239557446, -1.6045589351500325],
+    [-10.122459204957003, 7.803567661322469, -1.367984970861871],
+    [-9.19321224181976, 6.899389152604495, -1.1534950792764733],
+    [-8.347119200065547, 6.070085713248864, -0.9551127390098844],
+    [-7.5604099741966735, 5.293875194673717, -0.7680454042751097],
+    [-6.8128420833863705, 4.552182995612419, -0.5882034097451805],
+    [-6.085967140426347, 3.828043001282819, -0.4118447306691778],
+    [-5.361695666453324, 3.104764587151843, -0.2352770593322052],
+    [-37.17517392528538, 33.44716812489673, -7.228827344529863],
+    [-27.969497085425438, 24.920699032979158, -5.319915134468234],
+    [-22.621747484495312, 19.95113269581234, -4.204565848023509],
+    [-19.02731823034621, 16.596745734829405, -3.448476081778219],
+    [-16.398467018426494, 14.130979582516842, -2.889490569678484],
+    [-14.36506021787783, 12.21256690426546, -2.4515872361741504],
+    [-12.726852796160824, 10.656947457946671, -2.093722006998243],
+    [-11.364332369559616, 9.354010369571528, -1.7914452080697287],
+    [-10.200655638315224, 8.232980970907066, -1.529063700831031],
+    [-9.183301749300389, 7.245498758124878, -1.2958677464254948],
+    [-8.274365749922493, 6.356664871343518, -1.0841367590997295],
+    [-7.4450015896495385, 5.539916842781458, -0.8879985445883071],
+    [-6.671998922040194, 4.77386927788566, -0.7027263510482143],
+    [-5.935498922009582, 4.0402017585670835, -0.5242684940621295],
+    [-5.21731071011598, 3.322096979332437, -0.348899658997383],
+    [-4.499516933266449, 2.6029376657705687, -0.17292811688368953],
+    [-35.374735393937, 32.20142509552242, -7.0242821412869345],
+    [-26.533962137100897, 23.967840675523995, -5.172322277102783],
+    [-21.370682901084425, 19.143456768188454, -4.084494448284703],
+    [-17.889534117365958, 15.877172298995768, -3.344836457103004],
+    [-15.33804753125681, 13.471139162232644, -2.796875720545647],
+    [-13.361083506364777, 11.596113798715365, -2.3669275885284664],
+    [-11.765910875798077, 10.07348706213942, -2.015087263969752],
+    [-10.43721844530415, 8.796438902600613, -1.7175264158213908],
+    [-9.300699613303275, 7.696160344273222, -1.4589180708418026],
+    [-8.305451405493756, 6.72552966701958, -1.2287798409544983],
+    [-7.414640214101706, 5.850455780156637, -1.0195365700700716],
+    [-6.60014474424931, 5.04491025382408, -0.8254094502097648],
+    [-5.839249279880126, 4.2878582316318665, -0.6417298812733122],
+    [-5.112425964599332, 3.5611974954610974, -0.46447853177551257],
+    [-4.401701900528596, 2.8482344360654466, -0.2899433365053354],
+    [-3.6893006363955045, 2.1324082059876726, -0.11443158910981202],
+    [-33.71264208031134, 31.052693021826027, -6.835499698108868],
+    [-25.20191520747408, 23.08542018929928, -5.035640723471337],
+    [-20.205667348715917, 18.39322243430976, -3.97303497228328],
+    [-16.826979621276045, 15.207094455938876, -3.2484319181908523],
+    [-14.34534558149724, 12.85533329778042, -2.7105635450033305],
+    [-12.419242562131588, 11.01966695996827, -2.287890036764628],
+    [-10.862767676758686, 9.526918947247935, -1.9415523271809645],
+    [-9.564425434599913, 8.273271070165366, -1.6482919215547427],
+    [-8.45220888237634, 7.19170378646926, -1.3931169508546615],
+    [-7.476676455189797, 6.236218791878339, -1.1657522876431576],
+    [-6.6019563484078, 5.373452797281059, -0.9587544750450628],
+    [-5.800571500291149, 4.57785340134344, -0.7664283205653856],
+    [-5.050237200680655, 3.8286915164503283, -0.5841560859517488],
+    [-4.331707159405049, 3.1080495741747645, -0.4079456373015369],
+    [-3.6271838411208135, 2.399330686830835, -0.23409450589980255],
+    [-2.918993953674853, 1.686009177979016, -0.05890684899227032],
+    [-32.16725817689004, 29.986184550675567, -6.660115760816777],
+    [-23.9564653658719, 22.262243079555304, -4.9081644008953225],
+    [-19.111973012731273, 17.690862171376505, -3.868777343717909],
+    [-15.826150067150223, 14.577868956118325, -3.15802180748632],
+    [-13.407627635210234, 12.275508947896055, -2.629423476332746],
+    [-11.527320980576503, 10.475577169522603, -2.2134213067385478],
+    [-10.005565726566985, 9.009882124349884, -1.8721209913898142],
+    [-8.734348255596545, 7.777356421188874, -1.5827890466093186],
+    [-7.643753887830109, 6.712615373729299, -1.3307414564641555],
+    [-6.685663476664065, 5.770681486844307, -1.1058925144434513],
+    [-5.825067499582551, 4.918846670006656, -0.9009180528736663],
+    [-5.035057251745319, 4.131980084336483, -0.7101974037775116],
+    [-4.293717721117375, 3.3896154647512198, -0.5291567835213533],
+    [-3.582031659541139, 2.673984510525358, -0.35382591845807126],
+    [-2.882329135861973, 1.9685568101151243, -0.1805078711526586],
+    [-2.1769923219646885, 1.2568139671660394, -0.005500553050784447],
+    [-30.720067366530532, 28.98926391329973, -6.496118262387197],
+    [-22.782841217667617, 21.488534336616656, -4.7884104859896865],
+    [-18.076488444008223, 17.02787063136453, -3.7704743681778248],
+    [-14.874833705761759, 13.981690456933965, -3.0724917578772613],
+    [-12.513217809458192, 11.724291006798332, -2.552425916385054],
+    [-10.673976355417205, 9.956750271526028, -2.1425501445235966],
+    [-9.183171668854666, 8.515471687254273, -1.8058641381494411],
+    [-7.935979673088718, 7.301918033653245, -1.5201199592562962],
+    [-6.864393698762384, 6.252201799470434, -1.2709171680579618],
+    [-5.92149118884919, 5.322273344741051, -1.0483433529603097],
+    [-5.073030777573884, 4.480011229940607, -0.8451819905631183],
+    [-4.29259846738225, 3.700653544857546, -0.6558782430922787],
+    [-3.558585441673574, 2.963952625563905, -0.4758952745909851],
+    [-2.8521468803103445, 2.252251571928139, -0.3012790349116674],
+    [-2.155684036293221, 1.5490505280048312, -0.12833327194162344],
+    [-1.4515744865984848, 0.8378022044755805, 0.04665466761734864],
+    [-29.354880140921505, 28.05090809509972, -6.34176058892851],
+    [-21.66777318637824, 20.755532406723372, -4.675057359881288],
+    [-17.08716466534965, 16.396448424043214, -3.676989306264384],
+    [-13.961583418199917, 13.411257195542069, -2.9908059424837266],
+    [-11.650978583056238, 11.194657847381933, -2.4785970494406655],
+    [-9.84822218534229, 9.456326873431578, -2.0743436702163813],
+    [-8.384655651068389, 8.036919843271573, -1.7418770917314639],
+    [-7.158385474033413, 6.840233450336129, -1.4593997271392949],
+    [-6.103146296483853, 5.803752482682117, -1.212772687136685],
+    [-5.173094066451181, 4.884267914247482, -0.9922417685588573],
+    [-4.334665857426603, 4.05017975349366, -0.7906870871821474],
+    [-3.561866707624366, 3.2770411748904116, -0.6026108219858252],
+    [-2.8333271798752637, 2.5447768497496024, -0.423505821600588],
+    [-2.130310237511394, 1.8357980199428703, -0.24942786779731765],
+    [-1.4352243569712808, 1.1335944641426074, -0.07667581868708173],
+    [-0.7303732340600366, 0.42154736139163, 0.09847888492600462],
+    [-28.057172024010068, 27.161259037793236, -6.19549018726129],
+    [-20.59894324377847, 20.055129262559113, -4.566890273541582],
+    [-16.13249220460176, 15.78916742530612, -3.5872472912565723],
+    [-13.075193508269766, 12.859441452637606, -2.9119606958801634],
+    [-10.80977751396515, 10.679609671391946, -2.406973343874735],
+    [-9.03888193764971, 8.967346393957998, -2.007862281180833],
+    [-7.598733452615482, 7.56725570836964, -1.6792348015419716],
+    [-6.390136993206311, 6.385291324763791, -1.3997118149878591],
+    [-5.348414758283381, 5.360194739746905, -1.1553955626956578],
+    [-4.428688549998712, 4.4495142057863495, -0.9366756580775005],
+    [-3.5979837028780497, 3.6221056947747012, -0.7365178426419979],
+    [-2.830646476681107, 2.8537821397064134, -0.5494722916217043],
+    [-2.1054757116874097, 2.1245912347567217, -0.37105380043500985],
+    [-1.4037747906136082, 1.416967060287578, -0.19732122602157703],
+    [-0.7078943275021315, 0.7143468393263995, -0.024562650186186537],
+    [1.3857750892266076e-12, -9.28230277607988e-13, 0.15097160501713666],
+    [-73.07263113626745, 58.81416840147195, -11.181334552317592],
+    [-56.09158704913003, 44.189512882068385, -8.128750079191105],
+    [-47.037595870741576, 36.35272118159303, -6.4841982273210315],
+    [-41.237240669742505, 31.298910155687818, -5.415111267313133],
+    [-37.13496911648863, 27.694919153668646, -4.6447168987431615],
+    [-34.046851322571214, 24.954618133777828, -4.051475265359878],
+    [-31.61853665643849, 22.774302563884838, -3.57246246353064],
+    [-29.644822931518515, 20.978028830462343, -3.1712352476459826],
+    [-27.996527926520002, 19.454929032889492, -2.824817221026124],
+    [-26.586487809970606, 18.13006179921159, -2.517650755716954],
+    [-25.35195523645504, 16.94931397477144, -2.2384641738303475],
+    [-24.244535978124603, 15.870753820299461, -1.9784749161351838],
+    [-23.223716179222716, 14.859045329996695, -1.7302234053816419],
+    [-22.251912502628453, 13.881138607990264, -1.4866641964561413],
+    [-21.28959864898537, 12.901969353595122, -1.2402450866703354],
+    [-20.288878641379263, 11.87870878041504, -0.9816566037874028],
+    [-66.85455528904137, 54.49150391356802, -10.475655280921636],
+    [-51.41927750688939, 41.052408257979, -7.642494321681763],
+    [-43.08370819892914, 33.758290582333984, -6.096522314708801],
+    [-37.70541085628112, 29.02096762592375, -5.084438609153283],
+    [-33.88224108167525, 25.625815283083465, -4.351604654699906],
+    [-30.991834524766574, 23.03373071347856, -3.7851388219178466],
+    [-28.70963604600106, 20.963562816874965, -3.3262231743650146],
+    [-26.846722123752922, 19.251596209498793, -2.9406201006935815],
+    [-25.28358243326641, 17.794177374411426, -2.6066438632697024],
+    [-23.939148169014985, 16.52087643567012, -2.309537176902857],
+    [-22.75466176278345, 15.38050141445079, -2.0385406305313265],
+    [-21.684377078141537, 14.333020324340383, -1.7851974237186647],
+    [-20.689489478268207, 13.344264893769507, -1.542236510185219],
+    [-19.733375261883317, 12.381738717702174, -1.3026795567168548],
+    [-18.776743515446263, 11.41029107270901, -1.0589053734690907],
+    [-17.771050625243145, 10.38616161299502, -0.8013428132134801],
+    [-61.6807300190728, 50.89798533072259, -9.889434367720433],
+    [-47.48686511122757, 38.4160733740076, -7.234402650315948],
+    [-39.736682624647415, 31.5663356575688, -5.769563100307845],
+    [-34.70500293312739, 27.090142031958546, -4.80472728257961],
+    [-31.112335385733495, 23.86820848188781, -4.1031720212108675],
+    [-28.38599351641601, 21.399590805526774, -3.559082820442638],
+    [-26.225606805659243, 19.421538777796336, -3.1170100099732205],
+    [-24.455520749127334, 17.7803506700147, -2.7445308646294957],
+    [-22.96413296496712, 16.37829421888678, -2.421022985713706],
+    [-21.675367511365287, 15.14864421378602, -2.1323875903102074],
+    [-20.53373556093037, 14.042601225886004, -1.8682841291367092],
+    [-19.49565336043215, 13.021674977144407, -1.6205175257877096],
+    [-18.523695885023454, 12.052619276477152, -1.381960338420313],
+    [-17.581983392149475, 11.103327346272232, -1.1456701855418],
+    [-16.631342939334008, 10.138469612718248, -0.9039378623072718],
+    [-15.622582865300185, 9.113350934810116, -0.6469275386679997],
+    [-57.298593807089276, 47.857780759420166, -9.39372356138939],
+    [-44.12369974737721, 36.16553148273642, -6.88644354391689],
+    [-36.859796347655525, 29.68667057758937, -5.48965257930633],
+    [-32.11793338189513, 25.42976492089058, -4.564670944733744],
+    [-28.71889981054301, 22.35392508833996, -3.8895983488668286],
+    [-26.130938122388784, 19.989812149364827, -3.36450780674258],
+    [-24.07370041177789, 18.089984364389892, -2.9367668594567267],
+    [-22.382554901245204, 16.509094555635347, -2.5754741226766673],
+    [-20.95248362468531, 15.154358097952217, -2.2608990046332136],
+    [-19.711572635330214, 13.962144473845429, -1.9794938578727446],
+    [-18.607056094756754, 12.885646016576212, -1.7212662170859017],
+    [-17.597144012990185, 11.887642614493878, -1.478234357074747],
+    [-16.64554108508745, 10.935624969950315, -1.2433809262321214],
+    [-15.71695036728107, 9.997755627099933, -1.0097771648344713],
+    [-14.772236961461397, 9.03846698222994, -0.7696143900903722],
+    [-13.761588961971075, 8.012156208409392, -0.512797095152428],
+    [-53.529677784773845, 45.246745396070416, -8.968136614942058],
+    [-41.20659365432567, 34.21782852970758, -6.585639794543448],
+    [-34.35328080412969, 28.05351089313946, -5.24684523567203],
+    [-29.857458273716396, 23.98353237436506, -4.3559864948343225],
+    [-26.62345824817163, 21.032672034553066, -3.7036560262474887],
+    [-24.15384345602054, 18.75822670348899, -3.194917088547012],
+    [-22.185123545326533, 16.925702567954282, -2.7795326033710164],
+    [-20.561972128180305, 15.396833991237806, -2.4278967341761772],
+    [-19.184939117322784, 14.083033726450067, -2.1210382593891777],
+    [-17.985631762775856, 12.923294242265737, -1.8458775587007425],
+    [-16.913584788933253, 11.872488943548385, -1.5927154324083863],
+    [-15.928515942181345, 10.894457373332349, -1.353746170777672],
+    [-14.995065935503595, 9.957284461645234, -1.1220369920636282],
+    [-14.078397624567966, 9.02931209478844, -0.8906565402169299],
+    [-13.139359875993868, 8.07469814343636, -0.651690350996336],
+    [-12.127565586978283, 7.04697856456645, -0.3947935310944327],
+    [-50.244016515956496, 42.974554015912545, -8.597880053692258],
+    [-38.644155244103324, 32.51146018085936, -6.322394672086719],
+    [-32.142391201488316, 26.617596418008198, -5.033712955951309],
+    [-27.858182795831507, 22.709015416692395, -4.1724481017871025],
+    [-24.766591219857222, 19.866382693067425, -3.539888503756229],
+    [-22.399396819341277, 17.6697791491226, -3.0453901868625888],
+    [-20.50750864457976, 15.895818746057348, -2.6407817448339332],
+    [-18.943565537048066, 14.412320454021462, -2.297575658264015],
+    [-17.612888598609295, 13.134304517914428, -1.9974557716892194],
+    [-16.450102005714832, 12.003014230019636, -1.7277448795391863],
+    [-15.406707302004122, 10.97475826948458, -1.478993735394054],
+    [-14.443703185365315, 10.014269112665273, -1.243543225974398],
+    [-13.526512587972991, 9.090113401565858, -1.014525278330451],
+    [-12.620661539401732, 8.170743303910527, -0.7849939418164369],
+    [-11.686944717464645, 7.220020315593881, -0.5469260723098549],
+    [-10.67445194702365, 6.190675464831031, -0.2897407440506906],
+    [-47.34447234656556, 40.973764683096626, -8.27193869420791],
+    [-36.36690736564002, 30.999707551575227, -6.089438652640917],
+    [-30.169768841686274, 25.341151784878903, -4.844575402435994],
+    [-26.06960642189286, 21.57346972126857, -4.009264587733196],
+    [-23.102202314540285, 18.825543298023273, -3.3940772050377657],
+    [-20.8245401150481, 16.69719560579678, -2.9121096248098284],
+    [-18.999984114659775, 14.974687585359167, -2.5169937035746477],
+    [-17.48807361268377, 13.531127959756244, -2.1812186307362187],
+    [-16.198265126779834, 12.28467224931302, -1.8870396971820815],
+    [-15.067795462394892, 11.178515458768974, -1.6221293634014453],
+    [-14.04986397844844, 10.17020319556462, -1.3772534501504314],
+    [-13.106566359190499, 9.225225820993833, -1.1448757541026253],
+    [-12.203982083403572, 8.312540420222145, -0.9181770473041625],
+    [-11.307921138447279, 7.400654244995741, -0.6901876196958742],
+    [-10.379094587598027, 6.453118700035729, -0.45277479187846814],
+    [-9.366118826115827, 5.421917440047321, -0.19513613961148105],
+    [-44.75671583178763, 39.192835396460396, -7.981919246504287],
+    [-34.32080907959937, 29.646272066585265, -5.881140919561973],
+    [-28.390364305332877, 24.194539453525937, -4.674989960724296],
+    [-24.45179275891351, 20.551026866619626, -3.8626630379120437],
+    [-21.593646522315233, 17.886714294764822, -3.26288269005212],
+    [-19.39490237118875, 15.818725016387248, -2.7920408677255253],
+    [-17.629817351950734, 14.141788505286508, -2.4053604659392334],
+    [-16.16397143319911, 12.733659149360532, -2.0761924047679807],
+    [-14.910440556805572, 11.515243322716602, -1.787295267998031],
+    [-13.8087440264424, 10.431442948175567, -1.5266480210546847],
+    [-12.813557579059816, 9.440877149641548, -1.2852028864044376],
+    [-11.887920669135108, 8.509681907418729, -1.0555270244856083],
+    [-10.998462759458032, 7.607127959431801, -0.830836941115592],
+    [-10.111208629278845, 6.701730618593946, -0.6041317512412714],
+    [-9.186757715147566, 5.756719887369689, -0.3671692905587307],
+    [-8.17329069119962, 4.723382841072342, -0.10893968462091924],
+    [-42.422585003623716, 37.591501476956246, -7.721286630061137],
+    [-32.46285607040894, 28.422314448412962, -5.693043542675521],
+    [-26.767938187598013, 23.15395552398099, -4.521401612511938],
+    [-22.972352958213182, 19.620740066317786, -3.7295999955329924],
+    [-20.21101084066017, 17.030791614379552, -3.143593785170897],
+    [-18.082277670644366, 15.016544485418624, -2.682707146534702],
+    [-16.370029119704355, 13.380231994503301, -2.3035798489609633],
+    [-14.9451808926179, 12.00372425390893, -1.9803297694673436],
+    [-13.724002526915756, 10.810360314074705, -1.6961623320522086],
+    [-12.648020985475847, 9.746544822774482, -1.4393270383352514],
+    [-11.673201646311059, 8.771832571541626, -1.2009384241836314],
+    [-10.763395863954301, 7.852908779658331, -0.9736503863843011],
+    [-9.885688149693566, 6.959289971944658, -0.7507038075544806],
+    [-9.006252237510287, 6.059455606531317, -0.5250600353613529],
+    [-8.085542058900566, 5.116299775161265, -0.2883673347583642],
+    [-7.071318111988712, 4.080448850074449, -0.02942037838008286],
+    [-40.295533307517594, 36.13761232653285, -7.484842946561978],
+    [-30.75798217420752, 27.3043731858129, -5.521535190914399],
+    [-25.272551018508445, 22.19978019021764, -4.380893331693601],
+    [-21.604250747011918, 18.76516523369861, -3.6075527571592594],
+    [-18.92911215720365, 16.241729481334048, -3.033944253047725],
+    [-16.86274978980531, 14.275576331604016, -2.5820233307495863],
+    [-15.197604587334146, 12.675642214869505, -2.209701716841792],
+    [-13.809341201721947, 11.327470303712674, -1.8917849574570045],
+    [-12.617065063729614, 10.15656433724417, -1.6118777618785722],
+    [-11.564076851405195, 9.110657636850165, -1.3584695716228705],
+    [-10.607469330518171, 8.150120956880714, -1.1228164352256118],
+    [-9.711787273573561, 7.2421024724967085, -0.8976442702895067],
+    [-8.844479975940468, 6.356300480391618, -0.6762078512962273],
+    [-7.971800584185196, 5.461112636876822, -0.45142410018149687],
+    [-7.054009272368746, 4.519073183148384, -0.2148304143042725],
+    [-6.038427254578993, 3.4801594563066915, 0.04496566182981203],
+    [-38.33739528637401, 34.80488978778537, -7.2683597952694745],
+    [-29.176780607079785, 26.272838372805563, -5.363613196540218],
+    [-23.878669347245367, 21.315337454973616, -4.25099962635276],
+    [-20.32411074921014, 17.969271583623883, -3.4943603222074278],
+    [-17.725925572984803, 15.50554190332909, -2.9319706265033054],
+    [-15.715196087625836, 13.582548369683998, -2.488165095648604],
+    [-14.092045808709482, 12.01525675115616, -2.1220051825259927],
+    [-12.736392053942573, 10.692507620087758, -1.808916738711694],
+    [-11.569869546432706, 9.541739191826906, -1.5328629380757277],
+    [-10.537348012853014, 8.511861126266304, -1.282546481970077],
+    [-9.59690101050577, 7.56395221094675, -1.0493463278566602],
+    [-8.713654060721021, 6.665541953115319, -0.8260466898644351],
+    [-7.855328186812844, 5.786444935147677, -0.6059057340235302],
+    [-6.988174188384326, 4.89492301775928, -0.3817882489348329],
+    [-6.0721845814268525, 3.9531093167717564, -0.14511698934752976],
+    [-5.054170347741739, 2.910307555160452, 0.11568489183328019],
+    [-36.51598282523557, 33.57126938827873, -7.068307944512432],
+    [-27.693726654347337, 25.31076770404537, -5.216700540668744],
+    [-22.563631315380142, 20.485896297723965, -4.129557907371119],
+    [-19.11080208867571, 17.219535429275947, -3.3880926000708693],
+    [-16.581229762248867, 14.809447892736628, -2.8358920054117656],
+    [-14.619964786271598, 12.925168850669424, -2.3994555281592542],
+    [-13.034063395166797, 11.387118531876952, -2.0388898143019785],
+    [-11.707268414785325, 10.087110502336106, -1.7301828078898485],
+    [-10.563474525941803, 8.954314914601223, -1.457620304426729],
+    [-9.548934906726826, 7.938679773627042, -1.2100942404379846],
+    [-8.622564343044429, 7.001889589108406, -0.9790890442829641],
+    [-7.749953878469607, 6.111772331740489, -0.757433526702318],
+    [-6.898991124164444, 5.238185438949141, -0.5383776951166327],
+    [-6.03581890542742, 4.349184930973553, -0.314724203122108],
+    [-5.12004616378683, 3.4064330849101068, -0.07777322616577428],
+    [-4.097825207507068, 2.3584819239678936, 0.1842418957151891],
+    [-34.80318125783936, 32.417596194190274, -6.881646986601808],
+    [-26.28566890932712, 24.402890130331553, -5.078493877633848],
+    [-21.30626823103746, 19.69778183349339, -4.014578209502616],
+    [-17.944102298214894, 16.50309364832428, -3.286930197772226],
+    [-15.47527479456526, 14.14103903156134, -2.7439949429695583],
+    [-13.557528127226288, 12.291294380321817, -2.3142526747135865],
+    [-12.004205575270987, 10.779236903654594, -1.9587645290213662],
+    [-10.702500301202553, 9.499367271239787, -1.6540292188943535],
+    [-9.578322646984033, 8.382403760560765, -1.3846227443090326],
+    [-8.579132472226462, 7.3792017229606, -1.1396036712925655],
+    [-7.664543382266172, 6.451947744733683, -0.9105444927730837],
+    [-6.80048339079184, 5.568676687228342, -0.6903037576908468],
+    [-5.954876458808723, 4.69919857724151, -0.4721093408569772],
+    [-5.093610780573959, 3.811265943502227, -0.2486877270905971],
+    [-4.175729174126976, 2.865955718445071, -0.01120201670536059],
+    [-3.1464607458197102, 1.8109112124366546, 0.25232150298334577],
+    [-33.173294808266284, 31.32650478604611, -6.705647569900424],
+    [-24.930385287902727, 23.53466432287984, -4.946822567396189],
+    [-20.085478288229485, 18.93746706893273, -3.904112628293979],
+    [-16.803225162915744, 15.806822053071086, -3.1890360457470464],
+    [-14.387240777258466, 13.487327197742996, -2.6545041982950917],
+    [-12.50686515407333, 11.667941466821334, -2.2308183116468308],
+    [-10.981165586132372, 10.178562318999807, -1.8799140469848676],
+    [-9.700447611748336, 8.91611897046993, -1.5787544323158162],
+    [-8.59240541475135, 7.812703541080272, -1.3121750924423001],
+    [-7.605525297621106, 6.819945998851249, -1.0693785962280002],
+    [-6.699961173749386, 5.900420832792098, -0.8420066629529227],
+    [-5.8418207258565, 5.022258983721155, -0.622929928605036],
+    [-4.9988898014194465, 4.155103061860953, -0.405335777716144],
+    [-4.136589277263073, 3.2662602003569905, -0.18185398980959172],
+    [-3.21311238192326, 2.3160401253114427, 0.056513745834773504],
+    [-2.172335742810874, 1.2509095657275688, 0.3219818763170171],
+    [-31.601413062136096, 30.281331562089523, -6.537722533716905],
+    [-23.604970025750074, 22.691242719572283, -4.819496926779011],
+    [-18.87848092591533, 18.190475937703614, -3.796100777557255],
+    [-15.664899925825702, 15.116146957171035, -3.0923928666283707],
+    [-13.293138717776863, 12.833461527939498, -2.5654114817921916],
+    [-11.443197124025968, 11.039916243409541, -2.147138794083673],
+    [-9.939369750168892, 9.569539274181661, -1.800313060597113],
+    [-8.67475853324212, 8.321445377483462, -1.502317868849087],
+    [-7.578606133998844, 7.228924082729735, -1.2382177275297537],
+    [-6.6002241902111995, 6.244233296168621, -0.997334575026945],
+    [-5.700112978688858, 5.330197412899642, -0.7713569910191117],
+    [-4.844351785229637, 4.454898422381793, -0.5531449293120916],
+    [-4.000344272203899, 3.5876446161363424, -0.3358199028288795],
+    [-3.132736867386865, 2.6950924928714177, -0.11188502491473899],
+    [-2.198461438794488, 1.7365168666845436, 0.12785650390588452],
+    [-1.139440755025671, 0.6568227385017761, 0.39591154106808657],
+    [-30.061523918764596, 29.264881553198872, -6.375240203306211],
+    [-22.283694961778558, 21.856117273168337, -4.69411386485571],
+    [-17.65829682123653, 17.439817480845527, -3.6881528456994106],
+    [-14.500461781839281, 14.41326259280383, -2.9945628616204005],
+    [-12.162551410373004, 12.160754708249893, -2.4742154104087972],
+    [-10.334435725942992, 10.38768495608729, -2.060650013226525],
+    [-8.845191482389577, 8.93185608987758, -1.717340613098281],
+    [-7.590402429077842, 7.694324809916934, -1.4220472889256124],
+    [-6.500596920108159, 6.6093820748170025, -1.1600295501951212],
+    [-5.525664425274761, 5.629735126241813, -0.9206991142409687],
+    [-4.626196295183798, 4.718278412228801, -0.6957626018161012],
+    [-3.7679604156220603, 3.8428487664477684, -0.4780385900055566],
+    [-2.9176474128267564, 2.972193750123562, -0.2605480337529348],
+    [-2.038730715748041, 2.072050814682521, -0.035627367907517665],
+    [-1.0863943301764378, 1.1003383537464875, 0.20616500174173],
+    [-4.534231654925267e-14, 2.8690186422010046e-14, 0.47768538031065866],
+    [-85.23604976573952, 68.36341226572557, -12.657011018803578],
+    [-65.61041397650561, 51.52650162740546, -9.179159764244606],
+    [-55.488406917392226, 42.77629364568337, -7.353677783983037],
+    [-49.14290640970829, 37.23453644181503, -6.181491015396473],
+    [-44.73937009963092, 33.337837101591106, -5.342539059187374],
+    [-41.487337911839646, 30.41218708055289, -4.698841596826808],
+    [-38.982403307031866, 28.112351240407662, -4.17965234794486],
+    [-36.992133301812096, 26.2394412972976, -3.7441020304863755],
+    [-35.370415668265665, 24.667900886723924, -3.366247887551166],
+    [-34.01778048959906, 23.311642539262223, -3.0281325636249194],
+    [-32.86053584094776, 22.106181780115495, -2.716107002693701],
+    [-31.83799801930037, 20.997610005415087, -2.4185359897921948],
+    [-30.89251635908044, 19.93383596478749, -2.1239364283465796],
+    [-29.958152391311508, 18.854435117162595, -1.8187632196047911],
+    [-28.941483289780276, 17.67317294368313, -1.4835278576170785],
+    [-27.676758428655354, 16.23683745935954, -1.0835676240246357],
+    [-77.4999916781199, 62.933329880757476, -11.766915373256756],
+    [-59.88557183722027, 47.64734442009414, -8.576050572727393],
+    [-50.65333138109798, 39.57473294610303, -6.8743147523368835],
+    [-44.81241027416551, 34.416005770737456, -5.77202137429621],
+    [-40.73089761943563, 30.764706989346834, -4.978272479253775],
+    [-37.69755151790774, 28.007547423773424, -4.366236315216249],
+    [-35.34553734089718, 25.82787692640659, -3.8703730350479435],
+    [-33.46253929061337, 24.04203524986533, -3.4525755141788737],
+    [-31.914111516724937, 22.533271114743393, -3.088498664382653],
+    [-30.60788457408587, 21.220848492998705, -2.761161647999657],
+    [-29.47453952776188, 20.043570287830782, -2.4575219571772604],
+    [-28.45592514913128, 18.949400634666908, -2.1662940749556325],
+    [-27.49542010174656, 17.88690909501864, -1.876113312907089],
+    [-26.52645968834396, 16.794881662858725, -1.5732513789591283],
+    [-25.452352202671822, 15.583782386484536, -1.23747081314065],
+    [-24.098052428712545, 14.091087406968493, -0.8319402412479809],
+    [-71.22306961687498, 58.54137264871207, -11.048303598783962],
+    [-55.16949878267336, 44.464056480304805, -8.082311450321397],
+    [-46.641798990663084, 36.92998352692239, -6.479411631098291],
+    [-41.205092382699725, 32.07910323205518, -5.433529557815623],
+    [-37.38398320807723, 28.626772776871075, -4.676545020902731],
+    [-34.52915389470055, 26.007372064543116, -4.0904289372856875],
+    [-32.30338279380305, 23.926862976614686, -3.6137804049766227],
+    [-30.510318573793604, 22.213701986905285, -3.2106981173612854],
+    [-29.024831923790387, 20.758175608044926, -2.8581154739933585],
+    [-27.76025753510385, 19.483835136799538, -2.5398309530478165],
+    [-26.650806110993027, 18.33210782515983, -2.243278989363229],
+    [-25.64036747827501, 17.25241586777896, -1.9574338598371006],
+    [-24.67311131785778, 16.193746324925073, -1.6709843866325422],
+    [-23.6818270127913, 15.093993602445218, -1.3699723934232462],
+    [-22.56678082700034, 13.860372859655937, -1.0333830664217631],
+    [-21.1443364156013, 12.321421734234237, -0.6222333880695297],
+    [-66.01235456648034, 54.908728675028044, -10.454957269348032],
+    [-51.204704388371624, 41.79983585852699, -7.670070852085694],
+    [-43.24865297175211, 34.70413332120027, -6.147993968643208],
+    [-38.143097303171935, 30.106149622232465, -5.1486328519835824],
+    [-34.537025342338524, 26.818475661052428, -4.422157150852562],
+    [-31.83081283233288, 24.313891557885793, -3.8576673337519347],
+    [-29.711160528607348, 22.316683695595447, -3.397132382187768],
+    [-27.994682992486243, 20.66517106041449, -3.0064526815386103],
+    [-26.563852767742535, 19.255385585589504, -2.6636066118259927],
+    [-25.336676477962545, 18.01437975101467, -2.35302687720526],
+    [-24.2502597097437, 16.885709635597397, -2.0625292945762332],
+    [-23.250153068796184, 15.819942073042396, -1.781282163611868],
+    [-22.28112246419414, 14.766297251736031, -1.4979819202903273],
+    [-21.275282354538202, 13.661706392421518, -1.198411023002612],
+    [-20.130037465862376, 12.410208831879853, -0.860769429231925],
+    [-18.653861300165183, 10.831874440552582, -0.44398538339072746],
+    [-61.60251128143501, 51.84748976974011, -9.95581344852731],
+    [-47.81245520978596, 39.532173422398046, -7.320068584379536],
+    [-40.32963295730554, 32.800368393295095, -5.865377156198249],
+    [-35.50045328790755, 28.413929742943434, -4.905071540099166],
+    [-32.07508557759702, 25.264856057804803, -4.204342877673008],
+    [-29.49465562517201, 22.857521614579493, -3.65818787653247],
+    [-27.465587520927265, 20.931360889591428, -3.2113737210989686],
+    [-25.81523087772003, 19.33287245096903, -2.8313000435243643],
+    [-24.43237350346173, 17.96286156628539, -2.4968144676566846],
+    [-23.238911697397583, 16.751286026692295, -2.192874307189825],
+    [-22.174358774788935, 15.643452333661653, -1.9076045837804259],
+    [-21.185629320028113, 14.590831559121536, -1.6303184843513487],
+    [-20.217929596210343, 13.542737090532182, -1.3496871069351677],
+    [-19.202674616488775, 12.435090513583853, -1.0512154924881985],
+    [-18.034570759038132, 11.168848580564058, -0.7123242686787357],
+    [-16.51491482103569, 9.556158441430664, -0.28994759877594367],
+    [-57.80715145135721, 49.22594790722712, -9.529164820790696],
+    [-44.86415145839876, 37.57310877747129, -7.01851767964544],
+    [-37.77971544368119, 31.148353175602963, -5.620924986000058],
+    [-33.1848181580785, 26.941569373495845, -4.693904181468358],
+    [-29.91355514007444, 23.910847622812724, -4.015216680131425],
+    [-27.441080722047854, 21.587018753287094, -3.484821010212931],
+    [-25.490361757804166, 19.72224936790892, -3.0498463392001285],
+    [-23.8977812024828, 18.169929097408374, -2.6789594385764626],
+    [-22.557453467187365, 16.834887498043045, -2.351741676495161],
+    [-21.394558326712687, 15.649525567354646, -2.0535902060211857],
+    [-20.350635407097275, 14.560605043962315, -1.7728834936354383],
+    [-19.37372780900877, 13.520313172107624, -1.4990420728808342],
+    [-18.409356250711284, 12.47794831061598, -1.2206875251584044],
+    [-17.388216929301958, 11.36839300903702, -0.9230367787265217],
+    [-16.202469005064607, 10.089626403911751, -0.5827456136944694],
+    [-14.646934237363444, 8.446451806084907, -0.1548670801393134],
+    [-54.49075872042154, 46.94860045182868, -9.159324372757089],
+    [-42.26420811943589, 35.85744155558128, -6.755234371548422],
+    [-35.51993588262233, 29.695350671715435, -5.406690981133098],
+    [-31.126262847895514, 25.643098838260343, -4.508402825355734],
+    [-27.988059657708593, 22.71467152392728, -3.8488171895610557],
+    [-25.609340320961007, 20.46338690210347, -3.3321298591133988],
+    [-23.72715176131082, 18.65226468143784, -2.907491630552726],
+    [-22.185584056682686, 17.14057731176087, -2.54465437474099],
+    [-20.883302258881614, 15.836588919755949, -2.223826460896182],
+    [-19.748289819671854, 14.674777526217692, -1.9307778902871324],
+    [-18.723807480717305, 13.603124584911436, -1.654096501104695],
+    [-17.75883540613286, 12.5743824432396, -1.3832807141957657],
+    [-16.79910328537961, 11.537747251979946, -1.1068841969337528],
+    [-15.774569426673663, 10.42704152949324, -0.809829374427018],
+    [-14.574978802786351, 9.137371443482923, -0.46802591870191873],
+    [-12.989328594838884, 7.46676364516204, -0.03476470175638214],
+    [-51.551599541422185, 44.94400646395393, -8.834604637832888],
+    [-39.939292919573845, 34.33533744737138, -6.522468290526378],
+    [-33.48895844305149, 28.40055573320552, -5.216553817318382],
+    [-29.270014904935994, 24.482654349098897, -4.343342253706403],
+    [-26.24787914463439, 21.64354235060781, -3.700487473033626],
+    [-23.951372988119587, 19.45589776147924, -3.1958494245513],
+    [-22.129680264643643, 17.692103428780616, -2.7803297623454446],
+    [-20.63354461542351, 16.216510103460962, -2.4246198652893574],
+    [-19.36556037106292, 14.940342643237926, -2.1094692972610365],
+    [-18.256126460541253, 13.799859394985505, -1.8209667969581624],
+    [-17.249974045899936, 12.744068559268506, -1.5478738279693423],
+    [-16.29685886136588, 11.726161600542454, -1.279742739702838],
+    [-15.342624848719122, 10.695157019833491, -1.0050443678821515],
+    [-14.316465483327132, 9.583795555350363, -0.7084020245508753],
+    [-13.105813405323259, 8.284400099038743, -0.3649983324286089],
+    [-11.494394286113407, 6.588729810263893, 0.07352427950962376],
+    [-48.910818428051826, 43.15706212003137, -8.546036518998804],
+    [-37.83121526582043, 32.967460186752604, -6.314135319865532],
+    [-31.637380939621227, 27.231282299328182, -5.045638242821706],
+    [-27.571478246217364, 23.43119904811583, -4.194516745512766],
+    [-24.65137997942076, 20.670700924264725, -3.5664490933687643],
+    [-22.427487097671204, 18.5393189313091, -3.0724981218064054],
+    [-20.65956078462013, 16.817596516919128, -2.6650970493261896],
+    [-19.204145634994987, 15.374308474500653, -2.3157581042172146],
+    [-17.967257029783998, 14.123251443105215, -2.0057016172216375],
+    [-16.881387023128642, 13.002217426680234, -1.7212902681227615],
+    [-15.89252389465646, 11.961077693560132, -1.4514291915947348],
+    [-14.95105500831919, 10.953351840307747, -1.1857041535320167],
+    [-14.00284413263532, 9.927807949658702, -0.9124897410631553],
+    [-12.976273375425778, 8.816071863831901, -0.6161049267283736],
+    [-11.756513617924016, 7.507739819046654, -0.2710206663286094],
+    [-10.122420990140174, 5.788706441984252, 0.17267091864905187],
+    [-46.50512270012611, 41.54384477621201, -8.286518435650201],
+    [-35.89192626534594, 31.72157002964523, -6.1252851089049285],
+    [-29.92359477896914, 26.160211631462857, -4.889900444503552],
+    [-25.992521414674478, 22.464098574670246, -4.0583859259569035],
+    [-23.162545329711524, 19.773179627446048, -3.4434843395369863],
+    [-21.00303501264213, 17.691796961056397, -2.9590839779705167],
+    [-19.283055924202852, 16.007666740531075, -2.5589688359158753],
+    [-17.86424955318104, 14.593444723760289, -2.215373323590747],
+    [-16.655625920703017, 13.365171935592443, -1.909929333125423],
+    [-15.591493168206313, 12.261962018991769, -1.629235233076619],
+    [-14.618905831642996, 11.234403570994903, -1.3623135762948615],
+    [-13.688743950644685, 10.236238580716556, -1.098764629851391],
+    [-12.746784005340881, 9.215904305128296, -0.8268528188682189],
+    [-11.720513150019004, 8.103853214078201, -0.5305842300794165],
+    [-10.492808865555187, 6.786953770740072, -0.18372518711777544],
+    [-8.83783784608131, 5.045476011613201, 0.26511070041291923],
+    [-44.28152774591962, 40.067941882293134, -8.05021579968057],
+    [-34.07965616364251, 30.569925247995474, -5.951699077257234],
+    [-28.310417645951443, 25.163182319917087, -4.745799594826755],
+    [-24.498339069602387, 21.559098723199146, -3.931783724644056],
+    [-21.747951172741523, 18.92988207002001, -3.328667366270951],
+    [-19.645444827423304, 16.89299499326831, -2.852850193285578],
+    [-17.968130920924725, 15.242498923808647, -2.459315028951617],
+    [-16.582152169139892, 13.854468736815006, -2.1209344841777478],
+    [-15.399143275369562, 12.646905186398264, -1.8197007117325437],
+    [-14.35497849473549, 11.560052702334783, -1.542413689711988],
+    [-13.397594943426759, 10.545078141180156, -1.2781888577791536],
+    [-12.478216082219262, 9.555834053062679, -1.0166216743063814],
+    [-11.54239247560752, 8.540324797105994, -0.745849538476668],
+    [-10.51656723636798, 7.427724445162087, -0.449550121955354],
+    [-9.281158945808755, 6.102076706000457, -0.10077735060267311],
+    [-7.605491343797722, 4.33802226869307, 0.35330174751602417],
+    [-42.193131434959604, 38.69754473045152, -7.832093078719141],
+    [-32.35545809402542, 29.486998960375363, -5.789543989801839],
+    [-26.761854890884678, 24.217104996384652, -4.609994303178296],
+    [-23.0542631940826, 20.694309922318634, -3.8116343871614444],
+    [-20.373558270800856, 18.119584351398167, -3.2190901642664573],
+    [-18.320962909633824, 16.122086719868918, -2.75100660222659],
+    [-16.681134399655832, 14.501515513169803, -2.3634348486318215],
+    [-15.324197324093252, 13.13696002712714, -2.0298121374906555],
+    [-14.16407482541474, 11.94812201966437, -1.73244455050841],
+    [-13.13796514052397, 10.876193059514387, -1.4583013884864615],
+    [-12.19449221546802, 9.872772059420905, -1.1965658668067531],
+    [-11.285040588225405, 8.89168795740461, -0.93680626268589],
+    [-10.354733068728343, 7.880365869626147, -0.6670094559211764],
+    [-9.328702929175975, 6.766513444361807, -0.37049632520079107],
+    [-8.084515646477792, 5.4310900593246725, -0.01957535050749346],
+    [-6.385958398917397, 3.6427151516116196, 0.440062497971875],
+    [-40.19505303741433, 37.40272189757422, -7.627485700728356],
+    [-30.679426881395397, 28.4470399048234, -5.6350112220911805],
+    [-25.2392317753335, 23.2975264739346, -4.478996561033702],
+    [-21.621693049448645, 19.845686696015303, -3.6946052894975825],
+    [-19.00040546181671, 17.318302004321332, -3.111509062238293],
+    [-16.99011198423275, 15.355008140234554, -2.650368838897387],
+    [-15.382038410099476, 13.760521032104332, -2.268189274448445],
+    [-14.049820838957958, 12.416575794975557, -1.9389052065038905],
+    [-12.909343612025294, 11.244324138434738, -1.6450921150129554],
+    [-11.898868498456268, 10.185709700678855, -1.3738550571077526],
+    [-10.9674674724392, 9.192588522242193, -1.1144158960368067],
+    [-10.066429852066737, 8.218581128921157, -0.8562858677211735],
+    [-9.140125121165667, 7.210300860633069, -0.5872646941287939],
+    [-8.111893408068502, 6.093648241704764, -0.290264500714923],
+    [-6.855635022307015, 4.745947031778003, 0.06322940156309333],
+    [-5.127946170580694, 2.928734317682203, 0.5291338466066257],
+    [-38.239555044755505, 36.15223559760299, -7.431615171936906],
+    [-29.005509117624637, 27.42079993641546, -5.483844778278522],
+    [-23.695362298915676, 22.375024897421522, -4.348670122381896],
+    [-20.151538421323288, 18.983030366886794, -3.576565944543207],
+    [-17.577346450482434, 16.494910426668064, -3.001765718561215],
+    [-15.599786935605728, 14.559734932441705, -2.5467467149000296],
+    [-14.015974817328601, 12.986684368879795, -2.1693631226948056],
+    [-12.702607189009429, 11.65978346330318, -1.843982381572312],
+    [-11.577173198208165, 10.501362534714906, -1.5534018556117393],
+    [-10.57867114345638, 9.45387721908387, -1.2848221542140383],
+    [-9.65627730961638, 8.469194916953318, -1.0274641676885448],
+    [-8.760771011884822, 7.500435069366929, -0.7707360594633257],
+    [-7.835192000163154, 6.492994275105477, -0.5021875624756855],
+    [-6.800173864600592, 5.370328549208031, -0.2042248802099485],
+    [-5.524323593595392, 4.005016583968309, 0.1526512877266808],
+    [-3.7535449974665682, 2.1491970943230227, 0.626283311610142],
+    [-36.26873112603302, 34.90888497047389, -7.238898589735884],
+    [-27.272592446490616, 26.370004399492913, -5.3305578857795455],
+    [-22.063621730212795, 21.40853362578308, -4.213313730357944],
+    [-18.571264175192205, 18.062169007320698, -3.4515411191532097],
+    [-16.02624328749443, 15.602298741869362, -2.8836288949454736],
+    [-14.066852458207702, 13.686570335951814, -2.433697720628238],
+    [-12.495502694864541, 12.128120751801154, -2.0603518480717784],
+    [-11.191461984880766, 10.81287286568495, -1.7383193511925328],
+    [-10.07334766863299, 9.663979315376913, -1.4505583520063976],
+    [-9.08038536708727, 8.62404424490417, -1.1843060854696548],
+    [-8.161262834485004, 7.644533680347333, -0.9287179565175485],
+    [-7.265485068096586, 6.677538204396943, -0.6730179000233201],
+    [-6.333655755852884, 5.666473494869177, -0.40438870253680664],
+    [-5.281908265316097, 4.531115783323496, -0.10454338428731619],
+    [-3.9703206056123306, 3.137107148907328, 0.2573237408284818],
+    [-2.127240554721401, 1.22261512445271, 0.7416308464126449],
+    [-34.200932823891044, 33.62099613948188, -7.041708606607566],
+    [-25.38559439585538, 25.2357210551141, -5.166801600082429],
+    [-20.233123306734626, 20.330287715721305, -4.063610253554888],
+    [-16.754535357669077, 17.006774481505822, -3.3092950983550513],
+    [-14.206928815848531, 14.556614102949835, -2.746098436103409],
+    [-12.239388243007458, 12.645416831469266, -2.2996346067871642],
+    [-10.6590243779901, 11.089720615523941, -1.9291419134314238],
+    [-9.346926492427878, 9.776757568304134, -1.6096021459864744],
+    [-8.221957097043251, 8.629866523825953, -1.3240290125906093],
+    [-7.222553281427736, 7.591099511800469, -1.059586906893897],
+    [-6.29571273688154, 6.610706633237789, -0.8052418276047653],
+    [-5.388135904469676, 5.638712146444763, -0.5498762138417972],
+    [-4.43583763177804, 4.615080422215982, -0.28008143763924187],
+    [-3.347132527309319, 3.4536348120086857, 0.023469853173994026],
+    [-1.9681220840818603, 2.0093815350895414, 0.39344506367915183],
+    [1.2362519353041057e-12, -7.836085247576144e-13, 0.8938140051494035],
+    [-103.48790982333291, 82.73432671372025, -15.011220573659198],
+    [-79.154424970679, 61.98712399922088, -10.786139349566655],
+    [-67.2421564946432, 51.72078977988752, -8.66244922508739],
+    [-60.03922966698162, 45.41956542388928, -7.330433254574822],
+    [-55.21115572769597, 41.10881375362847, -6.392753004283941],
+    [-51.7831637300342, 37.962520247663434, -5.6828205910110645],
+    [-49.268452283447544, 35.5666402541752, -5.116692717764232],
+    [-47.393444162423584, 33.686797301170365, -4.646345251950801],
+    [-45.990033272729654, 32.17703643698828, -4.2412843095587665],
+    [-44.946237858118785, 30.937845353762274, -3.88003078469223],
+    [-44.179999892124385, 29.89358785877949, -3.5454597486860866],
+    [-43.62186769485412, 28.977172757768866, -3.221510816025896],
+    [-43.19796651636342, 28.1144061988654, -2.8896663310547632],
+    [-42.80137846222094, 27.197173259956372, -2.5227746683455994],
+    [-42.21644250466522, 26.012480547428964, -2.0687138956777686],
+    [-40.820152323536185, 23.963052505997783, -1.3862328473208507],
+    [-93.17560498019483, 75.40331034647431, -13.802642236107461],
+    [-71.75687177571362, 56.9148078259287, -9.9938612825334],
+    [-61.04078331023349, 47.56648918073932, -8.03817319138017],
+    [-54.47944257614344, 41.75859247853421, -6.7972611061275225],
+    [-50.0365551672424, 37.7479099447794, -5.916448008304405],
+    [-46.849206275202015, 34.79451685269004, -5.24488654554],
+    [-44.481629288395396, 32.52350962321915, -4.705786783156801],
+    [-42.686253511298176, 30.720518838240963, -4.254824268392017],
+    [-41.30891724135079, 29.250481271280837, -3.863590298572251],
+    [-40.244930563300905, 28.019830871380503, -3.511843047809372],
+    [-39.4152815431712, 26.955791652921302, -3.183194231016125],
+    [-38.75026396146547, 25.991777086999928, -2.8619627828545195],
+    [-38.1723096321814, 25.051588911839872, -2.5296276719891644],
+    [-37.56562247206883, 24.020980931463097, -2.1583107362270852],
+    [-36.6939559320707, 22.67039785394242, -1.6930070703486253],
+    [-34.86667898792146, 20.340594743869104, -0.9791417534379732],
+    [-85.11687411025233, 69.70675180275649, -12.866303145414525],
+    [-65.84911044401552, 52.89019307700053, -9.367428893601671],
+    [-56.0423427773858, 44.24144992938067, -7.54041482361718],
+    [-49.97766049086776, 38.81612044094224, -6.370426830537748],
+    [-45.83798857205213, 35.04177330032632, -5.534471087904646],
+    [-42.844065210997016, 32.243053775507434, -4.893571758138285],
+    [-40.598819721504974, 30.07478605864017, -4.376393078978715],
+    [-38.87470255767594, 28.337993483110736, -3.94145779744079],
+    [-37.528528612377436, 26.9061342488944, -3.561975144309215],
+    [-36.46160106320933, 25.690424247195704, -3.2186687913434593],
+    [-35.59774220168341, 24.620527671681828, -2.8957216767642593],
+    [-34.86763283940854, 23.630476586154714, -2.5777243475850575],
+    [-34.191492606910444, 22.6426512670292, -2.246072670311157],
+    [-33.447168753825316, 21.537788996683357, -1.8720919044684383],
+    [-32.38172629756472, 20.071479746719415, -1.3977852021154704],
+    [-30.24219677969168, 17.524036000005072, -0.6547531186916649],
+    [-78.6245855532886, 65.14767267933621, -12.119234713577844],
+    [-61.006207290054434, 49.615975101613124, -8.859705542030877],
+    [-51.91326765186853, 41.517221675605896, -7.13428261320766],
+    [-46.24434576610893, 36.39698187741022, -6.02102680768148],
+    [-42.34974843702405, 32.81358653172364, -5.221342299554523],
+    [-39.51495361295449, 30.14174973311041, -4.605503682922136],
+    [-37.37316240093737, 28.05957065827399, -4.106460078017542],
+    [-35.71267077671413, 26.38024061464018, -3.6849858030729137],
+    [-34.39912144137742, 24.98403282733163, -3.315575725723586],
+    [-33.33877460442046, 23.78605156175589, -2.9797209143216175],
+    [-32.45795738835567, 22.718000070459333, -2.662050542155683],
+    [-31.687942911770154, 21.714472016104104, -2.347342518788059],
+    [-30.94745404016111, 20.69666787940709, -2.0168489909755527],
+    [-30.10931786892097, 19.540889837594804, -1.6410548866385728],
+    [-28.906098937579753, 17.988864865854637, -1.1589267797935916],
+    [-26.52673432545076, 15.26240258616813, -0.3881918994175345],
+    [-73.26432223498627, 61.41284239905264, -11.509297455222452],
+    [-56.94872386119579, 46.89724672606879, -8.439880823365996],
+    [-48.430484741032984, 39.24149852012643, -6.796582659069473],
+    [-43.08427408007942, 34.36998787612168, -5.7296875934327165],
+    [-39.39203070168217, 30.944065802467296, -4.95991942791357],
+    [-36.690701117277655, 28.378287986217348, -4.364945413632661],
+    [-34.63776185589983, 26.369414855788815, -3.8811603681597715],
+    [-33.03433255747917, 24.740465572985997, -3.4711576904816512],
+    [-31.753287440168084, 23.377263882354104, -3.110473583423094],
+    [-30.705027794068307, 22.1981137214859, -2.7812187350813726],
+    [-29.81805903433555, 21.136415403427495, -2.4683739559371602],
+    [-29.02428439968672, 20.12722381180783, -2.1568410428133733],
+    [-28.241312896860887, 19.090672549209327, -1.8276864262216468],
+    [-27.337759915436543, 17.89899462458188, -1.4505344816965975],
+    [-26.033161765309643, 16.280799079110356, -0.9613245635645892],
+    [-23.46273345140501, 13.402152145230582, -0.16422772404863434],
+    [-68.74609862930514, 58.293948478955, -11.0019325654896],
+    [-53.483857600778606, 44.600056311415166, -8.086854447079634],
+    [-45.43778783297191, 37.308059411902654, -6.511196453801408],
+    [-40.35969927339196, 32.64295505734754, -5.4828436755432595],
+    [-36.83750825862094, 29.349092704251493, -4.738155257884762],
+    [-34.249971276510124, 26.873384607553824, -4.160824905532003],
+    [-32.27442537802279, 24.92783810078097, -3.690069736550209],
+    [-30.722559881637117, 23.34354817152105, -3.2899780054082863],
+    [-29.473266767842553, 22.010927744422148, -2.9369424622125115],
+    [-28.44044402819464, 20.850943391725707, -2.61358069970184],
+    [-27.554486543879833, 19.798411330384898, -2.305152822624792],
+    [-26.747902856212214, 18.78874678849668, -1.9966371571886983],
+    [-25.937415425557706, 17.741079894111223, -1.6688869082772952],
+    [-24.987977538340832, 16.523845517266107, -1.2906630830977375],
+    [-23.607239235483572, 14.853195139463692, -0.7949271884814585],
+    [-20.881292024639734, 11.8428358202961, 0.027141151126335517],
+    [-64.86708833499482, 55.64601213912309, -10.57318832958322],
+    [-50.47270676949592, 42.6284231428967, -7.7855751211675],
+    [-42.82103926598982, 35.63973026002744, -6.266467000355468],
+    [-37.96918563180616, 31.14839604327502, -5.2706094478304655],
+    [-34.59202707209497, 27.96683434507638, -4.5472344645473],
+    [-32.10288978997758, 25.568666639952713, -3.9850263394485075],
+    [-30.195630206528786, 23.678547363919176, -3.525547864320576],
+    [-28.69078329716577, 22.134300552310105, -3.134132404074844],
+    [-27.472316474651777, 20.83018055344285, -2.7878859497325132],
+    [-26.457110740824785, 19.6893915397741, -2.4698451710723495],
+    [-25.577204655925442, 18.647936412341377, -2.1654933128656517],
+    [-24.765666273256667, 17.641501888401166, -1.8598497632823061],
+    [-23.938479891604736, 16.588258304816133, -1.5335373331563789],
+    [-22.957345001324608, 15.353064988888097, -1.1544624534436365],
+    [-21.51898737680352, 13.640237902599857, -0.6526845228983591],
+    [-18.664953794362024, 10.51476552956208, 0.1929144921544836],
+    [-61.479120960653965, 53.36387565966387, -10.20578858184636],
+    [-47.81084090356495, 40.910752051854644, -7.524883703272813],
+    [-40.49311971773635, 34.17809033411849, -6.053625205999115],
+    [-35.834593043849836, 29.834763364431037, -5.08548187573312],
+    [-32.58265060347808, 26.749805890116406, -4.38042976054371],
+    [-30.17963128270641, 24.419179541246667, -3.8313379331948743],
+    [-28.33332483738723, 22.578158113257025, -3.381741331059436],
+    [-26.87176448244604, 21.07023514675019, -2.998021364758502],
+    [-25.68324678350291, 19.792911995182596, -2.6578834985817994],
+    [-24.687220168213916, 18.6712878585871, -2.344713508827776],
+    [-23.817158800443845, 17.642363825167834, -2.0441732965704187],
+    [-23.006638378327068, 16.64202201364425, -1.7412962632394162],
+    [-22.17100733152936, 15.587506198959629, -1.4164644242200974],
+    [-21.169028557366403, 14.340288793367277, -1.036744896500423],
+    [-19.687331058106484, 12.593428954790825, -0.5293838652575144],
+    [-16.72715009582962, 9.366713395890743, 0.33831540932894905],
+    [-58.46901158827623, 51.36805676103826, -9.886777954037415],
+    [-45.41595403715059, 39.391262690527896, -7.296160059554755],
+    [-38.38406592339235, 32.87678221535659, -5.86577371491534],
+    [-33.892369094973176, 28.660643161628155, -4.921486214639687],
+    [-30.749552572653084, 25.659538608021915, -4.232339106481703],
+    [-28.422621393500183, 23.38831745721965, -3.6947452012808437],
+    [-26.63125748694451, 21.591256411511278, -3.253912085492009],
+    [-25.209916904877616, 20.116665661045356, -2.8771099803943305],
+    [-24.050620808558953, 18.864809496076568, -2.5425512194081055],
+    [-23.075040665088903, 17.76239892310154, -2.23391342686462],
+    [-22.21790588550191, 16.747273831587606, -1.9370008168785093],
+    [-21.41324352825823, 15.755443674925365, -1.636839241821815],
+    [-20.57583271750428, 14.703247293908358, -1.3135630713017545],
+    [-19.56174016892325, 13.448921757375444, -0.9334168420385062],
+    [-18.048178438388586, 11.674769681540415, -0.4209218744687639],
+    [-14.999626220904483, 8.358415021417388, 0.46754201154705305],
+    [-55.74576079100702, 49.595642350618284, -9.606018110628117],
+    [-43.21920730232907, 38.02406155250256, -7.092397174346465],
+    [-36.43378149822286, 31.696643846633354, -5.697156741857185],
+    [-32.086876676004245, 27.59038052206995, -4.773541061349751],
+    [-29.039655090097927, 24.66246886290445, -4.098305800757247],
+    [-26.780328215485657, 22.443858793299903, -3.570886012386673],
+    [-25.038824887071453, 20.686510524982765, -3.1379158150272826],
+    [-23.655143312620023, 19.24284540993335, -2.7674219849571653],
+    [-22.524523439552098, 18.015483532899978, -2.4380459388978393],
+    [-21.570562076766546, 16.93250395682849, -2.133708492642357],
+    [-20.729077524799695, 15.932442630802768, -1.8403249254623992],
+    [-19.934480091087895, 14.951367410929306, -1.5428946874893639],
+    [-19.101005253991648, 13.904713119847049, -1.221297841246481],
+    [-18.082174943081476, 12.647578078624317, -0.840968746885512],
+    [-16.546257442306896, 10.851865866184344, -0.3237755696749653],
+    [-13.423509820417875, 7.4552703279521815, 0.5843076534740177],
+    [-53.231159558974255, 47.993739384149336, -9.355123295531792],
+    [-41.15799020885174, 36.768305143903, -6.907460873189019],
+    [-34.585432175097615, 30.601411241760484, -5.542529526658328],
+    [-30.364028166748135, 26.59001586687676, -4.636881649089544],
+    [-27.40034868925202, 23.725987708906786, -3.973873511172378],
+    [-25.20099807848401, 21.554072050442002, -3.4555268125760343],
+    [-23.504788810817857, 19.83280297466157, -3.029693752733353],
+    [-22.15651319845223, 18.418107571015877, -2.6650438347927756],
+    [-21.05418492227078, 17.21460265199332, -2.340579290335358],
+    [-20.123049344807164, 16.15151137796843, -2.0404203259638063],
+    [-19.2998416294746, 15.167924265056707, -1.7505640222291994],
+    [-18.519257344748787, 14.19988290127009, -1.4559640653993489],
+    [-17.694950313616268, 13.161878535951724, -1.136235347970821],
+    [-16.677915754394753, 11.905873489055171, -0.7560018204762228],
+    [-15.127612281405684, 10.093475415502466, -0.23451102406574964],
+    [-11.941127317606501, 6.62348235553043, 0.6923644843198481],
+    [-50.85104564252474, 46.5135819119655, -9.126529714598576],
+    [-39.16796752614936, 35.583060984616665, -6.7353262771626285],
+    [-32.77753346974084, 29.552731656503678, -5.396446912193728],
+    [-28.663214426046043, 25.622285724055846, -4.506368843539198],
+    [-25.77120545559193, 22.813375418748166, -3.854104567096716],
+    [-23.62415733955825, 20.682576225499773, -3.343887203487044],
+    [-21.968599678407237, 18.99402961547607, -2.9246040427088573],
+    [-20.65345196265149, 17.606623473493098, -2.56546468759898],
+    [-19.579078854619844, 16.426631695073336, -2.245768289611838],
+    [-18.67208464822032, 15.384192980246421, -1.9497912128932025],
+    [-17.86990992409856, 14.41878810197649, -1.6635808744259448],
+    [-17.107368514751705, 13.466303727526661, -1.3720203926937304],
+    [-16.297374962694295, 12.440165782935631, -1.0544370387622373],
+    [-15.288176292693638, 11.189027400447042, -0.6746167930291291],
+    [-13.72994303533064, 9.363832167359163, -0.14914109309892],
+    [-10.485258532913855, 5.823630150848605, 0.7962434154816835],
+    [-48.52393264088211, 45.103157720549035, -8.912372259119184],
+    [-37.171339410538266, 34.41995521529233, -6.569016561472334],
+    [-30.93130937691876, 28.50240247878632, -5.252182467738337],
+    [-26.903616010099334, 24.63833379957262, -4.375366798901246],
+    [-24.069291540759743, 21.87500314334534, -3.7324171558605705],
+    [-21.9650747130219, 19.77911647395126, -3.2294472993398826],
+    [-20.344200485526635, 18.11955871135752, -2.8162131790149956],
+    [-19.059086988772407, 16.757658195420785, -2.4623658757894518],
+    [-18.01199780300982, 15.600987268203905, -2.1474350296401306],
+    [-17.130531693741734, 14.580323850025353, -1.85580332890644],
+    [-16.352509019510858, 13.635301207119463, -1.5735268326762903],
+    [-15.61252986730751, 12.701413186592804, -1.2853776329340176],
+    [-14.822349646699458, 11.690716663510251, -0.970345483769336],
+    [-13.826738108715949, 10.448011584082513, -0.5912916221193064],
+    [-12.264814018845247, 8.612238383549885, -0.06192494109958923],
+    [-8.958633981740261, 4.998308209587933, 0.9027433560699011],
+    [-46.14139102960156, 43.69486607752803, -8.702658475611345],
+    [-35.054279334709264, 33.209327312281914, -6.3986428410054845],
+    [-28.924109163555592, 27.376289806630687, -5.0995200847936015],
+    [-24.95350759377177, 23.55934240970777, -4.233283398147882],
+    [-22.154760382122735, 20.827933210389556, -3.597914825568605],
+    [-20.077281984002447, 18.75752405327114, -3.1011222532939136],
+    [-18.480203729306368, 17.120983260163477, -2.6933739986036485],
+    [-17.218806321613673, 15.781534585087174, -2.3446561468636085],
+    [-16.19665637298423, 14.64758556921433, -2.0346443727140784],
+    [-15.341742708245595, 13.650116752088826, -1.7477531551835046],
+    [-14.59161347120214, 12.728462918133859, -1.469974083092366],
+    [-13.879878600821703, 11.817194088776555, -1.1858848008462597],
+    [-13.116033347533724, 10.82622996088012, -0.8740177304595457],
+    [-12.13934654791757, 9.595053089224537, -0.49607817438218066],
+    [-10.572867147844928, 7.746889418426084, 0.03768541459232087],
+    [-7.1802940300757125, 4.039651268584462, 1.024926181913189],
+    [-43.52510249071453, 42.17898014568289, -8.481410298737924],
+    [-32.609312780392194, 31.825190334718158, -6.2064882681677656],
+    [-26.514479987715486, 26.029303164380785, -4.918611285482083],
+    [-22.53838168591518, 22.221904318180954, -4.0582989814286865],
+    [-19.724041626419915, 19.493022060544178, -3.427201551996288],
+    [-17.633661884101176, 17.426305348003865, -2.9344178712877014],
+    [-16.031905991476272, 15.797997677802595, -2.5309660861829375],
+    [-14.776049163685633, 14.472435979851916, -2.187006719481642],
+    [-13.769742070278914, 13.357950623850192, -1.8822002031036014],
+    [-12.939895553549487, 12.384712793868356, -1.6008293097402246],
+    [-12.222056381335465, 11.490610264998127, -1.3286453787497536],
+    [-11.546658211363809, 10.607843581270204, -1.049815402303806],
+    [-10.817689065951036, 9.641999295211402, -0.7420838532386516],
+    [-9.862775956936066, 8.422945430384877, -0.3653373410262163],
+    [-8.2734351366645, 6.547172933189521, 0.17535219498833327],
+    [-4.698378010687734, 2.676104105642329, 1.1961828410968642],
+    [-40.29552021175639, 40.322987138560634, -8.215013946066883],
+    [-29.324329684137798, 29.957535781749915, -5.949177550025296],
+    [-23.04174473120746, 24.06609881863089, -4.655661171528849],
+    [-18.85811112885183, 20.1525725798532, -3.7875934432060383],
+    [-15.860286920405295, 17.333451706377996, -3.150684315859758],
+    [-13.628074802577395, 15.202024584861089, -2.6553155714315197],
+    [-11.930130390372126, 13.535930880675954, -2.2525684437059676],
+    [-10.620716362215779, 12.196906798752162, -1.9121355398709992],
+    [-9.597412669298064, 11.089070780774932, -1.6130223266194084],
+    [-8.779891449804262, 10.137809103587108, -1.3387970002125589],
+    [-8.095560468132122, 9.275649753343767, -1.0744087685945767],
+    [-7.464552369396281, 8.42782273797749, -0.8029475016583657],
+    [-6.774603577469296, 7.488433117792241, -0.5003057408873965],
+    [-5.8203381368712765, 6.2631050100929455, -0.12231476371356048],
+    [-4.108151350252277, 4.284522332772383, 0.4368217874971951],
+    [-3.251502369025553e-14, 2.3786817521603493e-14, 1.529481360760704],
+    [-124.47516124848632, 99.27695803103786, -17.78290305373209],
+    [-93.821243146663, 73.32321476837262, -12.5763119932136],
+    [-79.7180455663265, 61.2184910338193, -10.095720911710071],
+    [-71.55651103853452, 54.07215032741282, -8.586309900872505],
+    [-66.33133670473633, 49.360684017846246, -7.548556124539114],
+    [-62.83549706397541, 46.066750030854315, -6.77990628603306],
+    [-60.48779218109741, 43.696453313605, -6.180746431936114],
+    [-58.976626418798354, 41.98032776718978, -5.695234442031351],
+    [-58.12444363227016, 40.75899320320749, -5.2885034830684745],
+    [-57.82806381785608, 39.932050079838184, -4.936223871406045],
+    [-58.02893164930128, 39.43139166784514, -4.618771976996048],
+    [-58.69495711505374, 39.20298825189873, -4.31666993920292],
+    [-59.80203062194982, 39.185833867586304, -4.004642508305838],
+    [-61.29082502775881, 39.2639709884812, -3.638383096050903],
+    [-62.88241375952417, 39.07918216433542, -3.10720339018375],
+    [-62.70306181679613, 36.7049362591247, -1.9180130039382297],
+    [-110.6960326489801, 89.35652349707887, -16.13795829871009],
+    [-84.35660998282759, 66.75820285495006, -11.545937192249486],
+    [-71.87390255998258, 55.904310298651545, -9.293960425935264],
+    [-64.52573562125843, 49.39014744598471, -7.902317005521428],
+    [-59.75069612910709, 45.037325727770536, -6.934567246541401],
+    [-56.499668827808584, 41.95059613805173, -6.210414157021304],
+    [-54.25917774969503, 39.68860870921707, -5.63995278876473],
+    [-52.74853393345183, 38.00644497156177, -5.172111447229437],
+    [-51.80268187684527, 36.75561833683341, -4.77450001475566],
+    [-51.31940292368133, 35.838437299046916, -4.424030085326078],
+    [-51.23160620071251, 35.18318898693897, -4.101538607525965],
+    [-51.48830560477191, 34.72578273527833, -3.787405952918106],
+    [-52.03186503925718, 34.38628682204854, -3.4555301996216734],
+    [-52.74351421746236, 34.01348318997865, -3.0593043172204086],
+    [-53.22675065162126, 33.17279090939651, -2.4803027164561064],
+    [-51.183468631754494, 29.589102986752426, -1.1610496712613951],
+    [-100.38741457834891, 81.99201239602088, -14.92154472391342],
+    [-77.05318184318415, 61.73622530573886, -10.761209721782807],
+    [-65.74812170147536, 51.79275354198137, -8.676540196754546],
+    [-59.00698851011008, 45.75068683248827, -7.373169268022122],
+    [-54.577472700564286, 41.67266202198904, -6.459008107088607],
+    [-51.5231760949182, 38.75072577209274, -5.769756296693673],
+    [-49.38014468995922, 36.581924095692145, -5.222614118895663],
+    [-47.89119493488919, 34.9398673601702, -4.77007500667186],
+    [-46.901863079324464, 33.68494380964662, -4.381662062395697],
+    [-46.312716580021096, 32.722713383471934, -4.035317541475369],
+    [-46.05345831481979, 31.980627916419003, -3.712364002648176],
+    [-46.06381921368164, 31.38982895225766, -3.3932454227737994],
+    [-46.268606241731085, 30.860270764798404, -3.05138632733954],
+    [-46.51657086091891, 30.22017883988166, -2.6383821067266693],
+    [-46.34028843025528, 28.983086296433168, -2.0284457694070426],
+    [-43.069600894843504, 24.533134175283887, -0.5904921511383809],
+    [-92.36332557014515, 76.31249744712277, -13.987421744389152],
+    [-71.23178572685518, 57.77505204071871, -10.1453045613764],
+    [-60.818474825600724, 48.52092970276843, -8.18782858351431],
+    [-54.54714253523276, 42.843867995334314, -6.952853643146206],
+    [-50.39146361024209, 38.98295367309187, -6.080937552177377],
+    [-47.49902341233233, 36.19528351505147, -5.419749732900957],
+    [-45.4434526115863, 34.10698864840684, -4.891878237006998],
+    [-43.98592074349396, 32.50604622844845, -4.452559833821435],
+    [-42.98080899137338, 31.26006806548359, -4.072820581993767],
+    [-42.33191721898122, 30.27774637967351, -3.731442935206913],
+    [-41.96806692044842, 29.486791436334922, -3.410179243477094],
+    [-41.82412649883541, 28.815909233767638, -3.0895698995532044],
+    [-41.8146807194299, 28.16887945216782, -2.742660064873292],
+    [-41.7681300245424, 27.359872236200278, -2.3193988070755815],
+    [-41.16605004227509, 25.860701065551062, -1.6866377397546486],
+    [-36.98651536393871, 20.71422364961308, -0.13448866828028921],
+    [-85.92736974293338, 71.80854939756131, -13.250302504189508],
+    [-66.47082696680279, 54.5765663580806, -9.650876074403595],
+    [-56.75368577325411, 45.85960655763069, -7.7927926716990115],
+    [-50.85609018424284, 40.47212907555536, -6.612124681796364],
+    [-46.92292272085911, 36.786841322477855, -5.774262046420911],
+    [-44.166362578747844, 34.11078712819387, -5.136094024580881],
+    [-42.1893790944376, 32.092721632286136, -4.624400991045953],
+    [-40.76778896246385, 30.532001040523433, -4.196577675808025],
+    [-39.76337633927987, 29.30216121304585, -3.8248491415986043],
+    [-39.08313984147938, 28.31472745816154, -3.48868242520197],
+    [-38.65611822164029, 27.498114247540805, -3.1701912080899124],
+    [-38.41459983503922, 26.779748981668337, -2.849998382976055],
+    [-38.266810304496495, 26.059283032828823, -2.5007961516283457],
+    [-38.02719572707196, 25.14027322469919, -2.0709629206439555],
+    [-37.13680964875522, 23.45824490078668, -1.4200281916785482],
+    [-32.241921366528985, 17.722060728458285, 0.24187162144146815],
+    [-80.63997333997669, 68.16022653783688, -12.656817847267144],
+    [-62.492107696865475, 51.94515193765984, -9.246980487395204],
+    [-53.33113224161039, 43.65564502355004, -7.468123066043434],
+    [-47.737280391905074, 38.50243358785187, -6.331367318787008],
+    [-43.988608851476336, 34.96189048682281, -5.521434438400521],
+    [-41.34817469860532, 32.38024664008728, -4.9024627634925135],
+    [-39.44234836341191, 30.424200807361256, -4.404555673040537],
+    [-38.058849309011826, 28.902272318564535, -3.986833145273613],
+    [-37.065708904245156, 27.692903309723917, -3.6224890402910184],
+    [-36.37293679828254, 26.710133198563618, -3.291551199079546],
+    [-35.91034947694109, 25.88326462366718, -2.9764328030778504],
+    [-35.60896835099751, 25.13909524928318, -2.6578173588688463],
+    [-35.372960259602614, 24.37437635265607, -2.3080580485953965],
+    [-35.00670672048698, 23.38421188925072, -1.874035291472761],
+    [-33.91746088514301, 21.57158764767397, -1.2081154325690584],
+    [-28.444330642996427, 15.32699213710872, 0.55773526372548],
+    [-76.20670950775317, 65.15481069123084, -12.171660869243594],
+    [-59.10199659476339, 49.7457914094816, -8.912377945908226],
+    [-50.393240584635016, 41.80161109751502, -7.197560221303631],
+    [-45.050471047096906, 36.84072905927895, -6.096793288409499],
+    [-41.4573759043192, 33.421299465011366, -5.310084151018518],
+    [-38.917813377639895, 30.920765089404025, -4.707350250625239],
+    [-37.07713472096004, 29.02025566104297, -4.22136224627833],
+    [-35.732879504639364, 27.535763697075524, -3.8126351802295217],
+    [-34.75840757451248, 26.349836423461298, -3.455153489822618],
+    [-34.066523551756795, 25.37874191296503, -3.1294078305436934],
+    [-33.58813885255657, 24.552752558162343, -2.8180602339853067],
+    [-33.25385452805035, 23.79845690908198, -2.501837123720109],
+    [-32.96532285216269, 23.01055965778267, -2.1527708442823608],
+    [-32.51956669602859, 21.977303494001895, -1.716333031983049],
+    [-31.29519250479585, 20.072326765544073, -1.0380099006926573],
+    [-25.351269676430295, 13.387938162431459, 0.824941265665406],
+    [-72.41946611459284, 62.64367837636515, -11.770345993859713],
+    [-56.15853278034381, 47.88077341566406, -8.631823275795238],
+    [-47.822452552694486, 40.21844273495458, -6.96923948798317],
+    [-42.690057986259, 35.417188002337674, -5.898252440098107],
+    [-39.23004046100532, 32.100367523233004, -5.131067643054423],
+    [-36.779431785400234, 29.670527712827035, -4.542247285498693],
+    [-34.999185467915254, 27.820513898983535, -4.066718846414275],
+    [-33.69504893807244, 26.372471758078134, -3.6661332722602515],
+    [-32.74498130947036, 25.212417890269045, -3.3151246243309633],
+    [-32.06438686225258, 24.258612405297814, -2.9945685194979164],
+    [-31.585482638079487, 23.44234755917397, -2.687336369353881],
+    [-31.239067622035595, 22.69032463995077, -2.3741874485107335],
+    [-30.92544811214098, 21.895885726307757, -2.0268605167354257],
+    [-30.43642302639269, 20.841758739977227, -1.5895286077291906],
+    [-29.126486097656823, 18.87533258379195, -0.9011920960058033],
+    [-22.800596902662335, 11.811109743406304, 1.051727486476174],
+    [-69.1227738944365, 60.517838636466436, -11.43512536459303],
+    [-53.55144056976466, 46.27570091784966, -8.393853811224936],
+    [-45.525460141082995, 38.84467253338117, -6.774053630998001],
+    [-40.5711110228895, 34.17682758099483, -5.727853633493092],
+    [-37.22624265593839, 30.947789393754558, -4.977225219427034],
+    [-34.85515145702197, 28.580396642864418, -4.400472906524921],
+    [-33.13175381032131, 26.777054418055535, -3.9342716401128155],
+    [-31.868733725569687, 25.36500613612912, -3.541199030412124],
+    [-30.948068467943354, 24.233227575808307, -3.1964246053080863],
+    [-30.28760397692629, 23.30178387707775, -2.8811464634008823],
+    [-29.821034130316736, 22.50303919528736, -2.578415748887553],
+    [-29.479868723996304, 21.764096751248708, -2.2690226919768013],
+    [-29.164059889938496, 20.977557371123513, -1.9244499374461732],
+    [-28.662063283303752, 19.921902517766483, -1.487703322423066],
+    [-27.308629615696713, 17.921482948439127, -0.7917876819994834],
+    [-20.677350386187552, 10.53106298560577, 1.2441328779518885],
+    [-66.19278321256897, 58.692940091702354, -11.15251476937616],
+    [-51.18836566515907, 44.870071655465395, -8.18932220241534],
+    [-43.421568391890574, 37.6286699047079, -6.604493880913752],
+    [-38.61859911210971, 33.07246115486031, -5.578946747194839],
+    [-35.37404648488197, 29.918950564826396, -4.842440640691981],
+    [-33.07479129210771, 27.607366596856945, -4.276280745946416],
+    [-31.405598755728388, 25.84790871140685, -3.8185497740040146],
+    [-30.185060442765153, 24.472048009365196, -3.4325770473387593],
+    [-29.298699534883184, 23.371300676839052, -3.0939720206602956],
+    [-28.666682755455824, 22.467393582879545, -2.7842040328690536],
+    [-28.224345535919788, 21.693833091603576, -2.4864824138279547],
+    [-27.904384733026347, 20.978419139817237, -2.181634273335255],
+    [-27.607329528725757, 20.213634977357103, -1.840933998213901],
+    [-27.120104443911792, 19.175006367752214, -1.4063721502702846],
+    [-25.762162250793423, 17.1672719938165, -0.7055296872631768],
+    [-18.89483333667495, 9.50041651885932, 1.4066591421533088],
+    [-63.52181334061016, 57.09864208709933, -10.91158180910818],
+    [-48.98260944792154, 43.60920496687866, -8.010174206247209],
+    [-41.43117444374395, 36.52125499801716, -6.453577948658018],
+    [-36.756085768916826, 32.0575835564317, -5.445123762599091],
+    [-33.59867444168025, 28.96893695040256, -4.720687130637744],
+    [-31.364573180871314, 26.707636684456872, -4.163937534477754],
+    [-29.747634100267124, 24.990165366379394, -3.7140702881410936],
+    [-28.571483014780252, 23.651469626367046, -3.3350128041425706],
+    [-27.724783322515854, 22.58524575088669, -3.002730332664368],
+    [-27.129925365019144, 21.714771596201224, -2.6989194285789426],
+    [-26.72405606489678, 20.97479102934424, -2.4069321553342355],
+    [-26.44153850906964, 20.29412616085587, -2.107645555573273],
+    [-26.18442570336964, 19.565816206560392, -1.7721839977795149],
+    [-25.739979327058887, 18.563818263868782, -1.3416941651237266],
+    [-24.417007363654733, 16.576949485626965, -0.6389700180417819],
+    [-17.380144732479515, 8.682134940589254, 1.5428581578159424],
+    [-61.00172051807958, 55.6677445699739, -10.702264994312348],
+    [-46.83693699585835, 42.43403431387048, -7.847957083689265],
+    [-39.459273779124445, 35.46552288997158, -6.313418253762461],
+    [-34.888776404024235, 31.07607235300613, -5.3188126310238175],
+    [-31.805107056033716, 28.042091779909523, -4.6046382969877975],
+    [-29.629373227537315, 25.82606676875867, -4.0563539324749875],
+    [-28.062971170032593, 24.14939897829754, -3.6139965706365746],
+    [-26.933765039206197, 22.849816186682038, -3.2419477719374323],
+    [-26.133164392200317, 21.822854281152434, -2.9164480847100656],
+    [-25.5856710357772, 20.99323992300778, -2.6193790074503687],
+    [-25.230398056150154, 20.29706562815531, -2.3342222980717997],
+    [-25.003861571150352, 19.664541251032, -2.0419229583779415],
+    [-24.810641686384848, 18.990019331201673, -1.7135228258993127],
+    [-24.440346471381844, 18.047430208437955, -1.2895126237459955],
+    [-23.196050670509027, 16.11362313544477, -0.5885752089517875],
+    [-16.057427731041322, 8.0405806138962, 1.6561967353906963],
+    [-58.4954888406403, 54.318352156645915, -10.512715880941146],
+    [-44.61366748068921, 41.262813453799055, -7.691216123923959],
+    [-37.36313918161637, 34.37738400928749, -6.172540179993991],
+    [-32.86858121992143, 30.041393429933375, -5.188486342949851],
+    [-29.840847122868205, 27.05006378118628, -4.482790951019855],
+    [-27.713780133390596, 24.873416091388176, -3.9421673079527495],
+    [-26.194974417569597, 23.236509652349188, -3.507234049051375],
+    [-25.11574160384891, 21.979155581126385, -3.142678916460318],
+    [-24.36974088216104, 20.998331839331204, -2.8249265125877767],
+    [-23.88332160694823, 20.220038007006938, -2.535988639701134],
+    [-23.597563763619274, 19.581766496834202, -2.2594532919149004],
+    [-23.451511157790954, 19.015463502510695, -1.9763455259245093],
+    [-23.353249635581804, 18.417608264190008, -1.6576918663909086],
+    [-23.09684361948399, 17.563794562550427, -1.2435172125324034],
+    [-21.984562944638327, 15.722904450502197, -0.5490379277595464],
+    [-14.816380757124488, 7.524196424656519, 1.7519826930169506],
+    [-55.774049243889706, 52.915178912618835, -10.32366490071281],
+    [-42.061452876003834, 39.94699584210823, -7.519300267547626],
+    [-34.86488365228973, 33.09341452000638, -6.00875814820884],
+    [-30.39253221314386, 28.776554224102068, -5.030656553326612],
+    [-27.382283979993574, 25.805197447124584, -4.330782506538061],
+    [-25.279518753440623, 23.65506212280644, -3.7966501041700846],
+    [-23.797266713843854, 22.05385179962386, -3.369206812170493],
+    [-22.76941940157833, 20.842648149385987, -3.0132608326414454],
+    [-22.090877714016983, 19.91913538173536, -2.7052672807818796],
+    [-21.688807432552917, 19.209957449684637, -2.4272414123612176],
+    [-21.505375083749374, 18.653576164218507, -2.1627818712222036],
+    [-21.48164809927709, 18.18358095807193, -1.8929398563001656],
+    [-21.529409664741703, 17.699038585829552, -1.588733722478264],
+    [-21.448562930840055, 16.978577805976006, -1.189834344931976],
+    [-20.54373918103516, 15.285858133805998, -0.5083441225996232],
+    [-13.41893008400252, 7.011758013540339, 1.843705492491975],
+    [-52.3259608374288, 51.15412070714655, -10.091775537482427],
+    [-38.541091252768936, 38.10648693928032, -7.279314200388991],
+    [-31.176128946135407, 31.147660653893535, -5.758786711031431],
+    [-26.527189128979153, 26.73419148683032, -4.772702201889834],
+    [-23.372913936009816, 23.693120720963375, -4.068917244180611],
+    [-21.178856653003624, 21.50909492409798, -3.535794030991034],
+    [-19.666307323521075, 19.912869128335625, -3.1143742841539845],
+    [-18.67052835161629, 18.745249823593248, -2.7691637013308723],
+    [-18.083978627865413, 17.901987719157834, -2.4761392564723144],
+    [-17.829516103353388, 17.30720402669795, -2.2167884359470813],
+    [-17.84442370441697, 16.89694955964713, -1.9741953852669705],
+    [-18.065374535656936, 16.6030696912804, -1.7289301380638933],
+    [-18.40134745172862, 16.324007712929863, -1.4515129362989028],
+    [-18.65130450775158, 15.838124212953966, -1.0806996892682683],
+    [-18.109071775405326, 14.395486219048646, -0.4239879486041241],
+    [-11.039496612321054, 6.037265099334601, 1.986370790068597],
+    [-46.22152980563444, 47.87304757314261, -9.650341695983728],
+    [-30.771510420425805, 33.78492195167377, -6.699185131198561],
+    [-21.437694096385034, 25.687909809544077, -5.038092870160865],
+    [-15.134478052928836, 20.369859350100675, -3.950497861290216],
+    [-10.82836033388339, 16.740698613858523, -3.1906769277419644],
+    [-7.897079472863778, 14.215798459914689, -2.634655680838938],
+    [-5.962475449840101, 12.458700977422511, -2.213030634404609],
+    [-4.792096817731199, 11.266397758801459, -1.8837943719787333],
+    [-4.239961072397491, 10.510383426089234, -1.6193410473720247],
+    [-4.213376700746544, 10.1051302052355, -1.3995672249588331],
+    [-4.651114398173386, 9.987637770621058, -1.2072376108236125],
+    [-5.502007410193089, 10.097287512528924, -1.0231694923789751],
+    [-6.686436796748277, 10.339525980773516, -0.8175137078647201],
+    [-7.980364998791837, 10.476942865859757, -0.5245061702314605],
+    [-8.456004568180767, 9.603331157364934, 0.07604465948847823],
+    [-2.7803185086910594e-12, 2.075130221910251e-12, 2.745251177315428],
+    [-139.80228959513417, 111.3643613588204, -19.826887415187663],
+    [-103.9438785986917, 81.14979728165426, -13.826327216389904],
+    [-88.18644783977552, 67.66654884075884, -11.08116677381083],
+    [-79.35825530099957, 59.93376975959183, -9.448836017883144],
+    [-73.90852109479698, 54.98342290732042, -8.34772301447825],
+    [-70.45340941048454, 51.65243808114129, -7.547862752761378],
+    [-68.34841772123033, 49.39217062947746, -6.938496237865334],
+    [-67.26677872436082, 47.91587378066244, -6.458940708444479],
+    [-67.04418441425325, 47.06758453027, -6.072460085173317],
+    [-67.61550713458118, 46.76670829003083, -5.754571486082902],
+    [-68.99021749961116, 46.98299818452995, -5.486659357042752],
+    [-71.24840181733458, 47.72487251042958, -5.250917317144567],
+    [-74.55154921755424, 49.031563415772695, -5.023223951291868],
+    [-79.15463562956016, 50.94496376529467, -4.7549872641628665],
+    [-85.25631048731199, 53.27420718846486, -4.290856332913558],
+    [-89.61010308122286, 52.10282233932026, -2.4712176981979264],
+    [-123.12642328540608, 99.26108280621122, -17.81259074927],
+    [-92.88752633193019, 73.42510621694265, -12.610212540189464],
+    [-79.10741989934867, 61.47261607153152, -10.144250177535673],
+    [-71.22484854013273, 54.4793418331094, -8.650301531341102],
+    [-66.26486346591908, 49.92545071552253, -7.628245727494178],
+    [-63.04005783910986, 46.800264606385916, -6.8758779732117565],
+    [-60.98525900375422, 44.617357830475534, -6.294100387083963],
+    [-59.80477722922533, 43.11575147880365, -5.8276035598301466],
+    [-59.33940914846346, 42.14637175951539, -5.442034233994261],
+    [-59.50949593894515, 41.622106089611826, -5.113510979202963],
+    [-60.28867602826388, 41.49257564874762, -4.822616177286891],
+    [-61.69072448346356, 41.72753233374504, -4.5492788707462015],
+    [-63.757623638168376, 42.296025356468256, -4.265064536026786],
+    [-66.51493572855364, 43.10443421244532, -3.912861726729895],
+    [-69.66569708012865, 43.663318574748516, -3.316523427299836],
+    [-68.06583027786688, 38.6484162929985, -1.10411349805075],
+    [-111.04185913119103, 90.56765443761203, -16.37206389608751],
+    [-84.5571492024837, 67.66261224500417, -11.707446929259397],
+    [-72.16964515058349, 56.789472668378274, -9.43950369112406],
+    [-64.97553075886067, 50.33461187372194, -8.046677989766817],
+    [-60.386145399517254, 46.079624528777444, -7.084030427548699],
+    [-57.35004515086534, 43.11941244240725, -6.368682834036622],
+    [-55.35896706886466, 41.01205423401686, -5.80986471661597],
+    [-54.141426327074534, 39.5162453562745, -5.356253410046541],
+    [-53.54530142687171, 38.49000752819113, -4.975432595453154],
+    [-53.48605501109593, 37.845015384014964, -4.644293264781106],
+    [-53.92084158115911, 37.52214444661881, -4.3433658681278375],
+    [-54.83189717920396, 37.473151916990695, -4.0517699697579195],
+    [-56.205103857334926, 37.6342923004048, -3.7392315940063576],
+    [-57.961387863411844, 37.849009677477724, -3.3444054349106724],
+    [-59.58006138161112, 37.48357233512492, -2.6775150181592298],
+    [-54.410408869599564, 29.88067892381755, -0.089939212948979],
+    [-101.86494090978745, 84.03721350555739, -15.295257915565081],
+    [-78.04524268738601, 63.21276746684999, -11.014266439564498],
+    [-66.68638476207491, 53.136131072314825, -8.893032513484952],
+    [-60.014786168711815, 47.08906937241782, -7.576918572791991],
+    [-55.715954734833, 43.06721721615275, -6.660378382562698],
+    [-52.837254889881265, 40.24212409135787, -5.974613762587123],
+    [-50.912905400795054, 38.20495256868674, -5.435034047950492],
+    [-49.69051477769592, 36.72968622712846, -4.993352524880493],
+    [-49.02502969308555, 35.68074292675417, -4.6187072168149665],
+    [-48.83103779462002, 34.97060040063582, -4.288722478096297],
+    [-49.05774011479223, 34.53626188477811, -3.9841125160896844],
+    [-49.670903116885334, 34.320270161084245, -3.6836976035533184],
+    [-50.62658835317239, 34.24131000773644, -3.3562046464193793],
+    [-51.789554416683416, 34.10764749778296, -2.937417851060699],
+    [-52.510052036564076, 33.19351973477569, -2.2255525205145266],
+    [-44.68794917446778, 23.45298936553926, 0.7471675400790183],
+    [-94.65748997579726, 78.9778911533665, -14.465962250098181],
+    [-72.81008245874335, 59.689624529498055, -10.469212910724398],
+    [-62.237172933145025, 50.21951775125692, -8.459954275459753],
+    [-55.974384870935715, 44.490130488224224, -7.203577249888667],
+    [-51.90962607598465, 40.654801102072554, -6.3236753323089765],
+    [-49.1645917035889, 37.94253851631215, -5.66202857498954],
+    [-47.30615680988454, 35.96978153203737, -5.138755873679147],
+    [-46.09723359697997, 34.52259666906462, -4.707929140268389],
+    [-45.399274055898914, 33.47096080896911, -4.339937414773146],
+    [-45.1278087271977, 32.72895584295582, -4.013050757210675],
+    [-45.228362027938445, 32.23208430523853, -3.7082344155544607],
+    [-45.65780521306618, 31.918020180340676, -3.40421098607276],
+    [-46.35531022957843, 31.69530160423613, -3.069056187608157],
+    [-47.15248148135452, 31.350153298735126, -2.6362613830405723],
+    [-47.31292019353435, 30.088055494810177, -1.8926294385086173],
+    [-37.36558937328702, 18.491592839012743, 1.459590664407049],
+    [-88.8499938905465, 74.97212873268452, -13.814306632406483],
+    [-68.50485441729268, 56.84774118108905, -10.033368171656075],
+    [-58.54719842898052, 47.849438849148854, -8.11126862969972],
+    [-52.61162073729687, 42.37253610169436, -6.902259570985182],
+    [-48.73958034577501, 38.68940754818, -6.051985746334936],
+    [-46.11007088108174, 36.07306071538185, -5.410332762663836],
+    [-44.315622506551804, 34.15961237031059, -4.901071288681721],
+    [-43.13160632214789, 32.7448170982184, -4.480131834169658],
+    [-42.42533000912155, 31.703450413412924, -4.118917466343137],
+    [-42.11417098028818, 30.95152717174753, -3.796255095969661],
+    [-42.142392746291165, 30.4243608255472, -3.4933726783567023],
+    [-42.4623144893942, 30.057239492611473, -3.1889889017091764],
+    [-43.00359545140222, 29.752815164894635, -2.850714104537867],
+    [-43.577642231054035, 29.28279127211547, -2.410097002614545],
+    [-43.38525679337911, 27.79748488704575, -1.6430811761451252],
+    [-31.683981065405288, 14.566533716889111, 2.073341144292077],
+    [-84.07266898262533, 71.75126367446208, -13.29558487756068],
+    [-64.89398164586214, 54.522136283159334, -9.68073638486561],
+    [-55.42627942748895, 45.895736482470284, -7.827258137912829],
+    [-49.75717105696223, 40.62233155836258, -6.656253639844887],
+    [-46.046717627134846, 37.06537590752352, -5.83025507993871],
+    [-43.51882447289843, 34.531982582060664, -5.205422939049236],
+    [-41.78650335997609, 32.67377075666989, -4.708386055726858],
+    [-40.63541919902495, 31.29435532180967, -4.296552496856518],
+    [-39.938154372100165, 30.272680669767247, -3.9421537800172772],
+    [-39.61445201479958, 29.526851626707128, -3.624497634483194],
+    [-39.60887287598874, 28.992841638285384, -3.325072203761388],
+    [-39.872038520852065, 28.605213325083017, -3.0226513069864223],
+    [-40.328974005062776, 28.263627923650265, -2.684516717370909],
+    [-40.77951258484416, 27.73048040397815, -2.2405750003416536],
+    [-40.37845830920391, 26.111313555215364, -1.456674049738849],
+    [-27.185587640180906, 11.405954310302759, 2.611731206899104],
+    [-80.07078871991989, 69.13301444394061, -12.879734245184915],
+    [-61.80812721741333, 52.596223972756526, -9.393129070627776],
+    [-52.735088886224005, 44.264760026325774, -7.593880304379629],
+    [-47.285860200317906, 39.156834845576626, -6.453557800217151],
+    [-43.713055214105424, 35.70585014157098, -5.647643937645365],
+    [-41.276158613722885, 33.245461770143606, -5.037161789856044],
+    [-39.60473152137937, 31.43957291479847, -4.550980808672054],
+    [-38.49306954641665, 30.098239883375893, -4.147687954896734],
+    [-37.818569593727446, 29.104083653450918, -3.8001970641506415],
+    [-37.503719702090734, 28.37742091251583, -3.488237137995715],
+    [-37.494554702526116, 27.855554235887656, -3.1935568563879944],
+    [-37.74218172904683, 27.473646091388257, -2.895040201331836],
+    [-38.170772225229776, 27.13072087021566, -2.559774334260557],
+    [-38.575417889335185, 26.584239561813813, -2.1163438631256772],
+    [-38.07949405361578, 24.90484787684829, -1.3215537221926748],
+    [-23.586068278995647, 8.837697662709138, 3.0924702523474874],
+    [-76.65802963347559, 66.98742944680832, -12.545643298281062],
+    [-59.11771954568848, 50.983219918075186, -9.157223942214925],
+    [-50.364521173373596, 42.88522445849428, -7.400619199477721],
+    [-45.098387121304, 37.91233326726814, -6.2850729190238805],
+    [-41.644435830139784, 34.55129581394124, -5.495890075351296],
+    [-39.290503003500056, 32.15632688086784, -4.897824765740861],
+    [-37.67964395108253, 30.401059669060782, -4.421489502508541],
+    [-36.61350226312442, 29.10087535283675, -4.0264094983025505],
+    [-35.97396560226056, 28.141711118716508, -3.6860652126565543],
+    [-35.68663526181972, 27.446255132289885, -3.3805644896966554],
+    [-35.70007579813975, 26.953786721659295, -3.0919283131325908],
+    [-35.96784177214048, 26.601334639026053, -2.7992181657211583],
+    [-36.416704897430726, 26.289567059018953, -2.469482891516565],
+    [-36.84344439363322, 25.775379568936327, -2.0303638359475045],
+    [-36.354944489318726, 24.10539602144692, -1.2310099758021373],
+    [-20.711552749684902, 6.758823921830667, 3.527599167470827],
+    [-73.68836592713373, 65.21689503233308, -12.277859024359726],
+    [-56.7151371307985, 49.61400487552678, -8.96268009756323],
+    [-48.22066806223246, 41.698246657577315, -7.239002119990002],
+    [-43.107356972539336, 36.83499523933788, -6.1432947790063235],
+    [-39.7569685682028, 33.550807026651995, -5.368094179529867],
+    [-37.479935423459914, 31.215540979541178, -4.780939812091682],
+    [-35.930372964925816, 29.51046292196793, -4.313769599927446],
+    [-34.916276299858765, 28.255384883610468, -3.9268463294291918],
+    [-34.32383732407092, 27.33932443585772, -3.594124876385533],
+    [-34.08220930318047, 26.687589568622407, -3.2960669350548657],
+    [-34.143597040430485, 26.242179319007302, -3.0149955979113927],
+    [-34.46605974430834, 25.943407229301076, -2.73024292738749],
+    [-34.982658715571056, 25.696164067005572, -2.409020751336149],
+    [-35.49893493190967, 25.261827931348538, -1.9785027740238494],
+    [-35.12265626399615, 23.676202336681342, -1.181965365953762],
+    [-18.466993551899385, 5.120406593920891, 3.9239299891636197],
+    [-71.03584627071368, 63.74247721943067, -12.064409347157572],
+    [-54.498416914582, 48.426576147065646, -8.800556101762682],
+    [-46.20928457121599, 40.6475310410422, -7.101187173107375],
+    [-41.221912289209136, 35.871329899468535, -6.020985177688116],
+    [-37.96165307479259, 32.652694581128706, -5.257445478527184],
+    [-35.75674575398622, 30.372865653233063, -4.680055874806462],
+    [-34.270336842886174, 28.718927231768586, -4.22170870297846],
+    [-33.31597680786368, 27.51438292120517, -3.843228494003335],
+    [-32.78410577794389, 26.651214592618363, -3.5189702763495445],
+    [-32.60800406378403, 26.057738402140956, -3.2297434745997444],
+    [-32.744842637097584, 25.67963354002555, -2.9582296753562476],
+    [-33.15965090862508, 25.46230296207716, -2.684168206400847],
+    [-33.79630214717319, 25.318176773565852, -2.3752129982195855],
+    [-34.47801410141623, 25.019708858214415, -1.9587033985473556],
+    [-34.33584011579573, 23.609254020874886, -1.1743415690736547],
+    [-16.824812117852492, 3.925698154267205, 4.282593904227321],
+    [-68.57090886727626, 62.48892386585848, -11.894529557385617],
+    [-52.34731199335555, 47.351257786091615, -8.661174940280295],
+    [-44.21120711095619, 39.66445396576326, -6.977884203834625],
+    [-39.32246570803588, 34.95306954376294, -5.909124868871835],
+    [-36.13852487382341, 31.789200476389485, -5.155204905006464],
+    [-34.00119279567167, 29.561514525492772, -4.586767069645297],
+    [-32.580853930804174, 27.961244581470293, -4.13730616713402],
+    [-31.69593838420459, 26.81494357299608, -3.7680439947944295],
+    [-31.241185865328383, 26.01755576626228, -3.453673899550139],
+    [-31.15476224399509, 25.500988920313166, -3.1753678296570045],
+    [-31.40051214174754, 25.215932226966498, -2.916261094799018],
+    [-31.953699829797845, 25.115397161408513, -2.656709306461214],
+    [-32.775217945734916, 25.124090822442813, -2.365223987186437],
+    [-33.71861491298708, 25.03515349932557, -1.9702194569227707],
+    [-33.97022769092245, 23.922471954744285, -1.210887555740064],
+    [-15.83157141263005, 3.2441835463676387, 4.5967592827038395],
+    [-66.11272234673454, 61.35550578258258, -11.754388943000489],
+    [-50.07336652860598, 46.28067254900662, -8.529890096862978],
+    [-42.02873508830488, 38.63621852678868, -6.8539956670249556],
+    [-37.20307126374536, 33.96328676776663, -5.792391921075417],
+    [-34.075645676411945, 30.84095562964085, -5.046072567225444],
+    [-31.998231026999992, 28.661624441447746, -4.4860595587727925],
+    [-30.64690089557931, 27.11911303423617, -4.046099627325862],
+    [-29.844313487966335, 26.042409634382043, -3.687640092254099],
+    [-29.489514632673227, 25.32945978022981, -3.385645965778875],
+    [-29.52637115127349, 24.91651887032412, -3.1216774944006382],
+    [-29.92751379151654, 24.761105418439616, -2.879464421680625],
+    [-30.682904564948206, 24.82750895434206, -2.640299209820641],
+    [-31.779622950152326, 25.060011571636196, -2.374207373925099],
+    [-33.12140974352313, 25.287917914794345, -2.0121103708519774],
+    [-34.0011909845314, 24.656786247685726, -1.297099220293319],
+    [-15.640465038037288, 3.254924319088805, 4.845297722748786],
+    [-63.30781398184188, 60.14314378694106, -11.616604238743001],
+    [-47.27978269862835, 44.98629631999054, -8.375431657026274],
+    [-39.217471544017336, 37.30644476001673, -6.69507926373113],
+    [-34.37566217810462, 32.62156261126418, -5.633888700871617],
+    [-31.250652524956475, 29.509865210472707, -4.891660552252364],
+    [-29.203596717349615, 27.36524603629411, -4.33914511661004],
+    [-27.915859594942557, 25.88303706839301, -3.909994210623388],
+    [-27.21344735431131, 24.893834309721996, -3.5656297846318754],
+    [-26.99861797469362, 24.29802913962826, -3.2811038270668966],
+    [-27.220330567881977, 24.036369667421013, -3.038279143956354],
+    [-27.861048386925145, 24.074877214772357, -2.8215690899888486],
+    [-28.93025557712481, 24.39391317410929, -2.6136114448057475],
+    [-30.4543640401089, 24.96873139054807, -2.3869811808955355],
+    [-32.42052842922177, 25.691838481800733, -2.077576641341828],
+    [-34.321564866742335, 25.854294916657626, -1.4396353004289228],
+    [-16.588908393925053, 4.3584788643547165, 4.976223308758232],
+    [-59.17108020108953, 58.27912145387134, -11.400730573577532],
+    [-42.66241978801501, 42.70129554291851, -8.09163692777846],
+    [-34.082385521795686, 34.681916214319166, -6.366686416419965],
+    [-28.756194096627382, 29.715826592813954, -5.273320352246987],
+    [-25.25122871285885, 26.405078460219485, -4.512213053033511],
+    [-22.972439671870045, 24.1609472097328, -3.9550472131245837],
+    [-21.621766451149963, 22.686154217286546, -3.5353995549262973],
+    [-21.029193555717633, 21.81137519875846, -3.213860558714071],
+    [-21.091531440051, 21.433762021529596, -2.9643645339481792],
+    [-21.747616701983496, 21.49042358993055, -2.767679108954454],
+    [-22.969866780098588, 21.946666896755843, -2.6074567464252962],
+    [-24.768436605230935, 22.792596745869762, -2.4666105627786057],
+    [-27.20429330830239, 24.040410399054842, -2.3204994505789935],
+    [-30.38703535155097, 25.686865585422687, -2.113603985559109],
+    [-34.19348675506759, 27.323150618182762, -1.625365291637322],
+    [-19.22950259842621, 7.416135338760628, 4.867781345934138],
+    [-45.976110057383885, 51.14840047606931, -10.447033009114511],
+    [-19.19612378318558, 29.49629744892937, -6.316946742921287],
+    [-2.9287418988571137, 17.3315057104116, -4.1006171232890205],
+    [7.369523611371478, 9.923962573497496, -2.764421940790385],
+    [14.18117917322372, 5.149835107125492, -1.8920341869226158],
+    [18.794143664943846, 1.9805014396180112, -1.290530480841166],
+    [21.930946282769266, -0.13611262100637522, -0.858794838448657],
+    [24.004870637888637, -1.5085277759708577, -0.5399480767261517],
+    [25.232499094623332, -2.300807407189282, -0.3012455101117427],
+    [25.67684030255574, -2.5733297141877243, -0.12492617115349695],
+    [25.242251484521027, -2.2889426652115947, -0.004292640433445691],
+    [23.610416741369818, -1.283828280681535, 0.05691762114229438],
+    [20.06181569488288, 0.8233385340055411, 0.042420916213496834],
+    [13.026688530388231, 4.858061884795356, -0.0771600327066306],
+    [-0.8747741519435854, 12.478789156454233, -0.2706050392285474],
+    [-3.803323924494135e-12, 3.529955485535057e-12, 5.977080004848892],
+    [-145.08034362382756, 115.52768861143362, -20.533224144357245],
+    [-107.31629805258595, 83.75766159725707, -14.244510337567368],
+    [-90.98210436275421, 69.79540825146844, -11.407969618584165],
+    [-81.93175331974518, 61.86736520434828, -9.734734268867992],
+    [-76.41708235528108, 56.8449470948222, -8.613664121426696],
+    [-72.99279074510358, 53.51437365777531, -7.805240446362954],
+    [-70.99440512890278, 51.3093815566899, -7.195004506551993],
+    [-70.09340733642254, 49.9396186044499, -6.720867835359881],
+    [-70.13579090063305, 49.25411573227952, -6.345852340537418],
+    [-71.07924406400086, 49.18546744104951, -6.04605651847615],
+    [-72.9738776176109, 49.72759820360032, -5.804258112192043],
+    [-75.97070771856706, 50.93097766506806, -5.605015075231174],
+    [-80.36059995930171, 52.9101524971186, -5.427797724726461],
+    [-86.6541384102648, 55.85097440768854, -5.22858119774947],
+    [-95.58439431282345, 59.833711395217506, -4.84584950062869],
+    [-103.71621714829001, 60.03598934651876, -2.6942061235025196],
+    [-127.33302481465527, 102.6136153455739, -18.38147407850175],
+    [-95.69859206915639, 75.62226103547579, -12.962489728375163],
+    [-81.47333613193075, 63.29401838265007, -10.423732775459973],
+    [-73.41509622211083, 56.143285903387095, -8.896143305547007],
+    [-68.40200904086339, 51.52913905941222, -7.857098958160169],
+    [-65.19924517657685, 48.401277768893806, -7.096862462403027],
+    [-63.22529425926743, 46.25879057945775, -6.513301466423777],
+    [-62.1816907883093, 44.83681767726314, -6.049838521401396],
+    [-61.91495518615605, 43.98858583958909, -5.671711029793842],
+    [-62.35917484708869, 43.634587331115256, -5.355157135798081],
+    [-63.51209050727701, 43.7383561287061, -5.081275783177235],
+    [-65.42762443806079, 44.292699586447405, -4.830793535699553],
+    [-68.21574355428301, 45.30454443283525, -4.576008401015166],
+    [-72.02040431446146, 46.74163802724218, -4.2586357689858385],
+    [-76.72130391224975, 48.173442049308754, -3.683302900851924],
+    [-75.34040251699926, 42.22477864059533, -0.9068344442663269],
+    [-114.61202539607059, 93.441795697831, -16.86010424228905],
+    [-87.01468217408066, 69.60372727389804, -12.018829272899653],
+    [-74.25925307968677, 58.41553305113381, -9.689084640409511],
+    [-66.91744483085094, 51.826050304754546, -8.267044696999504],
+    [-62.28237313863392, 47.51825153440345, -7.289287267695296],
+    [-59.263451418983884, 44.55395323233046, -6.566596002911321],
+    [-57.33840053339712, 42.47872124222723, -6.00557767915283],
+    [-56.23265823990396, 41.047414820407916, -5.553750888074456],
+    [-55.79765436891919, 40.11912027191034, -5.1782345941997265],
+    [-55.95825675595767, 39.61048550877283, -4.855842608369874],
+    [-56.68804108141167, 39.471592585434585, -4.567250388160163],
+    [-57.99577001116633, 39.66895231861685, -4.291746843192937],
+    [-59.909826046130064, 40.161349632077844, -3.9987311442158924],
+    [-62.41705342822998, 40.82229579432165, -3.6237110624339723],
+    [-65.0508871669575, 41.00402598451802, -2.9533261578366163],
+    [-58.111860948248605, 30.927044504534635, 0.45514242501657104],
+    [-105.03247673294884, 86.61282014409358, -15.7331348248458],
+    [-80.27022335126695, 64.98865494660211, -11.299469871338998],
+    [-68.59157206518726, 54.63463193102329, -9.123266749298978],
+    [-61.7900572905965, 48.46740785208, -7.780746903639105],
+    [-57.450465005336035, 44.397656246130566, -6.850326572858869],
+    [-54.58619664532302, 41.56790615038548, -6.157610862846171],
+    [-52.71899275364803, 39.55809193614185, -5.615649918606386],
+    [-51.59334142250798, 38.13848552561532, -5.175078400398771],
+    [-51.06662678753303, 37.17396116446626, -4.804554130503478],
+    [-51.06056907434083, 36.58061944722998, -4.481533909889823],
+    [-51.53683568631529, 36.30228647401962, -4.186703733852043],
+    [-52.48091622818808, 36.29217653443273, -3.8987702751883546],
+    [-53.8791181214601, 36.484225137620456, -3.5856753933402397],
+    [-55.63799567262386, 36.70246149138036, -3.179203905751314],
+    [-57.10730504329996, 36.17363151212376, -2.4516299116877684],
+    [-45.95744633134353, 22.588757212432995, 1.6261262676931818],
+    [-97.56252292867474, 81.3638796052958, -14.87231215583179],
+    [-74.8792526693991, 61.35864504593257, -10.737731946754085],
+    [-64.01743444897923, 51.634980761515216, -8.677803959270246],
+    [-57.6363434999358, 45.79473654004909, -7.396814500124414],
+    [-53.53423977469828, 41.91483469976139, -6.503842975416918],
+    [-50.80219128746819, 39.19784170182079, -5.835539362194093],
+    [-48.9956333661834, 37.249812875567386, -5.3098326806787615],
+    [-47.87441546790587, 35.85323660647887, -4.879772720091224],
+    [-47.301851860901465, 34.87825161692822, -4.515268409455843],
+    [-47.199387595452954, 34.241862775026114, -4.194381713897068],
+    [-47.52290929058372, 33.88516322247575, -3.897968762303278],
+    [-48.24546180518489, 33.7544256186298, -3.604509114409137],
+    [-49.330215246818234, 33.76980982039005, -3.281086121532637],
+    [-50.638541522216734, 33.72622772078727, -2.856776022053799],
+    [-51.405325768478406, 32.763407879309646, -2.090725877295957],
+    [-36.84438134473435, 16.10245234791859, 2.6462634569933443],
+    [-91.58393709769632, 77.24070101473008, -14.201550563928198],
+    [-70.47035869675801, 58.45034786890793, -10.291827940797848],
+    [-60.24355213739846, 49.21323716296382, -8.321674712619984],
+    [-54.19726568486801, 43.6313508830397, -7.089157038821606],
+    [-50.29033822253966, 39.90592741388954, -6.226334878935195],
+    [-47.67328057814903, 37.28513447382538, -5.578248225051516],
+    [-45.92788481676377, 35.39525024507192, -5.066582654663637],
+    [-44.826561746096694, 34.02857520181308, -4.6462847986421485],
+    [-44.23822144220911, 33.05993444238541, -4.2882845359497255],
+    [-44.085556690948316, 32.40794955165983, -3.971187508477496],
+    [-44.32215583593307, 32.01296046288297, -3.67608244262785],
+    [-44.914788346300575, 31.81791632856742, -3.38139771556518],
+    [-45.81412796915488, 31.735460402435805, -3.053674187010175],
+    [-46.85563599034398, 31.54271952174475, -2.6198519265617795],
+    [-47.19636050868376, 30.313719141943636, -1.8268464963640503],
+    [-29.69916317983445, 10.830787611092342, 3.564891154189223],
+    [-86.69954164254409, 73.95425229425008, -13.672718296652214],
+    [-66.79332539142605, 56.08832336484351, -9.934101403797396],
+    [-57.068494539652306, 47.23140916033265, -8.03396959845595],
+    [-51.29347921057882, 41.85644451854134, -6.840056511329014],
+    [-47.549982026960855, 38.25875242144501, -6.001820209203537],
+    [-45.03474076871183, 35.721515959117475, -5.3707288830767395],
+    [-43.35049269581603, 33.88684417093725, -4.871383724478702],
+    [-42.28007324513708, 32.555012491625526, -4.460233154030655],
+    [-41.69762352406581, 31.605029700707814, -4.109046463033681],
+    [-41.52802563608325, 30.95755844528726, -3.796915356414521],
+    [-41.72484837436217, 30.55349532368183, -3.505192837367117],
+    [-42.25265676859196, 30.33489921549358, -3.212370049109359],
+    [-43.05664785174283, 30.211135700543004, -2.8846696756964425],
+    [-43.959066486447355, 29.949289725425714, -2.4473503811014665],
+    [-44.064514790495416, 28.573389258525037, -1.6361829451390713],
+    [-23.878275015540503, 6.361009506663969, 4.428629255896376],
+    [-82.63825454232276, 71.31048820141415, -13.253805874345614],
+    [-63.669557443013744, 54.14964886825894, -9.645378550550406],
+    [-54.3456537966042, 45.591011275424954, -7.799927087829005],
+    [-48.79327676845156, 40.38308978457303, -6.636902398653484],
+    [-45.18889937780996, 36.89243335389054, -5.818901359723882],
+    [-42.7655375827294, 34.42914727207099, -5.2023058674645],
+    [-41.14276834718622, 32.6477893777563, -4.713978155278684],
+    [-40.112219280089576, 31.355190458303806, -4.311559140127614],
+    [-39.55298040832521, 30.43416029359306, -3.9675153971512627],
+    [-39.39284505864299, 29.807749723507044, -3.6613810642694347],
+    [-39.587121522463924, 29.41846766541621, -3.3748030421293347],
+    [-40.1013376646478, 29.20945799790848, -3.0864378468249574],
+    [-40.88073349893473, 29.09030063454792, -2.762402318876373],
+    [-41.74449744524663, 28.82458192871666, -2.326740189016661],
+    [-41.766780510531156, 27.401160795422744, -1.5055358648354698],
+    [-18.9766301900653, 2.416309327487008, 5.277492970149547],
+    [-79.20343193775217, 69.17260295405484, -12.922613854709088],
+    [-60.96322664857902, 52.54364189187852, -9.411760678842905],
+    [-51.96085679345773, 44.21771417498356, -7.608601876815255],
+    [-46.59276887069587, 39.14488880589711, -6.470230903052534],
+    [-43.10853699376565, 35.74491982124756, -5.668985815011719],
+    [-40.76974233062396, 33.3484151394738, -5.064944525999772],
+    [-39.20962008586073, 31.61969955285019, -4.586702009866704],
+    [-38.227352250396265, 30.37105664209282, -4.1928432988312885],
+    [-37.70683065763965, 29.488852968990564, -3.8564222723979786],
+    [-37.57946036342119, 28.898916920949635, -3.5573905346604295],
+    [-37.803912152575855, 28.546412622923608, -3.277732280198391],
+    [-38.34961351085208, 28.377511118345026, -2.9963893477712444],
+    [-39.16706389400683, 28.30549906881491, -2.6796192915188426],
+    [-40.08229495049066, 28.09721028845588, -2.2508057646423594],
+    [-40.16220911266785, 26.72305469273286, -1.4282877728676828],
+    [-14.72266364310159, -1.2059866362845149, 6.149926408869327],
+    [-76.24216656960148, 67.43923004084537, -12.663165642367566],
+    [-58.56204409277015, 51.19879343916537, -9.222597579922619],
+    [-49.81617487320449, 43.05063567609089, -7.451272907368413],
+    [-44.60081757572966, 38.086197594001376, -6.332320563282003],
+    [-41.221413242475045, 34.76362364912163, -5.544980417071336],
+    [-38.96194994072222, 32.428722868085636, -4.952001651554478],
+    [-37.46679664582622, 30.753386379897822, -4.483266927730331],
+    [-36.541762692111064, 29.554491600419734, -4.098099675063834],
+    [-36.07556022517333, 28.721870293879704, -3.7700580076785366],
+    [-36.004023458576604, 28.184652930245043, -3.4795088555430684],
+    [-36.290952673825025, 27.89188200716312, -3.208844505898511],
+    [-36.91289183833433, 27.795016506727592, -2.9374552085064765],
+    [-37.83146036709328, 27.81518983598334, -2.63206506830781],
+    [-38.891061809943054, 27.73070962381301, -2.2161228046720316],
+    [-39.179877909462625, 26.514883909675774, -1.4027938500227808],
+    [-10.921737360595353, -4.673506188550274, 7.089317970575359],
+    [-73.62346362688581, 66.02983327628641, -12.463389611059325],
+    [-56.359587363029654, 50.05140712070185, -9.06879059032107],
+    [-47.812990631501236, 42.03170843784372, -7.319918440142213],
+    [-42.72224198212925, 37.1517611901892, -6.2157530600373185],
+    [-39.43429895374924, 33.89518406046976, -5.439909911324744],
+    [-37.250392337609114, 31.618341714551953, -4.856891311787531],
+    [-35.82393018622774, 29.99878835833606, -4.397469783026535],
+    [-34.96665798833898, 28.85732080613238, -4.021529140523329],
+    [-34.572319200142374, 28.087319497714265, -3.7030725071471515],
+    [-34.58224524598715, 27.621982376492067, -3.4229096655221456],
+    [-34.96756746452815, 27.415859066095415, -3.16395791860262],
+    [-35.71601334763171, 27.428710915228553, -2.906299023459514],
+    [-36.807970172341854, 27.595164630012118, -2.617598640290067],
+    [-38.121851992290644, 27.71654121342931, -2.2224048148314135],
+    [-38.80700362576503, 26.799375572547195, -1.4322026998472779],
+    [-7.422702044165505, -8.149593182826408, 8.153535170103806],
+    [-71.21203496509537, 64.86834870035759, -12.312661814923954],
+    [-54.228446194720576, 49.02914467286715, -8.940428715857037],
+    [-45.824361461610344, 41.089019190137876, -7.204902112321107],
+    [-40.82941638764765, 36.26982221120085, -6.1111332722094485],
+    [-37.619187420532654, 33.06840886695384, -5.344673290728646],
+    [-35.5075204760645, 30.847321504974186, -4.770892260738881],
+    [-34.155020879004915, 29.28802654169812, -4.321071999599357],
+    [-33.37884977584255, 28.214707116427142, -3.955493755090603],
+    [-33.07820535618478, 27.524585073083184, -3.648568753387761],
+    [-33.201377142883686, 27.156070706966194, -3.381617715619329],
+    [-33.72983127723596, 27.071577399624935, -3.138276084006495],
+    [-34.66822316870598, 27.24362355653314, -2.8997048634077767],
+    [-36.02700449083756, 27.62897254736094, -2.6352699340317307],
+    [-37.742843687188326, 28.070306835309474, -2.272254682172403],
+    [-39.08976483628139, 27.655936418187135, -1.525553256712254],
+    [-4.096353283778154, -11.82804854906825, 9.432795930614725],
+    [-68.81270465467935, 63.84953149962929, -12.196908697538607],
+    [-51.962114201545866, 48.016297480032435, -8.821909602042108],
+    [-43.63276500347756, 40.10002180715677, -7.089950387606732],
+    [-38.695495905464625, 35.3130417087403, -6.001885760407824],
+    [-35.54275652450089, 32.153370339229994, -5.242724122689224],
+    [-33.497037715673834, 29.985575421778094, -4.677821795221447],
+    [-32.22458123708906, 28.49341075915324, -4.238588035575138],
+    [-31.547524194392178, 27.50400074658668, -3.8855352458507486],
+    [-31.371027104668908, 26.918879340078046, -3.593454664113156],
+    [-31.652163017605016, 26.68328457191116, -3.3442887771572116],
+    [-32.38685270737146, 26.770978414846272, -3.122697459675479],
+    [-33.605268447480576, 27.174244795779543, -2.9115634329384497],
+    [-35.36619667253308, 27.8864773821249, -2.6832684661977146],
+    [-37.706877227981515, 28.824545469632067, -2.3707515326329855],
+    [-40.14342226447843, 29.248688852385868, -1.7008085137883402],
+    [-0.8305847475017627, -15.984787560630826, 11.090237217707452],
+    [-66.02277601212703, 62.75101015962928, -12.08601384213538],
+    [-49.10474509460212, 46.752839665891905, -8.677869879003207],
+    [-40.725495529634024, 38.77095486763542, -6.935751376675573],
+    [-35.75605778123169, 33.95905326579029, -5.8457253743161885],
+    [-32.60056918702044, 30.80713863086513, -5.090032167990496],
+    [-30.589934953245006, 28.679431044852183, -4.533277102433448],
+    [-29.395908167976803, 27.260971866048546, -4.106619410603041],
+    [-28.844877779362488, 26.381166383937092, -3.770542949741292],
+    [-28.847162301165316, 25.945724276459664, -3.5000596143984755],
+    [-29.36896050165787, 25.907922403444115, -3.277747593896563],
+    [-30.424363117766603, 26.257084307177767, -3.089636475297758],
+    [-32.08066362905717, 27.015962797902944, -2.9213297125833395],
+    [-34.47541930003954, 28.240303738475358, -2.7506297371914354],
+    [-37.828204080881875, 29.987020395129832, -2.5220666528327773],
+    [-42.15564694943286, 31.889994449015386, -1.9919570901843722],
+    [2.38645702157528, -21.052547025987728, 13.475307371540364],
+    [-61.62625428113359, 60.871332353795665, -11.881962053216208],
+    [-44.00792929238126, 44.27275310048671, -8.374776636550843],
+    [-34.91153617604831, 35.821279188132806, -6.569134142521308],
+    [-29.273109829857354, 30.619638999388, -5.432835790935706],
+    [-25.590421181543945, 27.18879279807703, -4.648983003238212],
+    [-23.25838312605579, 24.91932709455354, -4.083743036853985],
+    [-21.979732332343886, 23.508150075416463, -3.6683516271047956],
+    [-21.587971520168388, 22.786061707101762, -3.362281321301813],
+    [-21.987866207457685, 22.65617933286437, -3.1392114358703167],
+    [-23.132801155224293, 23.06888440455135, -2.98043690519898],
+    [-25.023494964281394, 24.01566604631085, -2.87125890869874],
+    [-27.737226785777853, 25.543895618232092, -2.798814144024684],
+    [-31.503149904018425, 27.799613582610334, -2.748663584970364],
+    [-36.869181268757984, 31.115269950707077, -2.6893903802784407],
+    [-45.057347237173694, 36.07519625067007, -2.4566602709326744],
+    [4.388958903639684, -27.257807468619262, 17.517159934630534],
+    [-40.73198842247962, 49.3655725510217, -10.32500220425326],
+    [-5.0532271678363925, 22.454272681781166, -5.459907724551236],
+    [15.221006940089902, 8.313787943211173, -3.024215732337012],
+    [27.741673373837664, 0.018280008834351567, -1.6218338677551056],
+    [36.04633013961135, -5.230875328400187, -0.7349450275472704],
+    [42.00625367103329, -8.796501537196738, -0.1257407674088628],
+    [46.73593575955189, -11.43481414264753, 0.3314764201144422],
+    [50.937653046549784, -13.589320948404136, 0.7077099597253772],
+    [55.078402335436124, -15.527391491273002, 1.0446788788493924],
+    [59.53102547111812, -17.431661762128588, 1.3702756879808937],
+    [64.68748056589995, -19.459199860312296, 1.7080576163238024],
+    [71.08099851795424, -21.78602740610411, 2.0844347793875295],
+    [79.59211563293756, -24.654900368893024, 2.5376413480746804],
+    [91.88041691939158, -28.405012409014848, 3.13316856020875],
+    [110.4515634361946, -32.6054919809527, 3.947372540685473],
+    [1.4528400214517795e-8, -1.0622236606430416e-8, 22.737998981754345],
+    [-145.50492873705687, 115.86261934539104, -20.59008991102902],
+    [-107.5850793353699, 83.96551568361106, -14.277870833535419],
+    [-91.20436417274856, 69.9646599931206, -11.43397762209816],
+    [-82.1363122285824, 62.02106188475498, -9.757484179159544],
+    [-76.6166855186925, 56.99306680789725, -8.634849141953504],
+    [-73.19523452504221, 53.66281011686854, -7.825783819233622],
+    [-71.20592776907839, 51.462644358227855, -7.215535763886112],
+    [-70.3201955979082, 50.101988024987904, -6.7419103320601845],
+    [-70.38501189221549, 49.43037517891392, -6.36792083323143],
+    [-71.36016814565295, 49.38163797208523, -6.0697305095288945],
+    [-73.29954516864696, 49.95197140385585, -5.83026069236677],
+    [-76.36086764440127, 51.19587101564595, -5.634317677568333],
+    [-80.84761115205073, 53.23532886826188, -5.461775273685307],
+    [-87.29642584993002, 56.271168413916286, -5.269224231012108],
+    [-96.49949997630185, 60.4150191554259, -4.895179886492399],
+    [-105.03175475301843, 60.77044620590826, -2.712280165573856],
+    [-127.66974796383163, 102.88198724582293, -18.427050703604156],
+    [-95.92195481722592, 75.79684950758303, -12.990509597014748],
+    [-81.66095293848316, 63.438458101782736, -10.44592026669709],
+    [-73.58876899737866, 56.27522730438008, -8.915660068731679],
+    [-68.5716354943856, 51.65642506090784, -7.87528591403815],
+    [-65.37091891560608, 48.52857178381086, -7.11445571035777],
+    [-63.4038318758796, 46.389617333732566, -6.530796462051506],
+    [-62.371745306460404, 44.97443112911253, -6.067633590645705],
+    [-62.1217351305015, 44.136489452307394, -5.690178540660677],
+    [-62.589153922223176, 43.797002520576164, -5.374689807938903],
+    [-63.773963225795775, 43.92080841067862, -5.102325375203967],
+    [-65.7338435336781, 44.502909644170565, -4.8539055583702195],
+    [-68.58529944676913, 45.55395359848583, -4.6018394920179215],
+    [-72.48419442606625, 47.04808681706597, -4.287844048421047],
+    [-77.33014233036256, 48.562768822902775, -3.715100839546597],
+    [-75.95824922903722, 42.51195216872067, -0.8811969427787588],
+    [-114.89701331523244, 93.6712344040543, -16.89909813732036],
+    [-87.20962570770295, 69.757710673225, -12.043556413641909],
+    [-74.42472819594515, 58.54430197623704, -9.708872188547186],
+    [-67.07122271095523, 51.944156121051144, -8.284517298189284],
+    [-62.43267368002618, 47.63228119264567, -7.305578268383514],
+    [-59.41536123369376, 44.6678443235999, -6.582330891839317],
+    [-57.49590964912228, 42.595427492602475, -6.021174079132437],
+    [-56.39955454694577, 41.16961344626534, -5.569537089128286],
+    [-55.97808057584696, 40.24962165170383, -5.194506702837946],
+    [-56.15722395581993, 39.752575414185074, -4.872898133343408],
+    [-56.91207188983719, 39.62942349434308, -4.585410288718546],
+    [-58.25386349878383, 39.84808575290261, -4.311364544804432],
+    [-60.215058340020946, 40.369576608481985, -4.020164804657656],
+    [-62.78916987713147, 41.070665503667136, -3.647116092869248],
+    [-65.51680792774721, 41.30399998490417, -2.9769612307550086],
+    [-58.36523584479525, 30.948809986165877, 0.5198171923744458],
+    [-105.28496265786664, 86.81813158623349, -15.768072376431698],
+    [-80.44658147209935, 65.12942000681142, -11.3221016441392],
+    [-68.74235173573881, 54.75322673077097, -9.141510504548062],
+    [-61.93056053938563, 48.57649577588342, -7.796900253330528],
+    [-57.5878689887206, 44.50304980448613, -6.86539501757175],
+    [-54.72496276500463, 41.67309646196675, -6.172151920231704],
+    [-52.862605605510346, 39.665687065804406, -5.630034166317395],
+    [-51.74507241164026, 38.25082208261504, -5.189592980828403],
+    [-51.2299994373848, 37.29345148833123, -4.819451862251294],
+    [-51.239769192593464, 36.71002762998515, -4.497060237102738],
+    [-51.73719918811218, 36.44502391031555, -4.203110982190207],
+    [-52.70962347931707, 36.452681882888946, -3.9163157717221866],
+    [-54.146262809866435, 36.66847089170412, -3.6045757263135854],
+    [-55.9579866973075, 36.918276945500494, -3.1993874141572705],
+    [-57.4962620418067, 36.42593715699865, -2.4709063792540142],
+    [-45.95920199070247, 22.405847436251623, 1.7287820730625958],
+    [-97.79396734960174, 81.55397805158876, -14.904719156937528],
+    [-75.04324055298841, 61.4909217703585, -10.75903809957393],
+    [-64.15832534046496, 51.74700112750655, -8.695066986284239],
+    [-57.76788166349991, 45.89799027566189, -7.412129682681074],
+    [-53.66294159425446, 42.01465273685506, -6.518136763405869],
+    [-50.93212491460473, 39.29744043074019, -5.84932767936665],
+    [-49.12997110872429, 37.35159186854192, -5.323457905096325],
+    [-48.01611697178219, 35.95933204966117, -4.893498025170821],
+    [-47.454075941784296, 34.99084746953717, -4.529322192767414],
+    [-47.36584581856826, 34.36343115059898, -4.208981001128376],
+    [-47.70827305806216, 34.01871126179388, -3.9133297104758262],
+    [-48.45592132726512, 33.90379623926871, -3.620840349518965],
+    [-49.57428969680442, 33.940038424738255, -3.2985351945754133],
+    [-50.92791468808994, 33.92352894669423, -2.875162115963338],
+    [-51.75067086272868, 32.98935816918421, -2.107599858905267],
+    [-36.65442484630575, 15.749909027579344, 2.7849792892464476],
+    [-91.8017947728242, 77.4214792545366, -14.23244110549267],
+    [-70.62619407789532, 58.577411135188285, -10.3123449917942],
+    [-60.377865622376916, 49.32121810842746, -8.338356375724834],
+    [-54.322826814678365, 43.731029068311614, -7.10397780896137],
+    [-50.413253355039075, 40.0023473828414, -6.2401749027698825],
+    [-47.79737864229942, 37.381353795217265, -5.591599747146792],
+    [-46.05615364752494, 35.493552322004845, -5.079772610472013],
+    [-44.961782773415315, 34.130988778370664, -4.659563823194455],
+    [-44.38335149641534, 33.168525305655706, -4.301868845574695],
+    [-44.24405239423128, 32.52504343613351, -3.985280597566487],
+    [-44.49834167140289, 32.14136784102965, -3.6908840478771485],
+    [-45.114352924758585, 31.96119892353731, -3.3970954883168907],
+    [-46.04481664981173, 31.898223421896116, -3.0703873192709454],
+    [-47.127828586787736, 31.73044655077503, -2.637353795743301],
+    [-47.5180461074043, 30.526315787273273, -1.8425285660973614],
+    [-29.339854897425514, 10.316386346088281, 3.7417672496284973],
+    [-86.909034019948, 74.12994186124925, -13.702827030749717],
+    [-66.94404601699425, 56.21260504895071, -9.95423201771566],
+    [-57.19863818164349, 47.33725642038462, -8.050373523446801],
+    [-51.415246352890414, 41.95425564931528, -6.85464585282141],
+    [-47.66924756699143, 38.35342754596675, -6.015452815963473],
+    [-45.15520569354157, 35.816039465923275, -5.383886643075856],
+    [-43.47505315214671, 33.983451925121905, -4.8843876671133675],
+    [-42.41142905815969, 32.655694731507964, -4.473329889507054],
+    [-41.83864628783074, 31.71181509322384, -4.122449054992252],
+    [-41.682072593042044, 31.07273220072126, -3.8108245998861467],
+    [-41.89611928270177, 30.67982004355709, -3.5198061775135496],
+    [-42.446675014809095, 30.475877285586392, -3.2278736450385748],
+    [-43.28092635053235, 30.371293472718538, -2.9011825279693633],
+    [-44.22362161725561, 30.133980505857707, -2.4646437163193977],
+    [-44.37645696203838, 28.781984408483954, -1.6515643459909382],
+    [-23.34961066602622, 5.673945925404654, 4.649376378314738],
+    [-82.84328343288495, 71.48437270602797, -13.283710656973419],
+    [-63.81746163736487, 54.27307592990891, -9.665446957864262],
+    [-54.473455449978836, 45.69624674051864, -7.816299885728377],
+    [-48.91291324966459, 40.48040564841504, -6.651475076351638],
+    [-45.306151730475584, 36.98669795958891, -5.832528687196479],
+    [-42.88406633141716, 34.5233412293756, -5.2154702413923895],
+    [-41.265452049156295, 32.74415759521521, -4.727002600986455],
+    [-40.24175408096739, 31.455742155326117, -4.324693382825397],
+    [-39.692247350869565, 30.540955064102004, -3.9809769843383696],
+    [-39.54522852598679, 29.923121454572808, -3.675377305583448],
+    [-39.75687757046967, 29.545256775552488, -3.389541162919606],
+    [-40.29409905583978, 29.35129548866635, -3.102119276092857],
+    [-41.104224098409745, 29.251933139862345, -2.779170463125371],
+    [-42.00914443480695, 29.011748085626852, -2.3444033689569745],
+    [-42.08034247392245, 27.613713344995812, -1.5213959680769826],
+    [-18.2603184230674, 1.5281056096290124, 5.552073046562672],
+    [-79.40707908860692, 69.34742552711063, -12.952807074606854],
+    [-61.110119713868045, 52.66783717592051, -9.432046377058997],
+    [-52.08774616719937, 44.32362325378927, -7.625157004675311],
+    [-46.71157804928641, 39.24287318984291, -6.484973620742231],
+    [-43.22506804935352, 35.83991385136084, -5.682784671697022],
+    [-40.887690628151454, 33.44345772881789, -5.078292203921523],
+    [-39.33191230565168, 31.71709681098535, -4.599930844166756],
+    [-38.35675163442163, 30.472891117061575, -4.206212923140286],
+    [-37.84631532316885, 29.597280836557015, -3.8701621697625206],
+    [-37.73256086222794, 29.016408345168447, -3.571723729807237],
+    [-37.97511404510422, 28.676011482834074, -3.2928881164066572],
+    [-38.54492692404145, 28.52316881648804, -3.0126021380356653],
+    [-39.39486698941849, 28.472495540720075, -2.697083643244432],
+    [-40.35422362570193, 28.292226046022137, -2.269411817124835],
+    [-40.488326404266104, 26.947582945643106, -1.4454298809942667],
+    [-13.779576721659168, -2.346457468243351, 6.494465156394138],
+    [-76.44698166985654, 67.6174541186178, -12.694101732941288],
+    [-58.70937121552656, 51.32520221097057, -9.24335775053083],
+    [-49.94327172642861, 43.15835539303219, -7.468205917758618],
+    [-44.71981476035412, 38.18588035747155, -6.347404458000617],
+    [-41.33823951390843, 34.86036391795309, -5.559113919923299],
+    [-39.080407940696304, 32.5256800578046, -4.965697564544892],
+    [-37.58992704386719, 30.852982155050817, -4.496874537547308],
+    [-36.67246909494216, 29.65894039402447, -4.111895894020847],
+    [-36.217010957766945, 28.83349762172332, -3.784292627123209],
+    [-36.160024941397985, 28.306164885241355, -3.4944313004087073],
+    [-36.46641388425782, 28.026672845464187, -3.224721060067481],
+    [-37.114509989082954, 27.947589496373478, -2.9545750584874035],
+    [-38.06880033248395, 27.991750427862637, -2.6507089905344454],
+    [-39.17797198088008, 27.939609093026142, -2.2363239137302284],
+    [-39.530932600367535, 26.76085491187548, -1.422178213990504],
+    [-9.682074097213809, -6.1529509209748525, 7.5301948561813745],
+    [-73.83162602083085, 66.21383041898349, -12.495521978158326],
+    [-56.508456441182716, 50.181359919300746, -9.090273589790492],
+    [-47.941080821734225, 42.14225077057963, -7.33741338640002],
+    [-42.84211183595131, 37.25404686377263, -6.231337492584046],
+    [-39.552114771041474, 33.994570185956015, -5.4545306802206905],
+    [-37.370143925129874, 31.718178847837173, -4.871092497510935],
+    [-35.94884779341651, 30.101677873509946, -4.411626644963957],
+    [-35.099872198902844, 28.965680416594633, -4.035944916273005],
+    [-34.71730151852948, 28.20373538541139, -3.7180279609311624],
+    [-34.74323964464875, 27.749525365147797, -3.4386947356046775],
+    [-35.15015589324753, 27.558469935799284, -3.180896169623288],
+    [-35.92799421918353, 27.591765663111186, -2.9247660016308084],
+    [-37.06084037251868, 27.786362505155736, -2.6380137877352516],
+    [-38.433157330557, 27.947016624386983, -2.2450405264045874],
+    [-39.199294085726976, 27.079665537268934, -1.4551372655748487],
+    [-5.762614660965092, -10.119343199502904, 8.73622036099833],
+    [-71.4253131284868, 65.06048087903328, -12.346465907297244],
+    [-54.379412391374196, 49.163785566305144, -8.96286921080611],
+    [-45.953622472683946, 41.20315252029582, -7.223118958630631],
+    [-40.95021330158433, 36.3753543263064, -6.127350765818279],
+    [-37.73805800263797, 33.171085218393245, -5.359908316621019],
+    [-35.62874289511405, 30.95077384622805, -4.785734290311208],
+    [-34.28211374284298, 29.395120547374745, -4.335934120098317],
+    [-33.515285862287406, 28.328160365662203, -3.9707178374369327],
+    [-33.22791218546304, 27.647370988664775, -3.664481528451294],
+    [-33.36926031261866, 27.291808522548152, -3.398570189016394],
+    [-33.922503535047504, 27.225044246076035, -3.1566799510505223],
+    [-34.89520464323888, 27.421563346964504, -2.920072099605884],
+    [-36.30290306989218, 27.841502550848162, -2.6582467676708443],
+    [-38.09141251135902, 28.33327171950841, -2.2985241604748263],
+    [-39.54782341465398, 27.990411392274897, -1.5540438622995887],
+    [-1.7814255356714104, -14.573699362886085, 10.24291328029281],
+    [-69.03175034261416, 64.05186178452404, -12.232854037808215],
+    [-52.11427876002803, 48.15612113207169, -8.845470250614246],
+    [-43.76176912584033, 40.217741238637444, -7.108957393212253],
+    [-38.81558052515176, 35.42163529944127, -6.018770424967935],
+    [-35.66101635033727, 32.25914315031353, -5.258602785892323],
+    [-33.618193932679766, 30.0925729450201, -4.693349685601635],
+    [-32.35259688314185, 28.60489229407972, -4.254234018011698],
+    [-31.686393456239824, 27.623131895094204, -3.901698771937271],
+    [-31.52536540883151, 27.049214249142683, -3.610531659026164],
+    [-31.827875758365227, 26.829274546300855, -3.362723691901805],
+    [-32.59213616668363, 26.938698332618227, -3.143040453788872],
+    [-33.85237049668075, 27.372634481472947, -2.934549025807798],
+    [-35.67488300503266, 28.129722721129014, -2.7099318649036856],
+    [-38.11186850225238, 29.13694695684647, -2.4025288625631993],
+    [-40.709116683113116, 29.67203974961976, -1.738326374185752],
+    [2.6432703386304017, -20.122960430089325, 12.313269320671184],
+    [-66.24415799094669, 62.96372636125634, -12.124357204599837],
+    [-49.2522623284914, 46.89572723811054, -8.702376206878933],
+    [-40.84707253004147, 38.88913237938715, -6.955211554613125],
+    [-35.86736033981636, 34.06700920076656, -5.86286134401602],
+    [-32.70963798880513, 30.912056927174085, -5.106112411663611],
+    [-30.702355653794218, 28.786079786638556, -4.549067919913851],
+    [-29.516536786231857, 27.37332270478034, -4.1226885206710655],
+    [-28.97869804974804, 26.503172419199938, -3.7873904712226296],
+    [-28.999983990628348, 26.081909085089478, -3.518197501856804],
+    [-29.54830908242737, 26.06407921615562, -3.2977725534797497],
+    [-30.64094764038158, 26.441383316323105, -3.1123256526996035],
+    [-32.35117959021319, 27.2410204643418, -2.947797927397884],
+    [-34.828601215863415, 28.527582337324283, -2.782625670413023],
+    [-38.31980764839102, 30.37748229934641, -2.562552864001633],
+    [-42.91126616466265, 32.47254565944075, -2.0454860667113226],
+    [8.372934818217828, -28.255333048051106, 15.618287817221818],
+    [-61.826097270344476, 61.083280473455176, -11.921418001926105],
+    [-44.11383477585006, 44.39860440649526, -8.39758475503614],
+    [-34.97284092498499, 35.91035469918575, -6.585149759783528],
+    [-29.30653278622956, 30.688200633382845, -5.4451945360408605],
+    [-25.607351217504558, 27.24659987275662, -4.659388648818224],
+    [-23.269908131486165, 24.97472077678247, -4.093477515701054],
+    [-21.99723761126767, 23.56912232647247, -3.678515648456538],
+    [-21.62332121667148, 22.860745389260437, -3.3738953388021566],
+    [-22.053839138843585, 22.753408676299316, -3.1532958078876003],
+    [-23.243703693681013, 23.198904320521592, -2.9980869974512516],
+    [-25.196486938305988, 24.191439198693956, -2.8937608134987953],
+    [-27.99661067683943, 25.78458716381249, -2.8279478849022808],
+    [-31.89251661843025, 28.139902528167287, -2.7874790127866866],
+    [-37.48687286539082, 31.63233151139058, -2.744253147777675],
+    [-46.19753979586542, 36.994024043158, -2.543799155020362],
+    [18.632929189915302, -44.75733175033313, 22.809128349773843],
+    [-39.96297101566408, 49.02381217511075, -10.287812864810714],
+    [-3.4378009374522702, 21.635072866839327, -5.359026197497471],
+    [17.20026477805878, 7.331043916100811, -2.9075860942409633],
+    [29.905922487515415, -1.0241976851947523, -1.503314078824739],
+    [38.33731734365129, -6.3024609368314986, -0.6178995127843346],
+    [44.43937534880769, -9.90657294167452, -0.008442917810943746],
+    [49.38070989274964, -12.61945892468774, 0.4537856821027195],
+    [53.89955301295962, -14.899351084766527, 0.8410451798327757],
+    [58.503439442136866, -17.029141055146802, 1.196274521735309],
+    [63.63790542304889, -19.221267670445727, 1.5501240328215857],
+    [69.83853847350598, -21.693547549180387, 1.9321039879402484],
+    [77.9495416486954, -24.75479275334735, 2.3818460199878326],
+    [89.63727716374972, -28.984116181331157, 2.9711892616057107],
+    [109.14122052703858, -35.824938194941886, 3.875836126994874],
+    [153.25536851877501, -50.95144853242261, 5.778263807164435],
+    [4447.8789243735955, -2217.8734243757867, 4605.79519831759],
+    [-1786.5243308589224, 600.4827580148847, -71.00158790133369],
+    [-328.53071205772943, -60.63653643660213, -5.591482328969194],
+    [56.39283739345838, -207.89177975423792, 1.1481334131255307],
+    [249.89764916350876, -281.5797916833943, 3.069810590917877],
+    [305.17834339906034, -300.1968442637133, 3.424453406674359],
+    [316.3735571306807, -300.82412079295847, 3.419538873111306],
+    [317.9378244952915, -297.66708488213163, 3.3401602135735153],
+    [317.9476840341782, -294.086644802162, 3.2507021172252353],
+    [315.382511114692, -289.6089366397921, 3.139868386528529],
+    [313.2158106429567, -285.4848569937442, 3.0330069082507296],
+    [309.30221992955103, -280.76684798945337, 2.908239419014403],
+    [307.799445587658, -277.2516500955026, 2.8069218585865174],
+    [303.94062187571393, -272.83822940259876, 2.6783003063606032],
+    [300.0786199977615, -268.5384370882974, 2.5453526894764233],
+    [297.97285586535037, -265.1236660404403, 2.4298462471274522],
+    [295.16470271674655, -261.49474893604287, 2.301600554176886],
+    [-624.0907456456451, 145.31841724219282, -28.989594080586873],
+    [-133.61925431738462, -90.04246357944837, -5.572661960623365],
+    [68.90787416325004, -175.79940944668596, -0.17329187573641963],
+    [178.00583325258197, -217.3231928653103, 0.8419293837508341],
+    [241.74956891669615, -242.28410049438693, 1.680400779857395],
+    [254.78143907117894, -245.4438219647253, 1.7607822975361294],
+    [260.80178246592504, -245.66630618550346, 1.7379523265696781],
+    [262.87123183885194, -244.24173693553834, 1.6554698073456864],
+    [263.6701465347109, -242.34924723270626, 1.5548213196441225],
+    [263.46398715639816, -240.09724489022926, 1.4400020305250236],
+    [262.0765829699323, -237.3960999161426, 1.3084173078443255],
+    [260.2766987820458, -234.59664919567894, 1.1754470128815095],
+    [259.66477614976026, -232.42749608443913, 1.0698243154188933],
+    [257.51496421490504, -229.64482732537078, 0.9466268227310367],
+    [255.39681478438317, -226.96074164315542, 0.8331476471114252],
+    [254.34583127391682, -224.83712710047683, 0.7438565495141016],
+    [-364.1789477245061, 62.34937694687451, -22.83689341166235],
+    [-93.47860419594448, -72.86834793554326, -8.875644346904938],
+    [58.246277412946796, -146.31691603208253, -2.1379743100025532],
+    [138.89947899291462, -180.07949253757843, -0.41591060700881755],
+    [190.53605158299993, -200.07249851299008, 0.09207260592156208],
+    [212.82323867902969, -207.9043545412787, 0.26634023276222557],
+    [221.07472567250232, -209.84495110856957, 0.2978294803681947],
+    [225.96135453218216, -210.41234696813143, 0.2957561150259003],
+    [227.7441676549659, -209.6891307641107, 0.2616297951610558],
+    [228.49858985933037, -208.57195197610702, 0.2183912417620644],
+    [229.1966280788256, -207.4817299703627, 0.1766150890189265],
+    [228.63150083309026, -205.8830255092278, 0.12261367072028694],
+    [228.03975889295447, -204.3168675504188, 0.0697835952452206],
+    [227.9652986005569, -203.0210023937415, 0.023930617080236666],
+    [226.85659892163423, -201.30636939389416, -0.032861940738210665],
+    [225.74717886578355, -199.6259485382132, -0.0894518570817062],
+    [-272.9834923073771, 45.41697886701481, -22.682009190242642],
+    [-91.71923340435713, -47.826152559247845, -12.69422165915086],
+    [25.177426135633564, -107.43024008063664, -6.610834338101757],
+    [102.14607047694265, -145.71225906532308, -3.001109394254723],
+    [151.62217248737593, -168.5888976820589, -1.2808109229947202],
+    [181.8271182090908, -181.09724325322208, -0.6838940683366351],
+    [191.84678946993512, -184.319072174959, -0.5798288010289461],
+    [198.2572309952588, -185.9791716762224, -0.5375154541373435],
+    [201.60503859151666, -186.31307178679006, -0.5427310361046247],
+    [203.41911730373795, -185.99293374875884, -0.5714043082023267],
+    [204.0339395387309, -185.15925490176505, -0.6201012684663116],
+    [204.89036058232753, -184.46310478353809, -0.6648219427529533],
+    [204.6322765842259, -183.27613433232798, -0.7318211820279992],
+    [204.43174900621074, -182.1342781081274, -0.8002629958238342],
+    [204.4663026732388, -181.1210662451876, -0.8659789850043454],
+    [203.732642507878, -179.75888515944595, -0.9526004612150999],
+    [-235.8328922255671, 47.48957436973748, -24.133266870669768],
+    [-101.46328323577815, -23.175951016700864, -16.32942763646799],
+    [-7.827099852445436, -72.2436120941929, -11.064083213214898],
+    [59.079625556229296, -107.02941993093741, -7.446995845498704],
+    [107.60407193882381, -131.90668015287648, -4.958040075959158],
+    [142.86899364455996, -149.55199034985412, -3.2883628829694738],
+    [163.58957227209189, -159.29273217048785, -2.445667901883988],
+    [171.41922198799173, -162.2156592332567, -2.230688251071074],
+    [176.9640864857336, -163.9846896465086, -2.1152453049263653],
+    [179.88839397043637, -164.42299968624042, -2.109431092454893],
+    [181.54757537399934, -164.2307833638279, -2.1548978603094833],
+    [182.19517653125516, -163.53223327157536, -2.2427317754075315],
+    [182.89795825117977, -162.885809511526, -2.326569010863486],
+    [182.79884666356753, -161.83170400805693, -2.446585972220585],
+    [182.51734005269861, -160.69714383294132, -2.575135454703609],
+    [182.53486483780267, -159.74236020063046, -2.6886319997889303],
+    [-220.56923659384276, 55.93251277551082, -26.040097469687794],
+    [-114.57039397028642, -0.7866921922699731, -19.618396880378103],
+    [-36.69567924061454, -42.39519844504341, -14.998976995886844],
+    [21.704680805797576, -73.48221668133381, -11.614063358863758],
+    [66.1589814696823, -96.99677036870432, -9.103637875569827],
+    [100.3193577360738, -114.8966222524979, -7.231659463744435],
+    [126.61461602518261, -128.48895880944406, -5.841691253976426],
+    [143.19780143910057, -136.71039968156788, -5.0270844266541275],
+    [150.28071488017534, -139.66824356802425, -4.75694256086318],
+    [155.40263389787094, -141.56954207825245, -4.593735526873462],
+    [158.40515069734562, -142.31727840392733, -4.54665297507804],
+    [160.0104191398571, -142.30693878011883, -4.57562136414359],
+    [161.1217263471689, -142.04174889572423, -4.629716320761791],
+    [161.68453746070307, -141.48820306111173, -4.7126444113280535],
+    [162.05969427510414, -140.84743348694562, -4.804038191164899],
+    [161.88581891477517, -139.91327795537526, -4.925338562562825],
+    [-215.3829683536747, 66.41776966179438, -28.019150280063773],
+    [-128.2110935480038, 19.112358117859564, -22.551630480316128],
+    [-61.624896168477235, -17.006423956013712, -18.436974748111204],
+    [-9.903340528083396, -45.00881546125461, -15.290657701623525],
+    [30.795977245922554, -66.9668233086673, -12.855992499721326],
+    [63.12824546843181, -84.31868080404746, -10.956668463614811],
+    [88.96516290620092, -98.08223334776658, -9.469076419248857],
+    [109.61594302754992, -108.97104108627423, -8.307033712041122],
+    [124.35076629614528, -116.54122497062184, -7.5124898337744295],
+    [131.13581360267702, -119.60182641403493, -7.20784896910986],
+    [135.94130818663206, -121.55949188692583, -7.021425258785576],
+    [139.1508835316978, -122.62801365462498, -6.929862866758047],
+    [141.1630692680386, -123.02847645660215, -6.909493847530684],
+    [142.54689353772, -123.08508885443266, -6.925470477826437],
+    [143.21355437812392, -122.74399715146033, -6.983822226670388],
+    [143.83736501388123, -122.39332442361263, -7.042633738128613],
+    [-215.18850038136526, 77.26936929535228, -29.930050111761656],
+    [-141.34531327879643, 36.723989338612526, -25.16158408632949],
+    [-83.22061853480442, 4.803712990501851, -21.449326086574416],
+    [-36.82793993172782, -20.651148280228682, -18.51995191315723],
+    [0.6165058625086894, -41.155094212195756, -16.183572190317754],
+    [31.104339889745837, -57.79596783771411, -14.305034126290801],
+    [56.08847299368361, -71.37024007943175, -12.786245392322943],
+    [76.64217985654092, -82.46803508519568, -11.555100820706972],
+    [93.55030795743104, -91.52140057303953, -10.559098712125177],
+    [107.11734238923572, -98.68924553055781, -9.7775786382052],
+    [114.21153188275156, -102.12735916677119, -9.414369283136637],
+    [118.84529219022093, -104.15961308590825, -9.20743100695297],
+    [122.42003084160805, -105.59610841025156, -9.066095324536464],
+    [124.72808162727792, -106.31016539603313, -9.004612414040722],
+    [126.18197270684249, -106.53875659791707, -8.996682637989652],
+    [127.29866883523535, -106.58296806233024, -9.008758490643126],
+    [-217.55414050354813, 87.80595766604029, -31.722553980272615],
+    [-153.6080303300763, 52.34141458219374, -27.488876120662777],
+    [-102.05564625713947, 23.73495391776869, -24.104457512238614],
+    [-60.004483607356214, 0.4067841028496605, -21.36750814539315],
+    [-25.369853261244476, -18.78596488548283, -19.133225143827573],
+    [3.382254320836808, -34.687285917271424, -17.29552412060117],
+    [27.400626251608823, -47.931344871242345, -15.775310862435312],
+    [47.55777399749441, -59.00124493112658, -14.512777503277139],
+    [64.5221236954879, -68.26798684815762, -13.4623078246524],
+    [78.80209135429486, -76.01402529090954, -12.589360258846913],
+    [90.73338916329902, -82.42407201137507, -11.871237844323383],
+    [98.96542624625366, -86.6889995108675, -11.39951212183195],
+    [103.68238305333648, -88.90403347689106, -11.162023222398847],
+    [107.32914330550017, -90.50529083936397, -10.994013262258914],
+    [110.01647249568808, -91.55534924778493, -10.888306223262546],
+    [111.88298061482006, -92.131939136456, -10.836157585346193],
+    [-221.2299148063195, 97.75822748942595, -33.38284662375795],
+    [-164.90609067108142, 66.2481287059566, -29.571982379159063],
+    [-118.60427544890388, 40.32460563782269, -26.45987107844239],
+    [-80.15697836499966, 18.79540147962978, -23.892954573770933],
+    [-47.960589438920394, 0.7754398468393617, -21.758045889207338],
+    [-20.80717786132344, -14.404149504771393, -19.970205150620025],
+    [2.22742514968277, -27.256699738944473, -18.464689605643326],
+    [21.859764065492737, -38.18137167129883, -17.191489713500648],
+    [38.6513961267176, -47.49180217512583, -16.11156047393039],
+    [53.0453575045021, -55.43595559205041, -15.194215869398288],
+    [65.38997111941896, -62.20898698998111, -14.415428479662298],
+    [75.94510299482705, -67.95600965560433, -13.75736682330452],
+    [84.70955049826223, -72.66832469012215, -13.220407734340178],
+    [90.01562363527499, -75.34110863292659, -12.92129979148467],
+    [93.72821787421186, -77.08050532375795, -12.730400758770868],
+    [96.6968671984343, -78.38896071077357, -12.589144946624764],
+    [-225.54003159057393, 107.03800197549788, -34.9123298550395],
+    [-175.25800806753134, 78.69159637035911, -31.44450712427465],
+    [-133.24690149582014, 54.985706160654004, -28.56234323413717],
+    [-97.83926280805467, 34.99731968277008, -26.146061117018604],
+    [-67.77387886915443, 18.026141914679506, -24.105355623457523],
+    [-42.08172101947515, 3.5324985114533916, -22.371065093971477],
+    [-20.007894930656953, -8.905244679134656, -20.88948303902662],
+    [-0.9571386828335468, -19.620476554909903, -19.618394311378214],
+    [15.54487833029173, -28.879539155655706, -18.524266504498243],
+    [29.87936753027679, -36.89698496025873, -17.580235957987497],
+    [42.3544331175413, -43.846557234174824, -16.764661621636133],
+    [53.218942107823736, -49.86876212052911, -16.060124494676483],
+    [62.66962054867086, -55.074563339377825, -15.452915337045539],
+    [70.83699353469812, -59.53647042742539, -14.934036422411157],
+    [77.34567999039879, -63.023046931491976, -14.53063219732308],
+    [81.39103461582317, -65.04226626277368, -14.300743617624871],
+    [-230.10660052438283, 115.6387575315713, -36.31859460738377],
+    [-184.72618524129683, 89.88031758823178, -33.13494944812115],
+    [-146.28708825221656, 68.03907644396243, -30.449706269436515],
+    [-113.47822299241801, 49.385159200477716, -28.167499739262183],
+    [-85.28899324125544, 33.35438021101878, -26.215027290389802],
+    [-60.929585586273355, 19.504586774085784, -24.5351595704392],
+    [-39.7750919996286, 7.484999840033527, -23.082819229613666],
+    [-21.325620859165916, -2.9857136148768664, -21.822044555378127],
+    [-5.177369820454782, -12.135372111057595, -20.72386422888081],
+    [8.99863016621709, -20.1500068928565, -19.76473877675434],
+    [21.472283298405507, -27.182573539826645, -18.92540671362292],
+    [32.46614818280423, -33.35942865441865, -18.19002874554542],
+    [42.16396441655467, -38.78502795938206, -17.54556648893762],
+    [50.716116200815875, -43.54487967316212, -16.981385614762512],
+    [58.2398726270978, -47.70540817649686, -16.489240702262727],
+    [64.79227613325419, -51.29725479897018, -16.06525688353719],
+    [-234.71426253731738, 123.59022566761026, -37.611405314053755],
+    [-193.3871973206759, 99.98789126593796, -34.667238809245006],
+    [-157.9681364302561, 79.737117956331, -32.1526652143279],
+    [-127.40701166255941, 62.25032733247384, -29.990427714422655],
+    [-100.88105850402354, 47.06592947625551, -28.120142077477944],
+    [-77.73765544491964, 33.816845978034344, -26.494027296102317],
+    [-57.45340384163151, 22.20794019645856, -25.073854490788797],
+    [-39.60435233504277, 11.9995665677876, -23.828730703991862],
+    [-23.84401546854735, 2.995405040791162, -22.73346576110969],
+    [-9.886939130161542, -4.966650217643212, -21.767353455876464],
+    [2.503719620445175, -12.021387554560715, -20.91325213256588],
+    [13.525654248154366, -18.281538556905968, -20.15688556928801],
+    [23.3446365824009, -23.841798799091503, -19.486309964815074],
+    [32.09997926280597, -28.781861790399432, -18.891512011623313],
+    [39.908434891551835, -33.16856557960267, -18.36412295542909],
+    [46.8662054773489, -37.05693439501235, -17.89727290888085],
+    [-239.23983698753938, 130.93689465278285, -38.80088646191861],
+    [-201.31868442023594, 109.15858088825499, -36.0614381363435],
+    [-168.48657127365826, 90.28099453436452, -33.69631450561129],
+    [-139.8891078382828, 73.82380361956747, -31.64199301260631],
+    [-114.8471870139633, 59.4042946985141, -29.84809355341415],
+    [-92.81506978335554, 46.71417700052039, -28.274216630458454],
+    [-73.34982113642505, 35.502643829583015, -26.88764147971248],
+    [-56.088692343141325, 25.563818684877493, -25.661625475702976],
+    [-40.73216153216264, 16.727327045661223, -24.574131097270726],
+    [-27.03106589544168, 8.851131276478089, -23.606861632414756],
+    [-14.776735421733676, 1.8160281553625908, -22.744523044343033],
+    [-3.793359480995395, -4.478614629409757, -21.974253838955075],
+    [6.067960495703492, -10.118184353828644, -21.28518162099404],
+    [14.933860781154497, -15.175425327759339, -20.6680770203293],
+    [22.91233501884251, -19.712463267238068, -20.115084848557544],
+    [30.09547939240976, -23.782334154926037, -19.619520797369013],
+    [-243.6139842461937, 137.72765542774, -39.89672143291378],
+    [-208.59378394733028, 117.51259029062435, -37.33441117742541],
+    [-178.00256090547344, 99.83302315257914, -35.10133124845573],
+    [-151.1355799640998, 84.29104520397425, -33.14457629047929],
+    [-127.42555175332886, 70.56563079628219, -31.421642626743512],
+    [-106.41129518601753, 58.395215664420256, -29.898042463423515],
+    [-87.71450898867863, 47.564766719425776, -28.54553818856288],
+    [-71.02232284972598, 37.89605568669674, -27.340818069107062],
+    [-56.074031328075144, 29.240253590202688, -26.264490966824845],
+    [-42.65089720726891, 21.472230208587682, -25.300314844020043],
+    [-30.568236298676485, 14.486122357736486, -24.434598803884313],
+    [-19.66921853194425, 8.191858006087363, -23.655735500448834],
+    [-9.819975490082731, 2.512409141464714, -22.953832657973052],
+    [-0.9057147623856033, -2.6183926605636665, -22.32042087234234],
+    [7.172378159140771, -7.257599363738787, -21.74822101660266],
+    [14.499608431031414, -11.45453542411246, -21.230959252273784],
+    [-247.79949367217415, 144.0108521468134, -40.90782956521508],
+    [-215.2790601762876, 125.15055743586295, -38.500395736595465],
+    [-186.6478363802581, 108.52568331742562, -36.384896323600614],
+    [-161.3175463611428, 93.80275044194843, -34.51677067231691],
+    [-138.8092827136532, 80.70970637073546, -32.859806744132975],
+    [-118.72967309342552, 69.02261939478214, -31.384311026158606],
+    [-100.75295344763681, 58.55565849071543, -30.065734033128514],
+    [-84.60731938135599, 49.153454983391676, -28.883625369349602],
+    [-70.06441947844435, 40.685214813966944, -27.820830514170087],
+    [-56.93118546591194, 33.04013405134078, -26.862867155257735],
+    [-45.04342073146874, 26.123796065246317, -25.99743644654311],
+    [-34.260726292735505, 19.855316254349678, -25.214036761964383],
+    [-24.46245452744091, 14.165061975678277, -24.50365611191246],
+    [-15.544460626262259, 8.992819587611145, -23.858525542240763],
+    [-7.4164796478187975, 4.286312740805392, -23.271920303688614],
+    [-2.043379347578331e-10, 1.8586159160817463e-10, -22.737998889246587],
+    [-1786.5243308589224, 600.4827580148847, -71.00158790133369],
+    [-328.53071205772943, -60.63653643660213, -5.591482328969194],
+    [56.39283739345838, -207.89177975423792, 1.1481334131255307],
+    [249.89764916350876, -281.5797916833943, 3.069810590917877],
+    [305.17834339906034, -300.1968442637133, 3.424453406674359],
+    [316.3735571306807, -300.82412079295847, 3.419538873111306],
+    [317.9378244952915, -297.66708488213163, 3.3401602135735153],
+    [317.9476840341782, -294.086644802162, 3.2507021172252353],
+    [315.382511114692, -289.6089366397921, 3.139868386528529],
+    [313.2158106429567, -285.4848569937442, 3.0330069082507296],
+    [309.30221992955103, -280.76684798945337, 2.908239419014403],
+    [307.799445587658, -277.2516500955026, 2.8069218585865174],
+    [303.94062187571393, -272.83822940259876, 2.6783003063606032],
+    [300.0786199977615, -268.5384370882974, 2.5453526894764233],
+    [297.97285586535037, -265.1236660404403, 2.4298462471274522],
+    [295.16470271674655, -261.49474893604287, 2.301600554176886],
+    [-624.0907456456451, 145.31841724219282, -28.989594080586873],
+    [-133.61925431738462, -90.04246357944837, -5.572661960623365],
+    [68.90787416325004, -175.79940944668596, -0.17329187573641963],
+    [178.00583325258197, -217.3231928653103, 0.8419293837508341],
+    [241.74956891669615, -242.28410049438693, 1.680400779857395],
+    [254.78143907117894, -245.4438219647253, 1.7607822975361294],
+    [260.80178246592504, -245.66630618550346, 1.7379523265696781],
+    [262.87123183885194, -244.24173693553834, 1.6554698073456864],
+    [263.6701465347109, -242.34924723270626, 1.5548213196441225],
+    [263.46398715639816, -240.09724489022926, 1.4400020305250236],
+    [262.0765829699323, -237.3960999161426, 1.3084173078443255],
+    [260.2766987820458, -234.59664919567894, 1.1754470128815095],
+    [259.66477614976026, -232.42749608443913, 1.0698243154188933],
+    [257.51496421490504, -229.64482732537078, 0.9466268227310367],
+    [255.39681478438317, -226.96074164315542, 0.8331476471114252],
+    [254.34583127391682, -224.83712710047683, 0.7438565495141016],
+    [-364.1789477245061, 62.34937694687451, -22.83689341166235],
+    [-93.47860419594448, -72.86834793554326, -8.875644346904938],
+    [58.246277412946796, -146.31691603208253, -2.1379743100025532],
+    [138.89947899291462, -180.07949253757843, -0.41591060700881755],
+    [190.53605158299993, -200.07249851299008, 0.09207260592156208],
+    [212.82323867902969, -207.9043545412787, 0.26634023276222557],
+    [221.07472567250232, -209.84495110856957, 0.2978294803681947],
+    [225.96135453218216, -210.41234696813143, 0.2957561150259003],
+    [227.7441676549659, -209.6891307641107, 0.2616297951610558],
+    [228.49858985933037, -208.57195197610702, 0.2183912417620644],
+    [229.1966280788256, -207.4817299703627, 0.1766150890189265],
+    [228.63150083309026, -205.8830255092278, 0.12261367072028694],
+    [228.03975889295447, -204.3168675504188, 0.0697835952452206],
+    [227.9652986005569, -203.0210023937415, 0.023930617080236666],
+    [226.85659892163423, -201.30636939389416, -0.032861940738210665],
+    [225.74717886578355, -199.6259485382132, -0.0894518570817062],
+    [-272.9834923073771, 45.41697886701481, -22.682009190242642],
+    [-91.71923340435713, -47.826152559247845, -12.69422165915086],
+    [25.177426135633564, -107.43024008063664, -6.610834338101757],
+    [102.14607047694265, -145.71225906532308, -3.001109394254723],
+    [151.62217248737593, -168.5888976820589, -1.2808109229947202],
+    [181.8271182090908, -181.09724325322208, -0.6838940683366351],
+    [191.84678946993512, -184.319072174959, -0.5798288010289461],
+    [198.2572309952588, -185.9791716762224, -0.5375154541373435],
+    [201.60503859151666, -186.31307178679006, -0.5427310361046247],
+    [203.41911730373795, -185.99293374875884, -0.5714043082023267],
+    [204.0339395387309, -185.15925490176505, -0.6201012684663116],
+    [204.89036058232753, -184.46310478353809, -0.6648219427529533],
+    [204.6322765842259, -183.27613433232798, -0.7318211820279992],
+    [204.43174900621074, -182.1342781081274, -0.8002629958238342],
+    [204.4663026732388, -181.1210662451876, -0.8659789850043454],
+    [203.732642507878, -179.75888515944595, -0.9526004612150999],
+    [-235.8328922255671, 47.48957436973748, -24.133266870669768],
+    [-101.46328323577815, -23.175951016700864, -16.32942763646799],
+    [-7.827099852445436, -72.2436120941929, -11.064083213214898],
+    [59.079625556229296, -107.02941993093741, -7.446995845498704],
+    [107.60407193882381, -131.90668015287648, -4.958040075959158],
+    [142.86899364455996, -149.55199034985412, -3.2883628829694738],
+    [163.58957227209189, -159.29273217048785, -2.445667901883988],
+    [171.41922198799173, -162.2156592332567, -2.230688251071074],
+    [176.9640864857336, -163.9846896465086, -2.1152453049263653],
+    [179.88839397043637, -164.42299968624042, -2.109431092454893],
+    [181.54757537399934, -164.2307833638279, -2.1548978603094833],
+    [182.19517653125516, -163.53223327157536, -2.2427317754075315],
+    [182.89795825117977, -162.885809511526, -2.326569010863486],
+    [182.79884666356753, -161.83170400805693, -2.446585972220585],
+    [182.51734005269861, -160.69714383294132, -2.575135454703609],
+    [182.53486483780267, -159.74236020063046, -2.6886319997889303],
+    [-220.56923659384276, 55.93251277551082, -26.040097469687794],
+    [-114.57039397028642, -0.7866921922699731, -19.618396880378103],
+    [-36.69567924061454, -42.39519844504341, -14.998976995886844],
+    [21.704680805797576, -73.48221668133381, -11.614063358863758],
+    [66.1589814696823, -96.99677036870432, -9.103637875569827],
+    [100.3193577360738, -114.8966222524979, -7.231659463744435],
+    [126.61461602518261, -128.48895880944406, -5.841691253976426],
+    [143.19780143910057, -136.71039968156788, -5.0270844266541275],
+    [150.28071488017534, -139.66824356802425, -4.75694256086318],
+    [155.40263389787094, -141.56954207825245, -4.593735526873462],
+    [158.40515069734562, -142.31727840392733, -4.54665297507804],
+    [160.0104191398571, -142.30693878011883, -4.57562136414359],
+    [161.1217263471689, -142.04174889572423, -4.629716320761791],
+    [161.68453746070307, -141.48820306111173, -4.7126444113280535],
+    [162.05969427510414, -140.84743348694562, -4.804038191164899],
+    [161.88581891477517, -139.91327795537526, -4.925338562562825],
+    [-215.3829683536747, 66.41776966179438, -28.019150280063773],
+    [-128.2110935480038, 19.112358117859564, -22.551630480316128],
+    [-61.624896168477235, -17.006423956013712, -18.436974748111204],
+    [-9.903340528083396, -45.00881546125461, -15.290657701623525],
+    [30.795977245922554, -66.9668233086673, -12.855992499721326],
+    [63.12824546843181, -84.31868080404746, -10.956668463614811],
+    [88.96516290620092, -98.08223334776658, -9.469076419248857],
+    [109.61594302754992, -108.97104108627423, -8.307033712041122],
+    [124.35076629614528, -116.54122497062184, -7.5124898337744295],
+    [131.13581360267702, -119.60182641403493, -7.20784896910986],
+    [135.94130818663206, -121.55949188692583, -7.021425258785576],
+    [139.1508835316978, -122.62801365462498, -6.929862866758047],
+    [141.1630692680386, -123.02847645660215, -6.909493847530684],
+    [142.54689353772, -123.08508885443266, -6.925470477826437],
+    [143.21355437812392, -122.74399715146033, -6.983822226670388],
+    [143.83736501388123, -122.39332442361263, -7.042633738128613],
+    [-215.18850038136526, 77.26936929535228, -29.930050111761656],
+    [-141.34531327879643, 36.723989338612526, -25.16158408632949],
+    [-83.22061853480442, 4.803712990501851, -21.449326086574416],
+    [-36.82793993172782, -20.651148280228682, -18.51995191315723],
+    [0.6165058625086894, -41.155094212195756, -16.183572190317754],
+    [31.104339889745837, -57.79596783771411, -14.305034126290801],
+    [56.08847299368361, -71.37024007943175, -12.786245392322943],
+    [76.64217985654092, -82.46803508519568, -11.555100820706972],
+    [93.55030795743104, -91.52140057303953, -10.559098712125177],
+    [107.11734238923572, -98.68924553055781, -9.7775786382052],
+    [114.21153188275156, -102.12735916677119, -9.414369283136637],
+    [118.84529219022093, -104.15961308590825, -9.20743100695297],
+    [122.42003084160805, -105.59610841025156, -9.066095324536464],
+    [124.72808162727792, -106.31016539603313, -9.004612414040722],
+    [126.18197270684249, -106.53875659791707, -8.996682637989652],
+    [127.29866883523535, -106.58296806233024, -9.008758490643126],
+    [-217.55414050354813, 87.80595766604029, -31.722553980272615],
+    [-153.6080303300763, 52.34141458219374, -27.488876120662777],
+    [-102.05564625713947, 23.73495391776869, -24.104457512238614],
+    [-60.004483607356214, 0.4067841028496605, -21.36750814539315],
+    [-25.369853261244476, -18.78596488548283, -19.133225143827573],
+    [3.382254320836808, -34.687285917271424, -17.29552412060117],
+    [27.400626251608823, -47.931344871242345, -15.775310862435312],
+    [47.55777399749441, -59.00124493112658, -14.512777503277139],
+    [64.5221236954879, -68.26798684815762, -13.4623078246524],
+    [78.80209135429486, -76.01402529090954, -12.589360258846913],
+    [90.73338916329902, -82.42407201137507, -11.871237844323383],
+    [98.96542624625366, -86.6889995108675, -11.39951212183195],
+    [103.68238305333648, -88.90403347689106, -11.162023222398847],
+    [107.32914330550017, -90.50529083936397, -10.994013262258914],
+    [110.01647249568808, -91.55534924778493, -10.888306223262546],
+    [111.88298061482006, -92.131939136456, -10.836157585346193],
+    [-221.2299148063195, 97.75822748942595, -33.38284662375795],
+    [-164.90609067108142, 66.2481287059566, -29.571982379159063],
+    [-118.60427544890388, 40.32460563782269, -26.45987107844239],
+    [-80.15697836499966, 18.79540147962978, -23.892954573770933],
+    [-47.960589438920394, 0.7754398468393617, -21.758045889207338],
+    [-20.80717786132344, -14.404149504771393, -19.970205150620025],
+    [2.22742514968277, -27.256699738944473, -18.464689605643326],
+    [21.859764065492737, -38.18137167129883, -17.191489713500648],
+    [38.6513961267176, -47.49180217512583, -16.11156047393039],
+    [53.0453575045021, -55.43595559205041, -15.194215869398288],
+    [65.38997111941896, -62.20898698998111, -14.415428479662298],
+    [75.94510299482705, -67.95600965560433, -13.75736682330452],
+    [84.70955049826223, -72.66832469012215, -13.220407734340178],
+    [90.01562363527499, -75.34110863292659, -12.92129979148467],
+    [93.72821787421186, -77.08050532375795, -12.730400758770868],
+    [96.6968671984343, -78.38896071077357, -12.589144946624764],
+    [-225.54003159057393, 107.03800197549788, -34.9123298550395],
+    [-175.25800806753134, 78.69159637035911, -31.44450712427465],
+    [-133.24690149582014, 54.985706160654004, -28.56234323413717],
+    [-97.83926280805467, 34.99731968277008, -26.146061117018604],
+    [-67.77387886915443, 18.026141914679506, -24.105355623457523],
+    [-42.08172101947515, 3.5324985114533916, -22.371065093971477],
+    [-20.007894930656953, -8.905244679134656, -20.88948303902662],
+    [-0.9571386828335468, -19.620476554909903, -19.618394311378214],
+    [15.54487833029173, -28.879539155655706, -18.524266504498243],
+    [29.87936753027679, -36.89698496025873, -17.580235957987497],
+    [42.3544331175413, -43.846557234174824, -16.764661621636133],
+    [53.218942107823736, -49.86876212052911, -16.060124494676483],
+    [62.66962054867086, -55.074563339377825, -15.452915337045539],
+    [70.83699353469812, -59.53647042742539, -14.934036422411157],
+    [77.34567999039879, -63.023046931491976, -14.53063219732308],
+    [81.39103461582317, -65.04226626277368, -14.300743617624871],
+    [-230.10660052438283, 115.6387575315713, -36.31859460738377],
+    [-184.72618524129683, 89.88031758823178, -33.13494944812115],
+    [-146.28708825221656, 68.03907644396243, -30.449706269436515],
+    [-113.47822299241801, 49.385159200477716, -28.167499739262183],
+    [-85.28899324125544, 33.35438021101878, -26.215027290389802],
+    [-60.929585586273355, 19.504586774085784, -24.5351595704392],
+    [-39.7750919996286, 7.484999840033527, -23.082819229613666],
+    [-21.325620859165916, -2.9857136148768664, -21.822044555378127],
+    [-5.177369820454782, -12.135372111057595, -20.72386422888081],
+    [8.99863016621709, -20.1500068928565, -19.76473877675434],
+    [21.472283298405507, -27.182573539826645, -18.92540671362292],
+    [32.46614818280423, -33.35942865441865, -18.19002874554542],
+    [42.16396441655467, -38.78502795938206, -17.54556648893762],
+    [50.716116200815875, -43.54487967316212, -16.981385614762512],
+    [58.2398726270978, -47.70540817649686, -16.489240702262727],
+    [64.79227613325419, -51.29725479897018, -16.06525688353719],
+    [-234.71426253731738, 123.59022566761026, -37.611405314053755],
+    [-193.3871973206759, 99.98789126593796, -34.667238809245006],
+    [-157.9681364302561, 79.737117956331, -32.1526652143279],
+    [-127.40701166255941, 62.25032733247384, -29.990427714422655],
+    [-100.88105850402354, 47.06592947625551, -28.120142077477944],
+    [-77.73765544491964, 33.816845978034344, -26.494027296102317],
+    [-57.45340384163151, 22.20794019645856, -25.073854490788797],
+    [-39.60435233504277, 11.9995665677876, -23.828730703991862],
+    [-23.84401546854735, 2.995405040791162, -22.73346576110969],
+    [-9.886939130161542, -4.966650217643212, -21.767353455876464],
+    [2.503719620445175, -12.021387554560715, -20.91325213256588],
+    [13.525654248154366, -18.281538556905968, -20.15688556928801],
+    [23.3446365824009, -23.841798799091503, -19.486309964815074],
+    [32.09997926280597, -28.781861790399432, -18.891512011623313],
+    [39.908434891551835, -33.16856557960267, -18.36412295542909],
+    [46.8662054773489, -37.05693439501235, -17.89727290888085],
+    [-239.23983698753938, 130.93689465278285, -38.80088646191861],
+    [-201.31868442023594, 109.15858088825499, -36.0614381363435],
+    [-168.48657127365826, 90.28099453436452, -33.69631450561129],
+    [-139.8891078382828, 73.82380361956747, -31.64199301260631],
+    [-114.8471870139633, 59.4042946985141, -29.84809355341415],
+    [-92.81506978335554, 46.71417700052039, -28.274216630458454],
+    [-73.34982113642505, 35.502643829583015, -26.88764147971248],
+    [-56.088692343141325, 25.563818684877493, -25.661625475702976],
+    [-40.73216153216264, 16.727327045661223, -24.574131097270726],
+    [-27.03106589544168, 8.851131276478089, -23.606861632414756],
+    [-14.776735421733676, 1.8160281553625908, -22.744523044343033],
+    [-3.793359480995395, -4.478614629409757, -21.974253838955075],
+    [6.067960495703492, -10.118184353828644, -21.28518162099404],
+    [14.933860781154497, -15.175425327759339, -20.6680770203293],
+    [22.91233501884251, -19.712463267238068, -20.115084848557544],
+    [30.09547939240976, -23.782334154926037, -19.619520797369013],
+    [-243.6139842461937, 137.72765542774, -39.89672143291378],
+    [-208.59378394733028, 117.51259029062435, -37.33441117742541],
+    [-178.00256090547344, 99.83302315257914, -35.10133124845573],
+    [-151.1355799640998, 84.29104520397425, -33.14457629047929],
+    [-127.42555175332886, 70.56563079628219, -31.421642626743512],
+    [-106.41129518601753, 58.395215664420256, -29.898042463423515],
+    [-87.71450898867863, 47.564766719425776, -28.54553818856288],
+    [-71.02232284972598, 37.89605568669674, -27.340818069107062],
+    [-56.074031328075144, 29.240253590202688, -26.264490966824845],
+    [-42.65089720726891, 21.472230208587682, -25.300314844020043],
+    [-30.568236298676485, 14.486122357736486, -24.434598803884313],
+    [-19.66921853194425, 8.191858006087363, -23.655735500448834],
+    [-9.819975490082731, 2.512409141464714, -22.953832657973052],
+    [-0.9057147623856033, -2.6183926605636665, -22.32042087234234],
+    [7.172378159140771, -7.257599363738787, -21.74822101660266],
+    [14.499608431031414, -11.45453542411246, -21.230959252273784],
+    [-247.79949367217415, 144.0108521468134, -40.90782956521508],
+    [-215.2790601762876, 125.15055743586295, -38.500395736595465],
+    [-186.6478363802581, 108.52568331742562, -36.384896323600614],
+    [-161.3175463611428, 93.80275044194843, -34.51677067231691],
+    [-138.8092827136532, 80.70970637073546, -32.859806744132975],
+    [-118.72967309342552, 69.02261939478214, -31.384311026158606],
+    [-100.75295344763681, 58.55565849071543, -30.065734033128514],
+    [-84.60731938135599, 49.153454983391676, -28.883625369349602],
+    [-70.06441947844435, 40.685214813966944, -27.820830514170087],
+    [-56.93118546591194, 33.04013405134078, -26.862867155257735],
+    [-45.04342073146874, 26.123796065246317, -25.99743644654311],
+    [-34.260726292735505, 19.855316254349678, -25.214036761964383],
+    [-24.46245452744091, 14.165061975678277, -24.50365611191246],
+    [-15.544460626262259, 8.992819587611145, -23.858525542240763],
+    [-7.4164796478187975, 4.286312740805392, -23.271920303688614],
+    [-2.043379347578331e-10, 1.8586159160817463e-10, -22.737998889246587],
+    [-474.546805294178, 159.34606352370523, -18.769112357606918],
+    [-86.30153317373204, -16.80569136314873, -1.3307177905372254],
+    [30.86990604993022, -66.69034531094545, 2.216615382424061],
+    [84.89048553636724, -89.83346658528092, 3.641732532088462],
+    [102.03616228650252, -96.5438145051682, 4.034144017553884],
+    [106.75852897725434, -97.5166591171907, 4.073525053451516],
+    [108.80952878536272, -97.30736527242777, 4.041169401291618],
+    [109.55166289734565, -96.54695070504991, 3.9755059048373482],
+    [109.56817081497583, -95.50402832137985, 3.892843126744265],
+    [109.1741410332985, -94.32268108884492, 3.801956503797303],
+    [108.60105503937211, -93.10965982185711, 3.7094556235705483],
+    [107.87101944142856, -91.86972321852735, 3.6155375248025945],
+    [107.08143525892113, -90.64637997491327, 3.5229240721166657],
+    [106.22642384718854, -89.43229271421937, 3.431084530716508],
+    [105.32712955155073, -88.23422921575366, 3.340403600466976],
+    [104.4192341022404, -87.06690310591335, 3.2518026081190023],
+    [-165.5144774895537, 38.33291592536224, -7.6012736369648835],
+    [-34.86734920915627, -24.4219402242901, -1.3480208462671521],
+    [25.10668100209275, -51.822244089982256, 0.8744032978877503],
+    [58.2659282199138, -66.60237203320176, 1.90589659155423],
+    [75.98225909677227, -74.30680180327914, 2.415479031458202],
+    [82.89348306887757, -76.89971609099473, 2.5748685583819393],
+    [86.08844275705341, -77.73269460997514, 2.616508057698315],
+    [87.89850677694425, -77.92720180125741, 2.6160019680580198],
+    [88.89749398038205, -77.75624123883158, 2.5914326242527568],
+    [89.37889496280623, -77.35916447212094, 2.5519509267492806],
+    [89.5446885588272, -76.8325771392245, 2.503929931778511],
+    [89.51909373318247, -76.23575802232276, 2.451295307312274],
+    [89.35723909364833, -75.59399347040535, 2.3956929461531837],
+    [89.09910542597426, -74.92545380394742, 2.3383143867699463],
+    [88.77331583096797, -74.24293822358119, 2.2800058184666403],
+    [88.38814811652642, -73.5490745787488, 2.2209117117315937],
+    [-96.52767968891996, 16.333225044829927, -5.972104766002036],
+    [-24.467039810298076, -19.68599170904532, -2.2493503829314405],
+    [16.530398968043944, -39.726811862756655, -0.3669020651984567],
+    [41.14704942184505, -51.294083726960835, 0.5770604885939248],
+    [56.95223797923104, -58.55712827123075, 1.126469595875415],
+    [65.68403508895786, -62.37728095070526, 1.4016499560218147],
+    [70.01587637551243, -64.03662941587754, 1.5132951176837974],
+    [72.50725672522697, -64.79800772231623, 1.5584615889952533],
+    [74.10943340657425, -65.13311582310153, 1.5724858261549433],
+    [75.14454135486315, -65.20022343539821, 1.5670249329142723],
+    [75.78821449533385, -65.08535196855189, 1.548338674367211],
+    [76.17368500809341, -64.85434360115684, 1.5212263336241485],
+    [76.38195050599994, -64.54707703672379, 1.4885844760426628],
+    [76.46853590614552, -64.19085450019008, 1.4524010469468134],
+    [76.46079592753463, -63.798678809375204, 1.4136021768161409],
+    [76.38247456195445, -63.38192154594541, 1.3730095666662692],
+    [-72.37398003093297, 11.885392604477005, -5.9372775118314856],
+    [-24.130223129503076, -12.942701418624601, -3.276209276316622],
+    [7.014958008729989, -28.843964076536356, -1.6489239972262197],
+    [27.722704973730842, -39.22417631491771, -0.6507535992611713],
+    [41.77908516118963, -46.074238918190844, -0.0426062402477772],
+    [51.2584960561387, -50.556710220027725, 0.3309236314637092],
+    [56.957384516296706, -53.11026856364619, 0.5338914043072808],
+    [60.25099691872602, -54.43859207097307, 0.6336634999585126],
+    [62.36112747156788, -55.169044086586986, 0.6842275211313563],
+    [63.82186501903408, -55.57581707971874, 0.7085387367800485],
+    [64.8509547071054, -55.76984367783793, 0.7157310299045585],
+    [65.56767291176783, -55.8115463764246, 0.7107060902817793],
+    [66.06137300860661, -55.74640576134166, 0.6971330036456576],
+    [66.39598853084412, -55.607031335334845, 0.6776356174185771],
+    [66.61496530375774, -55.41553989696985, 0.653988926891995],
+    [66.74645463549344, -55.18614801869924, 0.6273275937133876],
+    [-62.56913162267145, 12.48216853771697, -6.328666393420298],
+    [-26.803677732683756, -6.333235115539241, -4.2500903541015385],
+    [-1.8760608504417013, -19.403594338176756, -2.846108775253339],
+    [15.949957281096687, -28.682676654628484, -1.8787634643314441],
+    [28.91235418876296, -35.348533163316226, -1.2068828499887434],
+    [38.407719751510164, -40.14398588002184, -0.7418886896567244],
+    [45.10408114208425, -43.43164150890934, -0.43620464148829136],
+    [49.47571971203409, -45.474966341446276, -0.2541478005483713],
+    [52.26222236618527, -46.67551898083016, -0.15209835479175532],
+    [54.160450101893424, -47.408346526902285, -0.09325117403457621],
+    [55.53635970480206, -47.86952710233818, -0.059033384987332814],
+    [56.55818156682559, -48.14851437730965, -0.04113209711437816],
+    [57.317862053886884, -48.29382099462311, -0.03511634116234892],
+    [57.88020615620279, -48.339587321977056, -0.037910880614963754],
+    [58.295737167793064, -48.31249695619087, -0.04712933558182938],
+    [58.60056302213937, -48.23159576756885, -0.06107850797322317],
+    [-58.57064791948024, 14.76663804747795, -6.840523713030501],
+    [-30.35109712932485, -0.3372620404769412, -5.130145190277025],
+    [-9.61848698281658, -11.418811725241213, -3.8992150097677207],
+    [5.932703144262224, -19.701392896367132, -2.9964424070004743],
+    [17.775694534902538, -25.971185173057094, -2.3258475319218475],
+    [26.884032331865246, -30.750544327087002, -1.8243850850416141],
+    [33.9061266598387, -34.38904543942082, -1.4501153684777477],
+    [39.13188129185128, -37.04085669347873, -1.1831475944608336],
+    [42.8074771058124, -38.838887522853845, -1.0065670871114822],
+    [45.33515568377605, -40.0051245561082, -0.8954268649139182],
+    [47.128267594015334, -40.76899787457335, -0.8252768066838085],
+    [48.46706806631244, -41.286290351776636, -0.7799049469838838],
+    [49.494827028477935, -41.636317689232776, -0.751155021712759],
+    [50.2915294246554, -41.86310892479361, -0.7345499502658046],
+    [50.90939021246339, -41.9952801345775, -0.7272139186358099],
+    [51.388389776811806, -42.05473898779672, -0.7269659183704427],
+    [-57.243588551391284, 17.59018329857458, -7.371142542776309],
+    [-34.030292182803414, 4.990500261240697, -5.914756638171296],
+    [-16.29907139654656, -4.630122989590028, -4.818460087565156],
+    [-2.5247937021535876, -12.090191196432075, -3.979804978250884],
+    [8.316406662233893, -17.94185572511596, -3.3304275844437585],
+    [16.93182922206826, -22.56827585486178, -2.823379959930575],
+    [23.820011673462545, -26.24059328726814, -2.4257492720460028],
+    [29.33036745109569, -29.149274918428755, -2.114551983054792],
+    [33.63691163126865, -31.387214825910966, -1.8781319864203014],
+    [36.85006783460966, -33.01245872259317, -1.708988206704986],
+    [39.21125079211743, -34.159478609024134, -1.5917352256940802],
+    [40.948900601400105, -34.95658674519085, -1.51209186141695],
+    [42.274789980450855, -35.523811045981994, -1.456950664275383],
+    [43.31607212635246, -35.93322386832163, -1.4185033497065238],
+    [44.1463873291961, -36.22643818819257, -1.392270473680387],
+    [44.81233846891934, -36.429696969960844, -1.3754455405297266],
+    [-57.23873867915432, 20.508583553082705, -7.883359823148649],
+    [-37.56910885562096, 9.706669294757166, -6.61295261704884],
+    [-22.086740915596533, 1.2024841003366964, -5.623789788229151],
+    [-9.728552454905397, -5.579921166878274, -4.843028408923158],
+    [0.24748052761891948, -11.044254666585234, -4.220083324894018],
+    [8.372050952154336, -15.480397165619491, -3.7189623372403715],
+    [15.032227869607828, -19.100543898321924, -3.313548183922068],
+    [20.513972796534183, -22.061931862569136, -2.984642121164352],
+    [25.02692598668565, -24.479978726229866, -2.718234081603679],
+    [28.697690915771556, -26.423486855608534, -2.5058718423411435],
+    [31.571105765780384, -27.914599172878344, -2.344525967068452],
+    [33.7975262639918, -29.037747250880578, -2.22435148070689],
+    [35.50219892394783, -29.863536843942857, -2.137238996796321],
+    [36.828374459950936, -30.473975189900205, -2.073935999011017],
+    [37.88655828055627, -30.932638013865343, -2.027321080772392],
+    [38.746292565807614, -31.27951240024942, -1.992935779474255],
+    [-57.91123080944391, 23.34150431976019, -8.363916217294019],
+    [-40.872686492091965, 13.890545494465156, -7.235731182392997],
+    [-27.136745939936127, 6.2671907269856515, -6.3337666248935935],
+    [-15.931776470365094, 0.04997716595063592, -5.604223149011989],
+    [-6.701887346316067, -5.065861822080548, -5.008510958801907],
+    [0.9618337657821036, -9.305316391793292, -4.518367772946978],
+    [7.365541906209672, -12.83740268472255, -4.112726498981947],
+    [12.741746090341852, -15.790866936437906, -3.775658299292321],
+    [17.26862423210132, -18.264612343760653, -3.495010705501699],
+    [21.082019306149867, -20.33412346353571, -3.2615609083346713],
+    [24.276846997519428, -22.051837262929883, -3.0688985298774827],
+    [26.88857262652329, -23.43580401914419, -2.9146678376754354],
+    [28.988045249310158, -24.52540586052365, -2.7941398165000693],
+    [30.663932152721856, -25.37107151348689, -2.7014074510160286],
+    [31.998511069786765, -26.01996284027079, -2.631007965536708],
+    [33.07743523191849, -26.52177014648349, -2.577234266365153],
+    [-58.929747750119645, 26.0178604007136, -8.809201919225083],
+    [-43.91744591652412, 17.61822438725444, -7.793428138867764],
+    [-31.576580207971457, 10.707838927335303, -6.963848097244224],
+    [-21.32871176814232, 4.968502280489561, -6.279504713135079],
+    [-12.746064322027337, 0.16409557534171407, -5.710220924086145],
+    [-5.506520262871074, -3.883761379703136, -5.233357859487669],
+    [0.6363059310514914, -7.311935027967106, -4.831663707030099],
+    [5.873418588139505, -10.22683597129098, -4.491814252569082],
+    [10.354510689366156, -12.712078072433673, -4.203406222628559],
+    [14.197728718004951, -14.833796295387948, -3.958260119347441],
+    [17.496140345616364, -16.644147795349756, -3.7499588918967937],
+    [20.32035365507755, -18.182616144007245, -3.573658845787991],
+    [22.711389836497723, -19.471549925741375, -3.42658360196485],
+    [24.69351465535599, -20.523604811186964, -3.3071313067296635],
+    [26.329575814362087, -21.37476616621482, -3.2110151630216213],
+    [27.67127751454478, -22.054687608400393, -3.134728365024864],
+    [-60.11524462744868, 28.514530671055027, -9.21962894061579],
+    [-46.70900069709634, 20.95591461421384, -8.295037793428332],
+    [-35.50812066224323, 14.63475830893469, -7.5265576307262],
+    [-26.067441794354917, 9.304619174131634, -6.8822281277215085],
+    [-18.0503925975855, 4.77859616443038, -6.33796244003331],
+    [-11.198461367355671, 0.9127041946701981, -5.875319054886102],
+    [-5.310314816373858, -2.4055194810524556, -5.479980899363248],
+    [-0.2272236869975919, -5.264983978145052, -5.140696041121732],
+    [4.177283535451034, -7.736712889608204, -4.848527321315674],
+    [8.004857470554974, -9.877924982666265, -4.596314167843601],
+    [11.337689237108851, -11.734977272240249, -4.378285428008422],
+    [14.242267201978402, -13.345419240609802, -4.189788602227297],
+    [16.771534106145257, -14.739137359413045, -4.027134532080795],
+    [18.964084148766243, -15.937751345125731, -3.88765457689055],
+    [20.839583621999036, -16.951738067561195, -3.770030064081433],
+    [22.424392015304868, -17.79578102728707, -3.672463513284427],
+    [-61.36765626557056, 30.829988724359268, -9.597240225874806],
+    [-49.26436682641938, 23.959301165792095, -8.748172337601295],
+    [-39.0125039070892, 18.133536198278016, -8.032001508400263],
+    [-30.261908682347826, 13.157738949875313, -7.423270555303491],
+    [-22.74278364127265, 8.881271701826087, -6.9024192873977395],
+    [-16.244343758390798, 5.1861189419057325, -6.454207972232038],
+    [-10.599859257447722, 1.9786790760972903, -6.066614826385238],
+    [-5.675983524193025, -0.8161032152308546, -5.730050875611555],
+    [-1.3650167539503262, -3.2589985242498773, -5.436791913318565],
+    [2.4207778532661046, -5.399629184081462, -5.180561997809083],
+    [5.753393956948957, -7.2788001109844345, -4.956225045683584],
+    [8.692210419794451, -8.93023318044756, -4.759555678023475],
+    [11.286319999896806, -10.381845007046694, -4.587071005807129],
+    [13.576051394339457, -11.656580094836643, -4.4359191624560115],
+    [15.593531290592589, -12.772674826618516, -4.303839356391604],
+    [17.36122604124121, -13.742707997044654, -4.189269564708009],
+    [-62.62999475885431, 32.972277354031334, -9.944647582231713],
+    [-51.60417669213826, 26.674697205098976, -9.159215061150896],
+    [-42.15467893583705, 21.27145221724488, -8.488370730459325],
+    [-34.00094535697877, 16.605531642604866, -7.911482690596936],
+    [-26.923236712634804, 12.5536394547901, -7.412431005362547],
+    [-20.74731266113602, 9.01775744073448, -6.9784642335378075],
+    [-15.333472421068263, 5.919099328695373, -6.599382737749253],
+    [-10.568584293209803, 3.193706324825928, -6.2669457758435545],
+    [-6.3602040839788625, 0.7891913194888245, -5.9744347679665974],
+    [-2.6321790504500484, -1.3376987858530267, -5.71632746095989],
+    [0.6786774664594822, -3.22292963933618, -5.4880519872360445],
+    [3.625100822437644, -4.896579664887956, -5.2858007867932555],
+    [6.251343102812337, -6.383933042420279, -5.106386135897383],
+    [8.594617984246737, -7.70628457029547, -4.947133464757974],
+    [10.686185906120627, -8.881539009858965, -4.805801911019416],
+    [12.552012359661713, -9.924563727999406, -4.680536423885413],
+    [-63.86969543380526, 34.953318862962796, -10.264552899852251],
+    [-53.74925528808214, 29.140561745086046, -9.533513077872014],
+    [-44.987065019031924, 24.10209829993008, -8.902353351624702],
+    [-37.35476063895982, 19.709523072605908, -8.354102502025915],
+    [-30.67089820553211, 15.86056621797947, -7.8753055157606395],
+    [-24.78971555938234, 12.472872791305795, -7.45517627430563],
+    [-19.59294215771935, 9.47946277395082, -7.0849810602917405],
+    [-14.98374140735979, 6.825368061682623, -6.757583335794223],
+    [-10.882170486893438, 4.465107905308706, -6.46710312356213],
+    [-7.22173679542317, 2.3607710356048637, -6.2086590894940175],
+    [-3.9467547971417405, 0.48054132612880596, -5.9781709311322],
+    [-1.010320267655244, -1.202434373551433, -5.772208028280967],
+    [1.627310618652311, -2.7109523725052207, -5.587868848266036],
+    [3.9999428529571364, -4.064436439250109, -5.42268991980652],
+    [6.136424458522395, -5.279487352427058, -5.274572979087963],
+    [8.061394176377853, -6.370300260697634, -5.141729604070673],
+    [-65.06845413120163, 36.78617789759306, -10.559537205246233],
+    [-55.71917420984142, 31.388923495152955, -9.875557999037198],
+    [-47.552359491903076, 26.66870006773167, -9.279456486642765],
+    [-40.37957238559755, 22.51909224290238, -8.757092083873154],
+    [-34.04918425233096, 18.854278789344892, -8.297108502551302],
+    [-28.437968605898742, 15.60435782072114, -7.89029405004796],
+    [-23.44487328796046, 12.711879229029304, -7.529108467700733],
+    [-18.98634464299969, 10.129237660328544, -7.207328235226845],
+    [-14.992774192262708, 7.816688474155698, -6.9197774424172],
+    [-11.40576173272918, 5.7408171647747634, -6.662120815236368],
+    [-8.176027683678418, 3.873370741940238, -6.430706083131906],
+    [-5.26170047924527, 2.190296230645004, -6.222434852996127],
+    [-2.6270504361616105, 0.6710303024794981, -6.034667299271693],
+    [-0.24143585711778603, -0.7020936553232803, -5.865142946404121],
+    [1.921526750444759, -1.9443126273717932, -5.711918396953766],
+    [3.8846257078867867, -3.0688073150575748, -5.5733176618011],
+    [-66.21647300294785, 38.48375518982764, -10.831976080403916],
+    [-57.53172129459139, 33.446587955675184, -10.18914022920608],
+    [-49.88566458918866, 29.006527086944843, -9.624253829019334],
+    [-43.120943314027095, 25.07435121626178, -9.125401665879988],
+    [-37.10950122624081, 21.577300996887097, -8.682904364015432],
+    [-31.74618828754156, 18.45550667324326, -8.288827414248455],
+    [-26.94395168353508, 15.659299437847622, -7.936612636374558],
+    [-22.630176367297107, 13.147162918600023, -7.620798148282734],
+    [-18.743869331940967, 10.884154894541917, -7.336803213638526],
+    [-15.233465729939436, 8.84067569956615, -7.08076081848413],
+    [-12.055135841213438, 6.99151741408071, -6.849388791510112],
+    [-9.171385558492968, 5.315074759511325, -6.6398831283775275],
+    [-6.550005833982912, 3.792753981904126, -6.449838307024783],
+    [-4.163192964316743, 2.408476019810081, -6.2771804979884545],
+    [-1.9868465754454188, 1.1482797968160467, -6.120114370447771],
+    [-5.50753258014726e-10, 4.833306864741706e-10, -5.97707999185309],
+    [-226.73294855105846, 75.84991404467623, -8.806795434873564],
+    [-39.51682055524149, -9.2788602765242, -0.36072691858926015],
+    [21.115492207643012, -36.557383041775786, 1.9039520062147561],
+    [47.361476662326815, -48.555880343525764, 2.8560926948302643],
+    [56.2964121145454, -52.31666398756936, 3.142836521582774],
+    [59.5897409553496, -53.31888862576519, 3.207979209244885],
+    [61.08281011947589, -53.45777970398655, 3.2043342199163964],
+    [61.81106728337304, -53.24919439481974, 3.1734211801444707],
+    [62.11651034810987, -52.861425225535605, 3.128703357796518],
+    [62.158362656316584, -52.37130361593997, 3.0762860442175652],
+    [62.02708774480014, -51.821546908957416, 3.0195445925520175],
+    [61.780124868322915, -51.23867663022583, 2.960565901504414],
+    [61.45550828219705, -50.63976149874915, 2.9006864352723793],
+    [61.07925574897328, -50.03607359190598, 2.840783615260886],
+    [60.66904759913151, -49.434872507429446, 2.7814161253887555],
+    [60.237067193078495, -48.840809363717405, 2.7229357449650973],
+    [-78.60907539169956, 17.831557958167746, -3.4554966255067088],
+    [-15.565409359057709, -12.55570477571753, -0.4113605503208345],
+    [14.860675285297601, -27.000683064998544, 0.8844150942118933],
+    [31.587539845769072, -34.91081777421613, 1.5566339441126542],
+    [40.42315314105951, -38.991944017111216, 1.8940496631773918],
+    [44.609026634403605, -40.75318608010922, 2.033539530858264],
+    [46.85926584607736, -41.54525037970243, 2.091157793301003],
+    [48.188199985690076, -41.88091481043318, 2.1105016711216384],
+    [49.02784973510884, -41.980240707115264, 2.110205036033658],
+    [49.566259919654016, -41.93863741393383, 2.098305588479],
+    [49.90106015212005, -41.80522343973068, 2.078918974721131],
+    [50.0912271981507, -41.609401042369946, 2.0544983451709458],
+    [50.17573981750201, -41.37041636074172, 2.026644485510464],
+    [50.18184005004178, -41.101583265510406, 1.9964623881815586],
+    [50.129119080375844, -40.812353273569514, 1.9647348076623559],
+    [50.03193780588876, -40.50954693807322, 1.9320252768902768],
+    [-45.74215906914278, 7.390505309475491, -2.686339669704244],
+    [-11.034840668542188, -10.001301169082327, -0.8819704611379748],
+    [9.053379196117644, -19.961191699507555, 0.08471315624336494],
+    [21.49333263703805, -26.05894457410978, 0.6456265663906531],
+    [29.393567402960514, -29.878913675779984, 0.9861140778887407],
+    [34.095137192475896, -32.07297314994311, 1.1765531179818904],
+    [36.86129619591966, -33.27324796105325, 1.277171981745992],
+    [38.61825731771559, -33.95586132024982, 1.331539642784113],
+    [39.79753580762385, -34.34417163987922, 1.35982179443259],
+    [40.627327427690794, -34.55696948938889, 1.3726675991858295],
+    [41.22714340755997, -34.6564310127217, 1.3756198723448767],
+    [41.66431290352301, -34.67742568498303, 1.3717697609091453],
+    [41.98111719547803, -34.64173451880603, 1.363038907495645],
+    [42.20627125739844, -34.5640693268175, 1.3507204306413993],
+    [42.36031841539789, -34.4548954714648, 1.3357316447557885],
+    [42.45846924313536, -34.32191316347749, 1.3187460904815018],
+    [-34.32809827969437, 5.355189408779066, -2.681098990726446],
+    [-11.106791620253171, -6.615971246708684, -1.3950760927590315],
+    [3.9291395177905852, -14.326324260532198, -0.5989861709158344],
+    [14.045859469985837, -19.46475224758227, -0.08887199613471214],
+    [21.034339883710047, -22.97177278125588, 0.2482811194746723],
+    [25.83392914033097, -25.337332900773788, 0.4701964777066102],
+    [29.002016797134274, -26.84649327341839, 0.6085238460872746],
+    [31.111283380122412, -27.79787461141334, 0.6934187048164344],
+    [32.58432772723035, -28.414833142090238, 0.7466586135794211],
+    [33.65166506434929, -28.819603733921504, 0.7799874901368242],
+    [34.45082786383956, -29.085382366084776, 0.8003718820273402],
+    [35.064337018962696, -29.256128841248497, 0.8119684854283228],
+    [35.542420256993424, -29.35853709594664, 0.8172867561609305],
+    [35.91768600264593, -29.409874976749542, 0.8179414566653399],
+    [36.21270290241134, -29.42204799582686, 0.8150408902809892],
+    [36.44386351225463, -29.40367000922554, 0.80938398643075],
+    [-29.757299556761254, 5.723510666504237, -2.8787318675565152],
+    [-12.53740994704698, -3.346815952717218, -1.8753530019815083],
+    [-0.5288431636580853, -9.657017436371817, -1.1949620102830625],
+    [8.078072720866697, -14.155264341843791, -0.7219722236624612],
+    [14.367471286405278, -17.414718852416993, -0.3873069187823095],
+    [19.009211613366123, -19.792088466804717, -0.14836326159629204],
+    [22.392520279115185, -21.494003695641517, 0.019450915342861527],
+    [24.808071181500924, -22.674556510701034, 0.1336749104709221],
+    [26.54641938527488, -23.48980973953037, 0.2109553029383586],
+    [27.837017199155845, -24.064096214112833, 0.2641462656051161],
+    [28.821346740596056, -24.4742283279878, 0.301072227471999],
+    [29.58995443058349, -24.769453672412833, 0.3266976712012134],
+    [30.20257771189502, -24.982306512605174, 0.34427768180493085],
+    [30.698466124897987, -25.134145385275072, 0.35593638279094814],
+    [31.1040861336732, -25.239339110837285, 0.36309506956903215],
+    [31.438087118818505, -25.307983304488964, 0.36674750103018783],
+    [-27.94744014933783, 6.890158547803916, -3.132928979657566],
+    [-14.350789490029692, -0.3941343850752337, -2.307425352530725],
+    [-4.36084806839245, -5.741186605057942, -1.7122952024923384],
+    [3.1377268573108856, -9.743028925715935, -1.2744623758047542],
+    [8.85651367633586, -12.779642651253736, -0.9475925100926332],
+    [13.265000938704809, -15.10311412526547, -0.7012875022488146],
+    [16.676036360541396, -16.882019192766165, -0.5154009596728912],
+    [19.28765865296874, -18.22278348503234, -0.3772381897813428],
+    [21.26376408151291, -19.21405359561011, -0.27653948425890956],
+    [22.765281305656842, -19.94401842461152, -0.20350557730561136],
+    [23.92919464455963, -20.48840136677281, -0.1499272445277835],
+    [24.8501764705628, -20.899675695750922, -0.11018982515034123],
+    [25.59201800006695, -21.213234971399807, -0.08054189789649722],
+    [26.19933059304251, -21.453860379691665, -0.058376628636770005],
+    [26.703452920333003, -21.638963599501377, -0.04187337125960279],
+    [27.126499749618183, -21.780817576073147, -0.029755429691256264],
+    [-27.4043332799673, 8.308239553792795, -3.395323707810511],
+    [-16.209159782730858, 2.2270613464626634, -2.6921584279144106],
+    [-7.658355668657934, -2.417107294771143, -2.1623589146225224],
+    [-1.0134390228099763, -6.020630704879838, -1.75643256449107],
+    [4.220482548557867, -8.850427595260404, -1.441406457529119],
+    [8.384844122756974, -11.091479885318398, -1.1946666001941997],
+    [11.720151976009396, -12.874584263070018, -1.0003789973433344],
+    [14.396085851616272, -14.292207145055427, -0.8474395738856771],
+    [16.528151915101184, -15.407144922343083, -0.7283347879315822],
+    [18.213783627993685, -16.272744373167132, -0.6368058569257813],
+    [19.54851540226446, -16.94202204712419, -0.5667976770978078],
+    [20.61790488589619, -17.462910972849823, -0.5129343966091464],
+    [21.488073158910304, -17.872628028541282, -0.4710871228612257],
+    [22.206204851798052, -18.19777391233087, -0.43832675242935376],
+    [22.80651689452152, -18.45766182323101, -0.4125398769768809],
+    [23.314232877794797, -18.666541150560764, -0.39217535704231143],
+    [-27.486283182096823, 9.76724834734414, -3.6483866631554864],
+    [-17.99001488709995, 4.548872502684001, -3.034616003767179],
+    [-10.515926317691823, 0.4403009900260615, -2.5564545246205976],
+    [-4.548612878238666, -2.837730164174013, -2.1786689881045977],
+    [0.27107799260073734, -5.480643888003129, -1.8768315296716467],
+    [4.199684940975949, -7.628583143547356, -1.6335832999453466],
+    [7.42418128904702, -9.384055878490274, -1.4363387193997086],
+    [10.082768302284926, -10.823055793990822, -1.2758449037974535],
+    [12.277643573164982, -12.00186787930505, -1.1452975189065928],
+    [14.082682947827129, -12.961119538298584, -1.0398083125139268],
+    [15.558937746434877, -13.734540973837053, -0.9553678994133562],
+    [16.766513157624914, -14.355782954388363, -0.888052948536917],
+    [17.760499723646333, -14.855980597351554, -0.8342826302171957],
+    [18.587602562180162, -15.26170249999134, -0.79102960994592],
+    [19.283663156545792, -15.593396560094245, -0.7559783449359831],
+    [19.875586153602743, -15.86643536886505, -0.7273966399056931],
+    [-27.88716256334978, 11.182254851017253, -3.885947737837052],
+    [-19.651835543450993, 6.611770345616823, -3.3404367037676503],
+    [-13.01330888210157, 2.9251165582934178, -2.9041502653458133],
+    [-7.5969531285907035, -0.0823660505284389, -2.5510271214582354],
+    [-3.1333212566292508, -2.5584284486251603, -2.262403294379258],
+    [0.5755468916848987, -4.612011307830877, -2.0246259378331035],
+    [3.6777415784596834, -6.324884876996173, -1.8275262142567439],
+    [6.285703407383889, -7.759323739593776, -1.6634173229746378],
+    [8.485704670839532, -8.963230696190529, -1.5264280645319683],
+    [10.344060878495235, -9.973484820931683, -1.412066904544263],
+    [11.911133431177504, -10.818081568657858, -1.3169414737049032],
+    [13.227583318402276, -11.519667281047164, -1.2383281926439837],
+    [14.332732513522169, -12.100397417592422, -1.173599491445592],
+    [15.263429627344724, -12.58122970940473, -1.1202968382613447],
+    [16.05259739486631, -12.98102593309103, -1.0762251614670935],
+    [16.72756273857339, -13.315512445767336, -1.0395644015774046],
+    [-28.449943056262352, 12.520111646210966, -4.106392322393405],
+    [-21.18548168810799, 8.453565910684222, -3.614772931054447],
+    [-15.21415085249918, 5.10810243103633, -3.2131672605776784],
+    [-10.254724507933522, 2.3289753828718345, -2.881704657015625],
+    [-6.099548308574578, 0.0015353921348081837, -2.605766333590221],
+    [-2.5924502777930503, -1.960725025144321, -2.374398003071927],
+    [0.3859159022066362, -3.6241214389492544, -2.1792589481003994],
+    [2.9280229966376847, -5.040187854954682, -2.013910099143247],
+    [5.106351929050287, -6.249425443868144, -1.8733229301118774],
+    [6.9781989605137404, -7.283924277859506, -1.7535372005256922],
+    [8.589007502709514, -8.169177918741585, -1.6514242517288509],
+    [9.97465886125502, -8.92532478553784, -1.56452472466383],
+    [11.164053894719423, -9.56859663043692, -1.4908669214706145],
+    [12.183739147578414, -10.11401857412491, -1.4286426765203706],
+    [13.059279667928816, -10.57620362502644, -1.3761096747134771],
+    [13.814058710361927, -10.968613924990505, -1.3316737150095757],
+    [-29.089928766943782, 13.770328118617277, -4.309991622276642],
+    [-22.594896474020874, 10.10676143776221, -3.8620479389290376],
+    [-17.16864659403369, 7.043099870240069, -3.4896704062066193],
+    [-12.594436690933641, 4.459311605834786, -3.1773287422971133],
+    [-8.70864351193536, 2.2645030034693425, -2.9133355342264387],
+    [-5.3857473882461875, 0.3887373920005058, -2.6887529264421954],
+    [-2.5280854901840106, -1.222559023420199, -2.496648042692093],
+    [-0.0587125896717779, -2.612502840476602, -2.3315753123046945],
+    [2.08366201284654, -3.815528981613844, -2.1892101374929225],
+    [3.948316771651434, -4.859390991425721, -2.066085937486843],
+    [5.575162696128208, -5.766615162036138, -1.9594036529382215],
+    [6.996657009118634, -6.555552884887448, -1.866894104542511],
+    [8.23920427644218, -7.241148567567208, -1.786717755904796],
+    [9.324402111262158, -7.835633798383333, -1.717375625054453],
+    [10.271102692221447, -8.349726975565869, -1.65756205809967],
+    [11.097372211112484, -8.793790737614207, -1.6060242058666738],
+    [-29.75986940091397, 14.932549123298193, -4.497770808400852],
+    [-23.88901168516013, 11.598516336611665, -4.085976982232313],
+    [-18.916477663135545, 8.771677824271302, -3.738593984500182],
+    [-14.671532154858514, 6.356932873075586, -3.443231948856965],
+    [-11.022818654011209, 4.280908258889409, -3.1903845232676202],
+    [-7.86783882016247, 2.486191343907547, -2.9726536705651396],
+    [-5.1255981049355, 0.9272915334356531, -2.7842087758981235],
+    [-2.731368175817486, -0.4322412559207392, -2.6204021814446605],
+    [-0.6328954666996806, -1.6219065031835156, -2.4774914202720417],
+    [1.2123826220259963, -2.6657904183451206, -2.3524356750854998],
+    [2.839406668466909, -3.5837103872299005, -2.2427448199849005],
+    [4.2770412032168545, -4.392071462514317, -2.146366779070851],
+    [5.54924710605516, -5.104515741216504, -2.061602436655144],
+    [6.675957448779982, -5.732407726297636, -1.9870420807825988],
+    [7.673806627469563, -6.285240602770562, -1.9215127097027824],
+    [8.55702277663383, -6.771149893589341, -1.8640133969057961],
+    [-30.4329318802736, 16.010884171679482, -4.671011729151291],
+    [-25.078216524827162, 12.951326478027514, -4.289660246124154],
+    [-20.489263093334177, 10.326413332652505, -3.9639165305671846],
+    [-16.529073206165002, 8.059434723113222, -3.683723187612329],
+    [-13.090502365404912, 6.090240534819227, -3.441233194097018],
+    [-10.08869620083857, 4.371071507359206, -3.2302464584935215],
+    [-7.455690905963121, 2.8635783357734113, -3.045808296953944],
+    [-5.136493788424292, 1.5366542329072692, -2.8839184918946925],
+    [-3.0861956154282226, 0.36483527710112373, -2.7413173246519476],
+    [-1.2678147552375814, -0.6728959592801717, -2.6153261184964745],
+    [0.3493319213004957, -1.5940159987394733, -2.5037269665461737],
+    [1.790868713029915, -2.4131507779710577, -2.4046713359134397],
+    [3.0783221449774576, -3.1426113407530476, -2.316609423947011],
+    [4.22985619439378, -3.7928042299458893, -2.238236135125454],
+    [5.260850505200561, -4.372554333111375, -2.168448732044761],
+    [6.184367716257192, -4.889366630563785, -2.106312665434754],
+    [-31.093839103147378, 17.011222001500116, -4.83102890232835],
+    [-26.172827583251326, 14.18382149719806, -4.475685941095101],
+    [-21.912443877927547, 11.733201253982298, -4.168875189220067],
+    [-18.201010580960986, 9.59652903668335, -3.902310533552786],
+    [-14.949923162107115, 7.723833611098349, -3.669431642406553],
+    [-12.088089474959556, 6.07492434919651, -3.4649855737086646],
+    [-9.55788628126281, 4.617148042979385, -3.2847230276656],
+    [-7.312174375006958, 3.323730473618361, -3.125174492216891],
+    [-5.312065177143945, 2.172533531038943, -2.983483022425815],
+    [-3.525227760874591, 1.1451115537372434, -2.8572777000498677],
+    [-1.9245994888405358, 0.2259914089440776, -2.7445773710856978],
+    [-0.4873730759622933, -0.5978938738302291, -2.643715242896658],
+    [0.8057762509078743, -1.3376380205160392, -2.553281339903701],
+    [1.9713085496277751, -2.0027079798556082, -2.472076640665047],
+    [3.023305634299999, -2.60121708599752, -2.3990770105773827],
+    [3.9738591737300952, -3.140143220350406, -2.3334044793356865],
+    [-31.734052520095474, 17.939947218500638, -4.97907220646471],
+    [-27.182458025641946, 15.311486316107594, -4.6462229196583635],
+    [-23.2066981048004, 13.012896175181409, -4.356128830757569],
+    [-19.714483518087246, 10.99202338103932, -4.101874845321809],
+    [-16.631657909774493, 9.206867478045456, -3.877914987604662],
+    [-13.898029807043777, 7.623261192194012, -3.679755854549902],
+    [-11.464291620146973, 6.213153451376417, -3.5037229867020603],
+    [-9.289710925929588, 4.953321014441077, -3.3467860716024407],
+    [-7.3403789998022875, 3.8243896558228174, -3.206426540967424],
+    [-5.587866039473753, 2.810081504211162, -3.080536070081631],
+    [-4.008184606223681, 1.896634010790248, -2.96733843613608],
+    [-2.580966131299775, 1.0723376298533818, -2.8653275768319784],
+    [-1.288825049742371, 0.3271783023188015, -2.7732197802856025],
+    [-0.11684514577165606, -0.34745177053689713, -2.6899151500731966],
+    [0.9478298352970974, -0.958984969615529, -2.6144669289938243],
+    [1.9163262488706612, -1.513864609316475, -2.546056803973844],
+    [-32.34904678706063, 18.80333176482158, -5.11628870014454],
+    [-28.11579990520834, 16.347262502240266, -4.8030981439314635],
+    [-24.38899921028317, 14.182495392242563, -4.527881558087868],
+    [-21.091460955232378, 12.26523495704849, -4.284802786116264],
+    [-18.16046256003686, 10.559816665309054, -4.06912716508717],
+    [-15.544564502869767, 9.036930421950169, -3.876978059343316],
+    [-13.201226325024146, 7.67228641665132, -3.7051544982445033],
+    [-11.094996013712558, 6.44560058838572, -3.550992876394359],
+    [-9.1961192592781, 5.339814329086272, -3.4122608779429515],
+    [-7.479459249834085, 4.340487576061255, -3.2870752034634747],
+    [-5.923655137940262, 3.4353253883732218, -3.17383756064125],
+    [-4.510446619662957, 2.6137973210699523, -3.0711833686136867],
+    [-3.2241472391251036, 1.8668402915498263, -2.9779417988488093],
+    [-2.0512151098203075, 1.1866160023927907, -2.893103257992101],
+    [-0.9799075271483485, 0.5663155055476546, -2.815793256502505],
+    [3.159226161704605e-14, -1.8964688078179085e-14, -2.745251178470558],
+    [-138.83284365988303, 46.033195646229046, -5.168554185430945],
+    [-21.743144144666278, -7.481028593722258, 0.16952900206394386],
+    [17.47064272624358, -25.847902959034347, 1.8456396278167717],
+    [33.37850802058306, -33.42119194737899, 2.5284447393436267],
+    [39.00918922746834, -35.885064851127595, 2.742360671074346],
+    [41.41488958706485, -36.71478909440218, 2.8062124597959652],
+    [42.66940348705035, -36.97316894293135, 2.818050733690125],
+    [43.361851232057106, -36.96314630690007, 2.8057801494298604],
+    [43.73808475485294, -36.810480452607806, 2.7809525253142193],
+    [43.92339607799373, -36.57861051289621, 2.7493759421949253],
+    [43.9871703878538, -36.302019860802744, 2.714189610060278],
+    [43.97040651410692, -36.000530933593566, 2.677182791857006],
+    [43.898839382788715, -35.686151315539036, 2.639427233753925],
+    [43.789464853517664, -35.366474522786085, 2.601591170247144],
+    [43.65395104931822, -35.04644827447955, 2.564102250578761],
+    [43.50054137704628, -34.7293527608234, 2.5272373939475754],
+    [-47.437568981350125, 10.21086913177364, -1.8681343706875657],
+    [-7.999732511270866, -8.940553915397578, 0.07250218326808111],
+    [11.444695182177853, -18.44162995969277, 0.9837891311077065],
+    [21.953142175222993, -23.59638012283071, 1.4688196402784182],
+    [27.438036885544555, -26.221557702686024, 1.711705431218181],
+    [30.30872685396127, -27.498352384908078, 1.8260758005104272],
+    [32.001054334871846, -28.1657215962209, 1.8826035713570524],
+    [33.09974583265299, -28.529446095060422, 1.9104936554299525],
+    [33.852642941932636, -28.719557887236725, 1.922136893528004],
+    [34.384931837335486, -28.801673044110938, 1.9237707129565367],
+    [34.76871796720687, -28.81347788449446, 1.9189699635642916],
+    [35.04849694917511, -28.77809302932279, 1.9099222610130193],
+    [35.253037068769146, -28.71032899969538, 1.8980241878306368],
+    [35.40170872618153, -28.620032977291746, 1.8842003708908812],
+    [35.50804615279341, -28.51398023582354, 1.8690837924363022],
+    [35.581814560781105, -28.396971844625806, 1.8531204182837004],
+    [-27.447956319023923, 3.9188909809324315, -1.4104731102734718],
+    [-5.8037669530047475, -6.989846092160422, -0.2685905914964595],
+    [6.864240287916451, -13.375495623735517, 0.3739359052104076],
+    [14.737833667463741, -17.33886517598362, 0.7638508756797125],
+    [19.694275564722265, -19.807975917010985, 1.0032084493188111],
+    [22.744858689821775, -21.282579895235912, 1.1437856955411891],
+    [24.691654190607753, -22.17574195834889, 1.226994721437533],
+    [26.01994725088088, -22.743126311788927, 1.2782335087496373],
+    [26.97966314749035, -23.117688704233192, 1.310657244704161],
+    [27.701638144146294, -23.369260515307356, 1.3311539009021476],
+    [28.260390422358903, -23.537624554594, 1.3436363259736657],
+    [28.702272298312007, -23.647504090142416, 1.35053034378769],
+    [29.05783144788377, -23.7151559316219, 1.3534274966886228],
+    [29.347996849167167, -23.75167430328626, 1.3534113247338446],
+    [29.587557108237945, -23.764854016162058, 1.3512410825369348],
+    [29.787252780886856, -23.76031494006117, 1.3474625265578413],
+    [-20.64182028941225, 2.8030165239547036, -1.4239152627303937],
+    [-6.178063168038408, -4.6839462349435435, -0.6150455085455172],
+    [3.2216960475507954, -9.545439611626513, -0.10452649765430475],
+    [9.584352404875336, -12.826186368438135, 0.2327165650713243],
+    [13.988888829987296, -15.082030261795659, 0.46110646353156637],
+    [17.032193125964312, -16.618204251282684, 0.6146387683041683],
+    [19.130799892016277, -17.649967618392782, 0.7163175705989352],
+    [20.623368820317733, -18.356764297690244, 0.7848346154316894],
+    [21.73024969995214, -18.856912131204673, 0.8323875844365193],
+    [22.582787300117502, -19.221417504798556, 0.8662560288423132],
+    [23.259588446625468, -19.492909590698158, 0.8907931037730467],
+    [23.80956106806827, -19.697931925539425, 0.9087002927554594],
+    [24.264900640943864, -19.853953181553212, 0.921750365157931],
+    [24.647809253310786, -19.973007489297963, 0.9311614948535788],
+    [24.974124490571757, -20.06366281536054, 0.9377989386173046],
+    [25.255442080877977, -20.132174027110775, 0.9422928696823273],
+    [-18.007414816962715, 3.1488168250359574, -1.5603898779980143],
+    [-7.275230926001467, -2.521117316087508, -0.9310058494202141],
+    [0.21548303171761424, -6.476745322881388, -0.5008177298822216],
+    [5.599292364590108, -9.312404381833812, -0.19775461609024442],
+    [9.547920057044813, -11.381729831229691, 0.020301404595746256],
+    [12.472643609096087, -12.901347056970648, 0.1785696715375273],
+    [14.63584732792837, -14.009261198559633, 0.29271516367305395],
+    [16.244717298549105, -14.815777198253532, 0.37488042266077876],
+    [17.4685603081125, -15.412493799572804, 0.4349443827993945],
+    [18.426332435637722, -15.864366867339044, 0.4798400960603736],
+    [19.196243688868254, -16.214322182478558, 0.5141203735421735],
+    [19.829610149396622, -16.49060568728121, 0.5407675056721049],
+    [20.3606752797481, -16.712086590651435, 0.5617664053845263],
+    [20.8130616277864, -16.891782747815743, 0.5784820760581497],
+    [21.203641222189855, -17.03898717177211, 0.5918861931253445],
+    [21.544816722833755, -17.160523916573794, 0.6026904527650253],
+    [-17.044227806692668, 3.9715428618589836, -1.7299900380544184],
+    [-8.556642853655514, -0.586040207054526, -1.2124667040858934],
+    [-2.320219452578642, -3.9349710703104583, -0.8379428835745508],
+    [2.3667311961023674, -6.447661406584088, -0.5607118953113325],
+    [5.949387060828934, -8.361626147137548, -0.352009714537029],
+    [8.719993638846518, -9.833367637145386, -0.19315746624396965],
+    [10.874427642624482, -10.967948268758288, -0.07180784601305473],
+    [12.55109497403766, -11.839762839244774, 0.020628883715114645],
+    [13.864805451474258, -12.511209942287916, 0.09120002193318506],
+    [14.910946838959491, -13.034749245062354, 0.14573549705357666],
+    [15.761079708529895, -13.450030070222574, 0.18859946622656634],
+    [16.465803502032756, -13.785208274441132, 0.22287144880849913],
+    [17.060605345248753, -14.060082775390097, 0.250706735887691],
+    [17.57054340161391, -14.288652602577832, 0.2736234268703694],
+    [18.013631296179387, -14.480985259856016, 0.2927096632877777],
+    [18.40312896013254, -14.644490101012833, 0.30876391825693916],
+    [-16.842946196045773, 4.9393529419985125, -1.9034691019286702],
+    [-9.839651677119654, 1.1282085813701284, -1.4623466628670247],
+    [-4.491512323623097, -1.7834271463514542, -1.1292825262458344],
+    [-0.3324945524672218, -4.045679418219711, -0.8732260498898408],
+    [2.948392391895187, -5.826243342599108, -0.6735868537504316],
+    [5.565022734333996, -7.2409210517312985, -0.5163042005744255],
+    [7.667931939832312, -8.371487086944699, -0.3915607399651148],
+    [9.364906571457002, -9.276624863965862, -0.29239049234480813],
+    [10.737260177922357, -10.000765751586057, -0.21358727118348547],
+    [11.853885688388099, -10.581917497109066, -0.1507673126183277],
+    [12.773219591824784, -11.05263475828432, -0.10022256636374635],
+    [13.541488712562193, -11.438842602189752, -0.05902466583163134],
+    [14.193358554003598, -11.760061147715625, -0.024981533397292564],
+    [14.754425029564619, -12.030740632214044, 0.003522296214118707],
+    [15.243607849070832, -12.26157874515377, 0.02767836345090047],
+    [15.675016514569329, -12.460559120845625, 0.048373068871478526],
+    [-17.015107535840883, 5.926049858294118, -2.070452689774151],
+    [-11.060314355939454, 2.648805745760693, -1.6848787067851703],
+    [-6.374598412292932, 0.06827829013311437, -1.3841092307181755],
+    [-2.6316580631745774, -1.9923619387643143, -1.1459626304001411],
+    [0.3950127007017896, -3.6563745074082097, -0.9551197186779911],
+    [2.8667546308096856, -5.011899444644276, -0.800726683108045],
+    [4.900991125471255, -6.123310715599043, -0.674928362825832],
+    [6.584682345873745, -7.038426441048778, -0.5719418533692915],
+    [7.983271107241601, -7.793325879475912, -0.4874422235614199],
+    [9.148185219974712, -8.416473819982379, -0.4180506296952816],
+    [10.123429872031355, -8.932425382200309, -0.3608856548585206],
+    [10.947061096134066, -9.36259774652796, -0.3134586397939019],
+    [11.650455866903316, -9.724760903000297, -0.2737192874215645],
+    [12.258332479067857, -10.032971799676021, -0.24005406117231776],
+    [12.78974223209368, -10.298093823887072, -0.21122103872933548],
+    [13.259307429978831, -10.52847957421049, -0.1862685542690711],
+    [-17.37621816878407, 6.88150725372737, -2.227420559757831],
+    [-12.19886713002009, 4.004426719906575, -1.8841040237134141],
+    [-8.026282939708443, 1.6837623121139311, -1.6092981293015616],
+    [-4.6205141805367855, -0.21052690102262567, -1.3865363504444204],
+    [-1.8110342078588813, -1.7719823312862215, -1.20406756607531],
+    [0.527098297140554, -3.069385709637798, -1.053318531122377],
+    [2.4871877247194023, -4.154276396210005, -0.9279143511858884],
+    [4.140064246042322, -5.065902710718211, -0.8230366727295723],
+    [5.5402613017346445, -5.834569549435451, -0.7349920732786124],
+    [6.730393382212802, -6.484023092398231, -0.6609070767723066],
+    [7.744873363851114, -7.0335118388376845, -0.598470171103052],
+    [8.613277253239032, -7.499690293068275, -0.5456985000018443],
+    [9.36157265718097, -7.8972923955027055, -0.5008515545512657],
+    [10.011863229195747, -8.238935697206044, -0.46244776547256616],
+    [10.582285773491856, -8.535017911771355, -0.42927176165081765],
+    [11.087356949540718, -8.793885623586254, -0.4003514352628994],
+    [-17.832041086340528, 7.786683413191456, -2.373558104540098],
+    [-13.252867894244686, 5.22041558061797, -2.063507258678602],
+    [-9.489569159633096, 3.109387550630769, -1.8100827938460236],
+    [-6.362917054861859, 1.3549207856292367, -1.6006830181461014],
+    [-3.741036486989177, -0.11583747164131472, -1.426068181995366],
+    [-1.5250191708048062, -1.3577078250202148, -1.2793324624790985],
+    [0.3605733392968317, -2.4126353836735945, -1.155226854924423],
+    [1.9741368238516757, -3.313187917507099, -1.04970200466581],
+    [3.361471183526151, -4.0849821382388525, -0.959592649366555],
+    [4.558929167185764, -4.748406586128527, -0.8823947059357983],
+    [5.595748927117302, -5.3199045772495825, -0.8161005418195646],
+    [6.49602231043656, -5.8130722440226, -0.7590602524672174],
+    [7.280502872763405, -6.239685980717209, -0.7098541958300834],
+    [7.967579596855792, -6.6102503366570495, -0.6672240663476077],
+    [8.573295074575558, -6.933982814494405, -0.6300714216568319],
+    [9.11124958764486, -7.218732173568067, -0.5974647048292419],
+    [-18.331967125499528, 8.635994547194905, -2.509147552842107],
+    [-14.22662548181774, 6.317998489041123, -2.225993613046019],
+    [-10.797453336069328, 4.379820164537973, -1.9905160287000458],
+    [-7.905848439691558, 2.744641106172442, -1.7928290308526063],
+    [-5.447536356284307, 1.3544905400834601, -1.6255173823766762],
+    [-3.3427461661667914, 0.16488600218175747, -1.482924878581937],
+    [-1.5295408264405566, -0.8588123524572611, -1.3606723181830447],
+    [0.04081121247797929, -1.7439370331604287, -1.2553237094615235],
+    [1.407111242830635, -2.5123112593101458, -1.1641505671116965],
+    [2.600590853096351, -3.1815479037007712, -1.084962394665322],
+    [3.6466489702126963, -3.7660080263268427, -1.015982203849863],
+    [4.566179202878602, -4.2775368793930175, -0.9557515769121756],
+    [5.3766795564703616, -4.72608603912676, -0.9030513759477986],
+    [6.093264410994098, -5.120289889788582, -0.8568293625136902],
+    [6.729371720350141, -5.467869114371574, -0.8161495173948017],
+    [7.296936511887286, -5.775718749901701, -0.7801796620509132],
+    [-18.847918250686536, 9.42980497968991, -2.6348960264363597],
+    [-15.126807019971, 7.314648013765623, -2.3739608297014203],
+    [-11.975605620558987, 5.521541235762879, -2.153783345897104],
+    [-9.284754965750652, 3.989399140183199, -1.9664472270652356],
+    [-6.970249432981799, 2.671251515650563, -1.8058980247729952],
+    [-4.96672098784809, 1.5304469779405092, -1.6674362069530673],
+    [-3.2226295839534433, 0.5380080893482395, -1.5473652526510462],
+    [-1.6968525696624481, -0.3292477200761977, -1.4427425900969375],
+    [-0.3562236208345537, -1.0900667714027874, -1.351200221332962],
+    [0.8262717964022758, -1.7597573862866114, -1.2708134491470267],
+    [1.8728404368291711, -2.3509350435844634, -1.2000032884515015],
+    [2.801900765055016, -2.8740891571618774, -1.1374625732796697],
+    [3.628879950760563, -3.338028080336202, -1.082098276639761],
+    [4.366876232210963, -3.7502520718795997, -1.0329836005877204],
+    [5.027251343529736, -4.117291243334327, -0.989315072708897],
+    [5.620109004100735, -4.444980426886511, -0.9503778314341306],
+    [-19.364054687235534, 10.171039298727997, -2.7516430156409193],
+    [-15.960570405903846, 8.224700889128812, -2.5093871259631397],
+    [-13.044271512046352, 6.555158399270979, -2.302423238501051],
+    [-10.526939330154441, 5.112956878744443, -2.1243010043787693],
+    [-8.339809211312195, 3.859446975876755, -1.9700036692042537],
+    [-6.428574360896702, 2.764026584998714, -1.8355777239245141],
+    [-4.749837551653061, 1.8021787883723221, -1.7178703472667805],
+    [-3.268546857901481, 0.9540515338801501, -1.6143399974431965],
+    [-1.9561132594118629, 0.20341314333762914, -1.522917614177917],
+    [-0.7890088762409695, -0.463126931786568, -1.4419034388288372],
+    [0.25229102193845526, -1.0567073970841165, -1.3698892704383316],
+    [1.184117404798319, -1.5866668956224144, -1.3056990866836087],
+    [2.0202365403749845, -2.060896043170294, -1.2483429637807766],
+    [2.772350616916473, -2.4861186517593477, -1.1969804482010018],
+    [3.450513176840594, -2.8681266240490486, -1.1508901725398706],
+    [4.0634934823847315, -3.211988059469298, -1.1094431776657372],
+    [-19.871445647970425, 10.863599349851752, -2.860231943914426],
+    [-16.734784031114973, 9.059954638390654, -2.6339111097962857],
+    [-14.019627687892848, 7.49697260134791, -2.43848450272119],
+    [-11.65380782126681, 6.133993833282298, -2.2686175363214867],
+    [-9.580254792189278, 4.938788364911242, -2.1200999579456985],
+    [-7.753307202107573, 3.8855072044285826, -1.9895694350410433],
+    [-6.136041669735482, 2.9531982827313943, -1.87431191084988],
+    [-4.698310519484093, 2.1247141927618354, -1.772115128635062],
+    [-3.4152791315571256, 1.385896749582656, -1.6811596291923085],
+    [-2.2663215196517923, 0.7249602371701567, -1.5999365752032226],
+    [-1.234176312262054, 0.13201925390460909, -1.5271850480891611],
+    [-0.30429427487598043, -0.40127696323821016, -1.461843646928034],
+    [0.535672038884716, -0.8820311376370702, -1.4030126840601227],
+    [1.2962728845767462, -1.316325713797944, -1.3499242524424775],
+    [1.9865881001994485, -1.7094074174711433, -1.3019180739590925],
+    [2.614498842855449, -2.065841517404188, -1.25842142188731],
+    [-20.365175348080808, 11.511614952196782, -2.961455891505379],
+    [-17.45572977488856, 9.830171544687355, -2.748898129797043],
+    [-14.914757916892922, 8.360066030559231, -2.5636401650830747],
+    [-12.682405855648884, 7.0674218712950845, -2.401212965794841],
+    [-10.710737042709264, 5.92504702032773, -2.258043520028261],
+    [-8.960951960432979, 4.91088331549502, -2.1312446265128004],
+    [-7.401343345863194, 4.006864656872346, -2.0184605367490684],
+    [-6.005770879354465, 3.1980640974315295, -1.9177519863861672],
+    [-4.752508616003805, 2.472048429691731, -1.8275094515565227],
+    [-3.6233637370508966, 1.8183839595531794, -1.746386921912831],
+    [-2.6029953537828185, 1.228253873514546, -1.6732507805342582],
+    [-1.6783825128046121, 0.6941589231391958, -1.607139935359658],
+    [-0.8384045686662217, 0.20968092083065545, -1.5472344128892757],
+    [-0.07350680454391305, -0.23070607697540105, -1.4928303628271562],
+    [0.6245690866138323, -0.6317883009675245, -1.443319933005511],
+    [1.2630054601270226, -0.9977373948895946, -1.3981748211962073],
+    [-20.84270961737979, 12.119094824928949, -3.0560376563968195],
+    [-18.12902289387301, 10.543484106229684, -2.8554934727796497],
+    [-15.740363858396256, 9.155073842619153, -2.6792711154294726],
+    [-13.626493359992683, 7.925307869665753, -2.52358422987609],
+    [-11.746717743073342, 6.831007605312712, -2.38537063496551],
+    [-10.06775819311208, 5.853178383732074, -2.2621297997026195],
+    [-8.562160559690502, 4.97611756490738, -2.151801723102894],
+    [-7.20709311385203, 4.186739394065513, -2.0526754927514483],
+    [-5.983426829149499, 3.474057840033311, -1.9633194293676637],
+    [-4.875024144650352, 2.8287861139529396, -1.882527139506231],
+    [-3.868183420433795, 2.2430234074009148, -1.8092754329804357],
+    [-2.951200925216564, 1.7100075381986468, -1.7426911855311724],
+    [-2.114022407688499, 1.2239178814839886, -1.682025011001099],
+    [-1.347963515447804, 0.7797169817297227, -1.6266301601782454],
+    [-0.6454834610169539, 0.3730221013850516, -1.5759454567073252],
+    [-5.28044058865703e-14, 3.479518260661504e-14, -1.5294813607276274],
+    [-97.88699770795412, 31.89967742020248, -3.3623670622904807],
+    [-12.045392516806059, -7.714321061844705, 0.6331212139362118],
+    [16.67121257346086, -21.653594846726065, 2.0043808402002696],
+    [27.42321671780243, -26.92202810776961, 2.5224477771190883],
+    [31.38993133106942, -28.696291463167466, 2.6899725547606264],
+    [33.306322130221226, -29.40536171992577, 2.750452353342343],
+    [34.409187616344354, -29.701422429645447, 2.769825171858835],
+    [35.105717639389354, -29.79982324440438, 2.7698699705646095],
+    [35.571962633549674, -29.793070416408366, 2.7599173431399087],
+    [35.89611566144372, -29.726810199485374, 2.7445451785958084],
+    [36.127701786554255, -29.626125728327384, 2.726248722240972],
+    [36.29695735130501, -29.50591652763204, 2.706494768520451],
+    [36.423480379264596, -29.37552279827018, 2.6861908210873837],
+    [36.52055079645164, -29.24102634832442, 2.6659181587512406],
+    [36.5974816291406, -29.106496297306204, 2.6460577578533435],
+    [36.660985292540374, -28.97470799567891, 2.626862780056004],
+    [-32.47180305610606, 6.221470265781885, -0.9995237208282068],
+    [-3.624101335507658, -7.969666638025787, 0.4676420511985158],
+    [10.5779551177525, -15.100803135042748, 1.1926907208006245],
+    [18.026184750253627, -18.858993127287818, 1.573076231159099],
+    [21.889943184849784, -20.75494055073146, 1.7624450693843317],
+    [24.06743015668475, -21.758610802015053, 1.8600175916187927],
+    [25.461755106052554, -22.34838183161327, 1.9151158507977057],
+    [26.437803436637694, -22.719808130357222, 1.947944285845767],
+    [27.164131383068955, -22.963529142028907, 1.9678671055089247],
+    [27.729801766379016, -23.12740009073489, 1.979822942597766],
+    [28.18620052907817, -23.238903963018206, 1.9866415836516893],
+    [28.565149645564357, -23.314928063216666, 1.9900561107167511],
+    [28.887547124465755, -23.3664487225843, 1.991188346447766],
+    [29.167779501865496, -23.400928392971664, 1.9907972690879867],
+    [29.416130748461757, -23.42362468540169, 1.9894146110423863],
+    [29.64018005471035, -23.438349441230883, 1.9874234321098856],
+    [-18.560238517015748, 1.92277153846908, -0.6948747975815747],
+    [-2.794772253796372, -6.106943093510077, 0.15968104877814887],
+    [6.444704293365744, -10.855323635685817, 0.6569678285322158],
+    [12.137711191822113, -13.788443609352925, 0.9619285003849185],
+    [15.68487503438146, -15.597008652559149, 1.1485514305790783],
+    [17.934465128754717, -16.713040239566705, 1.262325741708631],
+    [19.459803840144666, -17.439968647700116, 1.3352210038750745],
+    [20.569513447051182, -17.944089527398265, 1.384783614578936],
+    [21.422388968110493, -18.311630335949417, 1.4201131649117977],
+    [22.10580402828686, -18.590188194375145, 1.4462296807209734],
+    [22.671753998243368, -18.808109023258908, 1.466119859417021],
+    [23.15307814704931, -18.98327057049737, 1.481664652781264],
+    [23.57154776790204, -19.127486339452688, 1.494104540262786],
+    [23.94221543414951, -19.248884767622993, 1.5042910018277815],
+    [24.27587158721386, -19.353257225114266, 1.5128290216755902],
+    [24.58049266732732, -19.44485404862465, 1.5201612276842964],
+    [-14.009185717502028, 1.3137136264905687, -0.7273555727913918],
+    [-3.4890189350952157, -4.174300238655411, -0.12769231603046607],
+    [3.3552982034687213, -7.760199061311452, 0.2584169576886164],
+    [7.985201789181021, -10.188323982187214, 0.5175834777983094],
+    [11.180741230418661, -11.856140614464293, 0.6944136858062494],
+    [13.404894462154838, -13.001740360537836, 0.8149772214077728],
+    [14.994499433174319, -13.803055027372396, 0.8985383009251063],
+    [16.183364099428676, -14.386349228642763, 0.9587301971657366],
+    [17.113285388178223, -14.82907675929152, 1.0039073585501763],
+    [17.86844164685719, -15.17748992221412, 1.0390540238999975],
+    [18.50056126225057, -15.460094824211692, 1.0672387453850862],
+    [19.043009031007596, -15.695300434346306, 1.090440819922111],
+    [19.518237726879665, -15.895493217995133, 1.1099902242040716],
+    [19.941906702447643, -16.069300107540396, 1.1268118106257283],
+    [20.32528596849194, -16.22291487799524, 1.1415683944873918],
+    [20.676717038983117, -16.360906757116453, 1.154747966807475],
+    [-12.372636828958283, 1.7197701541952028, -0.8447001454448925],
+    [-4.557790283814534, -2.4328098264043705, -0.3803775565903864],
+    [0.897711588770577, -5.338774806431691, -0.05951094297659365],
+    [4.823475658223767, -7.430442888315377, 0.1693866163063376],
+    [7.7064055169597605, -8.962159182676078, 0.335884110222354],
+    [9.848440094843443, -10.09221501766349, 0.45798921918720675],
+    [11.457871739002393, -10.93101811526193, 0.5480572528638833],
+    [12.696130804137574, -11.565817195716432, 0.6157605127394958],
+    [13.679605338101844, -12.060428337782177, 0.6681447997816267],
+    [14.485509606727396, -12.457539818670133, 0.7099127368023068],
+    [15.164220538156615, -12.785136118686028, 0.7441420093514235],
+    [15.749186365679694, -13.061847717694084, 0.7728784965663106],
+    [16.26330009918834, -13.300443413029763, 0.7975223739130642],
+    [16.722717618697853, -13.50993782943344, 0.81906073006805],
+    [17.139156595885126, -13.696866172468464, 0.8382080900129647],
+    [17.521326454142145, -13.866080032931297, 0.8554940572924896],
+    [-11.88776765924348, 2.4451594446964022, -0.9831531800799069],
+    [-5.69049472579278, -0.8974293990586669, -0.6018946271854432],
+    [-1.1383423156773063, -3.356871859841421, -0.3242872898718289],
+    [2.286763113856655, -5.207538116790973, -0.11710714402154938],
+    [4.910728207385384, -6.6226905202230695, 0.040288101467089873],
+    [6.947145873146302, -7.716282904304537, 0.16125519101479183],
+    [8.542436708714288, -8.56680726497068, 0.25486083967538764],
+    [9.806899491040744, -9.234021435032954, 0.3279235890536364],
+    [10.828528367190753, -9.76627928329214, 0.3859162995221776],
+    [11.673446953313858, -10.200298251100888, 0.4329761854649598],
+    [12.3885641968936, -10.56229623009949, 0.4720491002831422],
+    [13.006618984384822, -10.870645568671845, 0.5051950194750181],
+    [13.550640035827803, -11.138301486685123, 0.5338639277103044],
+    [14.037134634735207, -11.374563646039629, 0.5590949675986653],
+    [14.478197876405943, -11.586250460228877, 0.5816488422702389],
+    [14.88288167353558, -11.778464877214716, 0.6020939951059706],
+    [-11.921900843215724, 3.260769940795455, -1.1227032989081769],
+    [-6.78973047565649, 0.45793890600866993, -0.7974779771712392],
+    [-2.8723816378431013, -1.684446130578118, -0.5510268879709067],
+    [0.17671090343410045, -3.3522408040181397, -0.3605449860823278],
+    [2.587144195371994, -4.669089267970382, -0.21104032655303803],
+    [4.516204789973758, -5.7200223703725, -0.0923264285717012],
+    [6.074953143027021, -6.565316847789697, 0.0027347841253167826],
+    [7.345103001655761, -7.249557163418518, 0.07936699109505456],
+    [8.391323714617785, -7.808357917701265, 0.14170355634802945],
+    [9.266357861507583, -8.271082642878271, 0.19312891590689332],
+    [10.011358477177367, -8.660832345386208, 0.23629398087070544],
+    [10.657068443249404, -8.994958931014395, 0.27318394958647896],
+    [11.226069302227454, -9.286253590700865, 0.3052588549978755],
+    [11.734975930048456, -9.544149624137777, 0.33359334866622886],
+    [12.196151900794263, -9.775676756453496, 0.35898686221316073],
+    [12.618940237841336, -9.986151541543247, 0.38204301350261544],
+    [-12.20766012820694, 4.081740127660143, -1.2566014842678142],
+    [-7.825575743884669, 1.662966489333396, -0.9717250706356475],
+    [-4.37916393761194, -0.24177194961794293, -0.7489988712685193],
+    [-1.6242094149031983, -1.7648122238240147, -0.5720010075824277],
+    [0.6076984135315128, -2.9977815794120235, -0.4294676929996446],
+    [2.4360077427884965, -4.005919241773083, -0.3134537886948336],
+    [3.947571399758246, -4.836841902651856, -0.21821289691262918],
+    [5.207134761262696, -5.526189258966817, -0.1394810800629349],
+    [6.264739163998905, -6.101653344824271, -0.07397224060834989],
+    [7.16119813495038, -6.586000193438456, -0.019004936856532772],
+    [7.930455240540984, -6.998330557694572, 0.027657455135939927],
+    [8.599863638753666, -7.354143598658648, 0.06782240305479201],
+    [9.190715203543762, -7.665562394923521, 0.1028994685588667],
+    [9.719298251801519, -7.941891732824051, 0.13396799406153131],
+    [10.19804113120443, -8.190247267778236, 0.16185174450702577],
+    [10.636484195402742, -8.416099263153372, 0.18718287263766312],
+    [-12.619251821076336, 4.875561568331487, -1.3827693511255519],
+    [-8.79204865842731, 2.743474259109789, -1.128353698478821],
+    [-5.709129941462147, 1.0239414597605256, -0.9244065498508486],
+    [-3.1912857640207273, -0.3810213702637682, -0.7586482473635384],
+    [-1.1108866950652752, -1.5414563749370687, -0.6223689320751534],
+    [0.6251620539898682, -2.5086398120876177, -0.5092408752409159],
+    [2.0862241726262796, -3.320914582398563, -0.41456740389677377],
+    [3.3250160648942026, -4.0075277451625055, -0.3347914446631584],
+    [4.382465385551772, -4.5912762933988684, -0.2671591237518743],
+    [5.291321411617641, -5.090493624486708, -0.20947004136842065],
+    [6.078851678036336, -5.52054860732185, -0.1598906394181562],
+    [6.76812414280539, -5.894539149454199, -0.11686601734062345],
+    [7.378239442062642, -6.223376566195106, -0.0791056614900941],
+    [7.924573047260735, -6.5158831860829425, -0.04556906779160619],
+    [8.41928781865352, -6.779059879033331, -0.015432429335699691],
+    [8.871950220130516, -7.01842518685554, 0.01195236848415918],
+    [-13.093015134372436, 5.6307044468432075, -1.5009068774646772],
+    [-9.691935970573345, 3.7205455972188535, -1.2703194683953303],
+    [-6.898169204776604, 2.1496443802140597, -1.0816628404777673],
+    [-4.575869783924325, 0.8431396691805154, -0.9254724112006615],
+    [-2.625694633703016, -0.2539005095632051, -0.7948490046228849],
+    [-0.9734760122980225, -1.1826257849095192, -0.6846577285426346],
+    [0.4371885579128572, -1.974434210857524, -0.5910050047801583],
+    [1.6499233849894572, -2.6536923077415198, -0.5108873499628608],
+    [2.699029768016215, -3.2396258472434645, -0.4419485341500396],
+    [3.6119771531475116, -3.7476901613226357, -0.38230477778347016],
+    [4.411311056919175, -4.190629087768094, -0.33041092370325015],
+    [5.116098528282442, -4.579283568237188, -0.28495898392357283],
+    [5.7427035553889985, -4.923016719992257, -0.2448240450967661],
+    [6.3049853245492296, -5.229801932828236, -0.20905136376919345],
+    [6.814415527296874, -5.506266605877967, -0.1768494533147159],
+    [7.2803293063566485, -5.75782028513982, -0.1475736646225206],
+    [-13.59511297997322, 6.344518037915921, -1.6113999984370018],
+    [-10.531015316881449, 4.611116658986428, -1.39997464138255],
+    [-7.972776051073804, 3.1622516290489173, -1.2240396052073848],
+    [-5.8146612004521225, 1.939244439166709, -1.076117371359841],
+    [-3.977603123766035, 0.8980791533050421, -0.9506310096880956],
+    [-2.4013981501532498, 0.005137228750145342, -0.8433447081391485],
+    [-1.0394583318782527, -0.7656771707282626, -0.7509882213015006],
+    [0.14480522873131116, -1.4349226059520301, -0.6709985903166796],
+    [1.180532573306139, -2.019023916370216, -0.6013383750243426],
+    [2.0912585923756017, -2.531290245843613, -0.5403644695943203],
+    [2.89629171299033, -2.9826800239838467, -0.48673012389630727],
+    [3.6117908388606788, -3.3824030214985035, -0.43930841516009295],
+    [4.251595436971183, -3.7383873008612976, -0.39713323353739177],
+    [4.827724574711462, -4.057556179912546, -0.35936398327505703],
+    [5.350547830381408, -4.345912713405136, -0.3252740019085332],
+    [5.828851941825759, -4.60856389477692, -0.2942466064727602],
+    [-14.107018351014322, 7.018159340912474, -1.7148776122499547],
+    [-11.315748439039615, 5.428783046577167, -1.5192045299853698],
+    [-8.953059770842257, 4.081972174792037, -1.3540361971789412],
+    [-6.934818902735052, 2.930738195780369, -1.2133417013432926],
+    [-5.196901202397833, 1.939165745585767, -1.0925345922888583],
+    [-3.6896479080450915, 1.0793623858484118, -0.988070420929532],
+    [-2.3740473509847093, 0.3293535077349369, -0.8971699930351282],
+    [-1.2190475454940188, -0.32840194094473313, -0.8176249753154357],
+    [-0.19962756395750822, -0.9080789203306088, -0.7476586369017849],
+    [0.7046135270851284, -1.4212692228306185, -0.685824131893204],
+    [1.5105115146034467, -1.877568581819976, -0.6309285680498958],
+    [2.2321476667613878, -2.285029859660023, -0.581974707041497],
+    [2.8814914282882063, -2.6505245835831586, -0.5381147420805679],
+    [3.4689045061237915, -2.980027954107679, -0.4986140339362513],
+    [4.00347395844136, -3.278805627440442, -0.46282724186057],
+    [4.493157367625775, -3.5514899336713426, -0.4301881964773813],
+    [-14.618463774165848, 7.65433859493192, -1.8120246742912283],
+    [-12.052377191645288, 6.184524249741814, -1.6295348016999787],
+    [-9.854622506237305, 4.92435045832075, -1.4736061036443686],
+    [-7.9569932218162105, 3.8355023112698428, -1.3392886269382278],
+    [-6.306640351508003, 2.8882145445205514, -1.2227542238856535],
+    [-4.8620301491261495, 2.0590334879945513, -1.1209996551085586],
+    [-3.590100328810597, 1.3292401599898809, -1.031638084549201],
+    [-2.4642212545115707, 0.6837178232350793, -0.9527498496334001],
+    [-1.4627087727644001, 0.11012443040475223, -0.8827737877608911],
+    [-0.567721552097642, -0.40172221759328336, -0.820426785221779],
+    [0.23557008368500884, -0.8603090226498809, -0.764643219953323],
+    [0.9596223019046637, -1.2727853095247474, -0.7145285210065311],
+    [1.6150187107421907, -1.6452447523464464, -0.6693227370386076],
+    [2.210872556578279, -1.9829528838633659, -0.6283712868419222],
+    [2.755147284944652, -2.290529194951532, -0.5911016440473869],
+    [3.2548843031402166, -2.5720759250571543, -0.5570068189036933],
+    [-15.123819004172459, 8.256292137458464, -1.9035046353325655],
+    [-12.746593556426276, 6.887280516680279, -1.7322125756793305],
+    [-10.689794129354839, 5.701584883691821, -1.5843049025382872],
+    [-8.897234465195812, 4.667473667390979, -1.4556574517626375],
+    [-7.32474783253941, 3.7599435075798637, -1.3430336462557666],
+    [-5.937171710659492, 2.9590403213409053, -1.2438606555248424],
+    [-4.706186994362398, 2.2486545273056113, -1.1560692488779518],
+    [-3.608743007219504, 1.61564158305295, -1.0779772488070134],
+    [-2.6258915834287158, 1.0491698908512928, -1.0082033851992325],
+    [-1.741911427133878, 0.5402301939966118, -0.9456026901012705],
+    [-0.9436412546239987, 0.0812612235100469, -0.8892173624399557],
+    [-0.21996467688282845, -0.3341401235055446, -0.8382388579115981],
+    [0.43859397345416046, -0.7114468072027489, -0.7919781718764811],
+    [1.040192463386602, -1.0553844091715383, -0.7498421167212146],
+    [1.5919327990306902, -1.3700801729606793, -0.7113140596139242],
+    [2.100073158247028, -1.659183930857599, -0.6759383498106231],
+    [-15.620129257217503, 8.827307596440209, -1.9899291754685415],
+    [-13.403456920265851, 7.544398603845306, -1.8282675626914413],
+    [-11.46847403029241, 6.423412832075726, -1.6873909694052136],
+    [-9.768249564844387, 5.437714052099418, -1.5638181946141807],
+    [-8.265420514213595, 4.566049418630288, -1.4547817769051647],
+    [-6.929897752173243, 3.791265341788113, -1.3580559945739439],
+    [-5.73719489178205, 3.099370927913634, -1.2718317604196079],
+    [-4.667192047343483, 2.4788442291698303, -1.1946242085122636],
+    [-3.703208382179391, 1.9201110269296187, -1.1252034604586347],
+    [-2.831297208300666, 1.4151480726133048, -1.0625420749849883],
+    [-2.039703605263378, 0.9571772913995475, -1.0057746638862404],
+    [-1.3184420423856673, 0.5404272024395343, -0.9541664790778416],
+    [-0.6589633789832112, 0.15994442539747802, -0.9070886716109783],
+    [-0.053888831799382905, -0.18855728794354606, -0.8639985414168444],
+    [0.5032056361876833, -0.5088197856662761, -0.824423537968332],
+    [1.0179662540789611, -0.8041415937937926, -0.7879481322844405],
+    [-16.106014255074026, 9.370512668160869, -2.0718494000071366],
+    [-14.027413352528162, 8.161972057667075, -1.918558220095247],
+    [-12.198721540601007, 7.097723319943598, -1.7838962391587316],
+    [-10.580259095234572, 6.155141421215501, -1.664891212317045],
+    [-9.140077202378581, 5.315971966479645, -1.559153070589346],
+    [-7.8521818727629755, 4.56533211670492, -1.4647395205603433],
+    [-6.6952210668385685, 3.8909712563958245, -1.3800568340214134],
+    [-5.651500479117354, 3.2827160753167437, -1.3037855733816834],
+    [-4.706236169708128, 2.7320486428663022, -1.2348242637981826],
+    [-3.8469802753247624, 2.2317817449087722, -1.1722461634169983],
+    [-3.0631747839642096, 1.7758062447983873, -1.1152657128506527],
+    [-2.345801107722472, 1.358892364477858, -1.0632122161170199],
+    [-1.6871019884893925, 0.9765317056579473, -1.0155089747689348],
+    [-1.0803584306770937, 0.6248102776490961, -0.9716565649982728],
+    [-0.5197087404290549, 0.30030525357455473, -0.9312192804059684],
+    [-6.399017348275175e-14, 4.269551513915897e-14, -0.8938140093540586],
+    [-75.5240908756414, 23.854097699264507, -2.251895834161029],
+    [-4.927783870279219, -9.261059221002052, 1.157396797495163],
+    [17.655484741187088, -20.609114286289437, 2.352209426769404],
+    [25.321570609830022, -24.441793587528302, 2.755751598812027],
+    [28.356763730600747, -25.815606876740933, 2.893788229883396],
+    [29.997593802967916, -26.451843209853664, 2.952295334729971],
+    [31.052825366583484, -26.788233221756883, 2.978942237730639],
+    [31.807138358629725, -26.9786554871697, 2.9904803612797948],
+    [32.38734343837278, -27.09088167791112, 2.994253334479277],
+    [32.85985429315708, -27.159774420275696, 2.9940106859640245],
+    [33.263474742044316, -27.205236911881908, 2.991871974911547],
+    [33.622687952424826, -27.239485170981975, 2.9891223938072384],
+    [33.953856238914256, -27.270432093632184, 2.9865826051047133],
+    [34.26842367485325, -27.303427299938747, 2.9847985208192624],
+    [34.574701035239265, -27.342219953034874, 2.984146020127278],
+    [34.87892158559119, -27.38952288477686, 2.9848920632529405],
+    [-23.664461923008265, 3.429151787839963, -0.3717951873625135],
+    [-0.14568212366617855, -8.370959747554, 0.886774885349958],
+    [11.124100750908688, -14.188498356903132, 1.5123713407450696],
+    [16.788924072503388, -17.11652769899292, 1.827611700382477],
+    [19.75946652830974, -18.603388290925217, 1.9856484706324258],
+    [21.56517384805176, -19.460568255691225, 2.0747128022016024],
+    [22.815909435227862, -20.019834160276336, 2.1312600637540777],
+    [23.762402386751848, -20.418703876733577, 2.1704505506107283],
+    [24.52477739032727, -20.723156526786198, 2.199561416790492],
+    [25.167858318038547, -20.968687766070087, 2.222502298366832],
+    [25.73011089234107, -21.176231014860026, 2.2415728735873053],
+    [26.23612928954097, -21.35905971908769, 2.258226262713902],
+    [26.702584250730666, -21.526082815878, 2.273433804057107],
+    [27.141317264400204, -21.683560430816733, 2.2878750033716897],
+    [27.561071762041625, -21.836063552804553, 2.302043757111474],
+    [27.96852087809996, -21.987043035642348, 2.3163112931123497],
+    [-13.184415313248941, 0.30609157607711057, -0.16225508677896383],
+    [-0.3994862152275322, -6.313087273136513, 0.5610969961859071],
+    [7.000748498448382, -10.201150274050248, 0.9865508485364081],
+    [11.477962598024, -12.560120748115288, 1.2448135645714964],
+    [14.250916158473059, -14.003405854207465, 1.4021205615424226],
+    [16.079928641031458, -14.932182342996816, 1.5024290051422902],
+    [17.396745564157754, -15.58121907010181, 1.5717499033009217],
+    [18.41608976136552, -16.068809256901996, 1.6232511885773822],
+    [19.249414952216732, -16.456712068908683, 1.6638208627865068],
+    [19.95953096823746, -16.779806617013037, 1.697351063475134],
+    [20.584492476622138, -17.059216455508917, 1.7261979086221855],
+    [21.14883846356832, -17.308532465061123, 1.7518756108169828],
+    [21.669258945540136, -17.53696640342203, 1.7754088695649402],
+    [22.15762894471631, -17.75104469394925, 1.7975223405110339],
+    [22.622727795563378, -17.955570110166363, 1.8187482691622978],
+    [23.071265025634705, -18.154196207720272, 1.8394907960362712],
+    [-10.009235921760483, 0.08069674999324908, -0.21979124680483475],
+    [-1.4822255774917183, -4.424674635789429, 0.28228912491885916],
+    [4.03487861057164, -7.36516016099296, 0.6093670803676138],
+    [7.740319401056404, -9.345501597276476, 0.8295044927631484],
+    [10.285873368454988, -10.699454514821628, 0.9797019700074335],
+    [12.08500806456645, -11.64458749322545, 1.0841031502627942],
+    [13.423646822767578, -12.335942305368677, 1.160047444070228],
+    [14.476360426969725, -12.869821458773423, 1.2183598784064424],
+    [15.344001531575932, -13.302352308296667, 1.2653653274792027],
+    [16.086468584520393, -13.667026359095837, 1.304842262917466],
+    [16.741154906272854, -13.984800396625358, 1.339155916966214],
+    [17.33256629589366, -14.269418012349943, 1.369857162676165],
+    [17.877489376392006, -14.53028910342675, 1.398007241868534],
+    [18.387891786819917, -14.774113716838647, 1.4243611601580806],
+    [18.872615919736035, -15.005833690637129, 1.4494752696336033],
+    [19.33840691265625, -15.22921163319352, 1.4737727620203285],
+    [-9.041157654690338, 0.6125319767976816, -0.33945004790366],
+    [-2.6918089039598656, -2.794816880030393, 0.046826790450653605],
+    [1.7285598688583348, -5.180304281914895, 0.31635561568531523],
+    [4.902399526812981, -6.896984164157156, 0.5099942350848254],
+    [7.231335636400346, -8.154327394107568, 0.651593505487412],
+    [8.972276234452472, -9.088221117213616, 0.7565198869062539],
+    [10.310623387796673, -9.798927108607495, 0.8361228620146011],
+    [11.379536807207913, -10.359872414562906, 0.8987444820807646],
+    [12.266563955181025, -10.819894676341876, 0.9499468286472511],
+    [13.027596510350705, -11.210381576485918, 0.9933093458918072],
+    [13.69890979512098, -11.551785887207206, 1.0311659221239897],
+    [14.304822257934573, -11.857850955605295, 1.0650851099443641],
+    [14.862210128171455, -12.138125751709696, 1.096156598534985],
+    [15.383180597069732, -12.399463586849064, 1.1251621692016682],
+    [15.876695410675524, -12.646936667703274, 1.1526801276188148],
+    [16.34958440229243, -12.884409020469421, 1.1791507758386972],
+    [-8.924454663430312, 1.3657868414268601, -0.47186127006324236],
+    [-3.8680174806744887, -1.3827242364675902, -0.15546779385559373],
+    [-0.16016681137148497, -3.406015293480574, 0.07653770092478358],
+    [2.6290579575519315, -4.930768008541049, 0.2509644540663945],
+    [4.76881488074999, -6.099687493480205, 0.38445186338120907],
+    [6.436925212231723, -7.007836872980095, 0.4879807767357723],
+    [7.759991271961719, -7.7237744674956135, 0.5694360648725755],
+    [8.83502520414328, -8.3009353106249, 0.6349635418526023],
+    [9.734071528779715, -8.779548027456602, 0.689196730360056],
+    [10.507505182603197, -9.187985508394286, 0.7354071046342003],
+    [11.189840949736418, -9.545793311121214, 0.7758496145857625],
+    [11.804947112079372, -9.866535309119294, 0.8120894465607539],
+    [12.369695034166782, -10.159821091789414, 0.8452354489365088],
+    [12.89631951018025, -10.432629517426953, 0.8760927350815005],
+    [13.393926432101248, -10.690159289307852, 0.9052608408140308],
+    [13.869467284133082, -10.936381200985656, 0.9331974960247004],
+    [-9.18428614862586, 2.1719482373384853, -0.6026620308023881],
+    [-4.9740215542321895, -0.14178929604497292, -0.33263858207756203],
+    [-1.7645693489553436, -1.9106039576495097, -0.1270189558614467],
+    [0.7346460456594779, -3.2899676174409462, 0.0328956672159583],
+    [2.714639206576609, -4.3825634291745965, 0.15931514695858098],
+    [4.3062088455835426, -5.2591191856002615, 0.26057278389715327],
+    [5.6029583917464105, -5.970647349601832, 0.3426407297472852],
+    [6.676187816166181, -6.55644678010265, 0.4101044071014925],
+    [7.582420776178666, -7.048081975790701, 0.46664408650213385],
+    [8.365032001703641, -7.4700259060946586, 0.5151139021154088],
+    [9.055882279117698, -7.8403797191717555, 0.5576254078033115],
+    [9.677985984867204, -8.172278866689764, 0.5957109925131383],
+    [10.248041894700872, -8.475284203692105, 0.630485911509592],
+    [10.778351250559666, -8.756454339401326, 0.6627733388229541],
+    [11.278154268142767, -9.021099774815735, 0.6931923697898711],
+    [11.754538693479546, -9.273298629111519, 0.7222182272498245],
+    [-9.624180328404123, 2.9721036836963095, -0.7275802395517195],
+    [-6.006668608914733, 0.9650702582646891, -0.4904511723025789],
+    [-3.1648421791932795, -0.6151622598273898, -0.3044413257534515],
+    [-0.8918172592274765, -1.8806122372364997, -0.15589708252172005],
+    [0.9538501898293896, -2.908191145349144, -0.03552802817942706],
+    [2.4720990017745503, -3.752501609871318, 0.06320904927186745],
+    [3.7358276370817043, -4.453598572160543, 0.14508296632834294],
+    [4.8002766849238, -5.042055933687659, 0.21371640688346405],
+    [5.7093778267919975, -5.5424246707549845, 0.2720101056298475],
+    [6.498843704869794, -5.974870424018107, 0.3223438361007808],
+    [7.196917674522576, -6.355473472212036, 0.3666144595449243],
+    [7.825190078199666, -6.696597596579262, 0.40628005390429694],
+    [8.399915827675768, -7.007588794191802, 0.4424424844482532],
+    [8.93334660498633, -7.295511646559243, 0.4759343427287949],
+    [9.434814433698495, -7.565758614969529, 0.5073908781242128],
+    [9.91153612175133, -7.822506622159774, 0.5373039006557676],
+    [-10.153558418449268, 3.745679265453828, -0.8456796730449201],
+    [-6.972419965635918, 1.9658218076725504, -0.6330526717183913],
+    [-4.412687152495995, 0.530990040211731, -0.46222897209781755],
+    [-2.3209593399613824, -0.642710271341433, -0.3228694240454989],
+    [-0.5889520251028504, -1.6147301036686978, -0.20770035845837895],
+    [0.8619027244609804, -2.4283847933048492, -0.11145772172556564],
+    [2.0900931835667595, -3.116068197087859, -0.03022791532614547],
+    [3.1403361740840294, -3.702679621419538, 0.038982455487846],
+    [4.047951483003718, -4.208017002833838, 0.09854447955539333],
+    [4.841875109352012, -4.64843427458149, 0.15041181090068723],
+    [5.546133103088357, -5.0376261957648705, 0.19621812319062545],
+    [6.180243242906773, -5.3867949983242935, 0.23729868846359695],
+    [6.759626777520897, -5.704836357856057, 0.27471322549864513],
+    [7.296292201031608, -5.998702438320238, 0.3092894760349431],
+    [7.799577881303657, -6.273814003854121, 0.341672483518721],
+    [8.276798085070448, -6.5344254853523065, 0.3723681947757192],
+    [-10.72759916775929, 4.486854456736613, -0.9571927005243208],
+    [-7.8800067437038654, 2.881581071715882, -0.763498444290936],
+    [-5.543367538833929, 1.562280062953036, -0.6048041002618325],
+    [-3.6001636792866765, 0.46412110045229943, -0.473044090217367],
+    [-1.9652176411984552, -0.4600519720530137, -0.36238699275791847],
+    [-0.5753217384277789, -1.2453623671685108, -0.2685139620350038],
+    [0.6174561994270588, -1.9185507404658602, -0.188153883604603],
+    [1.650243611327185, -2.5004204607126783, -0.11877357868447669],
+    [2.5524666782864593, -3.0075425019942568, -0.058363107287835156],
+    [3.3480962897112465, -3.4534973147504098, -0.0052804318562813335],
+    [4.057214991698648, -3.849743491997361, 0.041856747130846805],
+    [4.696811122041186, -4.206043928795406, 0.08422438881770242],
+    [5.281015985486005, -4.530568345673635, 0.12280555232007755],
+    [5.821312349094421, -4.829985159157219, 0.15840200465884488],
+    [6.3268991821805995, -5.109654248789048, 0.19165770969684673],
+    [6.805121941289354, -5.373866206967426, 0.2230877805566975],
+    [-11.323158222700382, 5.19574886971601, -1.0627459419004055],
+    [-8.738066517756168, 3.7285279150306603, -0.884099853238479],
+    [-6.582068334110178, 2.5031864495164116, -0.7353237498718042],
+    [-4.762685304591842, 1.4683121069706209, -0.6099681229701465],
+    [-3.2113823780324577, 0.5856806493203662, -0.5032624383154944],
+    [-1.8763159738199, -0.17371956158968468, -0.4116035026406576],
+    [-0.7175248292236723, -0.8323293435914579, -0.33221680543245696],
+    [0.29635063031137476, -1.407825191142613, -0.2629259121069133],
+    [1.190371191272539, -1.9143913232027723, -0.2019908335469913],
+    [1.9849684865173112, -2.363645982030112, -0.14799093751533587],
+    [2.697188689638844, -2.7653352458399207, -0.09973771670171606],
+    [3.341573288934096, -3.127824726426398, -0.05621315602552568],
+    [3.930628778983049, -3.458354084224863, -0.016537620776013177],
+    [4.474978179500299, -3.7631053510637473, 0.020039154404718026],
+    [4.983466145443167, -4.047246739722043, 0.054143797440606906],
+    [5.463354472089727, -4.315034614851889, 0.08629191977917508],
+    [-11.92808772937597, 5.87484823293623, -1.163066514665784],
+    [-9.554427449065763, 4.519284706910708, -0.9966488712148649],
+    [-7.547465344889871, 3.37177438712216, -0.856131652677703],
+    [-5.832772102101444, 2.3906487340022893, -0.7362488386700135],
+    [-4.354133470378143, 1.544344571792195, -0.6330292579916673],
+    [-3.0683161702192567, 0.808513827707241, -0.5434224922891735],
+    [-1.941514344010707, 0.1640578819030471, -0.46504533540693205],
+    [-0.9468771474962805, -0.4042428062025823, -0.3960056769297493],
+    [-0.06274769405902958, -0.9087071631348653, -0.33477723647963775],
+    [0.7286223284221435, -1.3594698992036935, -0.28010822841670785],
+    [1.442035721219792, -1.7650204814269919, -0.23095295390117587],
+    [2.090082810914903, -2.1326152275832406, -0.1864197241682961],
+    [2.6836698803119754, -2.4685742538545963, -0.1457334166697427],
+    [3.2323171174232765, -2.7784446326691348, -0.10821477753698056],
+    [3.744262351365524, -3.067049184945026, -0.07327397730333017],
+    [4.226513624940574, -3.3385062486886987, -0.040407888820257795],
+    [-12.536115719042844, 6.527480562914035, -1.2588695638880367],
+    [-10.335968377366438, 5.26387868691819, -1.1025657355742544],
+    [-8.453844479917679, 4.181792561610824, -0.9690242755982454],
+    [-6.828683558707601, 3.2468179085126736, -0.8538680218465526],
+    [-5.4136079654609555, 2.4324714062566084, -0.7537407861181493],
+    [-4.172041533774985, 1.7180328238583125, -0.6660265679454065],
+    [-3.0750196826829286, 1.0870473206223683, -0.5886552713510766],
+    [-2.0992842682347876, 0.5262628612382821, -0.5199653064512435],
+    [-1.225906536004771, 0.024860637828838985, -0.4586044098390912],
+    [-0.43927163629701244, -0.42611381464799275, -0.40345658548653407],
+    [0.27368603733196867, -0.8341802872261773, -0.35358712901031175],
+    [0.9240707018982025, -1.205755459175359, -0.30820036978568016],
+    [1.5214806807413064, -1.5464107429681289, -0.2666069279238692],
+    [2.074343146107293, -1.8610615866079119, -0.2281997164101344],
+    [2.590113431778103, -2.15407776177447, -0.1924398881046628],
+    [3.0753503255691697, -2.4293202225588457, -0.15885197441834828],
+    [-13.144237922056552, 7.157154223344963, -1.3508182605785441],
+    [-11.088678341293841, 5.970416303850666, -1.2029986659180976],
+    [-9.312425654311149, 4.943980687834246, -1.0754158682565396],
+    [-7.764552918155013, 4.048981318833566, -0.9643748284304448],
+    [-6.405400633737172, 3.2628842075433515, -0.8670011404571417],
+    [-5.20363023078799, 2.5678387993100147, -0.7810254493513398],
+    [-4.1341446845854195, 1.9495130049012839, -0.7046315351781905],
+    [-3.176592064786637, 1.3962535691994113, -0.6363471344987335],
+    [-2.3142689156698375, 0.8984697295017721, -0.5749640321985554],
+    [-1.5333025569109235, 0.44817272637017835, -0.5194785412872318],
+    [-0.8220306519832051, 0.03862557714514578, -0.46904637867569987],
+    [-0.17052196094299502, -0.3359282363941395, -0.4229478255266181],
+    [0.42979991987949207, -0.6804784960679238, -0.38056037689965605],
+    [0.986452741264271, -0.999416983721027, -0.3413372169236932],
+    [1.5061175499303587, -1.2966637703351065, -0.3047911328063288],
+    [1.9947776552569254, -1.5757448744462146, -0.2704845823960074],
+    [-13.751324777642372, 7.767277073829899, -1.4395135896195024],
+    [-11.817776117711666, 6.645562322757701, -1.2988930761595519],
+    [-10.132226855686627, 5.666920598293573, -1.1764456627318072],
+    [-8.65158488724843, 4.806790085358919, -1.069009583034268],
+    [-7.341868506118572, 4.045758939790017, -0.9740938798748455],
+    [-6.175923008089467, 3.36827994927993, -0.889709557959181],
+    [-5.131783176880592, 2.761747708888872, -0.8142486590204847],
+    [-4.191477836524901, 2.215824044736568, -0.7463960930869299],
+    [-3.340142680743976, 1.7219366546906865, -0.6850642819639039],
+    [-2.5653515765900723, 1.2729005766482524, -0.6293439479212891],
+    [-1.8566048256101744, 0.8626279399861275, -0.5784664854917673],
+    [-1.2049314876024297, 0.48590188975061094, -0.5317747385595447],
+    [-0.6025755532503968, 0.13819769071204102, -0.4886999469865239],
+    [-0.04274510434648115, -0.1844606937680321, -0.44874332265164246],
+    [0.48058804251372567, -0.48561543333553925, -0.4114613399908366],
+    [0.9728413488520157, -0.7684624149363365, -0.376454533012006],
+    [-14.357349543773223, 8.36104951558849, -1.525496267339121],
+    [-12.527837728204597, 7.294886043701032, -1.3910408511395274],
+    [-10.920649489738242, 6.357610873996707, -1.2730502847291691],
+    [-9.498855066444234, 5.528063539738132, -1.168786462870827],
+    [-8.233006780061555, 4.789336664321536, -1.0760678711873963],
+    [-7.099336873186376, 4.127755672817954, -0.9931349839974042],
+    [-6.078446463619261, 3.532135794336336, -0.9185524639483722],
+    [-5.154336036838176, 2.993231987952003, -0.8511369234497975],
+    [-4.313677623067509, 2.503325458560013, -0.7899027526033707],
+    [-3.5452606240186393, 2.0559083646068372, -0.7340209122064554],
+    [-2.839564059671565, 1.6454400463860954, -0.6827871541326155],
+    [-2.188421890353513, 1.2671559303833193, -0.6355971739612478],
+    [-1.5847575490834487, 0.9169156121514865, -0.5919269119277958],
+    [-1.0223705372671297, 0.5910804193826064, -0.5513167221702289],
+    [-0.4957631249633056, 0.286413715959142, -0.5133585200658649],
+    [6.802561590870302e-14, -8.055616406173519e-14, -0.4776853589533545],
+    [-61.592036863424866, 18.367113834758662, -1.4138919933479475],
+    [1.8460735670300168, -12.147413791285308, 1.8368320114476224],
+    [20.048623659598537, -21.59995583039982, 2.897240379974271],
+    [25.659806692525578, -24.429939391213505, 3.2117120621908883],
+    [28.154197259569088, -25.56398841203956, 3.3311872343177313],
+    [29.670166442559424, -26.176421897238757, 3.391238461030089],
+    [30.75629188732337, -26.570817445933784, 3.4270802271246166],
+    [31.617709248553673, -26.859830405547083, 3.4517412926568682],
+    [32.351867645265294, -27.09616929655846, 3.4712767616735634],
+    [33.01266878831821, -27.308422464945128, 3.4889416864735625],
+    [33.633206389263066, -27.51376681307646, 3.5066723636474393],
+    [34.235453731359506, -27.723368243674035, 3.525715197516851],
+    [34.834957767544154, -27.94498438882139, 3.546929086869284],
+    [35.443354140623306, -28.18434922014269, 3.570945797423373],
+    [36.069835293121336, -28.445975774702674, 3.598262677037851],
+    [36.722073600529434, -28.73365861598831, 3.629300606882539],
+    [-17.226251143973737, 0.7692256817933507, 0.21646275143819982],
+    [3.4349496850757784, -9.877771969686112, 1.4015959906233388],
+    [12.790857338288802, -14.843910421845512, 1.9659462368596579],
+    [17.2662506653246, -17.203784964338936, 2.23439285744335],
+    [19.718272279556977, -18.45214397199855, 2.3745006712561314],
+    [21.338721255746364, -19.24345784038475, 2.461778627071319],
+    [22.555223584365553, -19.816335260281804, 2.5240067572084013],
+    [23.547948975909986, -20.271746147066306, 2.5729728793056683],
+    [24.406254930149665, -20.65970516661509, 2.614515585890369],
+    [25.18053915845991, -21.008301182839382, 2.6519091006144953],
+    [25.902175130337344, -21.334922605503415, 2.687176296173493],
+    [26.59226406646209, -21.651199159585204, 2.7216676349752995],
+    [27.265929764829988, -21.965429572626718, 2.756345784082689],
+    [27.934624989246018, -22.283885220861844, 2.79193848147956],
+    [28.60746792324686, -22.611565147503974, 2.8290270751604885],
+    [29.292071215097067, -22.95266443103601, 2.868101473838758],
+    [-9.06941373156632, -1.4720703766786778, 0.3457984371775109],
+    [2.102532196279216, -7.3873674338336, 1.0165743562404153],
+    [8.386706308186882, -10.770008283799813, 1.4045785083071938],
+    [12.089927083353043, -12.763825697080415, 1.6340887199390315],
+    [14.406145403898172, -13.993019401297145, 1.7751076186037893],
+    [16.019030351209615, -14.831533800326767, 1.8707070191001827],
+    [17.257263462621008, -15.462930666678316, 1.942280066346766],
+    [18.27829765685548, -15.975933164896711, 2.0002192405541277],
+    [19.164968989542253, -16.41739620006749, 2.0500256098179803],
+    [19.965154918016257, -16.814427683653527, 2.094884817367937],
+    [20.708916285325028, -17.184040297500385, 2.1367967977636457],
+    [21.41651573199651, -17.537691761472164, 2.177108835183706],
+    [22.102471571743767, -17.883588245251122, 2.21678651473263],
+    [22.77776217149569, -18.22793974535265, 2.2565615843264464],
+    [23.451105949902715, -18.575690257964162, 2.297018046465593],
+    [24.12975064481238, -18.930968773877666, 2.3386453947101575],
+    [-6.9489469411382965, -1.3046217998552707, 0.2502628921572575],
+    [0.5231817195546625, -5.326447496808225, 0.7120466467426417],
+    [5.284235493254866, -7.917274346186691, 1.0116981502612297],
+    [8.439258052335415, -9.638290733412047, 1.2115118857475917],
+    [10.604822558896434, -10.812660468415528, 1.347896269725173],
+    [12.181561866871366, -11.658312167773996, 1.445918772210778],
+    [13.41345630510066, -12.311270176817864, 1.5214403544105795],
+    [14.435688970022806, -12.847834943783514, 1.583417863209623],
+    [15.324601494044398, -13.311377239614375, 1.6369609556987221],
+    [16.125932680217215, -13.727976731194763, 1.6851487699269816],
+    [16.868867594694514, -14.114308807872101, 1.729952911512261],
+    [17.573177099365278, -14.481685393017683, 1.772712424313708],
+    [18.253016735653933, -14.838216734826345, 1.8143886344116094],
+    [18.91905176663864, -15.190025274100929, 1.855708540625412],
+    [19.57970800222499, -15.54196221393727, 1.8972495974229886],
+    [20.241945950935918, -15.898052162149439, 1.9394924045505946],
+    [-6.559609138737726, -0.5673346377327005, 0.1107244324838565],
+    [-0.9649767959186624, -3.615721133761317, 0.4643832775561414],
+    [2.896740209787141, -5.736582755743788, 0.7116154609364427],
+    [5.651202563716116, -7.253810113946137, 0.8890794480762022],
+    [7.669879900339598, -8.363538840466026, 1.0190843085099435],
+    [9.200557344958598, -9.200025357380982, 1.1170892528171792],
+    [10.417519740127531, -9.860111342338216, 1.1943925646033304],
+    [11.43323029116093, -10.40726395387448, 1.2584591191327428],
+    [12.317021608454676, -10.880954824280645, 1.3139528805972933],
+    [13.112394281920233, -11.306082673773325, 1.3638243749302712],
+    [13.847788406198793, -11.698984522824215, 1.410013514089455],
+    [14.54268543206873, -12.070881174268628, 1.4538549249703043],
+    [15.211073751160676, -12.429848675594066, 1.4963107706231344],
+    [15.863471393249005, -12.781975020222392, 1.5381077260565845],
+    [16.5081477945516, -13.132061607371975, 1.5798202198323519],
+    [17.15189038129819, -13.484064827332517, 1.6219229328252611],
+    [-6.811571549701115, 0.31200954618581933, -0.03334886695284088],
+    [-2.3255162461265075, -2.157147227537885, 0.2557992095431617],
+    [0.9473601870117123, -3.9693077433544506, 0.46866692417832],
+    [3.4019515182166673, -5.332301186657147, 0.6292049269792876],
+    [5.285726233287367, -6.378077094005484, 0.7526090149244021],
+    [6.765621034714664, -7.197156815330846, 0.8493587618686281],
+    [7.964389888070022, -7.857502775436343, 0.9273958206606873],
+    [8.971784907605887, -8.40968762093525, 0.9926803400478156],
+    [9.849165714336632, -8.888671509547732, 1.0493550568088827],
+    [10.637422647529247, -9.317914364929814, 1.1002095964106469],
+    [11.364186207020682, -9.713343725368413, 1.1471425333844347],
+    [12.048716605141767, -10.086101699175241, 1.1914846087690962],
+    [12.70494998005539, -10.444274729031502, 1.2342033453251149],
+    [13.343377965545406, -10.793968337338672, 1.2760306203402632],
+    [13.972225337612132, -11.139983958852207, 1.3175432131898257],
+    [14.598206346593026, -11.486255411830752, 1.3592148590410669],
+    [-7.338862253525791, 1.2103653046811955, -0.17220504590866237],
+    [-3.574102192761721, -0.8800185855829229, 0.07471987100555559],
+    [-0.7138987606957296, -2.4754362426357908, 0.26353357458174875],
+    [1.5107238878319618, -3.719510587256021, 0.411059796437987],
+    [3.275875988224258, -4.707125711998655, 0.5283731511375535],
+    [4.703000687549321, -5.504466310432551, 0.6232052483702873],
+    [5.881412308503648, -6.160924371709551, 0.7013524526805232],
+    [6.880329522564279, -6.715412201170932, 0.7674124499637843],
+    [7.752060360877116, -7.197722411348872, 0.8249260332758797],
+    [8.534219041304667, -7.6294648991870195, 0.8764717340302343],
+    [9.253397435361242, -8.026006678580401, 0.9238882333901345],
+    [9.928602878568213, -8.39837915278561, 0.9684982513135656],
+    [10.57376811629315, -8.75469689539342, 1.0112765576022886],
+    [11.19943638292066, -9.101119812833982, 1.0529644463238292],
+    [11.813871803933658, -9.442491413249186, 1.0941459129589637],
+    [12.423796344329729, -9.782764543890323, 1.135298589578275],
+    [-7.996341338656176, 2.090428458341661, -0.3039379788464193],
+    [-4.733322946117645, 0.2644286103840512, -0.08648493927349318],
+    [-2.176526406226828, -1.1714977696269973, 0.0846937516460459],
+    [-0.1321765629060576, -2.322137666123618, 0.22204865668677645],
+    [1.5312200846146566, -3.2590656630544963, 0.33404441098545534],
+    [2.9066133002479706, -4.03329453401454, 0.4267043294163841],
+    [4.062672315668118, -4.682896978156115, 0.5045291530317814],
+    [5.0528927564713815, -5.237908702830973, 0.5710827108449759],
+    [5.920180268720859, -5.7227371788968355, 0.6292753637730356],
+    [6.6979961436228415, -6.156614395565954, 0.6814100331623237],
+    [7.411458990027216, -6.554080925387953, 0.7292331628277884],
+    [8.079182250427221, -6.9259668276351585, 0.7740484627490388],
+    [8.71508452626755, -7.280402659638869, 0.8168364848762389],
+    [9.329802122250893, -7.623622585476762, 0.8583503428736969],
+    [9.931698714909547, -7.960544061859798, 0.8991850941545431],
+    [10.527568478731146, -8.295174160174716, 0.9398265115324204],
+    [-8.720378104367954, 2.9429474646548046, -0.42892753064254513],
+    [-5.823620424938052, 1.3103972027291337, -0.2330289117741786],
+    [-3.4979132653518326, -0.0040355793924062655, -0.07523088126219374],
+    [-1.5975265987272083, -1.0800339199698494, 0.05404989126172327],
+    [-0.020575487768075287, -1.9735935099189201, 0.16151631304485997],
+    [1.3066178550544185, -2.725460121377169, 0.25203239770712293],
+    [2.439161304423468, -3.3663387609455424, 0.32926067736499254],
+    [3.4199023277142433, -3.920321591442445, 0.39607817487354496],
+    [4.2835882371644, -4.407158153480628, 0.4548501255505207],
+    [5.058860167128454, -4.843302745954387, 0.5075542767370019],
+    [5.768729565459341, -5.242088072564561, 0.555798210355955],
+    [6.431122918867597, -5.613964281962057, 0.6008449320098167],
+    [7.059855536284266, -5.967021645943252, 0.6436740333450459],
+    [7.665658832585557, -6.3075672238226455, 0.6850503831134479],
+    [8.257034104544852, -6.640613122862252, 0.725582702049865],
+    [8.840898399970454, -6.9702493014868265, 0.7657684539181502],
+    [-9.481639406547664, 3.76859255159391, -0.5481966647700196],
+    [-6.862001550453232, 2.282627801300473, -0.36861751883062804],
+    [-4.71705794921146, 1.0631860085261986, -0.2212392123331702],
+    [-2.93339638048169, 0.04764685660723853, -0.09845260009809077],
+    [-1.4297042180920696, -0.8090777918829579, 0.005197696445879019],
+    [-0.1459857053574044, -1.5404208268959583, 0.09374433465303882],
+    [0.9632731623762572, -2.1718923032878075, 0.17025885252373238],
+    [1.9336356541045674, -2.7235617705842454, 0.23715672412673258],
+    [2.7938539426743914, -3.211787930898534, 0.2964087165679062],
+    [3.5679672595473666, -3.650375552931557, 0.349681552601897],
+    [4.276278788763486, -4.051089131549249, 0.3983994323899174],
+    [4.935563346203389, -4.423718990559059, 0.4437503769242167],
+    [5.559325697421472, -4.77618896475125, 0.48669768459174606],
+    [6.1583344257512795, -5.114842942063157, 0.5280137074774941],
+    [6.741238317047453, -5.444792544786993, 0.5683215529464103],
+    [7.315113768511359, -5.770231730082406, 0.6081330641124425],
+    [-10.266688078693434, 4.571586666683706, -0.6628901698370648],
+    [-7.86247205293014, 3.199645283292009, -0.4959897080000235],
+    [-5.8618132315515785, 2.0559398271077853, -0.35688426973011167],
+    [-4.173930964257269, 1.0899060657260686, -0.23937526430941883],
+    [-2.732330077552322, 0.264370766302768, -0.1389227660198628],
+    [-1.4870743255493082, -0.44869730760044463, -0.052113700599303286],
+    [-0.39976030025610115, -1.0709565465575084, 0.023683324836560653],
+    [0.5598710805113828, -1.6195642494657578, 0.09055026121598599],
+    [1.416306900055941, -2.1084923042575565, 0.15018210384587521],
+    [2.1899714519200795, -2.549479930727931, 0.20400407481397748],
+    [2.8983778895984647, -2.9526744486317216, 0.25325012176934963],
+    [3.5566597608525288, -3.3269118820982917, 0.2989968458546224],
+    [4.177656515914318, -3.679735551016883, 0.3421649655884753],
+    [4.772018781595259, -4.017431958312769, 0.3835228539751189],
+    [5.348504748157683, -4.345188750614658, 0.4237052570340439],
+    [5.914365324142835, -4.667312683324453, 0.46323960640970124],
+    [-11.070042285726515, 5.35732561819957, -0.7741125274367741],
+    [-8.836739047490163, 4.075822682666944, -0.6172496118667223],
+    [-6.952996813769001, 2.9933669608715308, -0.48480346389726847],
+    [-5.3444027441462705, 2.0681797039763246, -0.37161304814559076],
+    [-3.9554432252575134, 1.268993007149661, -0.27382839584499247],
+    [-2.743780100154107, 0.5718759455605833, -0.18851117831803882],
+    [-1.6764422560004517, -0.04188080728248635, -0.11336934383929954],
+    [-0.7272128019970734, -0.5872194714200725, -0.04657529677349268],
+    [0.12521993510843493, -1.0763475969163172, 0.013362692806420932],
+    [0.8986131148724459, -1.5194954590786596, 0.06769448858146399],
+    [1.6081700814595776, -1.9254796348342509, 0.11749781835118168],
+    [2.267225216897871, -2.302087274995629, 0.16372565652724144],
+    [2.8875556867493275, -2.656241759358641, 0.20722644881825839],
+    [3.4794115738233886, -2.99400123319458, 0.24874353034063837],
+    [4.051538196539737, -3.320555193713329, 0.2889142467504407],
+    [4.611335653480002, -3.640307775465212, 0.3282798453116227],
+    [-11.890483385878728, 6.131482729912226, -0.8828884626633634],
+    [-9.794842471972421, 4.922720545934552, -0.7340763492307087],
+    [-8.006858341579859, 3.8902733575522737, -0.607036713334373],
+    [-6.464271743130318, 2.9989273013615105, -0.4973898801665139],
+    [-5.119897573862766, 2.221918941478813, -0.40181674450731375],
+    [-3.9372597173533888, 1.5384955900678554, -0.31775018797002935],
+    [-2.887628916538365, 0.9322573392952618, -0.24316593372314152],
+    [-1.9479572405262022, 0.3899987618363031, -0.17643684725518444],
+    [-1.0993987227083668, -0.09912272814858707, -0.1162286364042186],
+    [-0.32621989279911223, -0.5442013631993396, -0.06142301623188053],
+    [0.38502315227533274, -0.9530491471006562, -0.011059587842836102],
+    [1.0461121664347792, -1.332552475935217, 0.035708507076656765],
+    [1.667599241334467, -1.6889168836366364, 0.07964494571951726],
+    [2.2590074226481573, -2.0277746952149474, 0.12144347384492493],
+    [2.8288333955258214, -2.3541743518297737, 0.16172638574885245],
+    [3.3845251770125864, -2.672555973862488, 0.20104124941868648],
+    [-12.729248535420892, 6.8997036165162235, -0.9901663008630744],
+    [-10.745663379597264, 5.749983075938159, -0.8478616201035699],
+    [-9.036633718786394, 4.758573692409955, -0.7252263078839454],
+    [-7.549123051321331, 3.8952694900956963, -0.6184805998253027],
+    [-6.242382957648713, 3.1368049168558976, -0.5247206216958545],
+    [-5.084537372685696, 2.4649312882655914, -0.4416739448840214],
+    [-4.050224575603239, 1.8650872135147092, -0.3675310161395624],
+    [-3.118928623407081, 1.3254562673012384, -0.30082597382753035],
+    [-2.2737700814915023, 0.8362826540681738, -0.2403503119789396],
+    [-1.5006077856672229, 0.38936153199947937, -0.18508874557223098],
+    [-0.7873551951499843, -0.022350256863026084, -0.1341703501755588],
+    [-0.12344588200425369, -0.405039738337645, -0.08683031192920453],
+    [0.5005806184855801, -0.7642752951463797, -0.042380259662372265],
+    [1.0933723688096115, -1.1051741726866615, -0.00018739338939916772],
+    [1.662903271767825, -1.4324775477181872, 0.04033481562309695],
+    [2.2164644253685752, -1.7505371764585445, 0.0797248608029594],
+    [-13.58912318496183, 7.6675471445101815, -1.096835794819608],
+    [-11.697327449055692, 6.56596087745258, -0.959803725722348],
+    [-10.053571815649573, 5.608240990736201, -0.8407481117263712],
+    [-8.611945224960133, 4.768066913182744, -0.7363571292988152],
+    [-7.3367513153922275, 4.024928907502415, -0.6440559995282288],
+    [-6.199773288192431, 3.3625764762176504, -0.5618089495593027],
+    [-5.17835709579346, 2.7679298332112583, -0.4879805595239061],
+    [-4.254037193284094, 2.230298438526251, -0.4212364331775441],
+    [-3.411529216682241, 1.7408072112522506, -0.3604704275870189],
+    [-2.6379768965786807, 1.291966601481757, -0.3047502463924732],
+    [-1.9223710906234825, 0.8773400315820056, -0.2532754656728767],
+    [-1.2550968464242391, 0.49128366625701614, -0.20534475587528592],
+    [-0.6275679636268434, 0.12873568232656343, -0.1603294084482775],
+    [-0.0319358319595258, -0.21495204790237202, -0.11765223907828087],
+    [0.5391215802258325, -0.5441353514532411, -0.07677241024832732],
+    [1.0925029864313462, -0.8629345872734314, -0.03717836320125284],
+    [-14.473992916812863, 8.440533856318458, -1.2037503456027405],
+    [-12.657533741063993, 7.378163920195562, -1.0709749438026193],
+    [-11.067648189200574, 6.447961389246826, -0.9548018193133638],
+    [-9.664011429577045, 5.6266615640750635, -0.8522887898160901],
+    [-8.414942132636817, 4.895950888103152, -0.7611246006213956],
+    [-7.295165892758586, 4.241189123661797, -0.6794651636911636],
+    [-6.28422694100287, 3.650501106934236, -0.605817368914546],
+    [-5.365336084603916, 3.1141172092802774, -0.5389548701937135],
+    [-4.524517663194183, 2.623884288857395, -0.4778557647190158],
+    [-3.7499666342445113, 2.1728964199393723, -0.42165562774868065],
+    [-3.0315490022495326, 1.7552073428343902, -0.36961103128717526],
+    [-2.360410533068531, 1.365604532151279, -0.32107093560947814],
+    [-1.7286577255817996, 0.9994243852312413, -0.2754533505163088],
+    [-1.1290946540496025, 0.6523992338594391, -0.23222606368978496],
+    [-0.5550069389359711, 0.3205315138552008, -0.19089083233944779],
+    [-2.1457411496319232e-14, 1.6475645761807512e-14, -0.15097163922517257],
+    [-51.118817901763805, 13.51985640259053, -0.6071783842044393],
+    [9.53931462274738, -16.6917654039214, 2.7749301046565233],
+    [23.791897106314426, -24.296025623029706, 3.67815347830712],
+    [28.05496851298538, -26.43415922226491, 3.9251112677160833],
+    [30.253008756641655, -27.433028275191322, 4.034216910145296],
+    [31.753685600522832, -28.06385748645581, 4.099844561716941],
+    [32.939368457385406, -28.54172391165842, 4.148311343712351],
+    [33.967253576822465, -28.9539027365035, 4.190234001828436],
+    [34.91817200250992, -29.34453307948718, 4.230940696924387],
+    [35.84021330770512, -29.739471075581708, 4.273531153608954],
+    [36.765299143840934, -30.155706194114515, 4.32003501202302],
+    [37.7167311670775, -30.60563110092817, 4.371936229281305],
+    [38.71316685282814, -31.099286475093702, 4.4304487748971235],
+    [39.77097893735585, -31.64569811930533, 4.496681433512343],
+    [40.905804962818316, -32.25376461331728, 4.571748095413388],
+    [42.13367436833223, -32.93291511689994, 4.65685054015593],
+    [-11.105892632856891, -2.5469886702224094, 0.909052073120704],
+    [7.76624279397826, -12.586974676765378, 2.0852904577586346],
+    [15.581371029533788, -16.84449711009701, 2.5955722064904228],
+    [19.21169684701606, -18.787046825330783, 2.8276415677503275],
+    [21.37469660356866, -19.906052295394726, 2.959639209873313],
+    [22.939037773270258, -20.693351429758753, 3.051573142971145],
+    [24.209627541077523, -21.32308900100769, 3.1248233344345926],
+    [25.321047187189272, -21.872194069947074, 3.1888494935667633],
+    [26.344470855661488, -22.381269038524643, 3.248645141806975],
+    [27.323011538961463, -22.874859662199437, 3.307230493414935],
+    [28.285729232785663, -23.369514697340083, 3.366645984187623],
+    [29.254072752274414, -23.877498834946852, 3.4284106950929303],
+    [30.24523509868041, -24.408730774813566, 3.4937621270965415],
+    [31.27411056241828, -24.971919053562342, 3.5637972849613915],
+    [32.35456674361093, -25.575307272331752, 3.63956591498871],
+    [33.50036582675879, -26.22722194905926, 3.7221397978099446],
+    [-4.97843174490858, -3.8702541802835455, 0.9384031351643062],
+    [5.216327293922392, -9.416926896261279, 1.5965583385280728],
+    [10.694197036964542, -12.44010096341375, 1.9607315818215239],
+    [13.842288208283565, -14.169156148911892, 2.1697092757352965],
+    [15.892873401312164, -15.278609852145262, 2.3034719114432565],
+    [17.422060627871605, -16.09431759893138, 2.4015742578129804],
+    [18.678091457381914, -16.758552108700396, 2.4814471277309793],
+    [19.78012236747241, -17.34000007048479, 2.5515433603344433],
+    [20.793161715439386, -17.876238955296007, 2.6165001285666234],
+    [21.756976407955264, -18.39030443889909, 2.67916756360584],
+    [22.69842398177728, -18.897802445746823, 2.741483316599704],
+    [23.63729883502448, -19.41029454516629, 2.80489009408713],
+    [24.589397394343848, -19.9370794342075, 2.8705560166622375],
+    [25.56828980222026, -20.486227700641745, 2.939503133648249],
+    [26.586451810214424, -21.065248131402623, 3.0126907124653863],
+    [27.656069390457763, -21.681567078747456, 3.091075737538872],
+    [-3.8804683390483237, -3.20588592929805, 0.7823291968879044],
+    [3.002584901267123, -6.999761618675312, 1.235491109786801],
+    [7.274081447532362, -9.379217328908993, 1.5231026029842467],
+    [10.056029834671065, -10.92923770916826, 1.711492116102343],
+    [11.994873418616756, -12.002101842571909, 1.8420934174189487],
+    [13.475693357099937, -12.814885562585209, 1.9410933986792651],
+    [14.700469288227172, -13.483374409669125, 2.0226290337258255],
+    [15.775679911253002, -14.069134433037583, 2.0942650250930224],
+    [16.761945164909353, -14.607400012823724, 2.160349002587809],
+    [17.696946317443853, -15.120161812221312, 2.223604483802564],
+    [18.606206566823058, -15.622378956392959, 2.285891804783486],
+    [19.50847775054093, -16.125101713664826, 2.3485922674077004],
+    [20.41863271814121, -16.637156280856452, 2.412816164848552],
+    [21.349345569515474, -17.16612853529177, 2.479524767366498],
+    [22.31215644510263, -17.718991772260974, 2.5496086315220907],
+    [23.31821627124591, -18.30255011987426, 2.6239433964618195],
+    [-4.109723424396128, -2.1558925692719746, 0.6030924800078633],
+    [1.1028971216982864, -5.056112566130567, 0.9510824762285733],
+    [4.6425290448575955, -7.042323129272445, 1.1917837389431467],
+    [7.140270184084672, -8.447013644201558, 1.3630587933858014],
+    [8.978223341035921, -9.477859580396249, 1.4891800321888804],
+    [10.41362712470683, -10.279142785182664, 1.5874280802075729],
+    [11.607976818780621, -10.943295826486006, 1.6690401900135068],
+    [12.656122902142329, -11.525212275699, 1.7407446712114485],
+    [13.615020390289827, -12.058083673902688, 1.8066317352183456],
+    [14.520948018598316, -12.563163593217206, 1.8693321340388855],
+    [15.398676692237867, -13.055035393869954, 1.930659131677837],
+    [16.2663874818132, -13.544461024984855, 1.9919577557619796],
+    [17.138420853249357, -14.039983256546416, 2.054302141468364],
+    [18.026903964159025, -14.54887937139268, 2.1186135461278264],
+    [18.942785006316726, -15.077771269331198, 2.1857362904104165],
+    [19.896550543947335, -15.63305216194622, 2.256491297796198],
+    [-4.798482944951326, -1.0476413756978769, 0.4298842807948699],
+    [-0.5695614172208343, -3.418299154300447, 0.7153480690920435],
+    [2.482937718560663, -5.141571109118325, 0.9246281668739207],
+    [4.757408955114104, -6.429400335329782, 1.0819831213434443],
+    [6.504851820364884, -7.418335848480476, 1.203327696515106],
+    [7.900382421537725, -8.206092367291065, 1.300281952549849],
+    [9.069283928660035, -8.864146154001293, 1.3814898718855695],
+    [10.094680873502417, -9.44058315875558, 1.4528270834116335],
+    [11.030003180598229, -9.966578584967477, 1.5181280503120904],
+    [11.91045073051612, -10.462799104922054, 1.5799477251623402],
+    [12.760406443906023, -10.943637556797052, 1.6400740027106002],
+    [13.597819409235917, -11.419745929379461, 1.699836895073525],
+    [14.436797017875548, -11.899545031407857, 1.760293908107121],
+    [15.289189363993032, -12.39015315516271, 1.82234468499732],
+    [16.165610866628793, -12.897988716438594, 1.8868058478482221],
+    [17.076149228625347, -13.429191594109602, 1.9544637248231356],
+    [-5.675321629068947, 0.04118221273307423, 0.26725718445841606],
+    [-2.0841246255553214, -1.9849084090701632, 0.5119736430575614],
+    [0.6242315718548634, -3.5219368314320616, 0.6989803751222253],
+    [2.722402087298764, -4.716339513240706, 0.8451415423829336],
+    [4.388822019340923, -5.6655543554443675, 0.9618177283613798],
+    [5.749026264544055, -6.439439434660309, 1.0572753514522302],
+    [6.89788177792229, -7.091883314704728, 1.137992952505421],
+    [7.905950389457419, -7.663629531742795, 1.208929623991816],
+    [8.822709817905757, -8.183552412267996, 1.2736283732146139],
+    [9.682330502478973, -8.67173923255575, 1.3345685096856934],
+    [10.5090183246363, -9.142502474241152, 1.3935256162849534],
+    [11.32073496700097, -9.606519165319309, 1.451831069955397],
+    [12.131589809158143, -10.072221199090956, 1.5105421101221599],
+    [12.953373122879993, -10.546693398264976, 1.5705523988686163],
+    [13.79657092529585, -11.036271976946487, 1.6326661670472773],
+    [14.67107659136463, -11.546967108259027, 1.6976509615351592],
+    [-6.642050360786801, 1.0967875980894268, 0.11420699839582395],
+    [-3.4913868474994945, -0.6908819511325597, 0.3307206520856399],
+    [-1.03605992535335, -2.0908686332044777, 0.5013418374999566],
+    [0.9222450628887675, -3.21070643314795, 0.6385443398799199],
+    [2.517593370692774, -4.123999140966939, 0.7509328367174635],
+    [3.845545281124074, -4.883960176502242, 0.844793513965478],
+    [4.978722150096937, -5.5316978351451755, 0.9250437219742217],
+    [5.974788623611846, -6.100403011225462, 0.9957043981335603],
+    [6.878242750292795, -6.616018725580632, 1.0599492654714981],
+    [7.721928346702118, -7.097861543752093, 1.1201578000861787],
+    [8.529934334251617, -7.560190366102234, 1.1780961968511046],
+    [9.320405667584769, -8.013803550677252, 1.2351089219295848],
+    [10.107645390156467, -8.467255376191462, 1.2922666993679524],
+    [10.903569441027605, -8.927706722568754, 1.3504708831220356],
+    [11.718710936440313, -9.401516834298022, 1.4105265835861358],
+    [12.562939692778057, -9.89467021167675, 1.4731958221616999],
+    [-7.66187231822576, 2.1239646209694993, -0.03148278553005912],
+    [-4.828923174401772, 0.5082125472983101, 0.16471292156877834],
+    [-2.564640629867069, -0.7884286031409968, 0.3229943823061088],
+    [-0.7176969795984424, -1.8488103402222356, 0.45304936989870576],
+    [0.8169692757127132, -2.7309869748583564, 0.5616951192086286],
+    [2.1155025072344924, -3.477491855778034, 0.65396121011323],
+    [3.235807850271102, -4.121077808119395, 0.7337559159780159],
+    [4.2244012370188, -4.688433245548117, 0.8042981119122905],
+    [5.119658763039059, -5.201881716820231, 0.8683085014820212],
+    [5.952321749652325, -5.679494889085771, 0.9280076957797715],
+    [6.746186355461203, -6.135366049248154, 0.9851382336311107],
+    [7.519660777312971, -6.580459157964076, 1.0410629484917953],
+    [8.287396981917265, -7.023542307818603, 1.0968773262515583],
+    [9.061603607697007, -7.471952298121596, 1.153502851647229],
+    [9.853022153616411, -7.93216888653427, 1.2117577532677217],
+    [10.671653665036244, -8.41024544862108, 1.272410490150124],
+    [-8.723041488376092, 3.1330674401109566, -0.17214528481006108],
+    [-6.125927791632836, 1.6446450057049025, 0.009033438206132024],
+    [-4.008245522409574, 0.42702192166244407, 0.15790581038987164],
+    [-2.2499448026252544, -0.5861621760980043, 0.28229547692634754],
+    [-0.765815684366055, -1.442333918821769, 0.38780387753648177],
+    [0.506891906778075, -2.1767088793578258, 0.47860780983689843],
+    [1.6161995137660714, -2.8164981842737324, 0.5579548235867567],
+    [2.600544756149598, -3.3837275344790863, 0.628494672449528],
+    [3.4921791490481784, -3.897108130571469, 0.6924992120262895],
+    [4.318660490638977, -4.372814132463427, 0.7519497164251447],
+    [5.102924371200937, -4.824426410162318, 0.8085216930578797],
+    [5.863548161627686, -5.26301478833073, 0.8635886429959476],
+    [6.615631138015541, -5.697619228222029, 0.9182778917864817],
+    [7.371826459867192, -6.135843886913357, 0.9735423916335251],
+    [8.143241546376405, -6.584384681333727, 1.030225427629462],
+    [8.940160417085007, -7.049456589503638, 1.0891135344819338],
+    [-9.825370991768262, 4.136040395450016, -0.3099821232291108],
+    [-7.4064010999338, 2.743345984758997, -0.1400547267549003],
+    [-5.401994591672436, 1.5863670117241302, 0.0016285568441354327],
+    [-3.713788730700194, 0.6102203569039839, 0.12158984989484907],
+    [-2.270645484514034, -0.22499406658797075, 0.22457217635743726],
+    [-1.0194260956423196, -0.949269665752173, 0.31414875411571413],
+    [0.08088301346245108, -1.5859292464540824, 0.3931091066550175],
+    [1.0631127428714948, -2.1538061300004037, 0.4637185085638934],
+    [1.9546925993582405, -2.668793072270827, 0.5279020017257554],
+    [2.7795271260700094, -3.1448902515561192, 0.5873690813837661],
+    [3.558770805004311, -3.594608845004482, 0.6436591091991787],
+    [4.31073767102168, -4.028860450695468, 0.6981231129958855],
+    [5.050934943847856, -4.456931837061926, 0.7519169111688174],
+    [5.792576996238838, -4.886765056932624, 0.8060335859253586],
+    [6.54729408370632, -5.325367732080358, 0.861353330879385],
+    [7.32581194509775, -5.779213442412119, 0.9186928377341322],
+    [-10.974833568248844, 5.145334608200231, -0.4470577038708835],
+    [-8.691355068796241, 3.824850873842049, -0.2855737857437927],
+    [-6.774204337742531, 2.7140561678090624, -0.14932848423384912],
+    [-5.140535460083057, 1.7663074688267815, -0.03273838124370673],
+    [-3.729460417859475, 0.9471842598045902, 0.06831504671197329],
+    [-2.4949211994600518, 0.23052209203429977, 0.15696523688748354],
+    [-1.4010609332569262, -0.4041665541291911, 0.23567134074258755],
+    [-0.4190826713058666, -0.9734476314140543, 0.30642917532769076],
+    [0.47498941511811316, -1.4912226443763135, 0.37092199384689],
+    [1.3019694035216922, -1.9696676479655095, 0.4306334406251197],
+    [2.0806611542949063, -2.419884228785277, 0.4869255773873336],
+    [2.828322246521303, -2.8521445871130147, 0.5410671064430467],
+    [3.5605174403134603, -3.2757684582881055, 0.5942154277010804],
+    [4.291022747184335, -3.699035780658548, 0.647403532796734],
+    [5.032129386115437, -4.129350191706726, 0.7015588915368759],
+    [5.795175468315697, -4.573548194284059, 0.7575412588325687],
+    [-12.181415277496363, 6.173854139199859, -0.5853661355237805],
+    [-10.000446703387915, 4.9072528256019, -0.4301330730461379],
+    [-8.14942028130351, 3.830794838698927, -0.29788864485089744],
+    [-6.556845424230782, 2.9038815325695313, -0.1837413745116821],
+    [-5.169448501614235, 2.0961601128140104, -0.08403687920627337],
+    [-3.9464826774148425, 1.3843254030880605, 0.004031145119881453],
+    [-2.8559671389987273, 0.7500031633601691, 0.0826777846288305],
+    [-1.8721034759039814, 0.17829233170413053, 0.15370351524145134],
+    [-0.9734215968740263, -0.3432846714575795, 0.21862108259105284],
+    [-0.14138525267605162, -0.8255766540964437, 0.2787517670436405],
+    [0.6406967657196951, -1.2784283125750733, 0.3353011962872978],
+    [1.3884592021944313, -1.7111321182588146, 0.38941407426015373],
+    [2.1168114694932734, -2.1326075315758244, 0.4421953773384274],
+    [2.839786624912143, -2.5512866514622003, 0.49469456840439496],
+    [3.5703708013900406, -2.9749900623535397, 0.547888755090949],
+    [4.320681296965169, -3.4110193144217664, 0.6026932898407321],
+    [-13.458457664962957, 7.235329274058515, -0.7269228641083707],
+    [-11.353325415794595, 6.007641690331457, -0.5761455435757558],
+    [-9.550548968860708, 4.955394874973423, -0.4466720748289299],
+    [-7.98706073616836, 4.0424742415698445, -0.3341263474500258],
+    [-6.615306977764129, 3.2415759691574264, -0.23520248452649195],
+    [-5.398563463380926, 2.5315490786764183, -0.14734012858067327],
+    [-4.3077932435554835, 1.8956102679068239, -0.06850667486286871],
+    [-3.3194625921853986, 1.3201007506264604, 0.0029541523094821966],
+    [-2.4139633310173165, 0.793585170142368, 0.06843206184730569],
+    [-1.5744190150554052, 0.30616717040276475, 0.12913340981674154],
+    [-0.7857347031313013, -0.15105943799447974, 0.18614822752937216],
+    [-0.03381030791518593, -0.5864677659849894, 0.24050615973960446],
+    [0.6950674583611508, -1.0082507021448954, 0.2932195893643184],
+    [1.4145147930183022, -1.4245824768927424, 0.3453032678033828],
+    [2.137927907880034, -1.8435271301529979, 0.39776198172571514],
+    [2.878271519070262, -2.2728934018262668, 0.45157161742998236],
+    [-14.822816972777098, 8.344936687843182, -0.8738716132044642],
+    [-12.770902740453744, 7.143313004392487, -0.7260037918831722],
+    [-11.000577059573954, 6.1062166930412705, -0.5981988454216752],
+    [-9.455039503628605, 5.200857008147343, -0.48646338810008166],
+    [-8.091035471183593, 4.402208657976334, -0.38775210615701644],
+    [-6.874884273585363, 3.690737115917415, -0.29968879055723674],
+    [-5.779781891019478, 3.0508523707521116, -0.22037691628952732],
+    [-4.783907596113804, 2.4698218130565905, -0.1482665984266586],
+    [-3.869046578114439, 1.936977497356607, -0.08205763562564078],
+    [-3.019544556795919, 1.44311231618267, -0.02062565298368022],
+    [-2.221471542073221, 0.9799945391666263, 0.037037347543972775],
+    [-1.46191157679248, 0.5399529623376821, 0.09187391471200779],
+    [-0.7283324579455188, 0.11550681573291233, 0.1448067249063518],
+    [-0.008053088909293524, -0.30094682219695845, 0.19677715461486756],
+    [0.7120624372121198, -0.7173072075322702, 0.2487666390560504],
+    [1.4455406098162007, -1.1417164943289424, 0.30178953470173553],
+    [-16.295618365896743, 9.520154967118563, -1.0286121338936711],
+    [-14.276726325137119, 8.33294815778994, -0.8822458369291007],
+    [-12.524194941154999, 7.302475300220082, -0.7550692641349671],
+    [-10.985833937337626, 6.398364722332182, -0.6433670456639373],
+    [-9.621573158092534, 5.597257227777738, -0.5442837800803307],
+    [-8.399996053332506, 4.880802394365872, -0.4555774333155322],
+    [-7.295962021894772, 4.234283243693953, -0.37545022633654956],
+    [-6.288923709917445, 3.64564176968808, -0.3024289114932654],
+    [-5.361695666453324, 3.104764587151843, -0.2352770593322052],
+    [-4.499516933266449, 2.6029376657705687, -0.17292811688368953],
+    [-3.6893006363955045, 2.1324082059876726, -0.11443158910981202],
+    [-2.918993953674853, 1.686009177979016, -0.05890684899227032],
+    [-2.1769923219646885, 1.2568139671660394, -0.005500553050784447],
+    [-1.4515744865984848, 0.8378022044755805, 0.04665466761734864],
+    [-0.7303732340600366, 0.42154736139163, 0.09847888492600462],
+    [1.3857750892266076e-12, -9.28230277607988e-13, 0.15097160501713666],
+    [-40.137951223876705, 7.389559275140078, 0.43469305535706887],
+    [18.86642531406734, -23.26014156866376, 4.080293269133647],
+    [29.165039381290317, -28.81805688472684, 4.770439635795711],
+    [32.61975408514912, -30.51496566403559, 4.970629747257701],
+    [34.6941243574966, -31.454398527410987, 5.076067357891884],
+    [36.26758182267929, -32.141311710469964, 5.1516204120046325],
+    [37.62401335330982, -32.7368513292422, 5.217883529707305],
+    [38.894002401703496, -33.31400809406301, 5.284116852358418],
+    [40.151417269918895, -33.913599746691126, 5.355475770299221],
+    [41.444953958549206, -34.56250647270474, 5.4353642752010956],
+    [42.81155628411186, -35.281420786735936, 5.526435668706153],
+    [44.28337223879114, -36.088891197692845, 5.631117767933182],
+    [45.89196519090896, -37.00381080726914, 5.751940280981789],
+    [47.67131260104236, -38.04723818707498, 5.891779105261713],
+    [49.66042977190694, -39.244047104955804, 6.054082850931301],
+    [51.90621561511166, -40.62477517584146, 6.243131706201011],
+    [-3.8666843215148994, -7.276200794377605, 1.8481252667710057],
+    [13.322009664263, -16.732467447544163, 3.017798819664126],
+    [19.64777706665139, -20.247543876777723, 3.459936135314792],
+    [22.719252611345475, -21.907120247615033, 3.666983293087721],
+    [24.7612203288588, -22.98215190954227, 3.7999873995321787],
+    [26.375020503267574, -23.822000632116815, 3.9037909828943675],
+    [27.783390394492628, -24.556478672797088, 3.995115382877902],
+    [29.093804041058256, -25.248216354316288, 4.082042615629293],
+    [30.36891162163512, -25.933774851665085, 4.169305903367181],
+    [31.650991419852684, -26.637973662037226, 4.260139311345072],
+    [32.972414638377145, -27.380043563977416, 4.357074661224123],
+    [34.3610032584141, -28.176799225769162, 4.462355017881557],
+    [35.84327254592752, -29.04458654172496, 4.5781914743550765],
+    [37.44675196169116, -30.00070702104664, 4.706954245071277],
+    [39.20197265542889, -31.064669323855647, 4.851343902232853],
+    [41.14450522686561, -32.25950308728769, 5.0145742543745815],
+    [-0.015339481055303118, -7.370907119938269, 1.7204969724599268],
+    [9.372130421127165, -12.630840745741017, 2.376515591826152],
+    [14.11059827871423, -15.307186634965076, 2.7149538871783054],
+    [16.85697985386699, -16.84282260863017, 2.9095328493654184],
+    [18.784550671715817, -17.90792626018562, 3.0444966862334],
+    [20.33537992222381, -18.760033747938895, 3.1527289884439376],
+    [21.695873175839186, -19.50861141091228, 3.248302928379849],
+    [22.960066846284093, -20.209131722495677, 3.338383552897042],
+    [24.183612952036807, -20.894581855294476, 3.4272543021692203],
+    [25.40427598618873, -21.587509069575525, 3.517864673928374],
+    [26.65097703050402, -22.305363569679475, 3.6125198365834743],
+    [27.948422592542247, -23.063251938912526, 3.713238300127771],
+    [29.319869679875488, -23.875601178856733, 3.8219704097407865],
+    [30.789079046769036, -24.75735508470609, 3.9407579493801252],
+    [32.38196270346736, -25.7250048735832, 4.071874204366948],
+    [34.12823264316239, -26.797640981390686, 4.217969316514645],
+    [-0.07613476859371952, -6.032250292388378, 1.4708769916364615],
+    [6.3879237172629395, -9.693315135867008, 1.9288788412493318],
+    [10.25206568151642, -11.898693083232404, 2.2083314796638143],
+    [12.752597162277274, -13.321710163745314, 2.389686851651582],
+    [14.576857005436443, -14.353822426451067, 2.5216635070017044],
+    [16.060446532494016, -15.190541569789914, 2.629058996984643],
+    [17.364153163348625, -15.926556727090523, 2.7239941116088278],
+    [18.57349792071275, -16.612575725549927, 2.813001223525719],
+    [19.740011391752276, -17.279342080514976, 2.9000660381143137],
+    [20.898778406896216, -17.9479439695614, 2.9879437326356335],
+    [22.076599231482753, -18.63464246785943, 3.078779062999802],
+    [23.2962393697857, -19.35340361765401, 3.1744338001720958],
+    [24.57895883889271, -20.117420617003642, 3.276685606499353],
+    [25.94627544131104, -20.94019056543545, 3.3873708354576704],
+    [27.421429985991765, -21.83642503999718, 3.5085074653009873],
+    [29.03083002449662, -22.822962276916595, 3.6424203083718933],
+    [-1.033587298790483, -4.540115475816721, 1.2287075869500785],
+    [3.9594975222650146, -7.3898412761790535, 1.5855596043625726],
+    [7.268640231101511, -9.292298048539907, 1.8266933581691611],
+    [9.580842861781608, -10.622264204160006, 1.9965073552615875],
+    [11.32282303176506, -11.62158296681828, 2.1247425223572916],
+    [12.750842775838084, -12.439269221772815, 2.2301466124916622],
+    [14.005432153511563, -13.158148477448384, 2.323263471847055],
+    [15.166128583074045, -13.82549491551623, 2.4101584575889],
+    [16.28206423077598, -14.470715938107302, 2.4946315627834643],
+    [17.386891242162992, -15.114102261366378, 2.57932565798553],
+    [18.50625150519281, -15.771245977178543, 2.666290520228876],
+    [19.66180319263573, -16.455440508903667, 2.7572916007775996],
+    [20.873639096624505, -17.179135340401604, 2.8539991154929836],
+    [22.161962097786144, -17.954956339625294, 2.9581225935767415],
+    [23.548460708584745, -18.79655575219579, 3.0715249232157866],
+    [25.05764861229651, -19.71945148441177, 3.1963368303696287],
+    [-2.2618306408882707, -3.0942709595788185, 1.0077497598111156],
+    [1.8656270034890525, -5.463956161443946, 1.3044118984472874],
+    [4.7947114552925285, -7.157087924077847, 1.5188250324362038],
+    [6.958519258331525, -8.410529123567455, 1.6788356161780902],
+    [8.637047235107993, -9.38213517457275, 1.8036050597488062],
+    [10.023260182962728, -10.183684809051037, 1.9070553448336873],
+    [11.239542355348831, -10.887258958267454, 1.998301054521665],
+    [12.360710620827662, -11.53737024511741, 2.0830246986324776],
+    [13.43456749655217, -12.162678478855408, 2.164914108414403],
+    [14.494213335849873, -12.783173162457155, 2.246562558393173],
+    [15.564851797842513, -13.414191443267272, 2.329977962791593],
+    [16.667656552486527, -14.068721485288803, 2.416877491764059],
+    [17.82214817758408, -14.75883111288022, 2.5088722741501974],
+    [19.047846566940457, -15.496671646872251, 2.6075990309235304],
+    [20.36561823462386, -16.295306382044522, 2.714830572988071],
+    [21.79897710097828, -17.16951962590753, 2.832585490738411],
+    [-3.592020255423588, -1.715136136258092, 0.8051025219540195],
+    [-0.023675184176939496, -3.773620270118661, 1.0624998969090202],
+    [2.633647849001569, -5.316150250497835, 1.2574986784660047],
+    [4.6784865441483285, -6.506539852288876, 1.40922831600547],
+    [6.307851570083968, -7.455481165118817, 1.5309642084610655],
+    [7.665093206298678, -8.245553618963603, 1.6328658969213925],
+    [8.854139584718409, -8.937818425801078, 1.7225891952308294],
+    [9.945222189291124, -9.574053076579261, 1.8054352622725514],
+    [10.985430595302251, -10.182510777122522, 1.8850215454602546],
+    [12.007972230954417, -10.783260634755878, 1.9639395504999844],
+    [13.038198430468183, -11.391730849592594, 2.0441995364280117],
+    [14.097311361806382, -12.020913177254027, 2.127510528160918],
+    [15.20473573410974, -12.682786664730504, 2.215463305486097],
+    [16.37979279067659, -13.38933227002499, 2.309662688063898],
+    [17.643065442242374, -14.153368677917491, 2.411838351462632],
+    [19.017709752262437, -14.989362655539122, 2.5239540402096803],
+    [-4.978539989073083, -0.38588064348650314, 0.6156233542418598],
+    [-1.7926456831352287, -2.2310124834240663, 0.84589184516258],
+    [0.6655259574019733, -3.662738897072292, 1.0264315290822947],
+    [2.615570540418131, -4.802019439683801, 1.1712833648532617],
+    [4.20581896435032, -5.732125868722081, 1.2903342461671528],
+    [5.5447877002035115, -6.515238809174343, 1.3911348989095005],
+    [6.7172803200264415, -7.200958062101704, 1.479836305464641],
+    [7.787652428154885, -7.827503124964496, 1.561251306029085],
+    [8.80233420171852, -8.422728218548878, 1.6389252873976128],
+    [9.795114282638144, -9.007057446474741, 1.7154816147404044],
+    [10.792005471029254, -9.596317061890144, 1.7929714976872513],
+    [11.81470701250853, -10.20378684768709, 1.8731322103517085],
+    [12.882976021793839, -10.841620814718125, 1.9575684592687395],
+    [14.016353924670874, -11.521891122975733, 2.047887658551514],
+    [15.235587815018148, -12.257454868120693, 2.1458141507456765],
+    [16.563995648439242, -13.062792579304777, 2.2533015227521678],
+    [-6.417115188004059, 0.9180067152669679, 0.4344095708262672],
+    [-3.501585308400325, -0.776203500133156, 0.6452874554244148],
+    [-1.1906946001278356, -2.1258271519953733, 0.8149340822583396],
+    [0.6856077593221184, -3.224954760010211, 0.9542177985733657],
+    [2.244872237437261, -4.139643114636628, 1.0709156906579251],
+    [3.573320382827847, -4.919148841702074, 1.170939867707105],
+    [4.739006123347955, -5.603057820040953, 1.2591371314178295],
+    [5.798079650990363, -6.224594258291853, 1.3396495285851089],
+    [6.7953467497611415, -6.810602448338863, 1.4158690137097172],
+    [7.765324721358455, -7.381938744011155, 1.4904580674518033],
+    [8.73513519427359, -7.955095230274838, 1.5655415832862365],
+    [9.727440471103888, -8.543923227667918, 1.6429206261601996],
+    [10.762760379300946, -9.161016148969622, 1.724247471562955],
+    [11.861264712649692, -9.81879027034338, 1.8111647063617802],
+    [13.044284450343214, -10.530402785755086, 1.9054254220543545],
+    [14.335772732247378, -11.310644125228762, 2.009011971626954],
+    [-7.920569281736771, 2.222383904845701, 0.25705540651992126],
+    [-5.198418035980986, 0.6359270140313843, 0.45388413943123024],
+    [-2.9954921659447535, -0.6535590652566138, 0.615355037594542],
+    [-1.174237346107985, -1.7225996123210747, 0.7502782533642416],
+    [0.3622904454860001, -2.6258004696626758, 0.8650383127996006],
+    [1.6857962690558688, -3.4040969999377455, 0.9645007991647783],
+    [2.8524880835494955, -4.090054636107218, 1.0526043878096185],
+    [3.909527313278816, -4.711435832199756, 1.1327679328177787],
+    [4.897893914999467, -5.292714623478646, 1.208054413331325],
+    [5.8521697381268165, -5.854758458524613, 1.2811080215292454],
+    [6.800828613547576, -6.414867015790925, 1.354142824875814],
+    [7.767964821345008, -6.987748021889561, 1.4290584620344493],
+    [8.775333859975472, -7.586724099597506, 1.507590848592974],
+    [9.844178511479523, -8.224829181938675, 1.5914521489924864],
+    [10.99685478898696, -8.915792286613716, 1.6824586639114496],
+    [12.25843285958472, -9.675010085456963, 1.7826593718064225],
+    [-9.511344871898325, 3.5539780010985855, 0.07944730989321817],
+    [-6.925676756035644, 2.0430860237536597, 0.26619995954433323],
+    [-4.799040515799809, 0.7958405285327879, 0.42169682799744873],
+    [-3.0157578548962842, -0.2525442741137915, 0.5533879724856743],
+    [-1.4930631040195481, -1.1488644277454552, 0.6667173477998755],
+    [-0.1691740776724968, -1.9284299921540826, 0.7658485196578083],
+    [1.0042658188306723, -2.6192109842990896, 0.8541289974366076],
+    [2.067400300552175, -3.2447376219969697, 0.9344207502872987],
+    [3.055831570391286, -3.826170490335384, 1.009340643118616],
+    [4.002243932989645, -4.383172676603468, 1.0813566120995317],
+    [4.935981805318398, -4.933545077428614, 1.1527289831632774],
+    [5.882911358624223, -5.493055683296331, 1.2254791400583323],
+    [6.866556559545231, -6.076081822784862, 1.301466205032501],
+    [7.909764003842679, -6.696600128036343, 1.3825119380623818],
+    [9.036430122896181, -7.369226207744348, 1.4705348124270115],
+    [10.273285819953706, -8.11029300813308, 1.5676914591057556],
+    [-11.220046975928879, 4.941737814964314, -0.10254539463594724],
+    [-8.725554964960027, 3.4804676379194888, 0.07732410315562581],
+    [-6.647921043668023, 2.2598266091265873, 0.22876015510085285],
+    [-4.8863041540067425, 1.2228845526976566, 0.35831732409265704],
+    [-3.3677270860942463, 0.3281577970100139, 0.4708089762529613],
+    [-2.03729851746215, -0.45581496889364315, 0.5699231617967747],
+    [-0.8519923868484512, -1.1539296757984834, 0.6586140333949342],
+    [0.2236208339776434, -1.78690790478609, 0.7393746375224345],
+    [1.22057053500388, -2.373112906866848, 0.8144479941053889],
+    [2.167934510116304, -2.929958219875745, 0.8859940685745971],
+    [3.094061055401275, -3.4745878986692467, 0.9561684995102558],
+    [4.026193036934769, -4.02357827517222, 1.0270780648719322],
+    [4.990082569901131, -4.592639940151934, 1.100737160899849],
+    [6.010837136377235, -5.197093518869842, 1.1791255510898568],
+    [7.114553894356805, -5.852842861950728, 1.264312637826162],
+    [8.330299620488157, -6.57756392236194, 1.3586121101856161],
+    [-13.08692558524555, 6.419148412295571, -0.293434887531292],
+    [-10.64449558177215, 4.984756986840142, -0.11765984317539133],
+    [-8.590534573550938, 3.775957079657009, 0.03151420102357732],
+    [-6.834087810557905, 2.7409276312710587, 0.1600716689618645],
+    [-5.308764738118921, 1.8416644166661276, 0.2724219285516803],
+    [-3.9643473728520755, 1.0492498017358147, 0.3719437352883916],
+    [-2.76141004507995, 0.3408181776344349, 0.46133340460222444],
+    [-1.6676393600019417, -0.30252780693295117, 0.5428443851363548],
+    [-0.6550977167778442, -0.8972809553788528, 0.6184690281842526],
+    [0.302026639974695, -1.4588331139889523, 0.6900931646772559],
+    [1.2293566588950484, -2.002610633038678, 0.7596335948881678],
+    [2.15355923952864, -2.5447865689841223, 0.8291246163533936],
+    [3.1022197429331717, -3.1021532909854397, 0.9006988041172139],
+    [4.103345384847139, -3.6917635045099364, 0.9765386539629282],
+    [5.186122994067658, -4.331349650788886, 1.0589290037988504],
+    [6.382874625834841, -5.040483563459495, 1.1504066194527223],
+    [-15.16572789414831, 8.027645770157736, -0.4985764536431703],
+    [-12.738455047238492, 6.598294883292922, -0.32423306986346434],
+    [-10.682677485237518, 5.38620176914134, -0.17548409941547158],
+    [-8.913586505098444, 4.342566122386839, -0.046672646416069774],
+    [-7.3688795706932355, 3.43142505244197, 0.0663814478518312],
+    [-6.001247904386463, 2.6253603049054726, 0.16687934269588564],
+    [-4.773520354859264, 1.9027210811665645, 0.25736492925105203],
+    [-3.655341372420045, 1.2457196552511642, 0.33994571696429426],
+    [-2.6207250576199526, 0.6390215032171926, 0.4164574029224691],
+    [-1.646062074187682, 0.0685853955046464, 0.48860116722097235],
+    [-0.7082849078959091, -0.4794173543949564, 0.5580741967373898],
+    [0.21695656653869083, -1.0196872116950169, 0.6267032594713791],
+    [1.1568368143883792, -1.5687071210598704, 0.696558360208772],
+    [2.1417608650532833, -2.14494360589689, 0.7699776276397601],
+    [3.204949831944625, -2.7685301766531434, 0.8495269408339653],
+    [4.383319925446696, -3.461741567266453, 0.9380612821048628],
+    [-17.53068205141227, 9.821906879796604, -0.7248335572739351],
+    [-15.08021323342684, 8.374489105693137, -0.5491266894280197],
+    [-12.994723467138925, 7.142244327692645, -0.39876759327207156],
+    [-11.192405703106282, 6.07761057701294, -0.26822341190925164],
+    [-9.612908040098697, 5.145427112809318, -0.1533968432883451],
+    [-8.210298435569673, 4.318864331460121, -0.051152648030143],
+    [-6.948494329981054, 3.57677756966439, 0.04099035460029078],
+    [-5.7981356753279, 2.9018934782856665, 0.12507076708520556],
+    [-4.734301365156368, 2.2794809880700306, 0.2028403287035091],
+    [-3.734680713022998, 1.6962806813203917, 0.2758905939264304],
+    [-2.777910970593652, 1.1395231287470329, 0.3457708988972847],
+    [-1.8418315934604113, 0.59588933784163, 0.41411555921091575],
+    [-0.9014588844040607, 0.050299339638402975, 0.4827944355678731],
+    [0.07321586871620195, -0.5153980356114551, 0.5540776634637348],
+    [1.118664442034848, -1.1232430557501758, 0.6307399195609598],
+    [2.277923252459642, -1.7991323907092516, 0.716058612743041],
+    [-20.288878641379263, 11.87870878041504, -0.9816566037874028],
+    [-17.771050625243145, 10.38616161299502, -0.8013428132134801],
+    [-15.622582865300185, 9.113350934810116, -0.6469275386679997],
+    [-13.761588961971075, 8.012156208409392, -0.512797095152428],
+    [-12.127565586978283, 7.04697856456645, -0.3947935310944327],
+    [-10.67445194702365, 6.190675464831031, -0.2897407440506906],
+    [-9.366118826115827, 5.421917440047321, -0.19513613961148105],
+    [-8.17329069119962, 4.723382841072342, -0.10893968462091924],
+    [-7.071318111988712, 4.080448850074449, -0.02942037838008286],
+    [-6.038427254578993, 3.4801594563066915, 0.04496566182981203],
+    [-5.054170347741739, 2.910307555160452, 0.11568489183328019],
+    [-4.097825207507068, 2.3584819239678936, 0.1842418957151891],
+    [-3.1464607458197102, 1.8109112124366546, 0.25232150298334577],
+    [-2.172335742810874, 1.2509095657275688, 0.3219818763170171],
+    [-1.139440755025671, 0.6568227385017761, 0.39591154106808657],
+    [-4.534231654925267e-14, 2.8690186422010046e-14, 0.47768538031065866],
+    [-24.281860934675834, -2.624939301460762, 2.1151101762713997],
+    [29.542279252919187, -31.759886762723873, 5.794945374125363],
+    [36.64542774576783, -35.51584925131342, 6.270135855295033],
+    [39.68055509114281, -36.9543637445972, 6.439993971389059],
+    [41.75577122777602, -37.88679449728279, 6.546548530257091],
+    [43.48046584495387, -38.66546345675081, 6.636732879404529],
+    [45.08601016985675, -39.42181108634244, 6.727896833023246],
+    [46.69145935203017, -40.22263682590517, 6.828812931344889],
+    [48.37216105101415, -41.110333883431764, 6.945082056033889],
+    [50.18600649245085, -42.11830669726629, 7.08121002226274],
+    [52.18635665268657, -43.27866323883953, 7.241655336149283],
+    [54.42979259350955, -44.62694937607718, 7.431487736478157],
+    [56.98214251809503, -46.20597924070674, 7.656936738400517],
+    [59.92487624803529, -48.07006751233081, 7.926013946198936],
+    [63.36362659559203, -50.29082722239968, 8.249379377559594],
+    [67.44100278229446, -52.9660215062337, 8.641675195207236],
+    [5.35042113998613, -13.990441742555118, 3.1561652147993047],
+    [20.20522419965735, -22.4120377472906, 4.252610137560275],
+    [25.216406181814296, -25.222051085955428, 4.620383120521208],
+    [27.99737713083143, -26.736737655381724, 4.8169819749907505],
+    [30.05942805735932, -27.845684888445863, 4.960912303350435],
+    [31.82061151642327, -28.796607226972387, 5.085387043002475],
+    [33.45522886465111, -29.692877411476744, 5.204305422109912],
+    [35.058082762122815, -30.59090747461896, 5.325291653676428],
+    [36.69174902169693, -31.52830163053361, 5.453479611986738],
+    [38.404949719252365, -32.53485516603399, 5.592998914565163],
+    [40.24167991689409, -33.638086023649805, 5.747730676794879],
+    [42.246870422909154, -34.866745534851724, 5.921796628920308],
+    [44.47082214915382, -36.253651213681316, 6.119963729395],
+    [46.97364979406403, -37.838607748073166, 6.348070879650512],
+    [49.830756528738576, -39.67207815504404, 6.613573243414258],
+    [53.140540703324994, -41.820411418554656, 6.926323692379513],
+    [6.4073322596329545, -12.371909139808842, 2.7837752715852773],
+    [14.798855743555146, -17.207050853146583, 3.4174212921340064],
+    [18.82115828088089, -19.521426199583708, 3.723790282400321],
+    [21.33681433256353, -20.953376801585907, 3.9139071993524563],
+    [23.269673683371607, -22.048631653733437, 4.060021774565361],
+    [24.940977608395407, -22.999026907913457, 4.187780722124234],
+    [26.494887648691577, -23.891151420878305, 4.308832533969559],
+    [28.012067375006765, -24.773783678563632, 4.42978912108085],
+    [29.546599036396806, -25.679973865333032, 4.555179238229387],
+    [31.14087530140919, -26.63600907498499, 4.6886550455863585],
+    [32.83301667800482, -27.665927120367485, 4.83360615127293],
+    [34.66147648551579, -28.794371716594416, 4.9935551912806675],
+    [36.66865385751993, -30.04888844710372, 5.172483486783032],
+    [38.90446268852489, -31.462246180453505, 5.375167509844434],
+    [41.43059594649614, -33.075256738017515, 5.607593700992421],
+    [44.32633078240326, -34.94065047142051, 5.877533537982408],
+    [5.004153672664314, -10.160154151574245, 2.402708444372056],
+    [10.989610867769711, -13.645374075366462, 2.860553967027004],
+    [14.434315613729536, -15.656936492262995, 3.128009853350446],
+    [16.75182051571951, -17.005150776886914, 3.30851424701385],
+    [18.569734573408304, -18.061144135528405, 3.4508254713036384],
+    [20.150572924469508, -18.982240627729333, 3.575868005906399],
+    [21.620970902954365, -19.84494521573602, 3.6939005163671026],
+    [23.053589555316652, -20.69350352111623, 3.8109077053385327],
+    [24.497576806426707, -21.558147426148434, 3.9310242124305304],
+    [25.991559741928718, -22.462936909769226, 4.057584338198725],
+    [27.570215519402925, -23.42976569044497, 4.193664117020193],
+    [29.268347087747657, -24.480885057800883, 4.3424289153167726],
+    [31.12407385556232, -25.640920402301006, 4.507417526184801],
+    [33.181969830264876, -26.938893184529423, 4.692833124776724],
+    [35.49677305591855, -28.41064376565499, 4.903897152725317],
+    [38.13836120051199, -30.102107230292305, 5.147332055070882],
+    [3.215868016728753, -8.111544273819446, 2.0760482069884207],
+    [7.983371321409791, -10.909053153447354, 2.4436634408010365],
+    [11.053614093184944, -12.719320045253085, 2.6846134529910546],
+    [13.229742464300651, -14.001860631965709, 2.85682448560748],
+    [14.960123745951654, -15.021575988812758, 2.994772408204422],
+    [16.46737105055652, -15.912094262492664, 3.116115364073976],
+    [17.867278888812304, -16.74359328977817, 3.23021837648505],
+    [19.228175723735426, -17.557834455618373, 3.342707447470296],
+    [20.59662522641532, -18.38354782069386, 3.4574964205446443],
+    [22.009233670669527, -19.24356616221338, 3.577735891707098],
+    [23.498801221766097, -20.158577978218744, 3.7063182103945307],
+    [25.09816002794435, -21.14950100142524, 3.8462024377043007],
+    [26.843174452971574, -22.23936928702412, 4.000678502011669],
+    [28.775688666160136, -23.455216818034973, 4.173636436183358],
+    [30.94700454492013, -24.830324726344458, 4.369892417900104],
+    [33.42252909588929, -26.40724338999799, 4.5956312070002605],
+    [1.3718766400790996, -6.246892968083672, 1.7926847005903317],
+    [5.421066602144921, -8.63693357740498, 2.1063595461231026],
+    [8.232896250053559, -10.305988179121956, 2.3282909173273323],
+    [10.310719590210335, -11.541108641730542, 2.4941288657288228],
+    [11.97985552230264, -12.533900950250281, 2.6285106571034365],
+    [13.432077878122955, -13.399491435840337, 2.7465283932446716],
+    [14.776194423003506, -14.203936145673246, 2.856944212943409],
+    [16.078648421595144, -14.98793073147535, 2.9652121568945757],
+    [17.385130693757706, -15.779709651059274, 3.0751590620894214],
+    [18.73152614199157, -16.601657759700327, 3.189857211911489],
+    [20.149887662324186, -17.473955501078827, 3.3121117077935898],
+    [21.672224185921927, -18.416918223803368, 3.444778768033569],
+    [23.333468714580032, -19.45285502972586, 3.5910242653258764],
+    [25.174396695796503, -20.60792004865713, 3.7545866181799403],
+    [27.245078272701374, -21.914321537922827, 3.9400952151945043],
+    [29.609504592402303, -23.413296633036378, 4.153503127889702],
+    [-0.4823638875080998, -4.508615778555286, 1.5383946085324158],
+    [3.10669015890723, -6.636737600406927, 1.8169558513426485],
+    [5.734259939535537, -8.203914324717084, 2.0248006893174977],
+    [7.745464375284716, -9.40646446409333, 2.18593511883475],
+    [9.37704899978906, -10.383075985903165, 2.317916475850466],
+    [10.79230654815757, -11.231593165117634, 2.4334384471232724],
+    [12.094817834685559, -12.014927855661066, 2.540784326224633],
+    [13.35088800714335, -12.773736861311004, 2.6453688013203247],
+    [14.60674229171925, -13.536604491553822, 2.7510427173574805],
+    [15.898651078351753, -14.326144176649969, 2.860890390325009],
+    [17.258859037402143, -15.16261343632312, 2.9777093114783515],
+    [18.719460421372922, -16.066303235725417, 3.104333054124586],
+    [20.315462362823975, -17.059448837630953, 3.243895250443266],
+    [22.08780598852127, -18.168133344683508, 3.400098086216044],
+    [24.086957996729126, -19.42456548347877, 3.577538447126257],
+    [26.377757716716598, -20.8701640431232, 3.7821536596278076],
+    [-2.366511495956203, -2.8372312368792665, 1.3017833429227685],
+    [0.9155147894687492, -4.790141056667773, 1.556383640895283],
+    [3.412009049426369, -6.284269411959068, 1.7537450957461438],
+    [5.379213444530387, -7.465230324827248, 1.911402497450484],
+    [6.994009494793327, -8.43601426189667, 2.042158738361935],
+    [8.39021229502556, -9.276431808149743, 2.1562208154117406],
+    [9.66518398317829, -10.045546673932092, 2.261290545841834],
+    [10.886101624393715, -10.784558546322705, 2.3628130885110146],
+    [12.100981572644143, -11.523162638621846, 2.464760514141655],
+    [13.347556768825191, -12.284864258016677, 2.5703157482950565],
+    [14.659169450161452, -13.090563227190543, 2.6823437017838416],
+    [16.06883593406912, -13.961054797873578, 2.803726619974207],
+    [17.612523166058054, -14.91906554855063, 2.937643240483814],
+    [19.332401302051743, -15.991289766054717, 3.0878539334956767],
+    [21.280736470462664, -17.210837544410122, 3.259048758333793],
+    [23.52519677867597, -18.620578307235256, 3.4573273221782657],
+    [-4.320289409697765, -1.1788103679033561, 1.0737577825003928],
+    [-1.2441791515708749, -3.0140054991952647, 1.3116997653777935],
+    [1.1632310086954512, -4.458271077294807, 1.5014334079915899],
+    [3.104168069148496, -5.6265037265398075, 1.6565750787234577],
+    [4.718709338419625, -6.599907470999462, 1.78703385210973],
+    [6.113801440389912, -7.441830657677011, 1.900761478941166],
+    [7.37627197167623, -8.20473983820126, 2.004507150850866],
+    [8.573504752626347, -8.92991353565936, 2.1036732480945473],
+    [9.756293693588434, -9.648749726042857, 2.2024239941883788],
+    [10.964980467378796, -10.386352393856589, 2.304130483585309],
+    [12.235006343381048, -11.164887626538388, 2.411805582493703],
+    [13.601203181328843, -12.00621521451885, 2.5284534439946666],
+    [15.101398390859973, -12.934119532316311, 2.657372798033461],
+    [16.78004242894125, -13.976566788811933, 2.8024704362744743],
+    [18.692588701363718, -15.168437406930122, 2.9686466542176317],
+    [20.911537030219314, -16.55529860143087, 3.1623326312127333],
+    [-6.394618024915959, 0.5182715129743386, 0.846469876312123],
+    [-3.450757225640847, -1.241261928725202, 1.0729722814477092],
+    [-1.0967303396655386, -2.655641414399182, 1.2574679082803053],
+    [0.835039264333917, -3.820092133168786, 1.4110470469844858],
+    [2.46190661926618, -4.80246891532979, 1.5418362628095017],
+    [3.872213957589279, -5.654776515190031, 1.6562305897663672],
+    [5.1389811774894225, -6.420832426172669, 1.759761066635031],
+    [6.325770252822488, -7.139428290551794, 1.8574352711448785],
+    [7.485886924291198, -7.843464073224233, 1.9535703467158618],
+    [8.66339478696561, -8.560324118966262, 2.0518075190414353],
+    [9.897048187291704, -9.314195350616869, 2.1554022292548365],
+    [11.22458446184968, -10.128681876946697, 2.267566811499513],
+    [12.686739661966207, -11.029278236262245, 2.3918033488242507],
+    [14.331420963653393, -12.04595008725668, 2.5322587019675518],
+    [16.21881177383603, -13.21629432281577, 2.694166325888278],
+    [18.428519136984374, -14.589960191692311, 2.8844707341046596],
+    [-8.65325098852549, 2.3087123481127674, 0.6123546044058352],
+    [-5.782143963124318, 0.590740276923403, 0.8314964316549924],
+    [-3.4486333259550372, -0.8123166829060325, 1.0128786406359647],
+    [-1.507603603085331, -1.982970340061218, 1.1659306802625107],
+    [0.14369938137598587, -2.9805364403995345, 1.297616028346021],
+    [1.5822928000904881, -3.8501596397828233, 1.4133703124365866],
+    [2.870371804286924, -4.62889499068232, 1.5177686383201525],
+    [4.063140478729734, -5.350194928845989, 1.615045433747566],
+    [5.212556692663576, -6.0459986548283515, 1.7093304997585872],
+    [6.366350174520973, -6.745906810872596, 1.8045123047615044],
+    [7.567947238899088, -7.476966703912877, 1.9041894398817196],
+    [8.859454766239587, -8.265437336068857, 2.011894957237845],
+    [10.28590057282141, -9.139379912822205, 2.1314406363032736],
+    [11.900123309208956, -10.13166287250981, 2.267325830753249],
+    [13.768925947767622, -11.28374204103724, 2.4252604795669486],
+    [15.981849762449293, -12.651039424187264, 2.6129174553502295],
+    [-11.180006047634809, 4.257333278717527, 0.36315762447019584],
+    [-8.327561813452581, 2.5497467983218907, 0.5784904493418286],
+    [-5.981316256590005, 1.1390936671709013, 0.7588073674198643],
+    [-4.009948864245373, -0.04941805361558966, 0.9124908762135207],
+    [-2.319788081103751, -1.0698198415396978, 1.0457493459312395],
+    [-0.8406426448388551, -1.9631332194258133, 1.1634122376339056],
+    [0.48329889297480305, -2.762427444922896, 1.26946798959658],
+    [1.700096380094619, -3.496598591485815, 1.3674884109890584],
+    [2.855513350709891, -4.19356751716196, 1.4610087775604381],
+    [3.996573078804457, -4.882361828092744, 1.553780166542502],
+    [5.17128036387199, -5.592801159214115, 1.6497144604362792],
+    [6.427919827922314, -6.354919243260812, 1.7527980436799235],
+    [7.817839890702245, -7.200599814883378, 1.8673047402065373],
+    [9.400887610217325, -8.166883270872674, 1.9982401050895253],
+    [11.253017865603905, -9.30060440275006, 2.151975189293686],
+    [13.477482267620125, -10.665190970222715, 2.3371864873994737],
+    [-14.09290400843736, 6.4498008419502595, 0.08865158668605914],
+    [-11.202558837295767, 4.719660288082247, 0.3037546303024006],
+    [-8.806017464979886, 3.27985263678059, 0.48521891418678126],
+    [-6.778499560803904, 2.059018268072861, 0.6409086597305655],
+    [-5.030867515684714, 1.0056976072684838, 0.7766105476917574],
+    [-3.4964467412645557, 0.08099028912053445, 0.8967961899738157],
+    [-2.122754957748215, -0.7460736841014064, 1.0051078928047141],
+    [-0.8657047033114863, -1.5018288523533234, 1.104717144539837],
+    [0.3151376422286008, -2.21073570242439, 1.1986422154113006],
+    [1.4611636834825268, -2.898136219935815, 1.2900835913573698],
+    [2.6190998488600052, -3.5928947230006023, 1.3827573916283136],
+    [3.842734086750245, -4.328360317678664, 1.4810171392832916],
+    [5.192394617952964, -5.141882366484397, 1.5897892962940212],
+    [6.73824682943983, -6.076703565931267, 1.7148266803096077],
+    [8.56918053444756, -7.187309487704386, 1.8634300183681176],
+    [10.807577896001504, -8.548320100109942, 2.04566002793328],
+    [-17.57167143183224, 9.011906097231957, -0.22555635744691996],
+    [-14.574110473660753, 7.218100544274338, -0.00631549496146734],
+    [-12.079111187502512, 5.721065719367542, 0.17910542577127814],
+    [-9.960921880282301, 4.448275671567724, 0.33862512221237284],
+    [-8.130207967098, 3.3479347121030654, 0.4779686529030658],
+    [-6.520589866969179, 2.3812848230664345, 0.601491151925544],
+    [-5.080378101235821, 1.5178927638705826, 0.7126794419057657],
+    [-3.767004693765148, 0.7324604876467602, 0.8144954164513092],
+    [-2.542732835001902, 0.0023336285854221954, 0.9096540651410981],
+    [-1.3706437954305137, -0.6948783914859149, 1.0009035917648978],
+    [-0.20996782024406307, -1.3841250291980478, 1.0913746283945216],
+    [0.9899575794568927, -2.0965966606244213, 1.1850506204902824],
+    [2.29563824331396, -2.87329777273382, 1.2872336453749171],
+    [3.7918458095392786, -3.7663030426321376, 1.4047177274065124],
+    [5.586986739232396, -4.841735532496489, 1.5461961471592753],
+    [7.831219435014043, -6.190497327419043, 1.7237038795252266],
+    [-21.915346026816596, 12.148962154375244, -0.6024348904380363],
+    [-18.710832999683507, 10.230955265685788, -0.3725880357926012],
+    [-16.04643831733811, 8.6343297402804, -0.17889375794077586],
+    [-13.785952326182331, 7.279506303656283, -0.012676134593999118],
+    [-11.83367246885514, 6.110464928254984, 0.1322068985033656],
+    [-10.119366545866503, 5.085968402686816, 0.2603174710246124],
+    [-8.589164274825523, 4.1742503384673615, 0.3752148600100077],
+    [-7.199611042104502, 3.3495464775287678, 0.47983344634733305],
+    [-5.9133515716590255, 2.5895665517398387, 0.5767623787841138],
+    [-4.69542758318714, 1.8733036680290343, 0.6684962713907789],
+    [-3.509262827265113, 1.1786257649608252, 0.7577235151763625],
+    [-2.311108590379108, 0.47890831404906925, 0.8477446278281342],
+    [-1.0410374960694213, -0.26244209346913666, 0.9431677308470573],
+    [0.39114886589511483, -1.100497715029403, 1.051003261332327],
+    [2.1204444452469025, -2.1172773204693858, 1.1817735135820384],
+    [4.341462522877194, -3.4299075938474535, 1.350616909826677],
+    [-27.676758428655354, 16.23683745935954, -1.0835676240246357],
+    [-24.098052428712545, 14.091087406968493, -0.8319402412479809],
+    [-21.1443364156013, 12.321421734234237, -0.6222333880695297],
+    [-18.653861300165183, 10.831874440552582, -0.44398538339072746],
+    [-16.51491482103569, 9.556158441430664, -0.28994759877594367],
+    [-14.646934237363444, 8.446451806084907, -0.1548670801393134],
+    [-12.989328594838884, 7.46676364516204, -0.03476470175638214],
+    [-11.494394286113407, 6.588729810263893, 0.07352427950962376],
+    [-10.122420990140174, 5.788706441984252, 0.17267091864905187],
+    [-8.83783784608131, 5.045476011613201, 0.26511070041291923],
+    [-7.605491343797722, 4.33802226869307, 0.35330174751602417],
+    [-6.385958398917397, 3.6427151516116196, 0.440062497971875],
+    [-5.127946170580694, 2.928734317682203, 0.5291338466066257],
+    [-3.7535449974665682, 2.1491970943230227, 0.626283311610142],
+    [-2.127240554721401, 1.22261512445271, 0.7416308464126449],
+    [1.2362519353041057e-12, -7.836085247576144e-13, 0.8938140051494035],
+    [-2.1450780608561346, -17.6842587647799, 4.674476503856053],
+    [40.872757588191874, -41.68042219658216, 7.853230595096868],
+    [46.32609765282679, -44.41183813032808, 8.1931607385815],
+    [49.18750044044947, -45.699673024990716, 8.341497397406584],
+    [51.348240794499034, -46.65553188808982, 8.451445748973654],
+    [53.289912008366585, -47.556247742886924, 8.560665082152987],
+    [55.220251506102834, -48.5187942975404, 8.684888253597393],
+    [57.25728956053952, -49.609407585628595, 8.833046712615108],
+    [59.48726430190166, -50.87791148147057, 9.012036548452887],
+    [61.99004366730608, -52.37300746790779, 9.22886953137012],
+    [64.8530644258097, -54.15090759500476, 9.491918902707248],
+    [68.18218067765198, -56.28238707936535, 9.811975597199162],
+    [72.11432730956798, -58.86141225767684, 10.20358038069392],
+    [76.83621439190424, -62.018236049032865, 10.687078488317603],
+    [82.61502401532395, -65.94119932872395, 11.29206668510295],
+    [89.85285138917477, -70.91566406476535, 12.063573542128152],
+    [16.171032391768392, -22.45330041807852, 4.818422147093852],
+    [27.964622914483257, -29.27110965545955, 5.743279458668682],
+    [32.195197209834376, -31.64619659705699, 6.063960487170633],
+    [34.915620235127754, -33.14356790189046, 6.265831165307573],
+    [37.1164203644786, -34.35755973160271, 6.431146558251312],
+    [39.115039065808, -35.478895127822355, 6.586390872684994],
+    [41.06313556780196, -36.598956682799766, 6.744321335520229],
+    [43.053958145768874, -37.77454309058473, 6.91296133060466],
+    [45.15793883470634, -39.049453639612956, 7.098614651684077],
+    [47.43896265693405, -40.46450186793329, 7.307283415217381],
+    [49.964045189934154, -42.06363808257043, 7.545556684482119],
+    [52.81098640388059, -43.898966380622824, 7.821361265839143],
+    [56.076596765980966, -46.036306778777565, 8.144815148662223],
+    [59.88779900967775, -48.562846410087126, 8.529418403846556],
+    [64.41864476050613, -51.59898608279563, 8.99391048605913],
+    [69.91849709420396, -55.31806750263666, 9.565375269130367],
+    [14.0942451800699, -18.726991129097602, 4.119229582926817],
+    [21.186899205511178, -22.90321439327371, 4.6910255399573435],
+    [24.719752057477283, -24.966878250318167, 4.975976000098298],
+    [27.189147571636944, -26.40229416016688, 5.175707705010233],
+    [29.24560612807, -27.60299454956939, 5.344528840673802],
+    [31.130451227195824, -28.715960845141858, 5.502874102195938],
+    [32.965506404367524, -29.81594980856016, 5.661228362643607],
+    [34.82829312541418, -30.95112373358983, 5.826436294133334],
+    [36.778561135246676, -32.15925943924151, 6.003959371221022],
+    [38.87063679693811, -33.47539148655122, 6.198954603367326],
+    [41.16096450984602, -34.936590268001154, 6.416965008320231],
+    [43.714208656936215, -36.585942580458514, 6.664511616572299],
+    [46.60972914586195, -38.47688207231911, 6.949752953692609],
+    [49.95002839462712, -40.67892543774558, 7.2833711279363165],
+    [53.8732595083884, -43.28623608875492, 7.679904577038353],
+    [58.573269767699884, -46.431408254684136, 8.159902090816168],
+    [11.30986771668366, -15.545412307167496, 3.5835907484024285],
+    [16.633623377805897, -18.722276541434773, 4.020879619833752],
+    [19.741310514262032, -20.57636791289508, 4.279376394479789],
+    [22.01598372633693, -21.933532845950445, 4.470595412819214],
+    [23.93891613771063, -23.08631801057937, 4.634720212025441],
+    [25.710424757275916, -24.157602313020153, 4.78879164137085],
+    [27.436007860109846, -25.21268053789719, 4.941957780227627],
+    [29.184222852365643, -26.294497614469456, 5.100317723612737],
+    [31.008597525894306, -27.437094134652327, 5.268785643591717],
+    [32.95807101998024, -28.672089787589464, 5.452002030037744],
+    [35.08346333992347, -30.032781198305766, 5.654923033656721],
+    [37.44280237139144, -31.55759911588651, 5.88333165410342],
+    [40.107013775374, -33.29387650521711, 6.1444077653961555],
+    [43.16728387029484, -35.30278523984283, 6.447485486965259],
+    [46.74581580692569, -37.66659511024683, 6.805175310834287],
+    [51.012818119108154, -40.500179091045254, 7.235150196821153],
+    [8.716616339965492, -12.924855271588246, 3.167147853425937],
+    [13.135567898342371, -15.588480295673765, 3.5347739670734772],
+    [15.972914235154388, -17.304314035247312, 3.775065531331035],
+    [18.10930208961649, -18.59900721957939, 3.95854120930715],
+    [19.92839123325359, -19.706492993166634, 4.117144784440721],
+    [21.606934939568536, -20.735696466974485, 4.265920343170058],
+    [23.241649617983896, -21.746786188071717, 4.413271207548678],
+    [24.896315648909873, -22.779889896113172, 4.564879146523141],
+    [26.621115474902965, -23.866995542885316, 4.725345661449688],
+    [28.462145862382133, -25.037871761559302, 4.899020304382631],
+    [30.46739430507284, -26.32385153940576, 5.090542242843865],
+    [32.69172957063124, -27.76105614619372, 5.305314389675189],
+    [35.202297971094346, -29.393938522152304, 5.550036188587715],
+    [38.08554014833376, -31.27993577406823, 5.833412835793448],
+    [41.45743851227589, -33.49629770984064, 6.167204134855329],
+    [45.47967321096398, -36.15088049412789, 6.5678896101481925],
+    [6.3058806229819755, -10.65977451624282, 2.8239230297887667],
+    [10.193003601327415, -13.02109172772998, 3.1500347961643533],
+    [12.858614670754728, -14.648439657221735, 3.3782756510734187],
+    [14.903519973851717, -15.900761903006076, 3.5561617009919795],
+    [16.647591426066803, -16.973416657712523, 3.710172142921475],
+    [18.254084201906625, -17.967329195059705, 3.85417197410624],
+    [19.815879935178522, -18.940484122867176, 3.9962233169778143],
+    [21.39494620712528, -19.931971835068452, 4.141836677825582],
+    [23.040195328771247, -20.972997563119343, 4.295479461830536],
+    [24.796656015703917, -22.09259908650099, 4.461371738101128],
+    [26.711336325070675, -23.32135615946144, 4.644014458875165],
+    [28.83818009076485, -24.694578819385697, 4.84865578532558],
+    [31.243499477450804, -26.255847395090594, 5.0818201997475585],
+    [34.01311952608269, -28.061702523309766, 5.352018972965852],
+    [37.26289949582983, -30.188576548283613, 5.670808096594849],
+    [41.15544214904946, -32.74382051699807, 6.05447880598612],
+    [3.986038483290487, -8.59730561697401, 2.5246073674169884],
+    [7.540350721529637, -10.769567814198744, 2.8242692932382862],
+    [10.1021523505945, -12.344473048598966, 3.045005121013853],
+    [12.095921618831781, -13.574702677979385, 3.2197492568385644],
+    [13.791880514751956, -14.62524737898242, 3.370643910561538],
+    [15.345676546582926, -15.592515906527582, 3.5108433202837097],
+    [16.85009456421482, -16.534572528427397, 3.6483744360532984],
+    [18.3677667365778, -17.490993509948396, 3.7887849018166726],
+    [19.948096926347496, -18.49330157990655, 3.9365608746939045],
+    [21.636499259215498, -19.57072999610429, 4.095924142002066],
+    [23.480450680284935, -20.75404837512266, 4.271373693427213],
+    [25.53466964854227, -22.07888033649455, 4.4681680270788355],
+    [27.86686701936025, -23.58942431784312, 4.69287778399173],
+    [30.56542073844258, -25.34344529692609, 4.954137006816385],
+    [33.75084092706576, -27.41974886440159, 5.26377645227062],
+    [37.59424374445516, -29.930235435627026, 5.638659744481044],
+    [1.6631588717311165, -6.625202719734435, 2.2494886531052245],
+    [5.0091628327936695, -8.679813564580787, 2.532104541125109],
+    [7.5158865608129855, -10.228818881409614, 2.7486506407232145],
+    [9.49516644134386, -11.456884293090994, 2.922731202165361],
+    [11.17012409447246, -12.499806641590697, 3.072311586303923],
+    [12.690328372408453, -13.450261260785318, 3.2099208611756485],
+    [14.151413862175248, -14.36818421180027, 3.3437844169165456],
+    [15.619089750083285, -15.295139598607905, 3.4796879793095665],
+    [17.145032114062946, -16.264091024257088, 3.6222891108575417],
+    [18.776457568028274, -17.305372495779274, 3.7759361491272125],
+    [20.56264734135685, -18.45080670168833, 3.945246979875903],
+    [22.560610906157475, -19.737318562913096, 4.135630626131249],
+    [24.841451694272813, -21.211025928991425, 4.353890511151239],
+    [27.499001219273044, -22.932806614849902, 4.609056359257],
+    [30.662963092944782, -24.9867825684846, 4.913661625164248],
+    [34.520538669364306, -27.49428102632862, 5.285855563257302],
+    [-0.7569136444684872, -4.650609967160462, 1.9835908833256584],
+    [2.4705200660244766, -6.63950132480312, 2.2558554968224196],
+    [4.960258951489318, -8.183726105959561, 2.4707656195340646],
+    [6.957647610889862, -9.427961756054362, 2.646424565755798],
+    [8.641258414859847, -10.480175447460436, 2.7968251582097268],
+    [10.149347709788863, -11.425819692837038, 2.9333638558014905],
+    [11.58157783898549, -12.327366735113456, 3.064531514600077],
+    [13.009227745147408, -13.229925249091185, 3.196552901894485],
+    [14.488337485128248, -14.169225455009478, 3.3344325318301737],
+    [16.069566638974017, -15.177750318508698, 3.482785708857103],
+    [17.805451596553233, -16.289307050124332, 3.6464751326796114],
+    [19.756860836208197, -17.543128771430098, 3.8311978120853647],
+    [22.000338876273656, -18.988562921346986, 4.044170699026756],
+    [24.638237524524673, -20.691549183493223, 4.295090881000524],
+    [27.814493963362313, -22.744707539559403, 4.5976425701624475],
+    [31.741295653783045, -25.28437702281908, 4.972055115484598],
+    [-3.3791288659216416, -2.5835106290667356, 1.7136535229768974],
+    [-0.19637447132286204, -4.549790356899369, 1.9809129785120532],
+    [2.312643601716199, -6.109809393213751, 2.196577197155044],
+    [4.3555393544180605, -7.385670747487956, 2.375581431325042],
+    [6.079527184643326, -8.465745511603723, 2.5290961771776783],
+    [7.60275721929067, -9.422555373115133, 2.666589644838056],
+    [9.024324195574456, -10.318061770698693, 2.7963640008483504],
+    [10.422674084424845, -11.201885957114344, 2.9251901941790184],
+    [11.860807974613403, -12.114170695620317, 3.0586306715712555],
+    [13.395024526659375, -13.090951329994478, 3.2017507908821083],
+    [15.08296270160711, -14.169201489139574, 3.3598128062829358],
+    [16.991209304246233, -15.39164569390103, 3.538957065570203],
+    [19.204111301821623, -16.8123493370079, 3.7470111814877463],
+    [21.83615266549616, -18.50456837163134, 3.9946424492831505],
+    [25.05174037466518, -20.573282767189664, 4.297212964921317],
+    [29.09979706223999, -23.177078785482564, 4.678036395273899],
+    [-6.335810832941628, -0.3211109404338026, 1.4258129398406616],
+    [-3.127667204391129, -2.3058636775235115, 1.692862460748471],
+    [-0.5588131718688563, -3.905109430238443, 1.9118726692590353],
+    [1.5570694010686006, -5.228161400511774, 2.095860199235261],
+    [3.3507398245505757, -6.35310565462691, 2.25443238266854],
+    [4.922756744444789, -7.341116644110618, 2.3953537250684587],
+    [6.360708355072818, -8.24654570595206, 2.5257393082202206],
+    [7.745616003140124, -9.120482146119516, 2.6524475780598404],
+    [9.149400298977174, -10.008695515436468, 2.7817344350222175],
+    [10.637094423717713, -10.952749833655293, 2.9193654843067858],
+    [12.274162563250385, -11.994527252251574, 3.0712249232181423],
+    [14.135443939491712, -13.181852572219267, 3.2441007218162365],
+    [16.31610187151694, -14.575394670923464, 3.4466709788136916],
+    [18.947360502476286, -16.258568123673516, 3.6909405740724357],
+    [22.222437253613275, -18.353808645993478, 3.994623706537388],
+    [26.443794607190778, -21.052160188035582, 4.385501961473597],
+    [-9.813792257572317, 2.2725731622348824, 1.1031106721380801],
+    [-6.502900389108064, 0.22398334743923184, 1.3748169868844657],
+    [-3.8237926165142575, -1.4438231948069093, 1.6002083647544032],
+    [-1.5996775906388438, -2.834245945198031, 1.7911819475884074],
+    [0.2932098621475636, -4.020858840856261, 1.9565045338183669],
+    [1.9474784684185529, -5.059592192680013, 2.103052063941478],
+    [3.4392243828793796, -5.997178382529623, 2.2367486280842512],
+    [4.839782460945559, -6.878194048551441, 2.3634191572172263],
+    [6.223180328847182, -7.749545424230242, 2.489349234888431],
+    [7.665131921076148, -8.659533414215755, 2.6211264038409863],
+    [9.24313439820071, -9.657643239770062, 2.765590826318856],
+    [11.044320110277502, -10.799375312910065, 2.9304974452572368],
+    [13.178999993111443, -12.154714105052864, 3.1257074565431178],
+    [15.801455506838185, -13.821107863257426, 3.3650436930267835],
+    [19.14553597237699, -15.945602532415947, 3.669486302891954],
+    [23.592902863115707, -18.767126497826208, 4.073321807877706],
+    [-14.105857924375679, 5.401851751492354, 0.7217962208542882],
+    [-10.592252133219816, 3.2311395820760156, 1.003774773902764],
+    [-7.7333175846614175, 1.4544006359277812, 1.2393708923252413],
+    [-5.350442604370315, -0.03235251828229361, 1.4400057393116086],
+    [-3.3191147024527576, -1.3027432984874328, 1.6140907789466687],
+    [-1.5476433386972244, -2.4117700168042524, 1.7681262215447084],
+    [0.03628832285476036, -3.403428984337019, 1.907478444390944],
+    [1.4955883706532942, -4.316559740987421, 2.037034854679865],
+    [2.8931794011024135, -5.190584322679383, 2.161908507255259],
+    [4.302183815963029, -6.071849640272463, 2.288264267655811],
+    [5.811820462106617, -7.017181461671524, 2.4237915165526593],
+    [7.527988270382791, -8.09390077042045, 2.5777075477121474],
+    [9.583621461986635, -9.3860356772063, 2.761623118903563],
+    [12.165666568521717, -11.010980276354138, 2.9918807111531525],
+    [15.56635776452775, -13.151076907573223, 3.2940331957439177],
+    [20.288203981121534, -16.117947493387568, 3.7120430513340676],
+    [-19.724641308898363, 9.413936847868829, 0.24426774040282884],
+    [-15.855525835287557, 7.032765088411225, 0.5440568505724417],
+    [-12.708594292254377, 5.084939975926389, 0.7951002506165625],
+    [-10.087592775498639, 3.4567962116052056, 1.0091358551778096],
+    [-7.857575098269186, 2.0691050642318864, 1.194697489768346],
+    [-5.920803032334463, 0.8637238431887392, 1.3582930171280037],
+    [-4.2024100475435215, -0.20444056242163344, 1.5051484611018409],
+    [-2.6407166900114456, -1.1729931375515823, 1.6397689615435271],
+    [-1.1793127217254278, -2.0767490655602194, 1.7664638689037169],
+    [0.24159288435113438, -2.9530412148778593, 1.8899987684645367],
+    [1.6978603618496637, -3.8496670721339634, 2.0166311032731623],
+    [3.3007363115735675, -4.837001837911938, 2.155731897238328],
+    [5.214587210826022, -6.018722703590751, 2.32125131393983],
+    [7.677445409242952, -7.543705351555873, 2.5333852078855337],
+    [11.069586755361486, -9.647492789944147, 2.8243327536191707],
+    [16.086964228975887, -12.757301040447032, 3.252970886242996],
+    [-27.714759245070727, 15.00093710465627, -0.39949872502220696],
+    [-23.197242385406714, 12.23843899201695, -0.06792121543748936],
+    [-19.563061800187572, 10.005602574472771, 0.20722953997651317],
+    [-16.561860701874956, 8.156516699826975, 0.44038415123166685],
+    [-14.028707544993946, 6.594681444496001, 0.6412641043931412],
+    [-11.84806692496643, 5.251989107374074, 0.8169422860878135],
+    [-9.93435517765841, 4.077533568265721, 0.9728944519157181],
+    [-8.220202783374278, 3.030880572861411, 1.113628619498702],
+    [-6.6482076347088395, 2.077282162693915, 1.2431588875902435],
+    [-5.1636102727380075, 1.183310593760002, 1.3654857953408408],
+    [-3.705284951855302, 0.3113513051621561, 1.4852601237121825],
+    [-2.1901517325882556, -0.5900754745855982, 1.609016586949499],
+    [-0.47900922177728344, -1.6075962438754985, 1.7479485874258367],
+    [1.7006510861786512, -2.9106965839128103, 1.924192682928152],
+    [4.876581164755431, -4.824752597380844, 2.1805846363910257],
+    [10.086225843023808, -7.9801066783556065, 2.600927080124249],
+    [-40.820152323536185, 23.963052505997783, -1.3862328473208507],
+    [-34.86667898792146, 20.340594743869104, -0.9791417534379732],
+    [-30.24219677969168, 17.524036000005072, -0.6547531186916649],
+    [-26.52673432545076, 15.26240258616813, -0.3881918994175345],
+    [-23.46273345140501, 13.402152145230582, -0.16422772404863434],
+    [-20.881292024639734, 11.8428358202961, 0.027141151126335517],
+    [-18.664953794362024, 10.51476552956208, 0.1929144921544836],
+    [-16.72715009582962, 9.366713395890743, 0.33831540932894905],
+    [-14.999626220904483, 8.358415021417388, 0.46754201154705305],
+    [-13.423509820417875, 7.4552703279521815, 0.5843076534740177],
+    [-11.941127317606501, 6.62348235553043, 0.6923644843198481],
+    [-10.485258532913855, 5.823630150848605, 0.7962434154816835],
+    [-8.958633981740261, 4.998308209587933, 0.9027433560699011],
+    [-7.1802940300757125, 4.039651268584462, 1.024926181913189],
+    [-4.698378010687734, 2.676104105642329, 1.1961828410968642],
+    [-3.251502369025553e-14, 2.3786817521603493e-14, 1.529481360760704],
+    [20.6352088866325, -34.179897381608306, 7.574640045416922],
+    [51.80476874859278, -51.71144553351753, 9.955048053748545],
+    [56.56629288824675, -53.9262118841224, 10.216771005661068],
+    [59.37410642283257, -55.11013976743527, 10.345867332386447],
+    [61.65247077678734, -56.09570928936778, 10.458575930600986],
+    [63.83108006918013, -57.12581978938732, 10.588073716797746],
+    [66.10904468341741, -58.31047239102753, 10.749194241356351],
+    [68.61073615299335, -59.71934222411767, 10.951504582628495],
+    [71.44204402289799, -61.41552872046368, 11.20411978464243],
+    [74.71608391425025, -63.471342484552, 11.518005880152858],
+    [78.56982964343752, -65.97902596233557, 11.907609692674535],
+    [83.18241914536193, -69.06329397117577, 12.39284624840844],
+    [88.80269802447012, -72.90092040726074, 13.00227145253734],
+    [95.79591163844154, -77.75453637438453, 13.778611640263632],
+    [104.72986407977761, -84.03550809146004, 14.789099431835105],
+    [116.54941435752546, -92.43183104475538, 16.146552351449937],
+    [26.09523210476339, -30.67578186618925, 6.473257589052963],
+    [35.350163356602856, -36.054164181766666, 7.2217157397723915],
+    [39.295477935229805, -38.270851977121005, 7.528366402989156],
+    [42.10037681984104, -39.83512534318299, 7.746469221013932],
+    [44.504813342284415, -41.19546779530242, 7.939660147065136],
+    [46.78290358868889, -42.51810022233511, 8.131607940506656],
+    [49.079924725486556, -43.891784614943006, 8.335079019078364],
+    [51.494757609566406, -45.37812844223403, 8.559107715739584],
+    [54.11150261112974, -47.031117917615326, 8.81181436930993],
+    [57.01589843679611, -48.90753739986704, 9.101946568056423],
+    [60.30682183161383, -51.074554664856585, 9.44004102941325],
+    [64.107908229847, -53.617679317943896, 9.839681381144683],
+    [68.58321437052602, -56.651745453254215, 10.31926523834345],
+    [73.9617505842485, -60.33832299229809, 10.904830041035217],
+    [80.57936172319741, -64.9156307016487, 11.634930131760093],
+    [88.95573860899431, -70.75375029827921, 12.569664809296086],
+    [21.337251987598794, -24.999093275685567, 5.451423682249608],
+    [27.350714636779017, -28.587248492023118, 5.959372507809752],
+    [30.706181044211533, -30.576225638189246, 6.244180736853177],
+    [33.265232354264924, -32.09771776205012, 6.46495876558641],
+    [35.51667366238324, -33.451871069194034, 6.664341854857781],
+    [37.663432492707045, -34.76414995096746, 6.860303520049944],
+    [39.8199516572927, -36.106149846626174, 7.063238601260077],
+    [42.06755853375588, -37.52970049615402, 7.280816891122061],
+    [44.47662828358517, -39.080676354415004, 7.519973105903075],
+    [47.118669026397676, -40.806707453831585, 7.788045536405435],
+    [50.07526663779001, -42.763076474966795, 8.093676131131579],
+    [53.44715339554152, -45.01887826362576, 8.447778260093289],
+    [57.36601920157842, -47.66519216078843, 8.864843076987475],
+    [62.012349799258075, -50.82754271590456, 9.364948681321316],
+    [67.64480616975835, -54.686526406385724, 9.977101398390369],
+    [74.65204263399853, -59.51430497471567, 10.745164754194878],
+    [17.49402398448802, -21.023826495255808, 4.784107428156234],
+    [22.21107734742424, -23.89182852267668, 5.194563733517431],
+    [25.18953925565731, -25.706107929491868, 5.458468177576251],
+    [27.54654223632705, -27.150194805586793, 5.671594313700893],
+    [29.65053818000029, -28.45214945716704, 5.866282785952119],
+    [31.667484573837022, -29.715626491556726, 6.057388809137067],
+    [33.69485163223495, -31.002269290523085, 6.253876388159398],
+    [35.803884612857566, -32.357899505410245, 6.462534320825013],
+    [38.057280798846975, -33.82359473318303, 6.689568724218613],
+    [40.51914067671349, -35.44205605511407, 6.941541060983276],
+    [43.26263717981635, -37.26265411944725, 7.226135690998949],
+    [46.37797246501438, -39.346792843589796, 7.552998389608519],
+    [49.98271508036773, -41.77498124337812, 7.934860393642487],
+    [54.23722285552117, -44.657464009458856, 8.389242664751075],
+    [59.369693671249145, -48.151556218611304, 8.94124798954586],
+    [65.71966696093297, -52.491817389585215, 9.628435786005802],
+    [14.350846154761584, -17.997122301606982, 4.304595366166933],
+    [18.392190274416965, -20.489003954356793, 4.663778332856585],
+    [21.127582091816638, -22.184577554987534, 4.912669042269777],
+    [23.33736931282823, -23.563565102820917, 5.118171519316023],
+    [25.325127315451784, -24.81498981231546, 5.3070121721859165],
+    [27.23678717780416, -26.030630121056326, 5.4923234375695085],
+    [29.16058265925416, -27.266660414233595, 5.682265665805077],
+    [31.162248228628282, -28.565565136870973, 5.8831246524902445],
+    [33.30043466608115, -29.96584386484256, 6.100703091663426],
+    [35.635629246504635, -31.507732192630108, 6.341161310464969],
+    [38.237255826269134, -33.237864394080496, 6.611724338000136],
+    [41.191246835053896, -35.21434467676374, 6.921471278746566],
+    [44.610001503652605, -37.513493908016876, 7.282402209006441],
+    [48.64728598834269, -40.240002304532695, 7.711057196572145],
+    [53.52244470319188, -43.54347448077574, 8.231167149249675],
+    [59.5624546380591, -47.64721734113639, 8.878280534860096],
+    [11.608125443212352, -15.500660704176248, 3.9314529578150306],
+    [15.263341310042351, -17.779396299779037, 4.261423684927051],
+    [17.847190824354676, -19.401435529442566, 4.500890043228744],
+    [19.956110405407717, -20.734639647200677, 4.700839681008908],
+    [21.85749017433568, -21.946284313372725, 4.884829918997462],
+    [23.68742249242455, -23.122413886111538, 5.065137935915088],
+    [25.5299848127197, -24.316809869108766, 5.249556954470028],
+    [27.448541541585964, -25.570549571826696, 5.444147632115995],
+    [29.500170250192244, -26.921123068851685, 5.654540911278766],
+    [31.74423341895459, -28.407940724378125, 5.886745143912071],
+    [34.24938517893157, -30.076925816432457, 6.14784032569132],
+    [37.10124917658215, -31.985626885385546, 6.446770531295286],
+    [40.412712368165074, -34.21013233152323, 6.7954319491224],
+    [44.339522209288866, -36.855590797460955, 7.210341824976265],
+    [49.105902876077735, -40.0735236856666, 7.7153979533808785],
+    [55.04956895307768, -44.09228223657417, 8.34674907517819],
+    [9.06005472435668, -13.29582543259536, 3.620905555064363],
+    [12.49544796376395, -15.456702847727607, 3.9346173040233006],
+    [14.99842827418119, -17.043527434731047, 4.169700315003721],
+    [17.046893539792595, -18.351478906637855, 4.366717581271519],
+    [18.88852299352793, -19.536061586847723, 4.547452042825704],
+    [20.656874387444084, -20.682075041197997, 4.723954509716687],
+    [22.435901021839566, -21.843416373613163, 4.904005529248614],
+    [24.28916024338498, -23.06137367266303, 5.093672399543109],
+    [26.27413696110516, -24.373705555996327, 5.298601097969273],
+    [28.45099637412639, -25.820272508892415, 5.524838532015677],
+    [30.889895794956136, -27.447819762550196, 5.779552737807806],
+    [33.67919527002304, -29.3154148480926, 6.071871872854065],
+    [36.936716741747816, -31.501952178464567, 6.414058984736776],
+    [40.827137247797396, -34.117778163147555, 6.823346299293108],
+    [45.591099665107585, -37.32417069137105, 7.325023649404443],
+    [51.59745712781723, -41.36832050762158, 7.958003886382498],
+    [6.552086834396789, -11.224681758126346, 3.3457407699057793],
+    [9.877690018334146, -13.331918842538208, 3.651895689098147],
+    [12.361298576370697, -14.919038870033374, 3.8873823393506575],
+    [14.388915992937587, -16.224155513964995, 4.08450440510325],
+    [16.196596344902524, -17.395799499664676, 4.263912439051276],
+    [17.921368335970158, -18.521263844889596, 4.43794315093744],
+    [19.650951880910657, -19.65702731668622, 4.614707723898965],
+    [21.451643022173034, -20.846223398221046, 4.800510669370756],
+    [23.38323350874985, -22.128116824540744, 5.001190177655468],
+    [25.50845284556404, -23.544183214607994, 5.223001320152387],
+    [27.900997677911604, -25.143349732679017, 5.4734011553197295],
+    [30.654734061923495, -26.98805791500019, 5.761979123639822],
+    [33.89662084209597, -29.162815719591837, 6.101788071312328],
+    [37.807171492752836, -31.787759159458304, 6.511471561269295],
+    [42.65561380529072, -35.041965168657654, 7.0189389792935755],
+    [48.864920354961576, -39.20657666772629, 7.668189459845562],
+    [3.9446675074242434, -9.160816249647478, 3.085872843090406],
+    [7.244333590111968, -11.26431729730243, 3.3911414241098714],
+    [9.76572041275819, -12.886058646192334, 3.631658380941478],
+    [11.816877186050993, -14.2151446145423, 3.8325856125231925],
+    [13.61982070509094, -15.391226962571032, 4.01311531769882],
+    [15.319502091613188, -16.50684815464233, 4.186225088714579],
+    [17.01176490805694, -17.62386016140774, 4.360736611000371],
+    [18.768701972499052, -18.789226406176045, 4.5434661647646815],
+    [20.654610953971787, -20.045159325359137, 4.740645644940259],
+    [22.736681883769954, -21.436002819566628, 4.95891523700277],
+    [25.094249343095285, -23.01425677357128, 5.206219703898724],
+    [27.829562790442008, -24.847637695623497, 5.4928845398599595],
+    [31.08327639870676, -27.029259674965967, 5.833189783452406],
+    [35.05970078087916, -29.69423905379096, 6.247957197475013],
+    [40.071687153090004, -33.049199452059696, 6.76917158564902],
+    [46.627038591005274, -37.42905486389018, 7.448911758707933],
+    [1.0832366958302142, -6.978537047863872, 2.8230785730868897],
+    [4.4348802260297795, -9.125671229038257, 3.133638776865353],
+    [7.047625427882805, -10.81489682947321, 3.3835126255261194],
+    [9.175229060572319, -12.200999091547612, 3.592782289339608],
+    [11.012697310816886, -13.40603013248537, 3.777895145649335],
+    [12.711072048422686, -14.52641306295968, 3.9522125312728167],
+    [14.3789018582519, -15.632306161502957, 4.12564734444845],
+    [16.098276159615214, -16.777242845242334, 4.305898662517536],
+    [17.941039672719683, -18.00838535879487, 4.499865883143044],
+    [19.981250721462235, -19.374538847287063, 4.714792704367844],
+    [22.30633225228862, -20.933394218731138, 4.95933449216974],
+    [25.03021355925921, -22.76010257434574, 5.244853587025477],
+    [28.312665872741622, -24.959901712302397, 5.587354955629069],
+    [32.39190371695241, -27.68939142491267, 6.0107745517173665],
+    [37.64502353348124, -31.195932990558934, 6.553103389350552],
+    [44.71055447629081, -35.89746371904391, 7.278852444097348],
+    [-2.237185513004919, -4.523663542319226, 2.536737466844392],
+    [1.2546276599585642, -6.768936688171959, 2.859445131197341],
+    [4.016716875422317, -8.56168439436436, 3.1232388401821054],
+    [6.278773830903844, -10.041438414642741, 3.3457343233322634],
+    [8.207322226844514, -11.311495656810584, 3.5404469485171894],
+    [9.943035817211623, -12.461166995802591, 3.719489835548844],
+    [11.606314315890925, -13.568224235508321, 3.8936981708946656],
+    [13.294548766543178, -14.69622527914679, 4.072102249034491],
+    [15.091924954433376, -15.90044595074893, 4.262692960405105],
+    [17.083006084344582, -17.236565655690136, 4.4736407651254755],
+    [19.366566652674106, -18.769625543768917, 4.7145985920509625],
+    [22.0722965319473, -20.584878847387607, 4.998321946881732],
+    [25.385910818471302, -22.804087393722227, 5.3431402920833175],
+    [29.59313857128492, -25.61401589505291, 5.777317169383434],
+    [35.165637656131494, -29.32197363787419, 6.347601995789004],
+    [42.94726416479663, -34.476028591692256, 7.137841493438649],
+    [-6.332287767981637, -1.569718612672659, 2.19827474065588],
+    [-2.5830702955623774, -3.986059726296404, 2.5418484571589897],
+    [0.4085785585918237, -5.932550918343896, 2.8256615734735693],
+    [2.870122432521102, -7.546946769044889, 3.0665473075364207],
+    [4.959332014621061, -8.926451995371872, 3.276805313052398],
+    [6.798603931204428, -10.147913645743357, 3.4665013415132244],
+    [8.499910934858038, -11.2831286146856, 3.645379661901729],
+    [10.173124685937443, -12.403680847582352, 3.823428933718088],
+    [11.921128412870432, -13.57714157950004, 4.010263670570085],
+    [13.845358350076545, -14.870301645108055, 4.215537520858229],
+    [16.061148352776186, -16.359020866348345, 4.450350228984181],
+    [18.71948418715053, -18.142293276170605, 4.729313939332502],
+    [22.04092536947224, -20.364136940131278, 5.073825492230144],
+    [26.377666096421674, -23.253502232617453, 5.5180926058201525],
+    [32.342857557790076, -27.207161156884037, 6.121739571343478],
+    [41.11672130273626, -32.985363091934516, 6.999757409880022],
+    [-11.758537816213307, 2.2716573531303044, 1.7607727047860655],
+    [-7.56985968930838, -0.42857058908075846, 2.137782340952477],
+    [-4.220299320017857, -2.608928448324178, 2.4508072611951204],
+    [-1.4631248491400313, -4.418162027532405, 2.717202654331233],
+    [0.8688627667207854, -5.95871111368215, 2.94940347517199],
+    [2.897539377146293, -7.306403414332677, 3.1569385961036565],
+    [4.7215914595702415, -8.523664851719778, 3.3479273681188833],
+    [6.435531373286301, -9.671387080983575, 3.5305750322592053],
+    [8.146975035579715, -10.819973544553445, 3.7146544938526813],
+    [9.9798271967218, -12.051027312794577, 3.9117164734174397],
+    [12.075790674788514, -13.457804086098013, 4.135140310112895],
+    [14.616301723079463, -15.159143819794112, 4.40214446965413],
+    [17.86894001839337, -17.329233286293075, 4.738148290911117],
+    [22.278990773597418, -20.2560704831931, 5.185437434323805],
+    [28.676614825069553, -24.473063302637005, 5.822834829570344],
+    [38.82906272070981, -31.108195801964605, 6.818314593942245],
+    [-19.65465372735788, 7.778977853507884, 1.1346409854230757],
+    [-14.690151961243698, 4.589645529614359, 1.565575165932628],
+    [-10.746661712421956, 2.03009103066043, 1.923033948545504],
+    [-7.521783009652097, -0.0801267054549116, 2.22645233441682],
+    [-4.81573037960251, -1.86236477574217, 2.489636665274993],
+    [-2.488530962690777, -3.402899794937898, 2.7228235819326376],
+    [-0.43509119276292296, -4.767384558351526, 2.9340938898859847],
+    [1.431619268510139, -6.010939275249125, 3.130478281323248],
+    [3.195549349191708, -7.187514658224194, 3.319107710718742],
+    [4.957683299084665, -8.362900804353167, 3.5089565792413495],
+    [6.86698622175755, -9.634921977923225, 3.713655318991558],
+    [9.147020138303544, -11.150223331942888, 3.9538788965104543],
+    [12.133404255920619, -13.127212463775427, 4.260663559046416],
+    [16.40225804668359, -15.937099568539047, 4.687109959776457],
+    [23.12405861988501, -20.326561290190565, 5.340798487232064],
+    [35.184636475691896, -28.11769467098456, 6.486749492503622],
+    [-32.887234856551636, 16.85857260989103, 0.12036505230546925],
+    [-26.360201569080086, 12.717739502155712, 0.641731815129143],
+    [-21.290494687225276, 9.461500023301113, 1.0711882323438686],
+    [-17.224506432618515, 6.826896078817953, 1.4321466914763505],
+    [-13.87628809092808, 4.644192567267544, 1.7413193948995846],
+    [-11.054067726308787, 2.797416103000938, 2.0109932253275042],
+    [-8.621954841136441, 1.203275381481212, 2.2504756717626053],
+    [-6.4772709784894875, -0.2017958761308964, 2.4671846086796982],
+    [-4.535145639832721, -1.4707650092681632, 2.6675494064969545],
+    [-2.7150969041258883, -2.6542527527791706, 2.857951387441673],
+    [-0.9234644123595841, -3.811694425639092, 3.046142802332843],
+    [0.9824178101075796, -5.03449069919231, 3.244352286368419],
+    [3.2944787997506846, -6.510960197822984, 3.478155978181703],
+    [6.723764871254236, -8.698906261768899, 3.8102076997325707],
+    [12.989933536690504, -12.690035090875924, 4.390896894503756],
+    [27.378759174418438, -21.777200062439288, 5.679224829576894],
+    [-62.70306181679613, 36.7049362591247, -1.9180130039382297],
+    [-51.183468631754494, 29.589102986752426, -1.1610496712613951],
+    [-43.069600894843504, 24.533134175283887, -0.5904921511383809],
+    [-36.98651536393871, 20.71422364961308, -0.13448866828028921],
+    [-32.241921366528985, 17.722060728458285, 0.24187162144146815],
+    [-28.444330642996427, 15.32699213710872, 0.55773526372548],
+    [-25.351269676430295, 13.387938162431459, 0.824941265665406],
+    [-22.800596902662335, 11.811109743406304, 1.051727486476174],
+    [-20.677350386187552, 10.53106298560577, 1.2441328779518885],
+    [-18.89483333667495, 9.50041651885932, 1.4066591421533088],
+    [-17.380144732479515, 8.682134940589254, 1.5428581578159424],
+    [-16.057427731041322, 8.0405806138962, 1.6561967353906963],
+    [-14.816380757124488, 7.524196424656519, 1.7519826930169506],
+    [-13.41893008400252, 7.011758013540339, 1.843705492491975],
+    [-11.039496612321054, 6.037265099334601, 1.986370790068597],
+    [-2.7803185086910594e-12, 2.075130221910251e-12, 2.745251177315428],
+    [35.10959287431938, -45.207458301308606, 9.579218658665138],
+    [59.17314336507768, -58.59193258675025, 11.398735772468541],
+    [63.66859174816926, -60.55561466949461, 11.617015115678619],
+    [66.4609709006859, -61.67029191537168, 11.731381187518474],
+    [68.82403774112397, -62.67350158887764, 11.8449410918664],
+    [71.1694392526104, -63.79391683162758, 11.988744874048756],
+    [73.69390332925047, -65.1381716095903, 12.177075462703986],
+    [76.5296280829745, -66.78033477620451, 12.420214343338705],
+    [79.80055883554799, -68.79589036671022, 12.729361052950807],
+    [83.64852675638268, -71.27797091126257, 13.119052065404906],
+    [88.25345113147499, -74.35074030438835, 13.609260979191426],
+    [93.860273826625, -78.18835453280418, 14.228490589542693],
+    [100.82327775961339, -83.0471389486785, 15.01911269530389],
+    [109.68598273104486, -89.32439556533281, 16.04720303871995],
+    [121.33935590505588, -97.67557743685339, 17.422209371444286],
+    [137.3719576012225, -109.27455492473922, 19.340737651508753],
+    [32.00705271716116, -35.8098117579303, 7.534239554329292],
+    [40.15438281641852, -40.539346386456295, 8.200158314394479],
+    [44.05058710140965, -42.73272676821451, 8.508128013685539],
+    [46.95638273012794, -44.36963587875625, 8.74122450067992],
+    [49.521717126591625, -45.84567698594307, 8.956280771853407],
+    [52.00659451953703, -47.31931923394986, 9.176173194291854],
+    [54.556571731852785, -48.88030017376393, 9.414037827066606],
+    [57.27708884482957, -50.59531250652418, 9.67987944520933],
+    [60.2643178152401, -52.52732446948836, 9.983417168148303],
+    [63.62223977884596, -54.746642683257946, 10.335766649691786],
+    [67.47611359290029, -57.34002500016786, 10.750880636634092],
+    [71.98821363125934, -60.421708127423734, 11.247341820243506],
+    [77.38117986451633, -64.15008457736226, 11.851112162277934],
+    [83.97686984784717, -68.75571657148281, 12.60018251658987],
+    [92.26640424346245, -74.5921037521058, 13.553030820198526],
+    [103.04717889905413, -82.23531846841321, 14.805264706649687],
+    [25.82208326233016, -29.01615256434563, 6.317668210571666],
+    [31.364342229243004, -32.346666224408345, 6.798347105525511],
+    [34.709019854112, -34.35086771071865, 7.091942843346368],
+    [37.37404681060751, -35.960649000698034, 7.3316942482766105],
+    [39.78412150626899, -37.43876725136457, 7.555502391354504],
+    [42.12888979707752, -38.9035442010148, 7.780613714039975],
+    [44.522312167691354, -40.42717846570747, 8.017732873700375],
+    [47.05076502143144, -42.06563254437708, 8.275346490729833],
+    [49.794052324730806, -43.871912299129455, 8.561679278038723],
+    [52.83798640627791, -45.904264162343964, 8.885940251176146],
+    [56.284852957313795, -48.23324327161822, 9.259436721363452],
+    [60.26543074773517, -50.95005620869946, 9.696923809348103],
+    [64.95614901808031, -54.17864750550562, 10.218586037163075],
+    [70.60661652889353, -58.09523725332034, 10.853261931314652],
+    [77.58729054578022, -62.96229393159371, 11.64407279451661],
+    [86.47817768823992, -69.19192365494892, 12.658953410100123],
+    [21.450116349942327, -24.622369012771838, 5.579511954942231],
+    [25.889150171964985, -27.352596577864, 5.979725898985466],
+    [28.870092332153263, -29.195359005828305, 6.255069355121096],
+    [31.329899025570878, -30.730430338524847, 6.488289517913291],
+    [33.5871562443029, -32.15689007067644, 6.70811208758311],
+    [35.795179713140044, -33.571559048991446, 6.928686091189448],
+    [38.050432213136475, -35.0363123682934, 7.159218410872427],
+    [40.42846561955269, -36.60057805364891, 7.40722416713332],
+    [43.000321869262045, -38.311767428094406, 7.680062042612705],
+    [45.842876252859945, -40.22203409136738, 7.985961060939069],
+    [49.04788982550114, -42.394371002084696, 8.334979180994626],
+    [52.7325752068509, -44.909884105212335, 8.74017506850026],
+    [57.05456635996606, -47.878218802119235, 9.219309709580397],
+    [62.235632652946066, -51.45417293182483, 9.797575609464902],
+    [68.60214605524291, -55.8661381472265, 10.512287406249815],
+    [76.65909048677277, -61.46822612766713, 11.421502036549127],
+    [18.07605508126922, -21.423147728987324, 5.075699683725949],
+    [21.927636886117007, -23.83309423260669, 5.432842044417873],
+    [24.670881081367252, -25.563569424005493, 5.694690986210753],
+    [26.980108983352302, -27.034500669624606, 5.921021279151331],
+    [29.117952029505087, -28.41125362430379, 6.135670234504879],
+    [31.218013980003036, -29.778908074878448, 6.351075104446129],
+    [33.36711380948892, -31.19358454521632, 6.575592980080163],
+    [35.63493575757733, -32.70111911362719, 6.816193773300388],
+    [38.088125940665535, -34.34607668006103, 7.079785959959541],
+    [40.799558142058515, -36.17782041295834, 7.37413816612372],
+    [43.856822029471886, -38.256215114287286, 7.708774280744485],
+    [47.37240766195036, -40.65859054050634, 8.096091518672562],
+    [51.49826888843516, -43.489788894822986, 8.552993666725552],
+    [56.448951196946396, -46.89819107101479, 9.103513081046817],
+    [62.54111278214134, -51.103168164696065, 9.78331961421268],
+    [70.26592020619373, -56.44544744144886, 10.648016515077822],
+    [15.246086589002918, -18.87828728455348, 4.703277489610853],
+    [18.764030127895936, -21.10981136048011, 5.036699651008787],
+    [21.357139353471617, -22.77055468971572, 5.290369029867333],
+    [23.56233005335979, -24.196662005793236, 5.51196441351742],
+    [25.61256574560717, -25.535751120756604, 5.722724409152819],
+    [27.631625030111323, -26.86716517434269, 5.934236970394765],
+    [29.7017368269556, -28.244359119779247, 6.154456945078187],
+    [31.890041275943904, -29.711655508746492, 6.390116647196157],
+    [34.26168135902396, -31.31265524214519, 6.647961718601567],
+    [36.8887154433198, -33.096087528239266, 6.935638844356169],
+    [39.85861641884791, -35.12150830124343, 7.262588638420431],
+    [43.28479308131053, -37.466451828137664, 7.6411904839801394],
+    [47.32191853541984, -40.236920559288805, 8.08846137423726],
+    [52.190593587536945, -43.58431659289087, 8.628815337357555],
+    [58.220037683496415, -47.73479827142564, 9.298867047030217],
+    [65.92747009048428, -53.04392899759704, 10.156400654547541],
+    [12.694892024516003, -16.702025198002527, 4.410306265574169],
+    [16.034978269308336, -18.84519082739779, 4.732541988607585],
+    [18.546483525320678, -20.473729150149325, 4.98317787878992],
+    [20.68750644810463, -21.87566293225414, 5.2028670104348045],
+    [22.677764583901684, -23.190977546932977, 5.411692074130692],
+    [24.638374764544462, -24.497741223643693, 5.621032135852034],
+    [26.65088430136906, -25.849208210955993, 5.838797813283595],
+    [28.782508913231226, -27.289873898444927, 6.071733597820324],
+    [31.099144324776272, -28.86386915981111, 6.326649175121603],
+    [33.67449124323905, -30.620959424402777, 6.61132860603922],
+    [36.59905797877141, -32.62256821432194, 6.935472264173356],
+    [39.99166190754228, -34.94955472347599, 7.311938005643382],
+    [44.016600305789325, -37.71388216174459, 7.758623847745417],
+    [48.911869298449915, -41.07783429926065, 8.301587833055109],
+    [55.03911231427948, -45.288065494692916, 8.980599101613025],
+    [62.9789680637972, -50.74064931872035, 9.85977702720201],
+    [10.237903473583668, -14.713769131508156, 4.166072422373692],
+    [13.513641832780642, -16.83664982044849, 4.486748057222367],
+    [16.006014428982628, -18.470175163435844, 4.739705411895484],
+    [18.121034483202415, -19.870310571657626, 4.96081065964244],
+    [20.076894164083953, -21.176711366507888, 5.17001488465159],
+    [21.99843098344831, -22.4702331404366, 5.379065915816764],
+    [23.970240145697375, -23.806338285807332, 5.596177575595915],
+    [26.062104425734073, -25.231331799395946, 5.828346780485913],
+    [28.342754775655695, -26.79127035989756, 6.082656483438955],
+    [30.889765882589067, -28.538475812647327, 6.367260357160614],
+    [33.79958379310338, -30.538230986997622, 6.692426868545082],
+    [37.200725927818404, -32.877664935332795, 7.071952136371786],
+    [41.2740570055972, -35.67944664134604, 7.525360568100998],
+    [46.287063122312084, -39.12496071568131, 8.081652396309948],
+    [52.656443825028084, -43.49665319506406, 8.786181058838014],
+    [61.072159136078774, -49.261989921914946, 9.714338358926875],
+    [7.716655111390801, -12.774324771192317, 3.949110659934257],
+    [11.022587706934347, -14.935654396436902, 4.276607241900186],
+    [13.562119741008699, -16.616007973759594, 4.5380514196270525],
+    [15.694172935117425, -18.041654843858517, 4.764775994449709],
+    [17.642996809498595, -19.356619327615785, 4.977224845410422],
+    [19.543912793140024, -20.648916964035692, 5.188125709771821],
+    [21.488950142369205, -21.979100195132503, 5.406406389031936],
+    [23.553351612353122, -23.397212367043217, 5.639595595883979],
+    [25.810962440464003, -24.95277882045448, 5.895276957508859],
+    [28.34556948013143, -26.70227703912966, 6.182213464865903],
+    [31.262606411849784, -28.71693775292763, 6.511558780154676],
+    [34.70498083810892, -31.09335513182949, 6.898536938201791],
+    [38.878168350854594, -33.97034279232896, 7.3651387941760165],
+    [44.09414628417253, -37.558456450081636, 7.944874571908545],
+    [50.854964215631114, -42.196151216655345, 8.691856808058224],
+    [60.02687469994579, -48.4668043667115, 9.69980615159705],
+    [4.958983194470353, -10.747659973272743, 3.7407793859521634],
+    [8.385325103711853, -13.005263731237484, 4.083347884219609],
+    [11.048599480313053, -14.782584837848342, 4.36071194879217],
+    [13.255068880586292, -16.27184519778822, 4.598964468099782],
+    [15.232725928213371, -17.619618840838537, 4.8186884007507125],
+    [17.134413210646617, -18.92565274303045, 5.034206708546493],
+    [19.065624900983, -20.25959199717355, 5.255723196841313],
+    [21.111119513162, -21.67794850844304, 5.49168849547237],
+    [23.35281788185303, -23.23573813566612, 5.750479937880478],
+    [25.883503264411708, -24.995511811398575, 6.041751682833168],
+    [28.8211248420405, -27.03687273065276, 6.377903368439037],
+    [32.3285679917223, -29.46968347856298, 6.776158999773742],
+    [36.6460956846859, -32.45575417998378, 7.262017244514357],
+    [42.15062204479464, -36.24845266555372, 7.875594417298306],
+    [49.47447287273979, -41.272021389394226, 8.684392152949902],
+    [59.769650407613256, -48.29802031525005, 9.81183659171208],
+    [1.7307025443558195, -8.462628437730881, 3.519798768608171],
+    [5.384172498798394, -10.886533420066241, 3.887318544227732],
+    [8.259571394796057, -12.820023207775783, 4.189341648335384],
+    [10.6234811015586, -14.429250003988471, 4.44779431021052],
+    [12.687658993689844, -15.849746201471516, 4.6813225360584605],
+    [14.623000546614614, -17.19317762344843, 4.905776242006615],
+    [16.556602071290115, -18.543698745071172, 5.133374758208162],
+    [18.58931372255554, -19.9687397366754, 5.374117687277377],
+    [20.815728854165705, -21.531965309506944, 5.637627260877847],
+    [23.341423785569845, -23.304640326448364, 5.9348297488379],
+    [26.30125989824842, -25.37779375022241, 6.2798156244807695],
+    [29.885086684879962, -27.879341533805786, 6.692514052404784],
+    [34.38146854228271, -31.003202146901682, 7.203292235659791],
+    [40.26179797354527, -35.06522939612021, 7.861853652382834],
+    [48.36034473951104, -40.62278420893974, 8.756372201088531],
+    [60.311197092416954, -48.76466744865504, 10.060154653786759],
+    [-2.349547780230251, -5.651819200997759, 3.2545093835749577],
+    [1.6866076458467505, -8.345182860063954, 3.661177169390481],
+    [4.889037042968559, -10.51275371788794, 3.9991654562401684],
+    [7.521442515545061, -12.318283830936112, 4.289439996462288],
+    [9.772988351983912, -13.881677378771318, 4.547951186772551],
+    [11.808057606140947, -15.309766385382973, 4.789511209709894],
+    [13.776356763037167, -16.70176527283269, 5.028323140234917],
+    [15.804978689558382, -18.142900136920137, 5.276878498689784],
+    [18.009600485726054, -19.711281555036216, 5.54686160715329],
+    [20.51480354106446, -21.49124750374244, 5.851090430058322],
+    [23.478351231235784, -23.589487714347907, 6.205950077351208],
+    [27.125970825958277, -26.158176436400776, 6.634966686768055],
+    [31.812730970983516, -29.435715660555086, 7.175182499739991],
+    [38.148629679596496, -33.829892738838275, 7.890270135632788],
+    [47.29134431131016, -40.11126786397243, 8.901236447176723],
+    [61.745054462839406, -49.939964230238694, 10.470647279644131],
+    [-8.011086092180983, -1.8122964653393243, 2.886094083424744],
+    [-3.3258399786700696, -4.951505744715867, 3.3551794346477135],
+    [0.3951284713887523, -7.482806749616838, 3.7472930674651397],
+    [3.4501429266640704, -9.5910674666569, 4.085235994259462],
+    [6.03933664834671, -11.4024269504938, 4.385177920340745],
+    [8.312505872227623, -13.013090779230264, 4.659935118110212],
+    [10.406117932890172, -14.512763312229282, 4.921971907287984],
+    [12.466193067070027, -15.999379206071252, 5.185381644383619],
+    [14.64192809308623, -17.574083330607316, 5.465072327648214],
+    [17.089448113514532, -19.343118789586356, 5.776992466574284],
+    [19.99929947875349, -21.435965232402314, 6.140820867536438],
+    [23.644614118251173, -24.037359306262115, 6.5848355842557735],
+    [28.470589030564554, -27.446607238885072, 7.155066754199883],
+    [35.291638961274025, -32.20770734157103, 7.935560280556099],
+    [45.80561523409694, -39.44735958980573, 9.101432160881789],
+    [64.2502209398801, -51.96013269451351, 11.09070255506094],
+    [-16.9328278600053, 4.205687301049519, 2.2805504205027414],
+    [-11.04558434985884, 0.25927752353966815, 2.8564404338991616],
+    [-6.418844661830877, -2.8945109394929114, 3.3367669792616415],
+    [-2.653828113423321, -5.501601033178038, 3.7500662160902167],
+    [0.5049194598298556, -7.722317564912396, 4.1158905401480785],
+    [3.2333655727839092, -9.66891055641066, 4.448526813288987],
+    [5.664586296662092, -11.427768913780453, 4.75944395153023],
+    [7.916108112673248, -13.076758996395382, 5.059441440451041],
+    [10.122742687018523, -14.707065120788776, 5.36166405857596],
+    [12.468754507214314, -16.44482428794661, 5.68473069991947],
+    [15.195721836946063, -18.45569731890408, 6.053445220767189],
+    [18.641634038420797, -20.970253947850143, 6.502627704864537],
+    [23.367730528680283, -24.368014985830392, 7.089821210127494],
+    [30.48033111304612, -29.38966960118395, 7.9276772045],
+    [42.61096559452148, -37.7787152551586, 9.282785523652453],
+    [67.98835712917452, -54.9288824814672, 11.988170720596312],
+    [-34.234892234778705, 15.866003366680166, 1.0478588281453154],
+    [-25.583422750999762, 10.124169071066532, 1.834691887085028],
+    [-19.020788439033367, 5.678892974508552, 2.481252051911222],
+    [-13.829291720949579, 2.0982457451074517, 3.0300598499732643],
+    [-9.579259038179789, -0.8837452929025096, 3.5102276726113004],
+    [-5.995322160120072, -3.440967374800546, 3.9421636636090565],
+    [-2.888862897372039, -5.694593783878224, 4.340945573639158],
+    [-0.12028090557237082, -7.735865264726075, 4.718592489584728],
+    [2.425866985104968, -9.64164278506502, 5.085826909771502],
+    [4.863806123094322, -11.489396224500426, 5.454007280455513],
+    [7.3437439575375, -13.38239759849255, 5.838677802125378],
+    [10.1503205973386, -15.517166540602199, 6.269360349895602],
+    [13.919904113221216, -18.33063759138679, 6.811112937340224],
+    [20.08149794278425, -22.79034580167908, 7.6073558079035735],
+    [32.74814188327587, -31.629865525171134, 9.063134584457718],
+    [71.59749525291016, -57.641014631644175, 13.0933522253747],
+    [-89.61010308122286, 52.10282233932026, -2.4712176981979264],
+    [-68.06583027786688, 38.6484162929985, -1.10411349805075],
+    [-54.410408869599564, 29.88067892381755, -0.089939212948979],
+    [-44.68794917446778, 23.45298936553926, 0.7471675400790183],
+    [-37.36558937328702, 18.491592839012743, 1.459590664407049],
+    [-31.683981065405288, 14.566533716889111, 2.073341144292077],
+    [-27.185587640180906, 11.405954310302759, 2.611731206899104],
+    [-23.586068278995647, 8.837697662709138, 3.0924702523474874],
+    [-20.711552749684902, 6.758823921830667, 3.527599167470827],
+    [-18.466993551899385, 5.120406593920891, 3.9239299891636197],
+    [-16.824812117852492, 3.925698154267205, 4.282593904227321],
+    [-15.83157141263005, 3.2441835463676387, 4.5967592827038395],
+    [-15.640465038037288, 3.254924319088805, 4.845297722748786],
+    [-16.588908393925053, 4.3584788643547165, 4.976223308758232],
+    [-19.22950259842621, 7.416135338760628, 4.867781345934138],
+    [-3.803323924494135e-12, 3.529955485535057e-12, 5.977080004848892],
+    [39.61300980482714, -48.74609357694097, 10.235931425974135],
+    [61.63001875210372, -60.898940547484784, 11.882746428825468],
+    [66.053572960416, -62.78547066145109, 12.086822087083338],
+    [68.8415242554224, -63.875607408633506, 12.195764332689535],
+    [71.23283935588626, -64.88384661352204, 12.309377339479028],
+    [73.63463751062025, -66.0347552634004, 12.458043505834787],
+    [76.24323692123919, -67.4339559695435, 12.655857494805401],
+    [79.19406554337533, -69.15736630917431, 12.913396323280107],
+    [82.61792355542113, -71.28522157607986, 13.2426658250846],
+    [86.66736857002084, -73.91851953028349, 13.659567641909533],
+    [91.53852753346, -77.19359933678761, 14.186209594428217],
+    [97.50169838291315, -81.30385411634883, 14.854475869292765],
+    [104.95281327384569, -86.5373221306464, 15.712309834329307],
+    [114.5079901026728, -93.34662777467216, 16.835487645987744],
+    [127.1951880709771, -102.49156444036677, 18.35170170858172],
+    [144.8912326359901, -115.36534722863503, 20.495788744179528],
+    [33.84265469647321, -37.439627004266455, 7.87532980113527],
+    [41.720333862711435, -42.00946633043726, 8.520898413014976],
+    [45.615975785327024, -44.20455022484968, 8.830583184318774],
+    [48.56068162270451, -45.86918793824727, 9.069235212321507],
+    [51.18281241276423, -47.3862779020771, 9.292059279442638],
+    [53.739299359212865, -48.912665379275545, 9.521791801742037],
+    [56.37634914313045, -50.53875854245336, 9.771740767807199],
+    [59.202018928771174, -52.33324825672104, 10.052285176805452],
+    [62.31698556213024, -54.36246780733854, 10.373745590475533],
+    [65.83191901961607, -56.701724104145605, 10.748122441090327],
+    [69.88176566315938, -59.44503828637415, 11.190650640916886],
+    [74.64315937400336, -62.71742778420673, 11.721812892229172],
+    [80.36089863740159, -66.69392001824131, 12.370496878509307],
+    [87.39274539300686, -71.63201043615663, 13.179418966472122],
+    [96.29156458871182, -77.93146766144596, 14.21515375661684],
+    [107.96923745802725, -86.25406314984515, 15.588263717982608],
+    [27.24354191037743, -30.308521834218578, 6.59840715584595],
+    [32.67158061628891, -33.57789044464896, 7.073140879148865],
+    [36.0259097083197, -35.59547862066366, 7.3708630712266014],
+    [38.732035849961946, -37.23877350113831, 7.6176354684976],
+    [41.198762623283336, -38.7612688784968, 7.850191581166489],
+    [43.61275320123827, -40.27976842039246, 8.085649136538507],
+    [46.088356533391895, -41.86701962282598, 8.334866436817494],
+    [48.713997147213156, -43.58062062100684, 8.606645917388366],
+    [51.573001081670775, -45.476246695935, 8.909696635097186],
+    [54.75642606221338, -47.61606806379116, 9.25392604223928],
+    [58.37414049581056, -50.076289060374634, 9.651639914585122],
+    [62.568022338162194, -52.95636861979434, 10.119046711748291],
+    [67.53126413073346, -56.39269373832072, 10.678516344942063],
+    [73.53986067716097, -60.58103720103356, 11.3623131234505],
+    [81.00795122664417, -65.81618677594022, 12.219207380176579],
+    [90.59256121332258, -72.56714860129095, 13.327054992588645],
+    [22.722222370542813, -25.79350733566275, 5.839693637869237],
+    [27.09438543937717, -28.492914336692717, 6.23844641607866],
+    [30.088225669483087, -30.352989925992283, 6.518785885687403],
+    [32.588724753245586, -31.923069026570356, 6.759533885340712],
+    [34.901663078638, -33.39478267266167, 6.988488216722053],
+    [37.1774563936436, -34.863462732015385, 7.219666851172369],
+    [39.51279391719537, -36.39142092301091, 7.462410324606003],
+    [41.985030351955494, -38.029547516198555, 7.724519935570902],
+    [44.668357486125004, -39.82764840839526, 8.013791334936453],
+    [47.64437405748923, -41.841400632834386, 8.339081714917466],
+    [51.01170017559035, -44.13887549858544, 8.71134583947809],
+    [54.89758364279009, -46.808579715563845, 9.144943128527393],
+    [59.47473223439089, -49.97124437911109, 9.659577033871686],
+    [64.98841720179612, -53.79891068596862, 10.283454099456897],
+    [71.80340094351861, -58.54807795147079, 11.058791124844035],
+    [80.49117482857991, -64.62145309454773, 12.052101407847188],
+    [19.29196769867555, -22.553031679107985, 5.330932049803444],
+    [23.09778262435255, -24.94610434174792, 5.688795622065973],
+    [25.855344376491907, -26.69604453040274, 5.956209289066344],
+    [28.205124984644495, -28.203157702100825, 6.190495585274963],
+    [30.3987533979445, -29.626425230403232, 6.414708514616781],
+    [32.56693746987275, -31.04942427683399, 6.641150173174635],
+    [34.79661903017334, -32.52864097436546, 6.878300152937302],
+    [37.159203562045974, -34.111316483095194, 7.133405435996324],
+    [39.72440695135969, -35.844348273279344, 7.413800976995258],
+    [42.56974601132746, -37.780551096421675, 7.727870539179519],
+    [45.7896017766684, -39.984791053709344, 8.086019649279326],
+    [49.50645523605118, -42.54171719589858, 8.501928725274926],
+    [53.887319857799596, -45.56716408944098, 8.994422294966158],
+    [59.17026950883558, -49.22663490881269, 9.590517204797512],
+    [65.71044908353466, -53.76743296705074, 10.330741722845612],
+    [74.06578755031364, -59.57861312390384, 11.279087278028145],
+    [16.454443405511384, -20.010272656162, 4.9625439284802715],
+    [19.938186817141496, -22.23319260992268, 5.298151218679045],
+    [22.546147588743853, -23.915005116148293, 5.557924852048426],
+    [24.792189640484953, -25.37879019412752, 5.788000672824654],
+    [26.899393743608755, -26.766462551678337, 6.0089334732236255],
+    [28.988535583845827, -28.155793642004465, 6.23216854955205],
+    [31.14187028314191, -29.600548165607677, 6.465777538044555],
+    [33.42826785732653, -31.146467677349037, 6.716774431771548],
+    [35.91604027732375, -32.83955862721027, 6.992345006907362],
+    [38.68209403957857, -34.73214364207146, 7.30077566887121],
+    [41.82106370414513, -36.889030142806426, 7.652430848737664],
+    [45.456997095106395, -39.395505497829745, 8.061045588149977],
+    [49.76076129426399, -42.36932122916118, 8.545683882625857],
+    [54.978521999652955, -45.98035929359534, 9.133971339789252],
+    [61.48182113781164, -50.48526965120544, 9.867810725940023],
+    [69.86238998474752, -56.29312636367012, 10.814247600341794],
+    [13.9283871903461, -17.866502578282468, 4.680353923978481],
+    [17.242393471918863, -20.00763363375947, 5.006125611848425],
+    [19.768575171269035, -21.65867046527666, 5.263480462690655],
+    [21.950977763361944, -23.100201311377678, 5.492331822495442],
+    [24.00028398010582, -24.467063502272513, 5.7121694446089535],
+    [26.034379355481985, -25.83562523803561, 5.934218765655886],
+    [28.13470520906092, -27.259384770739974, 6.166503647728677],
+    [30.370266436900167, -28.784317010033202, 6.416065122590983],
+    [32.81036438540365, -30.457138348326055, 6.690186474367113],
+    [35.53399312539817, -32.33153042661966, 6.997348245669682],
+    [38.63958239222048, -34.47470787744662, 7.348267528368273],
+    [42.257878343048105, -36.97618872361003, 7.757312345960502],
+    [46.57161085199201, -39.96124641275694, 8.244693111796002],
+    [51.848384639490845, -43.613451685597745, 8.840156309459969],
+    [58.49989845232276, -48.215302142581315, 9.58966874482204],
+    [67.19727339475087, -54.22741345044201, 10.568487645581865],
+    [11.52261696884325, -15.936364888185949, 4.453053458076314],
+    [14.781136877348041, -18.06492646377706, 4.7789580092209105],
+    [17.287470869659824, -19.722527085356106, 5.039425256381837],
+    [19.444287011275808, -21.164579048336375, 5.270579373874632],
+    [21.46194600057966, -22.526429761376324, 5.491921662681598],
+    [23.461732391611037, -23.887071541293512, 5.715037063682753],
+    [25.527923089803245, -25.302110265674003, 5.9482487489761],
+    [27.73216637263988, -26.819418865539774, 6.1988733413928525],
+    [30.146957760605982, -28.487919561659595, 6.474520292179059],
+    [32.85585612114048, -30.36436071751016, 6.784130317341281],
+    [35.96441483255643, -32.520672992630814, 7.139137194316096],
+    [39.615108560030315, -35.054090806677024, 7.555095503009553],
+    [44.01080743129445, -38.103118817120446, 8.05427343270063],
+    [49.455204906619144, -41.87506245169111, 8.670149601488664],
+    [56.42805754143831, -46.69729500406347, 9.455822769894999],
+    [65.73773148495528, -53.12125763051372, 10.501133253805937],
+    [9.075690920266807, -14.079556225877383, 4.259448587173391],
+    [12.378375327061077, -16.258516441490226, 4.5947214463230575],
+    [14.93175740345243, -17.965700505661133, 4.864873081571031],
+    [17.105477419187245, -19.435962156793956, 5.102811976080958],
+    [19.11916863133201, -20.81124106225309, 5.328888559553826],
+    [21.104389315713203, -22.177639662309247, 5.555685208696226],
+    [23.152517191463556, -23.595700888022986, 5.792234750855595],
+    [25.340707173115938, -25.117128896511122, 6.046415524556231],
+    [27.7470405418971, -26.79469566564465, 6.326411865710793],
+    [30.4622610917883, -28.69003604656955, 6.641905003053643],
+    [33.602625412399625, -30.88228306485712, 7.005432814883076],
+    [37.327995525479004, -33.48029398829696, 7.434349490359632],
+    [41.871267461936185, -36.64257390669928, 7.954049572608544],
+    [47.5909620342252, -40.61289665580236, 8.603766611008755],
+    [55.073434420389965, -45.78954342148561, 9.447896734204443],
+    [65.35159536140206, -52.873509184766874, 10.600337432882572],
+    [6.414802334429924, -12.161592793620795, 4.081927915730749],
+    [9.859988471652565, -14.455396208393134, 4.436122210939649],
+    [12.542167901098445, -16.266937979825784, 4.7244290197241465],
+    [14.789673199091611, -17.804350772170043, 4.975539840227944],
+    [16.834926784315204, -19.218250511350213, 5.210872461365804],
+    [18.82802349509558, -20.607299466077748, 5.444766026176217],
+    [20.87341746493017, -22.040991409218382, 5.687539239763795],
+    [23.05772406423683, -23.577564033812216, 5.948028392478263],
+    [25.46765627547031, -25.275758900578953, 6.235310286496229],
+    [28.204264460686154, -27.204291090688766, 6.560141405544787],
+    [31.398742404602906, -29.45246081563283, 6.936621338143851],
+    [35.23529772257424, -32.145567468984, 7.384653026262409],
+    [39.98978185606971, -35.47096608206742, 7.934141959988105],
+    [46.10193638622262, -39.72678454401041, 8.632896511658716],
+    [54.32382132251285, -45.42197689288561, 9.562937084178074],
+    [66.0608847752009, -53.50622626941024, 10.878153688537477],
+    [3.3053696299938506, -10.014375771513388, 3.9009901452041933],
+    [7.009316216156913, -12.501318907320595, 4.285709001275913],
+    [9.922553306639324, -14.487578758281753, 4.603099824292579],
+    [12.330615252921788, -16.152944767095196, 4.877299546720731],
+    [14.46409429802068, -17.64663233173622, 5.129196389442673],
+    [16.49817095655517, -19.08415274428305, 5.3754323905341925],
+    [18.559465687990745, -20.550096467213752, 5.628481449644197],
+    [20.75051545439443, -22.11368361135552, 5.898784345361641],
+    [23.171263078248323, -23.842859001079898, 6.196791069205282],
+    [25.93724683999872, -25.816404442119396, 6.534786258602934],
+    [29.199964316605634, -28.13760853725432, 6.929017595811811],
+    [33.17698695478688, -30.954532162861472, 7.402912389081327],
+    [38.20498119276112, -34.495677540291254, 7.992796951687005],
+    [44.84454223296522, -39.140438023019826, 8.759268971675409],
+    [54.11147430538406, -45.57436757775173, 9.812408817885823],
+    [68.06108299761031, -55.18126379783654, 11.375814377856573],
+    [-0.640538814435699, -7.3712592740147675, 3.6872946543867346],
+    [3.494271338673457, -10.168744773163276, 4.119669947062456],
+    [6.772565341034876, -12.423624056082126, 4.480702712341694],
+    [9.471598068966596, -14.309637271953164, 4.793020930636357],
+    [11.79845578402411, -15.959654274812893, 5.074675202526782],
+    [13.938339644476827, -17.4956348675216, 5.34294243639606],
+    [16.04914214554056, -19.02342125342676, 5.613269805899078],
+    [18.260806902796762, -20.63115789927437, 5.898843037818899],
+    [20.69487062292656, -22.401914482254085, 6.212360398197464],
+    [23.48791097464834, -24.429302719151767, 6.568356232683551],
+    [26.819024388757484, -26.83594348651424, 6.986047445141179],
+    [30.951159665345486, -29.801285036940623, 7.493714982405944],
+    [36.307162530219905, -33.61270429212, 8.136844032608362],
+    [43.63075215423773, -38.77345073209861, 8.99544681608738],
+    [54.37617948098927, -46.26343361145692, 10.22619723762651],
+    [71.82896946260195, -58.28842151631735, 12.184348896979463],
+    [-6.1855550843574125, -3.7145454821209154, 3.3836706800819485],
+    [-1.3162877057574722, -7.029649236365207, 3.8929561722422785],
+    [2.549087657015263, -9.709192388712554, 4.321301222699535],
+    [5.727104645296058, -11.951317649389232, 4.693661195925588],
+    [8.430757859268114, -13.89180021339159, 5.027836179102582],
+    [10.826028664421022, -15.63883469398393, 5.338638253666907],
+    [13.075974431454712, -17.301522015255404, 5.641686640656425],
+    [15.34679353924759, -18.993126154503866, 5.953730025677148],
+    [17.798177515552382, -20.823625606199325, 6.291442708661998],
+    [20.600767634296744, -22.91104758276288, 6.67306552026702],
+    [23.972648839749276, -25.406028949835765, 7.122140165131378],
+    [28.238363269197784, -28.531648567398697, 7.673714153194863],
+    [33.94277234712638, -32.66006104712433, 8.386438196690639],
+    [42.11306629667544, -38.48817532750943, 9.37053233571433],
+    [54.976828834024786, -47.51744028966446, 10.864767689664214],
+    [78.45175407850041, -63.713139832999, 13.505149719906608],
+    [-15.162099516340797, 2.1999072790333356, 2.84945891216535],
+    [-8.904840325233751, -2.073407040978918, 3.494352060866712],
+    [-3.989888648325904, -5.4991626344151205, 4.036521511077922],
+    [0.018356939998497662, -8.349498956494596, 4.508523122352873],
+    [3.3967120064779652, -10.800612983961551, 4.93253482821554],
+    [6.335987551202907, -12.976295240871206, 5.32489725271096],
+    [8.983863911029646, -14.974523210631029, 5.699206650715285],
+    [11.48341613702586, -16.892728046523057, 6.069668848983202],
+    [14.015659842868905, -18.856758840595752, 6.455275404527894],
+    [16.80684762019664, -21.025442013672865, 6.880511442969589],
+    [20.138326741828543, -23.59657912173081, 7.37633755978976],
+    [24.419910390962922, -26.8566567422586, 7.987864616400938],
+    [30.364961155412896, -31.299535897555923, 8.792859326258187],
+    [39.440424901410054, -37.929622371053334, 9.949439669627909],
+    [55.33277247544726, -49.242845874421796, 11.851784968175679],
+    [90.77741789064025, -73.77049175009338, 15.860187547052844],
+    [-33.59189880621721, 14.45142803787781, 1.622559216318903],
+    [-23.975164964107986, 7.929806330698019, 2.5547196829710885],
+    [-16.71095314432292, 2.878158941431264, 3.325909091473264],
+    [-10.953359729694133, -1.223534490071473, 3.9907351682800947],
+    [-6.206891443745958, -4.6886312910192265, 4.585531856263638],
+    [-2.1574064968642026, -7.720722702961593, 5.135814149275306],
+    [1.4102927789538378, -10.463048041137673, 5.6609788990268735],
+    [4.657956250318345, -13.026807472998843, 6.177400224317473],
+    [7.725171219904323, -15.51158830297675, 6.701008679860007],
+    [10.764685427833877, -18.029779291690005, 7.250812210822519],
+    [14.022445188858109, -20.763408446545327, 7.857524497187971],
+    [17.983658209352438, -24.06781074571636, 8.579608074499463],
+    [23.566040512460695, -28.605904071997326, 9.524608459887766],
+    [32.831192541941036, -35.83675105495259, 10.925382698870223],
+    [52.24398570369356, -50.22603658278156, 13.484889341299018],
+    [119.53449085280579, -97.16369575584135, 21.21244978822617],
+    [-103.71621714829001, 60.03598934651876, -2.6942061235025196],
+    [-75.34040251699926, 42.22477864059533, -0.9068344442663269],
+    [-58.111860948248605, 30.927044504534635, 0.45514242501657104],
+    [-45.95744633134353, 22.588757212432995, 1.6261262676931818],
+    [-36.84438134473435, 16.10245234791859, 2.6462634569933443],
+    [-29.69916317983445, 10.830787611092342, 3.564891154189223],
+    [-23.878275015540503, 6.361009506663969, 4.428629255896376],
+    [-18.9766301900653, 2.416309327487008, 5.277492970149547],
+    [-14.72266364310159, -1.2059866362845149, 6.149926408869327],
+    [-10.921737360595353, -4.673506188550274, 7.089317970575359],
+    [-7.422702044165505, -8.149593182826408, 8.153535170103806],
+    [-4.096353283778154, -11.82804854906825, 9.432795930614725],
+    [-0.8305847475017627, -15.984787560630826, 11.090237217707452],
+    [2.38645702157528, -21.052547025987728, 13.475307371540364],
+    [4.388958903639684, -27.257807468619262, 17.517159934630534],
+    [1.4528400214517795e-8, -1.0622236606430416e-8, 22.737998981754345],
+    [39.96297108339237, -49.023812152728595, 10.287812855246202],
+    [61.826097092357145, -61.08328008512748, 11.921417919422632],
+    [66.24415764606105, -62.963725864249945, 12.12435710521696],
+    [69.0317499036682, -64.05186124638101, 12.232853933559605],
+    [71.42531260607646, -65.06048030129074, 12.346465797943544],
+    [73.83162541391984, -66.21382979377329, 12.495521861853193],
+    [76.44698095955994, -67.61745342663022, 12.694101606080133],
+    [79.40707824024186, -69.34742473829537, 12.952806931860923],
+    [82.84328239855515, -71.48437178050088, 13.283710491346271],
+    [86.90903273245931, -74.12994074431239, 13.702826832702572],
+    [91.80179312977108, -77.42147786440763, 14.232440860848172],
+    [97.793965183888, -81.55397625655209, 14.904718842958157],
+    [105.28495967855753, -86.81812915882476, 15.76807195394902],
+    [114.89700897249641, -93.67123091670285, 16.89909753281417],
+    [127.66974657367352, -102.88198612241865, 18.427050510289025],
+    [145.5049267003894, -115.86261772026612, 20.59008963211559],
+    [33.986536405253915, -37.56804504569798, 7.902283590437566],
+    [41.84451256434294, -42.12618824718324, 8.546363998791971],
+    [45.74038151902407, -44.32157326805647, 8.856208302709424],
+    [48.68828780698931, -45.98848933198748, 9.09531442290031],
+    [51.315011400131844, -47.50890422705894, 9.31876811633356],
+    [53.87726614682737, -49.03955014084689, 9.549296791089903],
+    [56.52132096133468, -50.67089533898557, 9.800223114676891],
+    [59.35545077915577, -52.471796568173815, 10.081957053805503],
+    [62.48070213043027, -54.50886364122662, 10.404868666366015],
+    [66.00829190082281, -56.85781326296778, 10.781031097175148],
+    [70.07395933383685, -59.613262550227105, 11.225784796404415],
+    [74.85551766011147, -62.90111659926302, 11.759769414491105],
+    [80.5995939693935, -66.89777902777043, 12.412115457892188],
+    [87.66693093288579, -71.86298672636116, 13.22593381951657],
+    [96.615558595112, -78.20038821695051, 14.26848020180458],
+    [108.36703538090975, -86.57901623518927, 15.651588160203456],
+    [27.355480684567635, -30.410653115842884, 6.620631422495375],
+    [32.775214849417836, -33.67561835329669, 7.094952972623954],
+    [36.13055051064967, -35.694426011614304, 7.393026856245256],
+    [38.8400633169272, -37.34047322199521, 7.640373265828972],
+    [41.31138424573907, -38.86657104616347, 7.873639895366956],
+    [43.73096371396692, -40.389417259304835, 8.109936247072596],
+    [46.21319180543626, -41.98180971798052, 8.360133866671578],
+    [48.84666391759264, -43.701482461567466, 8.633061326009287],
+    [51.71499524154418, -45.60433013344639, 8.93746753201093],
+    [54.9096724814381, -47.75284454726172, 9.283317338809656],
+    [58.54118430424777, -50.223693331583284, 9.682998676418872],
+    [62.75231883902055, -53.11701396258664, 10.152839919329793],
+    [67.73764864921051, -56.5702205203536, 10.71539201129571],
+    [73.77535167652127, -60.78070791168486, 11.403204473517658],
+    [81.28320422161356, -66.04596209547547, 12.26552091145976],
+    [90.92469938224545, -72.8397801470328, 13.381035462578176],
+    [22.82274258858555, -25.886316573788054, 5.860338583606625],
+    [27.190087551587308, -28.5835744196051, 6.259016388523737],
+    [30.185181339836355, -30.44518024093985, 6.539777772678586],
+    [32.689050417277514, -32.01814499877482, 6.781143351492543],
+    [35.006523995587706, -33.493546903336984, 7.010842215473695],
+    [37.28781002862917, -34.96661338676618, 7.242883827374173],
+    [39.62962631767869, -36.49969789062556, 7.486620435128168],
+    [42.10947892657048, -38.14381282044233, 7.749877126887453],
+    [44.80181899667195, -39.94896141554089, 8.040485609546202],
+    [47.78863255543488, -41.97111026393777, 8.36735484747498],
+    [51.169102056807056, -44.27874816960275, 8.741513591439563],
+    [55.07129973827817, -46.96099161623726, 9.177428838474507],
+    [59.66917606454655, -50.13948831005738, 9.694965269891581],
+    [65.20994874092004, -53.98771180332312, 10.322580766948716],
+    [72.06157832598089, -58.76450989376308, 11.102903305492458],
+    [80.801138677992, -64.87668046362533, 12.103160812789826],
+    [19.388403960901105, -22.642874114516914, 5.351247281573439],
+    [23.19095052078673, -25.034836281123944, 5.709201536724473],
+    [25.949883062197962, -26.786486522649703, 5.977085072585819],
+    [28.303046774240475, -28.296600011865596, 6.212027608620532],
+    [30.50124604774048, -29.723679755372505, 6.437024532721223],
+    [32.674980435064995, -31.15119698597373, 6.664369065553956],
+    [34.911213006232224, -32.635674589064344, 6.902551745230984],
+    [37.28149501920824, -34.22447200452189, 7.158842747887437],
+    [39.85579632733887, -35.96467987316472, 7.440612394889891],
+    [42.71201603831106, -37.909397848594175, 7.756295385074076],
+    [45.945094232698786, -40.123904958301864, 8.116370316121097],
+    [49.67833587224608, -42.6934558143923, 8.534624208685548],
+    [54.08000054797167, -45.734797517770694, 9.030041714349315],
+    [59.3901178128412, -49.41486111427872, 9.629889622058467],
+    [65.96705023637423, -53.98329283059941, 10.375105447784717],
+    [74.37435113337297, -59.833232063514096, 11.330392555920715],
+    [16.55070304239098, -20.10065781383566, 4.983261758449227],
+    [20.03201342372752, -22.32308065973689, 5.3190720342330176],
+    [22.641403376972068, -24.006748502363777, 5.579362954636544],
+    [24.890887171858868, -25.473683338862756, 5.8101432907411485],
+    [27.00279522749365, -26.865366460897175, 6.031915834453792],
+    [29.097685864642713, -28.25945764454415, 6.25611638880315],
+    [31.257829226317295, -29.709754987161972, 6.490827272821409],
+    [33.55224350269573, -31.26212073437823, 6.74308627981545],
+    [36.04950456954279, -32.962761899129255, 7.020115939035003],
+    [38.82692038875083, -34.8642995066228, 7.330255966335963],
+    [41.979718329664706, -37.031977119363916, 7.683947843270911],
+    [45.63282848828787, -39.551727326252944, 8.095039943610871],
+    [49.95846536441418, -42.542282758651744, 8.582767994645788],
+    [55.20493167033166, -46.17507575031281, 9.175028304650043],
+    [61.74732875677984, -50.70932998984357, 9.914172084283576],
+    [70.18370560869813, -56.55867291913114, 10.868036621398511],
+    [14.027188895464027, -17.959968304543146, 4.702040160057479],
+    [17.33932170499865, -20.10108030609185, 5.028118737134788],
+    [19.866942453063103, -21.754111670049962, 5.286046209102977],
+    [22.052877322577142, -23.198987735486664, 5.515665628550977],
+    [24.10710811771161, -24.5701451152068, 5.736420350509187],
+    [26.147283687012298, -25.9438294630557, 5.959525367516254],
+    [28.254851920145498, -27.373568730732206, 6.193015213620419],
+    [30.498973309747537, -28.905465394157922, 6.443956281699675],
+    [32.949235302977506, -30.586453348997512, 6.719671766340168],
+    [35.68507357427277, -32.470541993789055, 7.028700734497402],
+    [38.80557827947679, -34.62543058904776, 7.3818457691964685],
+    [42.442491946582926, -37.14136326909092, 7.7936021776429705],
+    [46.78008942828541, -40.14473362555535, 8.284375987734185],
+    [52.08846946308747, -43.82091274884553, 8.88422726175475],
+    [58.78357513203996, -48.4554498161774, 9.639653195177175],
+    [67.54426917149257, -54.514504877309975, 10.626872697427153],
+    [11.626248135784055, -16.03514939062165, 4.476243191811372],
+    [14.883481851266918, -18.16424466734184, 4.802584767734448],
+    [17.39121944951214, -19.823993667123965, 5.063695744689516],
+    [19.551666790479764, -21.26963482901153, 5.295701710354563],
+    [21.574547921082857, -22.636161457523283, 5.51806609675156],
+    [23.580881379344447, -24.002427993280943, 5.742362353538221],
+    [25.65493623677433, -25.424063912928315, 5.976924364026754],
+    [27.868526513812732, -26.949079872914552, 6.229096532989515],
+    [30.29446771163315, -28.626642031814058, 6.50653321642672],
+    [33.01682277711312, -30.51387299558095, 6.818241978304304],
+    [36.14191137652624, -32.6832651755309, 7.175755605385552],
+    [39.813384724223816, -35.23290868316814, 7.594778439089006],
+    [44.23597482138837, -38.30265416779001, 8.097813253351518],
+    [49.71645521655267, -42.1020222209367, 8.718723634417696],
+    [56.7399865459528, -46.96225409746959, 9.51127905658911],
+    [66.1251876571626, -53.44209527803451, 10.56658379992708],
+    [9.186315921879775, -14.185913909129622, 4.284722191980493],
+    [12.48869064059278, -16.366294466277026, 4.620632493676053],
+    [15.043455663758282, -18.07586041684354, 4.891531632710548],
+    [17.22088071434325, -19.55000614734806, 5.130433905372339],
+    [19.240151525314054, -20.93045129117387, 5.3576742823180545],
+    [21.23253120220354, -22.303148478621928, 5.585823959072976],
+    [23.289364974133456, -23.728653701470094, 5.82392648789072],
+    [25.48798316288011, -25.258820870004055, 6.079891243202688],
+    [27.9068329790517, -26.946702850658323, 6.361954629364475],
+    [30.637251795762474, -28.854376413030447, 6.67987697734526],
+    [33.79641771843348, -31.061650785867098, 7.046315368148892],
+    [37.545638879782324, -33.678434660898795, 7.478807849353543],
+    [42.12015942424263, -36.8649282714254, 8.003043951909154],
+    [47.88250980783371, -40.86778223499047, 8.658756218498848],
+    [55.426370184035974, -46.090503009600035, 9.511244930346257],
+    [65.79930900022619, -53.24448372885819, 10.676202951690364],
+    [6.53468396713998, -12.278050457458683, 4.109984012997793],
+    [9.981262872143063, -14.574755475515287, 4.465134556062855],
+    [12.66512792580603, -16.38923840221741, 4.754373601400664],
+    [14.916370393101579, -17.93091085442854, 5.006603972586471],
+    [16.96758310396763, -19.350592520588417, 5.243293594496534],
+    [18.968602715745046, -20.746833528691706, 5.478779428959972],
+    [21.023816270286346, -22.189127708182813, 5.723391665792982],
+    [23.22001810493186, -23.735874147983278, 5.986001781771903],
+    [25.644344986078213, -25.44614423985384, 6.275748586410011],
+    [28.398565265598755, -27.389191088762015, 6.603485380491657],
+    [31.61501849707412, -29.655158747411555, 6.983460806351493],
+    [35.47975919051049, -32.3706919731356, 7.435813134557751],
+    [40.271734531098254, -35.725381604868794, 7.990836632827815],
+    [46.43618805876945, -40.0212994479649, 8.697022777935416],
+    [54.735770130603036, -45.77493793797653, 9.637693981242302],
+    [66.5986814780984, -53.95206718784765, 10.969512288845488],
+    [3.4370371062016654, -10.143988406099835, 3.9327364061115504],
+    [7.144970805578473, -12.63608882082971, 4.318893147526122],
+    [10.061301850990278, -14.626768701312953, 4.637589403515088],
+    [12.473335327271846, -16.29708946019925, 4.913168016102094],
+    [14.613116339495416, -17.79733662530272, 5.166695913585335],
+    [16.656008457937006, -19.24322649277067, 5.414864857542894],
+    [18.728573920662793, -20.719373994129345, 5.670168646079641],
+    [20.933521079081764, -22.29517193389773, 5.943089219690123],
+    [23.3712827545753, -24.038957050114668, 6.244152340674891],
+    [26.158285515039474, -26.030185107488354, 6.585765342529126],
+    [29.4474987302866, -28.373237312013824, 6.984369943511293],
+    [33.45894665960761, -31.21797023125806, 7.463708310099064],
+    [38.533569271020255, -34.7959815879115, 8.060645306676182],
+    [45.23994663367716, -39.49239444915288, 8.836770342082927],
+    [54.61032623825815, -46.0044590746844, 9.904181025253926],
+    [68.73888965511303, -55.74339472071881, 11.491191918933948],
+    [-0.49451740346409995, -7.517679242505437, 3.7239499792549307],
+    [3.6483269243119305, -10.32385919722077, 4.158517346113799],
+    [6.9329057152569735, -12.586147823400921, 4.521541458081297],
+    [9.637643348353455, -14.47915109450121, 4.83579561377207],
+    [11.971387043309049, -16.137011410541053, 5.119536459459068],
+    [14.121018098170694, -17.682926129764212, 5.39024663786537],
+    [16.244910027521026, -19.22316092541335, 5.663460454882002],
+    [18.473230522809523, -20.84610110061825, 5.952423346884662],
+    [20.92807637033413, -22.635288834412645, 6.269930126493095],
+    [23.747140478574238, -24.68520373099709, 6.630677073749463],
+    [27.111473615304334, -27.119939270330583, 7.054145960100179],
+    [31.28742310716496, -30.121457668104537, 7.56906151106597],
+    [36.7040421185032, -33.98166355263663, 8.22169912710172],
+    [44.117395478380466, -39.21270115160007, 9.093611705344706],
+    [55.00950598079302, -46.814260427082246, 10.344882040655628],
+    [72.74089374343173, -59.044943186165135, 12.339837251003091],
+    [-6.024194010995833, -3.88128275852438, 3.4268323623992663],
+    [-1.1395694220878703, -7.211400770085688, 3.939629140381847],
+    [2.7380835795393517, -9.90383226266144, 4.371189340380276],
+    [5.926783111080971, -12.15781692551105, 4.746650651856239],
+    [8.640645469605015, -14.109979771111808, 5.0839597834138575],
+    [11.047354699229675, -15.869775236271577, 5.398138621587784],
+    [13.312482031681785, -17.548154193621563, 5.705111635148169],
+    [15.603665828499564, -19.25952091230853, 6.021837186451215],
+    [18.081446604587, -21.114599399517655, 6.365146470874985],
+    [20.917889675154985, -23.232583230962785, 6.753510076942397],
+    [24.33376292025561, -25.766194586053025, 7.2108563869365],
+    [28.658559496968177, -28.94225256938342, 7.7729047805124445],
+    [34.44672641146097, -33.14000782628802, 8.499556445162295],
+    [42.7460609372199, -39.0713604119917, 9.503639218499291],
+    [55.836306904347836, -48.27509399152058, 11.03036287031931],
+    [79.8063347787796, -64.83713160511459, 13.736426898757337],
+    [-14.994675780595948, 2.014894762175628, 2.9006860953741236],
+    [-8.705722363492699, -2.287070169525982, 3.5517972680185332],
+    [-3.76552593153784, -5.737216146747629, 4.099655812979491],
+    [0.2645763876498923, -8.609900674022384, 4.577169532958671],
+    [3.6631986629279507, -11.082691096046949, 5.006753389345256],
+    [6.622464308279262, -13.28043014806615, 5.404944735208168],
+    [9.291542324750601, -15.30229492454575, 5.785565660365076],
+    [11.816650323419118, -17.248128577481136, 6.1632445655775046],
+    [14.383441440302859, -19.24732418165671, 6.557588833741164],
+    [17.221581345586653, -21.461504205493327, 6.993623993884729],
+    [20.616455228091866, -24.091822774409717, 7.502977357793162],
+    [24.98560364394628, -27.430898032526077, 8.131918068769117],
+    [31.058529827372148, -31.98496151435937, 8.960460550042493],
+    [40.340356732126644, -38.786857229611336, 10.15175113773905],
+    [56.63095913378096, -50.41478984424756, 12.114240685109632],
+    [93.17527354484791, -75.76040658748815, 16.270020916731557],
+    [-33.499510142930724, 14.298114390845315, 1.678256073883712],
+    [-23.7971228714666, 7.7056481621121256, 2.624105284320357],
+    [-16.469103231574593, 2.597345018971306, 3.4073597026779985],
+    [-10.65823637734114, -1.554748895772564, 4.083769556749369],
+    [-5.86318260301502, -5.06833785541238, 4.690376351310632],
+    [-1.76622917568042, -8.149892632003812, 5.253235103948924],
+    [1.8504592201978325, -10.944998045316053, 5.792247201087524],
+    [5.151093463933493, -13.567233827558178, 6.324350028326051],
+    [8.278320061149035, -16.119213249401927, 6.866206114141519],
+    [11.390770725561145, -18.718716108005285, 7.438021820400225],
+    [14.75003137137504, -21.560717706622725, 8.07302897363073],
+    [18.865243124108954, -25.02048392218266, 8.833605612040156],
+    [24.688700925674222, -29.790235863588865, 9.833239851099464],
+    [34.36866905436014, -37.398879776735555, 11.318165066010204],
+    [54.685851798113895, -52.55253344532333, 14.034663503300592],
+    [125.97094441754399, -102.50453996671865, 22.313142532616926],
+    [-105.03175475301843, 60.77044620590826, -2.712280165573856],
+    [-75.95824922903722, 42.51195216872067, -0.8811969427787588],
+    [-58.36523584479525, 30.948809986165877, 0.5198171923744458],
+    [-45.95920199070247, 22.405847436251623, 1.7287820730625958],
+    [-36.65442484630575, 15.749909027579344, 2.7849792892464476],
+    [-29.339854897425514, 10.316386346088281, 3.7417672496284973],
+    [-23.34961066602622, 5.673945925404654, 4.649376378314738],
+    [-18.2603184230674, 1.5281056096290124, 5.552073046562672],
+    [-13.779576721659168, -2.346457468243351, 6.494465156394138],
+    [-9.682074097213809, -6.1529509209748525, 7.5301948561813745],
+    [-5.762614660965092, -10.119343199502904, 8.73622036099833],
+    [-1.7814255356714104, -14.573699362886085, 10.24291328029281],
+    [2.6432703386304017, -20.122960430089325, 12.313269320671184],
+    [8.372934818217828, -28.255333048051106, 15.618287817221818],
+    [18.632929189915302, -44.75733175033313, 22.809128349773843],
+    [4447.8789243735955, -2217.8734243757867, 4605.79519831759],
+];
+
+/// Mallett-Yuksel red, green and blue basis spectra for sRGB, 380 nm to 780 nm in 5 nm steps.
+pub(crate) const SRGB_MALLETT_YUKSEL: [[f64; 81]; 3] = [
+    [
+        0.02520012467817881, 0.025197816420017043, 0.02519160527907231, 0.02517776304562395, 0.025147051399117413, 0.025078690406166174,
+        0.02494804616483994, 0.024700846543700035, 0.024255945202303526, 0.023505201020420526, 0.022401063784752033, 0.02106237123782881,
+        0.01982631651161305, 0.019340250659844217, 0.020549035832936963, 0.02477881074583796, 0.029349072981615907, 0.023656368930568827,
+        0.01268317556376944, 0.002635949383193656, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.03513835437664264, 0.1111583175736981, 0.21736809204816607, 0.34272476600904594,
+        0.4760087694052508, 0.6073551616364964, 0.7281581540032888, 0.8314170887231268, 0.9119305498882829, 0.9665829471262579,
+        0.995463732343605, 1.0, 1.0, 1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+        1.0, 1.0, 1.0,
+    ],
+    [
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.008417974777240038, 0.05003691510712596,
+        0.11844428930141127, 0.20485552890903144, 0.3005495807552252, 0.40262112976951625, 0.5074747347473176, 0.6110663913363601,
+        0.7097057883028917, 0.7997573615278231, 0.8774236228515998, 0.9388896167769213, 0.9806539453232568, 1.0,
+        1.0, 1.0, 1.0, 1.0, 1.0, 1.0,
+        0.9966458935298872, 0.9760291288266798, 0.9236836536159095, 0.8400684486826037, 0.7338586821140858, 0.6139992570278251,
+        0.4894940190105291, 0.3682323708002285, 0.2571497158592712, 0.16185755238366833, 0.08647654005467856, 0.03341705287374208,
+        0.004536267656395032, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+    ],
+    [
+        0.9747998753218212, 0.9748021835799829, 0.9748083947209277, 0.9748222369543761, 0.9748529486008826, 0.9749213095938338,
+        0.9750519538351601, 0.9752991534562999, 0.9757440547976964, 0.9764947989795795, 0.977598936215248, 0.9789376287621712,
+        0.980173683488387, 0.9806597493401558, 0.979450964167063, 0.975221189254162, 0.962232952241144, 0.9263067159623052,
+        0.8688725351348193, 0.7925085217077749, 0.6994504192447748, 0.5973788702304837, 0.49252526525268236, 0.3889336086636399,
+        0.29029421169710834, 0.20024263847217694, 0.1225763771484002, 0.06111038322307871, 0.01934605467674322, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0033541064701128054, 0.023970871173320196, 0.04117799200744787, 0.04877323374369824, 0.048773225837748124, 0.043275976963128904,
+        0.03449721158422009, 0.024412467563275086, 0.01469213013744003, 0.006725358893204858, 0.0015929100570385157, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 0.0,
+    ],
+];
@@ -0,0 +1,75 @@
+use crate::{ Rgb, FromRgb, ToRgb, Xyz, approx };
+use crate::illuminant::D65_WHITE as WHITE;
+use crate::chromaticity::uv_to_xy;
+
+/// A CIE 1964 U*V*W* color.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Uvw {
+    pub u: f64,
+    pub v: f64,
+    pub w: f64,
+}
+
+impl Uvw {
+    /// Create a new CIE 1964 U*V*W* color.
+    /// 
+    /// `u`: U* chromaticness coordinate.
+    /// 
+    /// `v`: V* chromaticness coordinate.
+    /// 
+    /// `w`: W* lightness index (-17 to 99).
+    #[inline]
+    pub fn new(u: f64, v: f64, w: f64) -> Self {
+        Self { u, v, w }
+    }
+
+    /// Convert from `xyz` relative to the reference `white`.
+    pub fn from_xyz(xyz: &Xyz, white: &Xyz) -> Self {
+        let (u0, v0) = uv(white);
+        let (u, v) = uv(xyz);
+        let w = 25.0 * xyz.y.max(0.0).cbrt() - 17.0;
+        Self::new(13.0 * w * (u - u0), 13.0 * w * (v - v0), w)
+    }
+
+    /// Convert into an `Xyz` color relative to the reference `white`.
+    pub fn to_xyz(&self, white: &Xyz) -> Xyz {
+        let y = ((self.w + 17.0) / 25.0).powf(3.0);
+        if self.w == 0.0 {
+            return Xyz::new(white.x / white.y * y, y, white.z / white.y * y);
+        }
+        let (u0, v0) = uv(white);
+        let (x, y2) = uv_to_xy(
+            self.u / (13.0 * self.w) + u0,
+            self.v / (13.0 * self.w) + v0
+        );
+        Xyz::new(x * y / y2, y, (1.0 - x - y2) * y / y2)
+    }
+}
+
+fn uv(xyz: &Xyz) -> (f64, f64) {
+    let temp = xyz.x + 15.0 * xyz.y + 3.0 * xyz.z;
+    match temp == 0.0 {
+        true => (0.0, 0.0),
+        false => (4.0 * xyz.x / temp, 6.0 * xyz.y / temp),
+    }
+}
+
+impl PartialEq for Uvw {
+    fn eq(&self, other: &Self) -> bool {
+        approx(self.u, other.u) &&
+        approx(self.v, other.v) &&
+        approx(self.w, other.w)
+    }
+}
+
+impl FromRgb for Uvw {
+    fn from_rgb(rgb: &Rgb) -> Self {
+        Self::from_xyz(&Xyz::from_rgb(rgb), &WHITE)
+    }
+}
+
+impl ToRgb for Uvw {
+    fn to_rgb(&self) -> Rgb {
+        self.to_xyz(&WHITE).to_rgb()
+    }
+}
@@ -24,7 +24,7 @@ impl PartialEq for Xyz {
     }
 }
 
-fn srgb_to_linear(val: f64) -> f64 {
+pub(crate) fn srgb_to_linear(val: f64) -> f64 {
     if val <= 0.04045 {
         val / 12.92
     } else {
@@ -32,6 +32,14 @@ fn srgb_to_linear(val: f64) -> f64 {
     }
 }
 
+pub(crate) fn linear_to_srgb(val: f64) -> f64 {
+    if val <= 0.0031308 {
+        12.92 * val
+    } else {
+        1.055 * val.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 impl FromRgb for Xyz {
     fn from_rgb(rgb: &Rgb) -> Self {
         let r = srgb_to_linear(rgb.r / 255.0);
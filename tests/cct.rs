@@ -0,0 +1,74 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance, "{} != {} (tolerance {})", a, b, tolerance);
+}
+
+#[test]
+fn test_cct_standard_illuminants() {
+    // CIE 15 tabulated chromaticities and correlated color temperatures.
+    let a = Yxy::new(100.0, 0.44757, 0.40745);
+    let d50 = Yxy::new(100.0, 0.34567, 0.35850);
+    let d65 = Yxy::new(100.0, 0.31271, 0.32902);
+    let f11 = Yxy::new(100.0, 0.38052, 0.37713);
+    assert_near(a.cct(CctMethod::Ohno), 2856.0, 2.0);
+    assert_near(d50.cct(CctMethod::Ohno), 5003.0, 2.0);
+    assert_near(d65.cct(CctMethod::Ohno), 6504.0, 2.0);
+    assert_near(f11.cct(CctMethod::Ohno), 4000.0, 2.0);
+    assert_near(a.duv(), 0.0, 0.0002);
+    assert_near(d50.duv(), 0.0032, 0.0002);
+    assert_near(d65.duv(), 0.0032, 0.0002);
+}
+
+#[test]
+fn test_cct_approximations() {
+    let d65 = Xyz::new(95.047, 100.0, 108.883);
+    assert_near(d65.cct(CctMethod::McCamy), 6504.0, 5.0);
+    assert_near(d65.cct(CctMethod::HernandezAndres), 6504.0, 15.0);
+    let a = Yxy::new(100.0, 0.44757, 0.40745);
+    assert_near(a.cct(CctMethod::McCamy), 2856.0, 5.0);
+}
+
+#[test]
+fn test_temperature_round_trip() {
+    for &t in &[1000.0, 1500.0, 2000.0, 2856.0, 4000.0, 6500.0, 10000.0, 25000.0] {
+        for &duv in &[-0.03, -0.01, -0.0025, 0.0, 0.0015, 0.0025, 0.01, 0.03] {
+            let yxy = Yxy::from_temperature(t, duv, Locus::Planckian);
+            assert_near(yxy.cct(CctMethod::Ohno), t, t * 1e-5);
+            assert_near(yxy.duv(), duv, 1e-6);
+        }
+    }
+}
+
+#[test]
+fn test_temperature_standard_illuminants() {
+    let a = Yxy::from_temperature(2856.0, 0.0, Locus::Planckian);
+    assert_near(a.x, 0.44757, 1e-4);
+    assert_near(a.y2, 0.40745, 1e-4);
+    let d50 = Yxy::from_temperature(5003.0, 0.0, Locus::Daylight);
+    assert_near(d50.x, 0.34567, 1e-4);
+    assert_near(d50.y2, 0.35850, 1e-4);
+    let d65 = Xyz::from_temperature(6504.0, 0.0, Locus::Daylight);
+    assert_near(d65.x / (d65.x + d65.y + d65.z), 0.31271, 1e-4);
+    assert_near(d65.y / (d65.x + d65.y + d65.z), 0.32902, 1e-4);
+    assert_near(d65.y, 100.0, 1e-9);
+    assert_near(d65.duv(), 0.0032, 0.0002);
+
+    let low = Yxy::from_temperature(3000.0, 0.01, Locus::Daylight);
+    let edge = Yxy::from_temperature(4000.0, 0.01, Locus::Daylight);
+    assert_eq!((low.x, low.y2), (edge.x, edge.y2));
+    let high = Yxy::from_temperature(40000.0, 0.0, Locus::Daylight);
+    let edge = Yxy::from_temperature(25000.0, 0.0, Locus::Daylight);
+    assert_eq!((high.x, high.y2), (edge.x, edge.y2));
+}
+
+#[test]
+fn test_temperature_rgb() {
+    let rgb = Rgb::from_temperature(6504.0, 0.0, Locus::Daylight);
+    assert_near(rgb.r, 255.0, 0.5);
+    assert_near(rgb.g, 255.0, 0.5);
+    assert_near(rgb.b, 255.0, 0.5);
+    let rgb = Rgb::from_temperature(3000.0, 0.0, Locus::Planckian);
+    assert_near(rgb.r, 255.0, 1e-6);
+    assert!(rgb.g < rgb.r && rgb.b < rgb.g);
+}
@@ -0,0 +1,130 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} is not within {} of {}", a, tolerance, b);
+}
+
+const MEASUREMENT: &str = r#"CGATS.17
+ORIGINATOR	"Spectrophotometer 2.1"   # exported by the instrument
+CREATED	"2024-03-01"
+KEYWORD	"SAMPLE_BACKING_NOTE"
+SAMPLE_BACKING_NOTE	"white backing"
+NUMBER_OF_FIELDS 11
+BEGIN_DATA_FORMAT
+SAMPLE_ID	SAMPLE_NAME	CMYK_C	CMYK_M	CMYK_Y	CMYK_K	LAB_L	LAB_A	LAB_B	nm400	nm410
+END_DATA_FORMAT
+NUMBER_OF_SETS 2
+BEGIN_DATA
+1	"Paper white"	0	0	0	0	95.1	0.5	-2.3	0.81	0.85
+2	Cyan	100	0	0	0	55.2	-37.1	-50.4	0.42	0.48
+END_DATA
+"#;
+
+#[test]
+fn test_parse() {
+    let cgats = Cgats::parse(MEASUREMENT).unwrap();
+    assert_eq!(cgats.identifier, "CGATS.17");
+    assert_eq!(cgats.keyword("ORIGINATOR"), Some("Spectrophotometer 2.1"));
+    assert_eq!(cgats.keyword("SAMPLE_BACKING_NOTE"), Some("white backing"));
+    assert_eq!(cgats.rows.len(), 2);
+    assert_eq!(cgats.sample_id(1), Some("2"));
+    assert_eq!(cgats.rows[0][1], "Paper white");
+
+    let lab = cgats.lab(1).unwrap();
+    assert_near(lab.l, 55.2, 1e-12);
+    assert_near(lab.b, -50.4, 1e-12);
+    let cmyk = cgats.cmyk(1).unwrap();
+    assert_near(cmyk.c, 1.0, 1e-12);
+    assert_near(cmyk.k, 0.0, 1e-12);
+    assert!(cgats.xyz(0).is_none());
+
+    let spectrum = cgats.spectrum(0).unwrap();
+    assert_eq!(spectrum.wavelengths, vec![400.0, 410.0]);
+    assert_eq!(spectrum.values, vec![0.81, 0.85]);
+}
+
+#[test]
+fn test_round_trip() {
+    let mut cgats = Cgats::new("CGATS.17");
+    cgats.set_keyword("ORIGINATOR", "QC station");
+    cgats.set_keyword("JOB", "Brochure 12");
+    let spectrum = Spectrum::new(vec![400.0, 410.0, 420.0], vec![0.2, 0.25, 0.3]);
+    let lab = Lab::new(52.5, 10.25, -4.0);
+    let xyz = Xyz::from_color(&lab);
+    cgats.push("A1", &[&lab, &xyz, &spectrum]).unwrap();
+    cgats.set_keyword("DESCRIPTOR", "Proof on 8.5\" x 11\" \"letter\"");
+    cgats.push("A2", &[&Lab::new(90.0, 0.0, 0.0), &xyz, &spectrum]).unwrap();
+    assert_eq!(cgats.push("A3", &[&lab]), Err(CgatsError::FieldMismatch));
+    cgats.push("#3", &[&lab, &xyz, &spectrum]).unwrap();
+    cgats.push("\"4\"", &[&lab, &xyz, &spectrum]).unwrap();
+
+    let text = cgats.to_string();
+    assert!(text.contains("KEYWORD \"JOB\""));
+    assert!(!text.contains("KEYWORD \"ORIGINATOR\""));
+    let back = Cgats::parse(&text).unwrap();
+    assert_eq!(back, Cgats { keywords: back.keywords.clone(), ..cgats.clone() });
+    assert_eq!(back.keyword("JOB"), Some("Brochure 12"));
+    assert_eq!(back.keyword("DESCRIPTOR"), Some("Proof on 8.5\" x 11\" \"letter\""));
+    assert_eq!(back.keyword("NUMBER_OF_SETS"), Some("4"));
+    assert_eq!(back.sample_id(0), Some("A1"));
+    assert_eq!(back.lab(0).unwrap(), lab);
+    assert_near(back.xyz(0).unwrap().y, xyz.y, 1e-4);
+    assert_eq!(back.spectrum(1).unwrap().values, spectrum.values);
+    assert_eq!(back.sample_id(2), Some("#3"));
+    assert_eq!(back.sample_id(3), Some("\"4\""));
+}
+
+#[test]
+fn test_multiple_tables() {
+    let text = "CGATS.17\nORIGINATOR one\nNUMBER_OF_SETS 2\nBEGIN_DATA_FORMAT\nSAMPLE_ID LAB_L\nEND_DATA_FORMAT\n\
+        BEGIN_DATA\n1 50\n2 60\nEND_DATA\n\
+        CGATS.17\nORIGINATOR two\nNUMBER_OF_SETS 1\nBEGIN_DATA_FORMAT\nSAMPLE_ID LAB_L LAB_A\nEND_DATA_FORMAT\n\
+        BEGIN_DATA\n3 70 5\nEND_DATA\n";
+    let cgats = Cgats::parse(text).unwrap();
+    assert_eq!(cgats.keyword("ORIGINATOR"), Some("one"));
+    assert_eq!(cgats.fields, vec!["SAMPLE_ID", "LAB_L"]);
+    assert_eq!(cgats.rows, vec![vec!["1", "50"], vec!["2", "60"]]);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(Cgats::parse("\n# nothing\n"), Err(CgatsError::Empty));
+    assert_eq!(Cgats::parse("CGATS.17\nORIGINATOR \"open\n"), Err(CgatsError::UnterminatedString(2)));
+    assert_eq!(
+        Cgats::parse("CGATS.17\nBEGIN_DATA\n1\nEND_DATA\n"),
+        Err(CgatsError::MissingSection("BEGIN_DATA_FORMAT"))
+    );
+    let data = "CGATS.17\nBEGIN_DATA_FORMAT\nSAMPLE_ID LAB_L\nEND_DATA_FORMAT\nBEGIN_DATA\n1 50 2\n";
+    assert_eq!(Cgats::parse(data), Err(CgatsError::MissingSection("BEGIN_DATA")));
+    assert_eq!(
+        Cgats::parse(&format!("{}END_DATA\n", data)),
+        Err(CgatsError::RowLength { expected: 2, found: 3 })
+    );
+    let sets = "CGATS.17\nNUMBER_OF_SETS 3\nBEGIN_DATA_FORMAT\nSAMPLE_ID\nEND_DATA_FORMAT\nBEGIN_DATA\n1\n2\nEND_DATA\n";
+    assert_eq!(
+        Cgats::parse(sets),
+        Err(CgatsError::CountMismatch { keyword: "NUMBER_OF_SETS", declared: 3, found: 2 })
+    );
+}
+
+#[test]
+fn test_cmyk_model() {
+    let mut cgats = Cgats::new("IT8.7/4");
+    let levels = [0.0, 0.25, 0.5, 0.75, 1.0];
+    for &k in &[0.0, 0.5, 1.0] {
+        for &c in &levels {
+            for &m in &levels {
+                for &y in &levels {
+                    let cmyk = Cmyk::new(c, m, y, k);
+                    cgats.push(&format!("{}", cgats.rows.len() + 1), &[&cmyk, &Lab::from_color(&cmyk)]).unwrap();
+                }
+            }
+        }
+    }
+    let model = CmykModel::from_cgats(&Cgats::parse(&cgats.to_string()).unwrap()).unwrap();
+    let cmyk = Cmyk::new(0.5, 0.25, 0.75, 0.5);
+    let (expected, found) = (Lab::from_color(&cmyk), model.to_lab(&cmyk));
+    assert_near(found.l, expected.l, 1e-3);
+    assert_near(found.a, expected.a, 1e-3);
+    assert!(CmykModel::from_cgats(&Cgats::new("CGATS.17")).is_none());
+}
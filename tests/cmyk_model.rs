@@ -0,0 +1,149 @@
+use color_space::*;
+
+/// A synthetic press: the device-naive CMYK with dot gain.
+fn press(cmyk: &Cmyk) -> Lab {
+    let gain = |v: f64| 1.0 - (1.0 - v).powf(1.4);
+    Lab::from_color(&Cmyk::new(gain(cmyk.c), gain(cmyk.m), gain(cmyk.y), gain(cmyk.k)))
+}
+
+/// A characterization in the manner of IT8.7/4: a fine grid without black and coarser
+/// grids at a few black levels.
+fn samples() -> Vec<(Cmyk, Lab)> {
+    let fine = [0.0, 0.1, 0.2, 0.3, 0.4, 0.55, 0.7, 0.85, 1.0];
+    let coarse = [0.0, 0.2, 0.4, 0.7, 1.0];
+    let mut samples = Vec::new();
+    for &k in &[0.0, 0.2, 0.4, 0.6, 0.8, 1.0] {
+        let levels: &[f64] = if k == 0.0 { &fine } else { &coarse };
+        for &c in levels {
+            for &m in levels {
+                for &y in levels {
+                    let cmyk = Cmyk::new(c, m, y, k);
+                    samples.push((cmyk, press(&cmyk)));
+                }
+            }
+        }
+    }
+    // too few to form a black level of their own
+    for &(c, k) in &[(0.5, 0.1), (0.3, 0.1)] {
+        let cmyk = Cmyk::new(c, c, c, k);
+        samples.push((cmyk, press(&cmyk)));
+    }
+    samples
+}
+
+fn delta(a: &Lab, b: &Lab) -> f64 {
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+#[test]
+fn test_forward() {
+    let data = samples();
+    let model = CmykModel::new(&data).unwrap();
+    for (cmyk, lab) in data.iter().step_by(7).filter(|(c, _)| c.k != 0.1) {
+        assert!(delta(&model.to_lab(cmyk), lab) < 1e-9);
+    }
+    for &(c, m, y, k) in &[(0.15, 0.5, 0.8, 0.0), (0.62, 0.33, 0.05, 0.1), (0.3, 0.3, 0.3, 0.5), (0.9, 0.1, 0.5, 0.7)] {
+        let cmyk = Cmyk::new(c, m, y, k);
+        let error = delta(&model.to_lab(&cmyk), &press(&cmyk));
+        assert!(error < 3.0, "{:?} {}", cmyk, error);
+    }
+    assert!(CmykModel::new(&data[..5]).is_none());
+    // a missing node is filled in from its neighbors
+    let mut sparse = data.clone();
+    sparse.retain(|(c, _)| !(c.c == 0.4 && c.m == 0.4 && c.y == 0.4 && c.k == 0.0));
+    let model = CmykModel::new(&sparse).unwrap();
+    let cmyk = Cmyk::new(0.4, 0.4, 0.4, 0.0);
+    assert!(delta(&model.to_lab(&cmyk), &press(&cmyk)) < 3.0);
+}
+
+#[test]
+fn test_inverse() {
+    let model = CmykModel::new(&samples()).unwrap();
+    for &(c, m, y) in &[(0.15, 0.5, 0.8), (0.6, 0.3, 0.1), (0.4, 0.4, 0.4)] {
+        let target = model.to_lab(&Cmyk::new(c, m, y, 0.3));
+        let cmyk = model.from_lab(&target, BlackGeneration::Fixed(0.3));
+        assert!(delta(&model.to_lab(&cmyk), &target) < 0.05, "{:?}", cmyk);
+    }
+    let separation = Separation::default();
+    // a dark neutral needs black to stay within the ink limit
+    let target = model.to_lab(&Cmyk::new(0.8, 0.75, 0.75, 0.5));
+    let cmyk = model.from_lab(&target, BlackGeneration::Separation(separation));
+    assert!(cmyk.total_ink() <= separation.total_ink + 1e-9, "{:?}", cmyk);
+    assert!(cmyk.k > 0.5);
+    assert!(delta(&model.to_lab(&cmyk), &target) < 1.0, "{:?}", cmyk);
+    // a light color gets no black
+    let target = model.to_lab(&Cmyk::new(0.1, 0.05, 0.2, 0.0));
+    let cmyk = model.from_lab(&target, BlackGeneration::Separation(separation));
+    assert_eq!(cmyk.k, 0.0);
+    assert!(delta(&model.to_lab(&cmyk), &target) < 0.05);
+}
+
+#[test]
+fn test_profile() {
+    let model = CmykModel::new(&samples()).unwrap();
+    let transform = Transform::new(&model, &RgbSpace::SRGB, RenderingIntent::RelativeColorimetric, false);
+    for v in transform.apply(&[0.0, 0.0, 0.0, 0.0]) {
+        assert!((v - 1.0).abs() < 1e-3);
+    }
+    let back = Transform::new(&RgbSpace::SRGB, &model, RenderingIntent::RelativeColorimetric, false);
+    let cmyk = back.apply(&[0.5, 0.5, 0.5]);
+    assert_eq!(cmyk.len(), 4);
+    let rgb = transform.apply(&cmyk);
+    assert!(rgb.iter().all(|v| (v - 0.5).abs() < 0.01), "{:?}", rgb);
+}
+
+#[test]
+fn test_black_generation() {
+    let gray = [0.5, 0.5, 0.5];
+    let model = CmykModel::new(&samples()).unwrap();
+    let fixed = model.clone().with_black(BlackGeneration::Fixed(0.3));
+    let back = Transform::new(&RgbSpace::SRGB, &fixed, RenderingIntent::RelativeColorimetric, false);
+    assert!((back.apply(&gray)[3] - 0.3).abs() < 1e-9);
+    let free = model.with_black(BlackGeneration::Fixed(0.0));
+    let back = Transform::new(&RgbSpace::SRGB, &free, RenderingIntent::RelativeColorimetric, false);
+    assert_eq!(back.apply(&gray)[3], 0.0);
+}
+
+#[test]
+fn test_fogra39() {
+    let model = CmykModel::fogra39();
+    // the ISO 12647-2 aims for paper type 1
+    for &((c, m, y, k), lab) in &[
+        ((0.0, 0.0, 0.0, 0.0), Lab::new(95.0, 0.0, -2.0)),
+        ((1.0, 0.0, 0.0, 0.0), Lab::new(55.0, -37.0, -50.0)),
+        ((0.0, 1.0, 0.0, 0.0), Lab::new(48.0, 74.0, -3.0)),
+        ((0.0, 0.0, 1.0, 0.0), Lab::new(89.0, -5.0, 93.0)),
+        ((0.0, 0.0, 0.0, 1.0), Lab::new(16.0, 0.0, 0.0)),
+        ((0.0, 1.0, 1.0, 0.0), Lab::new(47.0, 68.0, 48.0)),
+        ((1.0, 0.0, 1.0, 0.0), Lab::new(50.0, -65.0, 27.0)),
+        ((1.0, 1.0, 0.0, 0.0), Lab::new(24.0, 22.0, -46.0)),
+    ] {
+        let error = delta(&model.to_lab(&Cmyk::new(c, m, y, k)), &lab);
+        assert!(error < 1.0, "{:?} {}", (c, m, y, k), error);
+    }
+    // interpolated across a missing yellow level
+    let levels = [0.0, 0.1, 0.2, 0.3, 0.4, 0.7, 0.85, 1.0];
+    let mut sparse = Vec::new();
+    for &c in &levels {
+        for &m in &levels {
+            for &y in &levels {
+                let cmyk = Cmyk::new(c, m, y, 0.0);
+                sparse.push((cmyk, model.to_lab(&cmyk)));
+            }
+        }
+    }
+    let sparse = CmykModel::new(&sparse).unwrap();
+    for &c in &levels {
+        for &m in &levels {
+            let cmyk = Cmyk::new(c, m, 0.55, 0.0);
+            assert!(delta(&sparse.to_lab(&cmyk), &model.to_lab(&cmyk)) < 1.5, "{:?}", cmyk);
+        }
+    }
+    for &(c, m, y, k) in &[(0.25, 0.6, 0.15, 0.0), (0.5, 0.45, 0.8, 0.1), (0.8, 0.2, 0.35, 0.5), (0.1, 0.9, 0.6, 0.3)] {
+        let target = model.to_lab(&Cmyk::new(c, m, y, k));
+        let cmyk = model.from_lab(&target, BlackGeneration::Fixed(k));
+        assert!(delta(&model.to_lab(&cmyk), &target) < 0.05, "{:?}", cmyk);
+        let cmyk = model.from_lab(&target, BlackGeneration::Separation(Separation::default()));
+        assert!(delta(&model.to_lab(&cmyk), &target) < 1.0, "{:?}", cmyk);
+    }
+}
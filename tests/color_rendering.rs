@@ -0,0 +1,47 @@
+use color_space::*;
+
+#[test]
+fn test_cri_fluorescent() {
+    // CIE 15 general color rendering indices of F1 to F12
+    let expected = [76.0, 64.0, 57.0, 51.0, 72.0, 59.0, 90.0, 95.0, 90.0, 81.0, 83.0, 83.0];
+    let illuminants = [
+        Illuminant::F1, Illuminant::F2, Illuminant::F3, Illuminant::F4,
+        Illuminant::F5, Illuminant::F6, Illuminant::F7, Illuminant::F8,
+        Illuminant::F9, Illuminant::F10, Illuminant::F11, Illuminant::F12,
+    ];
+    for (illuminant, &ra) in illuminants.iter().zip(expected.iter()) {
+        let cri = cri(&illuminant.spectrum());
+        assert!((cri.ra - ra).abs() < 0.6, "{:?} {:?}", illuminant, cri);
+    }
+}
+
+#[test]
+fn test_cri_reference() {
+    for &illuminant in &[Illuminant::A, Illuminant::D65] {
+        let cri = cri(&illuminant.spectrum());
+        assert!(cri.ra > 99.9, "{:?} {:?}", illuminant, cri);
+        assert!(cri.r.iter().all(|&r| r > 99.5));
+    }
+    // the narrow tri-band lamps render saturated red poorly
+    let f11 = cri(&Illuminant::F11.spectrum());
+    assert!(f11.r[8] < 30.0);
+    assert!((f11.cct - 4000.0).abs() < 5.0);
+}
+
+#[test]
+fn test_tm30() {
+    // the CES are not bundled, so this checks the indices on upsampled reflectances
+    // around the hue circle rather than against published TM-30 values
+    let samples: Vec<Spectrum> = (0..48)
+        .map(|i| Rgb::from_color(&Lch::new(60.0, 35.0, i as f64 * 7.5)).to_spectrum(Upsampling::JakobHanika))
+        .collect();
+    let d65 = tm30(&Illuminant::D65.spectrum(), &samples);
+    assert!(d65.rf > 99.5 && (d65.rg - 100.0).abs() < 0.5, "{:?}", d65);
+    assert!(d65.rf_hue.iter().all(|rf| !rf.is_nan()), "{:?}", d65);
+
+    let f4 = tm30(&Illuminant::F4.spectrum(), &samples);
+    let f7 = tm30(&Illuminant::F7.spectrum(), &samples);
+    assert!(f4.rf < f7.rf, "{:?} {:?}", f4, f7);
+    assert!(f4.rg < 100.0, "{:?}", f4);
+    assert!(f4.rf_hue.iter().all(|&rf| rf <= 100.0));
+}
@@ -0,0 +1,74 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} is not within {} of {}", a, tolerance, b);
+}
+
+#[test]
+fn test_lookup() {
+    // the ends and middle of viridis, #440154, #21918c and #fde725
+    assert_eq!(Colormap::Viridis.at(0.0), Rgb::new(68.0, 1.0, 84.0));
+    assert_eq!(Colormap::Viridis.at(128.0 / 255.0), Rgb::new(33.0, 145.0, 140.0));
+    assert_eq!(Colormap::Viridis.at(1.0), Rgb::new(253.0, 231.0, 37.0));
+    assert_eq!(Colormap::Viridis.at(2.0), Colormap::Viridis.at(1.0));
+
+    // the published endpoints of each map
+    let ends = [
+        (Colormap::Viridis, [0x44, 0x01, 0x54], [0xfd, 0xe7, 0x25]),
+        (Colormap::Magma, [0x00, 0x00, 0x04], [0xfc, 0xfd, 0xbf]),
+        (Colormap::Inferno, [0x00, 0x00, 0x04], [0xfc, 0xff, 0xa4]),
+        (Colormap::Plasma, [0x0d, 0x08, 0x87], [0xf0, 0xf9, 0x21]),
+    ];
+    for (map, first, last) in &ends {
+        assert_eq!((map.table()[0], map.table()[255]), (*first, *last), "{:?}", map);
+    }
+
+    // halfway between two entries
+    let table = Colormap::Magma.table();
+    let mid = Colormap::Magma.at(10.5 / 255.0);
+    assert_near(mid.r, (table[10][0] as f64 + table[11][0] as f64) / 2.0, 1e-9);
+
+    for map in &Colormap::ALL {
+        let samples = map.sample(11);
+        assert_eq!(samples.len(), 11);
+        let expected = map.at(0.3);
+        let found = map.gradient().at(0.3);
+        assert_near(found.r, expected.r, 1e-9);
+        assert_near(found.g, expected.g, 1e-9);
+        assert_near(found.b, expected.b, 1e-9);
+    }
+}
+
+#[test]
+fn test_analysis() {
+    for map in &Colormap::ALL {
+        let analysis = map.analyze();
+        assert!(analysis.lightness_monotonic(), "{:?}", map);
+        assert!(analysis.end_lightness > analysis.start_lightness + 70.0);
+        assert!(analysis.step_variation < 0.5, "{:?}", map);
+    }
+    // the lightness of the endpoints, #440154 and #fde725 for viridis
+    let viridis = Colormap::Viridis.analyze();
+    assert_near(viridis.start_lightness, Lab::from_rgb(&Rgb::new(68.0, 1.0, 84.0)).l, 1e-9);
+    assert_near(viridis.end_lightness, Lab::from_rgb(&Rgb::new(253.0, 231.0, 37.0)).l, 1e-9);
+    assert!(viridis.start_lightness < 20.0 && viridis.end_lightness > 85.0);
+}
+
+#[test]
+fn test_analyze_any() {
+    // a diverging map through white
+    let diverging = Gradient::evenly(
+        vec![Rgb::new(40.0, 80.0, 200.0), Rgb::new(245.0, 245.0, 245.0), Rgb::new(200.0, 40.0, 40.0)],
+        Segment::default()
+    );
+    let analysis = analyze_colormap(&diverging.sample(65));
+    assert_eq!(analysis.lightness_reversals, 1);
+    assert!(analysis.min_step > 0.0 && analysis.min_step <= analysis.mean_step && analysis.mean_step <= analysis.max_step);
+
+    // a gray ramp even in lightness has nearly uniform steps
+    let ramp: Vec<Lab> = (0..=32).map(|i| Lab::new(10.0 + 2.5 * i as f64, 0.0, 0.0)).collect();
+    let analysis = analyze_colormap(&ramp);
+    assert!(analysis.lightness_monotonic());
+    assert_near(analysis.start_lightness, 10.0, 0.05);
+    assert!(analysis.step_variation < 0.2);
+}
@@ -0,0 +1,51 @@
+use color_space::*;
+
+fn d65() -> Xyz {
+    Illuminant::D65.white_point(Observer::Cie1931)
+}
+
+fn xy(x: f64, y: f64) -> Yxy {
+    Yxy::new(50.0, x, y)
+}
+
+#[test]
+fn test_dominant_wavelength() {
+    let sample = xy(0.54369557, 0.32107944);
+    match sample.dominant_wavelength(&d65()) {
+        Some(Wavelength::Dominant(nm)) => assert!((nm - 616.0).abs() < 1.0, "{}", nm),
+        other => panic!("{:?}", other),
+    }
+    assert!((sample.excitation_purity(&d65()) - 0.623).abs() < 0.005);
+    assert!(sample.complementary_wavelength(&d65()).unwrap() < 500.0);
+
+    // a monochromatic stimulus is its own dominant wavelength, with full purity
+    let (x, y, z) = Observer::Cie1931.cmf(550.0);
+    let mono = Xyz::new(x, y, z);
+    match mono.dominant_wavelength(&d65()) {
+        Some(Wavelength::Dominant(nm)) => assert!((nm - 550.0).abs() < 1e-6, "{}", nm),
+        other => panic!("{:?}", other),
+    }
+    assert!((mono.excitation_purity(&d65()) - 1.0).abs() < 1e-6);
+    assert_eq!(mono.complementary_wavelength(&d65()), None);
+}
+
+#[test]
+fn test_purple() {
+    let purple = xy(0.37605506, 0.24452225);
+    match purple.dominant_wavelength(&d65()) {
+        Some(Wavelength::Complementary(nm)) => assert!((nm - 509.0).abs() < 1.0, "{}", nm),
+        other => panic!("{:?}", other),
+    }
+    let complementary = purple.complementary_wavelength(&d65()).unwrap();
+    assert!((complementary - 509.0).abs() < 1.0);
+    let purity = purple.excitation_purity(&d65());
+    assert!(purity > 0.0 && purity < 1.0);
+}
+
+#[test]
+fn test_white() {
+    assert_eq!(d65().excitation_purity(&d65()), 0.0);
+    assert_eq!(d65().dominant_wavelength(&d65()), None);
+    assert_eq!(Xyz::new(0.0, 0.0, 0.0).dominant_wavelength(&d65()), None);
+    assert_eq!(Xyz::new(0.0, 0.0, 0.0).complementary_wavelength(&d65()), None);
+}
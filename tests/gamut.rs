@@ -0,0 +1,104 @@
+use color_space::*;
+
+#[test]
+fn test_rgb_space_matrix() {
+    // the published sRGB matrix
+    let m = RgbSpace::SRGB.to_xyz_matrix();
+    let expected = [[0.4124, 0.3576, 0.1805], [0.2126, 0.7152, 0.0722], [0.0193, 0.1192, 0.9505]];
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((m[i][j] - expected[i][j]).abs() < 1e-4, "{:?}", m);
+        }
+    }
+    let space = RgbSpace::PROPHOTO_RGB;
+    let xyz = space.to_xyz([0.2, 0.5, 0.8]);
+    let rgb = space.from_xyz(&xyz);
+    assert!((rgb[0] - 0.2).abs() < 1e-9 && (rgb[1] - 0.5).abs() < 1e-9 && (rgb[2] - 0.8).abs() < 1e-9);
+    // white maps to white after adaptation
+    let white = space.from_xyz(&Xyz::new(95.047, 100.0, 108.883));
+    assert!(white.iter().all(|&c| (c - 1.0).abs() < 1e-3), "{:?}", white);
+}
+
+#[test]
+fn test_transfer_functions() {
+    for transfer in &[
+        TransferFunction::Srgb,
+        TransferFunction::Gamma(2.2),
+        TransferFunction::Rec2020,
+        TransferFunction::ProPhoto,
+    ] {
+        for &v in &[-0.5, 0.0, 0.001, 0.01, 0.2, 0.9, 1.5] {
+            assert!((transfer.decode(transfer.encode(v)) - v).abs() < 1e-12, "{:?} {}", transfer, v);
+        }
+    }
+    assert!((TransferFunction::Rec2020.encode(1.0) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_is_in_gamut() {
+    let tolerance = 1e-3;
+    assert!(Rgb::new(255.0, 0.0, 0.0).is_in_gamut(&RgbSpace::SRGB, tolerance));
+    assert!(Rgb::new(0.0, 255.0, 0.0).is_in_gamut(&RgbSpace::DISPLAY_P3, tolerance));
+    assert!(!Rgb::new(0.0, 255.0, 0.0).is_in_gamut(&RgbSpace::SRGB, -0.01));
+
+    let lab = Lab::new(50.0, 90.0, 0.0);
+    assert!(!lab.is_in_gamut(&RgbSpace::SRGB, tolerance));
+    assert!(lab.is_in_gamut(&RgbSpace::REC2020, tolerance));
+    let lch = Lch::new(60.0, 110.0, 150.0);
+    assert!(!lch.is_in_gamut(&RgbSpace::DISPLAY_P3, tolerance));
+    assert!(lch.is_in_gamut(&RgbSpace::PROPHOTO_RGB, tolerance));
+    assert!(Hsl::new(200.0, 0.5, 0.5).is_in_gamut(&RgbSpace::ADOBE_RGB, tolerance));
+}
+
+const METHODS: [GamutMapping; 4] = [GamutMapping::Clip, GamutMapping::Css, GamutMapping::Minde, GamutMapping::Cusp];
+
+#[test]
+fn test_gamut_map_in_gamut() {
+    let lch = Lch::new(60.0, 110.0, 150.0);
+    for &space in &[RgbSpace::SRGB, RgbSpace::DISPLAY_P3] {
+        for &method in &METHODS {
+            let mapped: Lch = lch.gamut_map(&space, method);
+            assert!(mapped.is_in_gamut(&space, 1e-6), "{:?} {:?}", method, mapped);
+        }
+    }
+    // colors already in gamut are unchanged
+    let rgb = Rgb::new(10.0, 200.0, 90.0);
+    for &method in &METHODS {
+        let mapped: Rgb = rgb.gamut_map(&RgbSpace::SRGB, method);
+        assert_eq!(mapped, rgb);
+    }
+}
+
+#[test]
+fn test_gamut_map_methods() {
+    let lch = Lch::new(60.0, 110.0, 150.0);
+    let origin = Oklch::from(lch);
+    let map = |method| -> Oklch { lch.gamut_map(&RgbSpace::SRGB, method) };
+    let lab = |color: Oklch| Lab::from(color);
+    let delta = |color: Oklch| lab(color).compare_cie1976(&lch);
+
+    // MINDE is the closest in CIELAB
+    let minde = delta(map(GamutMapping::Minde));
+    for &method in &METHODS {
+        assert!(minde <= delta(map(method)) + 1e-6, "{:?}", method);
+    }
+
+    // chroma reduction keeps lightness and hue, within the just noticeable difference
+    let css = map(GamutMapping::Css);
+    assert!((css.l - origin.l).abs() < 0.02);
+    assert!((css.h - origin.h).abs() < 5.0);
+    assert!(css.c < origin.c);
+
+    // the cusp projection keeps hue and moves towards the cusp lightness
+    let cusp = map(GamutMapping::Cusp);
+    assert!((cusp.h - origin.h).abs() < 0.5);
+    assert!(cusp.c > css.c - 0.01);
+}
+
+#[test]
+fn test_gamut_map_extremes() {
+    let white: Rgb = Oklch::new(1.2, 0.1, 40.0).gamut_map(&RgbSpace::SRGB, GamutMapping::Css);
+    assert_eq!(white, Rgb::new(255.0, 255.0, 255.0));
+    let black: Rgb = Lab::new(-5.0, 20.0, 0.0).gamut_map(&RgbSpace::SRGB, GamutMapping::Css);
+    assert_eq!(black, Rgb::new(0.0, 0.0, 0.0));
+}
@@ -0,0 +1,44 @@
+use color_space::*;
+
+#[test]
+fn test_max_chroma() {
+    let space = RgbSpace::SRGB;
+    for &h in &[0.0, 45.0, 130.0, 200.0, 300.0] {
+        for &l in &[20.0, 50.0, 80.0] {
+            let c = max_chroma::<Lch>(&space, l, h);
+            assert!(c > 0.0);
+            assert!(Lch::new(l, c * 0.999, h).is_in_gamut(&space, 1e-9));
+            assert!(!Lch::new(l, c * 1.01, h).is_in_gamut(&space, 1e-9));
+        }
+    }
+    assert_eq!(max_chroma::<Oklch>(&space, 0.0, 30.0), 0.0);
+    assert_eq!(max_chroma::<LchUv>(&space, 100.0, 30.0), 0.0);
+    // a wider space reaches further at the same lightness and hue
+    assert!(max_chroma::<Oklch>(&RgbSpace::DISPLAY_P3, 0.6, 150.0) > max_chroma::<Oklch>(&space, 0.6, 150.0));
+}
+
+#[test]
+fn test_cusp() {
+    let red = Oklch::from_rgb(&Rgb::new(255.0, 0.0, 0.0));
+    let (l, c) = cusp::<Oklch>(&RgbSpace::SRGB, red.h);
+    assert!((l - red.l).abs() < 1e-3 && (c - red.c).abs() < 1e-3, "{} {}", l, c);
+    let blue = Lch::from_rgb(&Rgb::new(0.0, 0.0, 255.0));
+    let (l, c) = cusp::<Lch>(&RgbSpace::SRGB, blue.h);
+    assert!((l - blue.l).abs() < 0.1 && (c - blue.c).abs() < 0.1, "{} {}", l, c);
+}
+
+#[test]
+fn test_gamut_boundary() {
+    let space = RgbSpace::SRGB;
+    let boundary = GamutBoundary::<LchUv>::new(&space, 20, 36);
+    for &h in &[15.0, 95.0, 185.0, 265.0] {
+        for &l in &[32.5, 62.5] {
+            let exact = max_chroma::<LchUv>(&space, l, h);
+            let segment = boundary.max_chroma(l, h);
+            assert!(segment > 0.0 && (segment - exact).abs() < 0.25 * exact, "{} {} {} {}", l, h, segment, exact);
+        }
+        let (l, c) = boundary.cusp(h);
+        let exact = cusp::<LchUv>(&space, h);
+        assert!((l - exact.0).abs() < 10.0 && (c - exact.1).abs() < 0.15 * exact.1, "{:?} {:?}", (l, c), exact);
+    }
+}
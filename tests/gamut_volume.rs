@@ -0,0 +1,27 @@
+use color_space::*;
+
+#[test]
+fn test_gamut_area() {
+    assert!((RgbSpace::SRGB.gamut_area(Chromaticity::Xy) - 0.1121).abs() < 1e-4);
+    assert!((RgbSpace::DISPLAY_P3.gamut_area(Chromaticity::Xy) - 0.1520).abs() < 1e-4);
+    assert!((RgbSpace::REC2020.gamut_area(Chromaticity::Xy) - 0.2119).abs() < 1e-4);
+    // sRGB lies inside Display P3
+    let coverage = RgbSpace::SRGB.area_coverage(&RgbSpace::DISPLAY_P3, Chromaticity::Xy);
+    assert!((coverage - 100.0 * 0.1121 / 0.1520).abs() < 0.1, "{}", coverage);
+    assert!((RgbSpace::DISPLAY_P3.area_coverage(&RgbSpace::SRGB, Chromaticity::UvPrime) - 100.0).abs() < 1e-9);
+    let coverage = RgbSpace::ADOBE_RGB.area_coverage(&RgbSpace::DISPLAY_P3, Chromaticity::UvPrime);
+    assert!(coverage > 50.0 && coverage < 100.0, "{}", coverage);
+}
+
+#[test]
+fn test_gamut_volume() {
+    let srgb = RgbSpace::SRGB.gamut_volume();
+    let p3 = RgbSpace::DISPLAY_P3.gamut_volume();
+    let rec2020 = RgbSpace::REC2020.gamut_volume();
+    // about 830 000 in the literature
+    assert!(srgb > 800_000.0 && srgb < 850_000.0, "{}", srgb);
+    assert!(srgb < p3 && p3 < rec2020, "{} {} {}", srgb, p3, rec2020);
+    let coverage = RgbSpace::SRGB.volume_coverage(&RgbSpace::DISPLAY_P3);
+    assert!((coverage - 100.0 * srgb / p3).abs() < 1.0, "{} {}", coverage, 100.0 * srgb / p3);
+    assert!(RgbSpace::REC2020.volume_coverage(&RgbSpace::SRGB) > 99.9);
+}
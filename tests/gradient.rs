@@ -0,0 +1,119 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} is not within {} of {}", a, tolerance, b);
+}
+
+fn assert_lab(a: &Lab, b: &Lab, tolerance: f64) {
+    assert_near(a.l, b.l, tolerance);
+    assert_near(a.a, b.a, tolerance);
+    assert_near(a.b, b.b, tolerance);
+}
+
+/// Colors pass through `Rgb`, whose matrices round trip `Lab` to about 0.01.
+fn lab_segment() -> Segment {
+    Segment { space: MixSpace::of::<Lab>(), ..Default::default() }
+}
+
+#[test]
+fn test_easing() {
+    assert_eq!(Easing::Linear.apply(0.3), 0.3);
+    for easing in &[Easing::Ease, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+        assert_near(easing.apply(0.0), 0.0, 1e-9);
+        assert_near(easing.apply(1.0), 1.0, 1e-9);
+    }
+    assert_near(Easing::EaseInOut.apply(0.5), 0.5, 1e-9);
+    assert!(Easing::EaseIn.apply(0.25) < 0.25 && Easing::EaseOut.apply(0.25) > 0.25);
+    assert_near(Easing::CubicBezier(1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0).apply(0.7), 0.7, 1e-9);
+}
+
+#[test]
+fn test_linear() {
+    let stops = vec![(1.0, Lab::new(80.0, 0.0, 0.0)), (0.0, Lab::new(20.0, 40.0, 0.0)), (0.5, Lab::new(50.0, 0.0, 40.0))];
+    let gradient = Gradient::new(stops, lab_segment());
+    assert_eq!(gradient.stops[0].0, 0.0);
+    assert_lab(&gradient.at(0.25), &Lab::new(35.0, 20.0, 20.0), 0.05);
+    assert_lab(&gradient.at(-1.0), &Lab::new(20.0, 40.0, 0.0), 0.05);
+    assert_lab(&gradient.at(2.0), &Lab::new(80.0, 0.0, 0.0), 0.05);
+
+    let samples = gradient.sample(5);
+    assert_eq!(samples.len(), 5);
+    assert_lab(&samples[3], &Lab::new(65.0, 0.0, 20.0), 0.05);
+
+    // a hard stop
+    let hard = Gradient::new(vec![(0.0, Lab::new(10.0, 0.0, 0.0)), (0.5, Lab::new(10.0, 0.0, 0.0)), (0.5, Lab::new(90.0, 0.0, 0.0)), (1.0, Lab::new(90.0, 0.0, 0.0))], lab_segment());
+    assert_near(hard.at(0.49).l, 10.0, 0.05);
+    assert_near(hard.at(0.5).l, 90.0, 0.05);
+}
+
+#[test]
+fn test_segments() {
+    let red = Rgb::new(255.0, 0.0, 0.0);
+    let blue = Rgb::new(0.0, 0.0, 255.0);
+    let mut gradient = Gradient::evenly(vec![red, blue, red], Segment::default());
+    gradient.segments[1] = Segment { space: MixSpace::of::<Lch>(), hue: HueInterpolation::Longer, ..Default::default() };
+    gradient.segments[0].easing = Easing::EaseIn;
+
+    let oklab = mix_in::<Oklab, _>(&red, &blue, Easing::EaseIn.apply(0.5), HueInterpolation::Shorter);
+    assert_near(gradient.at(0.25).r, oklab.r, 1e-9);
+    // the longer way from blue to red in Lch passes through green
+    let lch = Lch::from_rgb(&gradient.at(0.75));
+    let (from, to) = (Lch::from_rgb(&blue).h, Lch::from_rgb(&red).h);
+    assert_near(lch.h, HueInterpolation::Longer.interpolate(from, to, 0.5), 0.05);
+    assert!(lch.h > 90.0 && lch.h < 180.0);
+}
+
+#[test]
+fn test_splines() {
+    let colors = vec![Lab::new(20.0, 10.0, -40.0), Lab::new(50.0, 40.0, 10.0), Lab::new(70.0, -10.0, 50.0), Lab::new(95.0, 0.0, 0.0)];
+    let catmull = Gradient::evenly(colors.clone(), Segment { path: Path::CatmullRom, ..lab_segment() });
+    let spline = Gradient::evenly(colors.clone(), Segment { path: Path::BSpline, ..lab_segment() });
+    // Catmull-Rom passes through every stop, the B-spline only through the ends
+    for (i, color) in colors.iter().enumerate() {
+        assert_lab(&catmull.at(i as f64 / 3.0), color, 0.05);
+    }
+    assert_lab(&spline.at(0.0), &colors[0], 0.05);
+    assert_lab(&spline.at(1.0), &colors[3], 0.05);
+    let middle = Lab::new(
+        (colors[0].l + 4.0 * colors[1].l + colors[2].l) / 6.0,
+        (colors[0].a + 4.0 * colors[1].a + colors[2].a) / 6.0,
+        (colors[0].b + 4.0 * colors[1].b + colors[2].b) / 6.0
+    );
+    assert_lab(&spline.at(1.0 / 3.0), &middle, 0.05);
+
+    // both are smooth across stops, where a linear path turns by 15 in lightness
+    let linear = Gradient::evenly(colors, lab_segment());
+    let h = 1e-4;
+    let kink = |gradient: &Gradient<Lab>| {
+        let slope = |p: f64| (gradient.at(p + h).l - gradient.at(p - h).l) / (2.0 * h);
+        (slope(2.0 / 3.0 + 2.0 * h) - slope(2.0 / 3.0 - 2.0 * h)).abs()
+    };
+    assert!(kink(&linear) > 10.0);
+    assert!(kink(&catmull) < 1.0 && kink(&spline) < 1.0);
+}
+
+#[test]
+fn test_sample_even() {
+    let colors = vec![Rgb::new(10.0, 10.0, 60.0), Rgb::new(250.0, 60.0, 40.0), Rgb::new(255.0, 255.0, 220.0)];
+    let mut gradient = Gradient::evenly(colors, Segment::default());
+    gradient.stops[1].0 = 0.2;
+    let de = |a: &Rgb, b: &Rgb| a.compare_cie2000(b);
+    let spread = |samples: &[Rgb]| {
+        let steps: Vec<f64> = samples.windows(2).map(|p| de(&p[0], &p[1])).collect();
+        let max = steps.iter().cloned().fold(0.0, f64::max);
+        let min = steps.iter().cloned().fold(f64::INFINITY, f64::min);
+        max / min
+    };
+    let uneven = gradient.sample(12);
+    let even = gradient.sample_even(12, de);
+    assert_eq!(even.len(), 12);
+    assert!(spread(&uneven) > 2.0);
+    // chords fall a little short of the arc where the path bends
+    assert!(spread(&even) < 1.1, "{}", spread(&even));
+    assert_eq!(even[0], gradient.at(0.0));
+    assert_eq!(even[11], gradient.at(1.0));
+
+    let positions = gradient.even_positions(5, |a, b| a.compare_cie1976(b));
+    assert!(positions.windows(2).all(|p| p[0] < p[1]));
+    assert_eq!(gradient.even_positions(1, de), vec![0.0]);
+}
@@ -0,0 +1,343 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} != {}", a, b);
+}
+
+fn s15(v: f64) -> [u8; 4] {
+    ((v * 65536.0).round() as i32).to_be_bytes()
+}
+
+fn xyz_tag(x: f64, y: f64, z: f64) -> Vec<u8> {
+    [&b"XYZ \0\0\0\0"[..], &s15(x), &s15(y), &s15(z)].concat()
+}
+
+fn para_tag(kind: u16, params: &[f64]) -> Vec<u8> {
+    let mut tag = [&b"para\0\0\0\0"[..], &kind.to_be_bytes(), &[0, 0]].concat();
+    for &p in params {
+        tag.extend_from_slice(&s15(p));
+    }
+    tag
+}
+
+fn identity_curve() -> Vec<u8> {
+    b"curv\0\0\0\0\0\0\0\0".to_vec()
+}
+
+/// A profile with the given header fields and tags, each tag padded to 4 bytes.
+fn profile(version: u8, color_space: &[u8; 4], pcs: &[u8; 4], tags: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+    let mut header = vec![0u8; 128];
+    header[8] = version;
+    header[12..16].copy_from_slice(b"mntr");
+    header[16..20].copy_from_slice(color_space);
+    header[20..24].copy_from_slice(pcs);
+    header[36..40].copy_from_slice(b"acsp");
+    let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+    let mut data = Vec::new();
+    let start = 128 + 4 + 12 * tags.len();
+    for (sig, tag) in tags {
+        table.extend_from_slice(*sig);
+        table.extend_from_slice(&((start + data.len()) as u32).to_be_bytes());
+        table.extend_from_slice(&(tag.len() as u32).to_be_bytes());
+        data.extend_from_slice(tag);
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+    }
+    let mut bytes = [header, table, data].concat();
+    let size = (bytes.len() as u32).to_be_bytes();
+    bytes[0..4].copy_from_slice(&size);
+    bytes
+}
+
+fn srgb_profile() -> Vec<u8> {
+    let trc = para_tag(3, &[2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045]);
+    profile(4, b"RGB ", b"XYZ ", &[
+        (b"wtpt", xyz_tag(0.9642, 1.0, 0.8249)),
+        (b"rXYZ", xyz_tag(0.4361, 0.2225, 0.0139)),
+        (b"gXYZ", xyz_tag(0.3851, 0.7169, 0.0971)),
+        (b"bXYZ", xyz_tag(0.1431, 0.0606, 0.7141)),
+        (b"rTRC", trc.clone()),
+        (b"gTRC", trc.clone()),
+        (b"bTRC", trc),
+    ])
+}
+
+/// A `mAB ` or `mBA ` tag with identity curves and a 2×2×2 table holding `corner`.
+fn lut_tag(kind: &[u8; 4], corner: impl Fn(usize, usize, usize) -> [f64; 3]) -> Vec<u8> {
+    let curves = [identity_curve(), identity_curve(), identity_curve()].concat();
+    let b = 32;
+    let clut = b + curves.len();
+    let mut clut_data = vec![2u8, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0];
+    for r in 0..2 {
+        for g in 0..2 {
+            for bl in 0..2 {
+                for v in &corner(r, g, bl) {
+                    clut_data.extend_from_slice(&((v * 65535.0).round() as u16).to_be_bytes());
+                }
+            }
+        }
+    }
+    let a = clut + clut_data.len();
+    let mut tag = [&kind[..], &[0, 0, 0, 0, 3, 3, 0, 0]].concat();
+    for offset in &[b, 0, 0, clut, a] {
+        tag.extend_from_slice(&(*offset as u32).to_be_bytes());
+    }
+    [tag, curves.clone(), clut_data, curves].concat()
+}
+
+#[test]
+fn test_matrix_trc() {
+    let icc = IccProfile::parse(&srgb_profile()).unwrap();
+    assert_eq!(icc.version, (4, 0));
+    assert_eq!(&icc.color_space, b"RGB ");
+    assert_eq!(icc.channels(), 3);
+    for &(rgb, xyz) in &[
+        ([1.0, 0.0, 0.0], [41.24, 21.26, 1.93]),
+        ([0.0, 1.0, 0.0], [35.76, 71.52, 11.92]),
+        ([1.0, 1.0, 1.0], [95.047, 100.0, 108.883]),
+    ] {
+        let result = icc.device_to_xyz(&rgb);
+        assert_near(result.x, xyz[0], 0.1);
+        assert_near(result.y, xyz[1], 0.1);
+        assert_near(result.z, xyz[2], 0.1);
+    }
+    let back = icc.xyz_to_device(&icc.device_to_xyz(&[0.2, 0.5, 0.9]));
+    assert_near(back[0], 0.2, 1e-6);
+    assert_near(back[1], 0.5, 1e-6);
+    assert_near(back[2], 0.9, 1e-6);
+    // usable wherever a profile is
+    let transform = Transform::new(&icc, &RgbSpace::SRGB, RenderingIntent::RelativeColorimetric, false);
+    for (a, b) in transform.apply(&[0.3, 0.6, 0.1]).iter().zip(&[0.3, 0.6, 0.1]) {
+        assert_near(*a, *b, 2e-3);
+    }
+}
+
+#[test]
+fn test_lut_pipelines() {
+    // the table maps the device channels straight onto encoded L*, a*, b*
+    let a2b = lut_tag(b"mAB ", |r, g, b| [r as f64, g as f64, b as f64]);
+    let b2a = lut_tag(b"mBA ", |l, a, b| [l as f64, a as f64, b as f64]);
+    let icc = IccProfile::parse(&profile(4, b"RGB ", b"Lab ", &[(b"A2B0", a2b), (b"B2A0", b2a)])).unwrap();
+    let gray = 128.0 / 255.0;
+    let white = icc.device_to_xyz(&[1.0, gray, gray]);
+    assert_near(white.x, 95.047, 0.01);
+    assert_near(white.y, 100.0, 0.01);
+    assert_near(white.z, 108.883, 0.01);
+    // neutrals are the same in the D50 connection space and the D65 conversions
+    let lab = Lab::from_color(&icc.device_to_xyz(&[0.5, gray, gray]));
+    assert_near(lab.l, 50.0, 0.01);
+    assert_near(lab.a, 0.0, 0.01);
+    assert_near(lab.b, 0.0, 0.01);
+    let back = icc.xyz_to_device(&icc.device_to_xyz(&[0.5, 0.25, 0.75]));
+    assert_near(back[0], 0.5, 1e-4);
+    assert_near(back[1], 0.25, 1e-4);
+    assert_near(back[2], 0.75, 1e-4);
+}
+
+#[test]
+fn test_errors() {
+    let valid = srgb_profile();
+    assert_eq!(IccProfile::parse(&valid[..100]).unwrap_err(), IccError::Truncated);
+    assert_eq!(IccProfile::parse(&valid[..valid.len() - 8]).unwrap_err(), IccError::Truncated);
+    let mut bad = valid.clone();
+    bad[36] = b'x';
+    assert_eq!(IccProfile::parse(&bad).unwrap_err(), IccError::InvalidSignature);
+    let mut bad = valid.clone();
+    bad[8] = 3;
+    assert_eq!(IccProfile::parse(&bad).unwrap_err(), IccError::UnsupportedVersion(3));
+    let empty = profile(2, b"RGB ", b"XYZ ", &[(b"wtpt", xyz_tag(0.9642, 1.0, 0.8249))]);
+    assert_eq!(IccProfile::parse(&empty).unwrap_err(), IccError::MissingTransform);
+    let text = profile(2, b"GRAY", b"XYZ ", &[(b"kTRC", b"text\0\0\0\0".to_vec())]);
+    assert_eq!(IccProfile::parse(&text).unwrap_err(), IccError::UnsupportedTagType(*b"kTRC", *b"text"));
+}
+
+#[test]
+fn test_write_profiles() {
+    let spaces = [RgbSpace::SRGB, RgbSpace::DISPLAY_P3, RgbSpace::REC2020, RgbSpace::ADOBE_RGB, RgbSpace::PROPHOTO_RGB];
+    for space in &spaces {
+        for &version in &[IccVersion::V2, IccVersion::V4] {
+            let bytes = space.to_icc(version, "Measured display", "Public domain");
+            let icc = IccProfile::parse(&bytes).unwrap();
+            assert_eq!(icc.version.0, if version == IccVersion::V4 { 4 } else { 2 });
+            assert_eq!(icc.description.as_deref(), Some("Measured display"));
+            assert_eq!(bytes.len() % 4, 0);
+            // the encoded size and, for version 4, a profile ID
+            assert_eq!(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize, bytes.len());
+            assert_eq!(bytes[84..100].iter().any(|&b| b != 0), version == IccVersion::V4);
+            let white = icc.media_white();
+            let expected = space.media_white();
+            assert_near(white.x, expected.x, 0.01);
+            assert_near(white.z, expected.z, 0.01);
+            for rgb in &[[1.0, 0.0, 0.0], [0.1, 0.6, 0.3], [0.9, 0.9, 0.2], [0.02, 0.01, 0.03]] {
+                let a = icc.device_to_xyz(rgb);
+                let b = space.to_xyz(*rgb);
+                assert_near(a.x, b.x, 0.02);
+                assert_near(a.y, b.y, 0.02);
+                assert_near(a.z, b.z, 0.02);
+            }
+        }
+    }
+    // the ID depends on the content
+    let a = RgbSpace::SRGB.to_icc(IccVersion::V4, "a", "");
+    let b = RgbSpace::SRGB.to_icc(IccVersion::V4, "b", "");
+    assert_ne!(a[84..100], b[84..100]);
+}
+
+fn gray_profile_bytes(trc: Vec<u8>) -> Vec<u8> {
+    profile(2, b"GRAY", b"XYZ ", &[(b"wtpt", xyz_tag(0.9642, 1.0, 0.8249)), (b"kTRC", trc)])
+}
+
+fn gray_profile(trc: Vec<u8>) -> IccProfile {
+    IccProfile::parse(&gray_profile_bytes(trc)).unwrap()
+}
+
+#[test]
+fn test_gray_trc() {
+    // a gamma of 461 / 256
+    let icc = gray_profile(b"curv\0\0\0\0\0\0\0\x01\x01\xcd".to_vec());
+    assert_eq!(icc.channels(), 1);
+    let gamma = 461.0 / 256.0;
+    let xyz = icc.device_to_xyz(&[0.5]);
+    assert_near(xyz.y, 100.0 * 0.5f64.powf(gamma), 1e-6);
+    // neutrals land on the white of the conversions
+    assert_near(xyz.x / xyz.y, 0.95047, 1e-3);
+    assert_near(xyz.z / xyz.y, 1.08883, 1e-3);
+    assert_near(icc.xyz_to_device(&xyz)[0], 0.5, 1e-6);
+}
+
+#[test]
+fn test_parametric_curves() {
+    let srgb = [2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045, 0.01, 0.02];
+    type Case<'a> = (u16, &'a [f64], fn(f64) -> f64);
+    let cases: [Case; 4] = [
+        (0, &[2.2], |x| x.powf(2.2)),
+        (1, &[2.0, 1.25, -0.25], |x| if x >= 0.2 { (1.25 * x - 0.25).powi(2) } else { 0.0 }),
+        (2, &[2.0, 1.25, -0.25, 0.1], |x| if x >= 0.2 { (1.25 * x - 0.25).powi(2) + 0.1 } else { 0.1 }),
+        (4, &srgb, |x| {
+            if x >= 0.04045 { ((x + 0.055) / 1.055).powf(2.4) + 0.01 } else { x / 12.92 + 0.02 }
+        }),
+    ];
+    for (kind, params, expected) in &cases {
+        let icc = gray_profile(para_tag(*kind, params));
+        for &x in &[0.0, 0.03, 0.1, 0.5, 0.9] {
+            // the parameters are stored with a resolution of 1/65536
+            assert_near(icc.device_to_xyz(&[x]).y / 100.0, expected(x), 2e-4);
+        }
+    }
+}
+
+#[test]
+fn test_chromatic_adaptation_tag() {
+    // the Bradford adaptation from D65 to D50
+    let chad = [1.0478, 0.0229, -0.0501, 0.0295, 0.9905, -0.0170, -0.0092, 0.0150, 0.7521];
+    let mut tag = b"sf32\0\0\0\0".to_vec();
+    for &v in &chad {
+        tag.extend_from_slice(&s15(v));
+    }
+    let trc = para_tag(0, &[1.0]);
+    let icc = IccProfile::parse(&profile(4, b"GRAY", b"XYZ ", &[
+        (b"wtpt", xyz_tag(0.9642, 1.0, 0.8249)),
+        (b"chad", tag),
+        (b"kTRC", trc),
+    ]))
+    .unwrap();
+    let white = icc.media_white();
+    assert_near(white.x, 95.047, 0.05);
+    assert_near(white.y, 100.0, 0.05);
+    assert_near(white.z, 108.883, 0.05);
+}
+
+/// A `mft1` or `mft2` tag with identity tables and a 2×2×2 table holding `corner`.
+fn mft_tag(kind: &[u8; 4], corner: impl Fn(usize, usize, usize) -> [f64; 3]) -> Vec<u8> {
+    let wide = kind == b"mft2";
+    let value = |v: f64| if wide { ((v * 65535.0).round() as u16).to_be_bytes().to_vec() } else { vec![(v * 255.0).round() as u8] };
+    let mut tag = [&kind[..], &[0, 0, 0, 0, 3, 3, 2, 0]].concat();
+    for &v in &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0] {
+        tag.extend_from_slice(&s15(v));
+    }
+    let entries = if wide { 2 } else { 256 };
+    if wide {
+        tag.extend_from_slice(&[0, 2, 0, 2]);
+    }
+    let table: Vec<u8> = (0..entries).flat_map(|i| value(i as f64 / (entries - 1) as f64)).collect();
+    for _ in 0..3 {
+        tag.extend_from_slice(&table);
+    }
+    for r in 0..2 {
+        for g in 0..2 {
+            for b in 0..2 {
+                for &v in &corner(r, g, b) {
+                    tag.extend(value(v));
+                }
+            }
+        }
+    }
+    for _ in 0..3 {
+        tag.extend_from_slice(&table);
+    }
+    tag
+}
+
+#[test]
+fn test_legacy_lut_pipelines() {
+    for kind in &[b"mft1", b"mft2"] {
+        let a2b = mft_tag(kind, |r, g, b| [r as f64, g as f64, b as f64]);
+        let icc = IccProfile::parse(&profile(2, b"RGB ", b"Lab ", &[(b"A2B0", a2b)])).unwrap();
+        // mft2 has the version 2 Lab encoding, where 0xFF00 is the top of the range
+        let (top, gray) = match *kind {
+            b"mft1" => (1.0, 128.0 / 255.0),
+            _ => (65280.0 / 65535.0, 32768.0 / 65535.0),
+        };
+        let white = icc.device_to_xyz(&[top, gray, gray]);
+        assert_near(white.x, 95.047, 0.01);
+        assert_near(white.y, 100.0, 0.01);
+        assert_near(white.z, 108.883, 0.01);
+        let lab = Lab::from_color(&icc.device_to_xyz(&[top / 2.0, gray, gray]));
+        assert_near(lab.l, 50.0, 0.01);
+        assert_near(lab.a, 0.0, 0.01);
+        assert_near(lab.b, 0.0, 0.01);
+        // without a B2A table the A2B table is inverted numerically
+        let back = icc.xyz_to_device(&icc.device_to_xyz(&[0.5, 0.25, 0.75]));
+        assert_near(back[0], 0.5, 1e-3);
+        assert_near(back[1], 0.25, 1e-3);
+        assert_near(back[2], 0.75, 1e-3);
+    }
+}
+
+#[test]
+fn test_malformed() {
+    // a grid of 255 points over 15 inputs has more entries than memory can address
+    let mut mft = [&b"mft2\0\0\0\0"[..], &[15, 3, 255, 0]].concat();
+    mft.extend_from_slice(&[0; 36]);
+    mft.extend_from_slice(&[0, 2, 0, 2]);
+    let huge = profile(2, b"FCLR", b"Lab ", &[(b"A2B0", mft)]);
+    assert_eq!(IccProfile::parse(&huge).unwrap_err(), IccError::Malformed("lookup table grid"));
+    // tables whose channels differ from the color spaces they connect
+    let reshaped = |name: &[u8; 4], inputs: u8, outputs: u8| {
+        let mut mft = mft_tag(b"mft1", |r, g, b| [r as f64, g as f64, b as f64]);
+        mft[8] = inputs;
+        mft[9] = outputs;
+        IccProfile::parse(&profile(2, b"RGB ", b"Lab ", &[(name, mft)])).unwrap_err()
+    };
+    assert_eq!(reshaped(b"A2B0", 3, 1), IccError::Malformed("lookup table channels"));
+    assert_eq!(reshaped(b"A2B0", 4, 3), IccError::Malformed("lookup table channels"));
+    assert_eq!(reshaped(b"B2A0", 3, 4), IccError::Malformed("lookup table channels"));
+    let unknown = gray_profile_bytes(para_tag(5, &[1.0]));
+    assert_eq!(IccProfile::parse(&unknown).unwrap_err(), IccError::Malformed("parametric curve type"));
+    let flat = gray_profile_bytes(para_tag(1, &[1.0, 0.0, 0.0]));
+    assert_eq!(IccProfile::parse(&flat).unwrap_err(), IccError::Malformed("parametric curve"));
+    let short = gray_profile_bytes(b"curv\0\0\0\0\0\0\0\x04\0\0".to_vec());
+    assert_eq!(IccProfile::parse(&short).unwrap_err(), IccError::Truncated);
+
+    // descriptions shorter than their header, and localized records beyond any offset
+    let described = |desc: Vec<u8>| {
+        IccProfile::parse(&profile(4, b"GRAY", b"XYZ ", &[(b"desc", desc), (b"kTRC", identity_curve())]))
+    };
+    assert_eq!(described(b"text".to_vec()).unwrap_err(), IccError::Truncated);
+    let mluc = [&b"mluc\0\0\0\0"[..], &u32::MAX.to_be_bytes(), &u32::MAX.to_be_bytes()].concat();
+    assert_eq!(described(mluc).unwrap_err(), IccError::Truncated);
+    let mluc = b"mluc\0\0\0\0\0\0\0\x01\0\0\0\0".to_vec();
+    assert_eq!(described(mluc).unwrap_err(), IccError::Malformed("localized text record"));
+    assert_eq!(described(b"text\0\0\0\0Gray\0".to_vec()).unwrap().description.as_deref(), Some("Gray"));
+}
@@ -0,0 +1,93 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance, "{} != {} (tolerance {})", a, b, tolerance);
+}
+
+fn assert_xyz(a: Xyz, b: Xyz, tolerance: f64) {
+    assert_near(a.x, b.x, tolerance);
+    assert_near(a.y, b.y, tolerance);
+    assert_near(a.z, b.z, tolerance);
+}
+
+#[test]
+fn test_illuminant_white_points() {
+    // CIE 15 tabulated white points
+    let cases = [
+        (Illuminant::A, Observer::Cie1931, Xyz::new(109.850, 100.0, 35.585)),
+        (Illuminant::D50, Observer::Cie1931, Xyz::new(96.422, 100.0, 82.521)),
+        (Illuminant::D55, Observer::Cie1931, Xyz::new(95.682, 100.0, 92.149)),
+        (Illuminant::D65, Observer::Cie1931, Xyz::new(95.047, 100.0, 108.883)),
+        (Illuminant::D75, Observer::Cie1931, Xyz::new(94.972, 100.0, 122.638)),
+        (Illuminant::E, Observer::Cie1931, Xyz::new(100.0, 100.0, 100.0)),
+        (Illuminant::A, Observer::Cie1964, Xyz::new(111.144, 100.0, 35.200)),
+        (Illuminant::D50, Observer::Cie1964, Xyz::new(96.720, 100.0, 81.427)),
+        (Illuminant::D65, Observer::Cie1964, Xyz::new(94.811, 100.0, 107.304)),
+    ];
+    for &(illuminant, observer, white) in &cases {
+        assert_xyz(illuminant.white_point(observer), white, 0.03);
+    }
+}
+
+#[test]
+fn test_illuminant_lab_reference_white() {
+    // the reference white of Lab is integrated from the spectrum, within 0.005 of the
+    // ASTM E308 value, so the rounded sRGB white is neutral to within a hundredth or two
+    let white = Illuminant::D65.white_point(Observer::Cie1931);
+    assert_xyz(white, Xyz::new(95.043, 100.0, 108.880), 0.001);
+    assert_xyz(white, Xyz::new(95.047, 100.0, 108.883), 0.005);
+    for lab in &[Lab::from(white), Lab::from_rgb(&Rgb::new(255.0, 255.0, 255.0))] {
+        assert_near(lab.l, 100.0, 0.01);
+        assert_near(lab.a, 0.0, 0.02);
+        assert_near(lab.b, 0.0, 0.02);
+    }
+}
+
+#[test]
+fn test_illuminant_fluorescent() {
+    // CIE 15 tabulated chromaticities and nominal correlated color temperatures
+    let cases = [
+        (Illuminant::F1, 0.31310, 0.33727, 6430.0),
+        (Illuminant::F2, 0.37208, 0.37529, 4230.0),
+        (Illuminant::F3, 0.40910, 0.39430, 3450.0),
+        (Illuminant::F4, 0.44018, 0.40329, 2940.0),
+        (Illuminant::F5, 0.31379, 0.34531, 6350.0),
+        (Illuminant::F6, 0.37790, 0.38835, 4150.0),
+        (Illuminant::F7, 0.31292, 0.32933, 6500.0),
+        (Illuminant::F8, 0.34588, 0.35875, 5000.0),
+        (Illuminant::F9, 0.37417, 0.37281, 4150.0),
+        (Illuminant::F11, 0.38052, 0.37713, 4000.0),
+        (Illuminant::F12, 0.43695, 0.40441, 3000.0),
+    ];
+    for &(illuminant, x, y, cct) in &cases {
+        let white = illuminant.white_point(Observer::Cie1931);
+        let sum = white.x + white.y + white.z;
+        assert_near(white.x / sum, x, 3e-4);
+        assert_near(white.y / sum, y, 3e-4);
+        assert_near(white.cct(CctMethod::Ohno), cct, 10.0);
+    }
+    let f10 = Illuminant::F10.white_point(Observer::Cie1931);
+    assert_near(f10.cct(CctMethod::Ohno), 5000.0, 10.0);
+}
+
+#[test]
+fn test_illuminant_daylight() {
+    let d65 = Illuminant::D65.spectrum();
+    let daylight = Spectrum::daylight(6504.0);
+    assert_eq!(d65.wavelengths, daylight.wavelengths);
+    for (a, b) in d65.values.iter().zip(daylight.values.iter()) {
+        assert_near(*a, *b, 0.1);
+    }
+    let d93 = Spectrum::daylight(9300.0).to_xyz(Observer::Cie1931);
+    assert_near(d93.cct(CctMethod::Ohno), 9300.0, 10.0);
+}
+
+#[test]
+fn test_illuminant_blackbody() {
+    let a = Illuminant::A.spectrum();
+    let blackbody = Spectrum::blackbody(2848.0 * 1.4388 / 1.435);
+    assert_near(blackbody.value_at(560.0), 100.0, 1e-9);
+    for (a, b) in a.values.iter().zip(blackbody.values.iter()) {
+        assert_near(*a, *b, 0.01);
+    }
+}
@@ -0,0 +1,70 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} is not within {} of {}", a, tolerance, b);
+}
+
+/// A flat reflectance from 380 nm to 780 nm.
+fn flat(r: f64) -> Spectrum {
+    Spectrum::from_fn(380.0, 780.0, 10.0, |_| r)
+}
+
+#[test]
+fn test_pigments() {
+    let white = Pigment::from_reflectance(&flat(0.9));
+    let black = Pigment::from_reflectance(&flat(0.05));
+    assert_near(white.reflectance().value_at(550.0), 0.9, 1e-12);
+
+    // equal parts average K/S, as both scatter equally
+    let gray = mix_pigments(&[(&white, 1.0), (&black, 1.0)]);
+    let ks: f64 = (0.01 / 1.8 + 0.9025 / 0.1) / 2.0;
+    assert_near(gray.value_at(500.0), 1.0 + ks - (ks * ks + 2.0 * ks).sqrt(), 1e-12);
+    // a little black darkens far more than its share
+    let tint = mix_pigments(&[(&white, 9.0), (&black, 1.0)]);
+    assert!(tint.value_at(500.0) < 0.5);
+
+    // the same masstone scattering less is dominated by the white
+    let blue = Spectrum::from_fn(380.0, 780.0, 10.0, |nm| if nm < 500.0 { 0.6 } else { 0.05 });
+    let strong = Pigment::from_reflectance(&blue);
+    let weak = Pigment::new(strong.absorption.scale(0.1), strong.scattering.scale(0.1));
+    assert_near(weak.reflectance().value_at(600.0), 0.05, 1e-12);
+    let pale = mix_pigments(&[(&white, 1.0), (&weak, 1.0)]);
+    let dark = mix_pigments(&[(&white, 1.0), (&strong, 1.0)]);
+    assert!(pale.value_at(600.0) > dark.value_at(600.0) + 0.2);
+
+    // scattering measured at other wavelengths is interpolated at those of the absorption
+    let scattering = Spectrum::from_fn(400.0, 700.0, 20.0, |nm| nm / 100.0);
+    let pigment = Pigment::new(strong.absorption.clone(), scattering);
+    assert_eq!(pigment.scattering.wavelengths, strong.absorption.wavelengths);
+    assert_near(pigment.scattering.value_at(410.0), 4.1, 1e-12);
+    assert_near(pigment.scattering.value_at(380.0), 4.0, 1e-12);
+}
+
+#[test]
+fn test_latent() {
+    for rgb in &[Rgb::new(0.0, 33.0, 133.0), Rgb::new(252.0, 211.0, 0.0), Rgb::new(0.0, 0.0, 0.0), Rgb::new(255.0, 255.0, 255.0)] {
+        let back = PigmentLatent::from_rgb(rgb).to_rgb();
+        assert_near(back.r, rgb.r, 1e-6);
+        assert_near(back.g, rgb.g, 1e-6);
+        assert_near(back.b, rgb.b, 1e-6);
+    }
+    let a = PigmentLatent::from_rgb(&Rgb::new(200.0, 30.0, 40.0));
+    let b = PigmentLatent::from_rgb(&Rgb::new(20.0, 90.0, 160.0));
+    assert_eq!(a.mix(&b, 0.0), a);
+    assert_eq!(a.mix(&b, 1.0).to_rgb(), b.to_rgb());
+}
+
+#[test]
+fn test_mix_paint() {
+    // blue and yellow make green, where a linear mix of sRGB is a desaturated gray
+    let green = mix_paint(&Rgb::new(0.0, 33.0, 133.0), &Rgb::new(252.0, 211.0, 0.0), 0.5);
+    assert!(green.g > green.r + 40.0 && green.g > green.b + 20.0, "{:?}", green);
+    let green = mix_paint(&Rgb::new(0.0, 0.0, 255.0), &Rgb::new(255.0, 255.0, 0.0), 0.5);
+    assert!(green.g > green.r + 40.0 && green.g > green.b + 20.0, "{:?}", green);
+
+    // black and white make a gray, yellow and red an orange
+    let gray = mix_paint(&Lab::new(0.0, 0.0, 0.0), &Lab::new(100.0, 0.0, 0.0), 0.5);
+    assert!(gray.l > 20.0 && gray.l < 60.0 && gray.a.abs() < 1.0 && gray.b.abs() < 1.0);
+    let orange = Hsv::from_rgb(&mix_paint(&Rgb::new(255.0, 0.0, 0.0), &Rgb::new(255.0, 255.0, 0.0), 0.5));
+    assert!(orange.h > 5.0 && orange.h < 45.0);
+}
@@ -0,0 +1,41 @@
+use color_space::*;
+
+#[test]
+fn test_metamerism_identical() {
+    let a = Rgb::new(120.0, 90.0, 60.0).to_spectrum(Upsampling::MallettYuksel);
+    let b = a.scale(0.8);
+    // the multiplicative correction removes a plain difference in reflectance
+    for &test in &[Illuminant::A, Illuminant::F11, Illuminant::D50] {
+        let index = metamerism_index(&a, &b, Illuminant::D65, test, Observer::Cie1931, |a, b| a.compare_cie1976(b));
+        assert!(index < 1e-6, "{:?} {}", test, index);
+    }
+}
+
+#[test]
+fn test_metamerism_pair() {
+    let rgb = Rgb::new(120.0, 90.0, 60.0);
+    let a = rgb.to_spectrum(Upsampling::MallettYuksel);
+    let b = rgb.to_spectrum(Upsampling::JakobHanika);
+    let reference = a.to_xyz_relative(Illuminant::D65, Observer::Cie1931)
+        .compare_cie2000(&b.to_xyz_relative(Illuminant::D65, Observer::Cie1931));
+    assert!(reference < 0.05);
+
+    let tests = [Illuminant::D65, Illuminant::A, Illuminant::F11];
+    let indices = metamerism_indices(&a, &b, Illuminant::D65, &tests, Observer::Cie1931, |a, b| a.compare_cie2000(b));
+    assert_eq!(indices.len(), 3);
+    assert_eq!(indices[0].0, Illuminant::D65);
+    assert!(indices[0].1 < 1e-6);
+    // the tri-band lamp separates the spectra more than the smooth incandescent
+    assert!(indices[1].1 > 0.05, "{:?}", indices);
+    assert!(indices[2].1 > indices[1].1, "{:?}", indices);
+}
+
+#[test]
+fn test_relative_white() {
+    let white = Spectrum::from_fn(380.0, 780.0, 5.0, |_| 1.0);
+    for &illuminant in &[Illuminant::A, Illuminant::F2, Illuminant::D65] {
+        let lab = Lab::from_color(&white.to_xyz_relative(illuminant, Observer::Cie1964));
+        // within the rounding of the sRGB matrices used on the way to Lab
+        assert!((lab.l - 100.0).abs() < 1e-3 && lab.a.abs() < 0.02 && lab.b.abs() < 0.02);
+    }
+}
@@ -0,0 +1,82 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} is not within {} of {}", a, tolerance, b);
+}
+
+#[test]
+fn test_hue_interpolation() {
+    let cases = [
+        (HueInterpolation::Shorter, 350.0, 10.0, 0.0),
+        (HueInterpolation::Shorter, 10.0, 350.0, 0.0),
+        (HueInterpolation::Longer, 350.0, 10.0, 180.0),
+        (HueInterpolation::Longer, 10.0, 350.0, 180.0),
+        (HueInterpolation::Increasing, 350.0, 10.0, 0.0),
+        (HueInterpolation::Increasing, 10.0, 350.0, 180.0),
+        (HueInterpolation::Decreasing, 350.0, 10.0, 180.0),
+        (HueInterpolation::Decreasing, 10.0, 350.0, 0.0),
+        (HueInterpolation::Shorter, -30.0, 390.0, 0.0),
+    ];
+    for &(method, from, to, expected) in &cases {
+        assert_near(method.interpolate(from, to, 0.5), expected, 1e-9);
+    }
+    assert_near(HueInterpolation::Increasing.interpolate(300.0, 60.0, 0.25), 330.0, 1e-9);
+    assert_eq!(HueInterpolation::default(), HueInterpolation::Shorter);
+}
+
+#[test]
+fn test_mix() {
+    let a = Lab::new(20.0, 10.0, -10.0);
+    let b = Lab::new(60.0, -30.0, 50.0);
+    assert_eq!(a.lerp(&b, 0.25), Lab::new(30.0, 0.0, 5.0));
+    assert_eq!(Cmyk::new(0.0, 1.0, 0.0, 0.2).lerp(&Cmyk::new(1.0, 0.0, 0.0, 0.4), 0.5), Cmyk::new(0.5, 0.5, 0.0, 0.3));
+
+    let red = Lch::new(50.0, 60.0, 20.0);
+    let violet = Lch::new(50.0, 40.0, 300.0);
+    assert_eq!(red.lerp(&violet, 0.5), Lch::new(50.0, 50.0, 340.0));
+    assert_eq!(red.mix(&violet, 0.5, HueInterpolation::Longer), Lch::new(50.0, 50.0, 160.0));
+    let hsl = Hsl::new(0.0, 1.0, 0.5).mix(&Hsl::new(240.0, 1.0, 0.5), 0.5, HueInterpolation::Increasing);
+    assert_eq!(hsl, Hsl::new(120.0, 1.0, 0.5));
+}
+
+#[test]
+fn test_powerless_hue() {
+    // white converts with an arbitrary hue, which must not pull the mix
+    let white = Oklch::from_rgb(&Rgb::new(255.0, 255.0, 255.0));
+    let blue = Oklch::from_rgb(&Rgb::new(0.0, 0.0, 255.0));
+    for &t in &[0.25, 0.5, 0.75] {
+        assert_near(white.lerp(&blue, t).h, blue.h, 1e-9);
+        assert_near(blue.lerp(&white, t).h, blue.h, 1e-9);
+    }
+    let gray = Hsv::new(200.0, 0.0, 0.5);
+    assert_near(gray.lerp(&Hsv::new(20.0, 1.0, 1.0), 0.5).h, 20.0, 1e-9);
+
+    let purple: Rgb = mix_in::<Oklch, _>(&Rgb::new(255.0, 0.0, 0.0), &Rgb::new(0.0, 0.0, 255.0), 0.5, HueInterpolation::Shorter);
+    assert!(purple.r > purple.g + 50.0 && purple.b > purple.g + 50.0);
+    let gray: Rgb = mix_in::<Lch, _>(&Rgb::new(0.0, 0.0, 0.0), &Rgb::new(255.0, 255.0, 255.0), 0.5, HueInterpolation::Longer);
+    assert_near(gray.r, gray.b, 0.05);
+}
+
+#[test]
+fn test_premultiplied_alpha() {
+    let red = Alpha::new(Rgb::new(255.0, 0.0, 0.0), 1.0);
+    let clear = Alpha::new(Rgb::new(0.0, 0.0, 255.0), 0.0);
+    // a transparent color contributes no color
+    let mixed = red.lerp(&clear, 0.5);
+    assert_eq!(mixed, Alpha::new(Rgb::new(255.0, 0.0, 0.0), 0.5));
+
+    // CSS Color 4: 25% opaque blue and opaque red meet at (0.25·255, 0, 0.75·255) / 0.625
+    let a = Alpha::new(Rgb::new(0.0, 0.0, 255.0), 0.25);
+    let b = Alpha::new(Rgb::new(255.0, 0.0, 0.0), 1.0);
+    let mixed = a.lerp(&b, 0.5);
+    assert_near(mixed.alpha, 0.625, 1e-12);
+    assert_near(mixed.color.r, 0.5 * 255.0 / 0.625, 1e-9);
+    assert_near(mixed.color.b, 0.125 * 255.0 / 0.625, 1e-9);
+
+    // hues are not premultiplied
+    let a = Alpha::new(Lch::new(50.0, 40.0, 0.0), 0.2);
+    let b = Alpha::new(Lch::new(70.0, 40.0, 90.0), 1.0);
+    assert_near(a.lerp(&b, 0.5).color.h, 45.0, 1e-9);
+    let none = Alpha::new(Lch::new(50.0, 40.0, 0.0), 0.0).lerp(&Alpha::new(Lch::new(70.0, 40.0, 90.0), 0.0), 0.5);
+    assert_eq!(none, Alpha::new(Lch::new(60.0, 40.0, 45.0), 0.0));
+}
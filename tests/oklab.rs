@@ -0,0 +1,36 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance, "{} != {} (tolerance {})", a, b, tolerance);
+}
+
+#[test]
+fn test_oklab_primaries() {
+    let expected = [
+        (Rgb::new(255.0, 0.0, 0.0), Oklab::new(0.627955, 0.224863, 0.125846)),
+        (Rgb::new(0.0, 255.0, 0.0), Oklab::new(0.866440, -0.233888, 0.179498)),
+        (Rgb::new(0.0, 0.0, 255.0), Oklab::new(0.452014, -0.032457, -0.311528)),
+        (Rgb::new(255.0, 255.0, 255.0), Oklab::new(1.0, 0.0, 0.0)),
+    ];
+    for (rgb, lab) in expected.iter() {
+        let oklab = Oklab::from(*rgb);
+        assert_near(oklab.l, lab.l, 1e-5);
+        assert_near(oklab.a, lab.a, 1e-5);
+        assert_near(oklab.b, lab.b, 1e-5);
+    }
+}
+
+#[test]
+fn test_oklab_round_trip() {
+    let rgb = Rgb::new(192.0, 128.0, 64.0);
+    let back = Oklab::from(rgb).to_rgb();
+    assert_near(back.r, rgb.r, 1e-4);
+    assert_near(back.g, rgb.g, 1e-4);
+    assert_near(back.b, rgb.b, 1e-4);
+
+    let lch = Oklch::from(rgb);
+    let lab = Oklab::from(rgb);
+    assert_near(lch.c, (lab.a * lab.a + lab.b * lab.b).sqrt(), 1e-12);
+    let back = Rgb::from(lch);
+    assert_near(back.g, rgb.g, 1e-4);
+}
@@ -0,0 +1,87 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} is not within {} of {}", a, tolerance, b);
+}
+
+fn reference() -> Vec<(&'static str, Lab)> {
+    vec![
+        ("P1", Lab::new(95.0, 0.0, -2.0)),
+        ("C", Lab::new(55.0, -37.0, -50.0)),
+        ("M", Lab::new(48.0, 74.0, -3.0)),
+        ("Y", Lab::new(89.0, -5.0, 93.0)),
+        ("K", Lab::new(16.0, 0.0, 0.0)),
+    ]
+}
+
+#[test]
+fn test_statistics() {
+    let measured = vec![
+        ("K", Lab::new(20.0, 0.0, 0.0)),
+        ("P1", Lab::new(95.0, 0.0, -2.0)),
+        ("C", Lab::new(56.0, -37.0, -50.0)),
+        ("M", Lab::new(48.0, 72.0, -3.0)),
+        ("X", Lab::new(50.0, 0.0, 0.0)),
+    ];
+    let report = QcReport::new(&reference(), &measured, DeltaE::Cie1976, 3.0);
+    assert_eq!(report.patches.len(), 4);
+    assert_eq!(report.missing, vec!["Y".to_string()]);
+    assert_near(report.mean(), 7.0 / 4.0, 1e-9);
+    assert_near(report.max(), 4.0, 1e-9);
+    // sorted 0, 1, 2, 4: the 95th percentile is 85% of the way from 2 to 4
+    assert_near(report.percentile(95.0), 3.7, 1e-9);
+    assert_near(report.percentile(50.0), 1.5, 1e-9);
+    assert_eq!(report.over_tolerance(), 1);
+    assert!(!report.passes());
+    let worst: Vec<&str> = report.worst(2).iter().map(|p| p.id.as_str()).collect();
+    assert_eq!(worst, vec!["K", "M"]);
+    assert!(report.to_string().contains("1 over tolerance"));
+}
+
+#[test]
+fn test_metrics() {
+    let measured = vec![("M", Lab::new(48.0, 70.0, -3.0))];
+    let reference = reference();
+    let de76 = QcReport::new(&reference, &measured, DeltaE::Cie1976, 2.0);
+    let de00 = QcReport::new(&reference, &measured, DeltaE::Cie2000, 2.0);
+    assert_near(de76.max(), 4.0, 1e-9);
+    // chroma differences of saturated colors weigh less in ΔE00
+    assert!(de00.max() < 1.5);
+    assert_near(de00.max(), reference[2].1.compare_cie2000(&measured[0].1), 1e-12);
+
+    let empty = QcReport::new(&reference[..0], &measured, DeltaE::Cmc, 2.0);
+    assert_eq!((empty.mean(), empty.max(), empty.percentile(95.0)), (0.0, 0.0, 0.0));
+    assert!(empty.passes());
+}
+
+#[test]
+fn test_not_a_number() {
+    let measured = vec![
+        ("P1", Lab::new(95.0, 0.0, -2.0)),
+        ("C", Lab::new(f64::NAN, -37.0, -50.0)),
+        ("M", Lab::new(48.0, 72.0, -3.0)),
+        ("Y", Lab::new(89.0, -5.0, 93.0)),
+        ("K", Lab::new(16.0, 0.0, f64::INFINITY)),
+    ];
+    let report = QcReport::new(&reference(), &measured, DeltaE::Cie2000, 3.0);
+    assert_eq!(report.missing, vec!["C".to_string(), "K".to_string()]);
+    assert!(report.patches.iter().all(|p| p.delta_e.is_finite()));
+    assert!(!report.passes());
+    assert!(report.percentile(95.0).is_finite());
+    assert_eq!(report.worst(1)[0].id, "M");
+}
+
+#[test]
+fn test_from_cgats() {
+    let mut reference = Cgats::new("CGATS.17");
+    let mut measured = Cgats::new("CGATS.17");
+    for (i, l) in [20.0, 50.0, 80.0].iter().enumerate() {
+        let id = format!("{}", i + 1);
+        reference.push(&id, &[&Lab::new(*l, 0.0, 0.0)]).unwrap();
+        measured.push(&id, &[&Lab::new(*l + 0.5, 0.0, 0.0)]).unwrap();
+    }
+    let report = QcReport::from_cgats(&reference, &measured, DeltaE::Cie1976, 1.0);
+    assert_eq!(report.patches.len(), 3);
+    assert_near(report.mean(), 0.5, 1e-9);
+    assert!(report.passes());
+}
@@ -0,0 +1,162 @@
+use color_space::*;
+
+/// The white of the `Lab` conversion, D65 integrated from its spectrum.
+fn white() -> Xyz {
+    Illuminant::D65.white_point(Observer::Cie1931)
+}
+
+/// A display whose black is raised by flare.
+struct Flare(f64);
+
+impl Profile for Flare {
+    fn channels(&self) -> usize {
+        3
+    }
+
+    fn device_to_xyz(&self, device: &[f64]) -> Xyz {
+        let xyz = RgbSpace::SRGB.to_xyz([device[0], device[1], device[2]]);
+        Xyz::new(
+            xyz.x * (1.0 - self.0) + white().x * self.0,
+            xyz.y * (1.0 - self.0) + white().y * self.0,
+            xyz.z * (1.0 - self.0) + white().z * self.0
+        )
+    }
+
+    fn xyz_to_device(&self, xyz: &Xyz) -> Vec<f64> {
+        let f = 1.0 - self.0;
+        RgbSpace::SRGB.from_xyz(&Xyz::new(
+            (xyz.x - white().x * self.0) / f,
+            (xyz.y - white().y * self.0) / f,
+            (xyz.z - white().z * self.0) / f
+        )).to_vec()
+    }
+
+    fn media_white(&self) -> Xyz {
+        white()
+    }
+}
+
+fn assert_close(a: &[f64], b: &[f64], tolerance: f64) {
+    assert!(a.iter().zip(b).all(|(x, y)| (x - y).abs() < tolerance), "{:?} {:?}", a, b);
+}
+
+#[test]
+fn test_relative_colorimetric() {
+    let transform = Transform::new(&RgbSpace::SRGB, &RgbSpace::DISPLAY_P3, RenderingIntent::RelativeColorimetric, false);
+    let rgb = [0.8, 0.3, 0.1];
+    let expected = RgbSpace::DISPLAY_P3.from_xyz(&RgbSpace::SRGB.to_xyz(rgb));
+    assert_close(&transform.apply(&rgb), &expected, 1e-9);
+    // the white of a D50 space maps to white
+    let transform = Transform::new(&RgbSpace::PROPHOTO_RGB, &RgbSpace::SRGB, RenderingIntent::RelativeColorimetric, false);
+    assert_close(&transform.apply(&[1.0, 1.0, 1.0]), &[1.0, 1.0, 1.0], 1e-6);
+    // out of gamut colors are clipped
+    let out = transform.apply(&[0.0, 1.0, 0.0]);
+    assert!(out.iter().all(|&c| (0.0..=1.0).contains(&c)));
+}
+
+#[test]
+fn test_absolute_colorimetric() {
+    let transform = Transform::new(&RgbSpace::PROPHOTO_RGB, &RgbSpace::SRGB, RenderingIntent::AbsoluteColorimetric, false);
+    let white = transform.apply(&[0.8, 0.8, 0.8]);
+    // the D50 white is yellowish on a D65 display
+    assert!(white[0] > white[2] + 0.05, "{:?}", white);
+}
+
+#[test]
+fn test_black_point_compensation() {
+    let flare = Flare(0.02);
+    let black = flare.black_point();
+    assert!((black.y - 2.0).abs() < 1e-6, "{:?}", black);
+    let plain = Transform::new(&flare, &RgbSpace::SRGB, RenderingIntent::RelativeColorimetric, false);
+    let bpc = Transform::new(&flare, &RgbSpace::SRGB, RenderingIntent::RelativeColorimetric, true);
+    assert!(plain.apply(&[0.0, 0.0, 0.0])[1] > 0.1);
+    assert_close(&bpc.apply(&[0.0, 0.0, 0.0]), &[0.0, 0.0, 0.0], 1e-6);
+    assert_close(&bpc.apply(&[1.0, 1.0, 1.0]), &[1.0, 1.0, 1.0], 1e-6);
+    // and the other way round, black is lifted onto the flare
+    let back = Transform::new(&RgbSpace::SRGB, &flare, RenderingIntent::RelativeColorimetric, true);
+    assert_close(&back.apply(&[0.0, 0.0, 0.0]), &[0.0, 0.0, 0.0], 1e-6);
+}
+
+#[test]
+fn test_perceptual_and_saturation() {
+    let perceptual = Transform::new(&RgbSpace::DISPLAY_P3, &RgbSpace::SRGB, RenderingIntent::Perceptual, false);
+    let saturation = Transform::new(&RgbSpace::DISPLAY_P3, &RgbSpace::SRGB, RenderingIntent::Saturation, false);
+    let clip = Transform::new(&RgbSpace::DISPLAY_P3, &RgbSpace::SRGB, RenderingIntent::RelativeColorimetric, false);
+    // neutrals and weak colors are kept
+    assert_close(&perceptual.apply(&[0.5, 0.5, 0.5]), &[0.5, 0.5, 0.5], 1e-4);
+    let weak = [0.5, 0.45, 0.42];
+    assert_close(&perceptual.apply(&weak), &clip.apply(&weak), 1e-4);
+    // distinct out of gamut greens stay distinct rather than clipping to the same color
+    let distance = |t: &Transform<RgbSpace, RgbSpace>| {
+        let (a, b) = (t.apply(&[0.0, 1.0, 0.0]), t.apply(&[0.15, 1.0, 0.15]));
+        a.iter().zip(&b).map(|(x, y)| (x - y).abs()).sum::<f64>()
+    };
+    assert!(distance(&perceptual) > 2.0 * distance(&clip), "{} {}", distance(&perceptual), distance(&clip));
+    // the P3 primary lands on the boundary of sRGB with the saturation intent
+    let green = saturation.apply(&[0.0, 1.0, 0.0]);
+    assert!(green.iter().any(|&c| !(1e-3..=1.0 - 1e-3).contains(&c)), "{:?}", green);
+}
+
+/// A display whose profile lifts the shadows in its inverse, as some printer profiles do.
+struct Rolloff(f64);
+
+impl Profile for Rolloff {
+    fn channels(&self) -> usize {
+        3
+    }
+
+    fn device_to_xyz(&self, device: &[f64]) -> Xyz {
+        Flare(self.0).device_to_xyz(device)
+    }
+
+    fn xyz_to_device(&self, xyz: &Xyz) -> Vec<f64> {
+        let lift = 100.0 * self.0;
+        Flare(self.0).xyz_to_device(&Xyz::new(
+            xyz.x + white().x / white().y * lift,
+            xyz.y + lift,
+            xyz.z + white().z / white().y * lift
+        ))
+    }
+
+    fn media_white(&self) -> Xyz {
+        white()
+    }
+}
+
+/// A press with a black of lightness 16.
+fn press(cmyk: &Cmyk) -> Lab {
+    let lab = Lab::from_color(cmyk);
+    Lab::new(16.0 + lab.l * 0.84, lab.a * 0.9, lab.b * 0.9)
+}
+
+#[test]
+fn test_destination_black_point() {
+    // the round trip of the shadows is not straight, so the black is fitted below the
+    // initial estimate
+    let rolloff = Rolloff(0.02);
+    let initial = Flare(0.02).black_point();
+    let fitted = rolloff.black_point();
+    assert!(fitted.y < initial.y - 0.5 && fitted.y >= 0.0, "{:?} {:?}", fitted, initial);
+    assert!((fitted.x / fitted.y - white().x / white().y).abs() < 1e-9);
+
+    let levels = [0.0, 0.25, 0.5, 0.75, 1.0];
+    let mut samples = Vec::new();
+    for &k in &levels {
+        for &c in &levels {
+            for &m in &levels {
+                for &y in &levels {
+                    let cmyk = Cmyk::new(c, m, y, k);
+                    samples.push((cmyk, press(&cmyk)));
+                }
+            }
+        }
+    }
+    let model = CmykModel::new(&samples).unwrap();
+    let black = Lab::from_color(&model.black_point());
+    assert!(black.l > 16.0 && black.l < 25.0, "{:?}", black);
+    // with compensation, the black of sRGB lands on the black point of the press
+    let transform = Transform::new(&RgbSpace::SRGB, &model, RenderingIntent::RelativeColorimetric, true);
+    let device = transform.apply(&[0.0, 0.0, 0.0]);
+    let printed = Lab::from_color(&model.device_to_xyz(&device));
+    assert!((printed.l - black.l).abs() < 1.0, "{:?} {:?}", printed, black);
+}
@@ -0,0 +1,71 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() < tolerance, "{} != {}", a, b);
+}
+
+const COLORS: [(f64, f64, f64); 6] = [
+    (255.0, 255.0, 255.0),
+    (200.0, 180.0, 150.0),
+    (120.0, 60.0, 30.0),
+    (40.0, 90.0, 160.0),
+    (30.0, 30.0, 30.0),
+    (0.0, 0.0, 0.0),
+];
+
+#[test]
+fn test_naive() {
+    for &(r, g, b) in &COLORS {
+        let rgb = Rgb::new(r, g, b);
+        assert_eq!(Separation::NAIVE.separate(&rgb), Cmyk::from_rgb(&rgb));
+    }
+}
+
+#[test]
+fn test_separation() {
+    let separation = Separation::default();
+    for &(r, g, b) in &COLORS {
+        let rgb = Rgb::new(r, g, b);
+        let cmyk = separation.separate(&rgb);
+        assert!(cmyk.total_ink() <= separation.total_ink + 1e-9, "{:?}", cmyk);
+        assert!(cmyk.k <= separation.max_black);
+        // colors within the limits are reproduced
+        if r + g + b > 200.0 {
+            let back = cmyk.to_rgb();
+            assert_near(back.r, r, 1e-6);
+            assert_near(back.g, g, 1e-6);
+            assert_near(back.b, b, 1e-6);
+        }
+    }
+    // no black in the highlights, more black in the shadows
+    assert_eq!(separation.separate(&Rgb::new(220.0, 210.0, 200.0)).k, 0.0);
+    let k = |v: f64| separation.separate(&Rgb::new(v, v, v)).k;
+    assert!(k(60.0) > k(120.0) && k(120.0) > k(180.0));
+    assert!(k(0.0) >= 0.9);
+}
+
+#[test]
+fn test_ucr() {
+    let ucr = Separation { gcr: 0.0, ucr: 1.0, ..Separation::default() };
+    // the same gray component in a neutral and a chromatic color
+    let neutral = ucr.separate(&Rgb::new(90.0, 90.0, 90.0));
+    let chromatic = ucr.separate(&Rgb::new(90.0, 200.0, 240.0));
+    assert!(neutral.k > 0.3 && chromatic.k < 0.5 * neutral.k, "{:?} {:?}", neutral, chromatic);
+}
+
+#[test]
+fn test_limit_ink() {
+    let rich = Cmyk::new(1.0, 1.0, 1.0, 1.0);
+    let limited = rich.limit_ink(3.0, 0.9);
+    assert!(limited.total_ink() <= 3.0 + 1e-9 && limited.k <= 0.9, "{:?}", limited);
+    // replacing gray with black keeps the color when it suffices
+    let dark = Cmyk::new(0.9, 0.8, 0.85, 0.6);
+    let limited = dark.limit_ink(2.6, 1.0);
+    assert!(limited.total_ink() <= 2.6 + 1e-9);
+    let (a, b) = (dark.to_rgb(), limited.to_rgb());
+    assert_near(a.r, b.r, 1e-6);
+    assert_near(a.g, b.g, 1e-6);
+    assert_near(a.b, b.b, 1e-6);
+    let light = Cmyk::new(0.2, 0.3, 0.1, 0.0);
+    assert_eq!(light.limit_ink(3.0, 1.0), light);
+}
@@ -0,0 +1,63 @@
+use color_space::*;
+
+fn assert_near(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance, "{} != {} (tolerance {})", a, b, tolerance);
+}
+
+fn chromaticity(xyz: &Xyz) -> (f64, f64) {
+    let sum = xyz.x + xyz.y + xyz.z;
+    (xyz.x / sum, xyz.y / sum)
+}
+
+#[test]
+fn test_spectrum_interpolation() {
+    let spectrum = Spectrum::uniform(400.0, 10.0, vec![0.0, 1.0, 3.0]);
+    assert_eq!(spectrum.wavelengths, vec![400.0, 410.0, 420.0]);
+    assert_near(spectrum.value_at(405.0), 0.5, 1e-12);
+    assert_near(spectrum.value_at(417.5), 2.5, 1e-12);
+    assert_near(spectrum.value_at(300.0), 0.0, 1e-12);
+    assert_near(spectrum.value_at(800.0), 3.0, 1e-12);
+
+    let resampled = spectrum.resample(400.0, 420.0, 5.0);
+    assert_eq!(resampled.values, vec![0.0, 0.5, 1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_spectrum_equal_energy() {
+    let e = Spectrum::from_fn(380.0, 780.0, 5.0, |_| 1.0);
+    for &observer in &[Observer::Cie1931, Observer::Cie1964] {
+        let (x, y) = chromaticity(&e.to_xyz(observer));
+        assert_near(x, 1.0 / 3.0, 2e-4);
+        assert_near(y, 1.0 / 3.0, 2e-4);
+    }
+}
+
+#[test]
+fn test_spectrum_monochromatic() {
+    let (x, y) = chromaticity(&Spectrum::new(vec![545.0, 550.0, 555.0], vec![0.0, 1.0, 0.0]).to_xyz(Observer::Cie1931));
+    assert_near(x, 0.30160, 1e-4);
+    assert_near(y, 0.69231, 1e-4);
+    let (x, y, z) = Observer::Cie1931.cmf(552.5);
+    assert_near(x, 0.47275, 1e-6);
+    assert_near(y, 0.997475, 1e-6);
+    assert_near(z, 0.00725, 1e-6);
+    assert_eq!(Observer::Cie1931.cmf(830.0), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_spectrum_reflective() {
+    // a blackbody at 2856K is CIE illuminant A
+    let a = Spectrum::from_fn(380.0, 780.0, 5.0, |nm| {
+        let m = nm * 1e-9;
+        1.0 / (m.powf(5.0) * ((1.4388e-2 / (m * 2856.0)).exp() - 1.0))
+    });
+    let white = Spectrum::from_fn(380.0, 780.0, 5.0, |_| 1.0);
+    let xyz = white.to_xyz_reflective(&a, Observer::Cie1931);
+    assert_near(xyz.x, 109.85, 0.02);
+    assert_near(xyz.y, 100.0, 1e-9);
+    assert_near(xyz.z, 35.58, 0.02);
+    assert_eq!(white.to_xyz_reflective(&a, Observer::Cie1931), (&white * &a).to_xyz(Observer::Cie1931));
+
+    let grey = white.scale(0.2);
+    assert_near(grey.to_xyz_reflective(&a, Observer::Cie1931).y, 20.0, 1e-9);
+}
@@ -0,0 +1,42 @@
+use color_space::*;
+
+fn assert_close(a: (f64, f64), b: (f64, f64)) {
+    assert!((a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn test_chromaticity_d65() {
+    assert_close(xy_to_uv(0.31271, 0.32902), (0.19783, 0.31223));
+    assert_close(xy_to_uv_prime(0.31271, 0.32902), (0.19783, 0.46835));
+    assert_close(uv_to_xy(0.19783, 0.31223), (0.31271, 0.32902));
+    assert_close(uv_prime_to_xy(0.19783, 0.46835), (0.31271, 0.32902));
+    assert_close(uv_to_uv_prime(0.19783, 0.31223), (0.19783, 0.46835));
+    assert_close(uv_prime_to_uv(0.19783, 0.46835), (0.19783, 0.31223));
+}
+
+#[test]
+fn test_convert_xyz_ucs1960() {
+    let xyz = Xyz::new(18.74644744398548, 20.56235357029598, 46.16058375040178);
+    let ucs = Ucs1960::from(xyz);
+    assert_eq!(ucs, Ucs1960::new(20.56235357029598, 0.16102999142875477, 0.26494264812628177));
+    assert_eq!(Xyz::from(ucs), xyz);
+}
+
+#[test]
+fn test_convert_xyz_ucs1976() {
+    let xyz = Xyz::new(18.74644744398548, 20.56235357029598, 46.16058375040178);
+    let ucs = Ucs1976::from(xyz);
+    assert_eq!(ucs, Ucs1976::new(20.56235357029598, 0.16102999142875477, 0.3974139721894227));
+    assert_eq!(Xyz::from(ucs), xyz);
+}
+
+#[test]
+fn test_convert_xyz_uvw() {
+    let white = Xyz::new(95.047, 100.0, 108.883);
+    let xyz = Xyz::new(18.74644744398548, 20.56235357029598, 46.16058375040178);
+    let uvw = Uvw::from_xyz(&xyz, &white);
+    assert_eq!(uvw, Uvw::new(-24.63968369075066, -31.649220422113128, 51.490596648073875));
+    assert_eq!(uvw.to_xyz(&white), xyz);
+    assert_eq!(Uvw::from(xyz), uvw);
+    assert_eq!(Uvw::from_xyz(&white, &white), Uvw::new(0.0, 0.0, 99.0));
+}
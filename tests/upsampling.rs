@@ -0,0 +1,84 @@
+use color_space::*;
+
+fn reflected(spectrum: &Spectrum) -> Rgb {
+    let xyz = spectrum.to_xyz_reflective(&Illuminant::D65.spectrum(), Observer::Cie1931);
+    Rgb::from_color(&xyz)
+}
+
+fn colors() -> Vec<Rgb> {
+    vec![
+        Rgb::new(128.0, 128.0, 128.0),
+        Rgb::new(200.0, 40.0, 30.0),
+        Rgb::new(30.0, 180.0, 60.0),
+        Rgb::new(20.0, 50.0, 220.0),
+        Rgb::new(250.0, 220.0, 10.0),
+        Rgb::new(90.0, 60.0, 40.0),
+        Rgb::new(5.0, 5.0, 5.0),
+    ]
+}
+
+#[test]
+fn test_upsampling_reproduces_color() {
+    for &method in &[Upsampling::JakobHanika, Upsampling::MallettYuksel] {
+        for rgb in colors() {
+            let spectrum = rgb.to_spectrum(method);
+            assert!(spectrum.values.iter().all(|&v| (0.0..=1.0 + 1e-9).contains(&v)));
+            let diff = rgb.compare_cie2000(&reflected(&spectrum));
+            assert!(diff < 0.05, "{:?} {:?} differs by {}", method, rgb, diff);
+        }
+    }
+}
+
+#[test]
+fn test_upsampling_white() {
+    for &method in &[Upsampling::Smits, Upsampling::JakobHanika, Upsampling::MallettYuksel] {
+        let white = Rgb::new(255.0, 255.0, 255.0).to_spectrum(method);
+        assert!(white.values.iter().all(|&v| v > 0.99 && v < 1.001), "{:?}", method);
+    }
+}
+
+#[test]
+fn test_upsampling_smits() {
+    for rgb in colors() {
+        let diff = rgb.compare_cie2000(&reflected(&rgb.to_spectrum(Upsampling::Smits)));
+        assert!(diff < 6.0, "{:?} differs by {}", rgb, diff);
+    }
+}
+
+#[test]
+fn test_upsampling_other_types() {
+    let lab = Lab::new(60.0, 30.0, -20.0);
+    let spectrum = lab.to_spectrum(Upsampling::MallettYuksel);
+    assert!(lab.compare_cie2000(&reflected(&spectrum)) < 0.05);
+}
+
+#[test]
+fn test_sigmoid_table() {
+    let table = SigmoidTable::new(4);
+    let rgb = Rgb::new(70.0, 140.0, 210.0);
+    let spectrum = SigmoidTable::spectrum(&table.fetch(&rgb));
+    assert!(rgb.compare_cie2000(&reflected(&spectrum)) < 0.05);
+}
+
+#[test]
+fn test_precomputed_srgb_tables() {
+    let table = SigmoidTable::new(16);
+    let srgb = SigmoidTable::srgb();
+    assert_eq!(table.coefficients().len(), srgb.coefficients().len());
+    for (a, b) in table.coefficients().iter().zip(srgb.coefficients()) {
+        for k in 0..3 {
+            assert!((a[k] - b[k]).abs() <= 1e-9 * a[k].abs().max(1.0), "{:?} != {:?}", a, b);
+        }
+    }
+
+    let red = Xyz::new(41.24, 21.26, 1.93);
+    let green = Xyz::new(35.76, 71.52, 11.92);
+    let basis = mallett_yuksel_basis(&red, &green);
+    let primaries = [Rgb::new(255.0, 0.0, 0.0), Rgb::new(0.0, 255.0, 0.0), Rgb::new(0.0, 0.0, 255.0)];
+    for (spectrum, rgb) in basis.iter().zip(&primaries) {
+        let precomputed = rgb.to_spectrum(Upsampling::MallettYuksel);
+        for (a, b) in spectrum.values.iter().zip(&precomputed.values) {
+            assert!((a - b).abs() <= 1e-9, "{} != {}", a, b);
+        }
+    }
+}